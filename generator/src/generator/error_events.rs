@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use super::get_ns_name_prefix;
 use super::output::Output;
 
@@ -139,6 +141,55 @@ fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(out, "impl core::fmt::Display for ErrorKind {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "Self::Unknown(code) => write!(f, \"Unknown error {{}}\", code),"
+                );
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for err_name in sorted_errors(ns).iter().map(|def| def.name()) {
+                        // Core errors are known as e.g. BadWindow. Extension errors are prefixed
+                        // with the name of their extension.
+                        let bad_name = if err_name == "GContext" {
+                            // Xlib calls this BadGC
+                            "BadGC".to_string()
+                        } else if err_name.starts_with("Bad") {
+                            err_name.to_string()
+                        } else {
+                            format!("Bad{}", err_name)
+                        };
+                        let name = match &ns.ext_info {
+                            Some(ext_info) => format!("{} {}", ext_info.name, bad_name),
+                            None => bad_name,
+                        };
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out,
+                            "Self::{}{} => f.write_str(\"{}\"),",
+                            get_ns_name_prefix(ns),
+                            err_name,
+                            name,
+                        );
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
 }
 
 fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
@@ -287,18 +338,36 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "}}");
         outln!(out, "");
 
+        // Without any extension with XGE events, event_type is unused
+        let xge_features = namespaces
+            .iter()
+            .filter(|ns| ns.ext_info.is_some())
+            .filter(|ns| sorted_events(ns).iter().any(|event_def| event_def.is_xge()))
+            .map(|ns| format!("feature = \"{}\"", ns.header))
+            .collect::<Vec<_>>();
         outln!(out, "#[allow(clippy::match_single_binding)]");
+        outln!(
+            out,
+            "#[cfg_attr(not(any({})), allow(unused_variables))]",
+            xge_features.join(", "),
+        );
         outln!(out, "fn from_generic_event(");
         outln!(out.indent(), "event: &[u8],");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
         outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
+            // GeGenericEvent describes the bytes after the header as padding, but they belong
+            // to the actual event. Thus, only the header is parsed here, so that the
+            // strict_padding feature does not reject these bytes.
+            outln!(out, "let (_, remaining) = u8::try_parse(event)?;");
+            outln!(out, "let (extension, remaining) = u8::try_parse(remaining)?;");
             outln!(
                 out,
-                "let ge_event = xproto::GeGenericEvent::try_parse(event)?.0;"
+                "let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;"
             );
+            outln!(out, "let (event_type, _) = u16::try_parse(remaining)?;");
             outln!(out, "let ext_name = ext_info_provider");
-            outln!(out.indent(), ".get_from_major_opcode(ge_event.extension)");
+            outln!(out.indent(), ".get_from_major_opcode(extension)");
             outln!(out.indent(), ".map(|(name, _)| name);");
             outln!(out, "match ext_name {{");
             out.indented(|out| {
@@ -318,7 +387,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                     }
                     outln!(out, "Some({}::X11_EXTENSION_NAME) => {{", ns.header);
                     out.indented(|out| {
-                        outln!(out, "match ge_event.event_type {{");
+                        outln!(out, "match event_type {{");
                         for event_def in event_defs.iter() {
                             if !event_def.is_xge() {
                                 continue;
@@ -385,6 +454,31 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "}}");
         outln!(out, "");
 
+        outln!(out, "/// Get the full sequence number of this X11 event");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Only the low 16 bits of the sequence number are sent on the wire. The missing",
+        );
+        outln!(
+            out,
+            "/// bits are reconstructed from `reference` with [crate::widen_sequence_number].",
+        );
+        outln!(
+            out,
+            "/// `None` is returned for events without a sequence number, i.e. `KeymapNotify`.",
+        );
+        outln!(
+            out,
+            "pub fn sequence_number(&self, reference: crate::SequenceNumber) -> Option<crate::SequenceNumber> {{",
+        );
+        outln!(
+            out.indent(),
+            "self.wire_sequence_number().map(|wire| crate::widen_sequence_number(wire, reference))",
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+
         outln!(out, "/// Get the raw response type of this X11 event");
         outln!(out, "///");
         outln!(
@@ -454,6 +548,116 @@ pub fn sent_event(&self) -> bool {{
         );
     });
     outln!(out, "}}");
+    outln!(out, "");
+    generate_event_conversions(out, &namespaces);
+    generate_extension_event_count(out, &namespaces);
+}
+
+fn generate_extension_event_count(out: &mut Output, namespaces: &[Rc<xcbgen::defs::Namespace>]) {
+    outln!(
+        out,
+        "/// Get the number of event codes that an extension that this crate knows about uses."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// The event codes of an extension start at its `first_event`. Events that are sent via the"
+    );
+    outln!(
+        out,
+        "/// generic event extension do not use an event code of their own and are not counted."
+    );
+    outln!(
+        out,
+        "/// Extensions that are disabled via cargo features are unknown. Returns `None` for unknown"
+    );
+    outln!(out, "/// extensions.");
+    outln!(
+        out,
+        "pub fn extension_event_count(name: &str) -> Option<u8> {{"
+    );
+    out.indented(|out| {
+        outln!(out, "match name {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+                let count = if ns.header == "xkb" {
+                    // All XKB events share the first event code and have a subtype instead
+                    1
+                } else {
+                    sorted_events(ns)
+                        .iter()
+                        .filter(|event_def| !event_def.is_xge())
+                        .map(|event_def| event_def.get_original_full_def().number + 1)
+                        .max()
+                        .unwrap_or(0)
+                };
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "{}::X11_EXTENSION_NAME => Some({}),", ns.header, count);
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+/// Generate `TryFrom<Event>` for all event structs.
+///
+/// Some event structs are used by more than one event (e.g. `KeyPressEvent` is also
+/// `KeyReleaseEvent`), so all variants with the same struct are grouped together.
+fn generate_event_conversions(out: &mut Output, namespaces: &[Rc<xcbgen::defs::Namespace>]) {
+    // The namespace header (for the feature) and the variant name of each event with a struct
+    type Variants = Vec<(String, String)>;
+    let mut structs: Vec<(Rc<xcbgen::defs::EventFullDef>, Variants)> = Vec::new();
+    for ns in namespaces.iter() {
+        for event_def in sorted_events(ns).iter() {
+            let full_def = event_def.get_original_full_def();
+            let variant = format!("{}{}", get_ns_name_prefix(ns), event_def.name());
+            let entry = (ns.header.clone(), variant);
+            match structs
+                .iter_mut()
+                .find(|(def, _)| Rc::ptr_eq(def, &full_def))
+            {
+                Some((_, variants)) => variants.push(entry),
+                None => structs.push((full_def, vec![entry])),
+            }
+        }
+    }
+
+    for (full_def, variants) in structs.iter() {
+        let struct_ns = full_def.namespace.upgrade().unwrap();
+        let struct_name = format!("{}::{}Event", struct_ns.header, full_def.name);
+        if super::ext_has_feature(&struct_ns.header) {
+            outln!(out, "#[cfg(feature = \"{}\")]", struct_ns.header);
+        }
+        outln!(out, "impl TryFrom<Event> for {} {{", struct_name);
+        out.indented(|out| {
+            outln!(out, "type Error = Event;");
+            outln!(
+                out,
+                "fn try_from(event: Event) -> Result<Self, Self::Error> {{"
+            );
+            out.indented(|out| {
+                outln!(out, "match event {{");
+                for (header, variant) in variants.iter() {
+                    if super::ext_has_feature(header) {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", header);
+                    }
+                    outln!(out.indent(), "Event::{}(event) => Ok(event),", variant);
+                }
+                outln!(out.indent(), "event => Err(event),");
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+    }
 }
 
 fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
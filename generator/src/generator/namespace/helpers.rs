@@ -287,11 +287,8 @@ pub(super) enum StructSizeConstraint<'a> {
     /// This is a fixed size struct.
     Fixed(u8),
     /// This struct has a "length" field embedded that tells us how long it
-    /// should be (in 4-byte units).
-    EmbeddedLength {
-        /// The minimum size.
-        minimum: u8,
-    },
+    /// should be (in 4-byte units beyond the first 32 bytes).
+    EmbeddedLength,
     /// The size of the struct is calculated from a <length> expression
     LengthExpr(&'a xcbdefs::Expression),
 }
@@ -190,7 +190,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out: &mut Output,
     ) {
         let size_constraint = if event_full_def.xge {
-            StructSizeConstraint::EmbeddedLength { minimum: 32 }
+            StructSizeConstraint::EmbeddedLength
         } else {
             StructSizeConstraint::Fixed(32)
         };
@@ -371,6 +371,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
         );
 
+        special_cases::handle_struct(struct_def, out);
+
         outln!(out, "");
     }
 
@@ -870,7 +872,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "}}");
 
         if ok_for_bitmask {
-            outln!(out, "bitmask_binop!({}, {});", rust_name, raw_type);
+            let flags = enum_def
+                .items
+                .iter()
+                .filter(|enum_item| matches!(enum_item.value, xcbdefs::EnumValue::Bit(_)))
+                .map(|enum_item| ename_to_rust(&enum_item.name))
+                .collect::<Vec<_>>();
+            outln!(
+                out,
+                "bitmask_binop!({}, {}, [{}]);",
+                rust_name,
+                raw_type,
+                flags.join(", "),
+            );
         }
 
         outln!(out, "");
@@ -37,7 +37,7 @@ pub(super) fn emit_field_parse(
             };
             outln!(
                 out,
-                "let remaining = {from}.get({pad}..).ok_or(ParseError::InsufficientData)?;",
+                "let remaining = crate::x11_utils::parse_padding({from}, {pad})?;",
                 from = from,
                 pad = pad_size,
             );
@@ -48,7 +48,7 @@ pub(super) fn emit_field_parse(
                 out,
                 "let ({}, remaining) = {};",
                 rust_field_name,
-                emit_value_parse(generator, &normal_field.type_, from),
+                emit_field_value_parse(generator, &normal_field.type_, &rust_field_name, from),
             );
         }
         xcbdefs::FieldDef::List(list_field) => {
@@ -73,8 +73,8 @@ pub(super) fn emit_field_parse(
                 } else if let Some(ref length_expr) = list_field.length_expr {
                     outln!(
                         out,
-                        "let ({}, remaining) = crate::x11_utils::parse_u8_list({}, \
-                         {}.try_to_usize()?)?;",
+                        "let ({}, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list({}, \
+                         {}.try_to_usize()?), \"{}\")?;",
                         rust_field_name,
                         from,
                         expr_to_str(
@@ -85,6 +85,7 @@ pub(super) fn emit_field_parse(
                             None,
                             true,
                         ),
+                        rust_field_name,
                     );
                     // Only force taking ownership for non-request types.
                     if container == FieldContainer::Other {
@@ -117,7 +118,7 @@ pub(super) fn emit_field_parse(
                 outln!(
                     out,
                     "let ({}, remaining) = crate::x11_utils::parse_list::<{}>(remaining, \
-                     {}.try_to_usize()?)?;",
+                     {}.try_to_usize()?, Some(\"{}\"))?;",
                     rust_field_name,
                     rust_element_type,
                     expr_to_str(
@@ -128,6 +129,7 @@ pub(super) fn emit_field_parse(
                         None,
                         true,
                     ),
+                    rust_field_name,
                 );
             } else if let Some(list_len) = list_field.length() {
                 for i in 0..list_len {
@@ -252,7 +254,7 @@ pub(super) fn emit_field_parse(
                         out,
                         "let ({}, remaining) = {};",
                         rust_field_name,
-                        emit_value_parse(generator, &expr_ref.type_, from),
+                        emit_field_value_parse(generator, &expr_ref.type_, &rust_field_name, from),
                     );
                 }
                 _ => {
@@ -286,7 +288,11 @@ pub(super) fn emit_field_post_parse(field: &xcbdefs::FieldDef, out: &mut Output)
             assert!(u8::try_from(*v).is_ok());
             let rust_field_name = to_rust_variable_name(name);
             outln!(out, "if {} != {} {{", rust_field_name, v);
-            outln!(out.indent(), "return Err(ParseError::InvalidValue);");
+            outln!(
+                out.indent(),
+                "return Err(ParseError::UnexpectedValue {{ value: {}.into() }});",
+                rust_field_name
+            );
             outln!(out, "}}");
         }
         _ => (),
@@ -304,6 +310,24 @@ fn emit_value_parse(
     format!("{}::try_parse({})?", rust_type, params.join(", "))
 }
 
+/// Like `emit_value_parse`, but records the field name for `parse_context` if parsing fails.
+fn emit_field_value_parse(
+    generator: &NamespaceGenerator<'_, '_>,
+    type_: &xcbdefs::FieldValueType,
+    rust_field_name: &str,
+    from: &str,
+) -> String {
+    let type_type = type_.type_.get_resolved();
+    let rust_type = generator.type_to_rust_type(type_type);
+    let params = generator.get_type_parse_params(type_type, from);
+    format!(
+        "crate::x11_utils::in_field({}::try_parse({}), \"{}\")?",
+        rust_type,
+        params.join(", "),
+        rust_field_name,
+    )
+}
+
 fn emit_value_post_parse(type_: &xcbdefs::FieldValueType, var_name: &str, out: &mut Output) {
     if let xcbdefs::FieldValueSet::Enum(_) | xcbdefs::FieldValueSet::Mask(_) = type_.value_set {
         // Handle turning things into enum instances.
@@ -328,3 +352,118 @@ pub(super) fn can_use_simple_list_parsing(
         == 1
         && !needs_post_parse(type_)
 }
+
+/// Emit functions that lazily parse a list from the raw bytes of a struct.
+///
+/// This is only done for lists that come after fields that are simple to parse, so that the
+/// fields before the list can be skipped cheaply.
+pub(super) fn emit_lazy_list_accessors(
+    generator: &NamespaceGenerator<'_, '_>,
+    name: &str,
+    fields: &[xcbdefs::FieldDef],
+    out: &mut Output,
+) {
+    let mut accessors = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        match field {
+            xcbdefs::FieldDef::Pad(pad_field) => {
+                if let xcbdefs::PadKind::Align(_) = pad_field.kind {
+                    break;
+                }
+            }
+            xcbdefs::FieldDef::Normal(normal_field) => {
+                if !can_use_simple_list_parsing(generator, &normal_field.type_) {
+                    break;
+                }
+            }
+            xcbdefs::FieldDef::Expr(xcbdefs::ExprField {
+                expr: xcbdefs::Expression::Value(_),
+                ..
+            })
+            | xcbdefs::FieldDef::VirtualLen(_) => {}
+            xcbdefs::FieldDef::List(list_field) => {
+                if !generator.rust_value_type_is_u8(&list_field.element_type)
+                    && can_use_simple_list_parsing(generator, &list_field.element_type)
+                    && list_field.length_expr.is_some()
+                    && list_field.length().is_none()
+                {
+                    accessors.push((index, list_field));
+                }
+                // Anything after a list requires parsing the list first
+                break;
+            }
+            _ => break,
+        }
+    }
+    if accessors.is_empty() {
+        return;
+    }
+
+    outln!(out, "impl {} {{", name);
+    out.indented(|out| {
+        for (index, list_field) in accessors {
+            let rust_field_name = to_rust_variable_name(&list_field.name);
+            let rust_element_type =
+                generator.type_to_rust_type(list_field.element_type.type_.get_resolved());
+            outln!(
+                out,
+                "/// Lazily parse the `{}` list from the raw bytes of a `{}`.",
+                rust_field_name,
+                name,
+            );
+            outln!(out, "///");
+            outln!(
+                out,
+                "/// In contrast to parsing the whole `{}`, this does not allocate a `Vec` for the",
+                name,
+            );
+            outln!(
+                out,
+                "/// list. Instead, the returned iterator parses the entries on demand."
+            );
+            outln!(out, "#[allow(unused_variables)]");
+            outln!(
+                out,
+                "pub fn {}_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, {}>, ParseError> {{",
+                rust_field_name,
+                rust_element_type,
+            );
+            out.indented(|out| {
+                outln!(out, "let remaining = value;");
+                for field in fields[..index].iter() {
+                    emit_field_parse(
+                        generator,
+                        field,
+                        "",
+                        "remaining",
+                        FieldContainer::Other,
+                        out,
+                    );
+                }
+                for field in fields[..index].iter() {
+                    if let xcbdefs::FieldDef::Expr(_) = field {
+                        emit_field_post_parse(field, out);
+                    }
+                }
+                outln!(
+                    out,
+                    "let list_length = {}.try_to_usize()?;",
+                    expr_to_str(
+                        generator,
+                        list_field.length_expr.as_ref().unwrap(),
+                        to_rust_variable_name,
+                        false,
+                        None,
+                        true,
+                    ),
+                );
+                outln!(
+                    out,
+                    "Ok(crate::x11_utils::LazyList::new(remaining, list_length))"
+                );
+            });
+            outln!(out, "}}");
+        }
+    });
+    outln!(out, "}}");
+}
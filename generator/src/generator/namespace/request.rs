@@ -275,12 +275,13 @@ pub(super) fn generate_request(
             &[],
             false,
             true,
-            StructSizeConstraint::EmbeddedLength { minimum: 32 },
+            StructSizeConstraint::EmbeddedLength,
             true,
             true,
             reply.doc.as_ref(),
             proto_out,
         );
+        parse::emit_lazy_list_accessors(generator, &reply_struct_name, &reply_fields, proto_out);
 
         outln!(proto_out, "");
     } else {
@@ -1056,6 +1057,11 @@ fn emit_request_struct(
                 option = generator.option_name,
             );
         }
+        outln!(
+            out,
+            "const HAS_REPLY: bool = {};",
+            request_def.reply.is_some()
+        );
 
         outln!(out, "");
         let arg_name = if is_xproto {
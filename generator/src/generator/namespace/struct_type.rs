@@ -165,14 +165,12 @@ pub(super) fn emit_struct_type(
                         outln!(out, "let remaining = initial_value.get({}..)", fixed_size);
                         outln!(out.indent(), ".ok_or(ParseError::InsufficientData)?;");
                     }
-                    StructSizeConstraint::EmbeddedLength { minimum } => {
+                    StructSizeConstraint::EmbeddedLength => {
                         outln!(out, "let _ = remaining;");
                         outln!(
                             out,
-                            "let remaining = initial_value.get({} + length as usize * 4..)",
-                            minimum
+                            "let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];",
                         );
-                        outln!(out.indent(), ".ok_or(ParseError::InsufficientData)?;");
                     }
                     StructSizeConstraint::LengthExpr(length_expr) => {
                         outln!(
@@ -293,6 +293,43 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
     });
     outln!(out, "}}");
     outln!(out, "");
+
+    outln!(
+        out,
+        "/// Get the `'static` protocol name of an extension that this crate knows about.",
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// Extensions that are disabled via cargo features are unknown. Returns `None` for unknown",
+    );
+    outln!(out, "/// extensions.");
+    outln!(
+        out,
+        "pub fn known_extension_name(name: &str) -> Option<&'static str> {{"
+    );
+    out.indented(|out| {
+        outln!(out, "match name {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                if ns.header == "xproto" {
+                    continue;
+                }
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(
+                    out,
+                    "{0}::X11_EXTENSION_NAME => Some({0}::X11_EXTENSION_NAME),",
+                    ns.header,
+                );
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
 }
 
 /// Get all requests in the namespace in a sorted order
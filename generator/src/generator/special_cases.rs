@@ -81,6 +81,251 @@ pub fn value{width}(&self) -> Option<impl Iterator<Item=u{width}> + '_> {{
         outln!(out, "}}");
         outln!(out, "");
     }
+    if request_def.name == "QueryFont" && ns.header == "xproto" {
+        outln!(out, "impl QueryFontReply {{");
+        out.indented(|out| {
+            outln!(
+                out,
+                r"/// Get the metrics of the character with the given code.
+///
+/// For fonts with `min_byte1` and `max_byte1` both zero, `code` is the linear index of the
+/// character. Otherwise, the most significant byte of `code` is the row (byte1) and the least
+/// significant byte is the column (byte2) of the character.
+///
+/// `None` is returned if `code` is outside of the range of characters of the font. If the
+/// reply contains no char infos, all characters have the metrics in `min_bounds`.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xproto::{{Charinfo, QueryFontReply}};
+///
+/// let info = |width| Charinfo {{ character_width: width, ..Default::default() }};
+/// let reply = QueryFontReply {{
+///     min_char_or_byte2: 32,
+///     max_char_or_byte2: 34,
+///     char_infos: vec![info(1), info(2), info(3)],
+///     ..Default::default()
+/// }};
+/// assert_eq!(reply.char_info(33), Some(info(2)));
+/// assert_eq!(reply.char_info(35), None);
+/// ```
+pub fn char_info(&self, code: u16) -> Option<Charinfo> {{
+    let index = crate::wrapper::char_info_index(
+        self.min_byte1,
+        self.max_byte1,
+        self.min_char_or_byte2,
+        self.max_char_or_byte2,
+        code,
+    )?;
+    if self.char_infos.is_empty() {{
+        Some(self.min_bounds)
+    }} else {{
+        self.char_infos.get(index).copied()
+    }}
+}}
+
+/// Access the char infos of a raw `QueryFont` reply without parsing all of them.
+///
+/// Fonts can contain many characters and text measuring usually only needs a few of them.
+/// The returned value only parses the metrics of a character when it is looked up.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xproto::{{Charinfo, QueryFontReply}};
+/// use x11rb_protocol::x11_utils::Serialize;
+///
+/// let info = |width| Charinfo {{ character_width: width, ..Default::default() }};
+/// let raw_reply = QueryFontReply {{
+///     min_char_or_byte2: 32,
+///     max_char_or_byte2: 34,
+///     char_infos: vec![info(1), info(2), info(3)],
+///     ..Default::default()
+/// }}.serialize();
+///
+/// let char_infos = QueryFontReply::lazy_char_infos(&raw_reply)?;
+/// assert_eq!(char_infos.len(), 3);
+/// assert_eq!(char_infos.get(34), Some(info(3)));
+/// assert_eq!(char_infos.get(31), None);
+/// # Ok::<(), x11rb_protocol::errors::ParseError>(())
+/// ```
+pub fn lazy_char_infos(reply: &[u8]) -> Result<crate::wrapper::QueryFontCharInfos<'_>, ParseError> {{
+    crate::wrapper::QueryFontCharInfos::try_parse(reply)
+}}"
+            );
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+    }
+    if request_def.name == "EnableContext" && ns.header == "record" {
+        outln!(out, "impl EnableContextReply {{");
+        out.indented(|out| {
+            outln!(
+                out,
+                r"/// Iterate over the recorded protocol elements in this reply.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::record::{{EnableContextReply, HType}};
+///
+/// let mut data = vec![0; 32];
+/// data[0] = 12; // a MapNotify event
+/// data.splice(0..0, 42u32.to_ne_bytes());
+/// let reply = EnableContextReply {{
+///     category: 0, // FromServer
+///     element_header: HType::FROM_SERVER_TIME.into(),
+///     data,
+///     ..Default::default()
+/// }};
+/// let elements = reply.elements().collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(elements.len(), 1);
+/// assert_eq!(elements[0].time, Some(42));
+/// assert_eq!(elements[0].data[0], 12);
+/// # Ok::<(), x11rb_protocol::errors::ParseError>(())
+/// ```
+pub fn elements(&self) -> crate::wrapper::RecordedElements<'_> {{
+    crate::wrapper::RecordedElements::new(self)
+}}"
+            );
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+    }
+    if request_def.name == "QueryScreens" && ns.header == "xinerama" {
+        outln!(out, "impl QueryScreensReply {{");
+        out.indented(|out| {
+            outln!(
+                out,
+                r"/// Get the geometry of all screens together with their index.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xinerama::{{QueryScreensReply, ScreenInfo}};
+/// use x11rb_protocol::protocol::xproto::Rectangle;
+///
+/// let reply = QueryScreensReply {{
+///     sequence: 0,
+///     length: 0,
+///     screen_info: vec![ScreenInfo {{ x_org: 10, y_org: 20, width: 30, height: 40 }}],
+/// }};
+/// let rect = Rectangle {{ x: 10, y: 20, width: 30, height: 40 }};
+/// assert_eq!(reply.screen_rectangles(), vec![(0, rect)]);
+/// ```
+pub fn screen_rectangles(&self) -> Vec<(u32, xproto::Rectangle)> {{
+    self.screen_info
+        .iter()
+        .zip(0..)
+        .map(|(info, index)| {{
+            let rect = xproto::Rectangle {{
+                x: info.x_org,
+                y: info.y_org,
+                width: info.width,
+                height: info.height,
+            }};
+            (index, rect)
+        }})
+        .collect()
+}}
+
+/// Find the index of the first screen containing the given point.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xinerama::{{QueryScreensReply, ScreenInfo}};
+///
+/// let reply = QueryScreensReply {{
+///     sequence: 0,
+///     length: 0,
+///     screen_info: vec![
+///         ScreenInfo {{ x_org: 0, y_org: 0, width: 100, height: 100 }},
+///         ScreenInfo {{ x_org: 100, y_org: 0, width: 100, height: 100 }},
+///     ],
+/// }};
+/// assert_eq!(reply.screen_at(50, 50), Some(0));
+/// assert_eq!(reply.screen_at(100, 99), Some(1));
+/// assert_eq!(reply.screen_at(100, 100), None);
+/// ```
+pub fn screen_at(&self, x: i16, y: i16) -> Option<u32> {{
+    self.screen_containing(x, y)
+        .and_then(|(index, _)| u32::try_from(index).ok())
+}}
+
+/// Find the first screen containing the given point, together with its index.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xinerama::{{QueryScreensReply, ScreenInfo}};
+///
+/// let right = ScreenInfo {{ x_org: 100, y_org: 0, width: 100, height: 100 }};
+/// let reply = QueryScreensReply {{
+///     sequence: 0,
+///     length: 0,
+///     screen_info: vec![ScreenInfo {{ x_org: 0, y_org: 0, width: 100, height: 100 }}, right],
+/// }};
+/// assert_eq!(reply.screen_containing(150, 50), Some((1, &right)));
+/// assert_eq!(reply.screen_containing(-1, 50), None);
+/// ```
+pub fn screen_containing(&self, x: i16, y: i16) -> Option<(usize, &ScreenInfo)> {{
+    let (x, y) = (i32::from(x), i32::from(y));
+    self.screen_info
+        .iter()
+        .enumerate()
+        .find(|(_, info)| {{
+            let (x_org, y_org) = (i32::from(info.x_org), i32::from(info.y_org));
+            x_org <= x
+                && x < x_org + i32::from(info.width)
+                && y_org <= y
+                && y < y_org + i32::from(info.height)
+        }})
+}}
+
+/// Get the smallest rectangle that contains all screens as `(x, y, width, height)`.
+///
+/// If there are no screens, `(0, 0, 0, 0)` is returned.
+///
+/// # Example
+///
+/// ```
+/// use x11rb_protocol::protocol::xinerama::{{QueryScreensReply, ScreenInfo}};
+///
+/// let reply = QueryScreensReply {{
+///     sequence: 0,
+///     length: 0,
+///     screen_info: vec![
+///         ScreenInfo {{ x_org: 0, y_org: 100, width: 1280, height: 1024 }},
+///         ScreenInfo {{ x_org: 1280, y_org: 0, width: 1920, height: 1080 }},
+///     ],
+/// }};
+/// assert_eq!(reply.bounding_box(), (0, 0, 3200, 1124));
+/// ```
+pub fn bounding_box(&self) -> (i16, i16, u16, u16) {{
+    let mut screens = self.screen_info.iter().map(|info| {{
+        let (x, y) = (i32::from(info.x_org), i32::from(info.y_org));
+        (x, y, x + i32::from(info.width), y + i32::from(info.height))
+    }});
+    let first = match screens.next() {{
+        Some(first) => first,
+        None => return (0, 0, 0, 0),
+    }};
+    let (x1, y1, x2, y2) = screens.fold(first, |(x1, y1, x2, y2), (a1, b1, a2, b2)| {{
+        (x1.min(a1), y1.min(b1), x2.max(a2), y2.max(b2))
+    }});
+    // x1 and y1 come from i16 values, so the conversions cannot fail
+    let (x, y) = (i16::try_from(x1).unwrap(), i16::try_from(y1).unwrap());
+    let width = u16::try_from(x2 - x1).unwrap_or(u16::MAX);
+    let height = u16::try_from(y2 - y1).unwrap_or(u16::MAX);
+    (x, y, width, height)
+}}"
+            );
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+    }
 }
 
 pub(super) fn handle_request_switch(
@@ -145,6 +390,71 @@ pub fn from_configure_request(event: &ConfigureRequestEvent) -> Self {{
     }
 }
 
+pub(super) fn handle_struct(struct_def: &xcbdefs::StructDef, out: &mut Output) {
+    let ns = struct_def.namespace.upgrade().unwrap();
+    if struct_def.name == "SCREEN" && ns.header == "xproto" {
+        outln!(out, "impl Screen {{");
+        out.indented(|out| {
+            outln!(out, "/// Get the visual and the colormap of the root window.");
+            outln!(out, "///");
+            outln!(out, "/// These are needed together for creating a window with the default visual:");
+            outln!(out, "/// ```");
+            outln!(out, "/// use x11rb_protocol::protocol::xproto::Screen;");
+            outln!(out, "///");
+            outln!(out, "/// let screen = Screen {{");
+            outln!(out, "///     root_visual: 0x21,");
+            outln!(out, "///     default_colormap: 0x20,");
+            outln!(out, "///     ..Default::default()");
+            outln!(out, "/// }};");
+            outln!(out, "/// assert_eq!(screen.default_visual_and_colormap(), (0x21, 0x20));");
+            outln!(out, "/// ```");
+            outln!(out, "pub fn default_visual_and_colormap(&self) -> (Visualid, Colormap) {{");
+            out.indented(|out| {
+                outln!(out, "(self.root_visual, self.default_colormap)");
+            });
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(out, "/// Get the horizontal and vertical resolution of the screen in dots per inch.");
+            outln!(out, "///");
+            outln!(out, "/// The resolution is computed from the size of the screen in pixels and in millimeters.");
+            outln!(out, "/// Some X11 servers report a physical size of zero, e.g. because the monitor's EDID");
+            outln!(out, "/// data is broken. In this case, the common default of 96 DPI is returned instead.");
+            outln!(out, "/// ```");
+            outln!(out, "/// use x11rb_protocol::protocol::xproto::Screen;");
+            outln!(out, "///");
+            outln!(out, "/// let screen = Screen {{");
+            outln!(out, "///     width_in_pixels: 1920,");
+            outln!(out, "///     height_in_pixels: 1080,");
+            outln!(out, "///     width_in_millimeters: 254,");
+            outln!(out, "///     height_in_millimeters: 0,");
+            outln!(out, "///     ..Default::default()");
+            outln!(out, "/// }};");
+            outln!(out, "/// assert_eq!(screen.dpi(), (192.0, 96.0));");
+            outln!(out, "/// ```");
+            outln!(out, "pub fn dpi(&self) -> (f64, f64) {{");
+            out.indented(|out| {
+                outln!(out, "fn dpi(pixels: u16, millimeters: u16) -> f64 {{");
+                out.indented(|out| {
+                    outln!(out, "if millimeters == 0 {{");
+                    outln!(out.indent(), "96.0");
+                    outln!(out, "}} else {{");
+                    outln!(out.indent(), "f64::from(pixels) * 25.4 / f64::from(millimeters)");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+                outln!(out, "(");
+                out.indented(|out| {
+                    outln!(out, "dpi(self.width_in_pixels, self.width_in_millimeters),");
+                    outln!(out, "dpi(self.height_in_pixels, self.height_in_millimeters),");
+                });
+                outln!(out, ")");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+}
+
 pub(super) fn handle_event(
     event_name: &str,
     event_full_def: &xcbdefs::EventFullDef,
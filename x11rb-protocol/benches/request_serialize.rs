@@ -0,0 +1,54 @@
+//! Benchmark serializing a request with a large payload.
+//!
+//! The inherent `serialize()` methods of the generated request structs return the request as a
+//! list of pieces that borrow the payload, so that the pieces can be written with vectored I/O.
+//! The `Request::serialize()` trait method instead copies everything into a single buffer. This
+//! compares both approaches.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::borrow::Cow;
+use std::io::{IoSlice, Write};
+use x11rb_protocol::protocol::xproto::{ImageFormat, PutImageRequest};
+use x11rb_protocol::x11_utils::Request;
+
+fn make_request(data: &[u8]) -> PutImageRequest<'_> {
+    PutImageRequest {
+        format: ImageFormat::Z_PIXMAP,
+        drawable: 1,
+        gc: 2,
+        width: 256,
+        height: 256,
+        dst_x: 0,
+        dst_y: 0,
+        left_pad: 0,
+        depth: 24,
+        data: Cow::Borrowed(data),
+    }
+}
+
+fn serialize_test(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize_put_image");
+    for size in [1024, 64 * 1024, 1024 * 1024] {
+        let data = vec![0x42u8; size];
+        let mut sink = std::io::sink();
+
+        group.bench_function(format!("vectored_{}", size), |b| {
+            b.iter(|| {
+                let (bufs, _fds) = make_request(black_box(&data)).serialize();
+                let slices = bufs.iter().map(|b| IoSlice::new(b)).collect::<Vec<_>>();
+                black_box(sink.write_vectored(&slices).unwrap());
+            })
+        });
+
+        group.bench_function(format!("flattened_{}", size), |b| {
+            b.iter(|| {
+                let (buf, _fds) = Request::serialize(make_request(black_box(&data)), 0);
+                black_box(sink.write(&buf).unwrap());
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, serialize_test);
+criterion_main!(benches);
@@ -34,10 +34,24 @@ pub enum PollReply {
     Reply(Vec<u8>),
 }
 
+/// What happens when an event arrives while the queue of pending events is full.
+///
+/// See [`Connection::set_max_pending_events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueOverflowPolicy {
+    /// Discard the oldest pending event to make room for the new one.
+    DropOldest,
+    /// Discard the new event and remember that an overflow happened.
+    ///
+    /// The overflow can be queried with [`Connection::take_event_queue_overflow`].
+    Error,
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct SentRequest {
     seqno: SequenceNumber,
     discard_mode: Option<DiscardMode>,
+    has_reply: bool,
     has_fds: bool,
 }
 
@@ -59,8 +73,16 @@ pub struct Connection {
     last_sequence_read: SequenceNumber,
     // Events that were read, but not yet returned to the API user
     pending_events: VecDeque<(SequenceNumber, Vec<u8>)>,
+    // Events that were put back via requeue_events(); these come before pending_events
+    requeued_events: VecDeque<(SequenceNumber, Vec<u8>)>,
+    // The maximum number of pending events and what to do when there are more
+    max_pending_events: Option<(usize, QueueOverflowPolicy)>,
+    // Did an event get discarded under QueueOverflowPolicy::Error?
+    event_queue_overflowed: bool,
     // Replies that were read, but not yet returned to the API user
     pending_replies: VecDeque<(SequenceNumber, BufWithFds)>,
+    // The sequence number of a reply that did not belong to any request with a reply
+    unexpected_reply: Option<SequenceNumber>,
 
     // FDs that were read, but not yet assigned to any reply
     pending_fds: VecDeque<RawFdContainer>,
@@ -79,7 +101,11 @@ impl Connection {
             last_sequence_read: 0,
             sent_requests: VecDeque::new(),
             pending_events: VecDeque::new(),
+            requeued_events: VecDeque::new(),
+            max_pending_events: None,
+            event_queue_overflowed: false,
             pending_replies: VecDeque::new(),
+            unexpected_reply: None,
             pending_fds: VecDeque::new(),
         }
     }
@@ -113,6 +139,7 @@ impl Connection {
         let sent_request = SentRequest {
             seqno,
             discard_mode: None,
+            has_reply: has_response,
             has_fds: kind == ReplyFdKind::ReplyWithFDs,
         };
         self.sent_requests.push_back(sent_request);
@@ -138,7 +165,7 @@ impl Connection {
                         if let Some((_, packet)) = self.pending_replies.remove(index) {
                             if packet.0[0] == 0 {
                                 // This is an error
-                                self.pending_events.push_back((seqno, packet.0));
+                                self.push_event(seqno, packet.0);
                             }
                         }
                     }
@@ -209,18 +236,22 @@ impl Connection {
                 match request.discard_mode {
                     Some(DiscardMode::DiscardReplyAndError) => { /* This error should be ignored */
                     }
-                    Some(DiscardMode::DiscardReply) => {
-                        self.pending_events.push_back((seqno, packet))
-                    }
+                    Some(DiscardMode::DiscardReply) => self.push_event(seqno, packet),
                     None => self
                         .pending_replies
                         .push_back((seqno, (packet, Vec::new()))),
                 }
             } else {
                 // Unexpected error, send to main loop
-                self.pending_events.push_back((seqno, packet));
+                self.push_event(seqno, packet);
             }
         } else if kind == 1 {
+            if !request.map_or(false, |r| r.has_reply) {
+                // No request with a reply has this sequence number, so our bookkeeping does not
+                // match what the X11 server did. The reply is dropped and the problem reported.
+                self.unexpected_reply = self.unexpected_reply.or(Some(seqno));
+                return;
+            }
             let fds = if request.filter(|r| r.has_fds).is_some() {
                 // This reply has FDs, the number of FDs is always in the second byte
                 let num_fds = usize::from(packet[1]);
@@ -245,7 +276,7 @@ impl Connection {
             }
         } else {
             // It is an event
-            self.pending_events.push_back((seqno, packet));
+            self.push_event(seqno, packet);
         }
     }
 
@@ -303,7 +334,7 @@ impl Connection {
     pub fn poll_for_reply(&mut self, sequence: SequenceNumber) -> PollReply {
         if let Some(reply) = self.poll_for_reply_or_error(sequence) {
             if reply.0[0] == 0 {
-                self.pending_events.push_back((sequence, reply.0));
+                self.push_event(sequence, reply.0);
                 PollReply::NoReply
             } else {
                 PollReply::Reply(reply.0)
@@ -315,15 +346,81 @@ impl Connection {
 
     /// Get a pending event.
     pub fn poll_for_event_with_sequence(&mut self) -> Option<RawEventAndSeqNumber> {
-        self.pending_events
+        self.requeued_events
             .pop_front()
+            .or_else(|| self.pending_events.pop_front())
             .map(|(seqno, event)| (event, seqno))
     }
+
+    /// Limit the number of events that are kept in the queue of pending events.
+    ///
+    /// Without a limit, the queue grows until the events are fetched via
+    /// `poll_for_event_with_sequence()`. With a limit, `policy` decides which events are
+    /// discarded when the queue is full. X11 errors that are handled as events count as events
+    /// here. Events that are put back with `requeue_events()` are never discarded.
+    pub fn set_max_pending_events(&mut self, max: Option<usize>, policy: QueueOverflowPolicy) {
+        self.max_pending_events = max.map(|max| (max, policy));
+        if let Some(max) = max {
+            if policy == QueueOverflowPolicy::DropOldest && self.pending_events.len() > max {
+                let excess = self.pending_events.len() - max;
+                log::warn!("Event queue limit lowered, discarding {} events", excess);
+                let _ = self.pending_events.drain(..excess);
+            }
+        }
+    }
+
+    /// Check if an event was discarded because the queue of pending events was full.
+    ///
+    /// This only happens with [`QueueOverflowPolicy::Error`]. Calling this function resets the
+    /// overflow state.
+    pub fn take_event_queue_overflow(&mut self) -> bool {
+        core::mem::replace(&mut self.event_queue_overflowed, false)
+    }
+
+    /// Check if a reply was received that does not belong to any request with a reply.
+    ///
+    /// Such a reply means that the state of this connection does not match the X11 server, e.g.
+    /// because of a bug or because some other code wrote requests to the connection. The reply is
+    /// discarded. This function returns the sequence number of the first such reply and resets
+    /// the state.
+    pub fn take_unexpected_reply(&mut self) -> Option<SequenceNumber> {
+        self.unexpected_reply.take()
+    }
+
+    // Add an event to the queue of pending events, respecting the limit on its size
+    fn push_event(&mut self, seqno: SequenceNumber, event: Vec<u8>) {
+        match self.max_pending_events {
+            Some((max, QueueOverflowPolicy::Error)) if self.pending_events.len() >= max => {
+                log::warn!("Event queue is full, discarding event {}", seqno);
+                self.event_queue_overflowed = true;
+            }
+            Some((max, QueueOverflowPolicy::DropOldest)) => {
+                self.pending_events.push_back((seqno, event));
+                while self.pending_events.len() > max {
+                    if let Some((seqno, _)) = self.pending_events.pop_front() {
+                        log::warn!("Event queue is full, discarding oldest event {}", seqno);
+                    }
+                }
+            }
+            _ => self.pending_events.push_back((seqno, event)),
+        }
+    }
+
+    /// Put events back at the front of the queue of pending events.
+    ///
+    /// The given events are returned by the following calls to `poll_for_event_with_sequence()`
+    /// in the given order, before any events that were already pending.
+    pub fn requeue_events(&mut self, events: Vec<RawEventAndSeqNumber>) {
+        for (event, seqno) in events.into_iter().rev() {
+            self.requeued_events.push_front((seqno, event));
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Connection, ReplyFdKind};
+    use super::{Connection, QueueOverflowPolicy, ReplyFdKind, SequenceNumber};
+    use alloc::vec::Vec;
 
     #[test]
     fn insert_sync_no_reply() {
@@ -376,4 +473,195 @@ mod test {
         let seqno = connection.send_request(ReplyFdKind::ReplyWithoutFDs);
         assert_eq!(Some(0x10000), seqno);
     }
+
+    #[test]
+    fn requeue_events() {
+        fn event(seqno: u16, detail: u8) -> Vec<u8> {
+            let mut packet = alloc::vec![0; 32];
+            packet[0] = 2; // KeyPress
+            packet[1] = detail;
+            packet[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            packet
+        }
+
+        let mut connection = Connection::new();
+        assert_eq!(Some(1), connection.send_request(ReplyFdKind::NoReply));
+        connection.enqueue_packet(event(1, 1));
+        connection.enqueue_packet(event(1, 2));
+
+        let first = connection.poll_for_event_with_sequence().unwrap();
+        let second = connection.poll_for_event_with_sequence().unwrap();
+        assert_eq!(first, (event(1, 1), 1));
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+
+        connection.enqueue_packet(event(1, 3));
+        connection.requeue_events(alloc::vec![first, second]);
+        for detail in 1..=3 {
+            assert_eq!(
+                connection.poll_for_event_with_sequence(),
+                Some((event(1, detail), 1))
+            );
+        }
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+    }
+
+    fn event(seqno: u16) -> Vec<u8> {
+        let mut packet = alloc::vec![0; 32];
+        packet[0] = 2;
+        packet[2..4].copy_from_slice(&seqno.to_ne_bytes());
+        packet
+    }
+
+    #[test]
+    fn sequence_number_wraparound() {
+        // Only the low 16 bits of the sequence number are sent on the wire. Simulate enough
+        // requests to wrap these bits around many times and check that the full sequence numbers
+        // are still reconstructed correctly.
+        fn packet(kind: u8, seqno: SequenceNumber) -> Vec<u8> {
+            let mut packet = alloc::vec![0; 32];
+            packet[0] = kind;
+            packet[2..4].copy_from_slice(&(seqno as u16).to_ne_bytes());
+            packet
+        }
+
+        let mut connection = Connection::new();
+        let mut expected_seqno = 0;
+        while expected_seqno < 3_000_000 {
+            expected_seqno += 1;
+            let with_reply = expected_seqno % 70_001 == 0;
+            let kind = if with_reply {
+                ReplyFdKind::ReplyWithoutFDs
+            } else {
+                ReplyFdKind::NoReply
+            };
+            let seqno = match connection.send_request(kind) {
+                Some(seqno) => seqno,
+                None => {
+                    // Insert a sync, like a real connection does
+                    let sync = connection.send_request(ReplyFdKind::ReplyWithoutFDs);
+                    assert_eq!(sync, Some(expected_seqno));
+                    connection.enqueue_packet(packet(1, expected_seqno));
+                    let reply = connection.poll_for_reply_or_error(expected_seqno);
+                    assert_eq!(reply, Some((packet(1, expected_seqno), Vec::new())));
+                    expected_seqno += 1;
+                    connection.send_request(kind).unwrap()
+                }
+            };
+            assert_eq!(seqno, expected_seqno);
+
+            if with_reply {
+                connection.enqueue_packet(packet(1, seqno));
+                let reply = connection.poll_for_reply_or_error(seqno);
+                assert_eq!(reply, Some((packet(1, seqno), Vec::new())));
+            } else if seqno % 50_021 == 0 {
+                // An error for a request without a reply can be checked for
+                connection.enqueue_packet(packet(0, seqno));
+                let error = connection.poll_for_reply_or_error(seqno);
+                assert_eq!(error, Some((packet(0, seqno), Vec::new())));
+            } else if seqno % 30_011 == 0 {
+                connection.enqueue_packet(packet(2, seqno));
+                let event = connection.poll_for_event_with_sequence();
+                assert_eq!(event, Some((packet(2, seqno), seqno)));
+            }
+        }
+        assert!(connection.pending_replies.is_empty());
+        assert!(connection.pending_events.is_empty());
+    }
+
+    #[test]
+    fn max_pending_events_drop_oldest() {
+        let mut connection = Connection::new();
+        connection.set_max_pending_events(Some(2), QueueOverflowPolicy::DropOldest);
+        for seqno in 1..=3 {
+            assert_eq!(Some(seqno), connection.send_request(ReplyFdKind::NoReply));
+            connection.enqueue_packet(event(seqno as u16));
+        }
+        for seqno in 2..=3 {
+            assert_eq!(
+                connection.poll_for_event_with_sequence(),
+                Some((event(seqno as u16), seqno))
+            );
+        }
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+        assert!(!connection.take_event_queue_overflow());
+    }
+
+    #[test]
+    fn max_pending_events_keeps_requeued_events() {
+        let mut connection = Connection::new();
+        for seqno in 1..=2 {
+            assert_eq!(Some(seqno), connection.send_request(ReplyFdKind::NoReply));
+            connection.enqueue_packet(event(seqno as u16));
+        }
+        let requeued = (0..2)
+            .map(|_| connection.poll_for_event_with_sequence().unwrap())
+            .collect();
+        connection.requeue_events(requeued);
+
+        // Neither a lower limit nor new events discard the events that were put back
+        connection.set_max_pending_events(Some(1), QueueOverflowPolicy::DropOldest);
+        for seqno in 3..=4 {
+            assert_eq!(Some(seqno), connection.send_request(ReplyFdKind::NoReply));
+            connection.enqueue_packet(event(seqno as u16));
+        }
+        for seqno in [1, 2, 4] {
+            assert_eq!(
+                connection.poll_for_event_with_sequence(),
+                Some((event(seqno as u16), seqno))
+            );
+        }
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+    }
+
+    #[test]
+    fn max_pending_events_error() {
+        let mut connection = Connection::new();
+        for seqno in 1..=3 {
+            assert_eq!(Some(seqno), connection.send_request(ReplyFdKind::NoReply));
+            connection.enqueue_packet(event(seqno as u16));
+        }
+        // Lowering the limit does not discard events with this policy
+        connection.set_max_pending_events(Some(2), QueueOverflowPolicy::Error);
+        assert_eq!(Some(4), connection.send_request(ReplyFdKind::NoReply));
+        connection.enqueue_packet(event(4));
+        assert!(connection.take_event_queue_overflow());
+        assert!(!connection.take_event_queue_overflow());
+        for seqno in 1..=3 {
+            assert_eq!(
+                connection.poll_for_event_with_sequence(),
+                Some((event(seqno as u16), seqno))
+            );
+        }
+        assert_eq!(connection.poll_for_event_with_sequence(), None);
+    }
+
+    #[test]
+    fn unexpected_reply() {
+        fn reply(seqno: u16) -> Vec<u8> {
+            let mut packet = alloc::vec![0; 32];
+            packet[0] = 1;
+            packet[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            packet
+        }
+
+        let mut connection = Connection::new();
+        assert_eq!(Some(1), connection.send_request(ReplyFdKind::NoReply));
+        assert_eq!(
+            Some(2),
+            connection.send_request(ReplyFdKind::ReplyWithoutFDs)
+        );
+        assert_eq!(Some(3), connection.send_request(ReplyFdKind::NoReply));
+
+        connection.enqueue_packet(reply(2));
+        assert_eq!(connection.take_unexpected_reply(), None);
+        assert!(connection.poll_for_reply_or_error(2).is_some());
+
+        // Neither a request without a reply nor a request that was never sent expect a reply
+        connection.enqueue_packet(reply(3));
+        connection.enqueue_packet(reply(4));
+        assert_eq!(connection.take_unexpected_reply(), Some(3));
+        assert_eq!(connection.take_unexpected_reply(), None);
+        assert!(connection.poll_for_reply_or_error(3).is_none());
+        assert!(connection.poll_for_reply_or_error(4).is_none());
+    }
 }
@@ -14,8 +14,22 @@ use std::error::Error;
 #[non_exhaustive]
 pub enum ParseError {
     /// Not enough data was provided.
+    ///
+    /// This is used where it is not known how much data would be needed. Most parsers report
+    /// [`ParseError::DataTooShort`] instead.
     InsufficientData,
 
+    /// Not enough data was provided to parse a value.
+    ///
+    /// This is what the parsers for the primitive types and for lists of bytes report, and thus
+    /// the usual error for truncated data.
+    DataTooShort {
+        /// The number of bytes that were needed.
+        needed: usize,
+        /// The number of bytes that were available.
+        got: usize,
+    },
+
     /// A value did not fit.
     ///
     /// This error can e.g. happen when a value that was received from the X11 server does not fit
@@ -50,8 +64,23 @@ pub enum ParseError {
     /// - Parsing an X11 request with the wrong value for its `minor_opcode`.
     InvalidValue,
 
+    /// A field had a value that is not allowed.
+    ///
+    /// This is reported instead of [`ParseError::InvalidValue`] when the offending value is known,
+    /// e.g. for a `response_type` that does not belong to the type that is being parsed.
+    UnexpectedValue {
+        /// The value that was found.
+        value: u64,
+    },
+
     /// Some file descriptors were expected, but not enough were received.
     MissingFileDescriptors,
+
+    /// A padding byte was not zero.
+    ///
+    /// The X11 protocol requires padding to be zero, but this is only checked with the
+    /// `strict_padding` feature.
+    NonZeroPadding,
 }
 
 #[cfg(feature = "std")]
@@ -71,7 +100,16 @@ impl fmt::Display for ParseError {
             ParseError::InvalidValue => {
                 write!(f, "A value could not be parsed into an enumeration")
             }
+            ParseError::DataTooShort { needed, got } => write!(
+                f,
+                "Insufficient data was provided: needed {} bytes, got {}",
+                needed, got
+            ),
+            ParseError::UnexpectedValue { value } => {
+                write!(f, "A field had the unexpected value {}", value)
+            }
             ParseError::MissingFileDescriptors => write!(f, "Missing file descriptors"),
+            ParseError::NonZeroPadding => write!(f, "A padding byte was not zero"),
         }
     }
 }
@@ -131,7 +169,15 @@ pub enum ConnectError {
 }
 
 #[cfg(feature = "std")]
-impl Error for ConnectError {}
+impl Error for ConnectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConnectError::ParseError(err) => Some(err),
+            ConnectError::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for ConnectError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -146,9 +192,13 @@ impl fmt::Display for ConnectError {
             ConnectError::InsufficientMemory => write!(f, "Insufficient memory"),
             ConnectError::DisplayParsingError => write!(f, "Display parsing error"),
             ConnectError::InvalidScreen => write!(f, "Invalid screen"),
+            // Without std, there is no Error::source() to reach the ParseError
+            #[cfg(feature = "std")]
+            ConnectError::ParseError(_) => write!(f, "Failed to parse data from the X11 server"),
+            #[cfg(not(feature = "std"))]
             ConnectError::ParseError(err) => err.fmt(f),
             #[cfg(feature = "std")]
-            ConnectError::IoError(err) => err.fmt(f),
+            ConnectError::IoError(_) => write!(f, "I/O error while connecting to the X11 server"),
             ConnectError::ZeroIdMask => write!(f, "XID mask was zero"),
             ConnectError::SetupFailed(err) => display(f, "X11 setup failed", &err.reason),
             ConnectError::SetupAuthenticate(err) => {
@@ -47,6 +47,8 @@ pub mod x11_utils;
 pub mod errors;
 pub mod id_allocator;
 pub mod packet_reader;
+#[cfg(feature = "parse_context")]
+pub mod parse_context;
 pub mod parse_display;
 #[rustfmt::skip]
 #[allow(missing_docs)]
@@ -75,6 +77,34 @@ pub type PiecewiseBuf<'a> = Vec<Cow<'a, [u8]>>;
 /// caused an error.
 pub type SequenceNumber = u64;
 
+/// Reconstruct a full sequence number from the low 16 bits that are sent on the wire.
+///
+/// The result is the sequence number with the given low bits that is closest to `reference`,
+/// which should be a recent sequence number, e.g. the one of the last request that was sent. As
+/// long as the two numbers are less than 32768 requests apart, the result is correct, even if
+/// `reference` is slightly older or newer than the actual sequence number.
+///
+/// ```
+/// use x11rb_protocol::widen_sequence_number;
+///
+/// assert_eq!(widen_sequence_number(5, 0x3_0002), 0x3_0005);
+/// // The reference can be ahead of the sequence number...
+/// assert_eq!(widen_sequence_number(0xfffe, 0x3_0002), 0x2_fffe);
+/// // ...or behind it
+/// assert_eq!(widen_sequence_number(1, 0x2_fffe), 0x3_0001);
+/// ```
+pub fn widen_sequence_number(wire: u16, reference: SequenceNumber) -> SequenceNumber {
+    const WRAP: SequenceNumber = 1 << 16;
+    let candidate = (reference & !(WRAP - 1)) | SequenceNumber::from(wire);
+    if candidate > reference && candidate - reference > WRAP / 2 && candidate >= WRAP {
+        candidate - WRAP
+    } else if candidate < reference && reference - candidate > WRAP / 2 {
+        candidate + WRAP
+    } else {
+        candidate
+    }
+}
+
 /// The raw bytes of an event and its sequence number.
 pub type RawEventAndSeqNumber<B> = (B, SequenceNumber);
 
@@ -6,6 +6,8 @@ use core::mem::replace;
 
 use alloc::{vec, vec::Vec};
 
+use crate::errors::ParseError;
+
 /// Minimal length of an X11 packet.
 const MINIMAL_PACKET_LENGTH: usize = 32;
 
@@ -108,6 +110,38 @@ impl PacketReader {
     }
 }
 
+/// Assemble a complete packet from its fixed-size header and the data that follows it.
+///
+/// Replies and `GE_GENERIC_EVENT`s consist of a 32 byte header and additional data whose length
+/// is described by the length field in the header. This function checks that `extra` has the
+/// expected length and combines both parts into a packet that can then be parsed like the
+/// packets returned by [`PacketReader::advance`].
+///
+/// # Example
+///
+/// ```rust
+/// # use x11rb_protocol::packet_reader::packet_from_parts;
+/// // A GE_GENERIC_EVENT with one word of additional data
+/// let mut header = [0; 32];
+/// header[0] = 35;
+/// header[4] = 1;
+/// let packet = packet_from_parts(header, vec![1, 2, 3, 4]).unwrap();
+/// assert_eq!(packet.len(), 36);
+/// ```
+pub fn packet_from_parts(header: [u8; 32], extra: Vec<u8>) -> Result<Vec<u8>, ParseError> {
+    let expected = extra_length(&header);
+    if extra.len() < expected {
+        return Err(ParseError::InsufficientData);
+    }
+    if extra.len() > expected {
+        return Err(ParseError::InvalidValue);
+    }
+    let mut packet = Vec::with_capacity(MINIMAL_PACKET_LENGTH + extra.len());
+    packet.extend_from_slice(&header);
+    packet.extend(extra);
+    Ok(packet)
+}
+
 /// Compute the length of the data we need to read, beyond the `MINIMAL_PACKET_LENGTH`.
 fn extra_length(buffer: &[u8]) -> usize {
     use crate::protocol::xproto::GE_GENERIC_EVENT;
@@ -127,7 +161,8 @@ fn extra_length(buffer: &[u8]) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use super::PacketReader;
+    use super::{packet_from_parts, PacketReader};
+    use crate::errors::ParseError;
     use alloc::{vec, vec::Vec};
 
     fn test_packets(packets: Vec<Vec<u8>>) {
@@ -175,6 +210,27 @@ mod tests {
         test_packets(vec![packet]);
     }
 
+    #[test]
+    fn test_packet_from_parts() {
+        let packet = make_reply_with_length(40);
+        let mut header = [0; 32];
+        header.copy_from_slice(&packet[..32]);
+        assert_eq!(
+            packet_from_parts(header, packet[32..].to_vec()),
+            Ok(packet.clone())
+        );
+        assert_eq!(
+            packet_from_parts(header, vec![0; 4]),
+            Err(ParseError::InsufficientData)
+        );
+        assert_eq!(
+            packet_from_parts(header, vec![0; 12]),
+            Err(ParseError::InvalidValue)
+        );
+        // fixed size packets do not have any additional data
+        assert_eq!(packet_from_parts([0; 32], Vec::new()), Ok(vec![0; 32]));
+    }
+
     #[test]
     fn test_many_fixed_size_packets() {
         let mut packets = vec![];
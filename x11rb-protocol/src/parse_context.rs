@@ -0,0 +1,263 @@
+//! Finding out where parsing failed.
+//!
+//! A [`ParseError`] only describes what went wrong, but not where. For replies that contain
+//! nested lists of structures, this can make it hard to figure out which part of a reply is
+//! malformed. With the `parse_context` feature, the parser remembers the fields and list entries
+//! that it was parsing when an error occurred. [`parse_with_context`] makes this path available.
+//! Parsing outside of [`parse_with_context`] does not record anything.
+//!
+//! ```
+//! use x11rb_protocol::parse_context::parse_with_context;
+//! use x11rb_protocol::protocol::xproto::GetFontPathReply;
+//! use x11rb_protocol::x11_utils::TryParse;
+//!
+//! // A reply with two font path entries where the second one is truncated
+//! let mut data = vec![0; 32];
+//! data[0] = 1; // response type
+//! data[8] = 2; // number of entries
+//! data.extend([1, b'a', 5, b'b']);
+//! let error = parse_with_context(|| GetFontPathReply::try_parse(&data)).unwrap_err();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "Insufficient data was provided: needed 5 bytes, got 1 (in path[1].name)"
+//! );
+//! ```
+//!
+//! For lists, the name of the field that contains the list and the index of the entry that failed
+//! to parse are recorded. Other fields are recorded by their name. Padding and fields that are
+//! only parsed as part of a `switch` are not recorded.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use std::error::Error;
+
+use crate::errors::ParseError;
+
+std::thread_local! {
+    // The path that is recorded by the innermost active parse_with_context(), if any
+    static CONTEXT: RefCell<Option<Vec<PathEntry>>> = RefCell::new(None);
+}
+
+/// A part of the path to the place where parsing failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathEntry {
+    /// A field of a structure, identified by its name.
+    Field(&'static str),
+    /// An entry of a list.
+    ListEntry(ListEntry),
+}
+
+impl fmt::Display for PathEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathEntry::Field(name) => f.write_str(name),
+            PathEntry::ListEntry(entry) => entry.fmt(f),
+        }
+    }
+}
+
+/// An entry of a list that was being parsed when an error occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListEntry {
+    /// The name of the field that contains the list, if the list is a field of a structure.
+    pub field_name: Option<&'static str>,
+    /// The name of the type of the list entries.
+    pub type_name: &'static str,
+    /// The index of the entry in its list.
+    pub index: usize,
+}
+
+impl fmt::Display for ListEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.field_name.unwrap_or(self.type_name);
+        write!(f, "{}[{}]", name, self.index)
+    }
+}
+
+/// A [`ParseError`] together with the place where it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseErrorWithContext {
+    /// The error that occurred.
+    pub error: ParseError,
+    /// The fields and list entries that were being parsed, starting with the outermost one.
+    pub path: Vec<PathEntry>,
+}
+
+impl fmt::Display for ParseErrorWithContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.path.is_empty() {
+            let path = self
+                .path
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<String>>();
+            write!(f, " (in {})", path.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseErrorWithContext {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParseErrorWithContext> for ParseError {
+    fn from(err: ParseErrorWithContext) -> Self {
+        err.error
+    }
+}
+
+/// Restores the path of an outer `parse_with_context()`, even if parsing panics.
+struct ContextGuard(Option<Vec<PathEntry>>);
+
+impl ContextGuard {
+    fn enter() -> Self {
+        Self(CONTEXT.with(|context| context.replace(Some(Vec::new()))))
+    }
+
+    fn leave(mut self) -> Vec<PathEntry> {
+        let outer = self.0.take();
+        let path = CONTEXT.with(|context| context.replace(outer));
+        core::mem::forget(self);
+        path.unwrap_or_default()
+    }
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        CONTEXT.with(|context| *context.borrow_mut() = outer);
+    }
+}
+
+/// Run the given parsing function and record where it failed.
+///
+/// All parsing that happens on the current thread inside of `f` is tracked.
+pub fn parse_with_context<T, F>(f: F) -> Result<T, ParseErrorWithContext>
+where
+    F: FnOnce() -> Result<T, ParseError>,
+{
+    let guard = ContextGuard::enter();
+    let result = f();
+    let mut path = guard.leave();
+    result.map_err(|error| {
+        // Entries are recorded while unwinding, so the innermost one comes first
+        path.reverse();
+        ParseErrorWithContext { error, path }
+    })
+}
+
+/// Record that parsing entry `index` of a list of `T` in field `field_name` failed.
+///
+/// Nothing is recorded outside of [`parse_with_context`].
+pub(crate) fn push_list_entry<T>(field_name: Option<&'static str>, index: usize) {
+    CONTEXT.with(|context| {
+        if let Some(path) = context.borrow_mut().as_mut() {
+            let type_name = core::any::type_name::<T>();
+            let type_name = type_name.rsplit("::").next().unwrap_or(type_name);
+            path.push(PathEntry::ListEntry(ListEntry {
+                field_name,
+                type_name,
+                index,
+            }));
+        }
+    });
+}
+
+/// Record that parsing the field `name` of a structure failed.
+///
+/// Nothing is recorded outside of [`parse_with_context`].
+pub(crate) fn push_field(name: &'static str) {
+    CONTEXT.with(|context| {
+        if let Some(path) = context.borrow_mut().as_mut() {
+            path.push(PathEntry::Field(name));
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::{parse_with_context, ListEntry, PathEntry, CONTEXT};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{Depth, Screen};
+    use crate::x11_utils::{Serialize, TryParse};
+
+    #[test]
+    fn test_nested_path() {
+        let screen = Screen {
+            allowed_depths: vec![
+                Depth {
+                    depth: 24,
+                    visuals: Default::default(),
+                },
+                Depth {
+                    depth: 32,
+                    visuals: vec![Default::default(), Default::default()],
+                },
+            ],
+            ..Default::default()
+        };
+        let data = screen.serialize();
+        // Cut into the blue_mask of the last visual, which is followed by four bytes of padding
+        let error = parse_with_context(|| Screen::try_parse(&data[..data.len() - 5])).unwrap_err();
+        assert_eq!(error.error, ParseError::DataTooShort { needed: 4, got: 3 });
+        assert_eq!(
+            error.path,
+            [
+                PathEntry::ListEntry(ListEntry {
+                    field_name: Some("allowed_depths"),
+                    type_name: "Depth",
+                    index: 1
+                }),
+                PathEntry::ListEntry(ListEntry {
+                    field_name: Some("visuals"),
+                    type_name: "Visualtype",
+                    index: 1
+                }),
+                PathEntry::Field("blue_mask"),
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            "Insufficient data was provided: needed 4 bytes, got 3 (in allowed_depths[1].visuals[1].blue_mask)"
+        );
+
+        // Padding is not recorded
+        let error = parse_with_context(|| Screen::try_parse(&data[..data.len() - 1])).unwrap_err();
+        assert_eq!(error.path.len(), 2);
+
+        // A successful parse does not see the old context
+        assert!(parse_with_context(|| Screen::try_parse(&data)).is_ok());
+        let error = parse_with_context(|| Screen::try_parse(&[])).unwrap_err();
+        assert_eq!(error.path, [PathEntry::Field("root")]);
+    }
+
+    #[test]
+    fn test_no_recording_outside_of_context() {
+        let screen = Screen {
+            allowed_depths: vec![Default::default()],
+            ..Default::default()
+        };
+        let data = screen.serialize();
+        let data = &data[..data.len() - 1];
+        assert!(Screen::try_parse(data).is_err());
+        CONTEXT.with(|context| assert_eq!(*context.borrow(), None));
+
+        // A nested parse_with_context() does not disturb the outer one
+        let error = parse_with_context(|| {
+            assert!(parse_with_context(|| Screen::try_parse(data)).is_err());
+            Screen::try_parse(data)
+        })
+        .unwrap_err();
+        assert_eq!(error.path.len(), 1);
+        CONTEXT.with(|context| assert_eq!(*context.borrow(), None));
+    }
+}
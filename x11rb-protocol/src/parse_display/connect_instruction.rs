@@ -31,12 +31,14 @@ pub(super) fn connect_addresses(p: &ParsedDisplay) -> impl Iterator<Item = Conne
     } = p;
 
     let mut targets = Vec::new();
+    // Large display numbers do not have a TCP port
+    let port = TCP_PORT_BASE.checked_add(*display);
 
     if (protocol.is_none() || protocol.as_deref() != Some("unix"))
         && !host.is_empty()
         && host != "unix"
     {
-        targets.push(ConnectAddress::Hostname(host, TCP_PORT_BASE + display));
+        targets.extend(port.map(|port| ConnectAddress::Hostname(host, port)));
     } else {
         if protocol.is_none() || protocol.as_deref() == Some("unix") {
             let file_name = format!("/tmp/.X11-unix/X{}", display);
@@ -44,10 +46,7 @@ pub(super) fn connect_addresses(p: &ParsedDisplay) -> impl Iterator<Item = Conne
         }
 
         if protocol.is_none() && host.is_empty() {
-            targets.push(ConnectAddress::Hostname(
-                "localhost",
-                TCP_PORT_BASE + display,
-            ));
+            targets.extend(port.map(|port| ConnectAddress::Hostname("localhost", port)));
         }
     }
 
@@ -86,6 +85,46 @@ mod tests {
         assert_eq!(ci, vec![ConnectAddress::Hostname("192.168.1.111", 6000),]);
     }
 
+    #[test]
+    fn display_number_selects_port_and_socket() {
+        let pd = parse_display(Some("hostname:10.0")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+        assert_eq!(ci, vec![ConnectAddress::Hostname("hostname", 6010)]);
+
+        let pd = parse_display(Some(":10")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+        assert_eq!(
+            ci,
+            vec![
+                ConnectAddress::Socket(PathBuf::from("/tmp/.X11-unix/X10")),
+                ConnectAddress::Hostname("localhost", 6010),
+            ]
+        );
+
+        // There is no TCP port for this display
+        let pd = parse_display(Some(":60000")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+        assert_eq!(
+            ci,
+            vec![ConnectAddress::Socket(PathBuf::from(
+                "/tmp/.X11-unix/X60000"
+            ))]
+        );
+        let pd = parse_display(Some("hostname:60000")).unwrap();
+        assert_eq!(pd.connect_instruction().count(), 0);
+    }
+
+    #[test]
+    fn only_unix_socket_with_unix_protocol() {
+        // The socket is also tried in the abstract namespace, see `ConnectAddress::Socket`
+        let pd = parse_display(Some("unix/:0")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+        assert_eq!(
+            ci,
+            vec![ConnectAddress::Socket(PathBuf::from("/tmp/.X11-unix/X0"))]
+        );
+    }
+
     #[test]
     fn try_over_unix_hostname() {
         let pd = parse_display(Some("unix/host:0")).unwrap();
@@ -112,6 +112,33 @@ mod test {
     fn own_good_cases() {
         // The XCB test suite does not test protocol parsing
         for (input, output) in &[
+            (
+                "unix/:0",
+                ParsedDisplay {
+                    host: "".to_string(),
+                    protocol: Some("unix".to_string()),
+                    display: 0,
+                    screen: 0,
+                },
+            ),
+            (
+                "hostname:10.0",
+                ParsedDisplay {
+                    host: "hostname".to_string(),
+                    protocol: None,
+                    display: 10,
+                    screen: 0,
+                },
+            ),
+            (
+                "tcp/hostname:10.3",
+                ParsedDisplay {
+                    host: "hostname".to_string(),
+                    protocol: Some("tcp".to_string()),
+                    display: 10,
+                    screen: 3,
+                },
+            ),
             (
                 "foo/bar:1",
                 ParsedDisplay {
@@ -437,6 +464,12 @@ mod test {
             "localhost",
             "localhost:",
             "localhost::",
+            // Not part of libxcb's test suite
+            "unix/",
+            "unix/:",
+            ":65536",
+            ":0.65536",
+            ":-1",
         ] {
             assert_eq!(
                 do_parse_display(input),
@@ -67,6 +67,7 @@ impl EnableRequest {
 }
 impl Request for EnableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -89,18 +90,17 @@ pub struct EnableReply {
 impl TryParse for EnableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (maximum_request_length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (maximum_request_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "maximum_request_length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = EnableReply { sequence, length, maximum_request_length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -136,8 +136,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u32::try_parse(value)?;
-        let (client_minor_version, remaining) = u32::try_parse(remaining)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major_version,
@@ -147,6 +147,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -170,20 +171,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -282,10 +282,10 @@ impl RedirectWindowRequest {
         if header.minor_opcode != REDIRECT_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (update, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (update, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "update")?;
         let update = update.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(RedirectWindowRequest {
             window,
@@ -295,6 +295,7 @@ impl RedirectWindowRequest {
 }
 impl Request for RedirectWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -345,10 +346,10 @@ impl RedirectSubwindowsRequest {
         if header.minor_opcode != REDIRECT_SUBWINDOWS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (update, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (update, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "update")?;
         let update = update.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(RedirectSubwindowsRequest {
             window,
@@ -358,6 +359,7 @@ impl RedirectSubwindowsRequest {
 }
 impl Request for RedirectSubwindowsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -408,10 +410,10 @@ impl UnredirectWindowRequest {
         if header.minor_opcode != UNREDIRECT_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (update, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (update, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "update")?;
         let update = update.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(UnredirectWindowRequest {
             window,
@@ -421,6 +423,7 @@ impl UnredirectWindowRequest {
 }
 impl Request for UnredirectWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -471,10 +474,10 @@ impl UnredirectSubwindowsRequest {
         if header.minor_opcode != UNREDIRECT_SUBWINDOWS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (update, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (update, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "update")?;
         let update = update.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(UnredirectSubwindowsRequest {
             window,
@@ -484,6 +487,7 @@ impl UnredirectSubwindowsRequest {
 }
 impl Request for UnredirectSubwindowsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -534,8 +538,8 @@ impl CreateRegionFromBorderClipRequest {
         if header.minor_opcode != CREATE_REGION_FROM_BORDER_CLIP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = xfixes::Region::try_parse(value)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(value), "region")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         let _ = remaining;
         Ok(CreateRegionFromBorderClipRequest {
             region,
@@ -545,6 +549,7 @@ impl CreateRegionFromBorderClipRequest {
 }
 impl Request for CreateRegionFromBorderClipRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -595,8 +600,8 @@ impl NameWindowPixmapRequest {
         if header.minor_opcode != NAME_WINDOW_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
         let _ = remaining;
         Ok(NameWindowPixmapRequest {
             window,
@@ -606,6 +611,7 @@ impl NameWindowPixmapRequest {
 }
 impl Request for NameWindowPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -650,7 +656,7 @@ impl GetOverlayWindowRequest {
         if header.minor_opcode != GET_OVERLAY_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetOverlayWindowRequest {
             window,
@@ -659,6 +665,7 @@ impl GetOverlayWindowRequest {
 }
 impl Request for GetOverlayWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -681,19 +688,18 @@ pub struct GetOverlayWindowReply {
 impl TryParse for GetOverlayWindowReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (overlay_win, remaining) = xproto::Window::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (overlay_win, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "overlay_win")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetOverlayWindowReply { sequence, length, overlay_win };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -784,7 +790,7 @@ impl ReleaseOverlayWindowRequest {
         if header.minor_opcode != RELEASE_OVERLAY_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ReleaseOverlayWindowRequest {
             window,
@@ -793,6 +799,7 @@ impl ReleaseOverlayWindowRequest {
 }
 impl Request for ReleaseOverlayWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
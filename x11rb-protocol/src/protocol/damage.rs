@@ -145,8 +145,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u32::try_parse(value)?;
-        let (client_minor_version, remaining) = u32::try_parse(remaining)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major_version,
@@ -156,6 +156,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -179,20 +180,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -297,11 +297,11 @@ impl CreateRequest {
         if header.minor_opcode != CREATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (damage, remaining) = Damage::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (level, remaining) = u8::try_parse(remaining)?;
+        let (damage, remaining) = crate::x11_utils::in_field(Damage::try_parse(value), "damage")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (level, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "level")?;
         let level = level.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CreateRequest {
             damage,
@@ -312,6 +312,7 @@ impl CreateRequest {
 }
 impl Request for CreateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -356,7 +357,7 @@ impl DestroyRequest {
         if header.minor_opcode != DESTROY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (damage, remaining) = Damage::try_parse(value)?;
+        let (damage, remaining) = crate::x11_utils::in_field(Damage::try_parse(value), "damage")?;
         let _ = remaining;
         Ok(DestroyRequest {
             damage,
@@ -365,6 +366,7 @@ impl DestroyRequest {
 }
 impl Request for DestroyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -421,9 +423,9 @@ impl SubtractRequest {
         if header.minor_opcode != SUBTRACT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (damage, remaining) = Damage::try_parse(value)?;
-        let (repair, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (parts, remaining) = xfixes::Region::try_parse(remaining)?;
+        let (damage, remaining) = crate::x11_utils::in_field(Damage::try_parse(value), "damage")?;
+        let (repair, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "repair")?;
+        let (parts, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "parts")?;
         let _ = remaining;
         Ok(SubtractRequest {
             damage,
@@ -434,6 +436,7 @@ impl SubtractRequest {
 }
 impl Request for SubtractRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -484,8 +487,8 @@ impl AddRequest {
         if header.minor_opcode != ADD_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (region, remaining) = xfixes::Region::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (region, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "region")?;
         let _ = remaining;
         Ok(AddRequest {
             drawable,
@@ -495,6 +498,7 @@ impl AddRequest {
 }
 impl Request for AddRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -523,14 +527,14 @@ pub struct NotifyEvent {
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (damage, remaining) = Damage::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (area, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (geometry, remaining) = xproto::Rectangle::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (level, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "level")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (damage, remaining) = crate::x11_utils::in_field(Damage::try_parse(remaining), "damage")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (area, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "area")?;
+        let (geometry, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "geometry")?;
         let level = level.into();
         let result = NotifyEvent { response_type, level, sequence, drawable, damage, timestamp, area, geometry };
         let _ = remaining;
@@ -117,9 +117,9 @@ pub struct SwapInfo {
 }
 impl TryParse for SwapInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (swap_action, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (swap_action, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "swap_action")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let swap_action = swap_action.into();
         let result = SwapInfo { window, swap_action };
         Ok((result, remaining))
@@ -156,7 +156,7 @@ pub struct BufferAttributes {
 }
 impl TryParse for BufferAttributes {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         let result = BufferAttributes { window };
         Ok((result, remaining))
     }
@@ -187,10 +187,10 @@ pub struct VisualInfo {
 }
 impl TryParse for VisualInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual_id, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (perf_level, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (visual_id, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual_id")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (perf_level, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "perf_level")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = VisualInfo { visual_id, depth, perf_level };
         Ok((result, remaining))
     }
@@ -228,8 +228,8 @@ pub struct VisualInfos {
 }
 impl TryParse for VisualInfos {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (n_infos, remaining) = u32::try_parse(remaining)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<VisualInfo>(remaining, n_infos.try_to_usize()?)?;
+        let (n_infos, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_infos")?;
+        let (infos, remaining) = crate::x11_utils::parse_list::<VisualInfo>(remaining, n_infos.try_to_usize()?, Some("infos"))?;
         let result = VisualInfos { infos };
         Ok((result, remaining))
     }
@@ -309,9 +309,9 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u8::try_parse(value)?;
-        let (minor_version, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -321,6 +321,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -344,20 +345,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u8::try_parse(remaining)?;
-        let (minor_version, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -471,10 +471,10 @@ impl AllocateBackBufferRequest {
         if header.minor_opcode != ALLOCATE_BACK_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (buffer, remaining) = BackBuffer::try_parse(remaining)?;
-        let (swap_action, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (buffer, remaining) = crate::x11_utils::in_field(BackBuffer::try_parse(remaining), "buffer")?;
+        let (swap_action, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "swap_action")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(AllocateBackBufferRequest {
             window,
@@ -485,6 +485,7 @@ impl AllocateBackBufferRequest {
 }
 impl Request for AllocateBackBufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -536,7 +537,7 @@ impl DeallocateBackBufferRequest {
         if header.minor_opcode != DEALLOCATE_BACK_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (buffer, remaining) = BackBuffer::try_parse(value)?;
+        let (buffer, remaining) = crate::x11_utils::in_field(BackBuffer::try_parse(value), "buffer")?;
         let _ = remaining;
         Ok(DeallocateBackBufferRequest {
             buffer,
@@ -545,6 +546,7 @@ impl DeallocateBackBufferRequest {
 }
 impl Request for DeallocateBackBufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -601,8 +603,8 @@ impl<'input> SwapBuffersRequest<'input> {
         if header.minor_opcode != SWAP_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (n_actions, remaining) = u32::try_parse(value)?;
-        let (actions, remaining) = crate::x11_utils::parse_list::<SwapInfo>(remaining, n_actions.try_to_usize()?)?;
+        let (n_actions, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "n_actions")?;
+        let (actions, remaining) = crate::x11_utils::parse_list::<SwapInfo>(remaining, n_actions.try_to_usize()?, Some("actions"))?;
         let _ = remaining;
         Ok(SwapBuffersRequest {
             actions: Cow::Owned(actions),
@@ -617,6 +619,7 @@ impl<'input> SwapBuffersRequest<'input> {
 }
 impl<'input> Request for SwapBuffersRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -664,6 +667,7 @@ impl BeginIdiomRequest {
 }
 impl Request for BeginIdiomRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -709,6 +713,7 @@ impl EndIdiomRequest {
 }
 impl Request for EndIdiomRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -759,8 +764,8 @@ impl<'input> GetVisualInfoRequest<'input> {
         if header.minor_opcode != GET_VISUAL_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (n_drawables, remaining) = u32::try_parse(value)?;
-        let (drawables, remaining) = crate::x11_utils::parse_list::<xproto::Drawable>(remaining, n_drawables.try_to_usize()?)?;
+        let (n_drawables, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "n_drawables")?;
+        let (drawables, remaining) = crate::x11_utils::parse_list::<xproto::Drawable>(remaining, n_drawables.try_to_usize()?, Some("drawables"))?;
         let _ = remaining;
         Ok(GetVisualInfoRequest {
             drawables: Cow::Owned(drawables),
@@ -775,6 +780,7 @@ impl<'input> GetVisualInfoRequest<'input> {
 }
 impl<'input> Request for GetVisualInfoRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -797,20 +803,19 @@ pub struct GetVisualInfoReply {
 impl TryParse for GetVisualInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (n_supported_visuals, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (supported_visuals, remaining) = crate::x11_utils::parse_list::<VisualInfos>(remaining, n_supported_visuals.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (n_supported_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_supported_visuals")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (supported_visuals, remaining) = crate::x11_utils::parse_list::<VisualInfos>(remaining, n_supported_visuals.try_to_usize()?, Some("supported_visuals"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetVisualInfoReply { sequence, length, supported_visuals };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -849,6 +854,27 @@ impl GetVisualInfoReply {
             .try_into().unwrap()
     }
 }
+impl GetVisualInfoReply {
+    /// Lazily parse the `supported_visuals` list from the raw bytes of a `GetVisualInfoReply`.
+    ///
+    /// In contrast to parsing the whole `GetVisualInfoReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn supported_visuals_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, VisualInfos>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (n_supported_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_supported_visuals")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n_supported_visuals.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetBackBufferAttributes request
 pub const GET_BACK_BUFFER_ATTRIBUTES_REQUEST: u8 = 7;
@@ -891,7 +917,7 @@ impl GetBackBufferAttributesRequest {
         if header.minor_opcode != GET_BACK_BUFFER_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (buffer, remaining) = BackBuffer::try_parse(value)?;
+        let (buffer, remaining) = crate::x11_utils::in_field(BackBuffer::try_parse(value), "buffer")?;
         let _ = remaining;
         Ok(GetBackBufferAttributesRequest {
             buffer,
@@ -900,6 +926,7 @@ impl GetBackBufferAttributesRequest {
 }
 impl Request for GetBackBufferAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -922,19 +949,18 @@ pub struct GetBackBufferAttributesReply {
 impl TryParse for GetBackBufferAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (attributes, remaining) = BufferAttributes::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (attributes, remaining) = crate::x11_utils::in_field(BufferAttributes::try_parse(remaining), "attributes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetBackBufferAttributesReply { sequence, length, attributes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -69,8 +69,8 @@ impl GetVersionRequest {
         if header.minor_opcode != GET_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u16::try_parse(value)?;
-        let (client_minor_version, remaining) = u16::try_parse(remaining)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "client_minor_version")?;
         let _ = remaining;
         Ok(GetVersionRequest {
             client_major_version,
@@ -80,6 +80,7 @@ impl GetVersionRequest {
 }
 impl Request for GetVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -103,19 +104,18 @@ pub struct GetVersionReply {
 impl TryParse for GetVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major_version, remaining) = u16::try_parse(remaining)?;
-        let (server_minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major_version")?;
+        let (server_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetVersionReply { sequence, length, server_major_version, server_minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -187,6 +187,7 @@ impl CapableRequest {
 }
 impl Request for CapableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -209,19 +210,18 @@ pub struct CapableReply {
 impl TryParse for CapableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (capable, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (capable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "capable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CapableReply { sequence, length, capable };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -312,6 +312,7 @@ impl GetTimeoutsRequest {
 }
 impl Request for GetTimeoutsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -336,21 +337,20 @@ pub struct GetTimeoutsReply {
 impl TryParse for GetTimeoutsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (standby_timeout, remaining) = u16::try_parse(remaining)?;
-        let (suspend_timeout, remaining) = u16::try_parse(remaining)?;
-        let (off_timeout, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (standby_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "standby_timeout")?;
+        let (suspend_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "suspend_timeout")?;
+        let (off_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "off_timeout")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTimeoutsReply { sequence, length, standby_timeout, suspend_timeout, off_timeout };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -453,9 +453,9 @@ impl SetTimeoutsRequest {
         if header.minor_opcode != SET_TIMEOUTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (standby_timeout, remaining) = u16::try_parse(value)?;
-        let (suspend_timeout, remaining) = u16::try_parse(remaining)?;
-        let (off_timeout, remaining) = u16::try_parse(remaining)?;
+        let (standby_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "standby_timeout")?;
+        let (suspend_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "suspend_timeout")?;
+        let (off_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "off_timeout")?;
         let _ = remaining;
         Ok(SetTimeoutsRequest {
             standby_timeout,
@@ -466,6 +466,7 @@ impl SetTimeoutsRequest {
 }
 impl Request for SetTimeoutsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -510,6 +511,7 @@ impl EnableRequest {
 }
 impl Request for EnableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -554,6 +556,7 @@ impl DisableRequest {
 }
 impl Request for DisableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -655,7 +658,7 @@ impl ForceLevelRequest {
         if header.minor_opcode != FORCE_LEVEL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (power_level, remaining) = u16::try_parse(value)?;
+        let (power_level, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "power_level")?;
         let power_level = power_level.into();
         let _ = remaining;
         Ok(ForceLevelRequest {
@@ -665,6 +668,7 @@ impl ForceLevelRequest {
 }
 impl Request for ForceLevelRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -709,6 +713,7 @@ impl InfoRequest {
 }
 impl Request for InfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -732,21 +737,20 @@ pub struct InfoReply {
 impl TryParse for InfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (power_level, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (power_level, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "power_level")?;
+        let (state, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 21)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let power_level = power_level.into();
         let result = InfoReply { sequence, length, power_level, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -214,11 +214,11 @@ pub struct DRI2Buffer {
 }
 impl TryParse for DRI2Buffer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (attachment, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = u32::try_parse(remaining)?;
-        let (pitch, remaining) = u32::try_parse(remaining)?;
-        let (cpp, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (attachment, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "attachment")?;
+        let (name, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "name")?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pitch")?;
+        let (cpp, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cpp")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let attachment = attachment.into();
         let result = DRI2Buffer { attachment, name, pitch, cpp, flags };
         Ok((result, remaining))
@@ -273,8 +273,8 @@ pub struct AttachFormat {
 }
 impl TryParse for AttachFormat {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (attachment, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
+        let (attachment, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "attachment")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
         let attachment = attachment.into();
         let result = AttachFormat { attachment, format };
         Ok((result, remaining))
@@ -342,8 +342,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -353,6 +353,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -376,19 +377,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -470,8 +470,8 @@ impl ConnectRequest {
         if header.minor_opcode != CONNECT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (driver_type, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (driver_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "driver_type")?;
         let driver_type = driver_type.into();
         let _ = remaining;
         Ok(ConnectRequest {
@@ -482,6 +482,7 @@ impl ConnectRequest {
 }
 impl Request for ConnectRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -506,26 +507,25 @@ pub struct ConnectReply {
 impl TryParse for ConnectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (driver_name_length, remaining) = u32::try_parse(remaining)?;
-        let (device_name_length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, driver_name_length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (driver_name_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "driver_name_length")?;
+        let (device_name_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "device_name_length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (driver_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, driver_name_length.try_to_usize()?), "driver_name")?;
         let driver_name = driver_name.to_vec();
-        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(driver_name_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(driver_name_length)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (alignment_pad, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, (u32::from(driver_name_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(driver_name_length)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "alignment_pad")?;
         let alignment_pad = alignment_pad.to_vec();
-        let (device_name, remaining) = crate::x11_utils::parse_u8_list(remaining, device_name_length.try_to_usize()?)?;
+        let (device_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, device_name_length.try_to_usize()?), "device_name")?;
         let device_name = device_name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ConnectReply { sequence, length, driver_name, alignment_pad, device_name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -622,8 +622,8 @@ impl AuthenticateRequest {
         if header.minor_opcode != AUTHENTICATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (magic, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (magic, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "magic")?;
         let _ = remaining;
         Ok(AuthenticateRequest {
             window,
@@ -633,6 +633,7 @@ impl AuthenticateRequest {
 }
 impl Request for AuthenticateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -655,18 +656,17 @@ pub struct AuthenticateReply {
 impl TryParse for AuthenticateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (authenticated, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (authenticated, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "authenticated")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AuthenticateReply { sequence, length, authenticated };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -736,7 +736,7 @@ impl CreateDrawableRequest {
         if header.minor_opcode != CREATE_DRAWABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(CreateDrawableRequest {
             drawable,
@@ -745,6 +745,7 @@ impl CreateDrawableRequest {
 }
 impl Request for CreateDrawableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -789,7 +790,7 @@ impl DestroyDrawableRequest {
         if header.minor_opcode != DESTROY_DRAWABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(DestroyDrawableRequest {
             drawable,
@@ -798,6 +799,7 @@ impl DestroyDrawableRequest {
 }
 impl Request for DestroyDrawableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -853,8 +855,8 @@ impl<'input> GetBuffersRequest<'input> {
         if header.minor_opcode != GET_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut attachments = Vec::new();
@@ -881,6 +883,7 @@ impl<'input> GetBuffersRequest<'input> {
 }
 impl<'input> Request for GetBuffersRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -905,22 +908,21 @@ pub struct GetBuffersReply {
 impl TryParse for GetBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_to_usize()?, Some("buffers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetBuffersReply { sequence, length, width, height, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -961,6 +963,29 @@ impl GetBuffersReply {
             .try_into().unwrap()
     }
 }
+impl GetBuffersReply {
+    /// Lazily parse the `buffers` list from the raw bytes of a `GetBuffersReply`.
+    ///
+    /// In contrast to parsing the whole `GetBuffersReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn buffers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, DRI2Buffer>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = count.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CopyRegion request
 pub const COPY_REGION_REQUEST: u8 = 6;
@@ -1013,10 +1038,10 @@ impl CopyRegionRequest {
         if header.minor_opcode != COPY_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (region, remaining) = u32::try_parse(remaining)?;
-        let (dest, remaining) = u32::try_parse(remaining)?;
-        let (src, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (region, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "region")?;
+        let (dest, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dest")?;
+        let (src, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "src")?;
         let _ = remaining;
         Ok(CopyRegionRequest {
             drawable,
@@ -1028,6 +1053,7 @@ impl CopyRegionRequest {
 }
 impl Request for CopyRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1049,17 +1075,16 @@ pub struct CopyRegionReply {
 impl TryParse for CopyRegionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CopyRegionReply { sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1134,8 +1159,8 @@ impl<'input> GetBuffersWithFormatRequest<'input> {
         if header.minor_opcode != GET_BUFFERS_WITH_FORMAT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut attachments = Vec::new();
@@ -1162,6 +1187,7 @@ impl<'input> GetBuffersWithFormatRequest<'input> {
 }
 impl<'input> Request for GetBuffersWithFormatRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1186,22 +1212,21 @@ pub struct GetBuffersWithFormatReply {
 impl TryParse for GetBuffersWithFormatReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list::<DRI2Buffer>(remaining, count.try_to_usize()?, Some("buffers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetBuffersWithFormatReply { sequence, length, width, height, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1242,6 +1267,29 @@ impl GetBuffersWithFormatReply {
             .try_into().unwrap()
     }
 }
+impl GetBuffersWithFormatReply {
+    /// Lazily parse the `buffers` list from the raw bytes of a `GetBuffersWithFormatReply`.
+    ///
+    /// In contrast to parsing the whole `GetBuffersWithFormatReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn buffers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, DRI2Buffer>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = count.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 8;
@@ -1312,13 +1360,13 @@ impl SwapBuffersRequest {
         if header.minor_opcode != SWAP_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (target_msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (target_msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (divisor_hi, remaining) = u32::try_parse(remaining)?;
-        let (divisor_lo, remaining) = u32::try_parse(remaining)?;
-        let (remainder_hi, remaining) = u32::try_parse(remaining)?;
-        let (remainder_lo, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (target_msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_msc_hi")?;
+        let (target_msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_msc_lo")?;
+        let (divisor_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "divisor_hi")?;
+        let (divisor_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "divisor_lo")?;
+        let (remainder_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "remainder_hi")?;
+        let (remainder_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "remainder_lo")?;
         let _ = remaining;
         Ok(SwapBuffersRequest {
             drawable,
@@ -1333,6 +1381,7 @@ impl SwapBuffersRequest {
 }
 impl Request for SwapBuffersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1356,19 +1405,18 @@ pub struct SwapBuffersReply {
 impl TryParse for SwapBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (swap_hi, remaining) = u32::try_parse(remaining)?;
-        let (swap_lo, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (swap_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "swap_hi")?;
+        let (swap_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "swap_lo")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = SwapBuffersReply { sequence, length, swap_hi, swap_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1444,7 +1492,7 @@ impl GetMSCRequest {
         if header.minor_opcode != GET_MSC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(GetMSCRequest {
             drawable,
@@ -1453,6 +1501,7 @@ impl GetMSCRequest {
 }
 impl Request for GetMSCRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1480,23 +1529,22 @@ pub struct GetMSCReply {
 impl TryParse for GetMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ust_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_hi")?;
+        let (ust_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_lo")?;
+        let (msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_hi")?;
+        let (msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_lo")?;
+        let (sbc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_hi")?;
+        let (sbc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_lo")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1632,13 +1680,13 @@ impl WaitMSCRequest {
         if header.minor_opcode != WAIT_MSC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (target_msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (target_msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (divisor_hi, remaining) = u32::try_parse(remaining)?;
-        let (divisor_lo, remaining) = u32::try_parse(remaining)?;
-        let (remainder_hi, remaining) = u32::try_parse(remaining)?;
-        let (remainder_lo, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (target_msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_msc_hi")?;
+        let (target_msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_msc_lo")?;
+        let (divisor_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "divisor_hi")?;
+        let (divisor_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "divisor_lo")?;
+        let (remainder_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "remainder_hi")?;
+        let (remainder_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "remainder_lo")?;
         let _ = remaining;
         Ok(WaitMSCRequest {
             drawable,
@@ -1653,6 +1701,7 @@ impl WaitMSCRequest {
 }
 impl Request for WaitMSCRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1680,23 +1729,22 @@ pub struct WaitMSCReply {
 impl TryParse for WaitMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ust_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_hi")?;
+        let (ust_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_lo")?;
+        let (msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_hi")?;
+        let (msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_lo")?;
+        let (sbc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_hi")?;
+        let (sbc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_lo")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = WaitMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1808,9 +1856,9 @@ impl WaitSBCRequest {
         if header.minor_opcode != WAIT_SBC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (target_sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (target_sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (target_sbc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_sbc_hi")?;
+        let (target_sbc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target_sbc_lo")?;
         let _ = remaining;
         Ok(WaitSBCRequest {
             drawable,
@@ -1821,6 +1869,7 @@ impl WaitSBCRequest {
 }
 impl Request for WaitSBCRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1848,23 +1897,22 @@ pub struct WaitSBCReply {
 impl TryParse for WaitSBCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc_hi, remaining) = u32::try_parse(remaining)?;
-        let (sbc_lo, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ust_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_hi")?;
+        let (ust_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_lo")?;
+        let (msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_hi")?;
+        let (msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_lo")?;
+        let (sbc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_hi")?;
+        let (sbc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc_lo")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = WaitSBCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1970,8 +2018,8 @@ impl SwapIntervalRequest {
         if header.minor_opcode != SWAP_INTERVAL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (interval, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (interval, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "interval")?;
         let _ = remaining;
         Ok(SwapIntervalRequest {
             drawable,
@@ -1981,6 +2029,7 @@ impl SwapIntervalRequest {
 }
 impl Request for SwapIntervalRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2031,8 +2080,8 @@ impl GetParamRequest {
         if header.minor_opcode != GET_PARAM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (param, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (param, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "param")?;
         let _ = remaining;
         Ok(GetParamRequest {
             drawable,
@@ -2042,6 +2091,7 @@ impl GetParamRequest {
 }
 impl Request for GetParamRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2066,19 +2116,18 @@ pub struct GetParamReply {
 impl TryParse for GetParamReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (is_param_recognized, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value_hi, remaining) = u32::try_parse(remaining)?;
-        let (value_lo, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (is_param_recognized, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_param_recognized")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (value_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_hi")?;
+        let (value_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_lo")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetParamReply { is_param_recognized, sequence, length, value_hi, value_lo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2140,17 +2189,17 @@ pub struct BufferSwapCompleteEvent {
 impl TryParse for BufferSwapCompleteEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (ust_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_hi")?;
+        let (ust_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_lo")?;
+        let (msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_hi")?;
+        let (msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_lo")?;
+        let (sbc, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc")?;
         let event_type = event_type.into();
         let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
         let _ = remaining;
@@ -2286,10 +2335,10 @@ pub struct InvalidateBuffersEvent {
 impl TryParse for InvalidateBuffersEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
         let result = InvalidateBuffersEvent { response_type, sequence, drawable };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -75,8 +75,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -86,6 +86,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -109,19 +110,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -203,8 +203,8 @@ impl OpenRequest {
         if header.minor_opcode != OPEN_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (provider, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (provider, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "provider")?;
         let _ = remaining;
         Ok(OpenRequest {
             drawable,
@@ -214,6 +214,7 @@ impl OpenRequest {
 }
 impl Request for OpenRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -236,20 +237,19 @@ pub struct OpenReply {
 impl TryParseFd for OpenReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let device_fd = fds.remove(0);
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = OpenReply { nfd, sequence, length, device_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -369,14 +369,14 @@ impl PixmapFromBufferRequest {
         if header.minor_opcode != PIXMAP_FROM_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (stride, remaining) = u16::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(value), "pixmap")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "size")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (stride, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "stride")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let pixmap_fd = fds.remove(0);
         let _ = remaining;
@@ -395,6 +395,7 @@ impl PixmapFromBufferRequest {
 }
 impl Request for PixmapFromBufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -439,7 +440,7 @@ impl BufferFromPixmapRequest {
         if header.minor_opcode != BUFFER_FROM_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(value)?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(value), "pixmap")?;
         let _ = remaining;
         Ok(BufferFromPixmapRequest {
             pixmap,
@@ -448,6 +449,7 @@ impl BufferFromPixmapRequest {
 }
 impl Request for BufferFromPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -476,26 +478,25 @@ pub struct BufferFromPixmapReply {
 impl TryParseFd for BufferFromPixmapReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (stride, remaining) = u16::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "size")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (stride, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "stride")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let pixmap_fd = fds.remove(0);
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = BufferFromPixmapReply { nfd, sequence, length, size, width, height, stride, depth, bpp, pixmap_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -609,10 +610,10 @@ impl FenceFromFDRequest {
         if header.minor_opcode != FENCE_FROM_FD_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (fence, remaining) = u32::try_parse(remaining)?;
-        let (initially_triggered, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (fence, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fence")?;
+        let (initially_triggered, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "initially_triggered")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let fence_fd = fds.remove(0);
         let _ = remaining;
@@ -626,6 +627,7 @@ impl FenceFromFDRequest {
 }
 impl Request for FenceFromFDRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -676,8 +678,8 @@ impl FDFromFenceRequest {
         if header.minor_opcode != FD_FROM_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (fence, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (fence, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fence")?;
         let _ = remaining;
         Ok(FDFromFenceRequest {
             drawable,
@@ -687,6 +689,7 @@ impl FDFromFenceRequest {
 }
 impl Request for FDFromFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -709,20 +712,19 @@ pub struct FDFromFenceReply {
 impl TryParseFd for FDFromFenceReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let fence_fd = fds.remove(0);
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = FDFromFenceReply { nfd, sequence, length, fence_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -820,10 +822,10 @@ impl GetSupportedModifiersRequest {
         if header.minor_opcode != GET_SUPPORTED_MODIFIERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = u32::try_parse(value)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "window")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetSupportedModifiersRequest {
             window,
@@ -834,6 +836,7 @@ impl GetSupportedModifiersRequest {
 }
 impl Request for GetSupportedModifiersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -857,22 +860,21 @@ pub struct GetSupportedModifiersReply {
 impl TryParse for GetSupportedModifiersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_window_modifiers, remaining) = u32::try_parse(remaining)?;
-        let (num_screen_modifiers, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (window_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_window_modifiers.try_to_usize()?)?;
-        let (screen_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_screen_modifiers.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_window_modifiers, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_window_modifiers")?;
+        let (num_screen_modifiers, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_screen_modifiers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (window_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_window_modifiers.try_to_usize()?, Some("window_modifiers"))?;
+        let (screen_modifiers, remaining) = crate::x11_utils::parse_list::<u64>(remaining, num_screen_modifiers.try_to_usize()?, Some("screen_modifiers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSupportedModifiersReply { sequence, length, window_modifiers, screen_modifiers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -927,6 +929,28 @@ impl GetSupportedModifiersReply {
             .try_into().unwrap()
     }
 }
+impl GetSupportedModifiersReply {
+    /// Lazily parse the `window_modifiers` list from the raw bytes of a `GetSupportedModifiersReply`.
+    ///
+    /// In contrast to parsing the whole `GetSupportedModifiersReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn window_modifiers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u64>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_window_modifiers, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_window_modifiers")?;
+        let (num_screen_modifiers, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_screen_modifiers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_window_modifiers.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the PixmapFromBuffers request
 pub const PIXMAP_FROM_BUFFERS_REQUEST: u8 = 7;
@@ -1047,24 +1071,24 @@ impl PixmapFromBuffersRequest {
         if header.minor_opcode != PIXMAP_FROM_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(value)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (num_buffers, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (stride0, remaining) = u32::try_parse(remaining)?;
-        let (offset0, remaining) = u32::try_parse(remaining)?;
-        let (stride1, remaining) = u32::try_parse(remaining)?;
-        let (offset1, remaining) = u32::try_parse(remaining)?;
-        let (stride2, remaining) = u32::try_parse(remaining)?;
-        let (offset2, remaining) = u32::try_parse(remaining)?;
-        let (stride3, remaining) = u32::try_parse(remaining)?;
-        let (offset3, remaining) = u32::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (modifier, remaining) = u64::try_parse(remaining)?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(value), "pixmap")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (num_buffers, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_buffers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (stride0, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "stride0")?;
+        let (offset0, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset0")?;
+        let (stride1, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "stride1")?;
+        let (offset1, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset1")?;
+        let (stride2, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "stride2")?;
+        let (offset2, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset2")?;
+        let (stride3, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "stride3")?;
+        let (offset3, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset3")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (modifier, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "modifier")?;
         let fds_len = num_buffers.try_to_usize()?;
         if fds.len() < fds_len { return Err(ParseError::MissingFileDescriptors) }
         let mut buffers = fds.split_off(fds_len);
@@ -1092,6 +1116,7 @@ impl PixmapFromBuffersRequest {
 }
 impl Request for PixmapFromBuffersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1136,7 +1161,7 @@ impl BuffersFromPixmapRequest {
         if header.minor_opcode != BUFFERS_FROM_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(value)?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(value), "pixmap")?;
         let _ = remaining;
         Ok(BuffersFromPixmapRequest {
             pixmap,
@@ -1145,6 +1170,7 @@ impl BuffersFromPixmapRequest {
 }
 impl Request for BuffersFromPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1173,30 +1199,29 @@ pub struct BuffersFromPixmapReply {
 impl TryParseFd for BuffersFromPixmapReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (modifier, remaining) = u64::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;
-        let (strides, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_to_usize()?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (modifier, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "modifier")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 6)?;
+        let (strides, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_to_usize()?, Some("strides"))?;
+        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, nfd.try_to_usize()?, Some("offsets"))?;
         let fds_len = nfd.try_to_usize()?;
         if fds.len() < fds_len { return Err(ParseError::MissingFileDescriptors) }
         let mut buffers = fds.split_off(fds_len);
         core::mem::swap(fds, &mut buffers);
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = BuffersFromPixmapReply { sequence, length, width, height, modifier, depth, bpp, strides, offsets, buffers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1243,6 +1268,32 @@ impl BuffersFromPixmapReply {
             .try_into().unwrap()
     }
 }
+impl BuffersFromPixmapReply {
+    /// Lazily parse the `strides` list from the raw bytes of a `BuffersFromPixmapReply`.
+    ///
+    /// In contrast to parsing the whole `BuffersFromPixmapReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn strides_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (modifier, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "modifier")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 6)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = nfd.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetDRMDeviceInUse request
 pub const SET_DRM_DEVICE_IN_USE_REQUEST: u8 = 9;
@@ -1289,9 +1340,9 @@ impl SetDRMDeviceInUseRequest {
         if header.minor_opcode != SET_DRM_DEVICE_IN_USE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (drm_major, remaining) = u32::try_parse(remaining)?;
-        let (drm_minor, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (drm_major, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drm_major")?;
+        let (drm_minor, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drm_minor")?;
         let _ = remaining;
         Ok(SetDRMDeviceInUseRequest {
             window,
@@ -1302,6 +1353,7 @@ impl SetDRMDeviceInUseRequest {
 }
 impl Request for SetDRMDeviceInUseRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
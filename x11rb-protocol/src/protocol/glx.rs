@@ -120,20 +120,20 @@ pub struct PbufferClobberEvent {
 impl TryParse for PbufferClobberEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (draw_type, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (b_mask, remaining) = u32::try_parse(remaining)?;
-        let (aux_buffer, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (draw_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "draw_type")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (b_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "b_mask")?;
+        let (aux_buffer, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "aux_buffer")?;
+        let (x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let result = PbufferClobberEvent { response_type, sequence, event_type, draw_type, drawable, b_mask, aux_buffer, x, y, width, height, count };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -283,17 +283,17 @@ pub struct BufferSwapCompleteEvent {
 impl TryParse for BufferSwapCompleteEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (ust_hi, remaining) = u32::try_parse(remaining)?;
-        let (ust_lo, remaining) = u32::try_parse(remaining)?;
-        let (msc_hi, remaining) = u32::try_parse(remaining)?;
-        let (msc_lo, remaining) = u32::try_parse(remaining)?;
-        let (sbc, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (ust_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_hi")?;
+        let (ust_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ust_lo")?;
+        let (msc_hi, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_hi")?;
+        let (msc_lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "msc_lo")?;
+        let (sbc, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sbc")?;
         let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -559,7 +559,7 @@ impl<'input> RenderRequest<'input> {
         if header.minor_opcode != RENDER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(RenderRequest {
@@ -577,6 +577,7 @@ impl<'input> RenderRequest<'input> {
 }
 impl<'input> Request for RenderRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -639,11 +640,11 @@ impl<'input> RenderLargeRequest<'input> {
         if header.minor_opcode != RENDER_LARGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (request_num, remaining) = u16::try_parse(remaining)?;
-        let (request_total, remaining) = u16::try_parse(remaining)?;
-        let (data_len, remaining) = u32::try_parse(remaining)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_to_usize()?)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (request_num, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "request_num")?;
+        let (request_total, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "request_total")?;
+        let (data_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_len")?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, data_len.try_to_usize()?), "data")?;
         let _ = remaining;
         Ok(RenderLargeRequest {
             context_tag,
@@ -664,6 +665,7 @@ impl<'input> RenderLargeRequest<'input> {
 }
 impl<'input> Request for RenderLargeRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -732,12 +734,12 @@ impl CreateContextRequest {
         if header.minor_opcode != CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (share_list, remaining) = Context::try_parse(remaining)?;
-        let (is_direct, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
+        let (share_list, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "share_list")?;
+        let (is_direct, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_direct")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CreateContextRequest {
             context,
@@ -750,6 +752,7 @@ impl CreateContextRequest {
 }
 impl Request for CreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -794,7 +797,7 @@ impl DestroyContextRequest {
         if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(DestroyContextRequest {
             context,
@@ -803,6 +806,7 @@ impl DestroyContextRequest {
 }
 impl Request for DestroyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -859,9 +863,9 @@ impl MakeCurrentRequest {
         if header.minor_opcode != MAKE_CURRENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (context, remaining) = Context::try_parse(remaining)?;
-        let (old_context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "context")?;
+        let (old_context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "old_context_tag")?;
         let _ = remaining;
         Ok(MakeCurrentRequest {
             drawable,
@@ -872,6 +876,7 @@ impl MakeCurrentRequest {
 }
 impl Request for MakeCurrentRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -894,19 +899,18 @@ pub struct MakeCurrentReply {
 impl TryParse for MakeCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "context_tag")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = MakeCurrentReply { sequence, length, context_tag };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -997,7 +1001,7 @@ impl IsDirectRequest {
         if header.minor_opcode != IS_DIRECT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(IsDirectRequest {
             context,
@@ -1006,6 +1010,7 @@ impl IsDirectRequest {
 }
 impl Request for IsDirectRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1028,19 +1033,18 @@ pub struct IsDirectReply {
 impl TryParse for IsDirectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (is_direct, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (is_direct, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_direct")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsDirectReply { sequence, length, is_direct };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1137,8 +1141,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -1148,6 +1152,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1171,20 +1176,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1277,7 +1281,7 @@ impl WaitGLRequest {
         if header.minor_opcode != WAIT_GL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(WaitGLRequest {
             context_tag,
@@ -1286,6 +1290,7 @@ impl WaitGLRequest {
 }
 impl Request for WaitGLRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1330,7 +1335,7 @@ impl WaitXRequest {
         if header.minor_opcode != WAIT_X_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(WaitXRequest {
             context_tag,
@@ -1339,6 +1344,7 @@ impl WaitXRequest {
 }
 impl Request for WaitXRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1401,10 +1407,10 @@ impl CopyContextRequest {
         if header.minor_opcode != COPY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (src, remaining) = Context::try_parse(value)?;
-        let (dest, remaining) = Context::try_parse(remaining)?;
-        let (mask, remaining) = u32::try_parse(remaining)?;
-        let (src_context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (src, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "src")?;
+        let (dest, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "dest")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mask")?;
+        let (src_context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "src_context_tag")?;
         let _ = remaining;
         Ok(CopyContextRequest {
             src,
@@ -1416,6 +1422,7 @@ impl CopyContextRequest {
 }
 impl Request for CopyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1551,8 +1558,8 @@ impl SwapBuffersRequest {
         if header.minor_opcode != SWAP_BUFFERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
         let _ = remaining;
         Ok(SwapBuffersRequest {
             context_tag,
@@ -1562,6 +1569,7 @@ impl SwapBuffersRequest {
 }
 impl Request for SwapBuffersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1630,11 +1638,11 @@ impl UseXFontRequest {
         if header.minor_opcode != USE_X_FONT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (font, remaining) = xproto::Font::try_parse(remaining)?;
-        let (first, remaining) = u32::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
-        let (list_base, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (font, remaining) = crate::x11_utils::in_field(xproto::Font::try_parse(remaining), "font")?;
+        let (first, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "first")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
+        let (list_base, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list_base")?;
         let _ = remaining;
         Ok(UseXFontRequest {
             context_tag,
@@ -1647,6 +1655,7 @@ impl UseXFontRequest {
 }
 impl Request for UseXFontRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1709,10 +1718,10 @@ impl CreateGLXPixmapRequest {
         if header.minor_opcode != CREATE_GLX_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (glx_pixmap, remaining) = Pixmap::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
+        let (glx_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "glx_pixmap")?;
         let _ = remaining;
         Ok(CreateGLXPixmapRequest {
             screen,
@@ -1724,6 +1733,7 @@ impl CreateGLXPixmapRequest {
 }
 impl Request for CreateGLXPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1768,7 +1778,7 @@ impl GetVisualConfigsRequest {
         if header.minor_opcode != GET_VISUAL_CONFIGS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(GetVisualConfigsRequest {
             screen,
@@ -1777,6 +1787,7 @@ impl GetVisualConfigsRequest {
 }
 impl Request for GetVisualConfigsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1800,21 +1811,20 @@ pub struct GetVisualConfigsReply {
 impl TryParse for GetVisualConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_visuals, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_visuals")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("property_list"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetVisualConfigsReply { sequence, num_visuals, num_properties, property_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1854,6 +1864,28 @@ impl GetVisualConfigsReply {
             .try_into().unwrap()
     }
 }
+impl GetVisualConfigsReply {
+    /// Lazily parse the `property_list` list from the raw bytes of a `GetVisualConfigsReply`.
+    ///
+    /// In contrast to parsing the whole `GetVisualConfigsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn property_list_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_visuals")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the DestroyGLXPixmap request
 pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
@@ -1888,7 +1920,7 @@ impl DestroyGLXPixmapRequest {
         if header.minor_opcode != DESTROY_GLX_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glx_pixmap, remaining) = Pixmap::try_parse(value)?;
+        let (glx_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(value), "glx_pixmap")?;
         let _ = remaining;
         Ok(DestroyGLXPixmapRequest {
             glx_pixmap,
@@ -1897,6 +1929,7 @@ impl DestroyGLXPixmapRequest {
 }
 impl Request for DestroyGLXPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1951,8 +1984,8 @@ impl<'input> VendorPrivateRequest<'input> {
         if header.minor_opcode != VENDOR_PRIVATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (vendor_code, remaining) = u32::try_parse(value)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (vendor_code, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "vendor_code")?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "context_tag")?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(VendorPrivateRequest {
@@ -1972,6 +2005,7 @@ impl<'input> VendorPrivateRequest<'input> {
 }
 impl<'input> Request for VendorPrivateRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2026,8 +2060,8 @@ impl<'input> VendorPrivateWithReplyRequest<'input> {
         if header.minor_opcode != VENDOR_PRIVATE_WITH_REPLY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (vendor_code, remaining) = u32::try_parse(value)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
+        let (vendor_code, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "vendor_code")?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "context_tag")?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(VendorPrivateWithReplyRequest {
@@ -2047,6 +2081,7 @@ impl<'input> VendorPrivateWithReplyRequest<'input> {
 }
 impl<'input> Request for VendorPrivateWithReplyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2070,21 +2105,20 @@ pub struct VendorPrivateWithReplyReply {
 impl TryParse for VendorPrivateWithReplyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (retval, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (retval, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "retval")?;
         let (data1, remaining) = crate::x11_utils::parse_u8_array::<24>(remaining)?;
-        let (data2, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (data2, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data2")?;
         let data2 = data2.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = VendorPrivateWithReplyReply { sequence, retval, data1, data2 };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2159,7 +2193,7 @@ impl QueryExtensionsStringRequest {
         if header.minor_opcode != QUERY_EXTENSIONS_STRING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(QueryExtensionsStringRequest {
             screen,
@@ -2168,6 +2202,7 @@ impl QueryExtensionsStringRequest {
 }
 impl Request for QueryExtensionsStringRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2190,20 +2225,19 @@ pub struct QueryExtensionsStringReply {
 impl TryParse for QueryExtensionsStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryExtensionsStringReply { sequence, length, n };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2301,8 +2335,8 @@ impl QueryServerStringRequest {
         if header.minor_opcode != QUERY_SERVER_STRING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (name, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (name, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "name")?;
         let _ = remaining;
         Ok(QueryServerStringRequest {
             screen,
@@ -2312,6 +2346,7 @@ impl QueryServerStringRequest {
 }
 impl Request for QueryServerStringRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2334,22 +2369,21 @@ pub struct QueryServerStringReply {
 impl TryParse for QueryServerStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (str_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "str_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, str_len.try_to_usize()?), "string")?;
         let string = string.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryServerStringReply { sequence, length, string };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2439,10 +2473,10 @@ impl<'input> ClientInfoRequest<'input> {
         if header.minor_opcode != CLIENT_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let (str_len, remaining) = u32::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_to_usize()?)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let (str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "str_len")?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, str_len.try_to_usize()?), "string")?;
         let _ = remaining;
         Ok(ClientInfoRequest {
             major_version,
@@ -2461,6 +2495,7 @@ impl<'input> ClientInfoRequest<'input> {
 }
 impl<'input> Request for ClientInfoRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2505,7 +2540,7 @@ impl GetFBConfigsRequest {
         if header.minor_opcode != GET_FB_CONFIGS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(GetFBConfigsRequest {
             screen,
@@ -2514,6 +2549,7 @@ impl GetFBConfigsRequest {
 }
 impl Request for GetFBConfigsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2537,21 +2573,20 @@ pub struct GetFBConfigsReply {
 impl TryParse for GetFBConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_fb_configs, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_fb_configs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_fb_configs")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("property_list"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetFBConfigsReply { sequence, num_fb_configs, num_properties, property_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2591,6 +2626,28 @@ impl GetFBConfigsReply {
             .try_into().unwrap()
     }
 }
+impl GetFBConfigsReply {
+    /// Lazily parse the `property_list` list from the raw bytes of a `GetFBConfigsReply`.
+    ///
+    /// In contrast to parsing the whole `GetFBConfigsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn property_list_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_fb_configs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_fb_configs")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 22;
@@ -2655,12 +2712,12 @@ impl<'input> CreatePixmapRequest<'input> {
         if header.minor_opcode != CREATE_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (fbconfig, remaining) = Fbconfig::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (glx_pixmap, remaining) = Pixmap::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (fbconfig, remaining) = crate::x11_utils::in_field(Fbconfig::try_parse(remaining), "fbconfig")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
+        let (glx_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "glx_pixmap")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         let _ = remaining;
         Ok(CreatePixmapRequest {
             screen,
@@ -2683,6 +2740,7 @@ impl<'input> CreatePixmapRequest<'input> {
 }
 impl<'input> Request for CreatePixmapRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2727,7 +2785,7 @@ impl DestroyPixmapRequest {
         if header.minor_opcode != DESTROY_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glx_pixmap, remaining) = Pixmap::try_parse(value)?;
+        let (glx_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(value), "glx_pixmap")?;
         let _ = remaining;
         Ok(DestroyPixmapRequest {
             glx_pixmap,
@@ -2736,6 +2794,7 @@ impl DestroyPixmapRequest {
 }
 impl Request for DestroyPixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2810,13 +2869,13 @@ impl CreateNewContextRequest {
         if header.minor_opcode != CREATE_NEW_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (fbconfig, remaining) = Fbconfig::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (render_type, remaining) = u32::try_parse(remaining)?;
-        let (share_list, remaining) = Context::try_parse(remaining)?;
-        let (is_direct, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (fbconfig, remaining) = crate::x11_utils::in_field(Fbconfig::try_parse(remaining), "fbconfig")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
+        let (render_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "render_type")?;
+        let (share_list, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "share_list")?;
+        let (is_direct, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_direct")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CreateNewContextRequest {
             context,
@@ -2830,6 +2889,7 @@ impl CreateNewContextRequest {
 }
 impl Request for CreateNewContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2874,7 +2934,7 @@ impl QueryContextRequest {
         if header.minor_opcode != QUERY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(QueryContextRequest {
             context,
@@ -2883,6 +2943,7 @@ impl QueryContextRequest {
 }
 impl Request for QueryContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2905,20 +2966,19 @@ pub struct QueryContextReply {
 impl TryParse for QueryContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryContextReply { sequence, length, attribs };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2959,6 +3019,27 @@ impl QueryContextReply {
             .try_into().unwrap()
     }
 }
+impl QueryContextReply {
+    /// Lazily parse the `attribs` list from the raw bytes of a `QueryContextReply`.
+    ///
+    /// In contrast to parsing the whole `QueryContextReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn attribs_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the MakeContextCurrent request
 pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
@@ -3011,10 +3092,10 @@ impl MakeContextCurrentRequest {
         if header.minor_opcode != MAKE_CONTEXT_CURRENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (old_context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (read_drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (context, remaining) = Context::try_parse(remaining)?;
+        let (old_context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "old_context_tag")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (read_drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "read_drawable")?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "context")?;
         let _ = remaining;
         Ok(MakeContextCurrentRequest {
             old_context_tag,
@@ -3026,6 +3107,7 @@ impl MakeContextCurrentRequest {
 }
 impl Request for MakeContextCurrentRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3048,19 +3130,18 @@ pub struct MakeContextCurrentReply {
 impl TryParse for MakeContextCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_tag, remaining) = ContextTag::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(remaining), "context_tag")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = MakeContextCurrentReply { sequence, length, context_tag };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3175,11 +3256,11 @@ impl<'input> CreatePbufferRequest<'input> {
         if header.minor_opcode != CREATE_PBUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (fbconfig, remaining) = Fbconfig::try_parse(remaining)?;
-        let (pbuffer, remaining) = Pbuffer::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (fbconfig, remaining) = crate::x11_utils::in_field(Fbconfig::try_parse(remaining), "fbconfig")?;
+        let (pbuffer, remaining) = crate::x11_utils::in_field(Pbuffer::try_parse(remaining), "pbuffer")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         let _ = remaining;
         Ok(CreatePbufferRequest {
             screen,
@@ -3200,6 +3281,7 @@ impl<'input> CreatePbufferRequest<'input> {
 }
 impl<'input> Request for CreatePbufferRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3244,7 +3326,7 @@ impl DestroyPbufferRequest {
         if header.minor_opcode != DESTROY_PBUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pbuffer, remaining) = Pbuffer::try_parse(value)?;
+        let (pbuffer, remaining) = crate::x11_utils::in_field(Pbuffer::try_parse(value), "pbuffer")?;
         let _ = remaining;
         Ok(DestroyPbufferRequest {
             pbuffer,
@@ -3253,6 +3335,7 @@ impl DestroyPbufferRequest {
 }
 impl Request for DestroyPbufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3297,7 +3380,7 @@ impl GetDrawableAttributesRequest {
         if header.minor_opcode != GET_DRAWABLE_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(GetDrawableAttributesRequest {
             drawable,
@@ -3306,6 +3389,7 @@ impl GetDrawableAttributesRequest {
 }
 impl Request for GetDrawableAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3328,20 +3412,19 @@ pub struct GetDrawableAttributesReply {
 impl TryParse for GetDrawableAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDrawableAttributesReply { sequence, length, attribs };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3382,6 +3465,27 @@ impl GetDrawableAttributesReply {
             .try_into().unwrap()
     }
 }
+impl GetDrawableAttributesReply {
+    /// Lazily parse the `attribs` list from the raw bytes of a `GetDrawableAttributesReply`.
+    ///
+    /// In contrast to parsing the whole `GetDrawableAttributesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn attribs_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ChangeDrawableAttributes request
 pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
@@ -3428,9 +3532,9 @@ impl<'input> ChangeDrawableAttributesRequest<'input> {
         if header.minor_opcode != CHANGE_DRAWABLE_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         let _ = remaining;
         Ok(ChangeDrawableAttributesRequest {
             drawable,
@@ -3447,6 +3551,7 @@ impl<'input> ChangeDrawableAttributesRequest<'input> {
 }
 impl<'input> Request for ChangeDrawableAttributesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3521,12 +3626,12 @@ impl<'input> CreateWindowRequest<'input> {
         if header.minor_opcode != CREATE_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (fbconfig, remaining) = Fbconfig::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (glx_window, remaining) = Window::try_parse(remaining)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (fbconfig, remaining) = crate::x11_utils::in_field(Fbconfig::try_parse(remaining), "fbconfig")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (glx_window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "glx_window")?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         let _ = remaining;
         Ok(CreateWindowRequest {
             screen,
@@ -3549,6 +3654,7 @@ impl<'input> CreateWindowRequest<'input> {
 }
 impl<'input> Request for CreateWindowRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3593,7 +3699,7 @@ impl DeleteWindowRequest {
         if header.minor_opcode != DELETE_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glxwindow, remaining) = Window::try_parse(value)?;
+        let (glxwindow, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "glxwindow")?;
         let _ = remaining;
         Ok(DeleteWindowRequest {
             glxwindow,
@@ -3602,6 +3708,7 @@ impl DeleteWindowRequest {
 }
 impl Request for DeleteWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3682,18 +3789,18 @@ impl<'input> SetClientInfoARBRequest<'input> {
         if header.minor_opcode != SET_CLIENT_INFO_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let (num_versions, remaining) = u32::try_parse(remaining)?;
-        let (gl_str_len, remaining) = u32::try_parse(remaining)?;
-        let (glx_str_len, remaining) = u32::try_parse(remaining)?;
-        let (gl_versions, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_versions).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_to_usize()?)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let (num_versions, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_versions")?;
+        let (gl_str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "gl_str_len")?;
+        let (glx_str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "glx_str_len")?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_versions).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("gl_versions"))?;
+        let (gl_extension_string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_to_usize()?), "gl_extension_string")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (glx_extension_string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_to_usize()?), "glx_extension_string")?;
         let _ = remaining;
         Ok(SetClientInfoARBRequest {
             major_version,
@@ -3716,6 +3823,7 @@ impl<'input> SetClientInfoARBRequest<'input> {
 }
 impl<'input> Request for SetClientInfoARBRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3796,14 +3904,14 @@ impl<'input> CreateContextAttribsARBRequest<'input> {
         if header.minor_opcode != CREATE_CONTEXT_ATTRIBS_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (fbconfig, remaining) = Fbconfig::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (share_list, remaining) = Context::try_parse(remaining)?;
-        let (is_direct, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (num_attribs, remaining) = u32::try_parse(remaining)?;
-        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (fbconfig, remaining) = crate::x11_utils::in_field(Fbconfig::try_parse(remaining), "fbconfig")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
+        let (share_list, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "share_list")?;
+        let (is_direct, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_direct")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (num_attribs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attribs")?;
+        let (attribs, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_attribs).checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("attribs"))?;
         let _ = remaining;
         Ok(CreateContextAttribsARBRequest {
             context,
@@ -3828,6 +3936,7 @@ impl<'input> CreateContextAttribsARBRequest<'input> {
 }
 impl<'input> Request for CreateContextAttribsARBRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3908,18 +4017,18 @@ impl<'input> SetClientInfo2ARBRequest<'input> {
         if header.minor_opcode != SET_CLIENT_INFO2_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let (num_versions, remaining) = u32::try_parse(remaining)?;
-        let (gl_str_len, remaining) = u32::try_parse(remaining)?;
-        let (glx_str_len, remaining) = u32::try_parse(remaining)?;
-        let (gl_versions, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_versions).checked_mul(3u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_to_usize()?)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let (num_versions, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_versions")?;
+        let (gl_str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "gl_str_len")?;
+        let (glx_str_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "glx_str_len")?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_versions).checked_mul(3u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("gl_versions"))?;
+        let (gl_extension_string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_to_usize()?), "gl_extension_string")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (glx_extension_string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_to_usize()?), "glx_extension_string")?;
         let _ = remaining;
         Ok(SetClientInfo2ARBRequest {
             major_version,
@@ -3942,6 +4051,7 @@ impl<'input> SetClientInfo2ARBRequest<'input> {
 }
 impl<'input> Request for SetClientInfo2ARBRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3998,9 +4108,9 @@ impl NewListRequest {
         if header.minor_opcode != NEW_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (list, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (list, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mode")?;
         let _ = remaining;
         Ok(NewListRequest {
             context_tag,
@@ -4011,6 +4121,7 @@ impl NewListRequest {
 }
 impl Request for NewListRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4055,7 +4166,7 @@ impl EndListRequest {
         if header.minor_opcode != END_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(EndListRequest {
             context_tag,
@@ -4064,6 +4175,7 @@ impl EndListRequest {
 }
 impl Request for EndListRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4120,9 +4232,9 @@ impl DeleteListsRequest {
         if header.minor_opcode != DELETE_LISTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (list, remaining) = u32::try_parse(remaining)?;
-        let (range, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (list, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list")?;
+        let (range, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "range")?;
         let _ = remaining;
         Ok(DeleteListsRequest {
             context_tag,
@@ -4133,6 +4245,7 @@ impl DeleteListsRequest {
 }
 impl Request for DeleteListsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4183,8 +4296,8 @@ impl GenListsRequest {
         if header.minor_opcode != GEN_LISTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (range, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (range, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "range")?;
         let _ = remaining;
         Ok(GenListsRequest {
             context_tag,
@@ -4194,6 +4307,7 @@ impl GenListsRequest {
 }
 impl Request for GenListsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4216,18 +4330,17 @@ pub struct GenListsReply {
 impl TryParse for GenListsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ret_val")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GenListsReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4309,9 +4422,9 @@ impl FeedbackBufferRequest {
         if header.minor_opcode != FEEDBACK_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (size, remaining) = i32::try_parse(remaining)?;
-        let (type_, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (size, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "size")?;
+        let (type_, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "type_")?;
         let _ = remaining;
         Ok(FeedbackBufferRequest {
             context_tag,
@@ -4322,6 +4435,7 @@ impl FeedbackBufferRequest {
 }
 impl Request for FeedbackBufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4372,8 +4486,8 @@ impl SelectBufferRequest {
         if header.minor_opcode != SELECT_BUFFER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (size, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (size, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "size")?;
         let _ = remaining;
         Ok(SelectBufferRequest {
             context_tag,
@@ -4383,6 +4497,7 @@ impl SelectBufferRequest {
 }
 impl Request for SelectBufferRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4433,8 +4548,8 @@ impl RenderModeRequest {
         if header.minor_opcode != RENDER_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (mode, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mode")?;
         let _ = remaining;
         Ok(RenderModeRequest {
             context_tag,
@@ -4444,6 +4559,7 @@ impl RenderModeRequest {
 }
 impl Request for RenderModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4468,22 +4584,21 @@ pub struct RenderModeReply {
 impl TryParse for RenderModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = u32::try_parse(remaining)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (new_mode, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ret_val")?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (new_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "new_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = RenderModeReply { sequence, length, ret_val, new_mode, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4524,6 +4639,29 @@ impl RenderModeReply {
             .try_into().unwrap()
     }
 }
+impl RenderModeReply {
+    /// Lazily parse the `data` list from the raw bytes of a `RenderModeReply`.
+    ///
+    /// In contrast to parsing the whole `RenderModeReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ret_val")?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (new_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "new_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4613,7 +4751,7 @@ impl FinishRequest {
         if header.minor_opcode != FINISH_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(FinishRequest {
             context_tag,
@@ -4622,6 +4760,7 @@ impl FinishRequest {
 }
 impl Request for FinishRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4643,17 +4782,16 @@ pub struct FinishReply {
 impl TryParse for FinishReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = FinishReply { sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4729,9 +4867,9 @@ impl PixelStorefRequest {
         if header.minor_opcode != PIXEL_STOREF_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
         let _ = remaining;
         Ok(PixelStorefRequest {
             context_tag,
@@ -4742,6 +4880,7 @@ impl PixelStorefRequest {
 }
 impl Request for PixelStorefRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4798,9 +4937,9 @@ impl PixelStoreiRequest {
         if header.minor_opcode != PIXEL_STOREI_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
         let _ = remaining;
         Ok(PixelStoreiRequest {
             context_tag,
@@ -4811,6 +4950,7 @@ impl PixelStoreiRequest {
 }
 impl Request for PixelStoreiRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4899,15 +5039,15 @@ impl ReadPixelsRequest {
         if header.minor_opcode != READ_PIXELS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (x, remaining) = i32::try_parse(remaining)?;
-        let (y, remaining) = i32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
-        let (lsb_first, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "height")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
+        let (lsb_first, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "lsb_first")?;
         let _ = remaining;
         Ok(ReadPixelsRequest {
             context_tag,
@@ -4924,6 +5064,7 @@ impl ReadPixelsRequest {
 }
 impl Request for ReadPixelsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4945,20 +5086,19 @@ pub struct ReadPixelsReply {
 impl TryParse for ReadPixelsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ReadPixelsReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5038,8 +5178,8 @@ impl GetBooleanvRequest {
         if header.minor_opcode != GET_BOOLEANV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetBooleanvRequest {
             context_tag,
@@ -5049,6 +5189,7 @@ impl GetBooleanvRequest {
 }
 impl Request for GetBooleanvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5072,22 +5213,21 @@ pub struct GetBooleanvReply {
 impl TryParse for GetBooleanvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetBooleanvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5128,6 +5268,29 @@ impl GetBooleanvReply {
             .try_into().unwrap()
     }
 }
+impl GetBooleanvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetBooleanvReply`.
+    ///
+    /// In contrast to parsing the whole `GetBooleanvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, bool>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetClipPlane request
 pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
@@ -5168,8 +5331,8 @@ impl GetClipPlaneRequest {
         if header.minor_opcode != GET_CLIP_PLANE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (plane, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (plane, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "plane")?;
         let _ = remaining;
         Ok(GetClipPlaneRequest {
             context_tag,
@@ -5179,6 +5342,7 @@ impl GetClipPlaneRequest {
 }
 impl Request for GetClipPlaneRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5200,19 +5364,18 @@ pub struct GetClipPlaneReply {
 impl TryParse for GetClipPlaneReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetClipPlaneReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5251,6 +5414,26 @@ impl GetClipPlaneReply {
             .try_into().unwrap()
     }
 }
+impl GetClipPlaneReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetClipPlaneReply`.
+    ///
+    /// In contrast to parsing the whole `GetClipPlaneReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float64>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetDoublev request
 pub const GET_DOUBLEV_REQUEST: u8 = 114;
@@ -5291,8 +5474,8 @@ impl GetDoublevRequest {
         if header.minor_opcode != GET_DOUBLEV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetDoublevRequest {
             context_tag,
@@ -5302,6 +5485,7 @@ impl GetDoublevRequest {
 }
 impl Request for GetDoublevRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5325,22 +5509,21 @@ pub struct GetDoublevReply {
 impl TryParse for GetDoublevReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDoublevReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5381,6 +5564,29 @@ impl GetDoublevReply {
             .try_into().unwrap()
     }
 }
+impl GetDoublevReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetDoublevReply`.
+    ///
+    /// In contrast to parsing the whole `GetDoublevReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float64>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetError request
 pub const GET_ERROR_REQUEST: u8 = 115;
@@ -5415,7 +5621,7 @@ impl GetErrorRequest {
         if header.minor_opcode != GET_ERROR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(GetErrorRequest {
             context_tag,
@@ -5424,6 +5630,7 @@ impl GetErrorRequest {
 }
 impl Request for GetErrorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5446,18 +5653,17 @@ pub struct GetErrorReply {
 impl TryParse for GetErrorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (error, remaining) = i32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (error, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "error")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetErrorReply { sequence, length, error };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5533,8 +5739,8 @@ impl GetFloatvRequest {
         if header.minor_opcode != GET_FLOATV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetFloatvRequest {
             context_tag,
@@ -5544,6 +5750,7 @@ impl GetFloatvRequest {
 }
 impl Request for GetFloatvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5567,22 +5774,21 @@ pub struct GetFloatvReply {
 impl TryParse for GetFloatvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetFloatvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5623,6 +5829,29 @@ impl GetFloatvReply {
             .try_into().unwrap()
     }
 }
+impl GetFloatvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetFloatvReply`.
+    ///
+    /// In contrast to parsing the whole `GetFloatvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetIntegerv request
 pub const GET_INTEGERV_REQUEST: u8 = 117;
@@ -5663,8 +5892,8 @@ impl GetIntegervRequest {
         if header.minor_opcode != GET_INTEGERV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetIntegervRequest {
             context_tag,
@@ -5674,6 +5903,7 @@ impl GetIntegervRequest {
 }
 impl Request for GetIntegervRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5697,22 +5927,21 @@ pub struct GetIntegervReply {
 impl TryParse for GetIntegervReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetIntegervReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5753,6 +5982,29 @@ impl GetIntegervReply {
             .try_into().unwrap()
     }
 }
+impl GetIntegervReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetIntegervReply`.
+    ///
+    /// In contrast to parsing the whole `GetIntegervReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetLightfv request
 pub const GET_LIGHTFV_REQUEST: u8 = 118;
@@ -5799,9 +6051,9 @@ impl GetLightfvRequest {
         if header.minor_opcode != GET_LIGHTFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (light, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (light, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "light")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetLightfvRequest {
             context_tag,
@@ -5812,6 +6064,7 @@ impl GetLightfvRequest {
 }
 impl Request for GetLightfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5835,22 +6088,21 @@ pub struct GetLightfvReply {
 impl TryParse for GetLightfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetLightfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5891,6 +6143,29 @@ impl GetLightfvReply {
             .try_into().unwrap()
     }
 }
+impl GetLightfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetLightfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetLightfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetLightiv request
 pub const GET_LIGHTIV_REQUEST: u8 = 119;
@@ -5937,9 +6212,9 @@ impl GetLightivRequest {
         if header.minor_opcode != GET_LIGHTIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (light, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (light, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "light")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetLightivRequest {
             context_tag,
@@ -5950,6 +6225,7 @@ impl GetLightivRequest {
 }
 impl Request for GetLightivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5973,22 +6249,21 @@ pub struct GetLightivReply {
 impl TryParse for GetLightivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetLightivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6029,6 +6304,29 @@ impl GetLightivReply {
             .try_into().unwrap()
     }
 }
+impl GetLightivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetLightivReply`.
+    ///
+    /// In contrast to parsing the whole `GetLightivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMapdv request
 pub const GET_MAPDV_REQUEST: u8 = 120;
@@ -6075,9 +6373,9 @@ impl GetMapdvRequest {
         if header.minor_opcode != GET_MAPDV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (query, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (query, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "query")?;
         let _ = remaining;
         Ok(GetMapdvRequest {
             context_tag,
@@ -6088,6 +6386,7 @@ impl GetMapdvRequest {
 }
 impl Request for GetMapdvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6111,22 +6410,21 @@ pub struct GetMapdvReply {
 impl TryParse for GetMapdvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMapdvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6167,6 +6465,29 @@ impl GetMapdvReply {
             .try_into().unwrap()
     }
 }
+impl GetMapdvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMapdvReply`.
+    ///
+    /// In contrast to parsing the whole `GetMapdvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float64>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMapfv request
 pub const GET_MAPFV_REQUEST: u8 = 121;
@@ -6213,9 +6534,9 @@ impl GetMapfvRequest {
         if header.minor_opcode != GET_MAPFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (query, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (query, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "query")?;
         let _ = remaining;
         Ok(GetMapfvRequest {
             context_tag,
@@ -6226,6 +6547,7 @@ impl GetMapfvRequest {
 }
 impl Request for GetMapfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6249,22 +6571,21 @@ pub struct GetMapfvReply {
 impl TryParse for GetMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMapfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6305,6 +6626,29 @@ impl GetMapfvReply {
             .try_into().unwrap()
     }
 }
+impl GetMapfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMapfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetMapfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMapiv request
 pub const GET_MAPIV_REQUEST: u8 = 122;
@@ -6351,9 +6695,9 @@ impl GetMapivRequest {
         if header.minor_opcode != GET_MAPIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (query, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (query, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "query")?;
         let _ = remaining;
         Ok(GetMapivRequest {
             context_tag,
@@ -6364,6 +6708,7 @@ impl GetMapivRequest {
 }
 impl Request for GetMapivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6387,22 +6732,21 @@ pub struct GetMapivReply {
 impl TryParse for GetMapivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMapivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6443,6 +6787,29 @@ impl GetMapivReply {
             .try_into().unwrap()
     }
 }
+impl GetMapivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMapivReply`.
+    ///
+    /// In contrast to parsing the whole `GetMapivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMaterialfv request
 pub const GET_MATERIALFV_REQUEST: u8 = 123;
@@ -6489,9 +6856,9 @@ impl GetMaterialfvRequest {
         if header.minor_opcode != GET_MATERIALFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (face, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (face, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "face")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetMaterialfvRequest {
             context_tag,
@@ -6502,6 +6869,7 @@ impl GetMaterialfvRequest {
 }
 impl Request for GetMaterialfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6525,22 +6893,21 @@ pub struct GetMaterialfvReply {
 impl TryParse for GetMaterialfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMaterialfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6581,6 +6948,29 @@ impl GetMaterialfvReply {
             .try_into().unwrap()
     }
 }
+impl GetMaterialfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMaterialfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetMaterialfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMaterialiv request
 pub const GET_MATERIALIV_REQUEST: u8 = 124;
@@ -6627,9 +7017,9 @@ impl GetMaterialivRequest {
         if header.minor_opcode != GET_MATERIALIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (face, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (face, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "face")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetMaterialivRequest {
             context_tag,
@@ -6640,6 +7030,7 @@ impl GetMaterialivRequest {
 }
 impl Request for GetMaterialivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6663,22 +7054,21 @@ pub struct GetMaterialivReply {
 impl TryParse for GetMaterialivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMaterialivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6719,6 +7109,29 @@ impl GetMaterialivReply {
             .try_into().unwrap()
     }
 }
+impl GetMaterialivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMaterialivReply`.
+    ///
+    /// In contrast to parsing the whole `GetMaterialivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetPixelMapfv request
 pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
@@ -6759,8 +7172,8 @@ impl GetPixelMapfvRequest {
         if header.minor_opcode != GET_PIXEL_MAPFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (map, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (map, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map")?;
         let _ = remaining;
         Ok(GetPixelMapfvRequest {
             context_tag,
@@ -6770,6 +7183,7 @@ impl GetPixelMapfvRequest {
 }
 impl Request for GetPixelMapfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6793,22 +7207,21 @@ pub struct GetPixelMapfvReply {
 impl TryParse for GetPixelMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPixelMapfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6849,6 +7262,29 @@ impl GetPixelMapfvReply {
             .try_into().unwrap()
     }
 }
+impl GetPixelMapfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetPixelMapfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetPixelMapfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetPixelMapuiv request
 pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
@@ -6889,8 +7325,8 @@ impl GetPixelMapuivRequest {
         if header.minor_opcode != GET_PIXEL_MAPUIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (map, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (map, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map")?;
         let _ = remaining;
         Ok(GetPixelMapuivRequest {
             context_tag,
@@ -6900,6 +7336,7 @@ impl GetPixelMapuivRequest {
 }
 impl Request for GetPixelMapuivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6923,22 +7360,21 @@ pub struct GetPixelMapuivReply {
 impl TryParse for GetPixelMapuivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPixelMapuivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6979,6 +7415,29 @@ impl GetPixelMapuivReply {
             .try_into().unwrap()
     }
 }
+impl GetPixelMapuivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetPixelMapuivReply`.
+    ///
+    /// In contrast to parsing the whole `GetPixelMapuivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetPixelMapusv request
 pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
@@ -7019,8 +7478,8 @@ impl GetPixelMapusvRequest {
         if header.minor_opcode != GET_PIXEL_MAPUSV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (map, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (map, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map")?;
         let _ = remaining;
         Ok(GetPixelMapusvRequest {
             context_tag,
@@ -7030,6 +7489,7 @@ impl GetPixelMapusvRequest {
 }
 impl Request for GetPixelMapusvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7053,22 +7513,21 @@ pub struct GetPixelMapusvReply {
 impl TryParse for GetPixelMapusvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPixelMapusvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7109,6 +7568,29 @@ impl GetPixelMapusvReply {
             .try_into().unwrap()
     }
 }
+impl GetPixelMapusvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetPixelMapusvReply`.
+    ///
+    /// In contrast to parsing the whole `GetPixelMapusvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u16>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetPolygonStipple request
 pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
@@ -7149,8 +7631,8 @@ impl GetPolygonStippleRequest {
         if header.minor_opcode != GET_POLYGON_STIPPLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (lsb_first, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (lsb_first, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "lsb_first")?;
         let _ = remaining;
         Ok(GetPolygonStippleRequest {
             context_tag,
@@ -7160,6 +7642,7 @@ impl GetPolygonStippleRequest {
 }
 impl Request for GetPolygonStippleRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7181,20 +7664,19 @@ pub struct GetPolygonStippleReply {
 impl TryParse for GetPolygonStippleReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPolygonStippleReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7274,8 +7756,8 @@ impl GetStringRequest {
         if header.minor_opcode != GET_STRING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (name, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (name, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "name")?;
         let _ = remaining;
         Ok(GetStringRequest {
             context_tag,
@@ -7285,6 +7767,7 @@ impl GetStringRequest {
 }
 impl Request for GetStringRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7307,22 +7790,21 @@ pub struct GetStringReply {
 impl TryParse for GetStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n.try_to_usize()?), "string")?;
         let string = string.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetStringReply { sequence, length, string };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7408,9 +7890,9 @@ impl GetTexEnvfvRequest {
         if header.minor_opcode != GET_TEX_ENVFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexEnvfvRequest {
             context_tag,
@@ -7421,6 +7903,7 @@ impl GetTexEnvfvRequest {
 }
 impl Request for GetTexEnvfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7444,22 +7927,21 @@ pub struct GetTexEnvfvReply {
 impl TryParse for GetTexEnvfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexEnvfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7500,6 +7982,29 @@ impl GetTexEnvfvReply {
             .try_into().unwrap()
     }
 }
+impl GetTexEnvfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexEnvfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexEnvfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexEnviv request
 pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
@@ -7546,9 +8051,9 @@ impl GetTexEnvivRequest {
         if header.minor_opcode != GET_TEX_ENVIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexEnvivRequest {
             context_tag,
@@ -7559,6 +8064,7 @@ impl GetTexEnvivRequest {
 }
 impl Request for GetTexEnvivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7582,22 +8088,21 @@ pub struct GetTexEnvivReply {
 impl TryParse for GetTexEnvivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexEnvivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7638,6 +8143,29 @@ impl GetTexEnvivReply {
             .try_into().unwrap()
     }
 }
+impl GetTexEnvivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexEnvivReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexEnvivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexGendv request
 pub const GET_TEX_GENDV_REQUEST: u8 = 132;
@@ -7684,9 +8212,9 @@ impl GetTexGendvRequest {
         if header.minor_opcode != GET_TEX_GENDV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (coord, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (coord, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "coord")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexGendvRequest {
             context_tag,
@@ -7697,6 +8225,7 @@ impl GetTexGendvRequest {
 }
 impl Request for GetTexGendvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7720,22 +8249,21 @@ pub struct GetTexGendvReply {
 impl TryParse for GetTexGendvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float64::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float64>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexGendvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7776,6 +8304,29 @@ impl GetTexGendvReply {
             .try_into().unwrap()
     }
 }
+impl GetTexGendvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexGendvReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexGendvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float64>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float64::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexGenfv request
 pub const GET_TEX_GENFV_REQUEST: u8 = 133;
@@ -7822,9 +8373,9 @@ impl GetTexGenfvRequest {
         if header.minor_opcode != GET_TEX_GENFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (coord, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (coord, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "coord")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexGenfvRequest {
             context_tag,
@@ -7835,6 +8386,7 @@ impl GetTexGenfvRequest {
 }
 impl Request for GetTexGenfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7858,22 +8410,21 @@ pub struct GetTexGenfvReply {
 impl TryParse for GetTexGenfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexGenfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7914,6 +8465,29 @@ impl GetTexGenfvReply {
             .try_into().unwrap()
     }
 }
+impl GetTexGenfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexGenfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexGenfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexGeniv request
 pub const GET_TEX_GENIV_REQUEST: u8 = 134;
@@ -7960,9 +8534,9 @@ impl GetTexGenivRequest {
         if header.minor_opcode != GET_TEX_GENIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (coord, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (coord, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "coord")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexGenivRequest {
             context_tag,
@@ -7973,6 +8547,7 @@ impl GetTexGenivRequest {
 }
 impl Request for GetTexGenivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7996,22 +8571,21 @@ pub struct GetTexGenivReply {
 impl TryParse for GetTexGenivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexGenivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8052,6 +8626,29 @@ impl GetTexGenivReply {
             .try_into().unwrap()
     }
 }
+impl GetTexGenivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexGenivReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexGenivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexImage request
 pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
@@ -8116,12 +8713,12 @@ impl GetTexImageRequest {
         if header.minor_opcode != GET_TEX_IMAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (level, remaining) = i32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (level, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "level")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
         let _ = remaining;
         Ok(GetTexImageRequest {
             context_tag,
@@ -8135,6 +8732,7 @@ impl GetTexImageRequest {
 }
 impl Request for GetTexImageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8159,24 +8757,23 @@ pub struct GetTexImageReply {
 impl TryParse for GetTexImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (depth, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "height")?;
+        let (depth, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexImageReply { sequence, width, height, depth, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8266,9 +8863,9 @@ impl GetTexParameterfvRequest {
         if header.minor_opcode != GET_TEX_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexParameterfvRequest {
             context_tag,
@@ -8279,6 +8876,7 @@ impl GetTexParameterfvRequest {
 }
 impl Request for GetTexParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8302,22 +8900,21 @@ pub struct GetTexParameterfvReply {
 impl TryParse for GetTexParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8358,6 +8955,29 @@ impl GetTexParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetTexParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexParameteriv request
 pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
@@ -8404,9 +9024,9 @@ impl GetTexParameterivRequest {
         if header.minor_opcode != GET_TEX_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexParameterivRequest {
             context_tag,
@@ -8417,6 +9037,7 @@ impl GetTexParameterivRequest {
 }
 impl Request for GetTexParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8440,22 +9061,21 @@ pub struct GetTexParameterivReply {
 impl TryParse for GetTexParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8496,6 +9116,29 @@ impl GetTexParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetTexParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexLevelParameterfv request
 pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
@@ -8548,10 +9191,10 @@ impl GetTexLevelParameterfvRequest {
         if header.minor_opcode != GET_TEX_LEVEL_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (level, remaining) = i32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (level, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "level")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexLevelParameterfvRequest {
             context_tag,
@@ -8563,6 +9206,7 @@ impl GetTexLevelParameterfvRequest {
 }
 impl Request for GetTexLevelParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8586,22 +9230,21 @@ pub struct GetTexLevelParameterfvReply {
 impl TryParse for GetTexLevelParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexLevelParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8642,6 +9285,29 @@ impl GetTexLevelParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetTexLevelParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexLevelParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexLevelParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetTexLevelParameteriv request
 pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
@@ -8694,10 +9360,10 @@ impl GetTexLevelParameterivRequest {
         if header.minor_opcode != GET_TEX_LEVEL_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (level, remaining) = i32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (level, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "level")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetTexLevelParameterivRequest {
             context_tag,
@@ -8709,6 +9375,7 @@ impl GetTexLevelParameterivRequest {
 }
 impl Request for GetTexLevelParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8732,22 +9399,21 @@ pub struct GetTexLevelParameterivReply {
 impl TryParse for GetTexLevelParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetTexLevelParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8788,6 +9454,29 @@ impl GetTexLevelParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetTexLevelParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetTexLevelParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetTexLevelParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the IsEnabled request
 pub const IS_ENABLED_REQUEST: u8 = 140;
@@ -8828,8 +9517,8 @@ impl IsEnabledRequest {
         if header.minor_opcode != IS_ENABLED_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (capability, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (capability, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "capability")?;
         let _ = remaining;
         Ok(IsEnabledRequest {
             context_tag,
@@ -8839,6 +9528,7 @@ impl IsEnabledRequest {
 }
 impl Request for IsEnabledRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8861,18 +9551,17 @@ pub struct IsEnabledReply {
 impl TryParse for IsEnabledReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsEnabledReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8948,8 +9637,8 @@ impl IsListRequest {
         if header.minor_opcode != IS_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (list, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (list, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list")?;
         let _ = remaining;
         Ok(IsListRequest {
             context_tag,
@@ -8959,6 +9648,7 @@ impl IsListRequest {
 }
 impl Request for IsListRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8981,18 +9671,17 @@ pub struct IsListReply {
 impl TryParse for IsListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsListReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9062,7 +9751,7 @@ impl FlushRequest {
         if header.minor_opcode != FLUSH_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
         let _ = remaining;
         Ok(FlushRequest {
             context_tag,
@@ -9071,6 +9760,7 @@ impl FlushRequest {
 }
 impl Request for FlushRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9126,9 +9816,9 @@ impl<'input> AreTexturesResidentRequest<'input> {
         if header.minor_opcode != ARE_TEXTURES_RESIDENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (n, remaining) = i32::try_parse(remaining)?;
-        let (textures, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (n, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "n")?;
+        let (textures, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("textures"))?;
         let _ = remaining;
         Ok(AreTexturesResidentRequest {
             context_tag,
@@ -9145,6 +9835,7 @@ impl<'input> AreTexturesResidentRequest<'input> {
 }
 impl<'input> Request for AreTexturesResidentRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9167,20 +9858,19 @@ pub struct AreTexturesResidentReply {
 impl TryParse for AreTexturesResidentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<bool>(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AreTexturesResidentReply { sequence, ret_val, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9221,6 +9911,27 @@ impl AreTexturesResidentReply {
             .try_into().unwrap()
     }
 }
+impl AreTexturesResidentReply {
+    /// Lazily parse the `data` list from the raw bytes of a `AreTexturesResidentReply`.
+    ///
+    /// In contrast to parsing the whole `AreTexturesResidentReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, bool>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the DeleteTextures request
 pub const DELETE_TEXTURES_REQUEST: u8 = 144;
@@ -9266,9 +9977,9 @@ impl<'input> DeleteTexturesRequest<'input> {
         if header.minor_opcode != DELETE_TEXTURES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (n, remaining) = i32::try_parse(remaining)?;
-        let (textures, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (n, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "n")?;
+        let (textures, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("textures"))?;
         let _ = remaining;
         Ok(DeleteTexturesRequest {
             context_tag,
@@ -9285,6 +9996,7 @@ impl<'input> DeleteTexturesRequest<'input> {
 }
 impl<'input> Request for DeleteTexturesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9335,8 +10047,8 @@ impl GenTexturesRequest {
         if header.minor_opcode != GEN_TEXTURES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (n, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (n, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "n")?;
         let _ = remaining;
         Ok(GenTexturesRequest {
             context_tag,
@@ -9346,6 +10058,7 @@ impl GenTexturesRequest {
 }
 impl Request for GenTexturesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9367,19 +10080,18 @@ pub struct GenTexturesReply {
 impl TryParse for GenTexturesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GenTexturesReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9417,6 +10129,26 @@ impl GenTexturesReply {
             .try_into().unwrap()
     }
 }
+impl GenTexturesReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GenTexturesReply`.
+    ///
+    /// In contrast to parsing the whole `GenTexturesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the IsTexture request
 pub const IS_TEXTURE_REQUEST: u8 = 146;
@@ -9457,8 +10189,8 @@ impl IsTextureRequest {
         if header.minor_opcode != IS_TEXTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (texture, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (texture, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "texture")?;
         let _ = remaining;
         Ok(IsTextureRequest {
             context_tag,
@@ -9468,6 +10200,7 @@ impl IsTextureRequest {
 }
 impl Request for IsTextureRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9490,18 +10223,17 @@ pub struct IsTextureReply {
 impl TryParse for IsTextureReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsTextureReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9595,11 +10327,11 @@ impl GetColorTableRequest {
         if header.minor_opcode != GET_COLOR_TABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
         let _ = remaining;
         Ok(GetColorTableRequest {
             context_tag,
@@ -9612,6 +10344,7 @@ impl GetColorTableRequest {
 }
 impl Request for GetColorTableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9634,22 +10367,21 @@ pub struct GetColorTableReply {
 impl TryParse for GetColorTableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetColorTableReply { sequence, width, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9737,9 +10469,9 @@ impl GetColorTableParameterfvRequest {
         if header.minor_opcode != GET_COLOR_TABLE_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetColorTableParameterfvRequest {
             context_tag,
@@ -9750,6 +10482,7 @@ impl GetColorTableParameterfvRequest {
 }
 impl Request for GetColorTableParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9773,22 +10506,21 @@ pub struct GetColorTableParameterfvReply {
 impl TryParse for GetColorTableParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetColorTableParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9829,6 +10561,29 @@ impl GetColorTableParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetColorTableParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetColorTableParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetColorTableParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetColorTableParameteriv request
 pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
@@ -9875,9 +10630,9 @@ impl GetColorTableParameterivRequest {
         if header.minor_opcode != GET_COLOR_TABLE_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetColorTableParameterivRequest {
             context_tag,
@@ -9888,6 +10643,7 @@ impl GetColorTableParameterivRequest {
 }
 impl Request for GetColorTableParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9911,22 +10667,21 @@ pub struct GetColorTableParameterivReply {
 impl TryParse for GetColorTableParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetColorTableParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9967,6 +10722,29 @@ impl GetColorTableParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetColorTableParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetColorTableParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetColorTableParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetConvolutionFilter request
 pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
@@ -10025,11 +10803,11 @@ impl GetConvolutionFilterRequest {
         if header.minor_opcode != GET_CONVOLUTION_FILTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
         let _ = remaining;
         Ok(GetConvolutionFilterRequest {
             context_tag,
@@ -10042,6 +10820,7 @@ impl GetConvolutionFilterRequest {
 }
 impl Request for GetConvolutionFilterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10065,23 +10844,22 @@ pub struct GetConvolutionFilterReply {
 impl TryParse for GetConvolutionFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetConvolutionFilterReply { sequence, width, height, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10170,9 +10948,9 @@ impl GetConvolutionParameterfvRequest {
         if header.minor_opcode != GET_CONVOLUTION_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetConvolutionParameterfvRequest {
             context_tag,
@@ -10183,6 +10961,7 @@ impl GetConvolutionParameterfvRequest {
 }
 impl Request for GetConvolutionParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10206,22 +10985,21 @@ pub struct GetConvolutionParameterfvReply {
 impl TryParse for GetConvolutionParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetConvolutionParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10262,6 +11040,29 @@ impl GetConvolutionParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetConvolutionParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetConvolutionParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetConvolutionParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetConvolutionParameteriv request
 pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
@@ -10308,9 +11109,9 @@ impl GetConvolutionParameterivRequest {
         if header.minor_opcode != GET_CONVOLUTION_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetConvolutionParameterivRequest {
             context_tag,
@@ -10321,6 +11122,7 @@ impl GetConvolutionParameterivRequest {
 }
 impl Request for GetConvolutionParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10344,22 +11146,21 @@ pub struct GetConvolutionParameterivReply {
 impl TryParse for GetConvolutionParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetConvolutionParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10400,6 +11201,29 @@ impl GetConvolutionParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetConvolutionParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetConvolutionParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetConvolutionParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetSeparableFilter request
 pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
@@ -10458,11 +11282,11 @@ impl GetSeparableFilterRequest {
         if header.minor_opcode != GET_SEPARABLE_FILTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
         let _ = remaining;
         Ok(GetSeparableFilterRequest {
             context_tag,
@@ -10475,6 +11299,7 @@ impl GetSeparableFilterRequest {
 }
 impl Request for GetSeparableFilterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10498,23 +11323,22 @@ pub struct GetSeparableFilterReply {
 impl TryParse for GetSeparableFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (row_w, remaining) = i32::try_parse(remaining)?;
-        let (col_h, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (rows_and_cols, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (row_w, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "row_w")?;
+        let (col_h, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "col_h")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (rows_and_cols, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "rows_and_cols")?;
         let rows_and_cols = rows_and_cols.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSeparableFilterReply { sequence, row_w, col_h, rows_and_cols };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10617,12 +11441,12 @@ impl GetHistogramRequest {
         if header.minor_opcode != GET_HISTOGRAM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
-        let (reset, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
+        let (reset, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "reset")?;
         let _ = remaining;
         Ok(GetHistogramRequest {
             context_tag,
@@ -10636,6 +11460,7 @@ impl GetHistogramRequest {
 }
 impl Request for GetHistogramRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10658,22 +11483,21 @@ pub struct GetHistogramReply {
 impl TryParse for GetHistogramReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetHistogramReply { sequence, width, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10761,9 +11585,9 @@ impl GetHistogramParameterfvRequest {
         if header.minor_opcode != GET_HISTOGRAM_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetHistogramParameterfvRequest {
             context_tag,
@@ -10774,6 +11598,7 @@ impl GetHistogramParameterfvRequest {
 }
 impl Request for GetHistogramParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10797,22 +11622,21 @@ pub struct GetHistogramParameterfvReply {
 impl TryParse for GetHistogramParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetHistogramParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10853,6 +11677,29 @@ impl GetHistogramParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetHistogramParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetHistogramParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetHistogramParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetHistogramParameteriv request
 pub const GET_HISTOGRAM_PARAMETERIV_REQUEST: u8 = 156;
@@ -10899,9 +11746,9 @@ impl GetHistogramParameterivRequest {
         if header.minor_opcode != GET_HISTOGRAM_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetHistogramParameterivRequest {
             context_tag,
@@ -10912,6 +11759,7 @@ impl GetHistogramParameterivRequest {
 }
 impl Request for GetHistogramParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10935,22 +11783,21 @@ pub struct GetHistogramParameterivReply {
 impl TryParse for GetHistogramParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetHistogramParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10991,6 +11838,29 @@ impl GetHistogramParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetHistogramParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetHistogramParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetHistogramParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMinmax request
 pub const GET_MINMAX_REQUEST: u8 = 157;
@@ -11051,12 +11921,12 @@ impl GetMinmaxRequest {
         if header.minor_opcode != GET_MINMAX_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
-        let (swap_bytes, remaining) = bool::try_parse(remaining)?;
-        let (reset, remaining) = bool::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (format, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "format")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
+        let (swap_bytes, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "swap_bytes")?;
+        let (reset, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "reset")?;
         let _ = remaining;
         Ok(GetMinmaxRequest {
             context_tag,
@@ -11070,6 +11940,7 @@ impl GetMinmaxRequest {
 }
 impl Request for GetMinmaxRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11091,20 +11962,19 @@ pub struct GetMinmaxReply {
 impl TryParse for GetMinmaxReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMinmaxReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11190,9 +12060,9 @@ impl GetMinmaxParameterfvRequest {
         if header.minor_opcode != GET_MINMAX_PARAMETERFV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetMinmaxParameterfvRequest {
             context_tag,
@@ -11203,6 +12073,7 @@ impl GetMinmaxParameterfvRequest {
 }
 impl Request for GetMinmaxParameterfvRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11226,22 +12097,21 @@ pub struct GetMinmaxParameterfvReply {
 impl TryParse for GetMinmaxParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = Float32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<Float32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMinmaxParameterfvReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11282,6 +12152,29 @@ impl GetMinmaxParameterfvReply {
             .try_into().unwrap()
     }
 }
+impl GetMinmaxParameterfvReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMinmaxParameterfvReply`.
+    ///
+    /// In contrast to parsing the whole `GetMinmaxParameterfvReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Float32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(Float32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetMinmaxParameteriv request
 pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
@@ -11328,9 +12221,9 @@ impl GetMinmaxParameterivRequest {
         if header.minor_opcode != GET_MINMAX_PARAMETERIV_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetMinmaxParameterivRequest {
             context_tag,
@@ -11341,6 +12234,7 @@ impl GetMinmaxParameterivRequest {
 }
 impl Request for GetMinmaxParameterivRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11364,22 +12258,21 @@ pub struct GetMinmaxParameterivReply {
 impl TryParse for GetMinmaxParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMinmaxParameterivReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11420,6 +12313,29 @@ impl GetMinmaxParameterivReply {
             .try_into().unwrap()
     }
 }
+impl GetMinmaxParameterivReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetMinmaxParameterivReply`.
+    ///
+    /// In contrast to parsing the whole `GetMinmaxParameterivReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetCompressedTexImageARB request
 pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
@@ -11466,9 +12382,9 @@ impl GetCompressedTexImageARBRequest {
         if header.minor_opcode != GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (level, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (level, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "level")?;
         let _ = remaining;
         Ok(GetCompressedTexImageARBRequest {
             context_tag,
@@ -11479,6 +12395,7 @@ impl GetCompressedTexImageARBRequest {
 }
 impl Request for GetCompressedTexImageARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11501,22 +12418,21 @@ pub struct GetCompressedTexImageARBReply {
 impl TryParse for GetCompressedTexImageARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (size, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (size, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCompressedTexImageARBReply { sequence, size, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11603,9 +12519,9 @@ impl<'input> DeleteQueriesARBRequest<'input> {
         if header.minor_opcode != DELETE_QUERIES_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (n, remaining) = i32::try_parse(remaining)?;
-        let (ids, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (n, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "n")?;
+        let (ids, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("ids"))?;
         let _ = remaining;
         Ok(DeleteQueriesARBRequest {
             context_tag,
@@ -11622,6 +12538,7 @@ impl<'input> DeleteQueriesARBRequest<'input> {
 }
 impl<'input> Request for DeleteQueriesARBRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11672,8 +12589,8 @@ impl GenQueriesARBRequest {
         if header.minor_opcode != GEN_QUERIES_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (n, remaining) = i32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (n, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "n")?;
         let _ = remaining;
         Ok(GenQueriesARBRequest {
             context_tag,
@@ -11683,6 +12600,7 @@ impl GenQueriesARBRequest {
 }
 impl Request for GenQueriesARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11704,19 +12622,18 @@ pub struct GenQueriesARBReply {
 impl TryParse for GenQueriesARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GenQueriesARBReply { sequence, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11754,6 +12671,26 @@ impl GenQueriesARBReply {
             .try_into().unwrap()
     }
 }
+impl GenQueriesARBReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GenQueriesARBReply`.
+    ///
+    /// In contrast to parsing the whole `GenQueriesARBReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the IsQueryARB request
 pub const IS_QUERY_ARB_REQUEST: u8 = 163;
@@ -11794,8 +12731,8 @@ impl IsQueryARBRequest {
         if header.minor_opcode != IS_QUERY_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
         let _ = remaining;
         Ok(IsQueryARBRequest {
             context_tag,
@@ -11805,6 +12742,7 @@ impl IsQueryARBRequest {
 }
 impl Request for IsQueryARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11827,18 +12765,17 @@ pub struct IsQueryARBReply {
 impl TryParse for IsQueryARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (ret_val, remaining) = Bool32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (ret_val, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "ret_val")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsQueryARBReply { sequence, length, ret_val };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11920,9 +12857,9 @@ impl GetQueryivARBRequest {
         if header.minor_opcode != GET_QUERYIV_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (target, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "target")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetQueryivARBRequest {
             context_tag,
@@ -11933,6 +12870,7 @@ impl GetQueryivARBRequest {
 }
 impl Request for GetQueryivARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11956,22 +12894,21 @@ pub struct GetQueryivARBReply {
 impl TryParse for GetQueryivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetQueryivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12012,6 +12949,29 @@ impl GetQueryivARBReply {
             .try_into().unwrap()
     }
 }
+impl GetQueryivARBReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetQueryivARBReply`.
+    ///
+    /// In contrast to parsing the whole `GetQueryivARBReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetQueryObjectivARB request
 pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
@@ -12058,9 +13018,9 @@ impl GetQueryObjectivARBRequest {
         if header.minor_opcode != GET_QUERY_OBJECTIV_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetQueryObjectivARBRequest {
             context_tag,
@@ -12071,6 +13031,7 @@ impl GetQueryObjectivARBRequest {
 }
 impl Request for GetQueryObjectivARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12094,22 +13055,21 @@ pub struct GetQueryObjectivARBReply {
 impl TryParse for GetQueryObjectivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = i32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<i32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetQueryObjectivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12150,6 +13110,29 @@ impl GetQueryObjectivARBReply {
             .try_into().unwrap()
     }
 }
+impl GetQueryObjectivARBReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetQueryObjectivARBReply`.
+    ///
+    /// In contrast to parsing the whole `GetQueryObjectivARBReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetQueryObjectuivARB request
 pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
@@ -12196,9 +13179,9 @@ impl GetQueryObjectuivARBRequest {
         if header.minor_opcode != GET_QUERY_OBJECTUIV_ARB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_tag, remaining) = ContextTag::try_parse(value)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (pname, remaining) = u32::try_parse(remaining)?;
+        let (context_tag, remaining) = crate::x11_utils::in_field(ContextTag::try_parse(value), "context_tag")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (pname, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pname")?;
         let _ = remaining;
         Ok(GetQueryObjectuivARBRequest {
             context_tag,
@@ -12209,6 +13192,7 @@ impl GetQueryObjectuivARBRequest {
 }
 impl Request for GetQueryObjectuivARBRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12232,22 +13216,21 @@ pub struct GetQueryObjectuivARBReply {
 impl TryParse for GetQueryObjectuivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (n, remaining) = u32::try_parse(remaining)?;
-        let (datum, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, n.try_to_usize()?, Some("data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetQueryObjectuivARBReply { sequence, length, datum, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12288,4 +13271,27 @@ impl GetQueryObjectuivARBReply {
             .try_into().unwrap()
     }
 }
+impl GetQueryObjectuivARBReply {
+    /// Lazily parse the `data` list from the raw bytes of a `GetQueryObjectuivARBReply`.
+    ///
+    /// In contrast to parsing the whole `GetQueryObjectuivARBReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (n, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n")?;
+        let (datum, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "datum")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
@@ -8313,6 +8313,75 @@ pub(crate) fn request_name(extension: Option<&str>, major_opcode: u8, minor_opco
     }
 }
 
+/// Get the `'static` protocol name of an extension that this crate knows about.
+///
+/// Extensions that are disabled via cargo features are unknown. Returns `None` for unknown
+/// extensions.
+pub fn known_extension_name(name: &str) -> Option<&'static str> {
+    match name {
+        bigreq::X11_EXTENSION_NAME => Some(bigreq::X11_EXTENSION_NAME),
+        #[cfg(feature = "composite")]
+        composite::X11_EXTENSION_NAME => Some(composite::X11_EXTENSION_NAME),
+        #[cfg(feature = "damage")]
+        damage::X11_EXTENSION_NAME => Some(damage::X11_EXTENSION_NAME),
+        #[cfg(feature = "dbe")]
+        dbe::X11_EXTENSION_NAME => Some(dbe::X11_EXTENSION_NAME),
+        #[cfg(feature = "dpms")]
+        dpms::X11_EXTENSION_NAME => Some(dpms::X11_EXTENSION_NAME),
+        #[cfg(feature = "dri2")]
+        dri2::X11_EXTENSION_NAME => Some(dri2::X11_EXTENSION_NAME),
+        #[cfg(feature = "dri3")]
+        dri3::X11_EXTENSION_NAME => Some(dri3::X11_EXTENSION_NAME),
+        ge::X11_EXTENSION_NAME => Some(ge::X11_EXTENSION_NAME),
+        #[cfg(feature = "glx")]
+        glx::X11_EXTENSION_NAME => Some(glx::X11_EXTENSION_NAME),
+        #[cfg(feature = "present")]
+        present::X11_EXTENSION_NAME => Some(present::X11_EXTENSION_NAME),
+        #[cfg(feature = "randr")]
+        randr::X11_EXTENSION_NAME => Some(randr::X11_EXTENSION_NAME),
+        #[cfg(feature = "record")]
+        record::X11_EXTENSION_NAME => Some(record::X11_EXTENSION_NAME),
+        #[cfg(feature = "render")]
+        render::X11_EXTENSION_NAME => Some(render::X11_EXTENSION_NAME),
+        #[cfg(feature = "res")]
+        res::X11_EXTENSION_NAME => Some(res::X11_EXTENSION_NAME),
+        #[cfg(feature = "screensaver")]
+        screensaver::X11_EXTENSION_NAME => Some(screensaver::X11_EXTENSION_NAME),
+        #[cfg(feature = "shape")]
+        shape::X11_EXTENSION_NAME => Some(shape::X11_EXTENSION_NAME),
+        #[cfg(feature = "shm")]
+        shm::X11_EXTENSION_NAME => Some(shm::X11_EXTENSION_NAME),
+        #[cfg(feature = "sync")]
+        sync::X11_EXTENSION_NAME => Some(sync::X11_EXTENSION_NAME),
+        xc_misc::X11_EXTENSION_NAME => Some(xc_misc::X11_EXTENSION_NAME),
+        #[cfg(feature = "xevie")]
+        xevie::X11_EXTENSION_NAME => Some(xevie::X11_EXTENSION_NAME),
+        #[cfg(feature = "xf86dri")]
+        xf86dri::X11_EXTENSION_NAME => Some(xf86dri::X11_EXTENSION_NAME),
+        #[cfg(feature = "xf86vidmode")]
+        xf86vidmode::X11_EXTENSION_NAME => Some(xf86vidmode::X11_EXTENSION_NAME),
+        #[cfg(feature = "xfixes")]
+        xfixes::X11_EXTENSION_NAME => Some(xfixes::X11_EXTENSION_NAME),
+        #[cfg(feature = "xinerama")]
+        xinerama::X11_EXTENSION_NAME => Some(xinerama::X11_EXTENSION_NAME),
+        #[cfg(feature = "xinput")]
+        xinput::X11_EXTENSION_NAME => Some(xinput::X11_EXTENSION_NAME),
+        #[cfg(feature = "xkb")]
+        xkb::X11_EXTENSION_NAME => Some(xkb::X11_EXTENSION_NAME),
+        #[cfg(feature = "xprint")]
+        xprint::X11_EXTENSION_NAME => Some(xprint::X11_EXTENSION_NAME),
+        #[cfg(feature = "xselinux")]
+        xselinux::X11_EXTENSION_NAME => Some(xselinux::X11_EXTENSION_NAME),
+        #[cfg(feature = "xtest")]
+        xtest::X11_EXTENSION_NAME => Some(xtest::X11_EXTENSION_NAME),
+        #[cfg(feature = "xv")]
+        xv::X11_EXTENSION_NAME => Some(xv::X11_EXTENSION_NAME),
+        #[cfg(feature = "xvmc")]
+        xvmc::X11_EXTENSION_NAME => Some(xvmc::X11_EXTENSION_NAME),
+        _ => None,
+    }
+}
+
 /// Enumeration of all possible X11 error kinds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
@@ -8601,6 +8670,127 @@ impl ErrorKind {
     }
 }
 
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unknown(code) => write!(f, "Unknown error {}", code),
+            Self::Access => f.write_str("BadAccess"),
+            Self::Alloc => f.write_str("BadAlloc"),
+            Self::Atom => f.write_str("BadAtom"),
+            Self::Colormap => f.write_str("BadColormap"),
+            Self::Cursor => f.write_str("BadCursor"),
+            Self::Drawable => f.write_str("BadDrawable"),
+            Self::Font => f.write_str("BadFont"),
+            Self::GContext => f.write_str("BadGC"),
+            Self::IDChoice => f.write_str("BadIDChoice"),
+            Self::Implementation => f.write_str("BadImplementation"),
+            Self::Length => f.write_str("BadLength"),
+            Self::Match => f.write_str("BadMatch"),
+            Self::Name => f.write_str("BadName"),
+            Self::Pixmap => f.write_str("BadPixmap"),
+            Self::Request => f.write_str("BadRequest"),
+            Self::Value => f.write_str("BadValue"),
+            Self::Window => f.write_str("BadWindow"),
+            #[cfg(feature = "damage")]
+            Self::DamageBadDamage => f.write_str("Damage BadDamage"),
+            #[cfg(feature = "dbe")]
+            Self::DbeBadBuffer => f.write_str("Dbe BadBuffer"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadContext => f.write_str("Glx BadContext"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadContextState => f.write_str("Glx BadContextState"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadContextTag => f.write_str("Glx BadContextTag"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadCurrentDrawable => f.write_str("Glx BadCurrentDrawable"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadCurrentWindow => f.write_str("Glx BadCurrentWindow"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadDrawable => f.write_str("Glx BadDrawable"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadFBConfig => f.write_str("Glx BadFBConfig"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadLargeRequest => f.write_str("Glx BadLargeRequest"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadPbuffer => f.write_str("Glx BadPbuffer"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadPixmap => f.write_str("Glx BadPixmap"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadRenderRequest => f.write_str("Glx BadRenderRequest"),
+            #[cfg(feature = "glx")]
+            Self::GlxBadWindow => f.write_str("Glx BadWindow"),
+            #[cfg(feature = "glx")]
+            Self::GlxGLXBadProfileARB => f.write_str("Glx BadGLXBadProfileARB"),
+            #[cfg(feature = "glx")]
+            Self::GlxUnsupportedPrivateRequest => f.write_str("Glx BadUnsupportedPrivateRequest"),
+            #[cfg(feature = "randr")]
+            Self::RandrBadCrtc => f.write_str("RandR BadCrtc"),
+            #[cfg(feature = "randr")]
+            Self::RandrBadMode => f.write_str("RandR BadMode"),
+            #[cfg(feature = "randr")]
+            Self::RandrBadOutput => f.write_str("RandR BadOutput"),
+            #[cfg(feature = "randr")]
+            Self::RandrBadProvider => f.write_str("RandR BadProvider"),
+            #[cfg(feature = "record")]
+            Self::RecordBadContext => f.write_str("Record BadContext"),
+            #[cfg(feature = "render")]
+            Self::RenderGlyph => f.write_str("Render BadGlyph"),
+            #[cfg(feature = "render")]
+            Self::RenderGlyphSet => f.write_str("Render BadGlyphSet"),
+            #[cfg(feature = "render")]
+            Self::RenderPictFormat => f.write_str("Render BadPictFormat"),
+            #[cfg(feature = "render")]
+            Self::RenderPictOp => f.write_str("Render BadPictOp"),
+            #[cfg(feature = "render")]
+            Self::RenderPicture => f.write_str("Render BadPicture"),
+            #[cfg(feature = "shm")]
+            Self::ShmBadSeg => f.write_str("Shm BadSeg"),
+            #[cfg(feature = "sync")]
+            Self::SyncAlarm => f.write_str("Sync BadAlarm"),
+            #[cfg(feature = "sync")]
+            Self::SyncCounter => f.write_str("Sync BadCounter"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeBadClock => f.write_str("XF86VidMode BadClock"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeBadHTimings => f.write_str("XF86VidMode BadHTimings"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeBadVTimings => f.write_str("XF86VidMode BadVTimings"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeClientNotLocal => f.write_str("XF86VidMode BadClientNotLocal"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeExtensionDisabled => f.write_str("XF86VidMode BadExtensionDisabled"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeModeUnsuitable => f.write_str("XF86VidMode BadModeUnsuitable"),
+            #[cfg(feature = "xf86vidmode")]
+            Self::Xf86vidmodeZoomLocked => f.write_str("XF86VidMode BadZoomLocked"),
+            #[cfg(feature = "xfixes")]
+            Self::XfixesBadRegion => f.write_str("XFixes BadRegion"),
+            #[cfg(feature = "xinput")]
+            Self::XinputClass => f.write_str("Input BadClass"),
+            #[cfg(feature = "xinput")]
+            Self::XinputDevice => f.write_str("Input BadDevice"),
+            #[cfg(feature = "xinput")]
+            Self::XinputDeviceBusy => f.write_str("Input BadDeviceBusy"),
+            #[cfg(feature = "xinput")]
+            Self::XinputEvent => f.write_str("Input BadEvent"),
+            #[cfg(feature = "xinput")]
+            Self::XinputMode => f.write_str("Input BadMode"),
+            #[cfg(feature = "xkb")]
+            Self::XkbKeyboard => f.write_str("xkb BadKeyboard"),
+            #[cfg(feature = "xprint")]
+            Self::XprintBadContext => f.write_str("XPrint BadContext"),
+            #[cfg(feature = "xprint")]
+            Self::XprintBadSequence => f.write_str("XPrint BadSequence"),
+            #[cfg(feature = "xv")]
+            Self::XvBadControl => f.write_str("Xv BadControl"),
+            #[cfg(feature = "xv")]
+            Self::XvBadEncoding => f.write_str("Xv BadEncoding"),
+            #[cfg(feature = "xv")]
+            Self::XvBadPort => f.write_str("Xv BadPort"),
+        }
+    }
+}
+
 
 /// Enumeration of all possible X11 events.
 #[derive(Debug, Clone)]
@@ -9003,18 +9193,22 @@ impl Event {
     }
 
     #[allow(clippy::match_single_binding)]
+    #[cfg_attr(not(any(feature = "present", feature = "xinput")), allow(unused_variables))]
     fn from_generic_event(
         event: &[u8],
         ext_info_provider: &dyn ExtInfoProvider,
     ) -> Result<Self, ParseError> {
-        let ge_event = xproto::GeGenericEvent::try_parse(event)?.0;
+        let (_, remaining) = u8::try_parse(event)?;
+        let (extension, remaining) = u8::try_parse(remaining)?;
+        let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;
+        let (event_type, _) = u16::try_parse(remaining)?;
         let ext_name = ext_info_provider
-            .get_from_major_opcode(ge_event.extension)
+            .get_from_major_opcode(extension)
             .map(|(name, _)| name);
         match ext_name {
             #[cfg(feature = "present")]
             Some(present::X11_EXTENSION_NAME) => {
-                match ge_event.event_type {
+                match event_type {
                     present::COMPLETE_NOTIFY_EVENT => Ok(Self::PresentCompleteNotify(TryParse::try_parse(event)?.0)),
                     present::CONFIGURE_NOTIFY_EVENT => Ok(Self::PresentConfigureNotify(TryParse::try_parse(event)?.0)),
                     present::IDLE_NOTIFY_EVENT => Ok(Self::PresentIdleNotify(TryParse::try_parse(event)?.0)),
@@ -9024,7 +9218,7 @@ impl Event {
             }
             #[cfg(feature = "xinput")]
             Some(xinput::X11_EXTENSION_NAME) => {
-                match ge_event.event_type {
+                match event_type {
                     xinput::BARRIER_HIT_EVENT => Ok(Self::XinputBarrierHit(TryParse::try_parse(event)?.0)),
                     xinput::BARRIER_LEAVE_EVENT => Ok(Self::XinputBarrierLeave(TryParse::try_parse(event)?.0)),
                     xinput::BUTTON_PRESS_EVENT => Ok(Self::XinputButtonPress(TryParse::try_parse(event)?.0)),
@@ -9274,6 +9468,15 @@ impl Event {
         }
     }
 
+    /// Get the full sequence number of this X11 event
+    ///
+    /// Only the low 16 bits of the sequence number are sent on the wire. The missing
+    /// bits are reconstructed from `reference` with [crate::widen_sequence_number].
+    /// `None` is returned for events without a sequence number, i.e. `KeymapNotify`.
+    pub fn sequence_number(&self, reference: crate::SequenceNumber) -> Option<crate::SequenceNumber> {
+        self.wire_sequence_number().map(|wire| crate::widen_sequence_number(wire, reference))
+    }
+
     /// Get the raw response type of this X11 event
     ///
     /// Response types have seven bits in X11. The eight bit indicates whether
@@ -9512,6 +9715,1130 @@ impl Event {
     }
 }
 
+impl TryFrom<Event> for xproto::ButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ButtonPress(event) => Ok(event),
+            Event::ButtonRelease(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::CirculateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::CirculateNotify(event) => Ok(event),
+            Event::CirculateRequest(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ClientMessageEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ClientMessage(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ColormapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ColormapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ConfigureNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ConfigureNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ConfigureRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ConfigureRequest(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::CreateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::CreateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::DestroyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::DestroyNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::EnterNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::EnterNotify(event) => Ok(event),
+            Event::LeaveNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ExposeEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Expose(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::FocusInEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::FocusIn(event) => Ok(event),
+            Event::FocusOut(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GeGenericEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::GeGeneric(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GraphicsExposureEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::GraphicsExposure(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GravityNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::GravityNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::KeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::KeyPress(event) => Ok(event),
+            Event::KeyRelease(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::KeymapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::KeymapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::MapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MapRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::MapRequest(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MappingNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::MappingNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MotionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::MotionNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::NoExposureEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::NoExposure(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::PropertyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::PropertyNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ReparentNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ReparentNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ResizeRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::ResizeRequest(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionClearEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::SelectionClear(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::SelectionNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::SelectionRequest(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::UnmapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::UnmapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::VisibilityNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::VisibilityNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "damage")]
+impl TryFrom<Event> for damage::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "dri2")]
+impl TryFrom<Event> for dri2::BufferSwapCompleteEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "dri2")]
+impl TryFrom<Event> for dri2::InvalidateBuffersEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "glx")]
+impl TryFrom<Event> for glx::BufferSwapCompleteEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "glx")]
+impl TryFrom<Event> for glx::PbufferClobberEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::CompleteNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::ConfigureNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::GenericEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::IdleNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::RedirectNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "randr")]
+impl TryFrom<Event> for randr::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "randr")]
+impl TryFrom<Event> for randr::ScreenChangeNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "screensaver")]
+impl TryFrom<Event> for screensaver::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "shape")]
+impl TryFrom<Event> for shape::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "shm")]
+impl TryFrom<Event> for shm::CompletionEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl TryFrom<Event> for sync::AlarmNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl TryFrom<Event> for sync::CounterNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xfixes")]
+impl TryFrom<Event> for xfixes::CursorNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xfixes")]
+impl TryFrom<Event> for xfixes::SelectionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::BarrierHitEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::ButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::ChangeDeviceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceKeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceButtonStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceChangedEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceFocusInEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceKeyStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceMappingNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DevicePresenceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DevicePropertyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceValuatorEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::EnterEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::GesturePinchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::GestureSwipeBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::HierarchyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::KeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::PropertyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawKeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawTouchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::TouchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(event) => Ok(event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::TouchOwnershipEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::AccessXNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ActionMessageEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::BellNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::CompatMapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ControlsNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ExtensionDeviceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::IndicatorMapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::IndicatorStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::MapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::NamesNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::NewKeyboardNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::StateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xprint")]
+impl TryFrom<Event> for xprint::AttributNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xprint")]
+impl TryFrom<Event> for xprint::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xv")]
+impl TryFrom<Event> for xv::PortNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xv")]
+impl TryFrom<Event> for xv::VideoNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(event) => Ok(event),
+            event => Err(event),
+        }
+    }
+}
+
+/// Get the number of event codes that an extension that this crate knows about uses.
+///
+/// The event codes of an extension start at its `first_event`. Events that are sent via the
+/// generic event extension do not use an event code of their own and are not counted.
+/// Extensions that are disabled via cargo features are unknown. Returns `None` for unknown
+/// extensions.
+pub fn extension_event_count(name: &str) -> Option<u8> {
+    match name {
+        bigreq::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "composite")]
+        composite::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "damage")]
+        damage::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "dbe")]
+        dbe::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "dpms")]
+        dpms::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "dri2")]
+        dri2::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "dri3")]
+        dri3::X11_EXTENSION_NAME => Some(0),
+        ge::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "glx")]
+        glx::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "present")]
+        present::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "randr")]
+        randr::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "record")]
+        record::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "render")]
+        render::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "res")]
+        res::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "screensaver")]
+        screensaver::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "shape")]
+        shape::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "shm")]
+        shm::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "sync")]
+        sync::X11_EXTENSION_NAME => Some(2),
+        xc_misc::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xevie")]
+        xevie::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xf86dri")]
+        xf86dri::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xf86vidmode")]
+        xf86vidmode::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xfixes")]
+        xfixes::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "xinerama")]
+        xinerama::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xinput")]
+        xinput::X11_EXTENSION_NAME => Some(17),
+        #[cfg(feature = "xkb")]
+        xkb::X11_EXTENSION_NAME => Some(1),
+        #[cfg(feature = "xprint")]
+        xprint::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "xselinux")]
+        xselinux::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xtest")]
+        xtest::X11_EXTENSION_NAME => Some(0),
+        #[cfg(feature = "xv")]
+        xv::X11_EXTENSION_NAME => Some(2),
+        #[cfg(feature = "xvmc")]
+        xvmc::X11_EXTENSION_NAME => Some(0),
+        _ => None,
+    }
+}
+
 /// Get the response type out of the raw bytes of an X11 error or event.
 fn response_type(raw_bytes: &[u8]) -> Result<u8, ParseError> {
     raw_bytes.first()
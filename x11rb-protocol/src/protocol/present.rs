@@ -157,7 +157,7 @@ impl core::fmt::Debug for EventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(EventMask, u32);
+bitmask_binop!(EventMask, u32, [CONFIGURE_NOTIFY, COMPLETE_NOTIFY, IDLE_NOTIFY, REDIRECT_NOTIFY]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -223,7 +223,7 @@ impl core::fmt::Debug for Option  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Option, u8);
+bitmask_binop!(Option, u8, [ASYNC, COPY, UST, SUBOPTIMAL]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -287,7 +287,7 @@ impl core::fmt::Debug for Capability  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Capability, u8);
+bitmask_binop!(Capability, u8, [ASYNC, FENCE, UST]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -419,8 +419,8 @@ pub struct Notify {
 }
 impl TryParse for Notify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
         let result = Notify { window, serial };
         Ok((result, remaining))
     }
@@ -487,8 +487,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -498,6 +498,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: core::option::Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -521,19 +522,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -704,21 +704,21 @@ impl<'input> PixmapRequest<'input> {
         if header.minor_opcode != PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (valid, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (update, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
-        let (target_crtc, remaining) = randr::Crtc::try_parse(remaining)?;
-        let (wait_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (idle_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (options, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (target_msc, remaining) = u64::try_parse(remaining)?;
-        let (divisor, remaining) = u64::try_parse(remaining)?;
-        let (remainder, remaining) = u64::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
+        let (valid, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "valid")?;
+        let (update, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "update")?;
+        let (x_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_off")?;
+        let (y_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_off")?;
+        let (target_crtc, remaining) = crate::x11_utils::in_field(randr::Crtc::try_parse(remaining), "target_crtc")?;
+        let (wait_fence, remaining) = crate::x11_utils::in_field(sync::Fence::try_parse(remaining), "wait_fence")?;
+        let (idle_fence, remaining) = crate::x11_utils::in_field(sync::Fence::try_parse(remaining), "idle_fence")?;
+        let (options, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "options")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (target_msc, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "target_msc")?;
+        let (divisor, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "divisor")?;
+        let (remainder, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "remainder")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut notifies = Vec::new();
@@ -769,6 +769,7 @@ impl<'input> PixmapRequest<'input> {
 }
 impl<'input> Request for PixmapRequest<'input> {
     const EXTENSION_NAME: core::option::Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -853,12 +854,12 @@ impl NotifyMSCRequest {
         if header.minor_opcode != NOTIFY_MSC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (target_msc, remaining) = u64::try_parse(remaining)?;
-        let (divisor, remaining) = u64::try_parse(remaining)?;
-        let (remainder, remaining) = u64::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (target_msc, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "target_msc")?;
+        let (divisor, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "divisor")?;
+        let (remainder, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "remainder")?;
         let _ = remaining;
         Ok(NotifyMSCRequest {
             window,
@@ -871,6 +872,7 @@ impl NotifyMSCRequest {
 }
 impl Request for NotifyMSCRequest {
     const EXTENSION_NAME: core::option::Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -929,9 +931,9 @@ impl SelectInputRequest {
         if header.minor_opcode != SELECT_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (eid, remaining) = Event::try_parse(value)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (eid, remaining) = crate::x11_utils::in_field(Event::try_parse(value), "eid")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
         let _ = remaining;
         Ok(SelectInputRequest {
@@ -943,6 +945,7 @@ impl SelectInputRequest {
 }
 impl Request for SelectInputRequest {
     const EXTENSION_NAME: core::option::Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -987,7 +990,7 @@ impl QueryCapabilitiesRequest {
         if header.minor_opcode != QUERY_CAPABILITIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (target, remaining) = u32::try_parse(value)?;
+        let (target, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "target")?;
         let _ = remaining;
         Ok(QueryCapabilitiesRequest {
             target,
@@ -996,6 +999,7 @@ impl QueryCapabilitiesRequest {
 }
 impl Request for QueryCapabilitiesRequest {
     const EXTENSION_NAME: core::option::Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1018,18 +1022,17 @@ pub struct QueryCapabilitiesReply {
 impl TryParse for QueryCapabilitiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (capabilities, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (capabilities, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "capabilities")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryCapabilitiesReply { sequence, length, capabilities };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1081,13 +1084,13 @@ pub struct GenericEvent {
 impl TryParse for GenericEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (evtype, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (evtype, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "evtype")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (event, remaining) = crate::x11_utils::in_field(Event::try_parse(remaining), "event")?;
         let result = GenericEvent { response_type, extension, sequence, length, evtype, event };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -1210,27 +1213,26 @@ pub struct ConfigureNotifyEvent {
 impl TryParse for ConfigureNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (off_x, remaining) = i16::try_parse(remaining)?;
-        let (off_y, remaining) = i16::try_parse(remaining)?;
-        let (pixmap_width, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_height, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_flags, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (event, remaining) = crate::x11_utils::in_field(Event::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (off_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "off_x")?;
+        let (off_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "off_y")?;
+        let (pixmap_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixmap_width")?;
+        let (pixmap_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixmap_height")?;
+        let (pixmap_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixmap_flags")?;
         let result = ConfigureNotifyEvent { response_type, extension, sequence, length, event_type, event, window, x, y, width, height, off_x, off_y, pixmap_width, pixmap_height, pixmap_flags };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1339,24 +1341,23 @@ pub struct CompleteNotifyEvent {
 impl TryParse for CompleteNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (ust, remaining) = u64::try_parse(remaining)?;
-        let (msc, remaining) = u64::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (kind, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "kind")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (event, remaining) = crate::x11_utils::in_field(Event::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
+        let (ust, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "ust")?;
+        let (msc, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "msc")?;
         let kind = kind.into();
         let mode = mode.into();
         let result = CompleteNotifyEvent { response_type, extension, sequence, length, event_type, kind, mode, event, window, serial, ust, msc };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1454,21 +1455,20 @@ pub struct IdleNotifyEvent {
 impl TryParse for IdleNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (idle_fence, remaining) = sync::Fence::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (event, remaining) = crate::x11_utils::in_field(Event::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
+        let (idle_fence, remaining) = crate::x11_utils::in_field(sync::Fence::try_parse(remaining), "idle_fence")?;
         let result = IdleNotifyEvent { response_type, extension, sequence, length, event_type, event, window, serial, pixmap, idle_fence };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1570,32 +1570,32 @@ pub struct RedirectNotifyEvent {
 impl TryParse for RedirectNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (update_window, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (event, remaining) = Event::try_parse(remaining)?;
-        let (event_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
-        let (serial, remaining) = u32::try_parse(remaining)?;
-        let (valid_region, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (update_region, remaining) = xfixes::Region::try_parse(remaining)?;
-        let (valid_rect, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (update_rect, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
-        let (target_crtc, remaining) = randr::Crtc::try_parse(remaining)?;
-        let (wait_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (idle_fence, remaining) = sync::Fence::try_parse(remaining)?;
-        let (options, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (target_msc, remaining) = u64::try_parse(remaining)?;
-        let (divisor, remaining) = u64::try_parse(remaining)?;
-        let (remainder, remaining) = u64::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (update_window, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "update_window")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (event, remaining) = crate::x11_utils::in_field(Event::try_parse(remaining), "event")?;
+        let (event_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event_window")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "pixmap")?;
+        let (serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "serial")?;
+        let (valid_region, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "valid_region")?;
+        let (update_region, remaining) = crate::x11_utils::in_field(xfixes::Region::try_parse(remaining), "update_region")?;
+        let (valid_rect, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "valid_rect")?;
+        let (update_rect, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "update_rect")?;
+        let (x_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_off")?;
+        let (y_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_off")?;
+        let (target_crtc, remaining) = crate::x11_utils::in_field(randr::Crtc::try_parse(remaining), "target_crtc")?;
+        let (wait_fence, remaining) = crate::x11_utils::in_field(sync::Fence::try_parse(remaining), "wait_fence")?;
+        let (idle_fence, remaining) = crate::x11_utils::in_field(sync::Fence::try_parse(remaining), "idle_fence")?;
+        let (options, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "options")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (target_msc, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "target_msc")?;
+        let (divisor, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "divisor")?;
+        let (remainder, remaining) = crate::x11_utils::in_field(u64::try_parse(remaining), "remainder")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut notifies = Vec::new();
@@ -1606,8 +1606,7 @@ impl TryParse for RedirectNotifyEvent {
         }
         let result = RedirectNotifyEvent { response_type, extension, sequence, length, event_type, update_window, event, event_window, window, pixmap, serial, valid_region, update_region, valid_rect, update_rect, x_off, y_off, target_crtc, wait_fence, idle_fence, options, target_msc, divisor, remainder, notifies };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -120,7 +120,7 @@ impl core::fmt::Debug for Rotation  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Rotation, u16);
+bitmask_binop!(Rotation, u16, [ROTATE0, ROTATE90, ROTATE180, ROTATE270, REFLECT_X, REFLECT_Y]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -132,10 +132,10 @@ pub struct ScreenSize {
 }
 impl TryParse for ScreenSize {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mwidth, remaining) = u16::try_parse(remaining)?;
-        let (mheight, remaining) = u16::try_parse(remaining)?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (mwidth, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mwidth")?;
+        let (mheight, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mheight")?;
         let result = ScreenSize { width, height, mwidth, mheight };
         Ok((result, remaining))
     }
@@ -174,8 +174,8 @@ pub struct RefreshRates {
 }
 impl TryParse for RefreshRates {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (n_rates, remaining) = u16::try_parse(remaining)?;
-        let (rates, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n_rates.try_to_usize()?)?;
+        let (n_rates, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_rates")?;
+        let (rates, remaining) = crate::x11_utils::parse_list::<u16>(remaining, n_rates.try_to_usize()?, Some("rates"))?;
         let result = RefreshRates { rates };
         Ok((result, remaining))
     }
@@ -248,8 +248,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u32::try_parse(value)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -259,6 +259,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -282,20 +283,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -477,14 +477,14 @@ impl SetScreenConfigRequest {
         if header.minor_opcode != SET_SCREEN_CONFIG_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (size_id, remaining) = u16::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (size_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size_id")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
         let rotation = rotation.into();
-        let (rate, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (rate, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rate")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(SetScreenConfigRequest {
             window,
@@ -498,6 +498,7 @@ impl SetScreenConfigRequest {
 }
 impl Request for SetScreenConfigRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -524,24 +525,23 @@ pub struct SetScreenConfigReply {
 impl TryParse for SetScreenConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (new_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (new_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "new_timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (subpixel_order, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "subpixel_order")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let subpixel_order = subpixel_order.into();
         let result = SetScreenConfigReply { status, sequence, length, new_timestamp, config_timestamp, root, subpixel_order };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -670,7 +670,7 @@ impl core::fmt::Debug for NotifyMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(NotifyMask, u16);
+bitmask_binop!(NotifyMask, u16, [SCREEN_CHANGE, CRTC_CHANGE, OUTPUT_CHANGE, OUTPUT_PROPERTY, PROVIDER_CHANGE, PROVIDER_PROPERTY, RESOURCE_CHANGE, LEASE]);
 
 /// Opcode for the SelectInput request
 pub const SELECT_INPUT_REQUEST: u8 = 4;
@@ -711,10 +711,10 @@ impl SelectInputRequest {
         if header.minor_opcode != SELECT_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (enable, remaining) = u16::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (enable, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "enable")?;
         let enable = enable.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(SelectInputRequest {
             window,
@@ -724,6 +724,7 @@ impl SelectInputRequest {
 }
 impl Request for SelectInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -768,7 +769,7 @@ impl GetScreenInfoRequest {
         if header.minor_opcode != GET_SCREEN_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetScreenInfoRequest {
             window,
@@ -777,6 +778,7 @@ impl GetScreenInfoRequest {
 }
 impl Request for GetScreenInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -808,30 +810,29 @@ pub struct GetScreenInfoReply {
 impl TryParse for GetScreenInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (rotations, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (n_sizes, remaining) = u16::try_parse(remaining)?;
-        let (size_id, remaining) = u16::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (rate, remaining) = u16::try_parse(remaining)?;
-        let (n_info, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (sizes, remaining) = crate::x11_utils::parse_list::<ScreenSize>(remaining, n_sizes.try_to_usize()?)?;
-        let (rates, remaining) = crate::x11_utils::parse_list::<RefreshRates>(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (rotations, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "rotations")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (n_sizes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_sizes")?;
+        let (size_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size_id")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
+        let (rate, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rate")?;
+        let (n_info, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_info")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list::<ScreenSize>(remaining, n_sizes.try_to_usize()?, Some("sizes"))?;
+        let (rates, remaining) = crate::x11_utils::parse_list::<RefreshRates>(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("rates"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let rotations = rotations.into();
         let rotation = rotation.into();
         let result = GetScreenInfoReply { rotations, sequence, length, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -913,7 +914,7 @@ impl GetScreenSizeRangeRequest {
         if header.minor_opcode != GET_SCREEN_SIZE_RANGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetScreenSizeRangeRequest {
             window,
@@ -922,6 +923,7 @@ impl GetScreenSizeRangeRequest {
 }
 impl Request for GetScreenSizeRangeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -947,22 +949,21 @@ pub struct GetScreenSizeRangeReply {
 impl TryParse for GetScreenSizeRangeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_width, remaining) = u16::try_parse(remaining)?;
-        let (min_height, remaining) = u16::try_parse(remaining)?;
-        let (max_width, remaining) = u16::try_parse(remaining)?;
-        let (max_height, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (min_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "min_width")?;
+        let (min_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "min_height")?;
+        let (max_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_width")?;
+        let (max_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetScreenSizeRangeReply { sequence, length, min_width, min_height, max_width, max_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1079,11 +1080,11 @@ impl SetScreenSizeRequest {
         if header.minor_opcode != SET_SCREEN_SIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mm_width, remaining) = u32::try_parse(remaining)?;
-        let (mm_height, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (mm_width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mm_width")?;
+        let (mm_height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mm_height")?;
         let _ = remaining;
         Ok(SetScreenSizeRequest {
             window,
@@ -1096,6 +1097,7 @@ impl SetScreenSizeRequest {
 }
 impl Request for SetScreenSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1177,7 +1179,7 @@ impl core::fmt::Debug for ModeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ModeFlag, u32);
+bitmask_binop!(ModeFlag, u32, [HSYNC_POSITIVE, HSYNC_NEGATIVE, VSYNC_POSITIVE, VSYNC_NEGATIVE, INTERLACE, DOUBLE_SCAN, CSYNC, CSYNC_POSITIVE, CSYNC_NEGATIVE, HSKEW_PRESENT, BCAST, PIXEL_MULTIPLEX, DOUBLE_CLOCK, HALVE_CLOCK]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1198,19 +1200,19 @@ pub struct ModeInfo {
 }
 impl TryParse for ModeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (dot_clock, remaining) = u32::try_parse(remaining)?;
-        let (hsync_start, remaining) = u16::try_parse(remaining)?;
-        let (hsync_end, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vsync_start, remaining) = u16::try_parse(remaining)?;
-        let (vsync_end, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (mode_flags, remaining) = u32::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (dot_clock, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dot_clock")?;
+        let (hsync_start, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsync_start")?;
+        let (hsync_end, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsync_end")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vsync_start, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsync_start")?;
+        let (vsync_end, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsync_end")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (mode_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mode_flags")?;
         let mode_flags = mode_flags.into();
         let result = ModeInfo { id, width, height, dot_clock, hsync_start, hsync_end, htotal, hskew, vsync_start, vsync_end, vtotal, name_len, mode_flags };
         Ok((result, remaining))
@@ -1318,7 +1320,7 @@ impl GetScreenResourcesRequest {
         if header.minor_opcode != GET_SCREEN_RESOURCES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetScreenResourcesRequest {
             window,
@@ -1327,6 +1329,7 @@ impl GetScreenResourcesRequest {
 }
 impl Request for GetScreenResourcesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1354,29 +1357,28 @@ pub struct GetScreenResourcesReply {
 impl TryParse for GetScreenResourcesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (names_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_to_usize()?)?;
-        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_modes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modes")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?, Some("crtcs"))?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?, Some("outputs"))?;
+        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_to_usize()?, Some("modes"))?;
+        let (names, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?), "names")?;
         let names = names.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetScreenResourcesReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1465,6 +1467,32 @@ impl GetScreenResourcesReply {
             .try_into().unwrap()
     }
 }
+impl GetScreenResourcesReply {
+    /// Lazily parse the `crtcs` list from the raw bytes of a `GetScreenResourcesReply`.
+    ///
+    /// In contrast to parsing the whole `GetScreenResourcesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn crtcs_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Crtc>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_modes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modes")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_crtcs.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1566,8 +1594,8 @@ impl GetOutputInfoRequest {
         if header.minor_opcode != GET_OUTPUT_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
         let _ = remaining;
         Ok(GetOutputInfoRequest {
             output,
@@ -1577,6 +1605,7 @@ impl GetOutputInfoRequest {
 }
 impl Request for GetOutputInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1610,36 +1639,35 @@ pub struct GetOutputInfoReply {
 impl TryParse for GetOutputInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mm_width, remaining) = u32::try_parse(remaining)?;
-        let (mm_height, remaining) = u32::try_parse(remaining)?;
-        let (connection, remaining) = u8::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u8::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (num_preferred, remaining) = u16::try_parse(remaining)?;
-        let (num_clones, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<Mode>(remaining, num_modes.try_to_usize()?)?;
-        let (clones, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_clones.try_to_usize()?)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(remaining), "crtc")?;
+        let (mm_width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mm_width")?;
+        let (mm_height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mm_height")?;
+        let (connection, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "connection")?;
+        let (subpixel_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "subpixel_order")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_modes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modes")?;
+        let (num_preferred, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_preferred")?;
+        let (num_clones, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_clones")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?, Some("crtcs"))?;
+        let (modes, remaining) = crate::x11_utils::parse_list::<Mode>(remaining, num_modes.try_to_usize()?, Some("modes"))?;
+        let (clones, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_clones.try_to_usize()?, Some("clones"))?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let connection = connection.into();
         let subpixel_order = subpixel_order.into();
         let result = GetOutputInfoReply { status, sequence, length, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1766,7 +1794,7 @@ impl ListOutputPropertiesRequest {
         if header.minor_opcode != LIST_OUTPUT_PROPERTIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
         let _ = remaining;
         Ok(ListOutputPropertiesRequest {
             output,
@@ -1775,6 +1803,7 @@ impl ListOutputPropertiesRequest {
 }
 impl Request for ListOutputPropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1797,20 +1826,19 @@ pub struct ListOutputPropertiesReply {
 impl TryParse for ListOutputPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?, Some("atoms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListOutputPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1849,6 +1877,27 @@ impl ListOutputPropertiesReply {
             .try_into().unwrap()
     }
 }
+impl ListOutputPropertiesReply {
+    /// Lazily parse the `atoms` list from the raw bytes of a `ListOutputPropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `ListOutputPropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn atoms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Atom>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_atoms.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryOutputProperty request
 pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
@@ -1889,8 +1938,8 @@ impl QueryOutputPropertyRequest {
         if header.minor_opcode != QUERY_OUTPUT_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(QueryOutputPropertyRequest {
             output,
@@ -1900,6 +1949,7 @@ impl QueryOutputPropertyRequest {
 }
 impl Request for QueryOutputPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1924,22 +1974,21 @@ pub struct QueryOutputPropertyReply {
 impl TryParse for QueryOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let (immutable, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
-        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let (immutable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "immutable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 21)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_to_usize()?, Some("valid_values"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryOutputPropertyReply { sequence, pending, range, immutable, valid_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1980,6 +2029,29 @@ impl QueryOutputPropertyReply {
             .try_into().unwrap()
     }
 }
+impl QueryOutputPropertyReply {
+    /// Lazily parse the `valid_values` list from the raw bytes of a `QueryOutputPropertyReply`.
+    ///
+    /// In contrast to parsing the whole `QueryOutputPropertyReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn valid_values_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let (immutable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "immutable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 21)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ConfigureOutputProperty request
 pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
@@ -2033,11 +2105,11 @@ impl<'input> ConfigureOutputPropertyRequest<'input> {
         if header.minor_opcode != CONFIGURE_OUTPUT_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut values = Vec::new();
@@ -2068,6 +2140,7 @@ impl<'input> ConfigureOutputPropertyRequest<'input> {
 }
 impl<'input> Request for ConfigureOutputPropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2143,15 +2216,15 @@ impl<'input> ChangeOutputPropertyRequest<'input> {
         if header.minor_opcode != CHANGE_OUTPUT_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num_units, remaining) = u32::try_parse(remaining)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(num_units).checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num_units, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_units")?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(num_units).checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let _ = remaining;
         Ok(ChangeOutputPropertyRequest {
             output,
@@ -2178,6 +2251,7 @@ impl<'input> ChangeOutputPropertyRequest<'input> {
 }
 impl<'input> Request for ChangeOutputPropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2228,8 +2302,8 @@ impl DeleteOutputPropertyRequest {
         if header.minor_opcode != DELETE_OUTPUT_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(DeleteOutputPropertyRequest {
             output,
@@ -2239,6 +2313,7 @@ impl DeleteOutputPropertyRequest {
 }
 impl Request for DeleteOutputPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2315,14 +2390,14 @@ impl GetOutputPropertyRequest {
         if header.minor_opcode != GET_OUTPUT_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (long_offset, remaining) = u32::try_parse(remaining)?;
-        let (long_length, remaining) = u32::try_parse(remaining)?;
-        let (delete, remaining) = bool::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (long_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_offset")?;
+        let (long_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_length")?;
+        let (delete, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "delete")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetOutputPropertyRequest {
             output,
@@ -2337,6 +2412,7 @@ impl GetOutputPropertyRequest {
 }
 impl Request for GetOutputPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2363,23 +2439,22 @@ pub struct GetOutputPropertyReply {
 impl TryParse for GetOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (bytes_after, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_after")?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetOutputPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2477,8 +2552,8 @@ impl<'input> CreateModeRequest<'input> {
         if header.minor_opcode != CREATE_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (mode_info, remaining) = ModeInfo::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (mode_info, remaining) = crate::x11_utils::in_field(ModeInfo::try_parse(remaining), "mode_info")?;
         let (name, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(CreateModeRequest {
@@ -2498,6 +2573,7 @@ impl<'input> CreateModeRequest<'input> {
 }
 impl<'input> Request for CreateModeRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2520,19 +2596,18 @@ pub struct CreateModeReply {
 impl TryParse for CreateModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateModeReply { sequence, length, mode };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2623,7 +2698,7 @@ impl DestroyModeRequest {
         if header.minor_opcode != DESTROY_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (mode, remaining) = Mode::try_parse(value)?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(value), "mode")?;
         let _ = remaining;
         Ok(DestroyModeRequest {
             mode,
@@ -2632,6 +2707,7 @@ impl DestroyModeRequest {
 }
 impl Request for DestroyModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2682,8 +2758,8 @@ impl AddOutputModeRequest {
         if header.minor_opcode != ADD_OUTPUT_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
         let _ = remaining;
         Ok(AddOutputModeRequest {
             output,
@@ -2693,6 +2769,7 @@ impl AddOutputModeRequest {
 }
 impl Request for AddOutputModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2743,8 +2820,8 @@ impl DeleteOutputModeRequest {
         if header.minor_opcode != DELETE_OUTPUT_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output, remaining) = Output::try_parse(value)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(value), "output")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
         let _ = remaining;
         Ok(DeleteOutputModeRequest {
             output,
@@ -2754,6 +2831,7 @@ impl DeleteOutputModeRequest {
 }
 impl Request for DeleteOutputModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2804,8 +2882,8 @@ impl GetCrtcInfoRequest {
         if header.minor_opcode != GET_CRTC_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
         let _ = remaining;
         Ok(GetCrtcInfoRequest {
             crtc,
@@ -2815,6 +2893,7 @@ impl GetCrtcInfoRequest {
 }
 impl Request for GetCrtcInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2847,32 +2926,31 @@ pub struct GetCrtcInfoReply {
 impl TryParse for GetCrtcInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (rotations, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_possible_outputs, remaining) = u16::try_parse(remaining)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?)?;
-        let (possible, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_possible_outputs.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
+        let (rotations, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotations")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_possible_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_possible_outputs")?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?, Some("outputs"))?;
+        let (possible, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_possible_outputs.try_to_usize()?, Some("possible"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let rotation = rotation.into();
         let rotations = rotations.into();
         let result = GetCrtcInfoReply { status, sequence, length, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3005,15 +3083,15 @@ impl<'input> SetCrtcConfigRequest<'input> {
         if header.minor_opcode != SET_CRTC_CONFIG_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
         let rotation = rotation.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut outputs = Vec::new();
@@ -3050,6 +3128,7 @@ impl<'input> SetCrtcConfigRequest<'input> {
 }
 impl<'input> Request for SetCrtcConfigRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3073,20 +3152,19 @@ pub struct SetCrtcConfigReply {
 impl TryParse for SetCrtcConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetCrtcConfigReply { status, sequence, length, timestamp };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3178,7 +3256,7 @@ impl GetCrtcGammaSizeRequest {
         if header.minor_opcode != GET_CRTC_GAMMA_SIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
         let _ = remaining;
         Ok(GetCrtcGammaSizeRequest {
             crtc,
@@ -3187,6 +3265,7 @@ impl GetCrtcGammaSizeRequest {
 }
 impl Request for GetCrtcGammaSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3209,19 +3288,18 @@ pub struct GetCrtcGammaSizeReply {
 impl TryParse for GetCrtcGammaSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCrtcGammaSizeReply { sequence, length, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3312,7 +3390,7 @@ impl GetCrtcGammaRequest {
         if header.minor_opcode != GET_CRTC_GAMMA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
         let _ = remaining;
         Ok(GetCrtcGammaRequest {
             crtc,
@@ -3321,6 +3399,7 @@ impl GetCrtcGammaRequest {
 }
 impl Request for GetCrtcGammaRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3345,22 +3424,21 @@ pub struct GetCrtcGammaReply {
 impl TryParse for GetCrtcGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("red"))?;
+        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("green"))?;
+        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("blue"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCrtcGammaReply { sequence, length, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3403,6 +3481,27 @@ impl GetCrtcGammaReply {
             .try_into().unwrap()
     }
 }
+impl GetCrtcGammaReply {
+    /// Lazily parse the `red` list from the raw bytes of a `GetCrtcGammaReply`.
+    ///
+    /// In contrast to parsing the whole `GetCrtcGammaReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn red_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u16>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = size.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetCrtcGamma request
 pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
@@ -3456,12 +3555,12 @@ impl<'input> SetCrtcGammaRequest<'input> {
         if header.minor_opcode != SET_CRTC_GAMMA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("red"))?;
+        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("green"))?;
+        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, size.try_to_usize()?, Some("blue"))?;
         let _ = remaining;
         Ok(SetCrtcGammaRequest {
             crtc,
@@ -3482,6 +3581,7 @@ impl<'input> SetCrtcGammaRequest<'input> {
 }
 impl<'input> Request for SetCrtcGammaRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3526,7 +3626,7 @@ impl GetScreenResourcesCurrentRequest {
         if header.minor_opcode != GET_SCREEN_RESOURCES_CURRENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetScreenResourcesCurrentRequest {
             window,
@@ -3535,6 +3635,7 @@ impl GetScreenResourcesCurrentRequest {
 }
 impl Request for GetScreenResourcesCurrentRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3562,29 +3663,28 @@ pub struct GetScreenResourcesCurrentReply {
 impl TryParse for GetScreenResourcesCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_modes, remaining) = u16::try_parse(remaining)?;
-        let (names_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?)?;
-        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_to_usize()?)?;
-        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_modes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modes")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?, Some("crtcs"))?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?, Some("outputs"))?;
+        let (modes, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, num_modes.try_to_usize()?, Some("modes"))?;
+        let (names, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, names_len.try_to_usize()?), "names")?;
         let names = names.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetScreenResourcesCurrentReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3673,6 +3773,32 @@ impl GetScreenResourcesCurrentReply {
             .try_into().unwrap()
     }
 }
+impl GetScreenResourcesCurrentReply {
+    /// Lazily parse the `crtcs` list from the raw bytes of a `GetScreenResourcesCurrentReply`.
+    ///
+    /// In contrast to parsing the whole `GetScreenResourcesCurrentReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn crtcs_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Crtc>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_modes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modes")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_crtcs.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3736,7 +3862,7 @@ impl core::fmt::Debug for Transform  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Transform, u8);
+bitmask_binop!(Transform, u8, [UNIT, SCALE_UP, SCALE_DOWN, PROJECTIVE]);
 
 /// Opcode for the SetCrtcTransform request
 pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
@@ -3824,15 +3950,15 @@ impl<'input> SetCrtcTransformRequest<'input> {
         if header.minor_opcode != SET_CRTC_TRANSFORM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
-        let (transform, remaining) = render::Transform::try_parse(remaining)?;
-        let (filter_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
+        let (transform, remaining) = crate::x11_utils::in_field(render::Transform::try_parse(remaining), "transform")?;
+        let (filter_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "filter_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (filter_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?), "filter_name")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut filter_params = Vec::new();
@@ -3861,6 +3987,7 @@ impl<'input> SetCrtcTransformRequest<'input> {
 }
 impl<'input> Request for SetCrtcTransformRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3905,7 +4032,7 @@ impl GetCrtcTransformRequest {
         if header.minor_opcode != GET_CRTC_TRANSFORM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
         let _ = remaining;
         Ok(GetCrtcTransformRequest {
             crtc,
@@ -3914,6 +4041,7 @@ impl GetCrtcTransformRequest {
 }
 impl Request for GetCrtcTransformRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3943,40 +4071,39 @@ impl TryParse for GetCrtcTransformReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending_transform, remaining) = render::Transform::try_parse(remaining)?;
-        let (has_transforms, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (current_transform, remaining) = render::Transform::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (pending_len, remaining) = u16::try_parse(remaining)?;
-        let (pending_nparams, remaining) = u16::try_parse(remaining)?;
-        let (current_len, remaining) = u16::try_parse(remaining)?;
-        let (current_nparams, remaining) = u16::try_parse(remaining)?;
-        let (pending_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, pending_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pending_transform, remaining) = crate::x11_utils::in_field(render::Transform::try_parse(remaining), "pending_transform")?;
+        let (has_transforms, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "has_transforms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (current_transform, remaining) = crate::x11_utils::in_field(render::Transform::try_parse(remaining), "current_transform")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (pending_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pending_len")?;
+        let (pending_nparams, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pending_nparams")?;
+        let (current_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "current_len")?;
+        let (current_nparams, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "current_nparams")?;
+        let (pending_filter_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, pending_len.try_to_usize()?), "pending_filter_name")?;
         let pending_filter_name = pending_filter_name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (pending_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, pending_nparams.try_to_usize()?)?;
-        let (current_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, current_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (pending_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, pending_nparams.try_to_usize()?, Some("pending_params"))?;
+        let (current_filter_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, current_len.try_to_usize()?), "current_filter_name")?;
         let current_filter_name = current_filter_name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (current_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, current_nparams.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (current_params, remaining) = crate::x11_utils::parse_list::<render::Fixed>(remaining, current_nparams.try_to_usize()?, Some("current_params"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCrtcTransformReply { sequence, length, pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4103,7 +4230,7 @@ impl GetPanningRequest {
         if header.minor_opcode != GET_PANNING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
         let _ = remaining;
         Ok(GetPanningRequest {
             crtc,
@@ -4112,6 +4239,7 @@ impl GetPanningRequest {
 }
 impl Request for GetPanningRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4147,31 +4275,30 @@ pub struct GetPanningReply {
 impl TryParse for GetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (left, remaining) = u16::try_parse(remaining)?;
-        let (top, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (track_left, remaining) = u16::try_parse(remaining)?;
-        let (track_top, remaining) = u16::try_parse(remaining)?;
-        let (track_width, remaining) = u16::try_parse(remaining)?;
-        let (track_height, remaining) = u16::try_parse(remaining)?;
-        let (border_left, remaining) = i16::try_parse(remaining)?;
-        let (border_top, remaining) = i16::try_parse(remaining)?;
-        let (border_right, remaining) = i16::try_parse(remaining)?;
-        let (border_bottom, remaining) = i16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (left, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "left")?;
+        let (top, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "top")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (track_left, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_left")?;
+        let (track_top, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_top")?;
+        let (track_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_width")?;
+        let (track_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_height")?;
+        let (border_left, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_left")?;
+        let (border_top, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_top")?;
+        let (border_right, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_right")?;
+        let (border_bottom, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_bottom")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = GetPanningReply { status, sequence, length, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4344,20 +4471,20 @@ impl SetPanningRequest {
         if header.minor_opcode != SET_PANNING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (crtc, remaining) = Crtc::try_parse(value)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (left, remaining) = u16::try_parse(remaining)?;
-        let (top, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (track_left, remaining) = u16::try_parse(remaining)?;
-        let (track_top, remaining) = u16::try_parse(remaining)?;
-        let (track_width, remaining) = u16::try_parse(remaining)?;
-        let (track_height, remaining) = u16::try_parse(remaining)?;
-        let (border_left, remaining) = i16::try_parse(remaining)?;
-        let (border_top, remaining) = i16::try_parse(remaining)?;
-        let (border_right, remaining) = i16::try_parse(remaining)?;
-        let (border_bottom, remaining) = i16::try_parse(remaining)?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(value), "crtc")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (left, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "left")?;
+        let (top, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "top")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (track_left, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_left")?;
+        let (track_top, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_top")?;
+        let (track_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_width")?;
+        let (track_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "track_height")?;
+        let (border_left, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_left")?;
+        let (border_top, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_top")?;
+        let (border_right, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_right")?;
+        let (border_bottom, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "border_bottom")?;
         let _ = remaining;
         Ok(SetPanningRequest {
             crtc,
@@ -4379,6 +4506,7 @@ impl SetPanningRequest {
 }
 impl Request for SetPanningRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4402,19 +4530,18 @@ pub struct SetPanningReply {
 impl TryParse for SetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetPanningReply { status, sequence, length, timestamp };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4491,8 +4618,8 @@ impl SetOutputPrimaryRequest {
         if header.minor_opcode != SET_OUTPUT_PRIMARY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(remaining), "output")?;
         let _ = remaining;
         Ok(SetOutputPrimaryRequest {
             window,
@@ -4502,6 +4629,7 @@ impl SetOutputPrimaryRequest {
 }
 impl Request for SetOutputPrimaryRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4546,7 +4674,7 @@ impl GetOutputPrimaryRequest {
         if header.minor_opcode != GET_OUTPUT_PRIMARY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetOutputPrimaryRequest {
             window,
@@ -4555,6 +4683,7 @@ impl GetOutputPrimaryRequest {
 }
 impl Request for GetOutputPrimaryRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4577,18 +4706,17 @@ pub struct GetOutputPrimaryReply {
 impl TryParse for GetOutputPrimaryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(remaining), "output")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetOutputPrimaryReply { sequence, length, output };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4658,7 +4786,7 @@ impl GetProvidersRequest {
         if header.minor_opcode != GET_PROVIDERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetProvidersRequest {
             window,
@@ -4667,6 +4795,7 @@ impl GetProvidersRequest {
 }
 impl Request for GetProvidersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4690,21 +4819,20 @@ pub struct GetProvidersReply {
 impl TryParse for GetProvidersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_providers, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
-        let (providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_providers.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (num_providers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_providers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
+        let (providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_providers.try_to_usize()?, Some("providers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetProvidersReply { sequence, length, timestamp, providers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4744,6 +4872,28 @@ impl GetProvidersReply {
             .try_into().unwrap()
     }
 }
+impl GetProvidersReply {
+    /// Lazily parse the `providers` list from the raw bytes of a `GetProvidersReply`.
+    ///
+    /// In contrast to parsing the whole `GetProvidersReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn providers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Provider>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (num_providers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_providers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_providers.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4795,7 +4945,7 @@ impl core::fmt::Debug for ProviderCapability  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ProviderCapability, u32);
+bitmask_binop!(ProviderCapability, u32, [SOURCE_OUTPUT, SINK_OUTPUT, SOURCE_OFFLOAD, SINK_OFFLOAD]);
 
 /// Opcode for the GetProviderInfo request
 pub const GET_PROVIDER_INFO_REQUEST: u8 = 33;
@@ -4836,8 +4986,8 @@ impl GetProviderInfoRequest {
         if header.minor_opcode != GET_PROVIDER_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
         let _ = remaining;
         Ok(GetProviderInfoRequest {
             provider,
@@ -4847,6 +4997,7 @@ impl GetProviderInfoRequest {
 }
 impl Request for GetProviderInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4876,31 +5027,30 @@ pub struct GetProviderInfoReply {
 impl TryParse for GetProviderInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (capabilities, remaining) = u32::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (num_associated_providers, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?)?;
-        let (associated_providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_associated_providers.try_to_usize()?)?;
-        let (associated_capability, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_associated_providers.try_to_usize()?)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (capabilities, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "capabilities")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (num_associated_providers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_associated_providers")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?, Some("crtcs"))?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?, Some("outputs"))?;
+        let (associated_providers, remaining) = crate::x11_utils::parse_list::<Provider>(remaining, num_associated_providers.try_to_usize()?, Some("associated_providers"))?;
+        let (associated_capability, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_associated_providers.try_to_usize()?, Some("associated_capability"))?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let capabilities = capabilities.into();
         let result = GetProviderInfoReply { status, sequence, length, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5037,9 +5187,9 @@ impl SetProviderOffloadSinkRequest {
         if header.minor_opcode != SET_PROVIDER_OFFLOAD_SINK_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (sink_provider, remaining) = Provider::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (sink_provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(remaining), "sink_provider")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
         let _ = remaining;
         Ok(SetProviderOffloadSinkRequest {
             provider,
@@ -5050,6 +5200,7 @@ impl SetProviderOffloadSinkRequest {
 }
 impl Request for SetProviderOffloadSinkRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5106,9 +5257,9 @@ impl SetProviderOutputSourceRequest {
         if header.minor_opcode != SET_PROVIDER_OUTPUT_SOURCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (source_provider, remaining) = Provider::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (source_provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(remaining), "source_provider")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
         let _ = remaining;
         Ok(SetProviderOutputSourceRequest {
             provider,
@@ -5119,6 +5270,7 @@ impl SetProviderOutputSourceRequest {
 }
 impl Request for SetProviderOutputSourceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5163,7 +5315,7 @@ impl ListProviderPropertiesRequest {
         if header.minor_opcode != LIST_PROVIDER_PROPERTIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
         let _ = remaining;
         Ok(ListProviderPropertiesRequest {
             provider,
@@ -5172,6 +5324,7 @@ impl ListProviderPropertiesRequest {
 }
 impl Request for ListProviderPropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5194,20 +5347,19 @@ pub struct ListProviderPropertiesReply {
 impl TryParse for ListProviderPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?, Some("atoms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListProviderPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5246,6 +5398,27 @@ impl ListProviderPropertiesReply {
             .try_into().unwrap()
     }
 }
+impl ListProviderPropertiesReply {
+    /// Lazily parse the `atoms` list from the raw bytes of a `ListProviderPropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `ListProviderPropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn atoms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Atom>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_atoms.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryProviderProperty request
 pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
@@ -5286,8 +5459,8 @@ impl QueryProviderPropertyRequest {
         if header.minor_opcode != QUERY_PROVIDER_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(QueryProviderPropertyRequest {
             provider,
@@ -5297,6 +5470,7 @@ impl QueryProviderPropertyRequest {
 }
 impl Request for QueryProviderPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5321,22 +5495,21 @@ pub struct QueryProviderPropertyReply {
 impl TryParse for QueryProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let (immutable, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
-        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let (immutable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "immutable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 21)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list::<i32>(remaining, length.try_to_usize()?, Some("valid_values"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryProviderPropertyReply { sequence, pending, range, immutable, valid_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5377,6 +5550,29 @@ impl QueryProviderPropertyReply {
             .try_into().unwrap()
     }
 }
+impl QueryProviderPropertyReply {
+    /// Lazily parse the `valid_values` list from the raw bytes of a `QueryProviderPropertyReply`.
+    ///
+    /// In contrast to parsing the whole `QueryProviderPropertyReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn valid_values_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, i32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let (immutable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "immutable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 21)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ConfigureProviderProperty request
 pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
@@ -5430,11 +5626,11 @@ impl<'input> ConfigureProviderPropertyRequest<'input> {
         if header.minor_opcode != CONFIGURE_PROVIDER_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let (range, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let (range, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "range")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut values = Vec::new();
@@ -5465,6 +5661,7 @@ impl<'input> ConfigureProviderPropertyRequest<'input> {
 }
 impl<'input> Request for ConfigureProviderPropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5540,14 +5737,14 @@ impl<'input> ChangeProviderPropertyRequest<'input> {
         if header.minor_opcode != CHANGE_PROVIDER_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let _ = remaining;
         Ok(ChangeProviderPropertyRequest {
             provider,
@@ -5574,6 +5771,7 @@ impl<'input> ChangeProviderPropertyRequest<'input> {
 }
 impl<'input> Request for ChangeProviderPropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5624,8 +5822,8 @@ impl DeleteProviderPropertyRequest {
         if header.minor_opcode != DELETE_PROVIDER_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(DeleteProviderPropertyRequest {
             provider,
@@ -5635,6 +5833,7 @@ impl DeleteProviderPropertyRequest {
 }
 impl Request for DeleteProviderPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5711,14 +5910,14 @@ impl GetProviderPropertyRequest {
         if header.minor_opcode != GET_PROVIDER_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (provider, remaining) = Provider::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (long_offset, remaining) = u32::try_parse(remaining)?;
-        let (long_length, remaining) = u32::try_parse(remaining)?;
-        let (delete, remaining) = bool::try_parse(remaining)?;
-        let (pending, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(value), "provider")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (long_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_offset")?;
+        let (long_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_length")?;
+        let (delete, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "delete")?;
+        let (pending, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "pending")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetProviderPropertyRequest {
             provider,
@@ -5733,6 +5932,7 @@ impl GetProviderPropertyRequest {
 }
 impl Request for GetProviderPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5759,23 +5959,22 @@ pub struct GetProviderPropertyReply {
 impl TryParse for GetProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (bytes_after, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_after")?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(num_items).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetProviderPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5824,19 +6023,19 @@ pub struct ScreenChangeNotifyEvent {
 impl TryParse for ScreenChangeNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (rotation, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (request_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (size_id, remaining) = u16::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (mwidth, remaining) = u16::try_parse(remaining)?;
-        let (mheight, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "rotation")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (request_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "request_window")?;
+        let (size_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size_id")?;
+        let (subpixel_order, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "subpixel_order")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (mwidth, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mwidth")?;
+        let (mheight, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mheight")?;
         let rotation = rotation.into();
         let subpixel_order = subpixel_order.into();
         let result = ScreenChangeNotifyEvent { response_type, rotation, sequence, timestamp, config_timestamp, root, request_window, size_id, subpixel_order, width, height, mwidth, mheight };
@@ -6055,16 +6254,16 @@ pub struct CrtcChange {
 }
 impl TryParse for CrtcChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(remaining), "crtc")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let rotation = rotation.into();
         let result = CrtcChange { timestamp, window, crtc, mode, rotation, x, y, width, height };
         Ok((result, remaining))
@@ -6143,15 +6342,15 @@ pub struct OutputChange {
 }
 impl TryParse for OutputChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (config_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
-        let (crtc, remaining) = Crtc::try_parse(remaining)?;
-        let (mode, remaining) = Mode::try_parse(remaining)?;
-        let (rotation, remaining) = u16::try_parse(remaining)?;
-        let (connection, remaining) = u8::try_parse(remaining)?;
-        let (subpixel_order, remaining) = u8::try_parse(remaining)?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (config_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "config_timestamp")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(remaining), "output")?;
+        let (crtc, remaining) = crate::x11_utils::in_field(Crtc::try_parse(remaining), "crtc")?;
+        let (mode, remaining) = crate::x11_utils::in_field(Mode::try_parse(remaining), "mode")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "rotation")?;
+        let (connection, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "connection")?;
+        let (subpixel_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "subpixel_order")?;
         let rotation = rotation.into();
         let connection = connection.into();
         let subpixel_order = subpixel_order.into();
@@ -6227,12 +6426,12 @@ pub struct OutputProperty {
 }
 impl TryParse for OutputProperty {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (output, remaining) = Output::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (output, remaining) = crate::x11_utils::in_field(Output::try_parse(remaining), "output")?;
+        let (atom, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "atom")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let status = status.into();
         let result = OutputProperty { window, output, atom, timestamp, status };
         Ok((result, remaining))
@@ -6297,10 +6496,10 @@ pub struct ProviderChange {
 }
 impl TryParse for ProviderChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (provider, remaining) = Provider::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(remaining), "provider")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         let result = ProviderChange { timestamp, window, provider };
         Ok((result, remaining))
     }
@@ -6362,12 +6561,12 @@ pub struct ProviderProperty {
 }
 impl TryParse for ProviderProperty {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (provider, remaining) = Provider::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (provider, remaining) = crate::x11_utils::in_field(Provider::try_parse(remaining), "provider")?;
+        let (atom, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "atom")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let result = ProviderProperty { window, provider, atom, timestamp, state };
         Ok((result, remaining))
     }
@@ -6430,9 +6629,9 @@ pub struct ResourceChange {
 }
 impl TryParse for ResourceChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         let result = ResourceChange { timestamp, window };
         Ok((result, remaining))
     }
@@ -6497,17 +6696,17 @@ pub struct MonitorInfo {
 }
 impl TryParse for MonitorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (primary, remaining) = bool::try_parse(remaining)?;
-        let (automatic, remaining) = bool::try_parse(remaining)?;
-        let (n_output, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (width_in_millimeters, remaining) = u32::try_parse(remaining)?;
-        let (height_in_millimeters, remaining) = u32::try_parse(remaining)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, n_output.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (primary, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "primary")?;
+        let (automatic, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "automatic")?;
+        let (n_output, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_output")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (width_in_millimeters, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width_in_millimeters")?;
+        let (height_in_millimeters, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height_in_millimeters")?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, n_output.try_to_usize()?, Some("outputs"))?;
         let result = MonitorInfo { name, primary, automatic, x, y, width, height, width_in_millimeters, height_in_millimeters, outputs };
         Ok((result, remaining))
     }
@@ -6590,8 +6789,8 @@ impl GetMonitorsRequest {
         if header.minor_opcode != GET_MONITORS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (get_active, remaining) = bool::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (get_active, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "get_active")?;
         let _ = remaining;
         Ok(GetMonitorsRequest {
             window,
@@ -6601,6 +6800,7 @@ impl GetMonitorsRequest {
 }
 impl Request for GetMonitorsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6625,22 +6825,21 @@ pub struct GetMonitorsReply {
 impl TryParse for GetMonitorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (n_monitors, remaining) = u32::try_parse(remaining)?;
-        let (n_outputs, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (monitors, remaining) = crate::x11_utils::parse_list::<MonitorInfo>(remaining, n_monitors.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (n_monitors, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_monitors")?;
+        let (n_outputs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_outputs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (monitors, remaining) = crate::x11_utils::parse_list::<MonitorInfo>(remaining, n_monitors.try_to_usize()?, Some("monitors"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMonitorsReply { sequence, length, timestamp, n_outputs, monitors };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6681,6 +6880,29 @@ impl GetMonitorsReply {
             .try_into().unwrap()
     }
 }
+impl GetMonitorsReply {
+    /// Lazily parse the `monitors` list from the raw bytes of a `GetMonitorsReply`.
+    ///
+    /// In contrast to parsing the whole `GetMonitorsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn monitors_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, MonitorInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (n_monitors, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_monitors")?;
+        let (n_outputs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "n_outputs")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n_monitors.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetMonitor request
 pub const SET_MONITOR_REQUEST: u8 = 43;
@@ -6720,8 +6942,8 @@ impl SetMonitorRequest {
         if header.minor_opcode != SET_MONITOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (monitorinfo, remaining) = MonitorInfo::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (monitorinfo, remaining) = crate::x11_utils::in_field(MonitorInfo::try_parse(remaining), "monitorinfo")?;
         let _ = remaining;
         Ok(SetMonitorRequest {
             window,
@@ -6731,6 +6953,7 @@ impl SetMonitorRequest {
 }
 impl Request for SetMonitorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6781,8 +7004,8 @@ impl DeleteMonitorRequest {
         if header.minor_opcode != DELETE_MONITOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
         let _ = remaining;
         Ok(DeleteMonitorRequest {
             window,
@@ -6792,6 +7015,7 @@ impl DeleteMonitorRequest {
 }
 impl Request for DeleteMonitorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6858,12 +7082,12 @@ impl<'input> CreateLeaseRequest<'input> {
         if header.minor_opcode != CREATE_LEASE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (lid, remaining) = Lease::try_parse(remaining)?;
-        let (num_crtcs, remaining) = u16::try_parse(remaining)?;
-        let (num_outputs, remaining) = u16::try_parse(remaining)?;
-        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?)?;
-        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (lid, remaining) = crate::x11_utils::in_field(Lease::try_parse(remaining), "lid")?;
+        let (num_crtcs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_crtcs")?;
+        let (num_outputs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_outputs")?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list::<Crtc>(remaining, num_crtcs.try_to_usize()?, Some("crtcs"))?;
+        let (outputs, remaining) = crate::x11_utils::parse_list::<Output>(remaining, num_outputs.try_to_usize()?, Some("outputs"))?;
         let _ = remaining;
         Ok(CreateLeaseRequest {
             window,
@@ -6884,6 +7108,7 @@ impl<'input> CreateLeaseRequest<'input> {
 }
 impl<'input> Request for CreateLeaseRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6906,20 +7131,19 @@ pub struct CreateLeaseReply {
 impl TryParseFd for CreateLeaseReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let master_fd = fds.remove(0);
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateLeaseReply { nfd, sequence, length, master_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7015,8 +7239,8 @@ impl FreeLeaseRequest {
         if header.minor_opcode != FREE_LEASE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (lid, remaining) = Lease::try_parse(value)?;
-        let (terminate, remaining) = u8::try_parse(remaining)?;
+        let (lid, remaining) = crate::x11_utils::in_field(Lease::try_parse(value), "lid")?;
+        let (terminate, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "terminate")?;
         let _ = remaining;
         Ok(FreeLeaseRequest {
             lid,
@@ -7026,6 +7250,7 @@ impl FreeLeaseRequest {
 }
 impl Request for FreeLeaseRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7047,11 +7272,11 @@ pub struct LeaseNotify {
 }
 impl TryParse for LeaseNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (lease, remaining) = Lease::try_parse(remaining)?;
-        let (created, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (lease, remaining) = crate::x11_utils::in_field(Lease::try_parse(remaining), "lease")?;
+        let (created, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "created")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
         let result = LeaseNotify { timestamp, window, lease, created };
         Ok((result, remaining))
     }
@@ -7110,7 +7335,7 @@ pub struct NotifyData([u8; 28]);
 impl NotifyData {
     pub fn as_cc(&self) -> CrtcChange {
         fn do_the_parse(remaining: &[u8]) -> Result<CrtcChange, ParseError> {
-            let (cc, remaining) = CrtcChange::try_parse(remaining)?;
+            let (cc, remaining) = crate::x11_utils::in_field(CrtcChange::try_parse(remaining), "cc")?;
             let _ = remaining;
             Ok(cc)
         }
@@ -7118,7 +7343,7 @@ impl NotifyData {
     }
     pub fn as_oc(&self) -> OutputChange {
         fn do_the_parse(remaining: &[u8]) -> Result<OutputChange, ParseError> {
-            let (oc, remaining) = OutputChange::try_parse(remaining)?;
+            let (oc, remaining) = crate::x11_utils::in_field(OutputChange::try_parse(remaining), "oc")?;
             let _ = remaining;
             Ok(oc)
         }
@@ -7126,7 +7351,7 @@ impl NotifyData {
     }
     pub fn as_op(&self) -> OutputProperty {
         fn do_the_parse(remaining: &[u8]) -> Result<OutputProperty, ParseError> {
-            let (op, remaining) = OutputProperty::try_parse(remaining)?;
+            let (op, remaining) = crate::x11_utils::in_field(OutputProperty::try_parse(remaining), "op")?;
             let _ = remaining;
             Ok(op)
         }
@@ -7134,7 +7359,7 @@ impl NotifyData {
     }
     pub fn as_pc(&self) -> ProviderChange {
         fn do_the_parse(remaining: &[u8]) -> Result<ProviderChange, ParseError> {
-            let (pc, remaining) = ProviderChange::try_parse(remaining)?;
+            let (pc, remaining) = crate::x11_utils::in_field(ProviderChange::try_parse(remaining), "pc")?;
             let _ = remaining;
             Ok(pc)
         }
@@ -7142,7 +7367,7 @@ impl NotifyData {
     }
     pub fn as_pp(&self) -> ProviderProperty {
         fn do_the_parse(remaining: &[u8]) -> Result<ProviderProperty, ParseError> {
-            let (pp, remaining) = ProviderProperty::try_parse(remaining)?;
+            let (pp, remaining) = crate::x11_utils::in_field(ProviderProperty::try_parse(remaining), "pp")?;
             let _ = remaining;
             Ok(pp)
         }
@@ -7150,7 +7375,7 @@ impl NotifyData {
     }
     pub fn as_rc(&self) -> ResourceChange {
         fn do_the_parse(remaining: &[u8]) -> Result<ResourceChange, ParseError> {
-            let (rc, remaining) = ResourceChange::try_parse(remaining)?;
+            let (rc, remaining) = crate::x11_utils::in_field(ResourceChange::try_parse(remaining), "rc")?;
             let _ = remaining;
             Ok(rc)
         }
@@ -7158,7 +7383,7 @@ impl NotifyData {
     }
     pub fn as_lc(&self) -> LeaseNotify {
         fn do_the_parse(remaining: &[u8]) -> Result<LeaseNotify, ParseError> {
-            let (lc, remaining) = LeaseNotify::try_parse(remaining)?;
+            let (lc, remaining) = crate::x11_utils::in_field(LeaseNotify::try_parse(remaining), "lc")?;
             let _ = remaining;
             Ok(lc)
         }
@@ -7240,10 +7465,10 @@ pub struct NotifyEvent {
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (sub_code, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (u, remaining) = NotifyData::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (sub_code, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "sub_code")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (u, remaining) = crate::x11_utils::in_field(NotifyData::try_parse(remaining), "u")?;
         let sub_code = sub_code.into();
         let result = NotifyEvent { response_type, sub_code, sequence, u };
         let _ = remaining;
@@ -44,8 +44,8 @@ pub struct Range8 {
 }
 impl TryParse for Range8 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first, remaining) = u8::try_parse(remaining)?;
-        let (last, remaining) = u8::try_parse(remaining)?;
+        let (first, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first")?;
+        let (last, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "last")?;
         let result = Range8 { first, last };
         Ok((result, remaining))
     }
@@ -75,8 +75,8 @@ pub struct Range16 {
 }
 impl TryParse for Range16 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first, remaining) = u16::try_parse(remaining)?;
-        let (last, remaining) = u16::try_parse(remaining)?;
+        let (first, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first")?;
+        let (last, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "last")?;
         let result = Range16 { first, last };
         Ok((result, remaining))
     }
@@ -108,8 +108,8 @@ pub struct ExtRange {
 }
 impl TryParse for ExtRange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (major, remaining) = Range8::try_parse(remaining)?;
-        let (minor, remaining) = Range16::try_parse(remaining)?;
+        let (major, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(Range16::try_parse(remaining), "minor")?;
         let result = ExtRange { major, minor };
         Ok((result, remaining))
     }
@@ -150,15 +150,15 @@ pub struct Range {
 }
 impl TryParse for Range {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (core_requests, remaining) = Range8::try_parse(remaining)?;
-        let (core_replies, remaining) = Range8::try_parse(remaining)?;
-        let (ext_requests, remaining) = ExtRange::try_parse(remaining)?;
-        let (ext_replies, remaining) = ExtRange::try_parse(remaining)?;
-        let (delivered_events, remaining) = Range8::try_parse(remaining)?;
-        let (device_events, remaining) = Range8::try_parse(remaining)?;
-        let (errors, remaining) = Range8::try_parse(remaining)?;
-        let (client_started, remaining) = bool::try_parse(remaining)?;
-        let (client_died, remaining) = bool::try_parse(remaining)?;
+        let (core_requests, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "core_requests")?;
+        let (core_replies, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "core_replies")?;
+        let (ext_requests, remaining) = crate::x11_utils::in_field(ExtRange::try_parse(remaining), "ext_requests")?;
+        let (ext_replies, remaining) = crate::x11_utils::in_field(ExtRange::try_parse(remaining), "ext_replies")?;
+        let (delivered_events, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "delivered_events")?;
+        let (device_events, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "device_events")?;
+        let (errors, remaining) = crate::x11_utils::in_field(Range8::try_parse(remaining), "errors")?;
+        let (client_started, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "client_started")?;
+        let (client_died, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "client_died")?;
         let result = Range { core_requests, core_replies, ext_requests, ext_replies, delivered_events, device_events, errors, client_started, client_died };
         Ok((result, remaining))
     }
@@ -278,7 +278,7 @@ impl core::fmt::Debug for HType  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(HType, u8);
+bitmask_binop!(HType, u8, [FROM_SERVER_TIME, FROM_CLIENT_TIME, FROM_CLIENT_SEQUENCE]);
 
 pub type ClientSpec = u32;
 
@@ -351,9 +351,9 @@ pub struct ClientInfo {
 }
 impl TryParse for ClientInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (client_resource, remaining) = ClientSpec::try_parse(remaining)?;
-        let (num_ranges, remaining) = u32::try_parse(remaining)?;
-        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?)?;
+        let (client_resource, remaining) = crate::x11_utils::in_field(ClientSpec::try_parse(remaining), "client_resource")?;
+        let (num_ranges, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_ranges")?;
+        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?, Some("ranges"))?;
         let result = ClientInfo { client_resource, ranges };
         Ok((result, remaining))
     }
@@ -427,8 +427,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u16::try_parse(value)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major_version,
@@ -438,6 +438,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -461,19 +462,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -571,13 +571,13 @@ impl<'input> CreateContextRequest<'input> {
         if header.minor_opcode != CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (num_client_specs, remaining) = u32::try_parse(remaining)?;
-        let (num_ranges, remaining) = u32::try_parse(remaining)?;
-        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?)?;
-        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (element_header, remaining) = crate::x11_utils::in_field(ElementHeader::try_parse(remaining), "element_header")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (num_client_specs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_client_specs")?;
+        let (num_ranges, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_ranges")?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?, Some("client_specs"))?;
+        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?, Some("ranges"))?;
         let _ = remaining;
         Ok(CreateContextRequest {
             context,
@@ -598,6 +598,7 @@ impl<'input> CreateContextRequest<'input> {
 }
 impl<'input> Request for CreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -668,13 +669,13 @@ impl<'input> RegisterClientsRequest<'input> {
         if header.minor_opcode != REGISTER_CLIENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (num_client_specs, remaining) = u32::try_parse(remaining)?;
-        let (num_ranges, remaining) = u32::try_parse(remaining)?;
-        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?)?;
-        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (element_header, remaining) = crate::x11_utils::in_field(ElementHeader::try_parse(remaining), "element_header")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (num_client_specs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_client_specs")?;
+        let (num_ranges, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_ranges")?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?, Some("client_specs"))?;
+        let (ranges, remaining) = crate::x11_utils::parse_list::<Range>(remaining, num_ranges.try_to_usize()?, Some("ranges"))?;
         let _ = remaining;
         Ok(RegisterClientsRequest {
             context,
@@ -695,6 +696,7 @@ impl<'input> RegisterClientsRequest<'input> {
 }
 impl<'input> Request for RegisterClientsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -750,9 +752,9 @@ impl<'input> UnregisterClientsRequest<'input> {
         if header.minor_opcode != UNREGISTER_CLIENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
-        let (num_client_specs, remaining) = u32::try_parse(remaining)?;
-        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
+        let (num_client_specs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_client_specs")?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list::<ClientSpec>(remaining, num_client_specs.try_to_usize()?, Some("client_specs"))?;
         let _ = remaining;
         Ok(UnregisterClientsRequest {
             context,
@@ -769,6 +771,7 @@ impl<'input> UnregisterClientsRequest<'input> {
 }
 impl<'input> Request for UnregisterClientsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -813,7 +816,7 @@ impl GetContextRequest {
         if header.minor_opcode != GET_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(GetContextRequest {
             context,
@@ -822,6 +825,7 @@ impl GetContextRequest {
 }
 impl Request for GetContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -846,22 +850,21 @@ pub struct GetContextReply {
 impl TryParse for GetContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (num_intercepted_clients, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (intercepted_clients, remaining) = crate::x11_utils::parse_list::<ClientInfo>(remaining, num_intercepted_clients.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (enabled, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enabled")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (element_header, remaining) = crate::x11_utils::in_field(ElementHeader::try_parse(remaining), "element_header")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (num_intercepted_clients, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_intercepted_clients")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (intercepted_clients, remaining) = crate::x11_utils::parse_list::<ClientInfo>(remaining, num_intercepted_clients.try_to_usize()?, Some("intercepted_clients"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetContextReply { enabled, sequence, length, element_header, intercepted_clients };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -902,6 +905,29 @@ impl GetContextReply {
             .try_into().unwrap()
     }
 }
+impl GetContextReply {
+    /// Lazily parse the `intercepted_clients` list from the raw bytes of a `GetContextReply`.
+    ///
+    /// In contrast to parsing the whole `GetContextReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn intercepted_clients_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ClientInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (enabled, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enabled")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (element_header, remaining) = crate::x11_utils::in_field(ElementHeader::try_parse(remaining), "element_header")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (num_intercepted_clients, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_intercepted_clients")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_intercepted_clients.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the EnableContext request
 pub const ENABLE_CONTEXT_REQUEST: u8 = 5;
@@ -936,7 +962,7 @@ impl EnableContextRequest {
         if header.minor_opcode != ENABLE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(EnableContextRequest {
             context,
@@ -945,6 +971,7 @@ impl EnableContextRequest {
 }
 impl Request for EnableContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -956,6 +983,34 @@ impl Request for EnableContextRequest {
 impl crate::x11_utils::ReplyRequest for EnableContextRequest {
     type Reply = EnableContextReply;
 }
+impl EnableContextReply {
+    /// Iterate over the recorded protocol elements in this reply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::record::{EnableContextReply, HType};
+    ///
+    /// let mut data = vec![0; 32];
+    /// data[0] = 12; // a MapNotify event
+    /// data.splice(0..0, 42u32.to_ne_bytes());
+    /// let reply = EnableContextReply {
+    ///     category: 0, // FromServer
+    ///     element_header: HType::FROM_SERVER_TIME.into(),
+    ///     data,
+    ///     ..Default::default()
+    /// };
+    /// let elements = reply.elements().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(elements.len(), 1);
+    /// assert_eq!(elements[0].time, Some(42));
+    /// assert_eq!(elements[0].data[0], 12);
+    /// # Ok::<(), x11rb_protocol::errors::ParseError>(())
+    /// ```
+    pub fn elements(&self) -> crate::wrapper::RecordedElements<'_> {
+        crate::wrapper::RecordedElements::new(self)
+    }
+}
+
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -972,26 +1027,25 @@ pub struct EnableContextReply {
 impl TryParse for EnableContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (category, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (element_header, remaining) = ElementHeader::try_parse(remaining)?;
-        let (client_swapped, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (xid_base, remaining) = u32::try_parse(remaining)?;
-        let (server_time, remaining) = u32::try_parse(remaining)?;
-        let (rec_sequence_num, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (category, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "category")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (element_header, remaining) = crate::x11_utils::in_field(ElementHeader::try_parse(remaining), "element_header")?;
+        let (client_swapped, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "client_swapped")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (xid_base, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "xid_base")?;
+        let (server_time, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "server_time")?;
+        let (rec_sequence_num, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rec_sequence_num")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = EnableContextReply { category, sequence, element_header, client_swapped, xid_base, server_time, rec_sequence_num, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1071,7 +1125,7 @@ impl DisableContextRequest {
         if header.minor_opcode != DISABLE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(DisableContextRequest {
             context,
@@ -1080,6 +1134,7 @@ impl DisableContextRequest {
 }
 impl Request for DisableContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1124,7 +1179,7 @@ impl FreeContextRequest {
         if header.minor_opcode != FREE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Context::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context")?;
         let _ = remaining;
         Ok(FreeContextRequest {
             context,
@@ -1133,6 +1188,7 @@ impl FreeContextRequest {
 }
 impl Request for FreeContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -475,7 +475,7 @@ impl core::fmt::Debug for CP  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(CP, u32);
+bitmask_binop!(CP, u32, [REPEAT, ALPHA_MAP, ALPHA_X_ORIGIN, ALPHA_Y_ORIGIN, CLIP_X_ORIGIN, CLIP_Y_ORIGIN, CLIP_MASK, GRAPHICS_EXPOSURE, SUBWINDOW_MODE, POLY_EDGE, POLY_MODE, DITHER, COMPONENT_ALPHA]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -622,14 +622,14 @@ pub struct Directformat {
 }
 impl TryParse for Directformat {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (red_shift, remaining) = u16::try_parse(remaining)?;
-        let (red_mask, remaining) = u16::try_parse(remaining)?;
-        let (green_shift, remaining) = u16::try_parse(remaining)?;
-        let (green_mask, remaining) = u16::try_parse(remaining)?;
-        let (blue_shift, remaining) = u16::try_parse(remaining)?;
-        let (blue_mask, remaining) = u16::try_parse(remaining)?;
-        let (alpha_shift, remaining) = u16::try_parse(remaining)?;
-        let (alpha_mask, remaining) = u16::try_parse(remaining)?;
+        let (red_shift, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red_shift")?;
+        let (red_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red_mask")?;
+        let (green_shift, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green_shift")?;
+        let (green_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green_mask")?;
+        let (blue_shift, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue_shift")?;
+        let (blue_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue_mask")?;
+        let (alpha_shift, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "alpha_shift")?;
+        let (alpha_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "alpha_mask")?;
         let result = Directformat { red_shift, red_mask, green_shift, green_mask, blue_shift, blue_mask, alpha_shift, alpha_mask };
         Ok((result, remaining))
     }
@@ -688,12 +688,12 @@ pub struct Pictforminfo {
 }
 impl TryParse for Pictforminfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = Pictformat::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (direct, remaining) = Directformat::try_parse(remaining)?;
-        let (colormap, remaining) = xproto::Colormap::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "id")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (direct, remaining) = crate::x11_utils::in_field(Directformat::try_parse(remaining), "direct")?;
+        let (colormap, remaining) = crate::x11_utils::in_field(xproto::Colormap::try_parse(remaining), "colormap")?;
         let type_ = type_.into();
         let result = Pictforminfo { id, type_, depth, direct, colormap };
         Ok((result, remaining))
@@ -757,8 +757,8 @@ pub struct Pictvisual {
 }
 impl TryParse for Pictvisual {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (format, remaining) = Pictformat::try_parse(remaining)?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "format")?;
         let result = Pictvisual { visual, format };
         Ok((result, remaining))
     }
@@ -794,11 +794,11 @@ pub struct Pictdepth {
 }
 impl TryParse for Pictdepth {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (num_visuals, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (visuals, remaining) = crate::x11_utils::parse_list::<Pictvisual>(remaining, num_visuals.try_to_usize()?)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (num_visuals, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_visuals")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (visuals, remaining) = crate::x11_utils::parse_list::<Pictvisual>(remaining, num_visuals.try_to_usize()?, Some("visuals"))?;
         let result = Pictdepth { depth, visuals };
         Ok((result, remaining))
     }
@@ -844,9 +844,9 @@ pub struct Pictscreen {
 }
 impl TryParse for Pictscreen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_depths, remaining) = u32::try_parse(remaining)?;
-        let (fallback, remaining) = Pictformat::try_parse(remaining)?;
-        let (depths, remaining) = crate::x11_utils::parse_list::<Pictdepth>(remaining, num_depths.try_to_usize()?)?;
+        let (num_depths, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_depths")?;
+        let (fallback, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "fallback")?;
+        let (depths, remaining) = crate::x11_utils::parse_list::<Pictdepth>(remaining, num_depths.try_to_usize()?, Some("depths"))?;
         let result = Pictscreen { fallback, depths };
         Ok((result, remaining))
     }
@@ -893,11 +893,11 @@ pub struct Indexvalue {
 }
 impl TryParse for Indexvalue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (pixel, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let (alpha, remaining) = u16::try_parse(remaining)?;
+        let (pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixel")?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let (alpha, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "alpha")?;
         let result = Indexvalue { pixel, red, green, blue, alpha };
         Ok((result, remaining))
     }
@@ -945,10 +945,10 @@ pub struct Color {
 }
 impl TryParse for Color {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let (alpha, remaining) = u16::try_parse(remaining)?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let (alpha, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "alpha")?;
         let result = Color { red, green, blue, alpha };
         Ok((result, remaining))
     }
@@ -988,8 +988,8 @@ pub struct Pointfix {
 }
 impl TryParse for Pointfix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x, remaining) = Fixed::try_parse(remaining)?;
-        let (y, remaining) = Fixed::try_parse(remaining)?;
+        let (x, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "y")?;
         let result = Pointfix { x, y };
         Ok((result, remaining))
     }
@@ -1025,8 +1025,8 @@ pub struct Linefix {
 }
 impl TryParse for Linefix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (p1, remaining) = Pointfix::try_parse(remaining)?;
-        let (p2, remaining) = Pointfix::try_parse(remaining)?;
+        let (p1, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p1")?;
+        let (p2, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p2")?;
         let result = Linefix { p1, p2 };
         Ok((result, remaining))
     }
@@ -1071,9 +1071,9 @@ pub struct Triangle {
 }
 impl TryParse for Triangle {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (p1, remaining) = Pointfix::try_parse(remaining)?;
-        let (p2, remaining) = Pointfix::try_parse(remaining)?;
-        let (p3, remaining) = Pointfix::try_parse(remaining)?;
+        let (p1, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p1")?;
+        let (p2, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p2")?;
+        let (p3, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p3")?;
         let result = Triangle { p1, p2, p3 };
         Ok((result, remaining))
     }
@@ -1129,10 +1129,10 @@ pub struct Trapezoid {
 }
 impl TryParse for Trapezoid {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = Fixed::try_parse(remaining)?;
-        let (bottom, remaining) = Fixed::try_parse(remaining)?;
-        let (left, remaining) = Linefix::try_parse(remaining)?;
-        let (right, remaining) = Linefix::try_parse(remaining)?;
+        let (top, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "top")?;
+        let (bottom, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "bottom")?;
+        let (left, remaining) = crate::x11_utils::in_field(Linefix::try_parse(remaining), "left")?;
+        let (right, remaining) = crate::x11_utils::in_field(Linefix::try_parse(remaining), "right")?;
         let result = Trapezoid { top, bottom, left, right };
         Ok((result, remaining))
     }
@@ -1208,12 +1208,12 @@ pub struct Glyphinfo {
 }
 impl TryParse for Glyphinfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (x_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_off")?;
+        let (y_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_off")?;
         let result = Glyphinfo { width, height, x, y, x_off, y_off };
         Ok((result, remaining))
     }
@@ -1292,8 +1292,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u32::try_parse(value)?;
-        let (client_minor_version, remaining) = u32::try_parse(remaining)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major_version,
@@ -1303,6 +1303,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1326,20 +1327,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1432,6 +1432,7 @@ impl QueryPictFormatsRequest {
 }
 impl Request for QueryPictFormatsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1458,18 +1459,18 @@ pub struct QueryPictFormatsReply {
 impl TryParse for QueryPictFormatsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_formats, remaining) = u32::try_parse(remaining)?;
-        let (num_screens, remaining) = u32::try_parse(remaining)?;
-        let (num_depths, remaining) = u32::try_parse(remaining)?;
-        let (num_visuals, remaining) = u32::try_parse(remaining)?;
-        let (num_subpixel, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (formats, remaining) = crate::x11_utils::parse_list::<Pictforminfo>(remaining, num_formats.try_to_usize()?)?;
-        let (screens, remaining) = crate::x11_utils::parse_list::<Pictscreen>(remaining, num_screens.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_formats, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_formats")?;
+        let (num_screens, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_screens")?;
+        let (num_depths, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_depths")?;
+        let (num_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_visuals")?;
+        let (num_subpixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_subpixel")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (formats, remaining) = crate::x11_utils::parse_list::<Pictforminfo>(remaining, num_formats.try_to_usize()?, Some("formats"))?;
+        let (screens, remaining) = crate::x11_utils::parse_list::<Pictscreen>(remaining, num_screens.try_to_usize()?, Some("screens"))?;
         let mut remaining = remaining;
         let list_length = num_subpixel.try_to_usize()?;
         let mut subpixels = Vec::with_capacity(list_length);
@@ -1480,12 +1481,11 @@ impl TryParse for QueryPictFormatsReply {
             subpixels.push(v);
         }
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryPictFormatsReply { sequence, length, num_depths, num_visuals, formats, screens, subpixels };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1560,6 +1560,31 @@ impl QueryPictFormatsReply {
             .try_into().unwrap()
     }
 }
+impl QueryPictFormatsReply {
+    /// Lazily parse the `formats` list from the raw bytes of a `QueryPictFormatsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryPictFormatsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn formats_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Pictforminfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_formats, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_formats")?;
+        let (num_screens, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_screens")?;
+        let (num_depths, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_depths")?;
+        let (num_visuals, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_visuals")?;
+        let (num_subpixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_subpixel")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_formats.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryPictIndexValues request
 pub const QUERY_PICT_INDEX_VALUES_REQUEST: u8 = 2;
@@ -1594,7 +1619,7 @@ impl QueryPictIndexValuesRequest {
         if header.minor_opcode != QUERY_PICT_INDEX_VALUES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (format, remaining) = Pictformat::try_parse(value)?;
+        let (format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(value), "format")?;
         let _ = remaining;
         Ok(QueryPictIndexValuesRequest {
             format,
@@ -1603,6 +1628,7 @@ impl QueryPictIndexValuesRequest {
 }
 impl Request for QueryPictIndexValuesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1625,20 +1651,19 @@ pub struct QueryPictIndexValuesReply {
 impl TryParse for QueryPictIndexValuesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_values, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (values, remaining) = crate::x11_utils::parse_list::<Indexvalue>(remaining, num_values.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_values")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (values, remaining) = crate::x11_utils::parse_list::<Indexvalue>(remaining, num_values.try_to_usize()?, Some("values"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryPictIndexValuesReply { sequence, length, values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1677,6 +1702,27 @@ impl QueryPictIndexValuesReply {
             .try_into().unwrap()
     }
 }
+impl QueryPictIndexValuesReply {
+    /// Lazily parse the `values` list from the raw bytes of a `QueryPictIndexValuesReply`.
+    ///
+    /// In contrast to parsing the whole `QueryPictIndexValuesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn values_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Indexvalue>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_values")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_values.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Auxiliary and optional information for the `create_picture` function
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1702,7 +1748,7 @@ impl CreatePictureAux {
         let mut outer_remaining = value;
         let repeat = if switch_expr & u32::from(CP::REPEAT) != 0 {
             let remaining = outer_remaining;
-            let (repeat, remaining) = u32::try_parse(remaining)?;
+            let (repeat, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "repeat")?;
             let repeat = repeat.into();
             outer_remaining = remaining;
             Some(repeat)
@@ -1711,7 +1757,7 @@ impl CreatePictureAux {
         };
         let alphamap = if switch_expr & u32::from(CP::ALPHA_MAP) != 0 {
             let remaining = outer_remaining;
-            let (alphamap, remaining) = Picture::try_parse(remaining)?;
+            let (alphamap, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "alphamap")?;
             outer_remaining = remaining;
             Some(alphamap)
         } else {
@@ -1719,7 +1765,7 @@ impl CreatePictureAux {
         };
         let alphaxorigin = if switch_expr & u32::from(CP::ALPHA_X_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (alphaxorigin, remaining) = i32::try_parse(remaining)?;
+            let (alphaxorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "alphaxorigin")?;
             outer_remaining = remaining;
             Some(alphaxorigin)
         } else {
@@ -1727,7 +1773,7 @@ impl CreatePictureAux {
         };
         let alphayorigin = if switch_expr & u32::from(CP::ALPHA_Y_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (alphayorigin, remaining) = i32::try_parse(remaining)?;
+            let (alphayorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "alphayorigin")?;
             outer_remaining = remaining;
             Some(alphayorigin)
         } else {
@@ -1735,7 +1781,7 @@ impl CreatePictureAux {
         };
         let clipxorigin = if switch_expr & u32::from(CP::CLIP_X_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (clipxorigin, remaining) = i32::try_parse(remaining)?;
+            let (clipxorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clipxorigin")?;
             outer_remaining = remaining;
             Some(clipxorigin)
         } else {
@@ -1743,7 +1789,7 @@ impl CreatePictureAux {
         };
         let clipyorigin = if switch_expr & u32::from(CP::CLIP_Y_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (clipyorigin, remaining) = i32::try_parse(remaining)?;
+            let (clipyorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clipyorigin")?;
             outer_remaining = remaining;
             Some(clipyorigin)
         } else {
@@ -1751,7 +1797,7 @@ impl CreatePictureAux {
         };
         let clipmask = if switch_expr & u32::from(CP::CLIP_MASK) != 0 {
             let remaining = outer_remaining;
-            let (clipmask, remaining) = xproto::Pixmap::try_parse(remaining)?;
+            let (clipmask, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "clipmask")?;
             outer_remaining = remaining;
             Some(clipmask)
         } else {
@@ -1759,7 +1805,7 @@ impl CreatePictureAux {
         };
         let graphicsexposure = if switch_expr & u32::from(CP::GRAPHICS_EXPOSURE) != 0 {
             let remaining = outer_remaining;
-            let (graphicsexposure, remaining) = u32::try_parse(remaining)?;
+            let (graphicsexposure, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "graphicsexposure")?;
             outer_remaining = remaining;
             Some(graphicsexposure)
         } else {
@@ -1767,7 +1813,7 @@ impl CreatePictureAux {
         };
         let subwindowmode = if switch_expr & u32::from(CP::SUBWINDOW_MODE) != 0 {
             let remaining = outer_remaining;
-            let (subwindowmode, remaining) = u32::try_parse(remaining)?;
+            let (subwindowmode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "subwindowmode")?;
             let subwindowmode = subwindowmode.into();
             outer_remaining = remaining;
             Some(subwindowmode)
@@ -1776,7 +1822,7 @@ impl CreatePictureAux {
         };
         let polyedge = if switch_expr & u32::from(CP::POLY_EDGE) != 0 {
             let remaining = outer_remaining;
-            let (polyedge, remaining) = u32::try_parse(remaining)?;
+            let (polyedge, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "polyedge")?;
             let polyedge = polyedge.into();
             outer_remaining = remaining;
             Some(polyedge)
@@ -1785,7 +1831,7 @@ impl CreatePictureAux {
         };
         let polymode = if switch_expr & u32::from(CP::POLY_MODE) != 0 {
             let remaining = outer_remaining;
-            let (polymode, remaining) = u32::try_parse(remaining)?;
+            let (polymode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "polymode")?;
             let polymode = polymode.into();
             outer_remaining = remaining;
             Some(polymode)
@@ -1794,7 +1840,7 @@ impl CreatePictureAux {
         };
         let dither = if switch_expr & u32::from(CP::DITHER) != 0 {
             let remaining = outer_remaining;
-            let (dither, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (dither, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "dither")?;
             outer_remaining = remaining;
             Some(dither)
         } else {
@@ -1802,7 +1848,7 @@ impl CreatePictureAux {
         };
         let componentalpha = if switch_expr & u32::from(CP::COMPONENT_ALPHA) != 0 {
             let remaining = outer_remaining;
-            let (componentalpha, remaining) = u32::try_parse(remaining)?;
+            let (componentalpha, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "componentalpha")?;
             outer_remaining = remaining;
             Some(componentalpha)
         } else {
@@ -2048,10 +2094,10 @@ impl<'input> CreatePictureRequest<'input> {
         if header.minor_opcode != CREATE_PICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pid, remaining) = Picture::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (format, remaining) = Pictformat::try_parse(remaining)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (pid, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "pid")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "format")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = CreatePictureAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(CreatePictureRequest {
@@ -2073,6 +2119,7 @@ impl<'input> CreatePictureRequest<'input> {
 }
 impl<'input> Request for CreatePictureRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2108,7 +2155,7 @@ impl ChangePictureAux {
         let mut outer_remaining = value;
         let repeat = if switch_expr & u32::from(CP::REPEAT) != 0 {
             let remaining = outer_remaining;
-            let (repeat, remaining) = u32::try_parse(remaining)?;
+            let (repeat, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "repeat")?;
             let repeat = repeat.into();
             outer_remaining = remaining;
             Some(repeat)
@@ -2117,7 +2164,7 @@ impl ChangePictureAux {
         };
         let alphamap = if switch_expr & u32::from(CP::ALPHA_MAP) != 0 {
             let remaining = outer_remaining;
-            let (alphamap, remaining) = Picture::try_parse(remaining)?;
+            let (alphamap, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "alphamap")?;
             outer_remaining = remaining;
             Some(alphamap)
         } else {
@@ -2125,7 +2172,7 @@ impl ChangePictureAux {
         };
         let alphaxorigin = if switch_expr & u32::from(CP::ALPHA_X_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (alphaxorigin, remaining) = i32::try_parse(remaining)?;
+            let (alphaxorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "alphaxorigin")?;
             outer_remaining = remaining;
             Some(alphaxorigin)
         } else {
@@ -2133,7 +2180,7 @@ impl ChangePictureAux {
         };
         let alphayorigin = if switch_expr & u32::from(CP::ALPHA_Y_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (alphayorigin, remaining) = i32::try_parse(remaining)?;
+            let (alphayorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "alphayorigin")?;
             outer_remaining = remaining;
             Some(alphayorigin)
         } else {
@@ -2141,7 +2188,7 @@ impl ChangePictureAux {
         };
         let clipxorigin = if switch_expr & u32::from(CP::CLIP_X_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (clipxorigin, remaining) = i32::try_parse(remaining)?;
+            let (clipxorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clipxorigin")?;
             outer_remaining = remaining;
             Some(clipxorigin)
         } else {
@@ -2149,7 +2196,7 @@ impl ChangePictureAux {
         };
         let clipyorigin = if switch_expr & u32::from(CP::CLIP_Y_ORIGIN) != 0 {
             let remaining = outer_remaining;
-            let (clipyorigin, remaining) = i32::try_parse(remaining)?;
+            let (clipyorigin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clipyorigin")?;
             outer_remaining = remaining;
             Some(clipyorigin)
         } else {
@@ -2157,7 +2204,7 @@ impl ChangePictureAux {
         };
         let clipmask = if switch_expr & u32::from(CP::CLIP_MASK) != 0 {
             let remaining = outer_remaining;
-            let (clipmask, remaining) = xproto::Pixmap::try_parse(remaining)?;
+            let (clipmask, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "clipmask")?;
             outer_remaining = remaining;
             Some(clipmask)
         } else {
@@ -2165,7 +2212,7 @@ impl ChangePictureAux {
         };
         let graphicsexposure = if switch_expr & u32::from(CP::GRAPHICS_EXPOSURE) != 0 {
             let remaining = outer_remaining;
-            let (graphicsexposure, remaining) = u32::try_parse(remaining)?;
+            let (graphicsexposure, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "graphicsexposure")?;
             outer_remaining = remaining;
             Some(graphicsexposure)
         } else {
@@ -2173,7 +2220,7 @@ impl ChangePictureAux {
         };
         let subwindowmode = if switch_expr & u32::from(CP::SUBWINDOW_MODE) != 0 {
             let remaining = outer_remaining;
-            let (subwindowmode, remaining) = u32::try_parse(remaining)?;
+            let (subwindowmode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "subwindowmode")?;
             let subwindowmode = subwindowmode.into();
             outer_remaining = remaining;
             Some(subwindowmode)
@@ -2182,7 +2229,7 @@ impl ChangePictureAux {
         };
         let polyedge = if switch_expr & u32::from(CP::POLY_EDGE) != 0 {
             let remaining = outer_remaining;
-            let (polyedge, remaining) = u32::try_parse(remaining)?;
+            let (polyedge, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "polyedge")?;
             let polyedge = polyedge.into();
             outer_remaining = remaining;
             Some(polyedge)
@@ -2191,7 +2238,7 @@ impl ChangePictureAux {
         };
         let polymode = if switch_expr & u32::from(CP::POLY_MODE) != 0 {
             let remaining = outer_remaining;
-            let (polymode, remaining) = u32::try_parse(remaining)?;
+            let (polymode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "polymode")?;
             let polymode = polymode.into();
             outer_remaining = remaining;
             Some(polymode)
@@ -2200,7 +2247,7 @@ impl ChangePictureAux {
         };
         let dither = if switch_expr & u32::from(CP::DITHER) != 0 {
             let remaining = outer_remaining;
-            let (dither, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (dither, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "dither")?;
             outer_remaining = remaining;
             Some(dither)
         } else {
@@ -2208,7 +2255,7 @@ impl ChangePictureAux {
         };
         let componentalpha = if switch_expr & u32::from(CP::COMPONENT_ALPHA) != 0 {
             let remaining = outer_remaining;
-            let (componentalpha, remaining) = u32::try_parse(remaining)?;
+            let (componentalpha, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "componentalpha")?;
             outer_remaining = remaining;
             Some(componentalpha)
         } else {
@@ -2442,8 +2489,8 @@ impl<'input> ChangePictureRequest<'input> {
         if header.minor_opcode != CHANGE_PICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = ChangePictureAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(ChangePictureRequest {
@@ -2461,6 +2508,7 @@ impl<'input> ChangePictureRequest<'input> {
 }
 impl<'input> Request for ChangePictureRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2518,9 +2566,9 @@ impl<'input> SetPictureClipRectanglesRequest<'input> {
         if header.minor_opcode != SET_PICTURE_CLIP_RECTANGLES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (clip_x_origin, remaining) = i16::try_parse(remaining)?;
-        let (clip_y_origin, remaining) = i16::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (clip_x_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_x_origin")?;
+        let (clip_y_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_y_origin")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -2549,6 +2597,7 @@ impl<'input> SetPictureClipRectanglesRequest<'input> {
 }
 impl<'input> Request for SetPictureClipRectanglesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2593,7 +2642,7 @@ impl FreePictureRequest {
         if header.minor_opcode != FREE_PICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
         let _ = remaining;
         Ok(FreePictureRequest {
             picture,
@@ -2602,6 +2651,7 @@ impl FreePictureRequest {
 }
 impl Request for FreePictureRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2696,20 +2746,20 @@ impl CompositeRequest {
         if header.minor_opcode != COMPOSITE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (mask, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (mask_x, remaining) = i16::try_parse(remaining)?;
-        let (mask_y, remaining) = i16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (mask, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "mask")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (mask_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "mask_x")?;
+        let (mask_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "mask_y")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(CompositeRequest {
             op,
@@ -2729,6 +2779,7 @@ impl CompositeRequest {
 }
 impl Request for CompositeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2804,14 +2855,14 @@ impl<'input> TrapezoidsRequest<'input> {
         if header.minor_opcode != TRAPEZOIDS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut traps = Vec::new();
@@ -2846,6 +2897,7 @@ impl<'input> TrapezoidsRequest<'input> {
 }
 impl<'input> Request for TrapezoidsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2921,14 +2973,14 @@ impl<'input> TrianglesRequest<'input> {
         if header.minor_opcode != TRIANGLES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut triangles = Vec::new();
@@ -2963,6 +3015,7 @@ impl<'input> TrianglesRequest<'input> {
 }
 impl<'input> Request for TrianglesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3038,14 +3091,14 @@ impl<'input> TriStripRequest<'input> {
         if header.minor_opcode != TRI_STRIP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut points = Vec::new();
@@ -3080,6 +3133,7 @@ impl<'input> TriStripRequest<'input> {
 }
 impl<'input> Request for TriStripRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3155,14 +3209,14 @@ impl<'input> TriFanRequest<'input> {
         if header.minor_opcode != TRI_FAN_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut points = Vec::new();
@@ -3197,6 +3251,7 @@ impl<'input> TriFanRequest<'input> {
 }
 impl<'input> Request for TriFanRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3247,8 +3302,8 @@ impl CreateGlyphSetRequest {
         if header.minor_opcode != CREATE_GLYPH_SET_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (gsid, remaining) = Glyphset::try_parse(value)?;
-        let (format, remaining) = Pictformat::try_parse(remaining)?;
+        let (gsid, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(value), "gsid")?;
+        let (format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "format")?;
         let _ = remaining;
         Ok(CreateGlyphSetRequest {
             gsid,
@@ -3258,6 +3313,7 @@ impl CreateGlyphSetRequest {
 }
 impl Request for CreateGlyphSetRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3308,8 +3364,8 @@ impl ReferenceGlyphSetRequest {
         if header.minor_opcode != REFERENCE_GLYPH_SET_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (gsid, remaining) = Glyphset::try_parse(value)?;
-        let (existing, remaining) = Glyphset::try_parse(remaining)?;
+        let (gsid, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(value), "gsid")?;
+        let (existing, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(remaining), "existing")?;
         let _ = remaining;
         Ok(ReferenceGlyphSetRequest {
             gsid,
@@ -3319,6 +3375,7 @@ impl ReferenceGlyphSetRequest {
 }
 impl Request for ReferenceGlyphSetRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3363,7 +3420,7 @@ impl FreeGlyphSetRequest {
         if header.minor_opcode != FREE_GLYPH_SET_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glyphset, remaining) = Glyphset::try_parse(value)?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(value), "glyphset")?;
         let _ = remaining;
         Ok(FreeGlyphSetRequest {
             glyphset,
@@ -3372,6 +3429,7 @@ impl FreeGlyphSetRequest {
 }
 impl Request for FreeGlyphSetRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3433,10 +3491,10 @@ impl<'input> AddGlyphsRequest<'input> {
         if header.minor_opcode != ADD_GLYPHS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glyphset, remaining) = Glyphset::try_parse(value)?;
-        let (glyphs_len, remaining) = u32::try_parse(remaining)?;
-        let (glyphids, remaining) = crate::x11_utils::parse_list::<u32>(remaining, glyphs_len.try_to_usize()?)?;
-        let (glyphs, remaining) = crate::x11_utils::parse_list::<Glyphinfo>(remaining, glyphs_len.try_to_usize()?)?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(value), "glyphset")?;
+        let (glyphs_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "glyphs_len")?;
+        let (glyphids, remaining) = crate::x11_utils::parse_list::<u32>(remaining, glyphs_len.try_to_usize()?, Some("glyphids"))?;
+        let (glyphs, remaining) = crate::x11_utils::parse_list::<Glyphinfo>(remaining, glyphs_len.try_to_usize()?, Some("glyphs"))?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(AddGlyphsRequest {
@@ -3458,6 +3516,7 @@ impl<'input> AddGlyphsRequest<'input> {
 }
 impl<'input> Request for AddGlyphsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3507,7 +3566,7 @@ impl<'input> FreeGlyphsRequest<'input> {
         if header.minor_opcode != FREE_GLYPHS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (glyphset, remaining) = Glyphset::try_parse(value)?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(value), "glyphset")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut glyphs = Vec::new();
@@ -3532,6 +3591,7 @@ impl<'input> FreeGlyphsRequest<'input> {
 }
 impl<'input> Request for FreeGlyphsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3612,15 +3672,15 @@ impl<'input> CompositeGlyphs8Request<'input> {
         if header.minor_opcode != COMPOSITE_GLYPHS8_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (glyphset, remaining) = Glyphset::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(remaining), "glyphset")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let (glyphcmds, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(CompositeGlyphs8Request {
@@ -3650,6 +3710,7 @@ impl<'input> CompositeGlyphs8Request<'input> {
 }
 impl<'input> Request for CompositeGlyphs8Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3730,15 +3791,15 @@ impl<'input> CompositeGlyphs16Request<'input> {
         if header.minor_opcode != COMPOSITE_GLYPHS16_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (glyphset, remaining) = Glyphset::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(remaining), "glyphset")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let (glyphcmds, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(CompositeGlyphs16Request {
@@ -3768,6 +3829,7 @@ impl<'input> CompositeGlyphs16Request<'input> {
 }
 impl<'input> Request for CompositeGlyphs16Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3848,15 +3910,15 @@ impl<'input> CompositeGlyphs32Request<'input> {
         if header.minor_opcode != COMPOSITE_GLYPHS32_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (src, remaining) = Picture::try_parse(remaining)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (mask_format, remaining) = Pictformat::try_parse(remaining)?;
-        let (glyphset, remaining) = Glyphset::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (src, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "src")?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (mask_format, remaining) = crate::x11_utils::in_field(Pictformat::try_parse(remaining), "mask_format")?;
+        let (glyphset, remaining) = crate::x11_utils::in_field(Glyphset::try_parse(remaining), "glyphset")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let (glyphcmds, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(CompositeGlyphs32Request {
@@ -3886,6 +3948,7 @@ impl<'input> CompositeGlyphs32Request<'input> {
 }
 impl<'input> Request for CompositeGlyphs32Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3951,11 +4014,11 @@ impl<'input> FillRectanglesRequest<'input> {
         if header.minor_opcode != FILL_RECTANGLES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (op, remaining) = u8::try_parse(value)?;
+        let (op, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "op")?;
         let op = op.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (dst, remaining) = Picture::try_parse(remaining)?;
-        let (color, remaining) = Color::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (dst, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "dst")?;
+        let (color, remaining) = crate::x11_utils::in_field(Color::try_parse(remaining), "color")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rects = Vec::new();
@@ -3984,6 +4047,7 @@ impl<'input> FillRectanglesRequest<'input> {
 }
 impl<'input> Request for FillRectanglesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4042,10 +4106,10 @@ impl CreateCursorRequest {
         if header.minor_opcode != CREATE_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cid, remaining) = xproto::Cursor::try_parse(value)?;
-        let (source, remaining) = Picture::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
+        let (cid, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "cid")?;
+        let (source, remaining) = crate::x11_utils::in_field(Picture::try_parse(remaining), "source")?;
+        let (x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y")?;
         let _ = remaining;
         Ok(CreateCursorRequest {
             cid,
@@ -4057,6 +4121,7 @@ impl CreateCursorRequest {
 }
 impl Request for CreateCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4083,15 +4148,15 @@ pub struct Transform {
 }
 impl TryParse for Transform {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (matrix11, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix12, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix13, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix21, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix22, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix23, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix31, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix32, remaining) = Fixed::try_parse(remaining)?;
-        let (matrix33, remaining) = Fixed::try_parse(remaining)?;
+        let (matrix11, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix11")?;
+        let (matrix12, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix12")?;
+        let (matrix13, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix13")?;
+        let (matrix21, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix21")?;
+        let (matrix22, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix22")?;
+        let (matrix23, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix23")?;
+        let (matrix31, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix31")?;
+        let (matrix32, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix32")?;
+        let (matrix33, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "matrix33")?;
         let result = Transform { matrix11, matrix12, matrix13, matrix21, matrix22, matrix23, matrix31, matrix32, matrix33 };
         Ok((result, remaining))
     }
@@ -4232,8 +4297,8 @@ impl SetPictureTransformRequest {
         if header.minor_opcode != SET_PICTURE_TRANSFORM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (transform, remaining) = Transform::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (transform, remaining) = crate::x11_utils::in_field(Transform::try_parse(remaining), "transform")?;
         let _ = remaining;
         Ok(SetPictureTransformRequest {
             picture,
@@ -4243,6 +4308,7 @@ impl SetPictureTransformRequest {
 }
 impl Request for SetPictureTransformRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4287,7 +4353,7 @@ impl QueryFiltersRequest {
         if header.minor_opcode != QUERY_FILTERS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(QueryFiltersRequest {
             drawable,
@@ -4296,6 +4362,7 @@ impl QueryFiltersRequest {
 }
 impl Request for QueryFiltersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4319,22 +4386,21 @@ pub struct QueryFiltersReply {
 impl TryParse for QueryFiltersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_aliases, remaining) = u32::try_parse(remaining)?;
-        let (num_filters, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (aliases, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_aliases.try_to_usize()?)?;
-        let (filters, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, num_filters.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_aliases, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_aliases")?;
+        let (num_filters, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_filters")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (aliases, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_aliases.try_to_usize()?, Some("aliases"))?;
+        let (filters, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, num_filters.try_to_usize()?, Some("filters"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryFiltersReply { sequence, length, aliases, filters };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4389,6 +4455,28 @@ impl QueryFiltersReply {
             .try_into().unwrap()
     }
 }
+impl QueryFiltersReply {
+    /// Lazily parse the `aliases` list from the raw bytes of a `QueryFiltersReply`.
+    ///
+    /// In contrast to parsing the whole `QueryFiltersReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn aliases_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u16>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_aliases, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_aliases")?;
+        let (num_filters, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_filters")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_aliases.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetPictureFilter request
 pub const SET_PICTURE_FILTER_REQUEST: u8 = 30;
@@ -4438,14 +4526,14 @@ impl<'input> SetPictureFilterRequest<'input> {
         if header.minor_opcode != SET_PICTURE_FILTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (filter_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (filter, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (filter_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "filter_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (filter, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, filter_len.try_to_usize()?), "filter")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut values = Vec::new();
@@ -4472,6 +4560,7 @@ impl<'input> SetPictureFilterRequest<'input> {
 }
 impl<'input> Request for SetPictureFilterRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4491,8 +4580,8 @@ pub struct Animcursorelt {
 }
 impl TryParse for Animcursorelt {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
-        let (delay, remaining) = u32::try_parse(remaining)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
+        let (delay, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "delay")?;
         let result = Animcursorelt { cursor, delay };
         Ok((result, remaining))
     }
@@ -4558,7 +4647,7 @@ impl<'input> CreateAnimCursorRequest<'input> {
         if header.minor_opcode != CREATE_ANIM_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cid, remaining) = xproto::Cursor::try_parse(value)?;
+        let (cid, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "cid")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut cursors = Vec::new();
@@ -4583,6 +4672,7 @@ impl<'input> CreateAnimCursorRequest<'input> {
 }
 impl<'input> Request for CreateAnimCursorRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4603,9 +4693,9 @@ pub struct Spanfix {
 }
 impl TryParse for Spanfix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (l, remaining) = Fixed::try_parse(remaining)?;
-        let (r, remaining) = Fixed::try_parse(remaining)?;
-        let (y, remaining) = Fixed::try_parse(remaining)?;
+        let (l, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "l")?;
+        let (r, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "r")?;
+        let (y, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "y")?;
         let result = Spanfix { l, r, y };
         Ok((result, remaining))
     }
@@ -4647,8 +4737,8 @@ pub struct Trap {
 }
 impl TryParse for Trap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = Spanfix::try_parse(remaining)?;
-        let (bot, remaining) = Spanfix::try_parse(remaining)?;
+        let (top, remaining) = crate::x11_utils::in_field(Spanfix::try_parse(remaining), "top")?;
+        let (bot, remaining) = crate::x11_utils::in_field(Spanfix::try_parse(remaining), "bot")?;
         let result = Trap { top, bot };
         Ok((result, remaining))
     }
@@ -4738,9 +4828,9 @@ impl<'input> AddTrapsRequest<'input> {
         if header.minor_opcode != ADD_TRAPS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (x_off, remaining) = i16::try_parse(remaining)?;
-        let (y_off, remaining) = i16::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (x_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_off")?;
+        let (y_off, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_off")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut traps = Vec::new();
@@ -4769,6 +4859,7 @@ impl<'input> AddTrapsRequest<'input> {
 }
 impl<'input> Request for AddTrapsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4823,8 +4914,8 @@ impl CreateSolidFillRequest {
         if header.minor_opcode != CREATE_SOLID_FILL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (color, remaining) = Color::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (color, remaining) = crate::x11_utils::in_field(Color::try_parse(remaining), "color")?;
         let _ = remaining;
         Ok(CreateSolidFillRequest {
             picture,
@@ -4834,6 +4925,7 @@ impl CreateSolidFillRequest {
 }
 impl Request for CreateSolidFillRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4913,12 +5005,12 @@ impl<'input> CreateLinearGradientRequest<'input> {
         if header.minor_opcode != CREATE_LINEAR_GRADIENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (p1, remaining) = Pointfix::try_parse(remaining)?;
-        let (p2, remaining) = Pointfix::try_parse(remaining)?;
-        let (num_stops, remaining) = u32::try_parse(remaining)?;
-        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?)?;
-        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (p1, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p1")?;
+        let (p2, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "p2")?;
+        let (num_stops, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_stops")?;
+        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?, Some("stops"))?;
+        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?, Some("colors"))?;
         let _ = remaining;
         Ok(CreateLinearGradientRequest {
             picture,
@@ -4941,6 +5033,7 @@ impl<'input> CreateLinearGradientRequest<'input> {
 }
 impl<'input> Request for CreateLinearGradientRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5032,14 +5125,14 @@ impl<'input> CreateRadialGradientRequest<'input> {
         if header.minor_opcode != CREATE_RADIAL_GRADIENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (inner, remaining) = Pointfix::try_parse(remaining)?;
-        let (outer, remaining) = Pointfix::try_parse(remaining)?;
-        let (inner_radius, remaining) = Fixed::try_parse(remaining)?;
-        let (outer_radius, remaining) = Fixed::try_parse(remaining)?;
-        let (num_stops, remaining) = u32::try_parse(remaining)?;
-        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?)?;
-        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (inner, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "inner")?;
+        let (outer, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "outer")?;
+        let (inner_radius, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "inner_radius")?;
+        let (outer_radius, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "outer_radius")?;
+        let (num_stops, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_stops")?;
+        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?, Some("stops"))?;
+        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?, Some("colors"))?;
         let _ = remaining;
         Ok(CreateRadialGradientRequest {
             picture,
@@ -5066,6 +5159,7 @@ impl<'input> CreateRadialGradientRequest<'input> {
 }
 impl<'input> Request for CreateRadialGradientRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5141,12 +5235,12 @@ impl<'input> CreateConicalGradientRequest<'input> {
         if header.minor_opcode != CREATE_CONICAL_GRADIENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (center, remaining) = Pointfix::try_parse(remaining)?;
-        let (angle, remaining) = Fixed::try_parse(remaining)?;
-        let (num_stops, remaining) = u32::try_parse(remaining)?;
-        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?)?;
-        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?)?;
+        let (picture, remaining) = crate::x11_utils::in_field(Picture::try_parse(value), "picture")?;
+        let (center, remaining) = crate::x11_utils::in_field(Pointfix::try_parse(remaining), "center")?;
+        let (angle, remaining) = crate::x11_utils::in_field(Fixed::try_parse(remaining), "angle")?;
+        let (num_stops, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_stops")?;
+        let (stops, remaining) = crate::x11_utils::parse_list::<Fixed>(remaining, num_stops.try_to_usize()?, Some("stops"))?;
+        let (colors, remaining) = crate::x11_utils::parse_list::<Color>(remaining, num_stops.try_to_usize()?, Some("colors"))?;
         let _ = remaining;
         Ok(CreateConicalGradientRequest {
             picture,
@@ -5169,6 +5263,7 @@ impl<'input> CreateConicalGradientRequest<'input> {
 }
 impl<'input> Request for CreateConicalGradientRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -44,8 +44,8 @@ pub struct Client {
 }
 impl TryParse for Client {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource_base, remaining) = u32::try_parse(remaining)?;
-        let (resource_mask, remaining) = u32::try_parse(remaining)?;
+        let (resource_base, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resource_base")?;
+        let (resource_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resource_mask")?;
         let result = Client { resource_base, resource_mask };
         Ok((result, remaining))
     }
@@ -81,8 +81,8 @@ pub struct Type {
 }
 impl TryParse for Type {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (count, remaining) = u32::try_parse(remaining)?;
+        let (resource_type, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "resource_type")?;
+        let (count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "count")?;
         let result = Type { resource_type, count };
         Ok((result, remaining))
     }
@@ -156,7 +156,7 @@ impl core::fmt::Debug for ClientIdMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ClientIdMask, u32);
+bitmask_binop!(ClientIdMask, u32, [CLIENT_XID, LOCAL_CLIENT_PID]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -166,8 +166,8 @@ pub struct ClientIdSpec {
 }
 impl TryParse for ClientIdSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (client, remaining) = u32::try_parse(remaining)?;
-        let (mask, remaining) = u32::try_parse(remaining)?;
+        let (client, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mask")?;
         let mask = mask.into();
         let result = ClientIdSpec { client, mask };
         Ok((result, remaining))
@@ -204,9 +204,9 @@ pub struct ClientIdValue {
 }
 impl TryParse for ClientIdValue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (spec, remaining) = ClientIdSpec::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(length).checked_div(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (spec, remaining) = crate::x11_utils::in_field(ClientIdSpec::try_parse(remaining), "spec")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (value, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(length).checked_div(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("value"))?;
         let result = ClientIdValue { spec, value };
         Ok((result, remaining))
     }
@@ -251,8 +251,8 @@ pub struct ResourceIdSpec {
 }
 impl TryParse for ResourceIdSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resource, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u32::try_parse(remaining)?;
+        let (resource, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resource")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "type_")?;
         let result = ResourceIdSpec { resource, type_ };
         Ok((result, remaining))
     }
@@ -290,10 +290,10 @@ pub struct ResourceSizeSpec {
 }
 impl TryParse for ResourceSizeSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (spec, remaining) = ResourceIdSpec::try_parse(remaining)?;
-        let (bytes, remaining) = u32::try_parse(remaining)?;
-        let (ref_count, remaining) = u32::try_parse(remaining)?;
-        let (use_count, remaining) = u32::try_parse(remaining)?;
+        let (spec, remaining) = crate::x11_utils::in_field(ResourceIdSpec::try_parse(remaining), "spec")?;
+        let (bytes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes")?;
+        let (ref_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ref_count")?;
+        let (use_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "use_count")?;
         let result = ResourceSizeSpec { spec, bytes, ref_count, use_count };
         Ok((result, remaining))
     }
@@ -345,9 +345,9 @@ pub struct ResourceSizeValue {
 }
 impl TryParse for ResourceSizeValue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (size, remaining) = ResourceSizeSpec::try_parse(remaining)?;
-        let (num_cross_references, remaining) = u32::try_parse(remaining)?;
-        let (cross_references, remaining) = crate::x11_utils::parse_list::<ResourceSizeSpec>(remaining, num_cross_references.try_to_usize()?)?;
+        let (size, remaining) = crate::x11_utils::in_field(ResourceSizeSpec::try_parse(remaining), "size")?;
+        let (num_cross_references, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_cross_references")?;
+        let (cross_references, remaining) = crate::x11_utils::parse_list::<ResourceSizeSpec>(remaining, num_cross_references.try_to_usize()?, Some("cross_references"))?;
         let result = ResourceSizeValue { size, cross_references };
         Ok((result, remaining))
     }
@@ -418,8 +418,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major, remaining) = u8::try_parse(value)?;
-        let (client_minor, remaining) = u8::try_parse(remaining)?;
+        let (client_major, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "client_major")?;
+        let (client_minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "client_minor")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major,
@@ -429,6 +429,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -452,19 +453,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major")?;
+        let (server_minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, server_major, server_minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -536,6 +536,7 @@ impl QueryClientsRequest {
 }
 impl Request for QueryClientsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -558,20 +559,19 @@ pub struct QueryClientsReply {
 impl TryParse for QueryClientsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_clients, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (clients, remaining) = crate::x11_utils::parse_list::<Client>(remaining, num_clients.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_clients, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_clients")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (clients, remaining) = crate::x11_utils::parse_list::<Client>(remaining, num_clients.try_to_usize()?, Some("clients"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryClientsReply { sequence, length, clients };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -610,6 +610,27 @@ impl QueryClientsReply {
             .try_into().unwrap()
     }
 }
+impl QueryClientsReply {
+    /// Lazily parse the `clients` list from the raw bytes of a `QueryClientsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryClientsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn clients_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Client>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_clients, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_clients")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_clients.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryClientResources request
 pub const QUERY_CLIENT_RESOURCES_REQUEST: u8 = 2;
@@ -644,7 +665,7 @@ impl QueryClientResourcesRequest {
         if header.minor_opcode != QUERY_CLIENT_RESOURCES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (xid, remaining) = u32::try_parse(value)?;
+        let (xid, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "xid")?;
         let _ = remaining;
         Ok(QueryClientResourcesRequest {
             xid,
@@ -653,6 +674,7 @@ impl QueryClientResourcesRequest {
 }
 impl Request for QueryClientResourcesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -675,20 +697,19 @@ pub struct QueryClientResourcesReply {
 impl TryParse for QueryClientResourcesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_types, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<Type>(remaining, num_types.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_types, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_types")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (types, remaining) = crate::x11_utils::parse_list::<Type>(remaining, num_types.try_to_usize()?, Some("types"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryClientResourcesReply { sequence, length, types };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -727,6 +748,27 @@ impl QueryClientResourcesReply {
             .try_into().unwrap()
     }
 }
+impl QueryClientResourcesReply {
+    /// Lazily parse the `types` list from the raw bytes of a `QueryClientResourcesReply`.
+    ///
+    /// In contrast to parsing the whole `QueryClientResourcesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn types_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Type>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_types, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_types")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_types.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryClientPixmapBytes request
 pub const QUERY_CLIENT_PIXMAP_BYTES_REQUEST: u8 = 3;
@@ -761,7 +803,7 @@ impl QueryClientPixmapBytesRequest {
         if header.minor_opcode != QUERY_CLIENT_PIXMAP_BYTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (xid, remaining) = u32::try_parse(value)?;
+        let (xid, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "xid")?;
         let _ = remaining;
         Ok(QueryClientPixmapBytesRequest {
             xid,
@@ -770,6 +812,7 @@ impl QueryClientPixmapBytesRequest {
 }
 impl Request for QueryClientPixmapBytesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -793,19 +836,18 @@ pub struct QueryClientPixmapBytesReply {
 impl TryParse for QueryClientPixmapBytesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (bytes, remaining) = u32::try_parse(remaining)?;
-        let (bytes_overflow, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (bytes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes")?;
+        let (bytes_overflow, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_overflow")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryClientPixmapBytesReply { sequence, length, bytes, bytes_overflow };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -886,8 +928,8 @@ impl<'input> QueryClientIdsRequest<'input> {
         if header.minor_opcode != QUERY_CLIENT_IDS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (num_specs, remaining) = u32::try_parse(value)?;
-        let (specs, remaining) = crate::x11_utils::parse_list::<ClientIdSpec>(remaining, num_specs.try_to_usize()?)?;
+        let (num_specs, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "num_specs")?;
+        let (specs, remaining) = crate::x11_utils::parse_list::<ClientIdSpec>(remaining, num_specs.try_to_usize()?, Some("specs"))?;
         let _ = remaining;
         Ok(QueryClientIdsRequest {
             specs: Cow::Owned(specs),
@@ -902,6 +944,7 @@ impl<'input> QueryClientIdsRequest<'input> {
 }
 impl<'input> Request for QueryClientIdsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -924,20 +967,19 @@ pub struct QueryClientIdsReply {
 impl TryParse for QueryClientIdsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_ids, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (ids, remaining) = crate::x11_utils::parse_list::<ClientIdValue>(remaining, num_ids.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_ids, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_ids")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (ids, remaining) = crate::x11_utils::parse_list::<ClientIdValue>(remaining, num_ids.try_to_usize()?, Some("ids"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryClientIdsReply { sequence, length, ids };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -976,6 +1018,27 @@ impl QueryClientIdsReply {
             .try_into().unwrap()
     }
 }
+impl QueryClientIdsReply {
+    /// Lazily parse the `ids` list from the raw bytes of a `QueryClientIdsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryClientIdsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn ids_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ClientIdValue>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_ids, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_ids")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_ids.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryResourceBytes request
 pub const QUERY_RESOURCE_BYTES_REQUEST: u8 = 5;
@@ -1021,9 +1084,9 @@ impl<'input> QueryResourceBytesRequest<'input> {
         if header.minor_opcode != QUERY_RESOURCE_BYTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client, remaining) = u32::try_parse(value)?;
-        let (num_specs, remaining) = u32::try_parse(remaining)?;
-        let (specs, remaining) = crate::x11_utils::parse_list::<ResourceIdSpec>(remaining, num_specs.try_to_usize()?)?;
+        let (client, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "client")?;
+        let (num_specs, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_specs")?;
+        let (specs, remaining) = crate::x11_utils::parse_list::<ResourceIdSpec>(remaining, num_specs.try_to_usize()?, Some("specs"))?;
         let _ = remaining;
         Ok(QueryResourceBytesRequest {
             client,
@@ -1040,6 +1103,7 @@ impl<'input> QueryResourceBytesRequest<'input> {
 }
 impl<'input> Request for QueryResourceBytesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1062,20 +1126,19 @@ pub struct QueryResourceBytesReply {
 impl TryParse for QueryResourceBytesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_sizes, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (sizes, remaining) = crate::x11_utils::parse_list::<ResourceSizeValue>(remaining, num_sizes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_sizes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_sizes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list::<ResourceSizeValue>(remaining, num_sizes.try_to_usize()?, Some("sizes"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryResourceBytesReply { sequence, length, sizes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1114,4 +1177,25 @@ impl QueryResourceBytesReply {
             .try_into().unwrap()
     }
 }
+impl QueryResourceBytesReply {
+    /// Lazily parse the `sizes` list from the raw bytes of a `QueryResourceBytesReply`.
+    ///
+    /// In contrast to parsing the whole `QueryResourceBytesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn sizes_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ResourceSizeValue>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_sizes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_sizes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_sizes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
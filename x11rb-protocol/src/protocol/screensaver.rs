@@ -143,7 +143,7 @@ impl core::fmt::Debug for Event  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(Event, u32);
+bitmask_binop!(Event, u32, [NOTIFY_MASK, CYCLE_MASK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -243,9 +243,9 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u8::try_parse(value)?;
-        let (client_minor_version, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "client_minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major_version,
@@ -255,6 +255,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -278,20 +279,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major_version, remaining) = u16::try_parse(remaining)?;
-        let (server_minor_version, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major_version")?;
+        let (server_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, server_major_version, server_minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -384,7 +384,7 @@ impl QueryInfoRequest {
         if header.minor_opcode != QUERY_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(QueryInfoRequest {
             drawable,
@@ -393,6 +393,7 @@ impl QueryInfoRequest {
 }
 impl Request for QueryInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -420,24 +421,23 @@ pub struct QueryInfoReply {
 impl TryParse for QueryInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (saver_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (ms_until_server, remaining) = u32::try_parse(remaining)?;
-        let (ms_since_user_input, remaining) = u32::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (saver_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "saver_window")?;
+        let (ms_until_server, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ms_until_server")?;
+        let (ms_since_user_input, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ms_since_user_input")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
+        let (kind, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "kind")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 7)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let kind = kind.into();
         let result = QueryInfoReply { state, sequence, length, saver_window, ms_until_server, ms_since_user_input, event_mask, kind };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -543,8 +543,8 @@ impl SelectInputRequest {
         if header.minor_opcode != SELECT_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
         let _ = remaining;
         Ok(SelectInputRequest {
@@ -555,6 +555,7 @@ impl SelectInputRequest {
 }
 impl Request for SelectInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -592,7 +593,7 @@ impl SetAttributesAux {
         let mut outer_remaining = value;
         let background_pixmap = if switch_expr & u32::from(xproto::CW::BACK_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (background_pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
+            let (background_pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "background_pixmap")?;
             outer_remaining = remaining;
             Some(background_pixmap)
         } else {
@@ -600,7 +601,7 @@ impl SetAttributesAux {
         };
         let background_pixel = if switch_expr & u32::from(xproto::CW::BACK_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (background_pixel, remaining) = u32::try_parse(remaining)?;
+            let (background_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "background_pixel")?;
             outer_remaining = remaining;
             Some(background_pixel)
         } else {
@@ -608,7 +609,7 @@ impl SetAttributesAux {
         };
         let border_pixmap = if switch_expr & u32::from(xproto::CW::BORDER_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (border_pixmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
+            let (border_pixmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "border_pixmap")?;
             outer_remaining = remaining;
             Some(border_pixmap)
         } else {
@@ -616,7 +617,7 @@ impl SetAttributesAux {
         };
         let border_pixel = if switch_expr & u32::from(xproto::CW::BORDER_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (border_pixel, remaining) = u32::try_parse(remaining)?;
+            let (border_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "border_pixel")?;
             outer_remaining = remaining;
             Some(border_pixel)
         } else {
@@ -624,7 +625,7 @@ impl SetAttributesAux {
         };
         let bit_gravity = if switch_expr & u32::from(xproto::CW::BIT_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (bit_gravity, remaining) = u32::try_parse(remaining)?;
+            let (bit_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bit_gravity")?;
             let bit_gravity = bit_gravity.into();
             outer_remaining = remaining;
             Some(bit_gravity)
@@ -633,7 +634,7 @@ impl SetAttributesAux {
         };
         let win_gravity = if switch_expr & u32::from(xproto::CW::WIN_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (win_gravity, remaining) = u32::try_parse(remaining)?;
+            let (win_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "win_gravity")?;
             let win_gravity = win_gravity.into();
             outer_remaining = remaining;
             Some(win_gravity)
@@ -642,7 +643,7 @@ impl SetAttributesAux {
         };
         let backing_store = if switch_expr & u32::from(xproto::CW::BACKING_STORE) != 0 {
             let remaining = outer_remaining;
-            let (backing_store, remaining) = u32::try_parse(remaining)?;
+            let (backing_store, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_store")?;
             let backing_store = backing_store.into();
             outer_remaining = remaining;
             Some(backing_store)
@@ -651,7 +652,7 @@ impl SetAttributesAux {
         };
         let backing_planes = if switch_expr & u32::from(xproto::CW::BACKING_PLANES) != 0 {
             let remaining = outer_remaining;
-            let (backing_planes, remaining) = u32::try_parse(remaining)?;
+            let (backing_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_planes")?;
             outer_remaining = remaining;
             Some(backing_planes)
         } else {
@@ -659,7 +660,7 @@ impl SetAttributesAux {
         };
         let backing_pixel = if switch_expr & u32::from(xproto::CW::BACKING_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (backing_pixel, remaining) = u32::try_parse(remaining)?;
+            let (backing_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_pixel")?;
             outer_remaining = remaining;
             Some(backing_pixel)
         } else {
@@ -667,7 +668,7 @@ impl SetAttributesAux {
         };
         let override_redirect = if switch_expr & u32::from(xproto::CW::OVERRIDE_REDIRECT) != 0 {
             let remaining = outer_remaining;
-            let (override_redirect, remaining) = xproto::Bool32::try_parse(remaining)?;
+            let (override_redirect, remaining) = crate::x11_utils::in_field(xproto::Bool32::try_parse(remaining), "override_redirect")?;
             outer_remaining = remaining;
             Some(override_redirect)
         } else {
@@ -675,7 +676,7 @@ impl SetAttributesAux {
         };
         let save_under = if switch_expr & u32::from(xproto::CW::SAVE_UNDER) != 0 {
             let remaining = outer_remaining;
-            let (save_under, remaining) = xproto::Bool32::try_parse(remaining)?;
+            let (save_under, remaining) = crate::x11_utils::in_field(xproto::Bool32::try_parse(remaining), "save_under")?;
             outer_remaining = remaining;
             Some(save_under)
         } else {
@@ -683,7 +684,7 @@ impl SetAttributesAux {
         };
         let event_mask = if switch_expr & u32::from(xproto::CW::EVENT_MASK) != 0 {
             let remaining = outer_remaining;
-            let (event_mask, remaining) = u32::try_parse(remaining)?;
+            let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
             let event_mask = event_mask.into();
             outer_remaining = remaining;
             Some(event_mask)
@@ -692,7 +693,7 @@ impl SetAttributesAux {
         };
         let do_not_propogate_mask = if switch_expr & u32::from(xproto::CW::DONT_PROPAGATE) != 0 {
             let remaining = outer_remaining;
-            let (do_not_propogate_mask, remaining) = u32::try_parse(remaining)?;
+            let (do_not_propogate_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "do_not_propogate_mask")?;
             let do_not_propogate_mask = do_not_propogate_mask.into();
             outer_remaining = remaining;
             Some(do_not_propogate_mask)
@@ -701,7 +702,7 @@ impl SetAttributesAux {
         };
         let colormap = if switch_expr & u32::from(xproto::CW::COLORMAP) != 0 {
             let remaining = outer_remaining;
-            let (colormap, remaining) = xproto::Colormap::try_parse(remaining)?;
+            let (colormap, remaining) = crate::x11_utils::in_field(xproto::Colormap::try_parse(remaining), "colormap")?;
             outer_remaining = remaining;
             Some(colormap)
         } else {
@@ -709,7 +710,7 @@ impl SetAttributesAux {
         };
         let cursor = if switch_expr & u32::from(xproto::CW::CURSOR) != 0 {
             let remaining = outer_remaining;
-            let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
+            let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
             outer_remaining = remaining;
             Some(cursor)
         } else {
@@ -999,17 +1000,17 @@ impl<'input> SetAttributesRequest<'input> {
         if header.minor_opcode != SET_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let (class, remaining) = u8::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let (class, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class")?;
         let class = class.into();
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = SetAttributesAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(SetAttributesRequest {
@@ -1043,6 +1044,7 @@ impl<'input> SetAttributesRequest<'input> {
 }
 impl<'input> Request for SetAttributesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1087,7 +1089,7 @@ impl UnsetAttributesRequest {
         if header.minor_opcode != UNSET_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(UnsetAttributesRequest {
             drawable,
@@ -1096,6 +1098,7 @@ impl UnsetAttributesRequest {
 }
 impl Request for UnsetAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1140,7 +1143,7 @@ impl SuspendRequest {
         if header.minor_opcode != SUSPEND_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (suspend, remaining) = u32::try_parse(value)?;
+        let (suspend, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "suspend")?;
         let _ = remaining;
         Ok(SuspendRequest {
             suspend,
@@ -1149,6 +1152,7 @@ impl SuspendRequest {
 }
 impl Request for SuspendRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1177,15 +1181,15 @@ pub struct NotifyEvent {
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (forced, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (kind, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "kind")?;
+        let (forced, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "forced")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 14)?;
         let state = state.into();
         let kind = kind.into();
         let result = NotifyEvent { response_type, state, sequence, time, root, window, kind, forced };
@@ -185,17 +185,17 @@ pub struct NotifyEvent {
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (shape_kind, remaining) = Kind::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (affected_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (extents_x, remaining) = i16::try_parse(remaining)?;
-        let (extents_y, remaining) = i16::try_parse(remaining)?;
-        let (extents_width, remaining) = u16::try_parse(remaining)?;
-        let (extents_height, remaining) = u16::try_parse(remaining)?;
-        let (server_time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (shaped, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (shape_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "shape_kind")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (affected_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "affected_window")?;
+        let (extents_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "extents_x")?;
+        let (extents_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "extents_y")?;
+        let (extents_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "extents_width")?;
+        let (extents_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "extents_height")?;
+        let (server_time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "server_time")?;
+        let (shaped, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "shaped")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let shape_kind = shape_kind.into();
         let result = NotifyEvent { response_type, shape_kind, sequence, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped };
         let _ = remaining;
@@ -354,6 +354,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -377,19 +378,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -484,16 +484,16 @@ impl<'input> RectanglesRequest<'input> {
         if header.minor_opcode != RECTANGLES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (operation, remaining) = Op::try_parse(value)?;
+        let (operation, remaining) = crate::x11_utils::in_field(Op::try_parse(value), "operation")?;
         let operation = operation.into();
-        let (destination_kind, remaining) = Kind::try_parse(remaining)?;
+        let (destination_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "destination_kind")?;
         let destination_kind = destination_kind.into();
-        let (ordering, remaining) = u8::try_parse(remaining)?;
+        let (ordering, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ordering")?;
         let ordering = ordering.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x_offset, remaining) = i16::try_parse(remaining)?;
-        let (y_offset, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "destination_window")?;
+        let (x_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_offset")?;
+        let (y_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_offset")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -528,6 +528,7 @@ impl<'input> RectanglesRequest<'input> {
 }
 impl<'input> Request for RectanglesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -594,15 +595,15 @@ impl MaskRequest {
         if header.minor_opcode != MASK_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (operation, remaining) = Op::try_parse(value)?;
+        let (operation, remaining) = crate::x11_utils::in_field(Op::try_parse(value), "operation")?;
         let operation = operation.into();
-        let (destination_kind, remaining) = Kind::try_parse(remaining)?;
+        let (destination_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "destination_kind")?;
         let destination_kind = destination_kind.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x_offset, remaining) = i16::try_parse(remaining)?;
-        let (y_offset, remaining) = i16::try_parse(remaining)?;
-        let (source_bitmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "destination_window")?;
+        let (x_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_offset")?;
+        let (y_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_offset")?;
+        let (source_bitmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "source_bitmap")?;
         let _ = remaining;
         Ok(MaskRequest {
             operation,
@@ -616,6 +617,7 @@ impl MaskRequest {
 }
 impl Request for MaskRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -684,17 +686,17 @@ impl CombineRequest {
         if header.minor_opcode != COMBINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (operation, remaining) = Op::try_parse(value)?;
+        let (operation, remaining) = crate::x11_utils::in_field(Op::try_parse(value), "operation")?;
         let operation = operation.into();
-        let (destination_kind, remaining) = Kind::try_parse(remaining)?;
+        let (destination_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "destination_kind")?;
         let destination_kind = destination_kind.into();
-        let (source_kind, remaining) = Kind::try_parse(remaining)?;
+        let (source_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "source_kind")?;
         let source_kind = source_kind.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x_offset, remaining) = i16::try_parse(remaining)?;
-        let (y_offset, remaining) = i16::try_parse(remaining)?;
-        let (source_window, remaining) = xproto::Window::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "destination_window")?;
+        let (x_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_offset")?;
+        let (y_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_offset")?;
+        let (source_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "source_window")?;
         let _ = remaining;
         Ok(CombineRequest {
             operation,
@@ -709,6 +711,7 @@ impl CombineRequest {
 }
 impl Request for CombineRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -767,12 +770,12 @@ impl OffsetRequest {
         if header.minor_opcode != OFFSET_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (destination_kind, remaining) = Kind::try_parse(value)?;
+        let (destination_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(value), "destination_kind")?;
         let destination_kind = destination_kind.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (destination_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x_offset, remaining) = i16::try_parse(remaining)?;
-        let (y_offset, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "destination_window")?;
+        let (x_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_offset")?;
+        let (y_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_offset")?;
         let _ = remaining;
         Ok(OffsetRequest {
             destination_kind,
@@ -784,6 +787,7 @@ impl OffsetRequest {
 }
 impl Request for OffsetRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -828,7 +832,7 @@ impl QueryExtentsRequest {
         if header.minor_opcode != QUERY_EXTENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (destination_window, remaining) = xproto::Window::try_parse(value)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "destination_window")?;
         let _ = remaining;
         Ok(QueryExtentsRequest {
             destination_window,
@@ -837,6 +841,7 @@ impl QueryExtentsRequest {
 }
 impl Request for QueryExtentsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -868,28 +873,27 @@ pub struct QueryExtentsReply {
 impl TryParse for QueryExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (bounding_shaped, remaining) = bool::try_parse(remaining)?;
-        let (clip_shaped, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (bounding_shape_extents_x, remaining) = i16::try_parse(remaining)?;
-        let (bounding_shape_extents_y, remaining) = i16::try_parse(remaining)?;
-        let (bounding_shape_extents_width, remaining) = u16::try_parse(remaining)?;
-        let (bounding_shape_extents_height, remaining) = u16::try_parse(remaining)?;
-        let (clip_shape_extents_x, remaining) = i16::try_parse(remaining)?;
-        let (clip_shape_extents_y, remaining) = i16::try_parse(remaining)?;
-        let (clip_shape_extents_width, remaining) = u16::try_parse(remaining)?;
-        let (clip_shape_extents_height, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (bounding_shaped, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "bounding_shaped")?;
+        let (clip_shaped, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "clip_shaped")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (bounding_shape_extents_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bounding_shape_extents_x")?;
+        let (bounding_shape_extents_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bounding_shape_extents_y")?;
+        let (bounding_shape_extents_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "bounding_shape_extents_width")?;
+        let (bounding_shape_extents_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "bounding_shape_extents_height")?;
+        let (clip_shape_extents_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_shape_extents_x")?;
+        let (clip_shape_extents_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_shape_extents_y")?;
+        let (clip_shape_extents_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "clip_shape_extents_width")?;
+        let (clip_shape_extents_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "clip_shape_extents_height")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryExtentsReply { sequence, length, bounding_shaped, clip_shaped, bounding_shape_extents_x, bounding_shape_extents_y, bounding_shape_extents_width, bounding_shape_extents_height, clip_shape_extents_x, clip_shape_extents_y, clip_shape_extents_width, clip_shape_extents_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1000,9 +1004,9 @@ impl SelectInputRequest {
         if header.minor_opcode != SELECT_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (destination_window, remaining) = xproto::Window::try_parse(value)?;
-        let (enable, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "destination_window")?;
+        let (enable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(SelectInputRequest {
             destination_window,
@@ -1012,6 +1016,7 @@ impl SelectInputRequest {
 }
 impl Request for SelectInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1056,7 +1061,7 @@ impl InputSelectedRequest {
         if header.minor_opcode != INPUT_SELECTED_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (destination_window, remaining) = xproto::Window::try_parse(value)?;
+        let (destination_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "destination_window")?;
         let _ = remaining;
         Ok(InputSelectedRequest {
             destination_window,
@@ -1065,6 +1070,7 @@ impl InputSelectedRequest {
 }
 impl Request for InputSelectedRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1087,17 +1093,16 @@ pub struct InputSelectedReply {
 impl TryParse for InputSelectedReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (enabled, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enabled")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = InputSelectedReply { enabled, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1168,10 +1173,10 @@ impl GetRectanglesRequest {
         if header.minor_opcode != GET_RECTANGLES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (source_kind, remaining) = Kind::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (source_kind, remaining) = crate::x11_utils::in_field(Kind::try_parse(remaining), "source_kind")?;
         let source_kind = source_kind.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetRectanglesRequest {
             window,
@@ -1181,6 +1186,7 @@ impl GetRectanglesRequest {
 }
 impl Request for GetRectanglesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1204,21 +1210,20 @@ pub struct GetRectanglesReply {
 impl TryParse for GetRectanglesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (ordering, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (rectangles_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, rectangles_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (ordering, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ordering")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (rectangles_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rectangles_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, rectangles_len.try_to_usize()?, Some("rectangles"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let ordering = ordering.into();
         let result = GetRectanglesReply { ordering, sequence, length, rectangles };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
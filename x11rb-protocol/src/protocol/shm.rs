@@ -54,15 +54,15 @@ pub struct CompletionEvent {
 impl TryParse for CompletionEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (minor_event, remaining) = u16::try_parse(remaining)?;
-        let (major_event, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (shmseg, remaining) = Seg::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (minor_event, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_event")?;
+        let (major_event, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_event")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(remaining), "shmseg")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
         let result = CompletionEvent { response_type, sequence, drawable, minor_event, major_event, shmseg, offset };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -204,6 +204,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -231,23 +232,22 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (shared_pixmaps, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
-        let (uid, remaining) = u16::try_parse(remaining)?;
-        let (gid, remaining) = u16::try_parse(remaining)?;
-        let (pixmap_format, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (shared_pixmaps, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "shared_pixmaps")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
+        let (uid, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "uid")?;
+        let (gid, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "gid")?;
+        let (pixmap_format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pixmap_format")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { shared_pixmaps, sequence, length, major_version, minor_version, uid, gid, pixmap_format };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -359,10 +359,10 @@ impl AttachRequest {
         if header.minor_opcode != ATTACH_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (shmseg, remaining) = Seg::try_parse(value)?;
-        let (shmid, remaining) = u32::try_parse(remaining)?;
-        let (read_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(value), "shmseg")?;
+        let (shmid, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "shmid")?;
+        let (read_only, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "read_only")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(AttachRequest {
             shmseg,
@@ -373,6 +373,7 @@ impl AttachRequest {
 }
 impl Request for AttachRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -417,7 +418,7 @@ impl DetachRequest {
         if header.minor_opcode != DETACH_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (shmseg, remaining) = Seg::try_parse(value)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(value), "shmseg")?;
         let _ = remaining;
         Ok(DetachRequest {
             shmseg,
@@ -426,6 +427,7 @@ impl DetachRequest {
 }
 impl Request for DetachRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -530,22 +532,22 @@ impl PutImageRequest {
         if header.minor_opcode != PUT_IMAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (total_width, remaining) = u16::try_parse(remaining)?;
-        let (total_height, remaining) = u16::try_parse(remaining)?;
-        let (src_x, remaining) = u16::try_parse(remaining)?;
-        let (src_y, remaining) = u16::try_parse(remaining)?;
-        let (src_width, remaining) = u16::try_parse(remaining)?;
-        let (src_height, remaining) = u16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (send_event, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (shmseg, remaining) = Seg::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (total_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_width")?;
+        let (total_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_height")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_y")?;
+        let (src_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_width")?;
+        let (src_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_height")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (send_event, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "send_event")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(remaining), "shmseg")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
         let _ = remaining;
         Ok(PutImageRequest {
             drawable,
@@ -568,6 +570,7 @@ impl PutImageRequest {
 }
 impl Request for PutImageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -652,16 +655,16 @@ impl GetImageRequest {
         if header.minor_opcode != GET_IMAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (plane_mask, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (shmseg, remaining) = Seg::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (plane_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "plane_mask")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(remaining), "shmseg")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
         let _ = remaining;
         Ok(GetImageRequest {
             drawable,
@@ -678,6 +681,7 @@ impl GetImageRequest {
 }
 impl Request for GetImageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -702,19 +706,18 @@ pub struct GetImageReply {
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "size")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetImageReply { depth, sequence, length, visual, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -823,14 +826,14 @@ impl CreatePixmapRequest {
         if header.minor_opcode != CREATE_PIXMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (pid, remaining) = xproto::Pixmap::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (shmseg, remaining) = Seg::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
+        let (pid, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(value), "pid")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(remaining), "shmseg")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
         let _ = remaining;
         Ok(CreatePixmapRequest {
             pid,
@@ -845,6 +848,7 @@ impl CreatePixmapRequest {
 }
 impl Request for CreatePixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -895,11 +899,11 @@ impl AttachFdRequest {
         if header.minor_opcode != ATTACH_FD_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (shmseg, remaining) = Seg::try_parse(value)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(value), "shmseg")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let shm_fd = fds.remove(0);
-        let (read_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (read_only, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "read_only")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(AttachFdRequest {
             shmseg,
@@ -910,6 +914,7 @@ impl AttachFdRequest {
 }
 impl Request for AttachFdRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -966,10 +971,10 @@ impl CreateSegmentRequest {
         if header.minor_opcode != CREATE_SEGMENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (shmseg, remaining) = Seg::try_parse(value)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
-        let (read_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(Seg::try_parse(value), "shmseg")?;
+        let (size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "size")?;
+        let (read_only, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "read_only")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CreateSegmentRequest {
             shmseg,
@@ -980,6 +985,7 @@ impl CreateSegmentRequest {
 }
 impl Request for CreateSegmentRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1002,20 +1008,19 @@ pub struct CreateSegmentReply {
 impl TryParseFd for CreateSegmentReply {
     fn try_parse_fd<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (nfd, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (nfd, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "nfd")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
         let shm_fd = fds.remove(0);
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateSegmentReply { nfd, sequence, length, shm_fd };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -255,7 +255,7 @@ impl core::fmt::Debug for CA  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(CA, u32);
+bitmask_binop!(CA, u32, [COUNTER, VALUE_TYPE, VALUE, TEST_TYPE, DELTA, EVENTS]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -265,8 +265,8 @@ pub struct Int64 {
 }
 impl TryParse for Int64 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (hi, remaining) = i32::try_parse(remaining)?;
-        let (lo, remaining) = u32::try_parse(remaining)?;
+        let (hi, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "hi")?;
+        let (lo, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "lo")?;
         let result = Int64 { hi, lo };
         Ok((result, remaining))
     }
@@ -304,15 +304,15 @@ pub struct Systemcounter {
 impl TryParse for Systemcounter {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (resolution, remaining) = Int64::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(remaining), "counter")?;
+        let (resolution, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "resolution")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = Systemcounter { counter, resolution, name };
         Ok((result, remaining))
     }
@@ -360,10 +360,10 @@ pub struct Trigger {
 }
 impl TryParse for Trigger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (wait_type, remaining) = u32::try_parse(remaining)?;
-        let (wait_value, remaining) = Int64::try_parse(remaining)?;
-        let (test_type, remaining) = u32::try_parse(remaining)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(remaining), "counter")?;
+        let (wait_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "wait_type")?;
+        let (wait_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "wait_value")?;
+        let (test_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "test_type")?;
         let wait_type = wait_type.into();
         let test_type = test_type.into();
         let result = Trigger { counter, wait_type, wait_value, test_type };
@@ -417,8 +417,8 @@ pub struct Waitcondition {
 }
 impl TryParse for Waitcondition {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (trigger, remaining) = Trigger::try_parse(remaining)?;
-        let (event_threshold, remaining) = Int64::try_parse(remaining)?;
+        let (trigger, remaining) = crate::x11_utils::in_field(Trigger::try_parse(remaining), "trigger")?;
+        let (event_threshold, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "event_threshold")?;
         let result = Waitcondition { trigger, event_threshold };
         Ok((result, remaining))
     }
@@ -507,8 +507,8 @@ impl InitializeRequest {
         if header.minor_opcode != INITIALIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (desired_major_version, remaining) = u8::try_parse(value)?;
-        let (desired_minor_version, remaining) = u8::try_parse(remaining)?;
+        let (desired_major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "desired_major_version")?;
+        let (desired_minor_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "desired_minor_version")?;
         let _ = remaining;
         Ok(InitializeRequest {
             desired_major_version,
@@ -518,6 +518,7 @@ impl InitializeRequest {
 }
 impl Request for InitializeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -541,20 +542,19 @@ pub struct InitializeReply {
 impl TryParse for InitializeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u8::try_parse(remaining)?;
-        let (minor_version, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = InitializeReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -647,6 +647,7 @@ impl ListSystemCountersRequest {
 }
 impl Request for ListSystemCountersRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -669,20 +670,19 @@ pub struct ListSystemCountersReply {
 impl TryParse for ListSystemCountersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (counters_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (counters, remaining) = crate::x11_utils::parse_list::<Systemcounter>(remaining, counters_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (counters_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "counters_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (counters, remaining) = crate::x11_utils::parse_list::<Systemcounter>(remaining, counters_len.try_to_usize()?, Some("counters"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListSystemCountersReply { sequence, length, counters };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -721,6 +721,27 @@ impl ListSystemCountersReply {
             .try_into().unwrap()
     }
 }
+impl ListSystemCountersReply {
+    /// Lazily parse the `counters` list from the raw bytes of a `ListSystemCountersReply`.
+    ///
+    /// In contrast to parsing the whole `ListSystemCountersReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn counters_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Systemcounter>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (counters_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "counters_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = counters_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CreateCounter request
 pub const CREATE_COUNTER_REQUEST: u8 = 2;
@@ -765,8 +786,8 @@ impl CreateCounterRequest {
         if header.minor_opcode != CREATE_COUNTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (id, remaining) = Counter::try_parse(value)?;
-        let (initial_value, remaining) = Int64::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(Counter::try_parse(value), "id")?;
+        let (initial_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "initial_value")?;
         let _ = remaining;
         Ok(CreateCounterRequest {
             id,
@@ -776,6 +797,7 @@ impl CreateCounterRequest {
 }
 impl Request for CreateCounterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -820,7 +842,7 @@ impl DestroyCounterRequest {
         if header.minor_opcode != DESTROY_COUNTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (counter, remaining) = Counter::try_parse(value)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(value), "counter")?;
         let _ = remaining;
         Ok(DestroyCounterRequest {
             counter,
@@ -829,6 +851,7 @@ impl DestroyCounterRequest {
 }
 impl Request for DestroyCounterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -873,7 +896,7 @@ impl QueryCounterRequest {
         if header.minor_opcode != QUERY_COUNTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (counter, remaining) = Counter::try_parse(value)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(value), "counter")?;
         let _ = remaining;
         Ok(QueryCounterRequest {
             counter,
@@ -882,6 +905,7 @@ impl QueryCounterRequest {
 }
 impl Request for QueryCounterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -904,18 +928,17 @@ pub struct QueryCounterReply {
 impl TryParse for QueryCounterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (counter_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "counter_value")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryCounterReply { sequence, length, counter_value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1010,6 +1033,7 @@ impl<'input> AwaitRequest<'input> {
 }
 impl<'input> Request for AwaitRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1064,8 +1088,8 @@ impl ChangeCounterRequest {
         if header.minor_opcode != CHANGE_COUNTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (counter, remaining) = Counter::try_parse(value)?;
-        let (amount, remaining) = Int64::try_parse(remaining)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(value), "counter")?;
+        let (amount, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "amount")?;
         let _ = remaining;
         Ok(ChangeCounterRequest {
             counter,
@@ -1075,6 +1099,7 @@ impl ChangeCounterRequest {
 }
 impl Request for ChangeCounterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1129,8 +1154,8 @@ impl SetCounterRequest {
         if header.minor_opcode != SET_COUNTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (counter, remaining) = Counter::try_parse(value)?;
-        let (value, remaining) = Int64::try_parse(remaining)?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(value), "counter")?;
+        let (value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "value")?;
         let _ = remaining;
         Ok(SetCounterRequest {
             counter,
@@ -1140,6 +1165,7 @@ impl SetCounterRequest {
 }
 impl Request for SetCounterRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1168,7 +1194,7 @@ impl CreateAlarmAux {
         let mut outer_remaining = value;
         let counter = if switch_expr & u32::from(CA::COUNTER) != 0 {
             let remaining = outer_remaining;
-            let (counter, remaining) = Counter::try_parse(remaining)?;
+            let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(remaining), "counter")?;
             outer_remaining = remaining;
             Some(counter)
         } else {
@@ -1176,7 +1202,7 @@ impl CreateAlarmAux {
         };
         let value_type = if switch_expr & u32::from(CA::VALUE_TYPE) != 0 {
             let remaining = outer_remaining;
-            let (value_type, remaining) = u32::try_parse(remaining)?;
+            let (value_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_type")?;
             let value_type = value_type.into();
             outer_remaining = remaining;
             Some(value_type)
@@ -1185,7 +1211,7 @@ impl CreateAlarmAux {
         };
         let value = if switch_expr & u32::from(CA::VALUE) != 0 {
             let remaining = outer_remaining;
-            let (value, remaining) = Int64::try_parse(remaining)?;
+            let (value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "value")?;
             outer_remaining = remaining;
             Some(value)
         } else {
@@ -1193,7 +1219,7 @@ impl CreateAlarmAux {
         };
         let test_type = if switch_expr & u32::from(CA::TEST_TYPE) != 0 {
             let remaining = outer_remaining;
-            let (test_type, remaining) = u32::try_parse(remaining)?;
+            let (test_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "test_type")?;
             let test_type = test_type.into();
             outer_remaining = remaining;
             Some(test_type)
@@ -1202,7 +1228,7 @@ impl CreateAlarmAux {
         };
         let delta = if switch_expr & u32::from(CA::DELTA) != 0 {
             let remaining = outer_remaining;
-            let (delta, remaining) = Int64::try_parse(remaining)?;
+            let (delta, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "delta")?;
             outer_remaining = remaining;
             Some(delta)
         } else {
@@ -1210,7 +1236,7 @@ impl CreateAlarmAux {
         };
         let events = if switch_expr & u32::from(CA::EVENTS) != 0 {
             let remaining = outer_remaining;
-            let (events, remaining) = u32::try_parse(remaining)?;
+            let (events, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "events")?;
             outer_remaining = remaining;
             Some(events)
         } else {
@@ -1360,8 +1386,8 @@ impl<'input> CreateAlarmRequest<'input> {
         if header.minor_opcode != CREATE_ALARM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (id, remaining) = Alarm::try_parse(value)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(Alarm::try_parse(value), "id")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = CreateAlarmAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(CreateAlarmRequest {
@@ -1379,6 +1405,7 @@ impl<'input> CreateAlarmRequest<'input> {
 }
 impl<'input> Request for CreateAlarmRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1407,7 +1434,7 @@ impl ChangeAlarmAux {
         let mut outer_remaining = value;
         let counter = if switch_expr & u32::from(CA::COUNTER) != 0 {
             let remaining = outer_remaining;
-            let (counter, remaining) = Counter::try_parse(remaining)?;
+            let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(remaining), "counter")?;
             outer_remaining = remaining;
             Some(counter)
         } else {
@@ -1415,7 +1442,7 @@ impl ChangeAlarmAux {
         };
         let value_type = if switch_expr & u32::from(CA::VALUE_TYPE) != 0 {
             let remaining = outer_remaining;
-            let (value_type, remaining) = u32::try_parse(remaining)?;
+            let (value_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_type")?;
             let value_type = value_type.into();
             outer_remaining = remaining;
             Some(value_type)
@@ -1424,7 +1451,7 @@ impl ChangeAlarmAux {
         };
         let value = if switch_expr & u32::from(CA::VALUE) != 0 {
             let remaining = outer_remaining;
-            let (value, remaining) = Int64::try_parse(remaining)?;
+            let (value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "value")?;
             outer_remaining = remaining;
             Some(value)
         } else {
@@ -1432,7 +1459,7 @@ impl ChangeAlarmAux {
         };
         let test_type = if switch_expr & u32::from(CA::TEST_TYPE) != 0 {
             let remaining = outer_remaining;
-            let (test_type, remaining) = u32::try_parse(remaining)?;
+            let (test_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "test_type")?;
             let test_type = test_type.into();
             outer_remaining = remaining;
             Some(test_type)
@@ -1441,7 +1468,7 @@ impl ChangeAlarmAux {
         };
         let delta = if switch_expr & u32::from(CA::DELTA) != 0 {
             let remaining = outer_remaining;
-            let (delta, remaining) = Int64::try_parse(remaining)?;
+            let (delta, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "delta")?;
             outer_remaining = remaining;
             Some(delta)
         } else {
@@ -1449,7 +1476,7 @@ impl ChangeAlarmAux {
         };
         let events = if switch_expr & u32::from(CA::EVENTS) != 0 {
             let remaining = outer_remaining;
-            let (events, remaining) = u32::try_parse(remaining)?;
+            let (events, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "events")?;
             outer_remaining = remaining;
             Some(events)
         } else {
@@ -1599,8 +1626,8 @@ impl<'input> ChangeAlarmRequest<'input> {
         if header.minor_opcode != CHANGE_ALARM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (id, remaining) = Alarm::try_parse(value)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(Alarm::try_parse(value), "id")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = ChangeAlarmAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(ChangeAlarmRequest {
@@ -1618,6 +1645,7 @@ impl<'input> ChangeAlarmRequest<'input> {
 }
 impl<'input> Request for ChangeAlarmRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1662,7 +1690,7 @@ impl DestroyAlarmRequest {
         if header.minor_opcode != DESTROY_ALARM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (alarm, remaining) = Alarm::try_parse(value)?;
+        let (alarm, remaining) = crate::x11_utils::in_field(Alarm::try_parse(value), "alarm")?;
         let _ = remaining;
         Ok(DestroyAlarmRequest {
             alarm,
@@ -1671,6 +1699,7 @@ impl DestroyAlarmRequest {
 }
 impl Request for DestroyAlarmRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1715,7 +1744,7 @@ impl QueryAlarmRequest {
         if header.minor_opcode != QUERY_ALARM_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (alarm, remaining) = Alarm::try_parse(value)?;
+        let (alarm, remaining) = crate::x11_utils::in_field(Alarm::try_parse(value), "alarm")?;
         let _ = remaining;
         Ok(QueryAlarmRequest {
             alarm,
@@ -1724,6 +1753,7 @@ impl QueryAlarmRequest {
 }
 impl Request for QueryAlarmRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1749,23 +1779,22 @@ pub struct QueryAlarmReply {
 impl TryParse for QueryAlarmReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (trigger, remaining) = Trigger::try_parse(remaining)?;
-        let (delta, remaining) = Int64::try_parse(remaining)?;
-        let (events, remaining) = bool::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (trigger, remaining) = crate::x11_utils::in_field(Trigger::try_parse(remaining), "trigger")?;
+        let (delta, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "delta")?;
+        let (events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "events")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let state = state.into();
         let result = QueryAlarmReply { sequence, length, trigger, delta, events, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1876,8 +1905,8 @@ impl SetPriorityRequest {
         if header.minor_opcode != SET_PRIORITY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (id, remaining) = u32::try_parse(value)?;
-        let (priority, remaining) = i32::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "id")?;
+        let (priority, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "priority")?;
         let _ = remaining;
         Ok(SetPriorityRequest {
             id,
@@ -1887,6 +1916,7 @@ impl SetPriorityRequest {
 }
 impl Request for SetPriorityRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1931,7 +1961,7 @@ impl GetPriorityRequest {
         if header.minor_opcode != GET_PRIORITY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (id, remaining) = u32::try_parse(value)?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "id")?;
         let _ = remaining;
         Ok(GetPriorityRequest {
             id,
@@ -1940,6 +1970,7 @@ impl GetPriorityRequest {
 }
 impl Request for GetPriorityRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1962,18 +1993,17 @@ pub struct GetPriorityReply {
 impl TryParse for GetPriorityReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (priority, remaining) = i32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (priority, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "priority")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPriorityReply { sequence, length, priority };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2055,9 +2085,9 @@ impl CreateFenceRequest {
         if header.minor_opcode != CREATE_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (fence, remaining) = Fence::try_parse(remaining)?;
-        let (initially_triggered, remaining) = bool::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (fence, remaining) = crate::x11_utils::in_field(Fence::try_parse(remaining), "fence")?;
+        let (initially_triggered, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "initially_triggered")?;
         let _ = remaining;
         Ok(CreateFenceRequest {
             drawable,
@@ -2068,6 +2098,7 @@ impl CreateFenceRequest {
 }
 impl Request for CreateFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2112,7 +2143,7 @@ impl TriggerFenceRequest {
         if header.minor_opcode != TRIGGER_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (fence, remaining) = Fence::try_parse(value)?;
+        let (fence, remaining) = crate::x11_utils::in_field(Fence::try_parse(value), "fence")?;
         let _ = remaining;
         Ok(TriggerFenceRequest {
             fence,
@@ -2121,6 +2152,7 @@ impl TriggerFenceRequest {
 }
 impl Request for TriggerFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2165,7 +2197,7 @@ impl ResetFenceRequest {
         if header.minor_opcode != RESET_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (fence, remaining) = Fence::try_parse(value)?;
+        let (fence, remaining) = crate::x11_utils::in_field(Fence::try_parse(value), "fence")?;
         let _ = remaining;
         Ok(ResetFenceRequest {
             fence,
@@ -2174,6 +2206,7 @@ impl ResetFenceRequest {
 }
 impl Request for ResetFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2218,7 +2251,7 @@ impl DestroyFenceRequest {
         if header.minor_opcode != DESTROY_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (fence, remaining) = Fence::try_parse(value)?;
+        let (fence, remaining) = crate::x11_utils::in_field(Fence::try_parse(value), "fence")?;
         let _ = remaining;
         Ok(DestroyFenceRequest {
             fence,
@@ -2227,6 +2260,7 @@ impl DestroyFenceRequest {
 }
 impl Request for DestroyFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2271,7 +2305,7 @@ impl QueryFenceRequest {
         if header.minor_opcode != QUERY_FENCE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (fence, remaining) = Fence::try_parse(value)?;
+        let (fence, remaining) = crate::x11_utils::in_field(Fence::try_parse(value), "fence")?;
         let _ = remaining;
         Ok(QueryFenceRequest {
             fence,
@@ -2280,6 +2314,7 @@ impl QueryFenceRequest {
 }
 impl Request for QueryFenceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2302,19 +2337,18 @@ pub struct QueryFenceReply {
 impl TryParse for QueryFenceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (triggered, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (triggered, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "triggered")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryFenceReply { sequence, length, triggered };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2426,6 +2460,7 @@ impl<'input> AwaitFenceRequest<'input> {
 }
 impl<'input> Request for AwaitFenceRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2455,16 +2490,16 @@ pub struct CounterNotifyEvent {
 impl TryParse for CounterNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (counter, remaining) = Counter::try_parse(remaining)?;
-        let (wait_value, remaining) = Int64::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
-        let (destroyed, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (kind, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "kind")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (counter, remaining) = crate::x11_utils::in_field(Counter::try_parse(remaining), "counter")?;
+        let (wait_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "wait_value")?;
+        let (counter_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "counter_value")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (count, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "count")?;
+        let (destroyed, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "destroyed")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = CounterNotifyEvent { response_type, kind, sequence, counter, wait_value, counter_value, timestamp, count, destroyed };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2603,15 +2638,15 @@ pub struct AlarmNotifyEvent {
 impl TryParse for AlarmNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (kind, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (alarm, remaining) = Alarm::try_parse(remaining)?;
-        let (counter_value, remaining) = Int64::try_parse(remaining)?;
-        let (alarm_value, remaining) = Int64::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (kind, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "kind")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (alarm, remaining) = crate::x11_utils::in_field(Alarm::try_parse(remaining), "alarm")?;
+        let (counter_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "counter_value")?;
+        let (alarm_value, remaining) = crate::x11_utils::in_field(Int64::try_parse(remaining), "alarm_value")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let state = state.into();
         let result = AlarmNotifyEvent { response_type, kind, sequence, alarm, counter_value, alarm_value, timestamp, state };
         let _ = remaining;
@@ -44,10 +44,10 @@ pub struct DrmClipRect {
 }
 impl TryParse for DrmClipRect {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x1, remaining) = i16::try_parse(remaining)?;
-        let (y1, remaining) = i16::try_parse(remaining)?;
-        let (x2, remaining) = i16::try_parse(remaining)?;
-        let (x3, remaining) = i16::try_parse(remaining)?;
+        let (x1, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x1")?;
+        let (y1, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y1")?;
+        let (x2, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x2")?;
+        let (x3, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x3")?;
         let result = DrmClipRect { x1, y1, x2, x3 };
         Ok((result, remaining))
     }
@@ -112,6 +112,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -136,20 +137,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (dri_major_version, remaining) = u16::try_parse(remaining)?;
-        let (dri_minor_version, remaining) = u16::try_parse(remaining)?;
-        let (dri_minor_patch, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (dri_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dri_major_version")?;
+        let (dri_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dri_minor_version")?;
+        let (dri_minor_patch, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dri_minor_patch")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, dri_major_version, dri_minor_version, dri_minor_patch };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -227,7 +227,7 @@ impl QueryDirectRenderingCapableRequest {
         if header.minor_opcode != QUERY_DIRECT_RENDERING_CAPABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(QueryDirectRenderingCapableRequest {
             screen,
@@ -236,6 +236,7 @@ impl QueryDirectRenderingCapableRequest {
 }
 impl Request for QueryDirectRenderingCapableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -258,18 +259,17 @@ pub struct QueryDirectRenderingCapableReply {
 impl TryParse for QueryDirectRenderingCapableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (is_capable, remaining) = bool::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (is_capable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "is_capable")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryDirectRenderingCapableReply { sequence, length, is_capable };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -336,7 +336,7 @@ impl OpenConnectionRequest {
         if header.minor_opcode != OPEN_CONNECTION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(OpenConnectionRequest {
             screen,
@@ -345,6 +345,7 @@ impl OpenConnectionRequest {
 }
 impl Request for OpenConnectionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -369,23 +370,22 @@ pub struct OpenConnectionReply {
 impl TryParse for OpenConnectionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (sarea_handle_low, remaining) = u32::try_parse(remaining)?;
-        let (sarea_handle_high, remaining) = u32::try_parse(remaining)?;
-        let (bus_id_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (bus_id, remaining) = crate::x11_utils::parse_u8_list(remaining, bus_id_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (sarea_handle_low, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sarea_handle_low")?;
+        let (sarea_handle_high, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "sarea_handle_high")?;
+        let (bus_id_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bus_id_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (bus_id, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, bus_id_len.try_to_usize()?), "bus_id")?;
         let bus_id = bus_id.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = OpenConnectionReply { sequence, length, sarea_handle_low, sarea_handle_high, bus_id };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -460,7 +460,7 @@ impl CloseConnectionRequest {
         if header.minor_opcode != CLOSE_CONNECTION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(CloseConnectionRequest {
             screen,
@@ -469,6 +469,7 @@ impl CloseConnectionRequest {
 }
 impl Request for CloseConnectionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -513,7 +514,7 @@ impl GetClientDriverNameRequest {
         if header.minor_opcode != GET_CLIENT_DRIVER_NAME_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(GetClientDriverNameRequest {
             screen,
@@ -522,6 +523,7 @@ impl GetClientDriverNameRequest {
 }
 impl Request for GetClientDriverNameRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -547,24 +549,23 @@ pub struct GetClientDriverNameReply {
 impl TryParse for GetClientDriverNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_major_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_minor_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_patch_version, remaining) = u32::try_parse(remaining)?;
-        let (client_driver_name_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (client_driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, client_driver_name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (client_driver_major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_driver_major_version")?;
+        let (client_driver_minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_driver_minor_version")?;
+        let (client_driver_patch_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_driver_patch_version")?;
+        let (client_driver_name_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_driver_name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (client_driver_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, client_driver_name_len.try_to_usize()?), "client_driver_name")?;
         let client_driver_name = client_driver_name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetClientDriverNameReply { sequence, length, client_driver_major_version, client_driver_minor_version, client_driver_patch_version, client_driver_name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -652,9 +653,9 @@ impl CreateContextRequest {
         if header.minor_opcode != CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (visual, remaining) = u32::try_parse(remaining)?;
-        let (context, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (visual, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "visual")?;
+        let (context, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context")?;
         let _ = remaining;
         Ok(CreateContextRequest {
             screen,
@@ -665,6 +666,7 @@ impl CreateContextRequest {
 }
 impl Request for CreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -687,18 +689,17 @@ pub struct CreateContextReply {
 impl TryParse for CreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (hw_context, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (hw_context, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "hw_context")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateContextReply { sequence, length, hw_context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -774,8 +775,8 @@ impl DestroyContextRequest {
         if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (context, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context")?;
         let _ = remaining;
         Ok(DestroyContextRequest {
             screen,
@@ -785,6 +786,7 @@ impl DestroyContextRequest {
 }
 impl Request for DestroyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -835,8 +837,8 @@ impl CreateDrawableRequest {
         if header.minor_opcode != CREATE_DRAWABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (drawable, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable")?;
         let _ = remaining;
         Ok(CreateDrawableRequest {
             screen,
@@ -846,6 +848,7 @@ impl CreateDrawableRequest {
 }
 impl Request for CreateDrawableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -868,18 +871,17 @@ pub struct CreateDrawableReply {
 impl TryParse for CreateDrawableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (hw_drawable_handle, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (hw_drawable_handle, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "hw_drawable_handle")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateDrawableReply { sequence, length, hw_drawable_handle };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -955,8 +957,8 @@ impl DestroyDrawableRequest {
         if header.minor_opcode != DESTROY_DRAWABLE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (drawable, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable")?;
         let _ = remaining;
         Ok(DestroyDrawableRequest {
             screen,
@@ -966,6 +968,7 @@ impl DestroyDrawableRequest {
 }
 impl Request for DestroyDrawableRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1016,8 +1019,8 @@ impl GetDrawableInfoRequest {
         if header.minor_opcode != GET_DRAWABLE_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (drawable, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable")?;
         let _ = remaining;
         Ok(GetDrawableInfoRequest {
             screen,
@@ -1027,6 +1030,7 @@ impl GetDrawableInfoRequest {
 }
 impl Request for GetDrawableInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1058,29 +1062,28 @@ pub struct GetDrawableInfoReply {
 impl TryParse for GetDrawableInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (drawable_table_index, remaining) = u32::try_parse(remaining)?;
-        let (drawable_table_stamp, remaining) = u32::try_parse(remaining)?;
-        let (drawable_origin_x, remaining) = i16::try_parse(remaining)?;
-        let (drawable_origin_y, remaining) = i16::try_parse(remaining)?;
-        let (drawable_size_w, remaining) = i16::try_parse(remaining)?;
-        let (drawable_size_h, remaining) = i16::try_parse(remaining)?;
-        let (num_clip_rects, remaining) = u32::try_parse(remaining)?;
-        let (back_x, remaining) = i16::try_parse(remaining)?;
-        let (back_y, remaining) = i16::try_parse(remaining)?;
-        let (num_back_clip_rects, remaining) = u32::try_parse(remaining)?;
-        let (clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_clip_rects.try_to_usize()?)?;
-        let (back_clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_back_clip_rects.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (drawable_table_index, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable_table_index")?;
+        let (drawable_table_stamp, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable_table_stamp")?;
+        let (drawable_origin_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_origin_x")?;
+        let (drawable_origin_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_origin_y")?;
+        let (drawable_size_w, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_size_w")?;
+        let (drawable_size_h, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_size_h")?;
+        let (num_clip_rects, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_clip_rects")?;
+        let (back_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "back_x")?;
+        let (back_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "back_y")?;
+        let (num_back_clip_rects, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_back_clip_rects")?;
+        let (clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_clip_rects.try_to_usize()?, Some("clip_rects"))?;
+        let (back_clip_rects, remaining) = crate::x11_utils::parse_list::<DrmClipRect>(remaining, num_back_clip_rects.try_to_usize()?, Some("back_clip_rects"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDrawableInfoReply { sequence, length, drawable_table_index, drawable_table_stamp, drawable_origin_x, drawable_origin_y, drawable_size_w, drawable_size_h, back_x, back_y, clip_rects, back_clip_rects };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1142,6 +1145,35 @@ impl GetDrawableInfoReply {
             .try_into().unwrap()
     }
 }
+impl GetDrawableInfoReply {
+    /// Lazily parse the `clip_rects` list from the raw bytes of a `GetDrawableInfoReply`.
+    ///
+    /// In contrast to parsing the whole `GetDrawableInfoReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn clip_rects_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, DrmClipRect>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (drawable_table_index, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable_table_index")?;
+        let (drawable_table_stamp, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "drawable_table_stamp")?;
+        let (drawable_origin_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_origin_x")?;
+        let (drawable_origin_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_origin_y")?;
+        let (drawable_size_w, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_size_w")?;
+        let (drawable_size_h, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drawable_size_h")?;
+        let (num_clip_rects, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_clip_rects")?;
+        let (back_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "back_x")?;
+        let (back_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "back_y")?;
+        let (num_back_clip_rects, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_back_clip_rects")?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_clip_rects.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetDeviceInfo request
 pub const GET_DEVICE_INFO_REQUEST: u8 = 10;
@@ -1176,7 +1208,7 @@ impl GetDeviceInfoRequest {
         if header.minor_opcode != GET_DEVICE_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
         let _ = remaining;
         Ok(GetDeviceInfoRequest {
             screen,
@@ -1185,6 +1217,7 @@ impl GetDeviceInfoRequest {
 }
 impl Request for GetDeviceInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1212,24 +1245,23 @@ pub struct GetDeviceInfoReply {
 impl TryParse for GetDeviceInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_handle_low, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_handle_high, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_origin_offset, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_size, remaining) = u32::try_parse(remaining)?;
-        let (framebuffer_stride, remaining) = u32::try_parse(remaining)?;
-        let (device_private_size, remaining) = u32::try_parse(remaining)?;
-        let (device_private, remaining) = crate::x11_utils::parse_list::<u32>(remaining, device_private_size.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (framebuffer_handle_low, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_handle_low")?;
+        let (framebuffer_handle_high, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_handle_high")?;
+        let (framebuffer_origin_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_origin_offset")?;
+        let (framebuffer_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_size")?;
+        let (framebuffer_stride, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_stride")?;
+        let (device_private_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "device_private_size")?;
+        let (device_private, remaining) = crate::x11_utils::parse_list::<u32>(remaining, device_private_size.try_to_usize()?, Some("device_private"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceInfoReply { sequence, length, framebuffer_handle_low, framebuffer_handle_high, framebuffer_origin_offset, framebuffer_size, framebuffer_stride, device_private };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1272,6 +1304,31 @@ impl GetDeviceInfoReply {
             .try_into().unwrap()
     }
 }
+impl GetDeviceInfoReply {
+    /// Lazily parse the `device_private` list from the raw bytes of a `GetDeviceInfoReply`.
+    ///
+    /// In contrast to parsing the whole `GetDeviceInfoReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn device_private_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (framebuffer_handle_low, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_handle_low")?;
+        let (framebuffer_handle_high, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_handle_high")?;
+        let (framebuffer_origin_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_origin_offset")?;
+        let (framebuffer_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_size")?;
+        let (framebuffer_stride, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "framebuffer_stride")?;
+        let (device_private_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "device_private_size")?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = device_private_size.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the AuthConnection request
 pub const AUTH_CONNECTION_REQUEST: u8 = 11;
@@ -1312,8 +1369,8 @@ impl AuthConnectionRequest {
         if header.minor_opcode != AUTH_CONNECTION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (magic, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (magic, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "magic")?;
         let _ = remaining;
         Ok(AuthConnectionRequest {
             screen,
@@ -1323,6 +1380,7 @@ impl AuthConnectionRequest {
 }
 impl Request for AuthConnectionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1345,18 +1403,17 @@ pub struct AuthConnectionReply {
 impl TryParse for AuthConnectionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (authenticated, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (authenticated, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "authenticated")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AuthConnectionReply { sequence, length, authenticated };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
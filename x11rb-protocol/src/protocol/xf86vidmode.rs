@@ -106,7 +106,7 @@ impl core::fmt::Debug for ModeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ModeFlag, u32);
+bitmask_binop!(ModeFlag, u32, [POSITIVE_H_SYNC, NEGATIVE_H_SYNC, POSITIVE_V_SYNC, NEGATIVE_V_SYNC, INTERLACE, COMPOSITE_SYNC, POSITIVE_C_SYNC, NEGATIVE_C_SYNC, H_SKEW, BROADCAST, PIXMUX, DOUBLE_CLOCK, HALF_CLOCK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -152,7 +152,7 @@ impl core::fmt::Debug for ClockFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ClockFlag, u32);
+bitmask_binop!(ClockFlag, u32, [PROGRAMABLE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -200,7 +200,7 @@ impl core::fmt::Debug for Permission  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(Permission, u32);
+bitmask_binop!(Permission, u32, [READ, WRITE]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -220,20 +220,20 @@ pub struct ModeInfo {
 }
 impl TryParse for ModeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u32::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
         let flags = flags.into();
         let result = ModeInfo { dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, privsize };
         Ok((result, remaining))
@@ -357,6 +357,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -380,19 +381,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -464,8 +464,8 @@ impl GetModeLineRequest {
         if header.minor_opcode != GET_MODE_LINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetModeLineRequest {
             screen,
@@ -474,6 +474,7 @@ impl GetModeLineRequest {
 }
 impl Request for GetModeLineRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -507,34 +508,33 @@ pub struct GetModeLineReply {
 impl TryParse for GetModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let private = private.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let flags = flags.into();
         let result = GetModeLineReply { sequence, length, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -685,22 +685,22 @@ impl<'input> ModModeLineRequest<'input> {
         if header.minor_opcode != MOD_MODE_LINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let _ = remaining;
         Ok(ModModeLineRequest {
             screen,
@@ -737,6 +737,7 @@ impl<'input> ModModeLineRequest<'input> {
 }
 impl<'input> Request for ModModeLineRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -783,8 +784,8 @@ impl SwitchModeRequest {
         if header.minor_opcode != SWITCH_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let (zoom, remaining) = u16::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let (zoom, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "zoom")?;
         let _ = remaining;
         Ok(SwitchModeRequest {
             screen,
@@ -794,6 +795,7 @@ impl SwitchModeRequest {
 }
 impl Request for SwitchModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -838,8 +840,8 @@ impl GetMonitorRequest {
         if header.minor_opcode != GET_MONITOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetMonitorRequest {
             screen,
@@ -848,6 +850,7 @@ impl GetMonitorRequest {
 }
 impl Request for GetMonitorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -874,30 +877,29 @@ pub struct GetMonitorReply {
 impl TryParse for GetMonitorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (vendor_length, remaining) = u8::try_parse(remaining)?;
-        let (model_length, remaining) = u8::try_parse(remaining)?;
-        let (num_hsync, remaining) = u8::try_parse(remaining)?;
-        let (num_vsync, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (hsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_hsync.try_to_usize()?)?;
-        let (vsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_vsync.try_to_usize()?)?;
-        let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (vendor_length, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vendor_length")?;
+        let (model_length, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "model_length")?;
+        let (num_hsync, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_hsync")?;
+        let (num_vsync, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_vsync")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (hsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_hsync.try_to_usize()?, Some("hsync"))?;
+        let (vsync, remaining) = crate::x11_utils::parse_list::<Syncrange>(remaining, num_vsync.try_to_usize()?, Some("vsync"))?;
+        let (vendor, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, vendor_length.try_to_usize()?), "vendor")?;
         let vendor = vendor.to_vec();
-        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(vendor_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(vendor_length)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (alignment_pad, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, (u32::from(vendor_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(vendor_length)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "alignment_pad")?;
         let alignment_pad = alignment_pad.to_vec();
-        let (model, remaining) = crate::x11_utils::parse_u8_list(remaining, model_length.try_to_usize()?)?;
+        let (model, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, model_length.try_to_usize()?), "model")?;
         let model = model.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMonitorReply { sequence, length, hsync, vsync, vendor, alignment_pad, model };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -986,6 +988,30 @@ impl GetMonitorReply {
             .try_into().unwrap()
     }
 }
+impl GetMonitorReply {
+    /// Lazily parse the `hsync` list from the raw bytes of a `GetMonitorReply`.
+    ///
+    /// In contrast to parsing the whole `GetMonitorReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn hsync_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Syncrange>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (vendor_length, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vendor_length")?;
+        let (model_length, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "model_length")?;
+        let (num_hsync, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_hsync")?;
+        let (num_vsync, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_vsync")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_hsync.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the LockModeSwitch request
 pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
@@ -1022,8 +1048,8 @@ impl LockModeSwitchRequest {
         if header.minor_opcode != LOCK_MODE_SWITCH_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let (lock, remaining) = u16::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let (lock, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "lock")?;
         let _ = remaining;
         Ok(LockModeSwitchRequest {
             screen,
@@ -1033,6 +1059,7 @@ impl LockModeSwitchRequest {
 }
 impl Request for LockModeSwitchRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1077,8 +1104,8 @@ impl GetAllModeLinesRequest {
         if header.minor_opcode != GET_ALL_MODE_LINES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetAllModeLinesRequest {
             screen,
@@ -1087,6 +1114,7 @@ impl GetAllModeLinesRequest {
 }
 impl Request for GetAllModeLinesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1109,20 +1137,19 @@ pub struct GetAllModeLinesReply {
 impl TryParse for GetAllModeLinesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (modecount, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (modeinfo, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, modecount.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (modecount, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "modecount")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (modeinfo, remaining) = crate::x11_utils::parse_list::<ModeInfo>(remaining, modecount.try_to_usize()?, Some("modeinfo"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetAllModeLinesReply { sequence, length, modeinfo };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1161,6 +1188,27 @@ impl GetAllModeLinesReply {
             .try_into().unwrap()
     }
 }
+impl GetAllModeLinesReply {
+    /// Lazily parse the `modeinfo` list from the raw bytes of a `GetAllModeLinesReply`.
+    ///
+    /// In contrast to parsing the whole `GetAllModeLinesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn modeinfo_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ModeInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (modecount, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "modecount")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = modecount.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the AddModeLine request
 pub const ADD_MODE_LINE_REQUEST: u8 = 7;
@@ -1329,37 +1377,37 @@ impl<'input> AddModeLineRequest<'input> {
         if header.minor_opcode != ADD_MODE_LINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (after_dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (after_hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (after_hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (after_hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (after_htotal, remaining) = u16::try_parse(remaining)?;
-        let (after_hskew, remaining) = u16::try_parse(remaining)?;
-        let (after_vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (after_vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (after_vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (after_vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (after_flags, remaining) = u32::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (after_dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "after_dotclock")?;
+        let (after_hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_hdisplay")?;
+        let (after_hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_hsyncstart")?;
+        let (after_hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_hsyncend")?;
+        let (after_htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_htotal")?;
+        let (after_hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_hskew")?;
+        let (after_vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_vdisplay")?;
+        let (after_vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_vsyncstart")?;
+        let (after_vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_vsyncend")?;
+        let (after_vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "after_vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (after_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "after_flags")?;
         let after_flags = after_flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let _ = remaining;
         Ok(AddModeLineRequest {
             screen,
@@ -1420,6 +1468,7 @@ impl<'input> AddModeLineRequest<'input> {
 }
 impl<'input> Request for AddModeLineRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1536,23 +1585,23 @@ impl<'input> DeleteModeLineRequest<'input> {
         if header.minor_opcode != DELETE_MODE_LINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let _ = remaining;
         Ok(DeleteModeLineRequest {
             screen,
@@ -1591,6 +1640,7 @@ impl<'input> DeleteModeLineRequest<'input> {
 }
 impl<'input> Request for DeleteModeLineRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1707,23 +1757,23 @@ impl<'input> ValidateModeLineRequest<'input> {
         if header.minor_opcode != VALIDATE_MODE_LINE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let _ = remaining;
         Ok(ValidateModeLineRequest {
             screen,
@@ -1762,6 +1812,7 @@ impl<'input> ValidateModeLineRequest<'input> {
 }
 impl<'input> Request for ValidateModeLineRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1784,19 +1835,18 @@ pub struct ValidateModeLineReply {
 impl TryParse for ValidateModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ValidateModeLineReply { sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1959,23 +2009,23 @@ impl<'input> SwitchToModeRequest<'input> {
         if header.minor_opcode != SWITCH_TO_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "screen")?;
+        let (dotclock, remaining) = crate::x11_utils::in_field(Dotclock::try_parse(remaining), "dotclock")?;
+        let (hdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hdisplay")?;
+        let (hsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncstart")?;
+        let (hsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hsyncend")?;
+        let (htotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "htotal")?;
+        let (hskew, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hskew")?;
+        let (vdisplay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vdisplay")?;
+        let (vsyncstart, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncstart")?;
+        let (vsyncend, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vsyncend")?;
+        let (vtotal, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vtotal")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (privsize, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "privsize")?;
+        let (private, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?), "private")?;
         let _ = remaining;
         Ok(SwitchToModeRequest {
             screen,
@@ -2014,6 +2064,7 @@ impl<'input> SwitchToModeRequest<'input> {
 }
 impl<'input> Request for SwitchToModeRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2058,8 +2109,8 @@ impl GetViewPortRequest {
         if header.minor_opcode != GET_VIEW_PORT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetViewPortRequest {
             screen,
@@ -2068,6 +2119,7 @@ impl GetViewPortRequest {
 }
 impl Request for GetViewPortRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2091,20 +2143,19 @@ pub struct GetViewPortReply {
 impl TryParse for GetViewPortReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = u32::try_parse(remaining)?;
-        let (y, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "y")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetViewPortReply { sequence, length, x, y };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2209,10 +2260,10 @@ impl SetViewPortRequest {
         if header.minor_opcode != SET_VIEW_PORT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (x, remaining) = u32::try_parse(remaining)?;
-        let (y, remaining) = u32::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "y")?;
         let _ = remaining;
         Ok(SetViewPortRequest {
             screen,
@@ -2223,6 +2274,7 @@ impl SetViewPortRequest {
 }
 impl Request for SetViewPortRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2267,8 +2319,8 @@ impl GetDotClocksRequest {
         if header.minor_opcode != GET_DOT_CLOCKS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetDotClocksRequest {
             screen,
@@ -2277,6 +2329,7 @@ impl GetDotClocksRequest {
 }
 impl Request for GetDotClocksRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2302,23 +2355,22 @@ pub struct GetDotClocksReply {
 impl TryParse for GetDotClocksReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (clocks, remaining) = u32::try_parse(remaining)?;
-        let (maxclocks, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (clock, remaining) = crate::x11_utils::parse_list::<u32>(remaining, 1u32.checked_sub(u32::from(flags) & 1u32).ok_or(ParseError::InvalidExpression)?.checked_mul(u32::from(clocks)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (clocks, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "clocks")?;
+        let (maxclocks, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "maxclocks")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (clock, remaining) = crate::x11_utils::parse_list::<u32>(remaining, 1u32.checked_sub(u32::from(flags) & 1u32).ok_or(ParseError::InvalidExpression)?.checked_mul(u32::from(clocks)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("clock"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let flags = flags.into();
         let result = GetDotClocksReply { sequence, length, flags, clocks, maxclocks, clock };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2380,8 +2432,8 @@ impl SetClientVersionRequest {
         if header.minor_opcode != SET_CLIENT_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major, remaining) = u16::try_parse(value)?;
-        let (minor, remaining) = u16::try_parse(remaining)?;
+        let (major, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor")?;
         let _ = remaining;
         Ok(SetClientVersionRequest {
             major,
@@ -2391,6 +2443,7 @@ impl SetClientVersionRequest {
 }
 impl Request for SetClientVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2465,12 +2518,12 @@ impl SetGammaRequest {
         if header.minor_opcode != SET_GAMMA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (red, remaining) = u32::try_parse(remaining)?;
-        let (green, remaining) = u32::try_parse(remaining)?;
-        let (blue, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (red, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         let _ = remaining;
         Ok(SetGammaRequest {
             screen,
@@ -2482,6 +2535,7 @@ impl SetGammaRequest {
 }
 impl Request for SetGammaRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2550,8 +2604,8 @@ impl GetGammaRequest {
         if header.minor_opcode != GET_GAMMA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(26..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 26)?;
         let _ = remaining;
         Ok(GetGammaRequest {
             screen,
@@ -2560,6 +2614,7 @@ impl GetGammaRequest {
 }
 impl Request for GetGammaRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2584,21 +2639,20 @@ pub struct GetGammaReply {
 impl TryParse for GetGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u32::try_parse(remaining)?;
-        let (green, remaining) = u32::try_parse(remaining)?;
-        let (blue, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (red, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetGammaReply { sequence, length, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2695,8 +2749,8 @@ impl GetGammaRampRequest {
         if header.minor_opcode != GET_GAMMA_RAMP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
         let _ = remaining;
         Ok(GetGammaRampRequest {
             screen,
@@ -2706,6 +2760,7 @@ impl GetGammaRampRequest {
 }
 impl Request for GetGammaRampRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2731,22 +2786,21 @@ pub struct GetGammaRampReply {
 impl TryParse for GetGammaRampReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("red"))?;
+        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("green"))?;
+        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("blue"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetGammaRampReply { sequence, length, size, red, green, blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2774,6 +2828,27 @@ impl Serialize for GetGammaRampReply {
         self.blue.serialize_into(bytes);
     }
 }
+impl GetGammaRampReply {
+    /// Lazily parse the `red` list from the raw bytes of a `GetGammaRampReply`.
+    ///
+    /// In contrast to parsing the whole `GetGammaRampReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn red_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u16>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetGammaRamp request
 pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
@@ -2824,11 +2899,11 @@ impl<'input> SetGammaRampRequest<'input> {
         if header.minor_opcode != SET_GAMMA_RAMP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
-        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
-        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let (red, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("red"))?;
+        let (green, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("green"))?;
+        let (blue, remaining) = crate::x11_utils::parse_list::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_to_usize()?, Some("blue"))?;
         let _ = remaining;
         Ok(SetGammaRampRequest {
             screen,
@@ -2851,6 +2926,7 @@ impl<'input> SetGammaRampRequest<'input> {
 }
 impl<'input> Request for SetGammaRampRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2895,8 +2971,8 @@ impl GetGammaRampSizeRequest {
         if header.minor_opcode != GET_GAMMA_RAMP_SIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetGammaRampSizeRequest {
             screen,
@@ -2905,6 +2981,7 @@ impl GetGammaRampSizeRequest {
 }
 impl Request for GetGammaRampSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2927,19 +3004,18 @@ pub struct GetGammaRampSizeReply {
 impl TryParse for GetGammaRampSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (size, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetGammaRampSizeReply { sequence, length, size };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3030,8 +3106,8 @@ impl GetPermissionsRequest {
         if header.minor_opcode != GET_PERMISSIONS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (screen, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (screen, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetPermissionsRequest {
             screen,
@@ -3040,6 +3116,7 @@ impl GetPermissionsRequest {
 }
 impl Request for GetPermissionsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3062,20 +3139,19 @@ pub struct GetPermissionsReply {
 impl TryParse for GetPermissionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (permissions, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (permissions, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "permissions")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let permissions = permissions.into();
         let result = GetPermissionsReply { sequence, length, permissions };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -79,8 +79,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major_version, remaining) = u32::try_parse(value)?;
-        let (client_minor_version, remaining) = u32::try_parse(remaining)?;
+        let (client_major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "client_major_version")?;
+        let (client_minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "client_minor_version")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major_version,
@@ -90,6 +90,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -113,20 +114,19 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -406,14 +406,14 @@ impl ChangeSaveSetRequest {
         if header.minor_opcode != CHANGE_SAVE_SET_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (mode, remaining) = u8::try_parse(value)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "mode")?;
         let mode = mode.into();
-        let (target, remaining) = u8::try_parse(remaining)?;
+        let (target, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "target")?;
         let target = target.into();
-        let (map, remaining) = u8::try_parse(remaining)?;
+        let (map, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map")?;
         let map = map.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         let _ = remaining;
         Ok(ChangeSaveSetRequest {
             mode,
@@ -425,6 +425,7 @@ impl ChangeSaveSetRequest {
 }
 impl Request for ChangeSaveSetRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -545,7 +546,7 @@ impl core::fmt::Debug for SelectionEventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(SelectionEventMask, u32);
+bitmask_binop!(SelectionEventMask, u32, [SET_SELECTION_OWNER, SELECTION_WINDOW_DESTROY, SELECTION_CLIENT_CLOSE]);
 
 /// Opcode for the SelectionNotify event
 pub const SELECTION_NOTIFY_EVENT: u8 = 0;
@@ -564,15 +565,15 @@ pub struct SelectionNotifyEvent {
 impl TryParse for SelectionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (subtype, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (owner, remaining) = xproto::Window::try_parse(remaining)?;
-        let (selection, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (selection_timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (subtype, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "subtype")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (owner, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "owner")?;
+        let (selection, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "selection")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (selection_timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "selection_timestamp")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
         let subtype = subtype.into();
         let result = SelectionNotifyEvent { response_type, subtype, sequence, window, owner, selection, timestamp, selection_timestamp };
         let _ = remaining;
@@ -737,9 +738,9 @@ impl SelectSelectionInputRequest {
         if header.minor_opcode != SELECT_SELECTION_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (selection, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (selection, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "selection")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
         let _ = remaining;
         Ok(SelectSelectionInputRequest {
@@ -751,6 +752,7 @@ impl SelectSelectionInputRequest {
 }
 impl Request for SelectSelectionInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -863,7 +865,7 @@ impl core::fmt::Debug for CursorNotifyMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(CursorNotifyMask, u32);
+bitmask_binop!(CursorNotifyMask, u32, [DISPLAY_CURSOR]);
 
 /// Opcode for the CursorNotify event
 pub const CURSOR_NOTIFY_EVENT: u8 = 1;
@@ -881,14 +883,14 @@ pub struct CursorNotifyEvent {
 impl TryParse for CursorNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (subtype, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
-        let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (subtype, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "subtype")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (cursor_serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cursor_serial")?;
+        let (timestamp, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "timestamp")?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         let subtype = subtype.into();
         let result = CursorNotifyEvent { response_type, subtype, sequence, window, cursor_serial, timestamp, name };
         let _ = remaining;
@@ -1044,8 +1046,8 @@ impl SelectCursorInputRequest {
         if header.minor_opcode != SELECT_CURSOR_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
         let _ = remaining;
         Ok(SelectCursorInputRequest {
@@ -1056,6 +1058,7 @@ impl SelectCursorInputRequest {
 }
 impl Request for SelectCursorInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1100,6 +1103,7 @@ impl GetCursorImageRequest {
 }
 impl Request for GetCursorImageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1129,26 +1133,25 @@ pub struct GetCursorImageReply {
 impl TryParse for GetCursorImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (xhot, remaining) = u16::try_parse(remaining)?;
-        let (yhot, remaining) = u16::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (xhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "xhot")?;
+        let (yhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "yhot")?;
+        let (cursor_serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cursor_serial")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("cursor_image"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCursorImageReply { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_image };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1178,6 +1181,33 @@ impl Serialize for GetCursorImageReply {
         self.cursor_image.serialize_into(bytes);
     }
 }
+impl GetCursorImageReply {
+    /// Lazily parse the `cursor_image` list from the raw bytes of a `GetCursorImageReply`.
+    ///
+    /// In contrast to parsing the whole `GetCursorImageReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn cursor_image_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (xhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "xhot")?;
+        let (yhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "yhot")?;
+        let (cursor_serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cursor_serial")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 pub type Region = u32;
 
@@ -1279,7 +1309,7 @@ impl<'input> CreateRegionRequest<'input> {
         if header.minor_opcode != CREATE_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -1304,6 +1334,7 @@ impl<'input> CreateRegionRequest<'input> {
 }
 impl<'input> Request for CreateRegionRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1354,8 +1385,8 @@ impl CreateRegionFromBitmapRequest {
         if header.minor_opcode != CREATE_REGION_FROM_BITMAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
-        let (bitmap, remaining) = xproto::Pixmap::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
+        let (bitmap, remaining) = crate::x11_utils::in_field(xproto::Pixmap::try_parse(remaining), "bitmap")?;
         let _ = remaining;
         Ok(CreateRegionFromBitmapRequest {
             region,
@@ -1365,6 +1396,7 @@ impl CreateRegionFromBitmapRequest {
 }
 impl Request for CreateRegionFromBitmapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1421,11 +1453,11 @@ impl CreateRegionFromWindowRequest {
         if header.minor_opcode != CREATE_REGION_FROM_WINDOW_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (kind, remaining) = shape::Kind::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (kind, remaining) = crate::x11_utils::in_field(shape::Kind::try_parse(remaining), "kind")?;
         let kind = kind.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CreateRegionFromWindowRequest {
             region,
@@ -1436,6 +1468,7 @@ impl CreateRegionFromWindowRequest {
 }
 impl Request for CreateRegionFromWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1486,8 +1519,8 @@ impl CreateRegionFromGCRequest {
         if header.minor_opcode != CREATE_REGION_FROM_GC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
         let _ = remaining;
         Ok(CreateRegionFromGCRequest {
             region,
@@ -1497,6 +1530,7 @@ impl CreateRegionFromGCRequest {
 }
 impl Request for CreateRegionFromGCRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1547,8 +1581,8 @@ impl CreateRegionFromPictureRequest {
         if header.minor_opcode != CREATE_REGION_FROM_PICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
-        let (picture, remaining) = render::Picture::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
+        let (picture, remaining) = crate::x11_utils::in_field(render::Picture::try_parse(remaining), "picture")?;
         let _ = remaining;
         Ok(CreateRegionFromPictureRequest {
             region,
@@ -1558,6 +1592,7 @@ impl CreateRegionFromPictureRequest {
 }
 impl Request for CreateRegionFromPictureRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1602,7 +1637,7 @@ impl DestroyRegionRequest {
         if header.minor_opcode != DESTROY_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
         let _ = remaining;
         Ok(DestroyRegionRequest {
             region,
@@ -1611,6 +1646,7 @@ impl DestroyRegionRequest {
 }
 impl Request for DestroyRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1660,7 +1696,7 @@ impl<'input> SetRegionRequest<'input> {
         if header.minor_opcode != SET_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -1685,6 +1721,7 @@ impl<'input> SetRegionRequest<'input> {
 }
 impl<'input> Request for SetRegionRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1735,8 +1772,8 @@ impl CopyRegionRequest {
         if header.minor_opcode != COPY_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source, remaining) = Region::try_parse(value)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(CopyRegionRequest {
             source,
@@ -1746,6 +1783,7 @@ impl CopyRegionRequest {
 }
 impl Request for CopyRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1802,9 +1840,9 @@ impl UnionRegionRequest {
         if header.minor_opcode != UNION_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source1, remaining) = Region::try_parse(value)?;
-        let (source2, remaining) = Region::try_parse(remaining)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source1, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source1")?;
+        let (source2, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "source2")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(UnionRegionRequest {
             source1,
@@ -1815,6 +1853,7 @@ impl UnionRegionRequest {
 }
 impl Request for UnionRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1871,9 +1910,9 @@ impl IntersectRegionRequest {
         if header.minor_opcode != INTERSECT_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source1, remaining) = Region::try_parse(value)?;
-        let (source2, remaining) = Region::try_parse(remaining)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source1, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source1")?;
+        let (source2, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "source2")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(IntersectRegionRequest {
             source1,
@@ -1884,6 +1923,7 @@ impl IntersectRegionRequest {
 }
 impl Request for IntersectRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1940,9 +1980,9 @@ impl SubtractRegionRequest {
         if header.minor_opcode != SUBTRACT_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source1, remaining) = Region::try_parse(value)?;
-        let (source2, remaining) = Region::try_parse(remaining)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source1, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source1")?;
+        let (source2, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "source2")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(SubtractRegionRequest {
             source1,
@@ -1953,6 +1993,7 @@ impl SubtractRegionRequest {
 }
 impl Request for SubtractRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2013,9 +2054,9 @@ impl InvertRegionRequest {
         if header.minor_opcode != INVERT_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source, remaining) = Region::try_parse(value)?;
-        let (bounds, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source")?;
+        let (bounds, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "bounds")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(InvertRegionRequest {
             source,
@@ -2026,6 +2067,7 @@ impl InvertRegionRequest {
 }
 impl Request for InvertRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2078,9 +2120,9 @@ impl TranslateRegionRequest {
         if header.minor_opcode != TRANSLATE_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
-        let (dx, remaining) = i16::try_parse(remaining)?;
-        let (dy, remaining) = i16::try_parse(remaining)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
+        let (dx, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dx")?;
+        let (dy, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dy")?;
         let _ = remaining;
         Ok(TranslateRegionRequest {
             region,
@@ -2091,6 +2133,7 @@ impl TranslateRegionRequest {
 }
 impl Request for TranslateRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2141,8 +2184,8 @@ impl RegionExtentsRequest {
         if header.minor_opcode != REGION_EXTENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source, remaining) = Region::try_parse(value)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
+        let (source, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(RegionExtentsRequest {
             source,
@@ -2152,6 +2195,7 @@ impl RegionExtentsRequest {
 }
 impl Request for RegionExtentsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2196,7 +2240,7 @@ impl FetchRegionRequest {
         if header.minor_opcode != FETCH_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (region, remaining) = Region::try_parse(value)?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "region")?;
         let _ = remaining;
         Ok(FetchRegionRequest {
             region,
@@ -2205,6 +2249,7 @@ impl FetchRegionRequest {
 }
 impl Request for FetchRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2227,20 +2272,19 @@ pub struct FetchRegionReply {
 impl TryParse for FetchRegionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (extents, remaining) = xproto::Rectangle::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (extents, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "extents")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list::<xproto::Rectangle>(remaining, u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("rectangles"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = FetchRegionReply { sequence, extents, rectangles };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2280,6 +2324,27 @@ impl FetchRegionReply {
             .try_into().unwrap()
     }
 }
+impl FetchRegionReply {
+    /// Lazily parse the `rectangles` list from the raw bytes of a `FetchRegionReply`.
+    ///
+    /// In contrast to parsing the whole `FetchRegionReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn rectangles_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Rectangle>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (extents, remaining) = crate::x11_utils::in_field(xproto::Rectangle::try_parse(remaining), "extents")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(length).checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetGCClipRegion request
 pub const SET_GC_CLIP_REGION_REQUEST: u8 = 20;
@@ -2328,10 +2393,10 @@ impl SetGCClipRegionRequest {
         if header.minor_opcode != SET_GC_CLIP_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (gc, remaining) = xproto::Gcontext::try_parse(value)?;
-        let (region, remaining) = Region::try_parse(remaining)?;
-        let (x_origin, remaining) = i16::try_parse(remaining)?;
-        let (y_origin, remaining) = i16::try_parse(remaining)?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(value), "gc")?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "region")?;
+        let (x_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_origin")?;
+        let (y_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_origin")?;
         let _ = remaining;
         Ok(SetGCClipRegionRequest {
             gc,
@@ -2343,6 +2408,7 @@ impl SetGCClipRegionRequest {
 }
 impl Request for SetGCClipRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2407,13 +2473,13 @@ impl SetWindowShapeRegionRequest {
         if header.minor_opcode != SET_WINDOW_SHAPE_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (dest, remaining) = xproto::Window::try_parse(value)?;
-        let (dest_kind, remaining) = shape::Kind::try_parse(remaining)?;
+        let (dest, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "dest")?;
+        let (dest_kind, remaining) = crate::x11_utils::in_field(shape::Kind::try_parse(remaining), "dest_kind")?;
         let dest_kind = dest_kind.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (x_offset, remaining) = i16::try_parse(remaining)?;
-        let (y_offset, remaining) = i16::try_parse(remaining)?;
-        let (region, remaining) = Region::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (x_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_offset")?;
+        let (y_offset, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_offset")?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "region")?;
         let _ = remaining;
         Ok(SetWindowShapeRegionRequest {
             dest,
@@ -2426,6 +2492,7 @@ impl SetWindowShapeRegionRequest {
 }
 impl Request for SetWindowShapeRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2484,10 +2551,10 @@ impl SetPictureClipRegionRequest {
         if header.minor_opcode != SET_PICTURE_CLIP_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (picture, remaining) = render::Picture::try_parse(value)?;
-        let (region, remaining) = Region::try_parse(remaining)?;
-        let (x_origin, remaining) = i16::try_parse(remaining)?;
-        let (y_origin, remaining) = i16::try_parse(remaining)?;
+        let (picture, remaining) = crate::x11_utils::in_field(render::Picture::try_parse(value), "picture")?;
+        let (region, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "region")?;
+        let (x_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_origin")?;
+        let (y_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_origin")?;
         let _ = remaining;
         Ok(SetPictureClipRegionRequest {
             picture,
@@ -2499,6 +2566,7 @@ impl SetPictureClipRegionRequest {
 }
 impl Request for SetPictureClipRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2553,10 +2621,10 @@ impl<'input> SetCursorNameRequest<'input> {
         if header.minor_opcode != SET_CURSOR_NAME_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cursor, remaining) = xproto::Cursor::try_parse(value)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "cursor")?;
+        let (nbytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "nbytes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(SetCursorNameRequest {
             cursor,
@@ -2573,6 +2641,7 @@ impl<'input> SetCursorNameRequest<'input> {
 }
 impl<'input> Request for SetCursorNameRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2617,7 +2686,7 @@ impl GetCursorNameRequest {
         if header.minor_opcode != GET_CURSOR_NAME_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cursor, remaining) = xproto::Cursor::try_parse(value)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "cursor")?;
         let _ = remaining;
         Ok(GetCursorNameRequest {
             cursor,
@@ -2626,6 +2695,7 @@ impl GetCursorNameRequest {
 }
 impl Request for GetCursorNameRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2649,22 +2719,21 @@ pub struct GetCursorNameReply {
 impl TryParse for GetCursorNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (atom, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "atom")?;
+        let (nbytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "nbytes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCursorNameReply { sequence, length, atom, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2738,6 +2807,7 @@ impl GetCursorImageAndNameRequest {
 }
 impl Request for GetCursorImageAndNameRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2769,30 +2839,29 @@ pub struct GetCursorImageAndNameReply {
 impl TryParse for GetCursorImageAndNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (xhot, remaining) = u16::try_parse(remaining)?;
-        let (yhot, remaining) = u16::try_parse(remaining)?;
-        let (cursor_serial, remaining) = u32::try_parse(remaining)?;
-        let (cursor_atom, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (xhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "xhot")?;
+        let (yhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "yhot")?;
+        let (cursor_serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cursor_serial")?;
+        let (cursor_atom, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "cursor_atom")?;
+        let (nbytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "nbytes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("cursor_image"))?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetCursorImageAndNameReply { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_atom, cursor_image, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2841,6 +2910,35 @@ impl GetCursorImageAndNameReply {
             .try_into().unwrap()
     }
 }
+impl GetCursorImageAndNameReply {
+    /// Lazily parse the `cursor_image` list from the raw bytes of a `GetCursorImageAndNameReply`.
+    ///
+    /// In contrast to parsing the whole `GetCursorImageAndNameReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn cursor_image_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (xhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "xhot")?;
+        let (yhot, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "yhot")?;
+        let (cursor_serial, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cursor_serial")?;
+        let (cursor_atom, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "cursor_atom")?;
+        let (nbytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "nbytes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ChangeCursor request
 pub const CHANGE_CURSOR_REQUEST: u8 = 26;
@@ -2881,8 +2979,8 @@ impl ChangeCursorRequest {
         if header.minor_opcode != CHANGE_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source, remaining) = xproto::Cursor::try_parse(value)?;
-        let (destination, remaining) = xproto::Cursor::try_parse(remaining)?;
+        let (source, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "source")?;
+        let (destination, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "destination")?;
         let _ = remaining;
         Ok(ChangeCursorRequest {
             source,
@@ -2892,6 +2990,7 @@ impl ChangeCursorRequest {
 }
 impl Request for ChangeCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2946,10 +3045,10 @@ impl<'input> ChangeCursorByNameRequest<'input> {
         if header.minor_opcode != CHANGE_CURSOR_BY_NAME_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (src, remaining) = xproto::Cursor::try_parse(value)?;
-        let (nbytes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?)?;
+        let (src, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(value), "src")?;
+        let (nbytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "nbytes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, nbytes.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(ChangeCursorByNameRequest {
             src,
@@ -2966,6 +3065,7 @@ impl<'input> ChangeCursorByNameRequest<'input> {
 }
 impl<'input> Request for ChangeCursorByNameRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3032,12 +3132,12 @@ impl ExpandRegionRequest {
         if header.minor_opcode != EXPAND_REGION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (source, remaining) = Region::try_parse(value)?;
-        let (destination, remaining) = Region::try_parse(remaining)?;
-        let (left, remaining) = u16::try_parse(remaining)?;
-        let (right, remaining) = u16::try_parse(remaining)?;
-        let (top, remaining) = u16::try_parse(remaining)?;
-        let (bottom, remaining) = u16::try_parse(remaining)?;
+        let (source, remaining) = crate::x11_utils::in_field(Region::try_parse(value), "source")?;
+        let (destination, remaining) = crate::x11_utils::in_field(Region::try_parse(remaining), "destination")?;
+        let (left, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "left")?;
+        let (right, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "right")?;
+        let (top, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "top")?;
+        let (bottom, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "bottom")?;
         let _ = remaining;
         Ok(ExpandRegionRequest {
             source,
@@ -3051,6 +3151,7 @@ impl ExpandRegionRequest {
 }
 impl Request for ExpandRegionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3095,7 +3196,7 @@ impl HideCursorRequest {
         if header.minor_opcode != HIDE_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(HideCursorRequest {
             window,
@@ -3104,6 +3205,7 @@ impl HideCursorRequest {
 }
 impl Request for HideCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3148,7 +3250,7 @@ impl ShowCursorRequest {
         if header.minor_opcode != SHOW_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ShowCursorRequest {
             window,
@@ -3157,6 +3259,7 @@ impl ShowCursorRequest {
 }
 impl Request for ShowCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3220,7 +3323,7 @@ impl core::fmt::Debug for BarrierDirections  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(BarrierDirections, u32);
+bitmask_binop!(BarrierDirections, u32, [POSITIVE_X, POSITIVE_Y, NEGATIVE_X, NEGATIVE_Y]);
 
 /// Opcode for the CreatePointerBarrier request
 pub const CREATE_POINTER_BARRIER_REQUEST: u8 = 31;
@@ -3294,17 +3397,17 @@ impl<'input> CreatePointerBarrierRequest<'input> {
         if header.minor_opcode != CREATE_POINTER_BARRIER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (barrier, remaining) = Barrier::try_parse(value)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (x1, remaining) = u16::try_parse(remaining)?;
-        let (y1, remaining) = u16::try_parse(remaining)?;
-        let (x2, remaining) = u16::try_parse(remaining)?;
-        let (y2, remaining) = u16::try_parse(remaining)?;
-        let (directions, remaining) = u32::try_parse(remaining)?;
+        let (barrier, remaining) = crate::x11_utils::in_field(Barrier::try_parse(value), "barrier")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (x1, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x1")?;
+        let (y1, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y1")?;
+        let (x2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x2")?;
+        let (y2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y2")?;
+        let (directions, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "directions")?;
         let directions = directions.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num_devices, remaining) = u16::try_parse(remaining)?;
-        let (devices, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_devices.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num_devices, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_devices")?;
+        let (devices, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_devices.try_to_usize()?, Some("devices"))?;
         let _ = remaining;
         Ok(CreatePointerBarrierRequest {
             barrier,
@@ -3333,6 +3436,7 @@ impl<'input> CreatePointerBarrierRequest<'input> {
 }
 impl<'input> Request for CreatePointerBarrierRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3377,7 +3481,7 @@ impl DeletePointerBarrierRequest {
         if header.minor_opcode != DELETE_POINTER_BARRIER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (barrier, remaining) = Barrier::try_parse(value)?;
+        let (barrier, remaining) = crate::x11_utils::in_field(Barrier::try_parse(value), "barrier")?;
         let _ = remaining;
         Ok(DeletePointerBarrierRequest {
             barrier,
@@ -3386,6 +3490,7 @@ impl DeletePointerBarrierRequest {
 }
 impl Request for DeletePointerBarrierRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3443,7 +3548,7 @@ impl core::fmt::Debug for ClientDisconnectFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ClientDisconnectFlags, u32);
+bitmask_binop!(ClientDisconnectFlags, u32, [TERMINATE]);
 
 /// Opcode for the SetClientDisconnectMode request
 pub const SET_CLIENT_DISCONNECT_MODE_REQUEST: u8 = 33;
@@ -3478,7 +3583,7 @@ impl SetClientDisconnectModeRequest {
         if header.minor_opcode != SET_CLIENT_DISCONNECT_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (disconnect_mode, remaining) = u32::try_parse(value)?;
+        let (disconnect_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "disconnect_mode")?;
         let disconnect_mode = disconnect_mode.into();
         let _ = remaining;
         Ok(SetClientDisconnectModeRequest {
@@ -3488,6 +3593,7 @@ impl SetClientDisconnectModeRequest {
 }
 impl Request for SetClientDisconnectModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3532,6 +3638,7 @@ impl GetClientDisconnectModeRequest {
 }
 impl Request for GetClientDisconnectModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3554,20 +3661,19 @@ pub struct GetClientDisconnectModeReply {
 impl TryParse for GetClientDisconnectModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (disconnect_mode, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (disconnect_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "disconnect_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let disconnect_mode = disconnect_mode.into();
         let result = GetClientDisconnectModeReply { sequence, length, disconnect_mode };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -46,10 +46,10 @@ pub struct ScreenInfo {
 }
 impl TryParse for ScreenInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x_org, remaining) = i16::try_parse(remaining)?;
-        let (y_org, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (x_org, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x_org")?;
+        let (y_org, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y_org")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let result = ScreenInfo { x_org, y_org, width, height };
         Ok((result, remaining))
     }
@@ -116,8 +116,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major, remaining) = u8::try_parse(value)?;
-        let (minor, remaining) = u8::try_parse(remaining)?;
+        let (major, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "minor")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             major,
@@ -127,6 +127,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -150,19 +151,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major, remaining) = u16::try_parse(remaining)?;
-        let (minor, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major, minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -234,7 +234,7 @@ impl GetStateRequest {
         if header.minor_opcode != GET_STATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetStateRequest {
             window,
@@ -243,6 +243,7 @@ impl GetStateRequest {
 }
 impl Request for GetStateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -266,18 +267,17 @@ pub struct GetStateReply {
 impl TryParse for GetStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetStateReply { state, sequence, length, window };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -348,7 +348,7 @@ impl GetScreenCountRequest {
         if header.minor_opcode != GET_SCREEN_COUNT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetScreenCountRequest {
             window,
@@ -357,6 +357,7 @@ impl GetScreenCountRequest {
 }
 impl Request for GetScreenCountRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -380,18 +381,17 @@ pub struct GetScreenCountReply {
 impl TryParse for GetScreenCountReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (screen_count, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (screen_count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "screen_count")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetScreenCountReply { screen_count, sequence, length, window };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -468,8 +468,8 @@ impl GetScreenSizeRequest {
         if header.minor_opcode != GET_SCREEN_SIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
         let _ = remaining;
         Ok(GetScreenSizeRequest {
             window,
@@ -479,6 +479,7 @@ impl GetScreenSizeRequest {
 }
 impl Request for GetScreenSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -504,21 +505,20 @@ pub struct GetScreenSizeReply {
 impl TryParse for GetScreenSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetScreenSizeReply { sequence, length, width, height, window, screen };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -606,6 +606,7 @@ impl IsActiveRequest {
 }
 impl Request for IsActiveRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -628,18 +629,17 @@ pub struct IsActiveReply {
 impl TryParse for IsActiveReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = IsActiveReply { sequence, length, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -709,6 +709,7 @@ impl QueryScreensRequest {
 }
 impl Request for QueryScreensRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -720,6 +721,132 @@ impl Request for QueryScreensRequest {
 impl crate::x11_utils::ReplyRequest for QueryScreensRequest {
     type Reply = QueryScreensReply;
 }
+impl QueryScreensReply {
+    /// Get the geometry of all screens together with their index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xinerama::{QueryScreensReply, ScreenInfo};
+    /// use x11rb_protocol::protocol::xproto::Rectangle;
+    ///
+    /// let reply = QueryScreensReply {
+    ///     sequence: 0,
+    ///     length: 0,
+    ///     screen_info: vec![ScreenInfo { x_org: 10, y_org: 20, width: 30, height: 40 }],
+    /// };
+    /// let rect = Rectangle { x: 10, y: 20, width: 30, height: 40 };
+    /// assert_eq!(reply.screen_rectangles(), vec![(0, rect)]);
+    /// ```
+    pub fn screen_rectangles(&self) -> Vec<(u32, xproto::Rectangle)> {
+        self.screen_info
+            .iter()
+            .zip(0..)
+            .map(|(info, index)| {
+                let rect = xproto::Rectangle {
+                    x: info.x_org,
+                    y: info.y_org,
+                    width: info.width,
+                    height: info.height,
+                };
+                (index, rect)
+            })
+            .collect()
+    }
+
+    /// Find the index of the first screen containing the given point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xinerama::{QueryScreensReply, ScreenInfo};
+    ///
+    /// let reply = QueryScreensReply {
+    ///     sequence: 0,
+    ///     length: 0,
+    ///     screen_info: vec![
+    ///         ScreenInfo { x_org: 0, y_org: 0, width: 100, height: 100 },
+    ///         ScreenInfo { x_org: 100, y_org: 0, width: 100, height: 100 },
+    ///     ],
+    /// };
+    /// assert_eq!(reply.screen_at(50, 50), Some(0));
+    /// assert_eq!(reply.screen_at(100, 99), Some(1));
+    /// assert_eq!(reply.screen_at(100, 100), None);
+    /// ```
+    pub fn screen_at(&self, x: i16, y: i16) -> Option<u32> {
+        self.screen_containing(x, y)
+            .and_then(|(index, _)| u32::try_from(index).ok())
+    }
+
+    /// Find the first screen containing the given point, together with its index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xinerama::{QueryScreensReply, ScreenInfo};
+    ///
+    /// let right = ScreenInfo { x_org: 100, y_org: 0, width: 100, height: 100 };
+    /// let reply = QueryScreensReply {
+    ///     sequence: 0,
+    ///     length: 0,
+    ///     screen_info: vec![ScreenInfo { x_org: 0, y_org: 0, width: 100, height: 100 }, right],
+    /// };
+    /// assert_eq!(reply.screen_containing(150, 50), Some((1, &right)));
+    /// assert_eq!(reply.screen_containing(-1, 50), None);
+    /// ```
+    pub fn screen_containing(&self, x: i16, y: i16) -> Option<(usize, &ScreenInfo)> {
+        let (x, y) = (i32::from(x), i32::from(y));
+        self.screen_info
+            .iter()
+            .enumerate()
+            .find(|(_, info)| {
+                let (x_org, y_org) = (i32::from(info.x_org), i32::from(info.y_org));
+                x_org <= x
+                    && x < x_org + i32::from(info.width)
+                    && y_org <= y
+                    && y < y_org + i32::from(info.height)
+            })
+    }
+
+    /// Get the smallest rectangle that contains all screens as `(x, y, width, height)`.
+    ///
+    /// If there are no screens, `(0, 0, 0, 0)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xinerama::{QueryScreensReply, ScreenInfo};
+    ///
+    /// let reply = QueryScreensReply {
+    ///     sequence: 0,
+    ///     length: 0,
+    ///     screen_info: vec![
+    ///         ScreenInfo { x_org: 0, y_org: 100, width: 1280, height: 1024 },
+    ///         ScreenInfo { x_org: 1280, y_org: 0, width: 1920, height: 1080 },
+    ///     ],
+    /// };
+    /// assert_eq!(reply.bounding_box(), (0, 0, 3200, 1124));
+    /// ```
+    pub fn bounding_box(&self) -> (i16, i16, u16, u16) {
+        let mut screens = self.screen_info.iter().map(|info| {
+            let (x, y) = (i32::from(info.x_org), i32::from(info.y_org));
+            (x, y, x + i32::from(info.width), y + i32::from(info.height))
+        });
+        let first = match screens.next() {
+            Some(first) => first,
+            None => return (0, 0, 0, 0),
+        };
+        let (x1, y1, x2, y2) = screens.fold(first, |(x1, y1, x2, y2), (a1, b1, a2, b2)| {
+            (x1.min(a1), y1.min(b1), x2.max(a2), y2.max(b2))
+        });
+        // x1 and y1 come from i16 values, so the conversions cannot fail
+        let (x, y) = (i16::try_from(x1).unwrap(), i16::try_from(y1).unwrap());
+        let width = u16::try_from(x2 - x1).unwrap_or(u16::MAX);
+        let height = u16::try_from(y2 - y1).unwrap_or(u16::MAX);
+        (x, y, width, height)
+    }
+}
+
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -731,20 +858,19 @@ pub struct QueryScreensReply {
 impl TryParse for QueryScreensReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (number, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (screen_info, remaining) = crate::x11_utils::parse_list::<ScreenInfo>(remaining, number.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (number, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "number")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (screen_info, remaining) = crate::x11_utils::parse_list::<ScreenInfo>(remaining, number.try_to_usize()?, Some("screen_info"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryScreensReply { sequence, length, screen_info };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -783,4 +909,25 @@ impl QueryScreensReply {
             .try_into().unwrap()
     }
 }
+impl QueryScreensReply {
+    /// Lazily parse the `screen_info` list from the raw bytes of a `QueryScreensReply`.
+    ///
+    /// In contrast to parsing the whole `QueryScreensReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn screen_info_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ScreenInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (number, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "number")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = number.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
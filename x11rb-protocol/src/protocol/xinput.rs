@@ -54,8 +54,8 @@ pub struct Fp3232 {
 }
 impl TryParse for Fp3232 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (integral, remaining) = i32::try_parse(remaining)?;
-        let (frac, remaining) = u32::try_parse(remaining)?;
+        let (integral, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "integral")?;
+        let (frac, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "frac")?;
         let result = Fp3232 { integral, frac };
         Ok((result, remaining))
     }
@@ -120,9 +120,9 @@ impl<'input> GetExtensionVersionRequest<'input> {
         if header.minor_opcode != GET_EXTENSION_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (name_len, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(GetExtensionVersionRequest {
             name: Cow::Borrowed(name),
@@ -137,6 +137,7 @@ impl<'input> GetExtensionVersionRequest<'input> {
 }
 impl<'input> Request for GetExtensionVersionRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -162,21 +163,20 @@ pub struct GetExtensionVersionReply {
 impl TryParse for GetExtensionVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
-        let (present, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(19..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major")?;
+        let (server_minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor")?;
+        let (present, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "present")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 19)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetExtensionVersionReply { xi_reply_type, sequence, length, server_major, server_minor, present };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -442,11 +442,11 @@ pub struct DeviceInfo {
 }
 impl TryParse for DeviceInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (device_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (num_class_info, remaining) = u8::try_parse(remaining)?;
-        let (device_use, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (device_type, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "device_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (num_class_info, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_class_info")?;
+        let (device_use, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_use")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let device_use = device_use.into();
         let result = DeviceInfo { device_type, device_id, num_class_info, device_use };
         Ok((result, remaining))
@@ -491,12 +491,12 @@ pub struct KeyInfo {
 }
 impl TryParse for KeyInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (min_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (max_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (min_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "min_keycode")?;
+        let (max_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "max_keycode")?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let class_id = class_id.into();
         let result = KeyInfo { class_id, len, min_keycode, max_keycode, num_keys };
         Ok((result, remaining))
@@ -541,9 +541,9 @@ pub struct ButtonInfo {
 }
 impl TryParse for ButtonInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_buttons")?;
         let class_id = class_id.into();
         let result = ButtonInfo { class_id, len, num_buttons };
         Ok((result, remaining))
@@ -579,9 +579,9 @@ pub struct AxisInfo {
 }
 impl TryParse for AxisInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (minimum, remaining) = i32::try_parse(remaining)?;
-        let (maximum, remaining) = i32::try_parse(remaining)?;
+        let (resolution, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resolution")?;
+        let (minimum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "minimum")?;
+        let (maximum, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "maximum")?;
         let result = AxisInfo { resolution, minimum, maximum };
         Ok((result, remaining))
     }
@@ -626,12 +626,12 @@ pub struct ValuatorInfo {
 }
 impl TryParse for ValuatorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (axes_len, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (motion_size, remaining) = u32::try_parse(remaining)?;
-        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (axes_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "axes_len")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (motion_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "motion_size")?;
+        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?, Some("axes"))?;
         let class_id = class_id.into();
         let mode = mode.into();
         let result = ValuatorInfo { class_id, len, mode, motion_size, axes };
@@ -681,10 +681,10 @@ pub struct InputInfoInfoKey {
 }
 impl TryParse for InputInfoInfoKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (max_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (min_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "min_keycode")?;
+        let (max_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "max_keycode")?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = InputInfoInfoKey { min_keycode, max_keycode, num_keys };
         Ok((result, remaining))
     }
@@ -719,7 +719,7 @@ pub struct InputInfoInfoButton {
 }
 impl TryParse for InputInfoInfoButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_buttons")?;
         let result = InputInfoInfoButton { num_buttons };
         Ok((result, remaining))
     }
@@ -747,10 +747,10 @@ pub struct InputInfoInfoValuator {
 }
 impl TryParse for InputInfoInfoValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (axes_len, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (motion_size, remaining) = u32::try_parse(remaining)?;
-        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?)?;
+        let (axes_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "axes_len")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (motion_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "motion_size")?;
+        let (axes, remaining) = crate::x11_utils::parse_list::<AxisInfo>(remaining, axes_len.try_to_usize()?, Some("axes"))?;
         let mode = mode.into();
         let result = InputInfoInfoValuator { mode, motion_size, axes };
         Ok((result, remaining))
@@ -888,8 +888,8 @@ pub struct InputInfo {
 }
 impl TryParse for InputInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
         let (info, remaining) = InputInfoInfo::try_parse(remaining, u8::from(class_id))?;
         let result = InputInfo { len, info };
         Ok((result, remaining))
@@ -918,8 +918,8 @@ pub struct DeviceName {
 }
 impl TryParse for DeviceName {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, len.try_to_usize()?)?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, len.try_to_usize()?), "string")?;
         let string = string.to_vec();
         let result = DeviceName { string };
         Ok((result, remaining))
@@ -988,6 +988,7 @@ impl ListInputDevicesRequest {
 }
 impl Request for ListInputDevicesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1014,26 +1015,25 @@ impl TryParse for ListInputDevicesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (devices_len, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (devices, remaining) = crate::x11_utils::parse_list::<DeviceInfo>(remaining, devices_len.try_to_usize()?)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<InputInfo>(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, devices_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (devices_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "devices_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (devices, remaining) = crate::x11_utils::parse_list::<DeviceInfo>(remaining, devices_len.try_to_usize()?, Some("devices"))?;
+        let (infos, remaining) = crate::x11_utils::parse_list::<InputInfo>(remaining, devices.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(x.num_class_info)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("infos"))?;
+        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Str>(remaining, devices_len.try_to_usize()?, Some("names"))?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListInputDevicesReply { xi_reply_type, sequence, length, devices, infos, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1077,6 +1077,27 @@ impl ListInputDevicesReply {
             .try_into().unwrap()
     }
 }
+impl ListInputDevicesReply {
+    /// Lazily parse the `devices` list from the raw bytes of a `ListInputDevicesReply`.
+    ///
+    /// In contrast to parsing the whole `ListInputDevicesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn devices_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, DeviceInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (devices_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "devices_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = devices_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 pub type EventTypeBase = u8;
 
@@ -1088,8 +1109,8 @@ pub struct InputClassInfo {
 }
 impl TryParse for InputClassInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (event_type_base, remaining) = EventTypeBase::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (event_type_base, remaining) = crate::x11_utils::in_field(EventTypeBase::try_parse(remaining), "event_type_base")?;
         let class_id = class_id.into();
         let result = InputClassInfo { class_id, event_type_base };
         Ok((result, remaining))
@@ -1145,8 +1166,8 @@ impl OpenDeviceRequest {
         if header.minor_opcode != OPEN_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(OpenDeviceRequest {
             device_id,
@@ -1155,6 +1176,7 @@ impl OpenDeviceRequest {
 }
 impl Request for OpenDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1179,24 +1201,23 @@ impl TryParse for OpenDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (class_info, remaining) = crate::x11_utils::parse_list::<InputClassInfo>(remaining, num_classes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (class_info, remaining) = crate::x11_utils::parse_list::<InputClassInfo>(remaining, num_classes.try_to_usize()?, Some("class_info"))?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = OpenDeviceReply { xi_reply_type, sequence, length, class_info };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1236,6 +1257,27 @@ impl OpenDeviceReply {
             .try_into().unwrap()
     }
 }
+impl OpenDeviceReply {
+    /// Lazily parse the `class_info` list from the raw bytes of a `OpenDeviceReply`.
+    ///
+    /// In contrast to parsing the whole `OpenDeviceReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn class_info_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, InputClassInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_classes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CloseDevice request
 pub const CLOSE_DEVICE_REQUEST: u8 = 4;
@@ -1270,8 +1312,8 @@ impl CloseDeviceRequest {
         if header.minor_opcode != CLOSE_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(CloseDeviceRequest {
             device_id,
@@ -1280,6 +1322,7 @@ impl CloseDeviceRequest {
 }
 impl Request for CloseDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1326,10 +1369,10 @@ impl SetDeviceModeRequest {
         if header.minor_opcode != SET_DEVICE_MODE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(SetDeviceModeRequest {
             device_id,
@@ -1339,6 +1382,7 @@ impl SetDeviceModeRequest {
 }
 impl Request for SetDeviceModeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1362,20 +1406,19 @@ pub struct SetDeviceModeReply {
 impl TryParse for SetDeviceModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetDeviceModeReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1478,10 +1521,10 @@ impl<'input> SelectExtensionEventRequest<'input> {
         if header.minor_opcode != SELECT_EXTENSION_EVENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(SelectExtensionEventRequest {
             window,
@@ -1498,6 +1541,7 @@ impl<'input> SelectExtensionEventRequest<'input> {
 }
 impl<'input> Request for SelectExtensionEventRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1542,7 +1586,7 @@ impl GetSelectedExtensionEventsRequest {
         if header.minor_opcode != GET_SELECTED_EXTENSION_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetSelectedExtensionEventsRequest {
             window,
@@ -1551,6 +1595,7 @@ impl GetSelectedExtensionEventsRequest {
 }
 impl Request for GetSelectedExtensionEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1575,22 +1620,21 @@ pub struct GetSelectedExtensionEventsReply {
 impl TryParse for GetSelectedExtensionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_this_classes, remaining) = u16::try_parse(remaining)?;
-        let (num_all_classes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (this_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_this_classes.try_to_usize()?)?;
-        let (all_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_all_classes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_this_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_this_classes")?;
+        let (num_all_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_all_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (this_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_this_classes.try_to_usize()?, Some("this_classes"))?;
+        let (all_classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_all_classes.try_to_usize()?, Some("all_classes"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectedExtensionEventsReply { xi_reply_type, sequence, length, this_classes, all_classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1645,6 +1689,28 @@ impl GetSelectedExtensionEventsReply {
             .try_into().unwrap()
     }
 }
+impl GetSelectedExtensionEventsReply {
+    /// Lazily parse the `this_classes` list from the raw bytes of a `GetSelectedExtensionEventsReply`.
+    ///
+    /// In contrast to parsing the whole `GetSelectedExtensionEventsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn this_classes_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, EventClass>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_this_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_this_classes")?;
+        let (num_all_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_all_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_this_classes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1751,12 +1817,12 @@ impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
         if header.minor_opcode != CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(ChangeDeviceDontPropagateListRequest {
             window,
@@ -1775,6 +1841,7 @@ impl<'input> ChangeDeviceDontPropagateListRequest<'input> {
 }
 impl<'input> Request for ChangeDeviceDontPropagateListRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1819,7 +1886,7 @@ impl GetDeviceDontPropagateListRequest {
         if header.minor_opcode != GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetDeviceDontPropagateListRequest {
             window,
@@ -1828,6 +1895,7 @@ impl GetDeviceDontPropagateListRequest {
 }
 impl Request for GetDeviceDontPropagateListRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1851,20 +1919,19 @@ pub struct GetDeviceDontPropagateListReply {
 impl TryParse for GetDeviceDontPropagateListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceDontPropagateListReply { xi_reply_type, sequence, length, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1903,6 +1970,27 @@ impl GetDeviceDontPropagateListReply {
             .try_into().unwrap()
     }
 }
+impl GetDeviceDontPropagateListReply {
+    /// Lazily parse the `classes` list from the raw bytes of a `GetDeviceDontPropagateListReply`.
+    ///
+    /// In contrast to parsing the whole `GetDeviceDontPropagateListReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn classes_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, EventClass>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_classes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1912,8 +2000,8 @@ pub struct DeviceTimeCoord {
 }
 impl DeviceTimeCoord {
     pub fn try_parse(remaining: &[u8], num_axes: u8) -> Result<(Self, &[u8]), ParseError> {
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_axes.try_to_usize()?)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_axes.try_to_usize()?, Some("axisvalues"))?;
         let result = DeviceTimeCoord { time, axisvalues };
         Ok((result, remaining))
     }
@@ -1977,10 +2065,10 @@ impl GetDeviceMotionEventsRequest {
         if header.minor_opcode != GET_DEVICE_MOTION_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (start, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (stop, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (start, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "start")?;
+        let (stop, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "stop")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetDeviceMotionEventsRequest {
             start,
@@ -1991,6 +2079,7 @@ impl GetDeviceMotionEventsRequest {
 }
 impl Request for GetDeviceMotionEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2016,14 +2105,14 @@ pub struct GetDeviceMotionEventsReply {
 impl TryParse for GetDeviceMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_events, remaining) = u32::try_parse(remaining)?;
-        let (num_axes, remaining) = u8::try_parse(remaining)?;
-        let (device_mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_events, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_events")?;
+        let (num_axes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_axes")?;
+        let (device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
         let mut remaining = remaining;
         let list_length = num_events.try_to_usize()?;
         let mut events = Vec::with_capacity(list_length);
@@ -2033,13 +2122,12 @@ impl TryParse for GetDeviceMotionEventsReply {
             events.push(v);
         }
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let device_mode = device_mode.into();
         let result = GetDeviceMotionEventsReply { xi_reply_type, sequence, length, num_axes, device_mode, events };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2116,8 +2204,8 @@ impl ChangeKeyboardDeviceRequest {
         if header.minor_opcode != CHANGE_KEYBOARD_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(ChangeKeyboardDeviceRequest {
             device_id,
@@ -2126,6 +2214,7 @@ impl ChangeKeyboardDeviceRequest {
 }
 impl Request for ChangeKeyboardDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2149,20 +2238,19 @@ pub struct ChangeKeyboardDeviceReply {
 impl TryParse for ChangeKeyboardDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = ChangeKeyboardDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2258,10 +2346,10 @@ impl ChangePointerDeviceRequest {
         if header.minor_opcode != CHANGE_POINTER_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (x_axis, remaining) = u8::try_parse(value)?;
-        let (y_axis, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (x_axis, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "x_axis")?;
+        let (y_axis, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "y_axis")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         Ok(ChangePointerDeviceRequest {
             x_axis,
@@ -2272,6 +2360,7 @@ impl ChangePointerDeviceRequest {
 }
 impl Request for ChangePointerDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2295,20 +2384,19 @@ pub struct ChangePointerDeviceReply {
 impl TryParse for ChangePointerDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = ChangePointerDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2429,17 +2517,17 @@ impl<'input> GrabDeviceRequest<'input> {
         if header.minor_opcode != GRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (this_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (this_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "this_device_mode")?;
         let this_device_mode = this_device_mode.into();
-        let (other_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (other_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "other_device_mode")?;
         let other_device_mode = other_device_mode.into();
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(GrabDeviceRequest {
             grab_window,
@@ -2466,6 +2554,7 @@ impl<'input> GrabDeviceRequest<'input> {
 }
 impl<'input> Request for GrabDeviceRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2489,20 +2578,19 @@ pub struct GrabDeviceReply {
 impl TryParse for GrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = GrabDeviceReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2600,9 +2688,9 @@ impl UngrabDeviceRequest {
         if header.minor_opcode != UNGRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (time, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(UngrabDeviceRequest {
             time,
@@ -2612,6 +2700,7 @@ impl UngrabDeviceRequest {
 }
 impl Request for UngrabDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2746,20 +2835,20 @@ impl<'input> GrabDeviceKeyRequest<'input> {
         if header.minor_opcode != GRAB_DEVICE_KEY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let (modifier_device, remaining) = u8::try_parse(remaining)?;
-        let (grabbed_device, remaining) = u8::try_parse(remaining)?;
-        let (key, remaining) = u8::try_parse(remaining)?;
-        let (this_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (modifier_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "modifier_device")?;
+        let (grabbed_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grabbed_device")?;
+        let (key, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "key")?;
+        let (this_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "this_device_mode")?;
         let this_device_mode = this_device_mode.into();
-        let (other_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (other_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "other_device_mode")?;
         let other_device_mode = other_device_mode.into();
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(GrabDeviceKeyRequest {
             grab_window,
@@ -2790,6 +2879,7 @@ impl<'input> GrabDeviceKeyRequest<'input> {
 }
 impl<'input> Request for GrabDeviceKeyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2850,12 +2940,12 @@ impl UngrabDeviceKeyRequest {
         if header.minor_opcode != UNGRAB_DEVICE_KEY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let (modifier_device, remaining) = u8::try_parse(remaining)?;
-        let (key, remaining) = u8::try_parse(remaining)?;
-        let (grabbed_device, remaining) = u8::try_parse(remaining)?;
+        let (modifier_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "modifier_device")?;
+        let (key, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "key")?;
+        let (grabbed_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grabbed_device")?;
         let _ = remaining;
         Ok(UngrabDeviceKeyRequest {
             grab_window,
@@ -2868,6 +2958,7 @@ impl UngrabDeviceKeyRequest {
 }
 impl Request for UngrabDeviceKeyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2945,20 +3036,20 @@ impl<'input> GrabDeviceButtonRequest<'input> {
         if header.minor_opcode != GRAB_DEVICE_BUTTON_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (grabbed_device, remaining) = u8::try_parse(remaining)?;
-        let (modifier_device, remaining) = u8::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (grabbed_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grabbed_device")?;
+        let (modifier_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "modifier_device")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let (this_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (this_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "this_device_mode")?;
         let this_device_mode = this_device_mode.into();
-        let (other_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (other_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "other_device_mode")?;
         let other_device_mode = other_device_mode.into();
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(GrabDeviceButtonRequest {
             grab_window,
@@ -2989,6 +3080,7 @@ impl<'input> GrabDeviceButtonRequest<'input> {
 }
 impl<'input> Request for GrabDeviceButtonRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3049,13 +3141,13 @@ impl UngrabDeviceButtonRequest {
         if header.minor_opcode != UNGRAB_DEVICE_BUTTON_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let (modifier_device, remaining) = u8::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (grabbed_device, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (modifier_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "modifier_device")?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let (grabbed_device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grabbed_device")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(UngrabDeviceButtonRequest {
             grab_window,
@@ -3068,6 +3160,7 @@ impl UngrabDeviceButtonRequest {
 }
 impl Request for UngrabDeviceButtonRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3187,11 +3280,11 @@ impl AllowDeviceEventsRequest {
         if header.minor_opcode != ALLOW_DEVICE_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (time, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "time")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(AllowDeviceEventsRequest {
             time,
@@ -3202,6 +3295,7 @@ impl AllowDeviceEventsRequest {
 }
 impl Request for AllowDeviceEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3246,8 +3340,8 @@ impl GetDeviceFocusRequest {
         if header.minor_opcode != GET_DEVICE_FOCUS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetDeviceFocusRequest {
             device_id,
@@ -3256,6 +3350,7 @@ impl GetDeviceFocusRequest {
 }
 impl Request for GetDeviceFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3281,22 +3376,21 @@ pub struct GetDeviceFocusReply {
 impl TryParse for GetDeviceFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (focus, remaining) = xproto::Window::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (revert_to, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (focus, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "focus")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (revert_to, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "revert_to")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let revert_to = revert_to.into();
         let result = GetDeviceFocusReply { xi_reply_type, sequence, length, focus, time, revert_to };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3406,12 +3500,12 @@ impl SetDeviceFocusRequest {
         if header.minor_opcode != SET_DEVICE_FOCUS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (focus, remaining) = xproto::Window::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (revert_to, remaining) = u8::try_parse(remaining)?;
+        let (focus, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "focus")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (revert_to, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "revert_to")?;
         let revert_to = revert_to.into();
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(SetDeviceFocusRequest {
             focus,
@@ -3423,6 +3517,7 @@ impl SetDeviceFocusRequest {
 }
 impl Request for SetDeviceFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3518,17 +3613,17 @@ pub struct KbdFeedbackState {
 }
 impl TryParse for KbdFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let (global_auto_repeat, remaining) = bool::try_parse(remaining)?;
-        let (click, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "duration")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
+        let (global_auto_repeat, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "global_auto_repeat")?;
+        let (click, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "click")?;
+        let (percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let class_id = class_id.into();
         let result = KbdFeedbackState { class_id, feedback_id, len, pitch, duration, led_mask, led_values, global_auto_repeat, click, percent, auto_repeats };
@@ -3632,13 +3727,13 @@ pub struct PtrFeedbackState {
 }
 impl TryParse for PtrFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (accel_num, remaining) = u16::try_parse(remaining)?;
-        let (accel_denom, remaining) = u16::try_parse(remaining)?;
-        let (threshold, remaining) = u16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (accel_num, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "accel_num")?;
+        let (accel_denom, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "accel_denom")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "threshold")?;
         let class_id = class_id.into();
         let result = PtrFeedbackState { class_id, feedback_id, len, accel_num, accel_denom, threshold };
         Ok((result, remaining))
@@ -3692,12 +3787,12 @@ pub struct IntegerFeedbackState {
 }
 impl TryParse for IntegerFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (min_value, remaining) = i32::try_parse(remaining)?;
-        let (max_value, remaining) = i32::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (resolution, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resolution")?;
+        let (min_value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_value")?;
+        let (max_value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_value")?;
         let class_id = class_id.into();
         let result = IntegerFeedbackState { class_id, feedback_id, len, resolution, min_value, max_value };
         Ok((result, remaining))
@@ -3753,12 +3848,12 @@ pub struct StringFeedbackState {
 }
 impl TryParse for StringFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (max_symbols, remaining) = u16::try_parse(remaining)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (max_symbols, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_symbols")?;
+        let (num_keysyms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keysyms")?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?, Some("keysyms"))?;
         let class_id = class_id.into();
         let result = StringFeedbackState { class_id, feedback_id, len, max_symbols, keysyms };
         Ok((result, remaining))
@@ -3810,13 +3905,13 @@ pub struct BellFeedbackState {
 }
 impl TryParse for BellFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "duration")?;
         let class_id = class_id.into();
         let result = BellFeedbackState { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
@@ -3869,11 +3964,11 @@ pub struct LedFeedbackState {
 }
 impl TryParse for LedFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let class_id = class_id.into();
         let result = LedFeedbackState { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
@@ -3926,14 +4021,14 @@ pub struct FeedbackStateDataKeyboard {
 }
 impl TryParse for FeedbackStateDataKeyboard {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
-        let (global_auto_repeat, remaining) = bool::try_parse(remaining)?;
-        let (click, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "duration")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
+        let (global_auto_repeat, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "global_auto_repeat")?;
+        let (click, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "click")?;
+        let (percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let result = FeedbackStateDataKeyboard { pitch, duration, led_mask, led_values, global_auto_repeat, click, percent, auto_repeats };
         Ok((result, remaining))
@@ -4022,10 +4117,10 @@ pub struct FeedbackStateDataPointer {
 }
 impl TryParse for FeedbackStateDataPointer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (accel_num, remaining) = u16::try_parse(remaining)?;
-        let (accel_denom, remaining) = u16::try_parse(remaining)?;
-        let (threshold, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (accel_num, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "accel_num")?;
+        let (accel_denom, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "accel_denom")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "threshold")?;
         let result = FeedbackStateDataPointer { accel_num, accel_denom, threshold };
         Ok((result, remaining))
     }
@@ -4063,9 +4158,9 @@ pub struct FeedbackStateDataString {
 }
 impl TryParse for FeedbackStateDataString {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (max_symbols, remaining) = u16::try_parse(remaining)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
+        let (max_symbols, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_symbols")?;
+        let (num_keysyms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keysyms")?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?, Some("keysyms"))?;
         let result = FeedbackStateDataString { max_symbols, keysyms };
         Ok((result, remaining))
     }
@@ -4109,9 +4204,9 @@ pub struct FeedbackStateDataInteger {
 }
 impl TryParse for FeedbackStateDataInteger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (min_value, remaining) = i32::try_parse(remaining)?;
-        let (max_value, remaining) = i32::try_parse(remaining)?;
+        let (resolution, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resolution")?;
+        let (min_value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_value")?;
+        let (max_value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_value")?;
         let result = FeedbackStateDataInteger { resolution, min_value, max_value };
         Ok((result, remaining))
     }
@@ -4152,8 +4247,8 @@ pub struct FeedbackStateDataLed {
 }
 impl TryParse for FeedbackStateDataLed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let result = FeedbackStateDataLed { led_mask, led_values };
         Ok((result, remaining))
     }
@@ -4189,10 +4284,10 @@ pub struct FeedbackStateDataBell {
 }
 impl TryParse for FeedbackStateDataBell {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
+        let (percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "duration")?;
         let result = FeedbackStateDataBell { percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -4369,9 +4464,9 @@ pub struct FeedbackState {
 }
 impl TryParse for FeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
         let (data, remaining) = FeedbackStateData::try_parse(remaining, u8::from(class_id))?;
         let result = FeedbackState { feedback_id, len, data };
         Ok((result, remaining))
@@ -4427,8 +4522,8 @@ impl GetFeedbackControlRequest {
         if header.minor_opcode != GET_FEEDBACK_CONTROL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetFeedbackControlRequest {
             device_id,
@@ -4437,6 +4532,7 @@ impl GetFeedbackControlRequest {
 }
 impl Request for GetFeedbackControlRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -4460,20 +4556,19 @@ pub struct GetFeedbackControlReply {
 impl TryParse for GetFeedbackControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_feedbacks, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (feedbacks, remaining) = crate::x11_utils::parse_list::<FeedbackState>(remaining, num_feedbacks.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_feedbacks, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_feedbacks")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (feedbacks, remaining) = crate::x11_utils::parse_list::<FeedbackState>(remaining, num_feedbacks.try_to_usize()?, Some("feedbacks"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetFeedbackControlReply { xi_reply_type, sequence, length, feedbacks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -4512,6 +4607,27 @@ impl GetFeedbackControlReply {
             .try_into().unwrap()
     }
 }
+impl GetFeedbackControlReply {
+    /// Lazily parse the `feedbacks` list from the raw bytes of a `GetFeedbackControlReply`.
+    ///
+    /// In contrast to parsing the whole `GetFeedbackControlReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn feedbacks_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, FeedbackState>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_feedbacks, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_feedbacks")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_feedbacks.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4530,17 +4646,17 @@ pub struct KbdFeedbackCtl {
 }
 impl TryParse for KbdFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (key, remaining) = KeyCode::try_parse(remaining)?;
-        let (auto_repeat_mode, remaining) = u8::try_parse(remaining)?;
-        let (key_click_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_pitch, remaining) = i16::try_parse(remaining)?;
-        let (bell_duration, remaining) = i16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (key, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "key")?;
+        let (auto_repeat_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "auto_repeat_mode")?;
+        let (key_click_percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "key_click_percent")?;
+        let (bell_percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "bell_percent")?;
+        let (bell_pitch, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bell_pitch")?;
+        let (bell_duration, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bell_duration")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let class_id = class_id.into();
         let result = KbdFeedbackCtl { class_id, feedback_id, len, key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values };
         Ok((result, remaining))
@@ -4611,13 +4727,13 @@ pub struct PtrFeedbackCtl {
 }
 impl TryParse for PtrFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num, remaining) = i16::try_parse(remaining)?;
-        let (denom, remaining) = i16::try_parse(remaining)?;
-        let (threshold, remaining) = i16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "num")?;
+        let (denom, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "denom")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "threshold")?;
         let class_id = class_id.into();
         let result = PtrFeedbackCtl { class_id, feedback_id, len, num, denom, threshold };
         Ok((result, remaining))
@@ -4669,10 +4785,10 @@ pub struct IntegerFeedbackCtl {
 }
 impl TryParse for IntegerFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (int_to_display, remaining) = i32::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (int_to_display, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "int_to_display")?;
         let class_id = class_id.into();
         let result = IntegerFeedbackCtl { class_id, feedback_id, len, int_to_display };
         Ok((result, remaining))
@@ -4715,12 +4831,12 @@ pub struct StringFeedbackCtl {
 }
 impl TryParse for StringFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num_keysyms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keysyms")?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?, Some("keysyms"))?;
         let class_id = class_id.into();
         let result = StringFeedbackCtl { class_id, feedback_id, len, keysyms };
         Ok((result, remaining))
@@ -4772,13 +4888,13 @@ pub struct BellFeedbackCtl {
 }
 impl TryParse for BellFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (percent, remaining) = i8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "duration")?;
         let class_id = class_id.into();
         let result = BellFeedbackCtl { class_id, feedback_id, len, percent, pitch, duration };
         Ok((result, remaining))
@@ -4831,11 +4947,11 @@ pub struct LedFeedbackCtl {
 }
 impl TryParse for LedFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let class_id = class_id.into();
         let result = LedFeedbackCtl { class_id, feedback_id, len, led_mask, led_values };
         Ok((result, remaining))
@@ -4888,14 +5004,14 @@ pub struct FeedbackCtlDataKeyboard {
 }
 impl TryParse for FeedbackCtlDataKeyboard {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (key, remaining) = KeyCode::try_parse(remaining)?;
-        let (auto_repeat_mode, remaining) = u8::try_parse(remaining)?;
-        let (key_click_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_percent, remaining) = i8::try_parse(remaining)?;
-        let (bell_pitch, remaining) = i16::try_parse(remaining)?;
-        let (bell_duration, remaining) = i16::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (key, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "key")?;
+        let (auto_repeat_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "auto_repeat_mode")?;
+        let (key_click_percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "key_click_percent")?;
+        let (bell_percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "bell_percent")?;
+        let (bell_pitch, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bell_pitch")?;
+        let (bell_duration, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "bell_duration")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let result = FeedbackCtlDataKeyboard { key, auto_repeat_mode, key_click_percent, bell_percent, bell_pitch, bell_duration, led_mask, led_values };
         Ok((result, remaining))
     }
@@ -4951,10 +5067,10 @@ pub struct FeedbackCtlDataPointer {
 }
 impl TryParse for FeedbackCtlDataPointer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num, remaining) = i16::try_parse(remaining)?;
-        let (denom, remaining) = i16::try_parse(remaining)?;
-        let (threshold, remaining) = i16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "num")?;
+        let (denom, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "denom")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "threshold")?;
         let result = FeedbackCtlDataPointer { num, denom, threshold };
         Ok((result, remaining))
     }
@@ -4991,9 +5107,9 @@ pub struct FeedbackCtlDataString {
 }
 impl TryParse for FeedbackCtlDataString {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (num_keysyms, remaining) = u16::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (num_keysyms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keysyms")?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, num_keysyms.try_to_usize()?, Some("keysyms"))?;
         let result = FeedbackCtlDataString { keysyms };
         Ok((result, remaining))
     }
@@ -5035,7 +5151,7 @@ pub struct FeedbackCtlDataInteger {
 }
 impl TryParse for FeedbackCtlDataInteger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (int_to_display, remaining) = i32::try_parse(remaining)?;
+        let (int_to_display, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "int_to_display")?;
         let result = FeedbackCtlDataInteger { int_to_display };
         Ok((result, remaining))
     }
@@ -5064,8 +5180,8 @@ pub struct FeedbackCtlDataLed {
 }
 impl TryParse for FeedbackCtlDataLed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (led_values, remaining) = u32::try_parse(remaining)?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (led_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_values")?;
         let result = FeedbackCtlDataLed { led_mask, led_values };
         Ok((result, remaining))
     }
@@ -5101,10 +5217,10 @@ pub struct FeedbackCtlDataBell {
 }
 impl TryParse for FeedbackCtlDataBell {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (percent, remaining) = i8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
+        let (percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "percent")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "duration")?;
         let result = FeedbackCtlDataBell { percent, pitch, duration };
         Ok((result, remaining))
     }
@@ -5281,9 +5397,9 @@ pub struct FeedbackCtl {
 }
 impl TryParse for FeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
         let (data, remaining) = FeedbackCtlData::try_parse(remaining, u8::from(class_id))?;
         let result = FeedbackCtl { feedback_id, len, data };
         Ok((result, remaining))
@@ -5374,7 +5490,7 @@ impl core::fmt::Debug for ChangeFeedbackControlMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ChangeFeedbackControlMask, u32);
+bitmask_binop!(ChangeFeedbackControlMask, u32, [KEY_CLICK_PERCENT, PERCENT, PITCH, DURATION, LED, LED_MODE, KEY, AUTO_REPEAT_MODE, STRING, INTEGER, ACCEL_NUM, ACCEL_DENOM, THRESHOLD]);
 
 /// Opcode for the ChangeFeedbackControl request
 pub const CHANGE_FEEDBACK_CONTROL_REQUEST: u8 = 23;
@@ -5422,12 +5538,12 @@ impl ChangeFeedbackControlRequest {
         if header.minor_opcode != CHANGE_FEEDBACK_CONTROL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (mask, remaining) = u32::try_parse(value)?;
+        let (mask, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "mask")?;
         let mask = mask.into();
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (feedback, remaining) = FeedbackCtl::try_parse(remaining)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (feedback, remaining) = crate::x11_utils::in_field(FeedbackCtl::try_parse(remaining), "feedback")?;
         let _ = remaining;
         Ok(ChangeFeedbackControlRequest {
             mask,
@@ -5439,6 +5555,7 @@ impl ChangeFeedbackControlRequest {
 }
 impl Request for ChangeFeedbackControlRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5487,10 +5604,10 @@ impl GetDeviceKeyMappingRequest {
         if header.minor_opcode != GET_DEVICE_KEY_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (first_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "first_keycode")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         Ok(GetDeviceKeyMappingRequest {
             device_id,
@@ -5501,6 +5618,7 @@ impl GetDeviceKeyMappingRequest {
 }
 impl Request for GetDeviceKeyMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5524,20 +5642,19 @@ pub struct GetDeviceKeyMappingReply {
 impl TryParse for GetDeviceKeyMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, length.try_to_usize()?, Some("keysyms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceKeyMappingReply { xi_reply_type, sequence, keysyms_per_keycode, keysyms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5576,6 +5693,27 @@ impl GetDeviceKeyMappingReply {
             .try_into().unwrap()
     }
 }
+impl GetDeviceKeyMappingReply {
+    /// Lazily parse the `keysyms` list from the raw bytes of a `GetDeviceKeyMappingReply`.
+    ///
+    /// In contrast to parsing the whole `GetDeviceKeyMappingReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn keysyms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Keysym>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ChangeDeviceKeyMapping request
 pub const CHANGE_DEVICE_KEY_MAPPING_REQUEST: u8 = 25;
@@ -5622,11 +5760,11 @@ impl<'input> ChangeDeviceKeyMappingRequest<'input> {
         if header.minor_opcode != CHANGE_DEVICE_KEY_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (first_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
-        let (keycode_count, remaining) = u8::try_parse(remaining)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "first_keycode")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let (keycode_count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycode_count")?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("keysyms"))?;
         let _ = remaining;
         Ok(ChangeDeviceKeyMappingRequest {
             device_id,
@@ -5649,6 +5787,7 @@ impl<'input> ChangeDeviceKeyMappingRequest<'input> {
 }
 impl<'input> Request for ChangeDeviceKeyMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5693,8 +5832,8 @@ impl GetDeviceModifierMappingRequest {
         if header.minor_opcode != GET_DEVICE_MODIFIER_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetDeviceModifierMappingRequest {
             device_id,
@@ -5703,6 +5842,7 @@ impl GetDeviceModifierMappingRequest {
 }
 impl Request for GetDeviceModifierMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5726,21 +5866,20 @@ pub struct GetDeviceModifierMappingReply {
 impl TryParse for GetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (keymaps, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (keycodes_per_modifier, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycodes_per_modifier")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (keymaps, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "keymaps")?;
         let keymaps = keymaps.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceModifierMappingReply { xi_reply_type, sequence, length, keymaps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5822,10 +5961,10 @@ impl<'input> SetDeviceModifierMappingRequest<'input> {
         if header.minor_opcode != SET_DEVICE_MODIFIER_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (keymaps, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (keycodes_per_modifier, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycodes_per_modifier")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (keymaps, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "keymaps")?;
         let _ = remaining;
         Ok(SetDeviceModifierMappingRequest {
             device_id,
@@ -5842,6 +5981,7 @@ impl<'input> SetDeviceModifierMappingRequest<'input> {
 }
 impl<'input> Request for SetDeviceModifierMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5865,20 +6005,19 @@ pub struct SetDeviceModifierMappingReply {
 impl TryParse for SetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetDeviceModifierMappingReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5970,8 +6109,8 @@ impl GetDeviceButtonMappingRequest {
         if header.minor_opcode != GET_DEVICE_BUTTON_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GetDeviceButtonMappingRequest {
             device_id,
@@ -5980,6 +6119,7 @@ impl GetDeviceButtonMappingRequest {
 }
 impl Request for GetDeviceButtonMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6004,25 +6144,24 @@ impl TryParse for GetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (map_size, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (map_size, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (map, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?), "map")?;
         let map = map.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceButtonMappingReply { xi_reply_type, sequence, length, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6102,10 +6241,10 @@ impl<'input> SetDeviceButtonMappingRequest<'input> {
         if header.minor_opcode != SET_DEVICE_BUTTON_MAPPING_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (map_size, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (map_size, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (map, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, map_size.try_to_usize()?), "map")?;
         let _ = remaining;
         Ok(SetDeviceButtonMappingRequest {
             device_id,
@@ -6122,6 +6261,7 @@ impl<'input> SetDeviceButtonMappingRequest<'input> {
 }
 impl<'input> Request for SetDeviceButtonMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6145,20 +6285,19 @@ pub struct SetDeviceButtonMappingReply {
 impl TryParse for SetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetDeviceButtonMappingReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6227,10 +6366,10 @@ pub struct KeyState {
 }
 impl TryParse for KeyState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let class_id = class_id.into();
         let result = KeyState { class_id, len, num_keys, keys };
@@ -6302,10 +6441,10 @@ pub struct ButtonState {
 }
 impl TryParse for ButtonState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_buttons")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let class_id = class_id.into();
         let result = ButtonState { class_id, len, num_buttons, buttons };
@@ -6425,7 +6564,7 @@ impl core::fmt::Debug for ValuatorStateModeMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ValuatorStateModeMask, u8);
+bitmask_binop!(ValuatorStateModeMask, u8, [DEVICE_MODE_ABSOLUTE, OUT_OF_PROXIMITY]);
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6437,11 +6576,11 @@ pub struct ValuatorState {
 }
 impl TryParse for ValuatorState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?, Some("valuators"))?;
         let class_id = class_id.into();
         let mode = mode.into();
         let result = ValuatorState { class_id, len, mode, valuators };
@@ -6489,8 +6628,8 @@ pub struct InputStateDataKey {
 }
 impl TryParse for InputStateDataKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let result = InputStateDataKey { num_keys, keys };
         Ok((result, remaining))
@@ -6552,8 +6691,8 @@ pub struct InputStateDataButton {
 }
 impl TryParse for InputStateDataButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_buttons")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         let result = InputStateDataButton { num_buttons, buttons };
         Ok((result, remaining))
@@ -6615,9 +6754,9 @@ pub struct InputStateDataValuator {
 }
 impl TryParse for InputStateDataValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?)?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?, Some("valuators"))?;
         let mode = mode.into();
         let result = InputStateDataValuator { mode, valuators };
         Ok((result, remaining))
@@ -6754,8 +6893,8 @@ pub struct InputState {
 }
 impl TryParse for InputState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (class_id, remaining) = u8::try_parse(remaining)?;
-        let (len, remaining) = u8::try_parse(remaining)?;
+        let (class_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "len")?;
         let (data, remaining) = InputStateData::try_parse(remaining, u8::from(class_id))?;
         let result = InputState { len, data };
         Ok((result, remaining))
@@ -6810,8 +6949,8 @@ impl QueryDeviceStateRequest {
         if header.minor_opcode != QUERY_DEVICE_STATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(QueryDeviceStateRequest {
             device_id,
@@ -6820,6 +6959,7 @@ impl QueryDeviceStateRequest {
 }
 impl Request for QueryDeviceStateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6843,20 +6983,19 @@ pub struct QueryDeviceStateReply {
 impl TryParse for QueryDeviceStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_classes, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<InputState>(remaining, num_classes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<InputState>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryDeviceStateReply { xi_reply_type, sequence, length, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6895,6 +7034,27 @@ impl QueryDeviceStateReply {
             .try_into().unwrap()
     }
 }
+impl QueryDeviceStateReply {
+    /// Lazily parse the `classes` list from the raw bytes of a `QueryDeviceStateReply`.
+    ///
+    /// In contrast to parsing the whole `QueryDeviceStateReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn classes_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, InputState>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_classes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_classes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the DeviceBell request
 pub const DEVICE_BELL_REQUEST: u8 = 32;
@@ -6935,10 +7095,10 @@ impl DeviceBellRequest {
         if header.minor_opcode != DEVICE_BELL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (feedback_id, remaining) = u8::try_parse(remaining)?;
-        let (feedback_class, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = i8::try_parse(remaining)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (feedback_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_id")?;
+        let (feedback_class, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "feedback_class")?;
+        let (percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "percent")?;
         let _ = remaining;
         Ok(DeviceBellRequest {
             device_id,
@@ -6950,6 +7110,7 @@ impl DeviceBellRequest {
 }
 impl Request for DeviceBellRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7003,11 +7164,11 @@ impl<'input> SetDeviceValuatorsRequest<'input> {
         if header.minor_opcode != SET_DEVICE_VALUATORS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let (first_valuator, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_valuator")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (valuators, remaining) = crate::x11_utils::parse_list::<i32>(remaining, num_valuators.try_to_usize()?, Some("valuators"))?;
         let _ = remaining;
         Ok(SetDeviceValuatorsRequest {
             device_id,
@@ -7026,6 +7187,7 @@ impl<'input> SetDeviceValuatorsRequest<'input> {
 }
 impl<'input> Request for SetDeviceValuatorsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7049,20 +7211,19 @@ pub struct SetDeviceValuatorsReply {
 impl TryParse for SetDeviceValuatorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetDeviceValuatorsReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7191,12 +7352,12 @@ pub struct DeviceResolutionState {
 }
 impl TryParse for DeviceResolutionState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (num_valuators, remaining) = u32::try_parse(remaining)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
-        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
-        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_valuators")?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_values"))?;
+        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_min"))?;
+        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_max"))?;
         let control_id = control_id.into();
         let result = DeviceResolutionState { control_id, len, resolution_values, resolution_min, resolution_max };
         Ok((result, remaining))
@@ -7254,16 +7415,16 @@ pub struct DeviceAbsCalibState {
 }
 impl TryParse for DeviceAbsCalibState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (min_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_x")?;
+        let (max_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_x")?;
+        let (min_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_y")?;
+        let (max_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_y")?;
+        let (flip_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_x")?;
+        let (flip_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_y")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rotation")?;
+        let (button_threshold, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "button_threshold")?;
         let control_id = control_id.into();
         let result = DeviceAbsCalibState { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
@@ -7350,14 +7511,14 @@ pub struct DeviceAbsAreaState {
 }
 impl TryParse for DeviceAbsAreaState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (offset_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_x")?;
+        let (offset_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
+        let (following, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "following")?;
         let control_id = control_id.into();
         let result = DeviceAbsAreaState { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
@@ -7428,11 +7589,11 @@ pub struct DeviceCoreState {
 }
 impl TryParse for DeviceCoreState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (iscore, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (iscore, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "iscore")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let control_id = control_id.into();
         let result = DeviceCoreState { control_id, len, status, iscore };
         Ok((result, remaining))
@@ -7475,10 +7636,10 @@ pub struct DeviceEnableState {
 }
 impl TryParse for DeviceEnableState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (enable, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (enable, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "enable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let control_id = control_id.into();
         let result = DeviceEnableState { control_id, len, enable };
         Ok((result, remaining))
@@ -7519,10 +7680,10 @@ pub struct DeviceStateDataResolution {
 }
 impl TryParse for DeviceStateDataResolution {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_valuators, remaining) = u32::try_parse(remaining)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
-        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
-        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_valuators")?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_values"))?;
+        let (resolution_min, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_min"))?;
+        let (resolution_max, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_max"))?;
         let result = DeviceStateDataResolution { resolution_values, resolution_min, resolution_max };
         Ok((result, remaining))
     }
@@ -7573,14 +7734,14 @@ pub struct DeviceStateDataAbsCalib {
 }
 impl TryParse for DeviceStateDataAbsCalib {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+        let (min_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_x")?;
+        let (max_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_x")?;
+        let (min_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_y")?;
+        let (max_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_y")?;
+        let (flip_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_x")?;
+        let (flip_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_y")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rotation")?;
+        let (button_threshold, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "button_threshold")?;
         let result = DeviceStateDataAbsCalib { min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -7651,9 +7812,9 @@ pub struct DeviceStateDataCore {
 }
 impl TryParse for DeviceStateDataCore {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (iscore, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (iscore, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "iscore")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = DeviceStateDataCore { status, iscore };
         Ok((result, remaining))
     }
@@ -7689,12 +7850,12 @@ pub struct DeviceStateDataAbsArea {
 }
 impl TryParse for DeviceStateDataAbsArea {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u32::try_parse(remaining)?;
-        let (height, remaining) = u32::try_parse(remaining)?;
-        let (screen, remaining) = u32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+        let (offset_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_x")?;
+        let (offset_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
+        let (screen, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "screen")?;
+        let (following, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "following")?;
         let result = DeviceStateDataAbsArea { offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -7788,8 +7949,8 @@ impl DeviceStateData {
         }
         if switch_expr == u16::from(DeviceControl::ENABLE) {
             let remaining = outer_remaining;
-            let (enable, remaining) = u8::try_parse(remaining)?;
-            let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+            let (enable, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "enable")?;
+            let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceStateData::Enable(enable));
@@ -7882,8 +8043,8 @@ pub struct DeviceState {
 }
 impl TryParse for DeviceState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
         let (data, remaining) = DeviceStateData::try_parse(remaining, u16::from(control_id))?;
         let result = DeviceState { len, data };
         Ok((result, remaining))
@@ -7940,10 +8101,10 @@ impl GetDeviceControlRequest {
         if header.minor_opcode != GET_DEVICE_CONTROL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (control_id, remaining) = u16::try_parse(value)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "control_id")?;
         let control_id = control_id.into();
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         Ok(GetDeviceControlRequest {
             control_id,
@@ -7953,6 +8114,7 @@ impl GetDeviceControlRequest {
 }
 impl Request for GetDeviceControlRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7977,20 +8139,19 @@ pub struct GetDeviceControlReply {
 impl TryParse for GetDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        let (control, remaining) = DeviceState::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
+        let (control, remaining) = crate::x11_utils::in_field(DeviceState::try_parse(remaining), "control")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceControlReply { xi_reply_type, sequence, length, status, control };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8024,12 +8185,12 @@ pub struct DeviceResolutionCtl {
 }
 impl TryParse for DeviceResolutionCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (first_valuator, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_valuator")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_values"))?;
         let control_id = control_id.into();
         let result = DeviceResolutionCtl { control_id, len, first_valuator, resolution_values };
         Ok((result, remaining))
@@ -8085,16 +8246,16 @@ pub struct DeviceAbsCalibCtl {
 }
 impl TryParse for DeviceAbsCalibCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (min_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_x")?;
+        let (max_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_x")?;
+        let (min_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_y")?;
+        let (max_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_y")?;
+        let (flip_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_x")?;
+        let (flip_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_y")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rotation")?;
+        let (button_threshold, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "button_threshold")?;
         let control_id = control_id.into();
         let result = DeviceAbsCalibCtl { control_id, len, min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
@@ -8181,14 +8342,14 @@ pub struct DeviceAbsAreaCtrl {
 }
 impl TryParse for DeviceAbsAreaCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (screen, remaining) = i32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (offset_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_x")?;
+        let (offset_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "height")?;
+        let (screen, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "screen")?;
+        let (following, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "following")?;
         let control_id = control_id.into();
         let result = DeviceAbsAreaCtrl { control_id, len, offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
@@ -8258,10 +8419,10 @@ pub struct DeviceCoreCtrl {
 }
 impl TryParse for DeviceCoreCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let control_id = control_id.into();
         let result = DeviceCoreCtrl { control_id, len, status };
         Ok((result, remaining))
@@ -8302,10 +8463,10 @@ pub struct DeviceEnableCtrl {
 }
 impl TryParse for DeviceEnableCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (enable, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (enable, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "enable")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let control_id = control_id.into();
         let result = DeviceEnableCtrl { control_id, len, enable };
         Ok((result, remaining))
@@ -8345,10 +8506,10 @@ pub struct DeviceCtlDataResolution {
 }
 impl TryParse for DeviceCtlDataResolution {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?)?;
+        let (first_valuator, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_valuator")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (resolution_values, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_valuators.try_to_usize()?, Some("resolution_values"))?;
         let result = DeviceCtlDataResolution { first_valuator, resolution_values };
         Ok((result, remaining))
     }
@@ -8398,14 +8559,14 @@ pub struct DeviceCtlDataAbsCalib {
 }
 impl TryParse for DeviceCtlDataAbsCalib {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (min_x, remaining) = i32::try_parse(remaining)?;
-        let (max_x, remaining) = i32::try_parse(remaining)?;
-        let (min_y, remaining) = i32::try_parse(remaining)?;
-        let (max_y, remaining) = i32::try_parse(remaining)?;
-        let (flip_x, remaining) = u32::try_parse(remaining)?;
-        let (flip_y, remaining) = u32::try_parse(remaining)?;
-        let (rotation, remaining) = u32::try_parse(remaining)?;
-        let (button_threshold, remaining) = u32::try_parse(remaining)?;
+        let (min_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_x")?;
+        let (max_x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_x")?;
+        let (min_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min_y")?;
+        let (max_y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max_y")?;
+        let (flip_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_x")?;
+        let (flip_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flip_y")?;
+        let (rotation, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "rotation")?;
+        let (button_threshold, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "button_threshold")?;
         let result = DeviceCtlDataAbsCalib { min_x, max_x, min_y, max_y, flip_x, flip_y, rotation, button_threshold };
         Ok((result, remaining))
     }
@@ -8475,8 +8636,8 @@ pub struct DeviceCtlDataCore {
 }
 impl TryParse for DeviceCtlDataCore {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = DeviceCtlDataCore { status };
         Ok((result, remaining))
     }
@@ -8510,12 +8671,12 @@ pub struct DeviceCtlDataAbsArea {
 }
 impl TryParse for DeviceCtlDataAbsArea {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (offset_x, remaining) = u32::try_parse(remaining)?;
-        let (offset_y, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = i32::try_parse(remaining)?;
-        let (height, remaining) = i32::try_parse(remaining)?;
-        let (screen, remaining) = i32::try_parse(remaining)?;
-        let (following, remaining) = u32::try_parse(remaining)?;
+        let (offset_x, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_x")?;
+        let (offset_y, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "height")?;
+        let (screen, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "screen")?;
+        let (following, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "following")?;
         let result = DeviceCtlDataAbsArea { offset_x, offset_y, width, height, screen, following };
         Ok((result, remaining))
     }
@@ -8609,8 +8770,8 @@ impl DeviceCtlData {
         }
         if switch_expr == u16::from(DeviceControl::ENABLE) {
             let remaining = outer_remaining;
-            let (enable, remaining) = u8::try_parse(remaining)?;
-            let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+            let (enable, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "enable")?;
+            let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(DeviceCtlData::Enable(enable));
@@ -8703,8 +8864,8 @@ pub struct DeviceCtl {
 }
 impl TryParse for DeviceCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (control_id, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control_id")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
         let (data, remaining) = DeviceCtlData::try_parse(remaining, u16::from(control_id))?;
         let result = DeviceCtl { len, data };
         Ok((result, remaining))
@@ -8766,11 +8927,11 @@ impl ChangeDeviceControlRequest {
         if header.minor_opcode != CHANGE_DEVICE_CONTROL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (control_id, remaining) = u16::try_parse(value)?;
+        let (control_id, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "control_id")?;
         let control_id = control_id.into();
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (control, remaining) = DeviceCtl::try_parse(remaining)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (control, remaining) = crate::x11_utils::in_field(DeviceCtl::try_parse(remaining), "control")?;
         let _ = remaining;
         Ok(ChangeDeviceControlRequest {
             control_id,
@@ -8781,6 +8942,7 @@ impl ChangeDeviceControlRequest {
 }
 impl Request for ChangeDeviceControlRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8804,19 +8966,18 @@ pub struct ChangeDeviceControlReply {
 impl TryParse for ChangeDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ChangeDeviceControlReply { xi_reply_type, sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8908,8 +9069,8 @@ impl ListDevicePropertiesRequest {
         if header.minor_opcode != LIST_DEVICE_PROPERTIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_id, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(ListDevicePropertiesRequest {
             device_id,
@@ -8918,6 +9079,7 @@ impl ListDevicePropertiesRequest {
 }
 impl Request for ListDevicePropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8941,20 +9103,19 @@ pub struct ListDevicePropertiesReply {
 impl TryParse for ListDevicePropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_atoms, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_atoms.try_to_usize()?, Some("atoms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListDevicePropertiesReply { xi_reply_type, sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8993,6 +9154,27 @@ impl ListDevicePropertiesReply {
             .try_into().unwrap()
     }
 }
+impl ListDevicePropertiesReply {
+    /// Lazily parse the `atoms` list from the raw bytes of a `ListDevicePropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `ListDevicePropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn atoms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Atom>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_atoms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_atoms")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_atoms.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9079,12 +9261,12 @@ impl ChangeDevicePropertyAux {
         if switch_expr == u8::from(PropertyFormat::M8_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
+            let (data8, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?), "data8")?;
             let data8 = data8.to_vec();
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(ChangeDevicePropertyAux::Data8(data8));
@@ -9092,18 +9274,18 @@ impl ChangeDevicePropertyAux {
         if switch_expr == u8::from(PropertyFormat::M16_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?, Some("data16"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(ChangeDevicePropertyAux::Data16(data16));
         }
         if switch_expr == u8::from(PropertyFormat::M32_BITS) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?, Some("data32"))?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(ChangeDevicePropertyAux::Data32(data32));
@@ -9233,14 +9415,14 @@ impl<'input> ChangeDevicePropertyRequest<'input> {
         if header.minor_opcode != CHANGE_DEVICE_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (property, remaining) = xproto::Atom::try_parse(value)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(value), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
         let (items, remaining) = ChangeDevicePropertyAux::try_parse(remaining, u8::from(format), u32::from(num_items))?;
         let _ = remaining;
         Ok(ChangeDevicePropertyRequest {
@@ -9266,6 +9448,7 @@ impl<'input> ChangeDevicePropertyRequest<'input> {
 }
 impl<'input> Request for ChangeDevicePropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9316,9 +9499,9 @@ impl DeleteDevicePropertyRequest {
         if header.minor_opcode != DELETE_DEVICE_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (property, remaining) = xproto::Atom::try_parse(value)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(value), "property")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(DeleteDevicePropertyRequest {
             property,
@@ -9328,6 +9511,7 @@ impl DeleteDevicePropertyRequest {
 }
 impl Request for DeleteDevicePropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9398,13 +9582,13 @@ impl GetDevicePropertyRequest {
         if header.minor_opcode != GET_DEVICE_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (property, remaining) = xproto::Atom::try_parse(value)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
-        let (len, remaining) = u32::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (delete, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(value), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
+        let (len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "len")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (delete, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "delete")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetDevicePropertyRequest {
             property,
@@ -9418,6 +9602,7 @@ impl GetDevicePropertyRequest {
 }
 impl Request for GetDevicePropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9454,12 +9639,12 @@ impl GetDevicePropertyItems {
         if switch_expr == u8::from(PropertyFormat::M8_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
+            let (data8, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?), "data8")?;
             let data8 = data8.to_vec();
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data8(data8));
@@ -9467,18 +9652,18 @@ impl GetDevicePropertyItems {
         if switch_expr == u8::from(PropertyFormat::M16_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?, Some("data16"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data16(data16));
         }
         if switch_expr == u8::from(PropertyFormat::M32_BITS) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?, Some("data32"))?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(GetDevicePropertyItems::Data32(data32));
@@ -9563,24 +9748,23 @@ pub struct GetDevicePropertyReply {
 impl TryParse for GetDevicePropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xi_reply_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xi_reply_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xi_reply_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (bytes_after, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_after")?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
         let (items, remaining) = GetDevicePropertyItems::try_parse(remaining, u8::from(format), u32::from(num_items))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDevicePropertyReply { xi_reply_type, sequence, length, type_, bytes_after, num_items, device_id, items };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9690,10 +9874,10 @@ pub struct GroupInfo {
 }
 impl TryParse for GroupInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (base, remaining) = u8::try_parse(remaining)?;
-        let (latched, remaining) = u8::try_parse(remaining)?;
-        let (locked, remaining) = u8::try_parse(remaining)?;
-        let (effective, remaining) = u8::try_parse(remaining)?;
+        let (base, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "base")?;
+        let (latched, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "latched")?;
+        let (locked, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "locked")?;
+        let (effective, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "effective")?;
         let result = GroupInfo { base, latched, locked, effective };
         Ok((result, remaining))
     }
@@ -9731,10 +9915,10 @@ pub struct ModifierInfo {
 }
 impl TryParse for ModifierInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (base, remaining) = u32::try_parse(remaining)?;
-        let (latched, remaining) = u32::try_parse(remaining)?;
-        let (locked, remaining) = u32::try_parse(remaining)?;
-        let (effective, remaining) = u32::try_parse(remaining)?;
+        let (base, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "base")?;
+        let (latched, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "latched")?;
+        let (locked, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "locked")?;
+        let (effective, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "effective")?;
         let result = ModifierInfo { base, latched, locked, effective };
         Ok((result, remaining))
     }
@@ -9813,9 +9997,9 @@ impl XIQueryPointerRequest {
         if header.minor_opcode != XI_QUERY_POINTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIQueryPointerRequest {
             window,
@@ -9825,6 +10009,7 @@ impl XIQueryPointerRequest {
 }
 impl Request for XIQueryPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9856,29 +10041,28 @@ pub struct XIQueryPointerReply {
 impl TryParse for XIQueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (win_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (win_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (win_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "win_x")?;
+        let (win_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "win_y")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?, Some("buttons"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIQueryPointerReply { sequence, length, root, child, root_x, root_y, win_x, win_y, same_screen, mods, group, buttons };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9926,6 +10110,36 @@ impl XIQueryPointerReply {
             .try_into().unwrap()
     }
 }
+impl XIQueryPointerReply {
+    /// Lazily parse the `buttons` list from the raw bytes of a `XIQueryPointerReply`.
+    ///
+    /// In contrast to parsing the whole `XIQueryPointerReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn buttons_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (win_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "win_x")?;
+        let (win_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "win_y")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = buttons_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the XIWarpPointer request
 pub const XI_WARP_POINTER_REQUEST: u8 = 41;
@@ -10004,16 +10218,16 @@ impl XIWarpPointerRequest {
         if header.minor_opcode != XI_WARP_POINTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (src_win, remaining) = xproto::Window::try_parse(value)?;
-        let (dst_win, remaining) = xproto::Window::try_parse(remaining)?;
-        let (src_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (src_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (src_width, remaining) = u16::try_parse(remaining)?;
-        let (src_height, remaining) = u16::try_parse(remaining)?;
-        let (dst_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (dst_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (src_win, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "src_win")?;
+        let (dst_win, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "dst_win")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "src_y")?;
+        let (src_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_width")?;
+        let (src_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_height")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "dst_y")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIWarpPointerRequest {
             src_win,
@@ -10030,6 +10244,7 @@ impl XIWarpPointerRequest {
 }
 impl Request for XIWarpPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10086,10 +10301,10 @@ impl XIChangeCursorRequest {
         if header.minor_opcode != XI_CHANGE_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIChangeCursorRequest {
             window,
@@ -10100,6 +10315,7 @@ impl XIChangeCursorRequest {
 }
 impl Request for XIChangeCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10239,17 +10455,17 @@ pub struct AddMaster {
 impl TryParse for AddMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (send_core, remaining) = bool::try_parse(remaining)?;
-        let (enable, remaining) = bool::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (send_core, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "send_core")?;
+        let (enable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enable")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let type_ = type_.into();
         let result = AddMaster { type_, len, send_core, enable, name };
         Ok((result, remaining))
@@ -10302,13 +10518,13 @@ pub struct RemoveMaster {
 }
 impl TryParse for RemoveMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (return_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "return_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (return_pointer, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "return_pointer")?;
+        let (return_keyboard, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "return_keyboard")?;
         let type_ = type_.into();
         let return_mode = return_mode.into();
         let result = RemoveMaster { type_, len, deviceid, return_mode, return_pointer, return_keyboard };
@@ -10361,10 +10577,10 @@ pub struct AttachSlave {
 }
 impl TryParse for AttachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (master, remaining) = DeviceId::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (master, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "master")?;
         let type_ = type_.into();
         let result = AttachSlave { type_, len, deviceid, master };
         Ok((result, remaining))
@@ -10406,10 +10622,10 @@ pub struct DetachSlave {
 }
 impl TryParse for DetachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let type_ = type_.into();
         let result = DetachSlave { type_, len, deviceid };
         Ok((result, remaining))
@@ -10451,15 +10667,15 @@ pub struct HierarchyChangeDataAddMaster {
 impl TryParse for HierarchyChangeDataAddMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (send_core, remaining) = bool::try_parse(remaining)?;
-        let (enable, remaining) = bool::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (send_core, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "send_core")?;
+        let (enable, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enable")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = HierarchyChangeDataAddMaster { send_core, enable, name };
         Ok((result, remaining))
     }
@@ -10506,11 +10722,11 @@ pub struct HierarchyChangeDataRemoveMaster {
 }
 impl TryParse for HierarchyChangeDataRemoveMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (return_pointer, remaining) = DeviceId::try_parse(remaining)?;
-        let (return_keyboard, remaining) = DeviceId::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (return_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "return_mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (return_pointer, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "return_pointer")?;
+        let (return_keyboard, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "return_keyboard")?;
         let return_mode = return_mode.into();
         let result = HierarchyChangeDataRemoveMaster { deviceid, return_mode, return_pointer, return_keyboard };
         Ok((result, remaining))
@@ -10551,8 +10767,8 @@ pub struct HierarchyChangeDataAttachSlave {
 }
 impl TryParse for HierarchyChangeDataAttachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (master, remaining) = DeviceId::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (master, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "master")?;
         let result = HierarchyChangeDataAttachSlave { deviceid, master };
         Ok((result, remaining))
     }
@@ -10582,8 +10798,8 @@ pub struct HierarchyChangeDataDetachSlave {
 }
 impl TryParse for HierarchyChangeDataDetachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = HierarchyChangeDataDetachSlave { deviceid };
         Ok((result, remaining))
     }
@@ -10721,8 +10937,8 @@ pub struct HierarchyChange {
 }
 impl TryParse for HierarchyChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
         let (data, remaining) = HierarchyChangeData::try_parse(remaining, u16::from(type_))?;
         let result = HierarchyChange { len, data };
         Ok((result, remaining))
@@ -10782,9 +10998,9 @@ impl<'input> XIChangeHierarchyRequest<'input> {
         if header.minor_opcode != XI_CHANGE_HIERARCHY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (num_changes, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (changes, remaining) = crate::x11_utils::parse_list::<HierarchyChange>(remaining, num_changes.try_to_usize()?)?;
+        let (num_changes, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "num_changes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (changes, remaining) = crate::x11_utils::parse_list::<HierarchyChange>(remaining, num_changes.try_to_usize()?, Some("changes"))?;
         let _ = remaining;
         Ok(XIChangeHierarchyRequest {
             changes: Cow::Owned(changes),
@@ -10799,6 +11015,7 @@ impl<'input> XIChangeHierarchyRequest<'input> {
 }
 impl<'input> Request for XIChangeHierarchyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10849,9 +11066,9 @@ impl XISetClientPointerRequest {
         if header.minor_opcode != XI_SET_CLIENT_POINTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XISetClientPointerRequest {
             window,
@@ -10861,6 +11078,7 @@ impl XISetClientPointerRequest {
 }
 impl Request for XISetClientPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10905,7 +11123,7 @@ impl XIGetClientPointerRequest {
         if header.minor_opcode != XI_GET_CLIENT_POINTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(XIGetClientPointerRequest {
             window,
@@ -10914,6 +11132,7 @@ impl XIGetClientPointerRequest {
 }
 impl Request for XIGetClientPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10937,21 +11156,20 @@ pub struct XIGetClientPointerReply {
 impl TryParse for XIGetClientPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (set, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (set, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "set")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIGetClientPointerReply { sequence, length, set, deviceid };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11106,7 +11324,7 @@ impl core::fmt::Debug for XIEventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(XIEventMask, u32);
+bitmask_binop!(XIEventMask, u32, [DEVICE_CHANGED, KEY_PRESS, KEY_RELEASE, BUTTON_PRESS, BUTTON_RELEASE, MOTION, ENTER, LEAVE, FOCUS_IN, FOCUS_OUT, HIERARCHY, PROPERTY, RAW_KEY_PRESS, RAW_KEY_RELEASE, RAW_BUTTON_PRESS, RAW_BUTTON_RELEASE, RAW_MOTION, TOUCH_BEGIN, TOUCH_UPDATE, TOUCH_END, TOUCH_OWNERSHIP, RAW_TOUCH_BEGIN, RAW_TOUCH_UPDATE, RAW_TOUCH_END, BARRIER_HIT, BARRIER_LEAVE]);
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11116,8 +11334,8 @@ pub struct EventMask {
 }
 impl TryParse for EventMask {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mask_len, remaining) = u16::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (mask_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mask_len")?;
         let mut remaining = remaining;
         let list_length = mask_len.try_to_usize()?;
         let mut mask = Vec::with_capacity(list_length);
@@ -11208,10 +11426,10 @@ impl<'input> XISelectEventsRequest<'input> {
         if header.minor_opcode != XI_SELECT_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (num_mask, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (masks, remaining) = crate::x11_utils::parse_list::<EventMask>(remaining, num_mask.try_to_usize()?)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (num_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (masks, remaining) = crate::x11_utils::parse_list::<EventMask>(remaining, num_mask.try_to_usize()?, Some("masks"))?;
         let _ = remaining;
         Ok(XISelectEventsRequest {
             window,
@@ -11228,6 +11446,7 @@ impl<'input> XISelectEventsRequest<'input> {
 }
 impl<'input> Request for XISelectEventsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11274,8 +11493,8 @@ impl XIQueryVersionRequest {
         if header.minor_opcode != XI_QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u16::try_parse(value)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(XIQueryVersionRequest {
             major_version,
@@ -11285,6 +11504,7 @@ impl XIQueryVersionRequest {
 }
 impl Request for XIQueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11308,20 +11528,19 @@ pub struct XIQueryVersionReply {
 impl TryParse for XIQueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIQueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11547,7 +11766,7 @@ impl core::fmt::Debug for ScrollFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ScrollFlags, u32);
+bitmask_binop!(ScrollFlags, u32, [NO_EMULATION, PREFERRED]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11672,12 +11891,12 @@ pub struct ButtonClass {
 }
 impl TryParse for ButtonClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::InvalidExpression)?.checked_div(32u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_to_usize()?)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_buttons")?;
+        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::InvalidExpression)?.checked_div(32u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("state"))?;
+        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_to_usize()?, Some("labels"))?;
         let type_ = type_.into();
         let result = ButtonClass { type_, len, sourceid, state, labels };
         Ok((result, remaining))
@@ -11728,11 +11947,11 @@ pub struct KeyClass {
 }
 impl TryParse for KeyClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_to_usize()?)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keys")?;
+        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_to_usize()?, Some("keys"))?;
         let type_ = type_.into();
         let result = KeyClass { type_, len, sourceid, keys };
         Ok((result, remaining))
@@ -11784,14 +12003,14 @@ pub struct ScrollClass {
 }
 impl TryParse for ScrollClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (scroll_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (increment, remaining) = Fp3232::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (number, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "number")?;
+        let (scroll_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "scroll_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (increment, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "increment")?;
         let type_ = type_.into();
         let scroll_type = scroll_type.into();
         let flags = flags.into();
@@ -11860,11 +12079,11 @@ pub struct TouchClass {
 }
 impl TryParse for TouchClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (num_touches, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_touches")?;
         let type_ = type_.into();
         let mode = mode.into();
         let result = TouchClass { type_, len, sourceid, mode, num_touches };
@@ -11910,11 +12129,11 @@ pub struct GestureClass {
 }
 impl TryParse for GestureClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (num_touches, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_touches")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let type_ = type_.into();
         let result = GestureClass { type_, len, sourceid, num_touches };
         Ok((result, remaining))
@@ -11964,17 +12183,17 @@ pub struct ValuatorClass {
 }
 impl TryParse for ValuatorClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (label, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (min, remaining) = Fp3232::try_parse(remaining)?;
-        let (max, remaining) = Fp3232::try_parse(remaining)?;
-        let (value, remaining) = Fp3232::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (number, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "number")?;
+        let (label, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "label")?;
+        let (min, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "min")?;
+        let (max, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "max")?;
+        let (value, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "value")?;
+        let (resolution, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resolution")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let type_ = type_.into();
         let mode = mode.into();
         let result = ValuatorClass { type_, len, sourceid, number, label, min, max, value, resolution, mode };
@@ -12064,8 +12283,8 @@ pub struct DeviceClassDataKey {
 }
 impl TryParse for DeviceClassDataKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_keys, remaining) = u16::try_parse(remaining)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_to_usize()?)?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_keys")?;
+        let (keys, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_keys.try_to_usize()?, Some("keys"))?;
         let result = DeviceClassDataKey { keys };
         Ok((result, remaining))
     }
@@ -12106,9 +12325,9 @@ pub struct DeviceClassDataButton {
 }
 impl TryParse for DeviceClassDataButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_buttons, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::InvalidExpression)?.checked_div(32u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
-        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_to_usize()?)?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_buttons")?;
+        let (state, remaining) = crate::x11_utils::parse_list::<u32>(remaining, u32::from(num_buttons).checked_add(31u32).ok_or(ParseError::InvalidExpression)?.checked_div(32u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("state"))?;
+        let (labels, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_buttons.try_to_usize()?, Some("labels"))?;
         let result = DeviceClassDataButton { state, labels };
         Ok((result, remaining))
     }
@@ -12156,14 +12375,14 @@ pub struct DeviceClassDataValuator {
 }
 impl TryParse for DeviceClassDataValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (label, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (min, remaining) = Fp3232::try_parse(remaining)?;
-        let (max, remaining) = Fp3232::try_parse(remaining)?;
-        let (value, remaining) = Fp3232::try_parse(remaining)?;
-        let (resolution, remaining) = u32::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (number, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "number")?;
+        let (label, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "label")?;
+        let (min, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "min")?;
+        let (max, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "max")?;
+        let (value, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "value")?;
+        let (resolution, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resolution")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let mode = mode.into();
         let result = DeviceClassDataValuator { number, label, min, max, value, resolution, mode };
         Ok((result, remaining))
@@ -12242,11 +12461,11 @@ pub struct DeviceClassDataScroll {
 }
 impl TryParse for DeviceClassDataScroll {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (number, remaining) = u16::try_parse(remaining)?;
-        let (scroll_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (increment, remaining) = Fp3232::try_parse(remaining)?;
+        let (number, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "number")?;
+        let (scroll_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "scroll_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (increment, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "increment")?;
         let scroll_type = scroll_type.into();
         let flags = flags.into();
         let result = DeviceClassDataScroll { number, scroll_type, flags, increment };
@@ -12298,8 +12517,8 @@ pub struct DeviceClassDataTouch {
 }
 impl TryParse for DeviceClassDataTouch {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (num_touches, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_touches")?;
         let mode = mode.into();
         let result = DeviceClassDataTouch { mode, num_touches };
         Ok((result, remaining))
@@ -12328,8 +12547,8 @@ pub struct DeviceClassDataGesture {
 }
 impl TryParse for DeviceClassDataGesture {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (num_touches, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (num_touches, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_touches")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = DeviceClassDataGesture { num_touches };
         Ok((result, remaining))
     }
@@ -12497,9 +12716,9 @@ pub struct DeviceClass {
 impl TryParse for DeviceClass {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
         let (data, remaining) = DeviceClassData::try_parse(remaining, u16::from(type_))?;
         let result = DeviceClass { len, sourceid, data };
         let length = u32::from(len).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?;
@@ -12539,20 +12758,20 @@ pub struct XIDeviceInfo {
 impl TryParse for XIDeviceInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (type_, remaining) = u16::try_parse(remaining)?;
-        let (attachment, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "type_")?;
+        let (attachment, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "attachment")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (enabled, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enabled")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let type_ = type_.into();
         let result = XIDeviceInfo { deviceid, type_, attachment, enabled, name, classes };
         Ok((result, remaining))
@@ -12643,8 +12862,8 @@ impl XIQueryDeviceRequest {
         if header.minor_opcode != XI_QUERY_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIQueryDeviceRequest {
             deviceid,
@@ -12653,6 +12872,7 @@ impl XIQueryDeviceRequest {
 }
 impl Request for XIQueryDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12675,20 +12895,19 @@ pub struct XIQueryDeviceReply {
 impl TryParse for XIQueryDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_infos, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<XIDeviceInfo>(remaining, num_infos.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_infos, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_infos")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (infos, remaining) = crate::x11_utils::parse_list::<XIDeviceInfo>(remaining, num_infos.try_to_usize()?, Some("infos"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIQueryDeviceReply { sequence, length, infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12727,6 +12946,27 @@ impl XIQueryDeviceReply {
             .try_into().unwrap()
     }
 }
+impl XIQueryDeviceReply {
+    /// Lazily parse the `infos` list from the raw bytes of a `XIQueryDeviceReply`.
+    ///
+    /// In contrast to parsing the whole `XIQueryDeviceReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn infos_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, XIDeviceInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_infos, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_infos")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_infos.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the XISetFocus request
 pub const XI_SET_FOCUS_REQUEST: u8 = 49;
@@ -12773,10 +13013,10 @@ impl XISetFocusRequest {
         if header.minor_opcode != XI_SET_FOCUS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XISetFocusRequest {
             window,
@@ -12787,6 +13027,7 @@ impl XISetFocusRequest {
 }
 impl Request for XISetFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12831,8 +13072,8 @@ impl XIGetFocusRequest {
         if header.minor_opcode != XI_GET_FOCUS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIGetFocusRequest {
             deviceid,
@@ -12841,6 +13082,7 @@ impl XIGetFocusRequest {
 }
 impl Request for XIGetFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12863,19 +13105,18 @@ pub struct XIGetFocusReply {
 impl TryParse for XIGetFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (focus, remaining) = xproto::Window::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (focus, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "focus")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIGetFocusReply { sequence, length, focus };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13072,19 +13313,19 @@ impl<'input> XIGrabDeviceRequest<'input> {
         if header.minor_opcode != XI_GRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let (paired_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (paired_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "paired_device_mode")?;
         let paired_device_mode = paired_device_mode.into();
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let owner_events = owner_events.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (mask_len, remaining) = u16::try_parse(remaining)?;
-        let (mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, mask_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (mask_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mask_len")?;
+        let (mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, mask_len.try_to_usize()?, Some("mask"))?;
         let _ = remaining;
         Ok(XIGrabDeviceRequest {
             window,
@@ -13113,6 +13354,7 @@ impl<'input> XIGrabDeviceRequest<'input> {
 }
 impl<'input> Request for XIGrabDeviceRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -13135,20 +13377,19 @@ pub struct XIGrabDeviceReply {
 impl TryParse for XIGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = XIGrabDeviceReply { sequence, length, status };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13245,9 +13486,9 @@ impl XIUngrabDeviceRequest {
         if header.minor_opcode != XI_UNGRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (time, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "time")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIUngrabDeviceRequest {
             time,
@@ -13257,6 +13498,7 @@ impl XIUngrabDeviceRequest {
 }
 impl Request for XIUngrabDeviceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -13392,13 +13634,13 @@ impl XIAllowEventsRequest {
         if header.minor_opcode != XI_ALLOW_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (time, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (event_mode, remaining) = u8::try_parse(remaining)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "time")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (event_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "event_mode")?;
         let event_mode = event_mode.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (touchid, remaining) = u32::try_parse(remaining)?;
-        let (grab_window, remaining) = xproto::Window::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (touchid, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "touchid")?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "grab_window")?;
         let _ = remaining;
         Ok(XIAllowEventsRequest {
             time,
@@ -13411,6 +13653,7 @@ impl XIAllowEventsRequest {
 }
 impl Request for XIAllowEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -13596,7 +13839,7 @@ impl core::fmt::Debug for ModifierMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(ModifierMask, u32);
+bitmask_binop!(ModifierMask, u32, [ANY]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -13606,9 +13849,9 @@ pub struct GrabModifierInfo {
 }
 impl TryParse for GrabModifierInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (modifiers, remaining) = u32::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "modifiers")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let status = status.into();
         let result = GrabModifierInfo { modifiers, status };
         Ok((result, remaining))
@@ -13723,24 +13966,24 @@ impl<'input> XIPassiveGrabDeviceRequest<'input> {
         if header.minor_opcode != XI_PASSIVE_GRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (time, remaining) = xproto::Timestamp::try_parse(value)?;
-        let (grab_window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_modifiers, remaining) = u16::try_parse(remaining)?;
-        let (mask_len, remaining) = u16::try_parse(remaining)?;
-        let (grab_type, remaining) = u8::try_parse(remaining)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(value), "time")?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "grab_window")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (num_modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modifiers")?;
+        let (mask_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mask_len")?;
+        let (grab_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grab_type")?;
         let grab_type = grab_type.into();
-        let (grab_mode, remaining) = u8::try_parse(remaining)?;
+        let (grab_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grab_mode")?;
         let grab_mode = grab_mode.into();
-        let (paired_device_mode, remaining) = u8::try_parse(remaining)?;
+        let (paired_device_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "paired_device_mode")?;
         let paired_device_mode = paired_device_mode.into();
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let owner_events = owner_events.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, mask_len.try_to_usize()?)?;
-        let (modifiers, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_modifiers.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, mask_len.try_to_usize()?, Some("mask"))?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_modifiers.try_to_usize()?, Some("modifiers"))?;
         let _ = remaining;
         Ok(XIPassiveGrabDeviceRequest {
             time,
@@ -13775,6 +14018,7 @@ impl<'input> XIPassiveGrabDeviceRequest<'input> {
 }
 impl<'input> Request for XIPassiveGrabDeviceRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -13797,20 +14041,19 @@ pub struct XIPassiveGrabDeviceReply {
 impl TryParse for XIPassiveGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_modifiers, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (modifiers, remaining) = crate::x11_utils::parse_list::<GrabModifierInfo>(remaining, num_modifiers.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modifiers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list::<GrabModifierInfo>(remaining, num_modifiers.try_to_usize()?, Some("modifiers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIPassiveGrabDeviceReply { sequence, length, modifiers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13849,6 +14092,27 @@ impl XIPassiveGrabDeviceReply {
             .try_into().unwrap()
     }
 }
+impl XIPassiveGrabDeviceReply {
+    /// Lazily parse the `modifiers` list from the raw bytes of a `XIPassiveGrabDeviceReply`.
+    ///
+    /// In contrast to parsing the whole `XIPassiveGrabDeviceReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn modifiers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, GrabModifierInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modifiers")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_modifiers.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the XIPassiveUngrabDevice request
 pub const XI_PASSIVE_UNGRAB_DEVICE_REQUEST: u8 = 55;
@@ -13908,14 +14172,14 @@ impl<'input> XIPassiveUngrabDeviceRequest<'input> {
         if header.minor_opcode != XI_PASSIVE_UNGRAB_DEVICE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (grab_window, remaining) = xproto::Window::try_parse(value)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (num_modifiers, remaining) = u16::try_parse(remaining)?;
-        let (grab_type, remaining) = u8::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "grab_window")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (num_modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_modifiers")?;
+        let (grab_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grab_type")?;
         let grab_type = grab_type.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (modifiers, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_modifiers.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (modifiers, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_modifiers.try_to_usize()?, Some("modifiers"))?;
         let _ = remaining;
         Ok(XIPassiveUngrabDeviceRequest {
             grab_window,
@@ -13938,6 +14202,7 @@ impl<'input> XIPassiveUngrabDeviceRequest<'input> {
 }
 impl<'input> Request for XIPassiveUngrabDeviceRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -13982,8 +14247,8 @@ impl XIListPropertiesRequest {
         if header.minor_opcode != XI_LIST_PROPERTIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(XIListPropertiesRequest {
             deviceid,
@@ -13992,6 +14257,7 @@ impl XIListPropertiesRequest {
 }
 impl Request for XIListPropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14014,20 +14280,19 @@ pub struct XIListPropertiesReply {
 impl TryParse for XIListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_properties, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (properties, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_properties.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (properties, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, num_properties.try_to_usize()?, Some("properties"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIListPropertiesReply { sequence, length, properties };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14066,6 +14331,27 @@ impl XIListPropertiesReply {
             .try_into().unwrap()
     }
 }
+impl XIListPropertiesReply {
+    /// Lazily parse the `properties` list from the raw bytes of a `XIListPropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `XIListPropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn properties_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Atom>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_properties, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_properties")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_properties.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -14091,12 +14377,12 @@ impl XIChangePropertyAux {
         if switch_expr == u8::from(PropertyFormat::M8_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
+            let (data8, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?), "data8")?;
             let data8 = data8.to_vec();
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIChangePropertyAux::Data8(data8));
@@ -14104,18 +14390,18 @@ impl XIChangePropertyAux {
         if switch_expr == u8::from(PropertyFormat::M16_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?, Some("data16"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIChangePropertyAux::Data16(data16));
         }
         if switch_expr == u8::from(PropertyFormat::M32_BITS) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?, Some("data32"))?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIChangePropertyAux::Data32(data32));
@@ -14245,13 +14531,13 @@ impl<'input> XIChangePropertyRequest<'input> {
         if header.minor_opcode != XI_CHANGE_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
         let (items, remaining) = XIChangePropertyAux::try_parse(remaining, u8::from(format), u32::from(num_items))?;
         let _ = remaining;
         Ok(XIChangePropertyRequest {
@@ -14277,6 +14563,7 @@ impl<'input> XIChangePropertyRequest<'input> {
 }
 impl<'input> Request for XIChangePropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14327,9 +14614,9 @@ impl XIDeletePropertyRequest {
         if header.minor_opcode != XI_DELETE_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(XIDeletePropertyRequest {
             deviceid,
@@ -14339,6 +14626,7 @@ impl XIDeletePropertyRequest {
 }
 impl Request for XIDeletePropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14409,13 +14697,13 @@ impl XIGetPropertyRequest {
         if header.minor_opcode != XI_GET_PROPERTY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (deviceid, remaining) = DeviceId::try_parse(value)?;
-        let (delete, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
-        let (len, remaining) = u32::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(value), "deviceid")?;
+        let (delete, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "delete")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
+        let (len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "len")?;
         let _ = remaining;
         Ok(XIGetPropertyRequest {
             deviceid,
@@ -14429,6 +14717,7 @@ impl XIGetPropertyRequest {
 }
 impl Request for XIGetPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14465,12 +14754,12 @@ impl XIGetPropertyItems {
         if switch_expr == u8::from(PropertyFormat::M8_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data8, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?)?;
+            let (data8, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, num_items.try_to_usize()?), "data8")?;
             let data8 = data8.to_vec();
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data8(data8));
@@ -14478,18 +14767,18 @@ impl XIGetPropertyItems {
         if switch_expr == u8::from(PropertyFormat::M16_BITS) {
             let remaining = outer_remaining;
             let value = remaining;
-            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?)?;
+            let (data16, remaining) = crate::x11_utils::parse_list::<u16>(remaining, num_items.try_to_usize()?, Some("data16"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data16(data16));
         }
         if switch_expr == u8::from(PropertyFormat::M32_BITS) {
             let remaining = outer_remaining;
-            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?)?;
+            let (data32, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_items.try_to_usize()?, Some("data32"))?;
             outer_remaining = remaining;
             assert!(parse_result.is_none(), "The XML should prevent more than one 'if' from matching");
             parse_result = Some(XIGetPropertyItems::Data32(data32));
@@ -14572,23 +14861,22 @@ pub struct XIGetPropertyReply {
 impl TryParse for XIGetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (num_items, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (type_, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "type_")?;
+        let (bytes_after, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_after")?;
+        let (num_items, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_items")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let (items, remaining) = XIGetPropertyItems::try_parse(remaining, u8::from(format), u32::from(num_items))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIGetPropertyReply { sequence, length, type_, bytes_after, num_items, items };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14649,7 +14937,7 @@ impl XIGetSelectedEventsRequest {
         if header.minor_opcode != XI_GET_SELECTED_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(XIGetSelectedEventsRequest {
             window,
@@ -14658,6 +14946,7 @@ impl XIGetSelectedEventsRequest {
 }
 impl Request for XIGetSelectedEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14680,20 +14969,19 @@ pub struct XIGetSelectedEventsReply {
 impl TryParse for XIGetSelectedEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_masks, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (masks, remaining) = crate::x11_utils::parse_list::<EventMask>(remaining, num_masks.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_masks, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_masks")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (masks, remaining) = crate::x11_utils::parse_list::<EventMask>(remaining, num_masks.try_to_usize()?, Some("masks"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = XIGetSelectedEventsReply { sequence, length, masks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14732,6 +15020,27 @@ impl XIGetSelectedEventsReply {
             .try_into().unwrap()
     }
 }
+impl XIGetSelectedEventsReply {
+    /// Lazily parse the `masks` list from the raw bytes of a `XIGetSelectedEventsReply`.
+    ///
+    /// In contrast to parsing the whole `XIGetSelectedEventsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn masks_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, EventMask>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_masks, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_masks")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_masks.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -14742,10 +15051,10 @@ pub struct BarrierReleasePointerInfo {
 }
 impl TryParse for BarrierReleasePointerInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (barrier, remaining) = xfixes::Barrier::try_parse(remaining)?;
-        let (eventid, remaining) = u32::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (barrier, remaining) = crate::x11_utils::in_field(xfixes::Barrier::try_parse(remaining), "barrier")?;
+        let (eventid, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "eventid")?;
         let result = BarrierReleasePointerInfo { deviceid, barrier, eventid };
         Ok((result, remaining))
     }
@@ -14818,8 +15127,8 @@ impl<'input> XIBarrierReleasePointerRequest<'input> {
         if header.minor_opcode != XI_BARRIER_RELEASE_POINTER_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (num_barriers, remaining) = u32::try_parse(value)?;
-        let (barriers, remaining) = crate::x11_utils::parse_list::<BarrierReleasePointerInfo>(remaining, num_barriers.try_to_usize()?)?;
+        let (num_barriers, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "num_barriers")?;
+        let (barriers, remaining) = crate::x11_utils::parse_list::<BarrierReleasePointerInfo>(remaining, num_barriers.try_to_usize()?, Some("barriers"))?;
         let _ = remaining;
         Ok(XIBarrierReleasePointerRequest {
             barriers: Cow::Owned(barriers),
@@ -14834,6 +15143,7 @@ impl<'input> XIBarrierReleasePointerRequest<'input> {
 }
 impl<'input> Request for XIBarrierReleasePointerRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -14861,12 +15171,12 @@ pub struct DeviceValuatorEvent {
 impl TryParse for DeviceValuatorEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (device_state, remaining) = u16::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (first_valuator, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (device_state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "device_state")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let (first_valuator, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_valuator")?;
         let (valuators_0, remaining) = i32::try_parse(remaining)?;
         let (valuators_1, remaining) = i32::try_parse(remaining)?;
         let (valuators_2, remaining) = i32::try_parse(remaining)?;
@@ -15061,7 +15371,7 @@ impl core::fmt::Debug for MoreEventsMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(MoreEventsMask, u8);
+bitmask_binop!(MoreEventsMask, u8, [MORE_EVENTS]);
 
 /// Opcode for the DeviceKeyPress event
 pub const DEVICE_KEY_PRESS_EVENT: u8 = 1;
@@ -15086,20 +15396,20 @@ pub struct DeviceKeyPressEvent {
 impl TryParse for DeviceKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_y")?;
+        let (state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
         let state = state.into();
         let result = DeviceKeyPressEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen, device_id };
         let _ = remaining;
@@ -15268,14 +15578,14 @@ pub struct DeviceFocusInEvent {
 impl TryParse for DeviceFocusInEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
         let detail = detail.into();
         let mode = mode.into();
         let result = DeviceFocusInEvent { response_type, detail, sequence, time, window, mode, device_id };
@@ -15469,7 +15779,7 @@ impl core::fmt::Debug for ClassesReportedMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ClassesReportedMask, u8);
+bitmask_binop!(ClassesReportedMask, u8, [OUT_OF_PROXIMITY, DEVICE_MODE_ABSOLUTE, REPORTING_VALUATORS, REPORTING_BUTTONS, REPORTING_KEYS]);
 
 /// Opcode for the DeviceStateNotify event
 pub const DEVICE_STATE_NOTIFY_EVENT: u8 = 10;
@@ -15491,14 +15801,14 @@ pub struct DeviceStateNotifyEvent {
 impl TryParse for DeviceStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_keys, remaining) = u8::try_parse(remaining)?;
-        let (num_buttons, remaining) = u8::try_parse(remaining)?;
-        let (num_valuators, remaining) = u8::try_parse(remaining)?;
-        let (classes_reported, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (num_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_keys")?;
+        let (num_buttons, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_buttons")?;
+        let (num_valuators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_valuators")?;
+        let (classes_reported, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "classes_reported")?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
         let (valuators_0, remaining) = u32::try_parse(remaining)?;
@@ -15652,15 +15962,15 @@ pub struct DeviceMappingNotifyEvent {
 impl TryParse for DeviceMappingNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (request, remaining) = u8::try_parse(remaining)?;
-        let (first_keycode, remaining) = KeyCode::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (request, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request")?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(KeyCode::try_parse(remaining), "first_keycode")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         let request = request.into();
         let result = DeviceMappingNotifyEvent { response_type, device_id, sequence, request, first_keycode, count, time };
         let _ = remaining;
@@ -15851,12 +16161,12 @@ pub struct ChangeDeviceNotifyEvent {
 impl TryParse for ChangeDeviceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (request, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (request, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 23)?;
         let request = request.into();
         let result = ChangeDeviceNotifyEvent { response_type, device_id, sequence, time, request };
         let _ = remaining;
@@ -15980,9 +16290,9 @@ pub struct DeviceKeyStateNotifyEvent {
 impl TryParse for DeviceKeyStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<28>(remaining)?;
         let result = DeviceKeyStateNotifyEvent { response_type, device_id, sequence, keys };
         let _ = remaining;
@@ -16100,9 +16410,9 @@ pub struct DeviceButtonStateNotifyEvent {
 impl TryParse for DeviceButtonStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
         let (buttons, remaining) = crate::x11_utils::parse_u8_array::<28>(remaining)?;
         let result = DeviceButtonStateNotifyEvent { response_type, device_id, sequence, buttons };
         let _ = remaining;
@@ -16289,14 +16599,14 @@ pub struct DevicePresenceNotifyEvent {
 impl TryParse for DevicePresenceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (devchange, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (control, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (devchange, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "devchange")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (control, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "control")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         let devchange = devchange.into();
         let result = DevicePresenceNotifyEvent { response_type, sequence, time, devchange, device_id, control };
         let _ = remaining;
@@ -16426,13 +16736,13 @@ pub struct DevicePropertyNotifyEvent {
 impl TryParse for DevicePropertyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let remaining = remaining.get(19..).ok_or(ParseError::InsufficientData)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 19)?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
         let state = state.into();
         let result = DevicePropertyNotifyEvent { response_type, state, sequence, time, property, device_id };
         let _ = remaining;
@@ -16624,23 +16934,22 @@ pub struct DeviceChangedEvent {
 impl TryParse for DeviceChangedEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (reason, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (reason, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "reason")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<DeviceClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let reason = reason.into();
         let result = DeviceChangedEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, reason, classes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -16728,7 +17037,7 @@ impl core::fmt::Debug for KeyEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(KeyEventFlags, u32);
+bitmask_binop!(KeyEventFlags, u32, [KEY_REPEAT]);
 
 /// Opcode for the KeyPress event
 pub const KEY_PRESS_EVENT: u16 = 2;
@@ -16761,36 +17070,35 @@ pub struct KeyPressEvent {
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?, Some("button_mask"))?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
         let flags = flags.into();
         let result = KeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -16910,7 +17218,7 @@ impl core::fmt::Debug for PointerEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(PointerEventFlags, u32);
+bitmask_binop!(PointerEventFlags, u32, [POINTER_EMULATED]);
 
 /// Opcode for the ButtonPress event
 pub const BUTTON_PRESS_EVENT: u16 = 4;
@@ -16943,36 +17251,35 @@ pub struct ButtonPressEvent {
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?, Some("button_mask"))?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
         let flags = flags.into();
         let result = ButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17221,35 +17528,34 @@ pub struct EnterEvent {
 impl TryParse for EnterEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (focus, remaining) = bool::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let (focus, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "focus")?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (buttons, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?, Some("buttons"))?;
         let mode = mode.into();
         let detail = detail.into();
         let result = EnterEvent { response_type, extension, sequence, length, event_type, deviceid, time, sourceid, mode, detail, root, event, child, root_x, root_y, event_x, event_y, same_screen, focus, mods, group, buttons };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17374,7 +17680,7 @@ impl core::fmt::Debug for HierarchyMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(HierarchyMask, u32);
+bitmask_binop!(HierarchyMask, u32, [MASTER_ADDED, MASTER_REMOVED, SLAVE_ADDED, SLAVE_REMOVED, SLAVE_ATTACHED, SLAVE_DETACHED, DEVICE_ENABLED, DEVICE_DISABLED]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17387,12 +17693,12 @@ pub struct HierarchyInfo {
 }
 impl TryParse for HierarchyInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (attachment, remaining) = DeviceId::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (enabled, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (attachment, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "attachment")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (enabled, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "enabled")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let type_ = type_.into();
         let flags = flags.into();
         let result = HierarchyInfo { deviceid, attachment, type_, enabled, flags };
@@ -17451,22 +17757,21 @@ pub struct HierarchyEvent {
 impl TryParse for HierarchyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (num_infos, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
-        let (infos, remaining) = crate::x11_utils::parse_list::<HierarchyInfo>(remaining, num_infos.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (num_infos, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_infos")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
+        let (infos, remaining) = crate::x11_utils::parse_list::<HierarchyInfo>(remaining, num_infos.try_to_usize()?, Some("infos"))?;
         let flags = flags.into();
         let result = HierarchyEvent { response_type, extension, sequence, length, event_type, deviceid, time, flags, infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17588,21 +17893,20 @@ pub struct PropertyEvent {
 impl TryParse for PropertyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (what, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
+        let (what, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "what")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let what = what.into();
         let result = PropertyEvent { response_type, extension, sequence, length, event_type, deviceid, time, property, what };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17690,26 +17994,25 @@ pub struct RawKeyPressEvent {
 impl TryParse for RawKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues_raw"))?;
         let flags = flags.into();
         let result = RawKeyPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17784,26 +18087,25 @@ pub struct RawButtonPressEvent {
 impl TryParse for RawButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues_raw"))?;
         let flags = flags.into();
         let result = RawButtonPressEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -17906,7 +18208,7 @@ impl core::fmt::Debug for TouchEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(TouchEventFlags, u32);
+bitmask_binop!(TouchEventFlags, u32, [TOUCH_PENDING_END, TOUCH_EMULATING_POINTER]);
 
 /// Opcode for the TouchBegin event
 pub const TOUCH_BEGIN_EVENT: u16 = 18;
@@ -17939,36 +18241,35 @@ pub struct TouchBeginEvent {
 impl TryParse for TouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (buttons_len, remaining) = u16::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (buttons_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "buttons_len")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (button_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, buttons_len.try_to_usize()?, Some("button_mask"))?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
         let flags = flags.into();
         let result = TouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, sourceid, flags, mods, group, button_mask, valuator_mask, axisvalues };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -18115,26 +18416,25 @@ pub struct TouchOwnershipEvent {
 impl TryParse for TouchOwnershipEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (touchid, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (touchid, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "touchid")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
         let flags = flags.into();
         let result = TouchOwnershipEvent { response_type, extension, sequence, length, event_type, deviceid, time, touchid, root, event, child, sourceid, flags };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -18247,26 +18547,25 @@ pub struct RawTouchBeginEvent {
 impl TryParse for RawTouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (valuators_len, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?)?;
-        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
-        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let (valuators_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "valuators_len")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (valuator_mask, remaining) = crate::x11_utils::parse_list::<u32>(remaining, valuators_len.try_to_usize()?, Some("valuator_mask"))?;
+        let (axisvalues, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues"))?;
+        let (axisvalues_raw, remaining) = crate::x11_utils::parse_list::<Fp3232>(remaining, valuator_mask.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("axisvalues_raw"))?;
         let flags = flags.into();
         let result = RawTouchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, sourceid, flags, valuator_mask, axisvalues, axisvalues_raw };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -18369,7 +18668,7 @@ impl core::fmt::Debug for BarrierFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(BarrierFlags, u32);
+bitmask_binop!(BarrierFlags, u32, [POINTER_RELEASED, DEVICE_IS_GRABBED]);
 
 /// Opcode for the BarrierHit event
 pub const BARRIER_HIT_EVENT: u16 = 25;
@@ -18398,30 +18697,29 @@ pub struct BarrierHitEvent {
 impl TryParse for BarrierHitEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (eventid, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (barrier, remaining) = xfixes::Barrier::try_parse(remaining)?;
-        let (dtime, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (dx, remaining) = Fp3232::try_parse(remaining)?;
-        let (dy, remaining) = Fp3232::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (eventid, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "eventid")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (barrier, remaining) = crate::x11_utils::in_field(xfixes::Barrier::try_parse(remaining), "barrier")?;
+        let (dtime, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dtime")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (dx, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "dx")?;
+        let (dy, remaining) = crate::x11_utils::in_field(Fp3232::try_parse(remaining), "dy")?;
         let flags = flags.into();
         let result = BarrierHitEvent { response_type, extension, sequence, length, event_type, deviceid, time, eventid, root, event, barrier, dtime, flags, sourceid, root_x, root_y, dx, dy };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -18589,7 +18887,7 @@ impl core::fmt::Debug for GesturePinchEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(GesturePinchEventFlags, u32);
+bitmask_binop!(GesturePinchEventFlags, u32, [GESTURE_PINCH_CANCELLED]);
 
 /// Opcode for the GesturePinchBegin event
 pub const GESTURE_PINCH_BEGIN_EVENT: u16 = 27;
@@ -18625,37 +18923,36 @@ pub struct GesturePinchBeginEvent {
 impl TryParse for GesturePinchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_unaccel_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_unaccel_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (scale, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_angle, remaining) = Fp1616::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (delta_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_x")?;
+        let (delta_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_y")?;
+        let (delta_unaccel_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_unaccel_x")?;
+        let (delta_unaccel_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_unaccel_y")?;
+        let (scale, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "scale")?;
+        let (delta_angle, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_angle")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
         let result = GesturePinchBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, delta_x, delta_y, delta_unaccel_x, delta_unaccel_y, scale, delta_angle, sourceid, mods, group, flags };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -18873,7 +19170,7 @@ impl core::fmt::Debug for GestureSwipeEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(GestureSwipeEventFlags, u32);
+bitmask_binop!(GestureSwipeEventFlags, u32, [GESTURE_SWIPE_CANCELLED]);
 
 /// Opcode for the GestureSwipeBegin event
 pub const GESTURE_SWIPE_BEGIN_EVENT: u16 = 30;
@@ -18907,35 +19204,34 @@ pub struct GestureSwipeBeginEvent {
 impl TryParse for GestureSwipeBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (detail, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event, remaining) = xproto::Window::try_parse(remaining)?;
-        let (child, remaining) = xproto::Window::try_parse(remaining)?;
-        let (root_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (root_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (event_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_unaccel_x, remaining) = Fp1616::try_parse(remaining)?;
-        let (delta_unaccel_y, remaining) = Fp1616::try_parse(remaining)?;
-        let (sourceid, remaining) = DeviceId::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (mods, remaining) = ModifierInfo::try_parse(remaining)?;
-        let (group, remaining) = GroupInfo::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "deviceid")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "detail")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "event_y")?;
+        let (delta_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_x")?;
+        let (delta_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_y")?;
+        let (delta_unaccel_x, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_unaccel_x")?;
+        let (delta_unaccel_y, remaining) = crate::x11_utils::in_field(Fp1616::try_parse(remaining), "delta_unaccel_y")?;
+        let (sourceid, remaining) = crate::x11_utils::in_field(DeviceId::try_parse(remaining), "sourceid")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (mods, remaining) = crate::x11_utils::in_field(ModifierInfo::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(GroupInfo::try_parse(remaining), "group")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let flags = flags.into();
         let result = GestureSwipeBeginEvent { response_type, extension, sequence, length, event_type, deviceid, time, detail, root, event, child, root_x, root_y, event_x, event_y, delta_x, delta_y, delta_unaccel_x, delta_unaccel_y, sourceid, mods, group, flags };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -19347,14 +19643,14 @@ impl<'input> SendExtensionEventRequest<'input> {
         if header.minor_opcode != SEND_EXTENSION_EVENT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (destination, remaining) = xproto::Window::try_parse(value)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (propagate, remaining) = bool::try_parse(remaining)?;
-        let (num_classes, remaining) = u16::try_parse(remaining)?;
-        let (num_events, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (events, remaining) = crate::x11_utils::parse_list::<EventForSend>(remaining, num_events.try_to_usize()?)?;
-        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?)?;
+        let (destination, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "destination")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (propagate, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "propagate")?;
+        let (num_classes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_classes")?;
+        let (num_events, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_events")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (events, remaining) = crate::x11_utils::parse_list::<EventForSend>(remaining, num_events.try_to_usize()?, Some("events"))?;
+        let (classes, remaining) = crate::x11_utils::parse_list::<EventClass>(remaining, num_classes.try_to_usize()?, Some("classes"))?;
         let _ = remaining;
         Ok(SendExtensionEventRequest {
             destination,
@@ -19377,6 +19673,7 @@ impl<'input> SendExtensionEventRequest<'input> {
 }
 impl<'input> Request for SendExtensionEventRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -169,7 +169,7 @@ impl core::fmt::Debug for EventType  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(EventType, u16);
+bitmask_binop!(EventType, u16, [NEW_KEYBOARD_NOTIFY, MAP_NOTIFY, STATE_NOTIFY, CONTROLS_NOTIFY, INDICATOR_STATE_NOTIFY, INDICATOR_MAP_NOTIFY, NAMES_NOTIFY, COMPAT_MAP_NOTIFY, BELL_NOTIFY, ACTION_MESSAGE, ACCESS_X_NOTIFY, EXTENSION_DEVICE_NOTIFY]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -225,7 +225,7 @@ impl core::fmt::Debug for NKNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(NKNDetail, u16);
+bitmask_binop!(NKNDetail, u16, [KEYCODES, GEOMETRY, DEVICE_ID]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -289,7 +289,7 @@ impl core::fmt::Debug for AXNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(AXNDetail, u16);
+bitmask_binop!(AXNDetail, u16, [SK_PRESS, SK_ACCEPT, SK_REJECT, SK_RELEASE, BK_ACCEPT, BK_REJECT, AXK_WARNING]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -355,7 +355,7 @@ impl core::fmt::Debug for MapPart  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(MapPart, u16);
+bitmask_binop!(MapPart, u16, [KEY_TYPES, KEY_SYMS, MODIFIER_MAP, EXPLICIT_COMPONENTS, KEY_ACTIONS, KEY_BEHAVIORS, VIRTUAL_MODS, VIRTUAL_MOD_MAP]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -409,7 +409,7 @@ impl core::fmt::Debug for SetMapFlags  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SetMapFlags, u16);
+bitmask_binop!(SetMapFlags, u16, [RESIZE_TYPES, RECOMPUTE_ACTIONS]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -487,7 +487,7 @@ impl core::fmt::Debug for StatePart  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(StatePart, u16);
+bitmask_binop!(StatePart, u16, [MODIFIER_STATE, MODIFIER_BASE, MODIFIER_LATCH, MODIFIER_LOCK, GROUP_STATE, GROUP_BASE, GROUP_LATCH, GROUP_LOCK, COMPAT_STATE, GRAB_MODS, COMPAT_GRAB_MODS, LOOKUP_MODS, COMPAT_LOOKUP_MODS, POINTER_BUTTONS]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -557,7 +557,7 @@ impl core::fmt::Debug for BoolCtrl  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(BoolCtrl, u32);
+bitmask_binop!(BoolCtrl, u32, [REPEAT_KEYS, SLOW_KEYS, BOUNCE_KEYS, STICKY_KEYS, MOUSE_KEYS, MOUSE_KEYS_ACCEL, ACCESS_X_KEYS, ACCESS_X_TIMEOUT_MASK, ACCESS_X_FEEDBACK_MASK, AUDIBLE_BELL_MASK, OVERLAY1_MASK, OVERLAY2_MASK, IGNORE_GROUP_LOCK_MASK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -611,7 +611,7 @@ impl core::fmt::Debug for Control  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(Control, u32);
+bitmask_binop!(Control, u32, [GROUPS_WRAP, INTERNAL_MODS, IGNORE_LOCK_MODS, PER_KEY_REPEAT, CONTROLS_ENABLED]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -685,7 +685,7 @@ impl core::fmt::Debug for AXOption  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(AXOption, u16);
+bitmask_binop!(AXOption, u16, [SK_PRESS_FB, SK_ACCEPT_FB, FEATURE_FB, SLOW_WARN_FB, INDICATOR_FB, STICKY_KEYS_FB, TWO_KEYS, LATCH_TO_LOCK, SK_RELEASE_FB, SK_REJECT_FB, BK_REJECT_FB, DUMB_BELL]);
 
 pub type DeviceSpec = u16;
 
@@ -1166,7 +1166,7 @@ impl core::fmt::Debug for SetOfGroup  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SetOfGroup, u8);
+bitmask_binop!(SetOfGroup, u8, [GROUP1, GROUP2, GROUP3, GROUP4]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1224,7 +1224,7 @@ impl core::fmt::Debug for SetOfGroups  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SetOfGroups, u8);
+bitmask_binop!(SetOfGroups, u8, [ANY]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1286,7 +1286,7 @@ impl core::fmt::Debug for GroupsWrap  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(GroupsWrap, u8);
+bitmask_binop!(GroupsWrap, u8, [CLAMP_INTO_RANGE, REDIRECT_INTO_RANGE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1358,7 +1358,7 @@ impl core::fmt::Debug for VModsHigh  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(VModsHigh, u8);
+bitmask_binop!(VModsHigh, u8, [M15, M14, M13, M12, M11, M10, M9, M8]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1430,7 +1430,7 @@ impl core::fmt::Debug for VModsLow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(VModsLow, u8);
+bitmask_binop!(VModsLow, u8, [M7, M6, M5, M4, M3, M2, M1, M0]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1512,7 +1512,7 @@ impl core::fmt::Debug for VMod  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(VMod, u16);
+bitmask_binop!(VMod, u16, [M15, M14, M13, M12, M11, M10, M9, M8, M7, M6, M5, M4, M3, M2, M1, M0]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1584,7 +1584,7 @@ impl core::fmt::Debug for Explicit  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Explicit, u8);
+bitmask_binop!(Explicit, u8, [V_MOD_MAP, BEHAVIOR, AUTO_REPEAT, INTERPRET, KEY_TYPE4, KEY_TYPE3, KEY_TYPE2, KEY_TYPE1]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1770,7 +1770,7 @@ impl core::fmt::Debug for IMFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(IMFlag, u8);
+bitmask_binop!(IMFlag, u8, [NO_EXPLICIT, NO_AUTOMATIC, LED_DRIVES_KB]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1836,7 +1836,7 @@ impl core::fmt::Debug for IMModsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(IMModsWhich, u8);
+bitmask_binop!(IMModsWhich, u8, [USE_COMPAT, USE_EFFECTIVE, USE_LOCKED, USE_LATCHED, USE_BASE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1902,7 +1902,7 @@ impl core::fmt::Debug for IMGroupsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(IMGroupsWhich, u8);
+bitmask_binop!(IMGroupsWhich, u8, [USE_COMPAT, USE_EFFECTIVE, USE_LOCKED, USE_LATCHED, USE_BASE]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1918,14 +1918,14 @@ pub struct IndicatorMap {
 }
 impl TryParse for IndicatorMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (which_groups, remaining) = u8::try_parse(remaining)?;
-        let (groups, remaining) = u8::try_parse(remaining)?;
-        let (which_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
-        let (ctrls, remaining) = u32::try_parse(remaining)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (which_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "which_groups")?;
+        let (groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups")?;
+        let (which_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "which_mods")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vmods")?;
+        let (ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ctrls")?;
         let flags = flags.into();
         let which_groups = which_groups.into();
         let groups = groups.into();
@@ -2035,7 +2035,7 @@ impl core::fmt::Debug for CMDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(CMDetail, u8);
+bitmask_binop!(CMDetail, u8, [SYM_INTERP, GROUP_COMPAT]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2107,7 +2107,7 @@ impl core::fmt::Debug for NameDetail  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(NameDetail, u32);
+bitmask_binop!(NameDetail, u32, [KEYCODES, GEOMETRY, SYMBOLS, PHYS_SYMBOLS, TYPES, COMPAT, KEY_TYPE_NAMES, KT_LEVEL_NAMES, INDICATOR_NAMES, KEY_NAMES, KEY_ALIASES, VIRTUAL_MOD_NAMES, GROUP_NAMES, RG_NAMES]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2173,7 +2173,7 @@ impl core::fmt::Debug for GBNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(GBNDetail, u16);
+bitmask_binop!(GBNDetail, u16, [TYPES, COMPAT_MAP, CLIENT_SYMBOLS, SERVER_SYMBOLS, INDICATOR_MAPS, KEY_NAMES, GEOMETRY, OTHER_NAMES]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2233,7 +2233,7 @@ impl core::fmt::Debug for XIFeature  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(XIFeature, u16);
+bitmask_binop!(XIFeature, u16, [KEYBOARDS, BUTTON_ACTIONS, INDICATOR_NAMES, INDICATOR_MAPS, INDICATOR_STATE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2287,7 +2287,7 @@ impl core::fmt::Debug for PerClientFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(PerClientFlag, u32);
+bitmask_binop!(PerClientFlag, u32, [DETECTABLE_AUTO_REPEAT, GRABS_USE_XKB_STATE, AUTO_RESET_CONTROLS, LOOKUP_STATE_WHEN_GRABBED, SEND_EVENT_USES_XKB_STATE]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2298,9 +2298,9 @@ pub struct ModDef {
 }
 impl TryParse for ModDef {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
+        let (mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mask")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vmods")?;
         let mask = mask.into();
         let real_mods = real_mods.into();
         let vmods = vmods.into();
@@ -2400,10 +2400,10 @@ pub struct CountedString16 {
 }
 impl TryParse for CountedString16 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (length, remaining) = u16::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_to_usize()?)?;
+        let (length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "length")?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, length.try_to_usize()?), "string")?;
         let string = string.to_vec();
-        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(length).checked_add(5u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (alignment_pad, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, (u32::from(length).checked_add(5u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "alignment_pad")?;
         let alignment_pad = alignment_pad.to_vec();
         let result = CountedString16 { string, alignment_pad };
         Ok((result, remaining))
@@ -2451,12 +2451,12 @@ pub struct KTMapEntry {
 }
 impl TryParse for KTMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (active, remaining) = bool::try_parse(remaining)?;
-        let (mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (mods_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (active, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "active")?;
+        let (mods_mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods_mask")?;
+        let (level, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "level")?;
+        let (mods_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods_mods")?;
+        let (mods_vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mods_vmods")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let mods_mask = mods_mask.into();
         let mods_mods = mods_mods.into();
         let mods_vmods = mods_vmods.into();
@@ -2507,15 +2507,15 @@ pub struct KeyType {
 }
 impl TryParse for KeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (mods_mods, remaining) = u8::try_parse(remaining)?;
-        let (mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (num_levels, remaining) = u8::try_parse(remaining)?;
-        let (n_map_entries, remaining) = u8::try_parse(remaining)?;
-        let (has_preserve, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (map, remaining) = crate::x11_utils::parse_list::<KTMapEntry>(remaining, n_map_entries.try_to_usize()?)?;
-        let (preserve, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(has_preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (mods_mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods_mask")?;
+        let (mods_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods_mods")?;
+        let (mods_vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mods_vmods")?;
+        let (num_levels, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_levels")?;
+        let (n_map_entries, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_map_entries")?;
+        let (has_preserve, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "has_preserve")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (map, remaining) = crate::x11_utils::parse_list::<KTMapEntry>(remaining, n_map_entries.try_to_usize()?, Some("map"))?;
+        let (preserve, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(has_preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("preserve"))?;
         let mods_mask = mods_mask.into();
         let mods_mods = mods_mods.into();
         let mods_vmods = mods_vmods.into();
@@ -2572,10 +2572,10 @@ pub struct KeySymMap {
 impl TryParse for KeySymMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (kt_index, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
-        let (group_info, remaining) = u8::try_parse(remaining)?;
-        let (width, remaining) = u8::try_parse(remaining)?;
-        let (n_syms, remaining) = u16::try_parse(remaining)?;
-        let (syms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, n_syms.try_to_usize()?)?;
+        let (group_info, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group_info")?;
+        let (width, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "width")?;
+        let (n_syms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_syms")?;
+        let (syms, remaining) = crate::x11_utils::parse_list::<xproto::Keysym>(remaining, n_syms.try_to_usize()?, Some("syms"))?;
         let result = KeySymMap { kt_index, group_info, width, syms };
         Ok((result, remaining))
     }
@@ -2621,8 +2621,8 @@ pub struct CommonBehavior {
 }
 impl TryParse for CommonBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (data, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (data, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "data")?;
         let result = CommonBehavior { type_, data };
         Ok((result, remaining))
     }
@@ -2651,8 +2651,8 @@ pub struct DefaultBehavior {
 }
 impl TryParse for DefaultBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = DefaultBehavior { type_ };
         Ok((result, remaining))
     }
@@ -2683,8 +2683,8 @@ pub struct RadioGroupBehavior {
 }
 impl TryParse for RadioGroupBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group")?;
         let result = RadioGroupBehavior { type_, group };
         Ok((result, remaining))
     }
@@ -2714,8 +2714,8 @@ pub struct OverlayBehavior {
 }
 impl TryParse for OverlayBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (key, remaining) = xproto::Keycode::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "key")?;
         let result = OverlayBehavior { type_, key };
         Ok((result, remaining))
     }
@@ -2749,7 +2749,7 @@ pub struct Behavior([u8; 2]);
 impl Behavior {
     pub fn as_common(&self) -> CommonBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<CommonBehavior, ParseError> {
-            let (common, remaining) = CommonBehavior::try_parse(remaining)?;
+            let (common, remaining) = crate::x11_utils::in_field(CommonBehavior::try_parse(remaining), "common")?;
             let _ = remaining;
             Ok(common)
         }
@@ -2757,7 +2757,7 @@ impl Behavior {
     }
     pub fn as_default(&self) -> DefaultBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<DefaultBehavior, ParseError> {
-            let (default, remaining) = DefaultBehavior::try_parse(remaining)?;
+            let (default, remaining) = crate::x11_utils::in_field(DefaultBehavior::try_parse(remaining), "default")?;
             let _ = remaining;
             Ok(default)
         }
@@ -2765,7 +2765,7 @@ impl Behavior {
     }
     pub fn as_lock(&self) -> LockBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<LockBehavior, ParseError> {
-            let (lock, remaining) = LockBehavior::try_parse(remaining)?;
+            let (lock, remaining) = crate::x11_utils::in_field(LockBehavior::try_parse(remaining), "lock")?;
             let _ = remaining;
             Ok(lock)
         }
@@ -2773,7 +2773,7 @@ impl Behavior {
     }
     pub fn as_radio_group(&self) -> RadioGroupBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<RadioGroupBehavior, ParseError> {
-            let (radio_group, remaining) = RadioGroupBehavior::try_parse(remaining)?;
+            let (radio_group, remaining) = crate::x11_utils::in_field(RadioGroupBehavior::try_parse(remaining), "radio_group")?;
             let _ = remaining;
             Ok(radio_group)
         }
@@ -2781,7 +2781,7 @@ impl Behavior {
     }
     pub fn as_overlay1(&self) -> OverlayBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
-            let (overlay1, remaining) = OverlayBehavior::try_parse(remaining)?;
+            let (overlay1, remaining) = crate::x11_utils::in_field(OverlayBehavior::try_parse(remaining), "overlay1")?;
             let _ = remaining;
             Ok(overlay1)
         }
@@ -2789,7 +2789,7 @@ impl Behavior {
     }
     pub fn as_overlay2(&self) -> OverlayBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
-            let (overlay2, remaining) = OverlayBehavior::try_parse(remaining)?;
+            let (overlay2, remaining) = crate::x11_utils::in_field(OverlayBehavior::try_parse(remaining), "overlay2")?;
             let _ = remaining;
             Ok(overlay2)
         }
@@ -2797,7 +2797,7 @@ impl Behavior {
     }
     pub fn as_permament_lock(&self) -> PermamentLockBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<PermamentLockBehavior, ParseError> {
-            let (permament_lock, remaining) = PermamentLockBehavior::try_parse(remaining)?;
+            let (permament_lock, remaining) = crate::x11_utils::in_field(PermamentLockBehavior::try_parse(remaining), "permament_lock")?;
             let _ = remaining;
             Ok(permament_lock)
         }
@@ -2805,7 +2805,7 @@ impl Behavior {
     }
     pub fn as_permament_radio_group(&self) -> PermamentRadioGroupBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<PermamentRadioGroupBehavior, ParseError> {
-            let (permament_radio_group, remaining) = PermamentRadioGroupBehavior::try_parse(remaining)?;
+            let (permament_radio_group, remaining) = crate::x11_utils::in_field(PermamentRadioGroupBehavior::try_parse(remaining), "permament_radio_group")?;
             let _ = remaining;
             Ok(permament_radio_group)
         }
@@ -2813,7 +2813,7 @@ impl Behavior {
     }
     pub fn as_permament_overlay1(&self) -> PermamentOverlayBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
-            let (permament_overlay1, remaining) = PermamentOverlayBehavior::try_parse(remaining)?;
+            let (permament_overlay1, remaining) = crate::x11_utils::in_field(PermamentOverlayBehavior::try_parse(remaining), "permament_overlay1")?;
             let _ = remaining;
             Ok(permament_overlay1)
         }
@@ -2821,7 +2821,7 @@ impl Behavior {
     }
     pub fn as_permament_overlay2(&self) -> PermamentOverlayBehavior {
         fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
-            let (permament_overlay2, remaining) = PermamentOverlayBehavior::try_parse(remaining)?;
+            let (permament_overlay2, remaining) = crate::x11_utils::in_field(PermamentOverlayBehavior::try_parse(remaining), "permament_overlay2")?;
             let _ = remaining;
             Ok(permament_overlay2)
         }
@@ -2829,7 +2829,7 @@ impl Behavior {
     }
     pub fn as_type(&self) -> u8 {
         fn do_the_parse(remaining: &[u8]) -> Result<u8, ParseError> {
-            let (type_, remaining) = u8::try_parse(remaining)?;
+            let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
             let _ = remaining;
             Ok(type_)
         }
@@ -2971,9 +2971,9 @@ pub struct SetBehavior {
 }
 impl TryParse for SetBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (behavior, remaining) = Behavior::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (behavior, remaining) = crate::x11_utils::in_field(Behavior::try_parse(remaining), "behavior")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = SetBehavior { keycode, behavior };
         Ok((result, remaining))
     }
@@ -3006,8 +3006,8 @@ pub struct SetExplicit {
 }
 impl TryParse for SetExplicit {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (explicit, remaining) = u8::try_parse(remaining)?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "explicit")?;
         let explicit = explicit.into();
         let result = SetExplicit { keycode, explicit };
         Ok((result, remaining))
@@ -3038,8 +3038,8 @@ pub struct KeyModMap {
 }
 impl TryParse for KeyModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
         let mods = mods.into();
         let result = KeyModMap { keycode, mods };
         Ok((result, remaining))
@@ -3070,9 +3070,9 @@ pub struct KeyVModMap {
 }
 impl TryParse for KeyVModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (vmods, remaining) = u16::try_parse(remaining)?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vmods")?;
         let vmods = vmods.into();
         let result = KeyVModMap { keycode, vmods };
         Ok((result, remaining))
@@ -3107,9 +3107,9 @@ pub struct KTSetMapEntry {
 }
 impl TryParse for KTSetMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (level, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (level, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "level")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let real_mods = real_mods.into();
         let virtual_mods = virtual_mods.into();
         let result = KTSetMapEntry { level, real_mods, virtual_mods };
@@ -3150,15 +3150,15 @@ pub struct SetKeyType {
 }
 impl TryParse for SetKeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (num_levels, remaining) = u8::try_parse(remaining)?;
-        let (n_map_entries, remaining) = u8::try_parse(remaining)?;
-        let (preserve, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, n_map_entries.try_to_usize()?)?;
-        let (preserve_entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, u32::from(preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mask")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
+        let (num_levels, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_levels")?;
+        let (n_map_entries, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_map_entries")?;
+        let (preserve, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "preserve")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, n_map_entries.try_to_usize()?, Some("entries"))?;
+        let (preserve_entries, remaining) = crate::x11_utils::parse_list::<KTSetMapEntry>(remaining, u32::from(preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("preserve_entries"))?;
         let mask = mask.into();
         let real_mods = real_mods.into();
         let virtual_mods = virtual_mods.into();
@@ -3214,10 +3214,10 @@ pub struct Outline {
 }
 impl TryParse for Outline {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (n_points, remaining) = u8::try_parse(remaining)?;
-        let (corner_radius, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (points, remaining) = crate::x11_utils::parse_list::<xproto::Point>(remaining, n_points.try_to_usize()?)?;
+        let (n_points, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_points")?;
+        let (corner_radius, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "corner_radius")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (points, remaining) = crate::x11_utils::parse_list::<xproto::Point>(remaining, n_points.try_to_usize()?, Some("points"))?;
         let result = Outline { corner_radius, points };
         Ok((result, remaining))
     }
@@ -3264,12 +3264,12 @@ pub struct Shape {
 }
 impl TryParse for Shape {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (n_outlines, remaining) = u8::try_parse(remaining)?;
-        let (primary_ndx, remaining) = u8::try_parse(remaining)?;
-        let (approx_ndx, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (outlines, remaining) = crate::x11_utils::parse_list::<Outline>(remaining, n_outlines.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (n_outlines, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_outlines")?;
+        let (primary_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "primary_ndx")?;
+        let (approx_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "approx_ndx")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (outlines, remaining) = crate::x11_utils::parse_list::<Outline>(remaining, n_outlines.try_to_usize()?, Some("outlines"))?;
         let result = Shape { name, primary_ndx, approx_ndx, outlines };
         Ok((result, remaining))
     }
@@ -3319,9 +3319,9 @@ pub struct Key {
 impl TryParse for Key {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
-        let (gap, remaining) = i16::try_parse(remaining)?;
-        let (shape_ndx, remaining) = u8::try_parse(remaining)?;
-        let (color_ndx, remaining) = u8::try_parse(remaining)?;
+        let (gap, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "gap")?;
+        let (shape_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "shape_ndx")?;
+        let (color_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "color_ndx")?;
         let result = Key { name, gap, shape_ndx, color_ndx };
         Ok((result, remaining))
     }
@@ -3395,10 +3395,10 @@ pub struct OverlayRow {
 }
 impl TryParse for OverlayRow {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (row_under, remaining) = u8::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<OverlayKey>(remaining, n_keys.try_to_usize()?)?;
+        let (row_under, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "row_under")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (keys, remaining) = crate::x11_utils::parse_list::<OverlayKey>(remaining, n_keys.try_to_usize()?, Some("keys"))?;
         let result = OverlayRow { row_under, keys };
         Ok((result, remaining))
     }
@@ -3443,10 +3443,10 @@ pub struct Overlay {
 }
 impl TryParse for Overlay {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (n_rows, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (rows, remaining) = crate::x11_utils::parse_list::<OverlayRow>(remaining, n_rows.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (n_rows, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_rows")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (rows, remaining) = crate::x11_utils::parse_list::<OverlayRow>(remaining, n_rows.try_to_usize()?, Some("rows"))?;
         let result = Overlay { name, rows };
         Ok((result, remaining))
     }
@@ -3493,12 +3493,12 @@ pub struct Row {
 }
 impl TryParse for Row {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (top, remaining) = i16::try_parse(remaining)?;
-        let (left, remaining) = i16::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (vertical, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (keys, remaining) = crate::x11_utils::parse_list::<Key>(remaining, n_keys.try_to_usize()?)?;
+        let (top, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "top")?;
+        let (left, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "left")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let (vertical, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "vertical")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (keys, remaining) = crate::x11_utils::parse_list::<Key>(remaining, n_keys.try_to_usize()?, Some("keys"))?;
         let result = Row { top, left, vertical, keys };
         Ok((result, remaining))
     }
@@ -3611,14 +3611,14 @@ pub struct Listing {
 impl TryParse for Listing {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (flags, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u16::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_to_usize()?)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "flags")?;
+        let (length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "length")?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, length.try_to_usize()?), "string")?;
         let string = string.to_vec();
         // Align offset to multiple of 2
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (2 - (offset % 2)) % 2;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = Listing { flags, string };
         Ok((result, remaining))
     }
@@ -3669,14 +3669,14 @@ pub struct DeviceLedInfo {
 }
 impl TryParse for DeviceLedInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
-        let (led_id, remaining) = IDSpec::try_parse(remaining)?;
-        let (names_present, remaining) = u32::try_parse(remaining)?;
-        let (maps_present, remaining) = u32::try_parse(remaining)?;
-        let (phys_indicators, remaining) = u32::try_parse(remaining)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(names_present).count_ones().try_to_usize()?)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(maps_present).count_ones().try_to_usize()?)?;
+        let (led_class, remaining) = crate::x11_utils::in_field(LedClassSpec::try_parse(remaining), "led_class")?;
+        let (led_id, remaining) = crate::x11_utils::in_field(IDSpec::try_parse(remaining), "led_id")?;
+        let (names_present, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "names_present")?;
+        let (maps_present, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "maps_present")?;
+        let (phys_indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "phys_indicators")?;
+        let (state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state")?;
+        let (names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(names_present).count_ones().try_to_usize()?, Some("names"))?;
+        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(maps_present).count_ones().try_to_usize()?, Some("maps"))?;
         let led_class = led_class.into();
         let result = DeviceLedInfo { led_class, led_id, names_present, maps_present, phys_indicators, state, names, maps };
         Ok((result, remaining))
@@ -3830,7 +3830,7 @@ impl core::fmt::Debug for SA  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SA, u8);
+bitmask_binop!(SA, u8, [CLEAR_LOCKS, LATCH_TO_LOCK, USE_MOD_MAP_MODS, GROUP_ABSOLUTE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -3936,8 +3936,8 @@ pub struct SANoAction {
 }
 impl TryParse for SANoAction {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 7)?;
         let type_ = type_.into();
         let result = SANoAction { type_ };
         Ok((result, remaining))
@@ -3977,13 +3977,13 @@ pub struct SASetMods {
 }
 impl TryParse for SASetMods {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mask")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (vmods_high, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_high")?;
+        let (vmods_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_low")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let type_ = type_.into();
         let flags = flags.into();
         let mask = mask.into();
@@ -4039,10 +4039,10 @@ pub struct SASetGroup {
 }
 impl TryParse for SASetGroup {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = i8::try_parse(remaining)?;
-        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (group, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "group")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 5)?;
         let type_ = type_.into();
         let flags = flags.into();
         let result = SASetGroup { type_, flags, group };
@@ -4139,7 +4139,7 @@ impl core::fmt::Debug for SAMovePtrFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SAMovePtrFlag, u8);
+bitmask_binop!(SAMovePtrFlag, u8, [NO_ACCELERATION, MOVE_ABSOLUTE_X, MOVE_ABSOLUTE_Y]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4153,13 +4153,13 @@ pub struct SAMovePtr {
 }
 impl TryParse for SAMovePtr {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (x_high, remaining) = i8::try_parse(remaining)?;
-        let (x_low, remaining) = u8::try_parse(remaining)?;
-        let (y_high, remaining) = i8::try_parse(remaining)?;
-        let (y_low, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (x_high, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "x_high")?;
+        let (x_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "x_low")?;
+        let (y_high, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "y_high")?;
+        let (y_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "y_low")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let type_ = type_.into();
         let flags = flags.into();
         let result = SAMovePtr { type_, flags, x_high, x_low, y_high, y_low };
@@ -4208,11 +4208,11 @@ pub struct SAPtrBtn {
 }
 impl TryParse for SAPtrBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let type_ = type_.into();
         let result = SAPtrBtn { type_, flags, count, button };
         Ok((result, remaining))
@@ -4255,11 +4255,11 @@ pub struct SALockPtrBtn {
 }
 impl TryParse for SALockPtrBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let type_ = type_.into();
         let result = SALockPtrBtn { type_, flags, button };
         Ok((result, remaining))
@@ -4350,7 +4350,7 @@ impl core::fmt::Debug for SASetPtrDfltFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SASetPtrDfltFlag, u8);
+bitmask_binop!(SASetPtrDfltFlag, u8, [DFLT_BTN_ABSOLUTE, AFFECT_DFLT_BUTTON]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4362,11 +4362,11 @@ pub struct SASetPtrDflt {
 }
 impl TryParse for SASetPtrDflt {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (affect, remaining) = u8::try_parse(remaining)?;
-        let (value, remaining) = i8::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (affect, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect")?;
+        let (value, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "value")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let type_ = type_.into();
         let flags = flags.into();
         let affect = affect.into();
@@ -4466,7 +4466,7 @@ impl core::fmt::Debug for SAIsoLockFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SAIsoLockFlag, u8);
+bitmask_binop!(SAIsoLockFlag, u8, [NO_LOCK, NO_UNLOCK, USE_MOD_MAP_MODS, GROUP_ABSOLUTE, ISO_DFLT_IS_GROUP]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4530,7 +4530,7 @@ impl core::fmt::Debug for SAIsoLockNoAffect  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SAIsoLockNoAffect, u8);
+bitmask_binop!(SAIsoLockNoAffect, u8, [CTRLS, PTR, GROUP, MODS]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4546,14 +4546,14 @@ pub struct SAIsoLock {
 }
 impl TryParse for SAIsoLock {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = i8::try_parse(remaining)?;
-        let (affect, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mask")?;
+        let (real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "group")?;
+        let (affect, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect")?;
+        let (vmods_high, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_high")?;
+        let (vmods_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_low")?;
         let type_ = type_.into();
         let flags = flags.into();
         let mask = mask.into();
@@ -4607,8 +4607,8 @@ pub struct SATerminate {
 }
 impl TryParse for SATerminate {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 7)?;
         let type_ = type_.into();
         let result = SATerminate { type_ };
         Ok((result, remaining))
@@ -4694,7 +4694,7 @@ impl core::fmt::Debug for SwitchScreenFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(SwitchScreenFlag, u8);
+bitmask_binop!(SwitchScreenFlag, u8, [APPLICATION, ABSOLUTE]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4705,10 +4705,10 @@ pub struct SASwitchScreen {
 }
 impl TryParse for SASwitchScreen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (new_screen, remaining) = i8::try_parse(remaining)?;
-        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (new_screen, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "new_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 5)?;
         let type_ = type_.into();
         let result = SASwitchScreen { type_, flags, new_screen };
         Ok((result, remaining))
@@ -4804,7 +4804,7 @@ impl core::fmt::Debug for BoolCtrlsHigh  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(BoolCtrlsHigh, u8);
+bitmask_binop!(BoolCtrlsHigh, u8, [ACCESS_X_FEEDBACK, AUDIBLE_BELL, OVERLAY1, OVERLAY2, IGNORE_GROUP_LOCK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4876,7 +4876,7 @@ impl core::fmt::Debug for BoolCtrlsLow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(BoolCtrlsLow, u8);
+bitmask_binop!(BoolCtrlsLow, u8, [REPEAT_KEYS, SLOW_KEYS, BOUNCE_KEYS, STICKY_KEYS, MOUSE_KEYS, MOUSE_KEYS_ACCEL, ACCESS_X_KEYS, ACCESS_X_TIMEOUT]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4887,11 +4887,11 @@ pub struct SASetControls {
 }
 impl TryParse for SASetControls {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (bool_ctrls_high, remaining) = u8::try_parse(remaining)?;
-        let (bool_ctrls_low, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (bool_ctrls_high, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bool_ctrls_high")?;
+        let (bool_ctrls_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bool_ctrls_low")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let type_ = type_.into();
         let bool_ctrls_high = bool_ctrls_high.into();
         let bool_ctrls_low = bool_ctrls_low.into();
@@ -4988,7 +4988,7 @@ impl core::fmt::Debug for ActionMessageFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ActionMessageFlag, u8);
+bitmask_binop!(ActionMessageFlag, u8, [ON_PRESS, ON_RELEASE, GEN_KEY_EVENT]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -4999,8 +4999,8 @@ pub struct SAActionMessage {
 }
 impl TryParse for SAActionMessage {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
         let (message, remaining) = crate::x11_utils::parse_u8_array::<6>(remaining)?;
         let type_ = type_.into();
         let flags = flags.into();
@@ -5046,14 +5046,14 @@ pub struct SARedirectKey {
 }
 impl TryParse for SARedirectKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (newkey, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (mask, remaining) = u8::try_parse(remaining)?;
-        let (real_modifiers, remaining) = u8::try_parse(remaining)?;
-        let (vmods_mask_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_mask_low, remaining) = u8::try_parse(remaining)?;
-        let (vmods_high, remaining) = u8::try_parse(remaining)?;
-        let (vmods_low, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (newkey, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "newkey")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mask")?;
+        let (real_modifiers, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "real_modifiers")?;
+        let (vmods_mask_high, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_mask_high")?;
+        let (vmods_mask_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_mask_low")?;
+        let (vmods_high, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_high")?;
+        let (vmods_low, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vmods_low")?;
         let type_ = type_.into();
         let mask = mask.into();
         let real_modifiers = real_modifiers.into();
@@ -5111,12 +5111,12 @@ pub struct SADeviceBtn {
 }
 impl TryParse for SADeviceBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let (device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let type_ = type_.into();
         let result = SADeviceBtn { type_, flags, count, button, device };
         Ok((result, remaining))
@@ -5210,7 +5210,7 @@ impl core::fmt::Debug for LockDeviceFlags  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(LockDeviceFlags, u8);
+bitmask_binop!(LockDeviceFlags, u8, [NO_LOCK, NO_UNLOCK]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -5222,12 +5222,12 @@ pub struct SALockDeviceBtn {
 }
 impl TryParse for SALockDeviceBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
+        let (device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let type_ = type_.into();
         let flags = flags.into();
         let result = SALockDeviceBtn { type_, flags, button, device };
@@ -5344,14 +5344,14 @@ pub struct SADeviceValuator {
 }
 impl TryParse for SADeviceValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (device, remaining) = u8::try_parse(remaining)?;
-        let (val1what, remaining) = u8::try_parse(remaining)?;
-        let (val1index, remaining) = u8::try_parse(remaining)?;
-        let (val1value, remaining) = u8::try_parse(remaining)?;
-        let (val2what, remaining) = u8::try_parse(remaining)?;
-        let (val2index, remaining) = u8::try_parse(remaining)?;
-        let (val2value, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (device, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device")?;
+        let (val1what, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val1what")?;
+        let (val1index, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val1index")?;
+        let (val1value, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val1value")?;
+        let (val2what, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val2what")?;
+        let (val2index, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val2index")?;
+        let (val2value, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "val2value")?;
         let type_ = type_.into();
         let val1what = val1what.into();
         let val2what = val2what.into();
@@ -5402,7 +5402,7 @@ pub struct SIAction {
 }
 impl TryParse for SIAction {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (type_, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
         let (data, remaining) = crate::x11_utils::parse_u8_array::<7>(remaining)?;
         let type_ = type_.into();
         let result = SIAction { type_, data };
@@ -5443,12 +5443,12 @@ pub struct SymInterpret {
 }
 impl TryParse for SymInterpret {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (sym, remaining) = xproto::Keysym::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (match_, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mod, remaining) = u8::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let (action, remaining) = SIAction::try_parse(remaining)?;
+        let (sym, remaining) = crate::x11_utils::in_field(xproto::Keysym::try_parse(remaining), "sym")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
+        let (match_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "match_")?;
+        let (virtual_mod, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "virtual_mod")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let (action, remaining) = crate::x11_utils::in_field(SIAction::try_parse(remaining), "action")?;
         let mods = mods.into();
         let virtual_mod = virtual_mod.into();
         let result = SymInterpret { sym, mods, match_, virtual_mod, flags, action };
@@ -5500,7 +5500,7 @@ pub struct Action([u8; 8]);
 impl Action {
     pub fn as_noaction(&self) -> SANoAction {
         fn do_the_parse(remaining: &[u8]) -> Result<SANoAction, ParseError> {
-            let (noaction, remaining) = SANoAction::try_parse(remaining)?;
+            let (noaction, remaining) = crate::x11_utils::in_field(SANoAction::try_parse(remaining), "noaction")?;
             let _ = remaining;
             Ok(noaction)
         }
@@ -5508,7 +5508,7 @@ impl Action {
     }
     pub fn as_setmods(&self) -> SASetMods {
         fn do_the_parse(remaining: &[u8]) -> Result<SASetMods, ParseError> {
-            let (setmods, remaining) = SASetMods::try_parse(remaining)?;
+            let (setmods, remaining) = crate::x11_utils::in_field(SASetMods::try_parse(remaining), "setmods")?;
             let _ = remaining;
             Ok(setmods)
         }
@@ -5516,7 +5516,7 @@ impl Action {
     }
     pub fn as_latchmods(&self) -> SALatchMods {
         fn do_the_parse(remaining: &[u8]) -> Result<SALatchMods, ParseError> {
-            let (latchmods, remaining) = SALatchMods::try_parse(remaining)?;
+            let (latchmods, remaining) = crate::x11_utils::in_field(SALatchMods::try_parse(remaining), "latchmods")?;
             let _ = remaining;
             Ok(latchmods)
         }
@@ -5524,7 +5524,7 @@ impl Action {
     }
     pub fn as_lockmods(&self) -> SALockMods {
         fn do_the_parse(remaining: &[u8]) -> Result<SALockMods, ParseError> {
-            let (lockmods, remaining) = SALockMods::try_parse(remaining)?;
+            let (lockmods, remaining) = crate::x11_utils::in_field(SALockMods::try_parse(remaining), "lockmods")?;
             let _ = remaining;
             Ok(lockmods)
         }
@@ -5532,7 +5532,7 @@ impl Action {
     }
     pub fn as_setgroup(&self) -> SASetGroup {
         fn do_the_parse(remaining: &[u8]) -> Result<SASetGroup, ParseError> {
-            let (setgroup, remaining) = SASetGroup::try_parse(remaining)?;
+            let (setgroup, remaining) = crate::x11_utils::in_field(SASetGroup::try_parse(remaining), "setgroup")?;
             let _ = remaining;
             Ok(setgroup)
         }
@@ -5540,7 +5540,7 @@ impl Action {
     }
     pub fn as_latchgroup(&self) -> SALatchGroup {
         fn do_the_parse(remaining: &[u8]) -> Result<SALatchGroup, ParseError> {
-            let (latchgroup, remaining) = SALatchGroup::try_parse(remaining)?;
+            let (latchgroup, remaining) = crate::x11_utils::in_field(SALatchGroup::try_parse(remaining), "latchgroup")?;
             let _ = remaining;
             Ok(latchgroup)
         }
@@ -5548,7 +5548,7 @@ impl Action {
     }
     pub fn as_lockgroup(&self) -> SALockGroup {
         fn do_the_parse(remaining: &[u8]) -> Result<SALockGroup, ParseError> {
-            let (lockgroup, remaining) = SALockGroup::try_parse(remaining)?;
+            let (lockgroup, remaining) = crate::x11_utils::in_field(SALockGroup::try_parse(remaining), "lockgroup")?;
             let _ = remaining;
             Ok(lockgroup)
         }
@@ -5556,7 +5556,7 @@ impl Action {
     }
     pub fn as_moveptr(&self) -> SAMovePtr {
         fn do_the_parse(remaining: &[u8]) -> Result<SAMovePtr, ParseError> {
-            let (moveptr, remaining) = SAMovePtr::try_parse(remaining)?;
+            let (moveptr, remaining) = crate::x11_utils::in_field(SAMovePtr::try_parse(remaining), "moveptr")?;
             let _ = remaining;
             Ok(moveptr)
         }
@@ -5564,7 +5564,7 @@ impl Action {
     }
     pub fn as_ptrbtn(&self) -> SAPtrBtn {
         fn do_the_parse(remaining: &[u8]) -> Result<SAPtrBtn, ParseError> {
-            let (ptrbtn, remaining) = SAPtrBtn::try_parse(remaining)?;
+            let (ptrbtn, remaining) = crate::x11_utils::in_field(SAPtrBtn::try_parse(remaining), "ptrbtn")?;
             let _ = remaining;
             Ok(ptrbtn)
         }
@@ -5572,7 +5572,7 @@ impl Action {
     }
     pub fn as_lockptrbtn(&self) -> SALockPtrBtn {
         fn do_the_parse(remaining: &[u8]) -> Result<SALockPtrBtn, ParseError> {
-            let (lockptrbtn, remaining) = SALockPtrBtn::try_parse(remaining)?;
+            let (lockptrbtn, remaining) = crate::x11_utils::in_field(SALockPtrBtn::try_parse(remaining), "lockptrbtn")?;
             let _ = remaining;
             Ok(lockptrbtn)
         }
@@ -5580,7 +5580,7 @@ impl Action {
     }
     pub fn as_setptrdflt(&self) -> SASetPtrDflt {
         fn do_the_parse(remaining: &[u8]) -> Result<SASetPtrDflt, ParseError> {
-            let (setptrdflt, remaining) = SASetPtrDflt::try_parse(remaining)?;
+            let (setptrdflt, remaining) = crate::x11_utils::in_field(SASetPtrDflt::try_parse(remaining), "setptrdflt")?;
             let _ = remaining;
             Ok(setptrdflt)
         }
@@ -5588,7 +5588,7 @@ impl Action {
     }
     pub fn as_isolock(&self) -> SAIsoLock {
         fn do_the_parse(remaining: &[u8]) -> Result<SAIsoLock, ParseError> {
-            let (isolock, remaining) = SAIsoLock::try_parse(remaining)?;
+            let (isolock, remaining) = crate::x11_utils::in_field(SAIsoLock::try_parse(remaining), "isolock")?;
             let _ = remaining;
             Ok(isolock)
         }
@@ -5596,7 +5596,7 @@ impl Action {
     }
     pub fn as_terminate(&self) -> SATerminate {
         fn do_the_parse(remaining: &[u8]) -> Result<SATerminate, ParseError> {
-            let (terminate, remaining) = SATerminate::try_parse(remaining)?;
+            let (terminate, remaining) = crate::x11_utils::in_field(SATerminate::try_parse(remaining), "terminate")?;
             let _ = remaining;
             Ok(terminate)
         }
@@ -5604,7 +5604,7 @@ impl Action {
     }
     pub fn as_switchscreen(&self) -> SASwitchScreen {
         fn do_the_parse(remaining: &[u8]) -> Result<SASwitchScreen, ParseError> {
-            let (switchscreen, remaining) = SASwitchScreen::try_parse(remaining)?;
+            let (switchscreen, remaining) = crate::x11_utils::in_field(SASwitchScreen::try_parse(remaining), "switchscreen")?;
             let _ = remaining;
             Ok(switchscreen)
         }
@@ -5612,7 +5612,7 @@ impl Action {
     }
     pub fn as_setcontrols(&self) -> SASetControls {
         fn do_the_parse(remaining: &[u8]) -> Result<SASetControls, ParseError> {
-            let (setcontrols, remaining) = SASetControls::try_parse(remaining)?;
+            let (setcontrols, remaining) = crate::x11_utils::in_field(SASetControls::try_parse(remaining), "setcontrols")?;
             let _ = remaining;
             Ok(setcontrols)
         }
@@ -5620,7 +5620,7 @@ impl Action {
     }
     pub fn as_lockcontrols(&self) -> SALockControls {
         fn do_the_parse(remaining: &[u8]) -> Result<SALockControls, ParseError> {
-            let (lockcontrols, remaining) = SALockControls::try_parse(remaining)?;
+            let (lockcontrols, remaining) = crate::x11_utils::in_field(SALockControls::try_parse(remaining), "lockcontrols")?;
             let _ = remaining;
             Ok(lockcontrols)
         }
@@ -5628,7 +5628,7 @@ impl Action {
     }
     pub fn as_message(&self) -> SAActionMessage {
         fn do_the_parse(remaining: &[u8]) -> Result<SAActionMessage, ParseError> {
-            let (message, remaining) = SAActionMessage::try_parse(remaining)?;
+            let (message, remaining) = crate::x11_utils::in_field(SAActionMessage::try_parse(remaining), "message")?;
             let _ = remaining;
             Ok(message)
         }
@@ -5636,7 +5636,7 @@ impl Action {
     }
     pub fn as_redirect(&self) -> SARedirectKey {
         fn do_the_parse(remaining: &[u8]) -> Result<SARedirectKey, ParseError> {
-            let (redirect, remaining) = SARedirectKey::try_parse(remaining)?;
+            let (redirect, remaining) = crate::x11_utils::in_field(SARedirectKey::try_parse(remaining), "redirect")?;
             let _ = remaining;
             Ok(redirect)
         }
@@ -5644,7 +5644,7 @@ impl Action {
     }
     pub fn as_devbtn(&self) -> SADeviceBtn {
         fn do_the_parse(remaining: &[u8]) -> Result<SADeviceBtn, ParseError> {
-            let (devbtn, remaining) = SADeviceBtn::try_parse(remaining)?;
+            let (devbtn, remaining) = crate::x11_utils::in_field(SADeviceBtn::try_parse(remaining), "devbtn")?;
             let _ = remaining;
             Ok(devbtn)
         }
@@ -5652,7 +5652,7 @@ impl Action {
     }
     pub fn as_lockdevbtn(&self) -> SALockDeviceBtn {
         fn do_the_parse(remaining: &[u8]) -> Result<SALockDeviceBtn, ParseError> {
-            let (lockdevbtn, remaining) = SALockDeviceBtn::try_parse(remaining)?;
+            let (lockdevbtn, remaining) = crate::x11_utils::in_field(SALockDeviceBtn::try_parse(remaining), "lockdevbtn")?;
             let _ = remaining;
             Ok(lockdevbtn)
         }
@@ -5660,7 +5660,7 @@ impl Action {
     }
     pub fn as_devval(&self) -> SADeviceValuator {
         fn do_the_parse(remaining: &[u8]) -> Result<SADeviceValuator, ParseError> {
-            let (devval, remaining) = SADeviceValuator::try_parse(remaining)?;
+            let (devval, remaining) = crate::x11_utils::in_field(SADeviceValuator::try_parse(remaining), "devval")?;
             let _ = remaining;
             Ok(devval)
         }
@@ -5668,7 +5668,7 @@ impl Action {
     }
     pub fn as_type(&self) -> SAType {
         fn do_the_parse(remaining: &[u8]) -> Result<SAType, ParseError> {
-            let (type_, remaining) = u8::try_parse(remaining)?;
+            let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
             let type_ = type_.into();
             let _ = remaining;
             Ok(type_)
@@ -5843,8 +5843,8 @@ impl UseExtensionRequest {
         if header.minor_opcode != USE_EXTENSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (wanted_major, remaining) = u16::try_parse(value)?;
-        let (wanted_minor, remaining) = u16::try_parse(remaining)?;
+        let (wanted_major, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "wanted_major")?;
+        let (wanted_minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "wanted_minor")?;
         let _ = remaining;
         Ok(UseExtensionRequest {
             wanted_major,
@@ -5854,6 +5854,7 @@ impl UseExtensionRequest {
 }
 impl Request for UseExtensionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -5878,20 +5879,19 @@ pub struct UseExtensionReply {
 impl TryParse for UseExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (supported, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (supported, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "supported")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major")?;
+        let (server_minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = UseExtensionReply { supported, sequence, length, server_major, server_minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -5960,8 +5960,8 @@ pub struct SelectEventsAuxNewKeyboardNotify {
 }
 impl TryParse for SelectEventsAuxNewKeyboardNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_new_keyboard, remaining) = u16::try_parse(remaining)?;
-        let (new_keyboard_details, remaining) = u16::try_parse(remaining)?;
+        let (affect_new_keyboard, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_new_keyboard")?;
+        let (new_keyboard_details, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "new_keyboard_details")?;
         let affect_new_keyboard = affect_new_keyboard.into();
         let new_keyboard_details = new_keyboard_details.into();
         let result = SelectEventsAuxNewKeyboardNotify { affect_new_keyboard, new_keyboard_details };
@@ -5994,8 +5994,8 @@ pub struct SelectEventsAuxStateNotify {
 }
 impl TryParse for SelectEventsAuxStateNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_state, remaining) = u16::try_parse(remaining)?;
-        let (state_details, remaining) = u16::try_parse(remaining)?;
+        let (affect_state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_state")?;
+        let (state_details, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state_details")?;
         let affect_state = affect_state.into();
         let state_details = state_details.into();
         let result = SelectEventsAuxStateNotify { affect_state, state_details };
@@ -6028,8 +6028,8 @@ pub struct SelectEventsAuxControlsNotify {
 }
 impl TryParse for SelectEventsAuxControlsNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (ctrl_details, remaining) = u32::try_parse(remaining)?;
+        let (affect_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_ctrls")?;
+        let (ctrl_details, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ctrl_details")?;
         let affect_ctrls = affect_ctrls.into();
         let ctrl_details = ctrl_details.into();
         let result = SelectEventsAuxControlsNotify { affect_ctrls, ctrl_details };
@@ -6066,8 +6066,8 @@ pub struct SelectEventsAuxIndicatorStateNotify {
 }
 impl TryParse for SelectEventsAuxIndicatorStateNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_indicator_state, remaining) = u32::try_parse(remaining)?;
-        let (indicator_state_details, remaining) = u32::try_parse(remaining)?;
+        let (affect_indicator_state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_indicator_state")?;
+        let (indicator_state_details, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicator_state_details")?;
         let result = SelectEventsAuxIndicatorStateNotify { affect_indicator_state, indicator_state_details };
         Ok((result, remaining))
     }
@@ -6102,8 +6102,8 @@ pub struct SelectEventsAuxIndicatorMapNotify {
 }
 impl TryParse for SelectEventsAuxIndicatorMapNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_indicator_map, remaining) = u32::try_parse(remaining)?;
-        let (indicator_map_details, remaining) = u32::try_parse(remaining)?;
+        let (affect_indicator_map, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_indicator_map")?;
+        let (indicator_map_details, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicator_map_details")?;
         let result = SelectEventsAuxIndicatorMapNotify { affect_indicator_map, indicator_map_details };
         Ok((result, remaining))
     }
@@ -6138,8 +6138,8 @@ pub struct SelectEventsAuxNamesNotify {
 }
 impl TryParse for SelectEventsAuxNamesNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_names, remaining) = u16::try_parse(remaining)?;
-        let (names_details, remaining) = u16::try_parse(remaining)?;
+        let (affect_names, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_names")?;
+        let (names_details, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_details")?;
         let affect_names = affect_names.into();
         let names_details = names_details.into();
         let result = SelectEventsAuxNamesNotify { affect_names, names_details };
@@ -6172,8 +6172,8 @@ pub struct SelectEventsAuxCompatMapNotify {
 }
 impl TryParse for SelectEventsAuxCompatMapNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_compat, remaining) = u8::try_parse(remaining)?;
-        let (compat_details, remaining) = u8::try_parse(remaining)?;
+        let (affect_compat, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_compat")?;
+        let (compat_details, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_details")?;
         let affect_compat = affect_compat.into();
         let compat_details = compat_details.into();
         let result = SelectEventsAuxCompatMapNotify { affect_compat, compat_details };
@@ -6204,8 +6204,8 @@ pub struct SelectEventsAuxBellNotify {
 }
 impl TryParse for SelectEventsAuxBellNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_bell, remaining) = u8::try_parse(remaining)?;
-        let (bell_details, remaining) = u8::try_parse(remaining)?;
+        let (affect_bell, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_bell")?;
+        let (bell_details, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bell_details")?;
         let result = SelectEventsAuxBellNotify { affect_bell, bell_details };
         Ok((result, remaining))
     }
@@ -6234,8 +6234,8 @@ pub struct SelectEventsAuxActionMessage {
 }
 impl TryParse for SelectEventsAuxActionMessage {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_msg_details, remaining) = u8::try_parse(remaining)?;
-        let (msg_details, remaining) = u8::try_parse(remaining)?;
+        let (affect_msg_details, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_msg_details")?;
+        let (msg_details, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "msg_details")?;
         let result = SelectEventsAuxActionMessage { affect_msg_details, msg_details };
         Ok((result, remaining))
     }
@@ -6264,8 +6264,8 @@ pub struct SelectEventsAuxAccessXNotify {
 }
 impl TryParse for SelectEventsAuxAccessXNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_access_x, remaining) = u16::try_parse(remaining)?;
-        let (access_x_details, remaining) = u16::try_parse(remaining)?;
+        let (affect_access_x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_access_x")?;
+        let (access_x_details, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_details")?;
         let affect_access_x = affect_access_x.into();
         let access_x_details = access_x_details.into();
         let result = SelectEventsAuxAccessXNotify { affect_access_x, access_x_details };
@@ -6298,8 +6298,8 @@ pub struct SelectEventsAuxExtensionDeviceNotify {
 }
 impl TryParse for SelectEventsAuxExtensionDeviceNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (affect_ext_dev, remaining) = u16::try_parse(remaining)?;
-        let (extdev_details, remaining) = u16::try_parse(remaining)?;
+        let (affect_ext_dev, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_ext_dev")?;
+        let (extdev_details, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "extdev_details")?;
         let affect_ext_dev = affect_ext_dev.into();
         let extdev_details = extdev_details.into();
         let result = SelectEventsAuxExtensionDeviceNotify { affect_ext_dev, extdev_details };
@@ -6637,15 +6637,15 @@ impl<'input> SelectEventsRequest<'input> {
         if header.minor_opcode != SELECT_EVENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (affect_which, remaining) = u16::try_parse(remaining)?;
-        let (clear, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (affect_which, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_which")?;
+        let (clear, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "clear")?;
         let clear = clear.into();
-        let (select_all, remaining) = u16::try_parse(remaining)?;
+        let (select_all, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "select_all")?;
         let select_all = select_all.into();
-        let (affect_map, remaining) = u16::try_parse(remaining)?;
+        let (affect_map, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_map")?;
         let affect_map = affect_map.into();
-        let (map, remaining) = u16::try_parse(remaining)?;
+        let (map, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "map")?;
         let map = map.into();
         let (details, remaining) = SelectEventsAux::try_parse(remaining, u16::from(affect_which), u16::from(clear), u16::from(select_all))?;
         let _ = remaining;
@@ -6672,6 +6672,7 @@ impl<'input> SelectEventsRequest<'input> {
 }
 impl<'input> Request for SelectEventsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6754,18 +6755,18 @@ impl BellRequest {
         if header.minor_opcode != BELL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (bell_class, remaining) = BellClassSpec::try_parse(remaining)?;
-        let (bell_id, remaining) = IDSpec::try_parse(remaining)?;
-        let (percent, remaining) = i8::try_parse(remaining)?;
-        let (force_sound, remaining) = bool::try_parse(remaining)?;
-        let (event_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (pitch, remaining) = i16::try_parse(remaining)?;
-        let (duration, remaining) = i16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (bell_class, remaining) = crate::x11_utils::in_field(BellClassSpec::try_parse(remaining), "bell_class")?;
+        let (bell_id, remaining) = crate::x11_utils::in_field(IDSpec::try_parse(remaining), "bell_id")?;
+        let (percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "percent")?;
+        let (force_sound, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "force_sound")?;
+        let (event_only, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "event_only")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (pitch, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "duration")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
         let _ = remaining;
         Ok(BellRequest {
             device_spec,
@@ -6783,6 +6784,7 @@ impl BellRequest {
 }
 impl Request for BellRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6827,8 +6829,8 @@ impl GetStateRequest {
         if header.minor_opcode != GET_STATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetStateRequest {
             device_spec,
@@ -6837,6 +6839,7 @@ impl GetStateRequest {
 }
 impl Request for GetStateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -6873,28 +6876,28 @@ pub struct GetStateReply {
 impl TryParse for GetStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (base_mods, remaining) = u8::try_parse(remaining)?;
-        let (latched_mods, remaining) = u8::try_parse(remaining)?;
-        let (locked_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
-        let (locked_group, remaining) = u8::try_parse(remaining)?;
-        let (base_group, remaining) = i16::try_parse(remaining)?;
-        let (latched_group, remaining) = i16::try_parse(remaining)?;
-        let (compat_state, remaining) = u8::try_parse(remaining)?;
-        let (grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (lookup_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_lookup_mods, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (ptr_btn_state, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
+        let (base_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "base_mods")?;
+        let (latched_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "latched_mods")?;
+        let (locked_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "locked_mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group")?;
+        let (locked_group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "locked_group")?;
+        let (base_group, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "base_group")?;
+        let (latched_group, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "latched_group")?;
+        let (compat_state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_state")?;
+        let (grab_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grab_mods")?;
+        let (compat_grab_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_grab_mods")?;
+        let (lookup_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "lookup_mods")?;
+        let (compat_lookup_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_lookup_mods")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (ptr_btn_state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "ptr_btn_state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 6)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let mods = mods.into();
         let base_mods = base_mods.into();
@@ -6910,8 +6913,7 @@ impl TryParse for GetStateReply {
         let ptr_btn_state = ptr_btn_state.into();
         let result = GetStateReply { device_id, sequence, length, mods, base_mods, latched_mods, locked_mods, group, locked_group, base_group, latched_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_lookup_mods, ptr_btn_state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7052,20 +7054,20 @@ impl LatchLockStateRequest {
         if header.minor_opcode != LATCH_LOCK_STATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (affect_mod_locks, remaining) = u8::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (affect_mod_locks, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_mod_locks")?;
         let affect_mod_locks = affect_mod_locks.into();
-        let (mod_locks, remaining) = u8::try_parse(remaining)?;
+        let (mod_locks, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mod_locks")?;
         let mod_locks = mod_locks.into();
-        let (lock_group, remaining) = bool::try_parse(remaining)?;
-        let (group_lock, remaining) = u8::try_parse(remaining)?;
+        let (lock_group, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "lock_group")?;
+        let (group_lock, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group_lock")?;
         let group_lock = group_lock.into();
-        let (affect_mod_latches, remaining) = u8::try_parse(remaining)?;
+        let (affect_mod_latches, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_mod_latches")?;
         let affect_mod_latches = affect_mod_latches.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (latch_group, remaining) = bool::try_parse(remaining)?;
-        let (group_latch, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (latch_group, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "latch_group")?;
+        let (group_latch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "group_latch")?;
         let _ = remaining;
         Ok(LatchLockStateRequest {
             device_spec,
@@ -7081,6 +7083,7 @@ impl LatchLockStateRequest {
 }
 impl Request for LatchLockStateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7125,8 +7128,8 @@ impl GetControlsRequest {
         if header.minor_opcode != GET_CONTROLS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetControlsRequest {
             device_spec,
@@ -7135,6 +7138,7 @@ impl GetControlsRequest {
 }
 impl Request for GetControlsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7183,40 +7187,40 @@ pub struct GetControlsReply {
 impl TryParse for GetControlsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (mouse_keys_dflt_btn, remaining) = u8::try_parse(remaining)?;
-        let (num_groups, remaining) = u8::try_parse(remaining)?;
-        let (groups_wrap, remaining) = u8::try_parse(remaining)?;
-        let (internal_mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (ignore_lock_mods_mask, remaining) = u8::try_parse(remaining)?;
-        let (internal_mods_real_mods, remaining) = u8::try_parse(remaining)?;
-        let (ignore_lock_mods_real_mods, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (internal_mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (ignore_lock_mods_vmods, remaining) = u16::try_parse(remaining)?;
-        let (repeat_delay, remaining) = u16::try_parse(remaining)?;
-        let (repeat_interval, remaining) = u16::try_parse(remaining)?;
-        let (slow_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (debounce_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_interval, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_time_to_max, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_max_speed, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_curve, remaining) = i16::try_parse(remaining)?;
-        let (access_x_option, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout_options_mask, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout_options_values, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (access_x_timeout_mask, remaining) = u32::try_parse(remaining)?;
-        let (access_x_timeout_values, remaining) = u32::try_parse(remaining)?;
-        let (enabled_controls, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (mouse_keys_dflt_btn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mouse_keys_dflt_btn")?;
+        let (num_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_groups")?;
+        let (groups_wrap, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups_wrap")?;
+        let (internal_mods_mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "internal_mods_mask")?;
+        let (ignore_lock_mods_mask, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ignore_lock_mods_mask")?;
+        let (internal_mods_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "internal_mods_real_mods")?;
+        let (ignore_lock_mods_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ignore_lock_mods_real_mods")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (internal_mods_vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "internal_mods_vmods")?;
+        let (ignore_lock_mods_vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "ignore_lock_mods_vmods")?;
+        let (repeat_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "repeat_delay")?;
+        let (repeat_interval, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "repeat_interval")?;
+        let (slow_keys_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "slow_keys_delay")?;
+        let (debounce_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "debounce_delay")?;
+        let (mouse_keys_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_delay")?;
+        let (mouse_keys_interval, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_interval")?;
+        let (mouse_keys_time_to_max, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_time_to_max")?;
+        let (mouse_keys_max_speed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_max_speed")?;
+        let (mouse_keys_curve, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "mouse_keys_curve")?;
+        let (access_x_option, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_option")?;
+        let (access_x_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout")?;
+        let (access_x_timeout_options_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout_options_mask")?;
+        let (access_x_timeout_options_values, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout_options_values")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (access_x_timeout_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "access_x_timeout_mask")?;
+        let (access_x_timeout_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "access_x_timeout_values")?;
+        let (enabled_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "enabled_controls")?;
         let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let internal_mods_mask = internal_mods_mask.into();
         let ignore_lock_mods_mask = ignore_lock_mods_mask.into();
@@ -7232,8 +7236,7 @@ impl TryParse for GetControlsReply {
         let enabled_controls = enabled_controls.into();
         let result = GetControlsReply { device_id, sequence, length, mouse_keys_dflt_btn, num_groups, groups_wrap, internal_mods_mask, ignore_lock_mods_mask, internal_mods_real_mods, ignore_lock_mods_real_mods, internal_mods_vmods, ignore_lock_mods_vmods, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_option, access_x_timeout, access_x_timeout_options_mask, access_x_timeout_options_values, access_x_timeout_mask, access_x_timeout_values, enabled_controls, per_key_repeat };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -7553,51 +7556,51 @@ impl<'input> SetControlsRequest<'input> {
         if header.minor_opcode != SET_CONTROLS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (affect_internal_real_mods, remaining) = u8::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (affect_internal_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_internal_real_mods")?;
         let affect_internal_real_mods = affect_internal_real_mods.into();
-        let (internal_real_mods, remaining) = u8::try_parse(remaining)?;
+        let (internal_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "internal_real_mods")?;
         let internal_real_mods = internal_real_mods.into();
-        let (affect_ignore_lock_real_mods, remaining) = u8::try_parse(remaining)?;
+        let (affect_ignore_lock_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "affect_ignore_lock_real_mods")?;
         let affect_ignore_lock_real_mods = affect_ignore_lock_real_mods.into();
-        let (ignore_lock_real_mods, remaining) = u8::try_parse(remaining)?;
+        let (ignore_lock_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ignore_lock_real_mods")?;
         let ignore_lock_real_mods = ignore_lock_real_mods.into();
-        let (affect_internal_virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (affect_internal_virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_internal_virtual_mods")?;
         let affect_internal_virtual_mods = affect_internal_virtual_mods.into();
-        let (internal_virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (internal_virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "internal_virtual_mods")?;
         let internal_virtual_mods = internal_virtual_mods.into();
-        let (affect_ignore_lock_virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (affect_ignore_lock_virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "affect_ignore_lock_virtual_mods")?;
         let affect_ignore_lock_virtual_mods = affect_ignore_lock_virtual_mods.into();
-        let (ignore_lock_virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (ignore_lock_virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "ignore_lock_virtual_mods")?;
         let ignore_lock_virtual_mods = ignore_lock_virtual_mods.into();
-        let (mouse_keys_dflt_btn, remaining) = u8::try_parse(remaining)?;
-        let (groups_wrap, remaining) = u8::try_parse(remaining)?;
-        let (access_x_options, remaining) = u16::try_parse(remaining)?;
+        let (mouse_keys_dflt_btn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mouse_keys_dflt_btn")?;
+        let (groups_wrap, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups_wrap")?;
+        let (access_x_options, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_options")?;
         let access_x_options = access_x_options.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (affect_enabled_controls, remaining) = u32::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (affect_enabled_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_enabled_controls")?;
         let affect_enabled_controls = affect_enabled_controls.into();
-        let (enabled_controls, remaining) = u32::try_parse(remaining)?;
+        let (enabled_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "enabled_controls")?;
         let enabled_controls = enabled_controls.into();
-        let (change_controls, remaining) = u32::try_parse(remaining)?;
+        let (change_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "change_controls")?;
         let change_controls = change_controls.into();
-        let (repeat_delay, remaining) = u16::try_parse(remaining)?;
-        let (repeat_interval, remaining) = u16::try_parse(remaining)?;
-        let (slow_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (debounce_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_interval, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_time_to_max, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_max_speed, remaining) = u16::try_parse(remaining)?;
-        let (mouse_keys_curve, remaining) = i16::try_parse(remaining)?;
-        let (access_x_timeout, remaining) = u16::try_parse(remaining)?;
-        let (access_x_timeout_mask, remaining) = u32::try_parse(remaining)?;
+        let (repeat_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "repeat_delay")?;
+        let (repeat_interval, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "repeat_interval")?;
+        let (slow_keys_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "slow_keys_delay")?;
+        let (debounce_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "debounce_delay")?;
+        let (mouse_keys_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_delay")?;
+        let (mouse_keys_interval, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_interval")?;
+        let (mouse_keys_time_to_max, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_time_to_max")?;
+        let (mouse_keys_max_speed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mouse_keys_max_speed")?;
+        let (mouse_keys_curve, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "mouse_keys_curve")?;
+        let (access_x_timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout")?;
+        let (access_x_timeout_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "access_x_timeout_mask")?;
         let access_x_timeout_mask = access_x_timeout_mask.into();
-        let (access_x_timeout_values, remaining) = u32::try_parse(remaining)?;
+        let (access_x_timeout_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "access_x_timeout_values")?;
         let access_x_timeout_values = access_x_timeout_values.into();
-        let (access_x_timeout_options_mask, remaining) = u16::try_parse(remaining)?;
+        let (access_x_timeout_options_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout_options_mask")?;
         let access_x_timeout_options_mask = access_x_timeout_options_mask.into();
-        let (access_x_timeout_options_values, remaining) = u16::try_parse(remaining)?;
+        let (access_x_timeout_options_values, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "access_x_timeout_options_values")?;
         let access_x_timeout_options_values = access_x_timeout_options_values.into();
         let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_array_ref::<32>(remaining)?;
         let _ = remaining;
@@ -7672,6 +7675,7 @@ impl<'input> SetControlsRequest<'input> {
 }
 impl<'input> Request for SetControlsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7770,28 +7774,28 @@ impl GetMapRequest {
         if header.minor_opcode != GET_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (full, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (full, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "full")?;
         let full = full.into();
-        let (partial, remaining) = u16::try_parse(remaining)?;
+        let (partial, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "partial")?;
         let partial = partial.into();
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (first_key_sym, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_sym")?;
+        let (n_key_syms, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_syms")?;
+        let (first_key_action, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_action")?;
+        let (n_key_actions, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_actions")?;
+        let (first_key_behavior, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_behavior")?;
+        let (n_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_behaviors")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let virtual_mods = virtual_mods.into();
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (first_key_explicit, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_explicit")?;
+        let (n_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_explicit")?;
+        let (first_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_mod_map_key")?;
+        let (n_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_mod_map_keys")?;
+        let (first_v_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_v_mod_map_key")?;
+        let (n_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_v_mod_map_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetMapRequest {
             device_spec,
@@ -7817,6 +7821,7 @@ impl GetMapRequest {
 }
 impl Request for GetMapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -7838,13 +7843,13 @@ pub struct GetMapMapKeyActions {
 impl GetMapMapKeyActions {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
+        let (acts_rtrn_count, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?), "acts_rtrn_count")?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?, Some("acts_rtrn_acts"))?;
         let result = GetMapMapKeyActions { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
@@ -7882,7 +7887,7 @@ impl GetMapMap {
         let mut outer_remaining = value;
         let types_rtrn = if switch_expr & u16::from(MapPart::KEY_TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_to_usize()?)?;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_to_usize()?, Some("types_rtrn"))?;
             outer_remaining = remaining;
             Some(types_rtrn)
         } else {
@@ -7890,7 +7895,7 @@ impl GetMapMap {
         };
         let syms_rtrn = if switch_expr & u16::from(MapPart::KEY_SYMS) != 0 {
             let remaining = outer_remaining;
-            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?)?;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?, Some("syms_rtrn"))?;
             outer_remaining = remaining;
             Some(syms_rtrn)
         } else {
@@ -7905,7 +7910,7 @@ impl GetMapMap {
         };
         let behaviors_rtrn = if switch_expr & u16::from(MapPart::KEY_BEHAVIORS) != 0 {
             let remaining = outer_remaining;
-            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?)?;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?, Some("behaviors_rtrn"))?;
             outer_remaining = remaining;
             Some(behaviors_rtrn)
         } else {
@@ -7926,7 +7931,7 @@ impl GetMapMap {
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(vmods_rtrn)
         } else {
@@ -7935,11 +7940,11 @@ impl GetMapMap {
         let explicit_rtrn = if switch_expr & u16::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?)?;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?, Some("explicit_rtrn"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(explicit_rtrn)
         } else {
@@ -7948,11 +7953,11 @@ impl GetMapMap {
         let modmap_rtrn = if switch_expr & u16::from(MapPart::MODIFIER_MAP) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?)?;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?, Some("modmap_rtrn"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(modmap_rtrn)
         } else {
@@ -7960,7 +7965,7 @@ impl GetMapMap {
         };
         let vmodmap_rtrn = if switch_expr & u16::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
             let remaining = outer_remaining;
-            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?)?;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?, Some("vmodmap_rtrn"))?;
             outer_remaining = remaining;
             Some(vmodmap_rtrn)
         } else {
@@ -8083,46 +8088,45 @@ pub struct GetMapReply {
 impl TryParse for GetMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (total_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_syms, remaining) = u16::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_actions, remaining) = u16::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (total_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (total_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (present, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "present")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (total_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_types")?;
+        let (first_key_sym, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_sym")?;
+        let (total_syms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_syms")?;
+        let (n_key_syms, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_syms")?;
+        let (first_key_action, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_action")?;
+        let (total_actions, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_actions")?;
+        let (n_key_actions, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_actions")?;
+        let (first_key_behavior, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_behavior")?;
+        let (n_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_behaviors")?;
+        let (total_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_behaviors")?;
+        let (first_key_explicit, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_explicit")?;
+        let (n_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_explicit")?;
+        let (total_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_explicit")?;
+        let (first_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_mod_map_key")?;
+        let (n_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_mod_map_keys")?;
+        let (total_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_mod_map_keys")?;
+        let (first_v_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_v_mod_map_key")?;
+        let (n_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_v_mod_map_keys")?;
+        let (total_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_v_mod_map_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let (map, remaining) = GetMapMap::try_parse(remaining, u16::from(present), u8::from(n_types), u8::from(n_key_syms), u8::from(n_key_actions), u16::from(total_actions), u8::from(total_key_behaviors), u16::from(virtual_mods), u8::from(total_key_explicit), u8::from(total_mod_map_keys), u8::from(total_v_mod_map_keys))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let virtual_mods = virtual_mods.into();
         let result = GetMapReply { device_id, sequence, length, min_key_code, max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8181,13 +8185,13 @@ pub struct SetMapAuxKeyActions {
 impl SetMapAuxKeyActions {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (actions_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
+        let (actions_count, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?), "actions_count")?;
         let actions_count = actions_count.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?, Some("actions"))?;
         let result = SetMapAuxKeyActions { actions_count, actions };
         Ok((result, remaining))
     }
@@ -8226,7 +8230,7 @@ impl SetMapAux {
         let mut outer_remaining = value;
         let types = if switch_expr & u16::from(MapPart::KEY_TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types, remaining) = crate::x11_utils::parse_list::<SetKeyType>(remaining, n_types.try_to_usize()?)?;
+            let (types, remaining) = crate::x11_utils::parse_list::<SetKeyType>(remaining, n_types.try_to_usize()?, Some("types"))?;
             outer_remaining = remaining;
             Some(types)
         } else {
@@ -8234,7 +8238,7 @@ impl SetMapAux {
         };
         let syms = if switch_expr & u16::from(MapPart::KEY_SYMS) != 0 {
             let remaining = outer_remaining;
-            let (syms, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?)?;
+            let (syms, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?, Some("syms"))?;
             outer_remaining = remaining;
             Some(syms)
         } else {
@@ -8249,7 +8253,7 @@ impl SetMapAux {
         };
         let behaviors = if switch_expr & u16::from(MapPart::KEY_BEHAVIORS) != 0 {
             let remaining = outer_remaining;
-            let (behaviors, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?)?;
+            let (behaviors, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?, Some("behaviors"))?;
             outer_remaining = remaining;
             Some(behaviors)
         } else {
@@ -8258,12 +8262,12 @@ impl SetMapAux {
         let vmods = if switch_expr & u16::from(MapPart::VIRTUAL_MODS) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (vmods, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?)?;
+            let (vmods, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?), "vmods")?;
             let vmods = vmods.to_vec();
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(vmods)
         } else {
@@ -8271,7 +8275,7 @@ impl SetMapAux {
         };
         let explicit = if switch_expr & u16::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
             let remaining = outer_remaining;
-            let (explicit, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?)?;
+            let (explicit, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?, Some("explicit"))?;
             outer_remaining = remaining;
             Some(explicit)
         } else {
@@ -8279,7 +8283,7 @@ impl SetMapAux {
         };
         let modmap = if switch_expr & u16::from(MapPart::MODIFIER_MAP) != 0 {
             let remaining = outer_remaining;
-            let (modmap, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?)?;
+            let (modmap, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?, Some("modmap"))?;
             outer_remaining = remaining;
             Some(modmap)
         } else {
@@ -8287,7 +8291,7 @@ impl SetMapAux {
         };
         let vmodmap = if switch_expr & u16::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
             let remaining = outer_remaining;
-            let (vmodmap, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?)?;
+            let (vmodmap, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?, Some("vmodmap"))?;
             outer_remaining = remaining;
             Some(vmodmap)
         } else {
@@ -8541,33 +8545,33 @@ impl<'input> SetMapRequest<'input> {
         if header.minor_opcode != SET_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (present, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "present")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "flags")?;
         let flags = flags.into();
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (total_syms, remaining) = u16::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (total_actions, remaining) = u16::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (total_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (total_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (first_key_sym, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_sym")?;
+        let (n_key_syms, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_syms")?;
+        let (total_syms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_syms")?;
+        let (first_key_action, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_action")?;
+        let (n_key_actions, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_actions")?;
+        let (total_actions, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_actions")?;
+        let (first_key_behavior, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_behavior")?;
+        let (n_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_behaviors")?;
+        let (total_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_behaviors")?;
+        let (first_key_explicit, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_explicit")?;
+        let (n_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_explicit")?;
+        let (total_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_explicit")?;
+        let (first_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_mod_map_key")?;
+        let (n_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_mod_map_keys")?;
+        let (total_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_mod_map_keys")?;
+        let (first_v_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_v_mod_map_key")?;
+        let (n_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_v_mod_map_keys")?;
+        let (total_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_v_mod_map_keys")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let virtual_mods = virtual_mods.into();
         let (values, remaining) = SetMapAux::try_parse(remaining, u16::from(present), u8::from(n_types), u8::from(n_key_syms), u8::from(n_key_actions), u16::from(total_actions), u8::from(total_key_behaviors), u16::from(virtual_mods), u8::from(total_key_explicit), u8::from(total_mod_map_keys), u8::from(total_v_mod_map_keys))?;
         let _ = remaining;
@@ -8634,6 +8638,7 @@ impl<'input> SetMapRequest<'input> {
 }
 impl<'input> Request for SetMapRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8690,12 +8695,12 @@ impl GetCompatMapRequest {
         if header.minor_opcode != GET_COMPAT_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (groups, remaining) = u8::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups")?;
         let groups = groups.into();
-        let (get_all_si, remaining) = bool::try_parse(remaining)?;
-        let (first_si, remaining) = u16::try_parse(remaining)?;
-        let (n_si, remaining) = u16::try_parse(remaining)?;
+        let (get_all_si, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "get_all_si")?;
+        let (first_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first_si")?;
+        let (n_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_si")?;
         let _ = remaining;
         Ok(GetCompatMapRequest {
             device_spec,
@@ -8708,6 +8713,7 @@ impl GetCompatMapRequest {
 }
 impl Request for GetCompatMapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8735,26 +8741,25 @@ pub struct GetCompatMapReply {
 impl TryParse for GetCompatMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (groups_rtrn, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (first_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_to_usize()?)?;
-        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups_rtrn).count_ones().try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (groups_rtrn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups_rtrn")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (first_si_rtrn, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first_si_rtrn")?;
+        let (n_si_rtrn, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_si_rtrn")?;
+        let (n_total_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_total_si")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_to_usize()?, Some("si_rtrn"))?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups_rtrn).count_ones().try_to_usize()?, Some("group_rtrn"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let groups_rtrn = groups_rtrn.into();
         let result = GetCompatMapReply { device_id, sequence, length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8860,17 +8865,17 @@ impl<'input> SetCompatMapRequest<'input> {
         if header.minor_opcode != SET_COMPAT_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (recompute_actions, remaining) = bool::try_parse(remaining)?;
-        let (truncate_si, remaining) = bool::try_parse(remaining)?;
-        let (groups, remaining) = u8::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (recompute_actions, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "recompute_actions")?;
+        let (truncate_si, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "truncate_si")?;
+        let (groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups")?;
         let groups = groups.into();
-        let (first_si, remaining) = u16::try_parse(remaining)?;
-        let (n_si, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (si, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si.try_to_usize()?)?;
-        let (group_maps, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups).count_ones().try_to_usize()?)?;
+        let (first_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first_si")?;
+        let (n_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_si")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (si, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si.try_to_usize()?, Some("si"))?;
+        let (group_maps, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups).count_ones().try_to_usize()?, Some("group_maps"))?;
         let _ = remaining;
         Ok(SetCompatMapRequest {
             device_spec,
@@ -8897,6 +8902,7 @@ impl<'input> SetCompatMapRequest<'input> {
 }
 impl<'input> Request for SetCompatMapRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8941,8 +8947,8 @@ impl GetIndicatorStateRequest {
         if header.minor_opcode != GET_INDICATOR_STATE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GetIndicatorStateRequest {
             device_spec,
@@ -8951,6 +8957,7 @@ impl GetIndicatorStateRequest {
 }
 impl Request for GetIndicatorStateRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -8974,19 +8981,18 @@ pub struct GetIndicatorStateReply {
 impl TryParse for GetIndicatorStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetIndicatorStateReply { device_id, sequence, length, state };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9084,9 +9090,9 @@ impl GetIndicatorMapRequest {
         if header.minor_opcode != GET_INDICATOR_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
         let _ = remaining;
         Ok(GetIndicatorMapRequest {
             device_spec,
@@ -9096,6 +9102,7 @@ impl GetIndicatorMapRequest {
 }
 impl Request for GetIndicatorMapRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9122,22 +9129,21 @@ pub struct GetIndicatorMapReply {
 impl TryParse for GetIndicatorMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (real_indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_indicators, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(which).count_ones().try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (real_indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "real_indicators")?;
+        let (n_indicators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_indicators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
+        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(which).count_ones().try_to_usize()?, Some("maps"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetIndicatorMapReply { device_id, sequence, length, which, real_indicators, n_indicators, maps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9163,6 +9169,29 @@ impl Serialize for GetIndicatorMapReply {
         self.maps.serialize_into(bytes);
     }
 }
+impl GetIndicatorMapReply {
+    /// Lazily parse the `maps` list from the raw bytes of a `GetIndicatorMapReply`.
+    ///
+    /// In contrast to parsing the whole `GetIndicatorMapReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn maps_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, IndicatorMap>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (real_indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "real_indicators")?;
+        let (n_indicators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_indicators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = u32::from(which).count_ones().try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetIndicatorMap request
 pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
@@ -9209,10 +9238,10 @@ impl<'input> SetIndicatorMapRequest<'input> {
         if header.minor_opcode != SET_INDICATOR_MAP_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(which).count_ones().try_to_usize()?)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, u32::from(which).count_ones().try_to_usize()?, Some("maps"))?;
         let _ = remaining;
         Ok(SetIndicatorMapRequest {
             device_spec,
@@ -9231,6 +9260,7 @@ impl<'input> SetIndicatorMapRequest<'input> {
 }
 impl<'input> Request for SetIndicatorMapRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9289,12 +9319,12 @@ impl GetNamedIndicatorRequest {
         if header.minor_opcode != GET_NAMED_INDICATOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (led_class, remaining) = crate::x11_utils::in_field(LedClassSpec::try_parse(remaining), "led_class")?;
         let led_class = led_class.into();
-        let (led_id, remaining) = IDSpec::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (led_id, remaining) = crate::x11_utils::in_field(IDSpec::try_parse(remaining), "led_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (indicator, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "indicator")?;
         let _ = remaining;
         Ok(GetNamedIndicatorRequest {
             device_spec,
@@ -9306,6 +9336,7 @@ impl GetNamedIndicatorRequest {
 }
 impl Request for GetNamedIndicatorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9342,27 +9373,27 @@ pub struct GetNamedIndicatorReply {
 impl TryParse for GetNamedIndicatorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (found, remaining) = bool::try_parse(remaining)?;
-        let (on, remaining) = bool::try_parse(remaining)?;
-        let (real_indicator, remaining) = bool::try_parse(remaining)?;
-        let (ndx, remaining) = u8::try_parse(remaining)?;
-        let (map_flags, remaining) = u8::try_parse(remaining)?;
-        let (map_which_groups, remaining) = u8::try_parse(remaining)?;
-        let (map_groups, remaining) = u8::try_parse(remaining)?;
-        let (map_which_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_real_mods, remaining) = u8::try_parse(remaining)?;
-        let (map_vmod, remaining) = u16::try_parse(remaining)?;
-        let (map_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (supported, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (indicator, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "indicator")?;
+        let (found, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "found")?;
+        let (on, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "on")?;
+        let (real_indicator, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "real_indicator")?;
+        let (ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ndx")?;
+        let (map_flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_flags")?;
+        let (map_which_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_which_groups")?;
+        let (map_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_groups")?;
+        let (map_which_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_which_mods")?;
+        let (map_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_mods")?;
+        let (map_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_real_mods")?;
+        let (map_vmod, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "map_vmod")?;
+        let (map_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map_ctrls")?;
+        let (supported, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "supported")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let map_flags = map_flags.into();
         let map_which_groups = map_which_groups.into();
@@ -9374,8 +9405,7 @@ impl TryParse for GetNamedIndicatorReply {
         let map_ctrls = map_ctrls.into();
         let result = GetNamedIndicatorReply { device_id, sequence, length, indicator, found, on, real_indicator, ndx, map_flags, map_which_groups, map_groups, map_which_mods, map_mods, map_real_mods, map_vmod, map_ctrls, supported };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9545,30 +9575,30 @@ impl SetNamedIndicatorRequest {
         if header.minor_opcode != SET_NAMED_INDICATOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (led_class, remaining) = crate::x11_utils::in_field(LedClassSpec::try_parse(remaining), "led_class")?;
         let led_class = led_class.into();
-        let (led_id, remaining) = IDSpec::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (indicator, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (set_state, remaining) = bool::try_parse(remaining)?;
-        let (on, remaining) = bool::try_parse(remaining)?;
-        let (set_map, remaining) = bool::try_parse(remaining)?;
-        let (create_map, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (map_flags, remaining) = u8::try_parse(remaining)?;
+        let (led_id, remaining) = crate::x11_utils::in_field(IDSpec::try_parse(remaining), "led_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (indicator, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "indicator")?;
+        let (set_state, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "set_state")?;
+        let (on, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "on")?;
+        let (set_map, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "set_map")?;
+        let (create_map, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "create_map")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (map_flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_flags")?;
         let map_flags = map_flags.into();
-        let (map_which_groups, remaining) = u8::try_parse(remaining)?;
+        let (map_which_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_which_groups")?;
         let map_which_groups = map_which_groups.into();
-        let (map_groups, remaining) = u8::try_parse(remaining)?;
+        let (map_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_groups")?;
         let map_groups = map_groups.into();
-        let (map_which_mods, remaining) = u8::try_parse(remaining)?;
+        let (map_which_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_which_mods")?;
         let map_which_mods = map_which_mods.into();
-        let (map_real_mods, remaining) = u8::try_parse(remaining)?;
+        let (map_real_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_real_mods")?;
         let map_real_mods = map_real_mods.into();
-        let (map_vmods, remaining) = u16::try_parse(remaining)?;
+        let (map_vmods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "map_vmods")?;
         let map_vmods = map_vmods.into();
-        let (map_ctrls, remaining) = u32::try_parse(remaining)?;
+        let (map_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map_ctrls")?;
         let map_ctrls = map_ctrls.into();
         let _ = remaining;
         Ok(SetNamedIndicatorRequest {
@@ -9592,6 +9622,7 @@ impl SetNamedIndicatorRequest {
 }
 impl Request for SetNamedIndicatorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9642,9 +9673,9 @@ impl GetNamesRequest {
         if header.minor_opcode != GET_NAMES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
         let which = which.into();
         let _ = remaining;
         Ok(GetNamesRequest {
@@ -9655,6 +9686,7 @@ impl GetNamesRequest {
 }
 impl Request for GetNamesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -9676,13 +9708,13 @@ pub struct GetNamesValueListKTLevelNames {
 impl GetNamesValueListKTLevelNames {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
+        let (n_levels_per_type, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?), "n_levels_per_type")?;
         let n_levels_per_type = n_levels_per_type.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("kt_level_names"))?;
         let result = GetNamesValueListKTLevelNames { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
@@ -9726,7 +9758,7 @@ impl GetNamesValueList {
         let mut outer_remaining = value;
         let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
             let remaining = outer_remaining;
-            let (keycodes_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (keycodes_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "keycodes_name")?;
             outer_remaining = remaining;
             Some(keycodes_name)
         } else {
@@ -9734,7 +9766,7 @@ impl GetNamesValueList {
         };
         let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
             let remaining = outer_remaining;
-            let (geometry_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (geometry_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "geometry_name")?;
             outer_remaining = remaining;
             Some(geometry_name)
         } else {
@@ -9742,7 +9774,7 @@ impl GetNamesValueList {
         };
         let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "symbols_name")?;
             outer_remaining = remaining;
             Some(symbols_name)
         } else {
@@ -9750,7 +9782,7 @@ impl GetNamesValueList {
         };
         let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (phys_symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (phys_symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "phys_symbols_name")?;
             outer_remaining = remaining;
             Some(phys_symbols_name)
         } else {
@@ -9758,7 +9790,7 @@ impl GetNamesValueList {
         };
         let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (types_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "types_name")?;
             outer_remaining = remaining;
             Some(types_name)
         } else {
@@ -9766,7 +9798,7 @@ impl GetNamesValueList {
         };
         let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
             let remaining = outer_remaining;
-            let (compat_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (compat_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "compat_name")?;
             outer_remaining = remaining;
             Some(compat_name)
         } else {
@@ -9774,7 +9806,7 @@ impl GetNamesValueList {
         };
         let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?)?;
+            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?, Some("type_names"))?;
             outer_remaining = remaining;
             Some(type_names)
         } else {
@@ -9789,7 +9821,7 @@ impl GetNamesValueList {
         };
         let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?)?;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?, Some("indicator_names"))?;
             outer_remaining = remaining;
             Some(indicator_names)
         } else {
@@ -9797,7 +9829,7 @@ impl GetNamesValueList {
         };
         let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?)?;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?, Some("virtual_mod_names"))?;
             outer_remaining = remaining;
             Some(virtual_mod_names)
         } else {
@@ -9805,7 +9837,7 @@ impl GetNamesValueList {
         };
         let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?)?;
+            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?, Some("groups"))?;
             outer_remaining = remaining;
             Some(groups)
         } else {
@@ -9813,7 +9845,7 @@ impl GetNamesValueList {
         };
         let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?)?;
+            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?, Some("key_names"))?;
             outer_remaining = remaining;
             Some(key_names)
         } else {
@@ -9821,7 +9853,7 @@ impl GetNamesValueList {
         };
         let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
             let remaining = outer_remaining;
-            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?)?;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?, Some("key_aliases"))?;
             outer_remaining = remaining;
             Some(key_aliases)
         } else {
@@ -9829,7 +9861,7 @@ impl GetNamesValueList {
         };
         let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?)?;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?, Some("radio_group_names"))?;
             outer_remaining = remaining;
             Some(radio_group_names)
         } else {
@@ -9970,33 +10002,32 @@ pub struct GetNamesReply {
 impl TryParse for GetNamesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (group_names, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (n_kt_levels, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (group_names, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group_names")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
+        let (first_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let (indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicators")?;
+        let (n_radio_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_radio_groups")?;
+        let (n_key_aliases, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_aliases")?;
+        let (n_kt_levels, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_kt_levels")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let (value_list, remaining) = GetNamesValueList::try_parse(remaining, u32::from(which), u8::from(n_types), u32::from(indicators), u16::from(virtual_mods), u8::from(group_names), u8::from(n_keys), u8::from(n_key_aliases), u8::from(n_radio_groups))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let group_names = group_names.into();
         let virtual_mods = virtual_mods.into();
         let result = GetNamesReply { device_id, sequence, length, min_key_code, max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10041,13 +10072,13 @@ pub struct SetNamesAuxKTLevelNames {
 impl SetNamesAuxKTLevelNames {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
+        let (n_levels_per_type, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?), "n_levels_per_type")?;
         let n_levels_per_type = n_levels_per_type.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("kt_level_names"))?;
         let result = SetNamesAuxKTLevelNames { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
@@ -10092,7 +10123,7 @@ impl SetNamesAux {
         let mut outer_remaining = value;
         let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
             let remaining = outer_remaining;
-            let (keycodes_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (keycodes_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "keycodes_name")?;
             outer_remaining = remaining;
             Some(keycodes_name)
         } else {
@@ -10100,7 +10131,7 @@ impl SetNamesAux {
         };
         let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
             let remaining = outer_remaining;
-            let (geometry_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (geometry_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "geometry_name")?;
             outer_remaining = remaining;
             Some(geometry_name)
         } else {
@@ -10108,7 +10139,7 @@ impl SetNamesAux {
         };
         let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "symbols_name")?;
             outer_remaining = remaining;
             Some(symbols_name)
         } else {
@@ -10116,7 +10147,7 @@ impl SetNamesAux {
         };
         let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (phys_symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (phys_symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "phys_symbols_name")?;
             outer_remaining = remaining;
             Some(phys_symbols_name)
         } else {
@@ -10124,7 +10155,7 @@ impl SetNamesAux {
         };
         let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (types_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "types_name")?;
             outer_remaining = remaining;
             Some(types_name)
         } else {
@@ -10132,7 +10163,7 @@ impl SetNamesAux {
         };
         let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
             let remaining = outer_remaining;
-            let (compat_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (compat_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "compat_name")?;
             outer_remaining = remaining;
             Some(compat_name)
         } else {
@@ -10140,7 +10171,7 @@ impl SetNamesAux {
         };
         let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?)?;
+            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?, Some("type_names"))?;
             outer_remaining = remaining;
             Some(type_names)
         } else {
@@ -10155,7 +10186,7 @@ impl SetNamesAux {
         };
         let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?)?;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?, Some("indicator_names"))?;
             outer_remaining = remaining;
             Some(indicator_names)
         } else {
@@ -10163,7 +10194,7 @@ impl SetNamesAux {
         };
         let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?)?;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?, Some("virtual_mod_names"))?;
             outer_remaining = remaining;
             Some(virtual_mod_names)
         } else {
@@ -10171,7 +10202,7 @@ impl SetNamesAux {
         };
         let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?)?;
+            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?, Some("groups"))?;
             outer_remaining = remaining;
             Some(groups)
         } else {
@@ -10179,7 +10210,7 @@ impl SetNamesAux {
         };
         let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?)?;
+            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?, Some("key_names"))?;
             outer_remaining = remaining;
             Some(key_names)
         } else {
@@ -10187,7 +10218,7 @@ impl SetNamesAux {
         };
         let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
             let remaining = outer_remaining;
-            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?)?;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?, Some("key_aliases"))?;
             outer_remaining = remaining;
             Some(key_aliases)
         } else {
@@ -10195,7 +10226,7 @@ impl SetNamesAux {
         };
         let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?)?;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?, Some("radio_group_names"))?;
             outer_remaining = remaining;
             Some(radio_group_names)
         } else {
@@ -10488,23 +10519,23 @@ impl<'input> SetNamesRequest<'input> {
         if header.minor_opcode != SET_NAMES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let virtual_mods = virtual_mods.into();
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_kt_levelt, remaining) = u8::try_parse(remaining)?;
-        let (n_kt_levels, remaining) = u8::try_parse(remaining)?;
-        let (indicators, remaining) = u32::try_parse(remaining)?;
-        let (group_names, remaining) = u8::try_parse(remaining)?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (first_kt_levelt, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_kt_levelt")?;
+        let (n_kt_levels, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_kt_levels")?;
+        let (indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicators")?;
+        let (group_names, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group_names")?;
         let group_names = group_names.into();
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (total_kt_level_names, remaining) = u16::try_parse(remaining)?;
+        let (n_radio_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_radio_groups")?;
+        let (first_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let (n_key_aliases, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_aliases")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (total_kt_level_names, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_kt_level_names")?;
         let (values, remaining) = SetNamesAux::try_parse(remaining, u32::from(which), u8::from(n_types), u32::from(indicators), u16::from(virtual_mods), u8::from(group_names), u8::from(n_keys), u8::from(n_key_aliases), u8::from(n_radio_groups))?;
         let _ = remaining;
         Ok(SetNamesRequest {
@@ -10546,6 +10577,7 @@ impl<'input> SetNamesRequest<'input> {
 }
 impl<'input> Request for SetNamesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10620,17 +10652,17 @@ impl PerClientFlagsRequest {
         if header.minor_opcode != PER_CLIENT_FLAGS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (change, remaining) = u32::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (change, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "change")?;
         let change = change.into();
-        let (value, remaining) = u32::try_parse(remaining)?;
+        let (value, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value")?;
         let value = value.into();
-        let (ctrls_to_change, remaining) = u32::try_parse(remaining)?;
+        let (ctrls_to_change, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ctrls_to_change")?;
         let ctrls_to_change = ctrls_to_change.into();
-        let (auto_ctrls, remaining) = u32::try_parse(remaining)?;
+        let (auto_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "auto_ctrls")?;
         let auto_ctrls = auto_ctrls.into();
-        let (auto_ctrls_values, remaining) = u32::try_parse(remaining)?;
+        let (auto_ctrls_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "auto_ctrls_values")?;
         let auto_ctrls_values = auto_ctrls_values.into();
         let _ = remaining;
         Ok(PerClientFlagsRequest {
@@ -10645,6 +10677,7 @@ impl PerClientFlagsRequest {
 }
 impl Request for PerClientFlagsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10671,17 +10704,17 @@ pub struct PerClientFlagsReply {
 impl TryParse for PerClientFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (supported, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = u32::try_parse(remaining)?;
-        let (auto_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (auto_ctrls_values, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (supported, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "supported")?;
+        let (value, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value")?;
+        let (auto_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "auto_ctrls")?;
+        let (auto_ctrls_values, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "auto_ctrls_values")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let supported = supported.into();
         let value = value.into();
@@ -10689,8 +10722,7 @@ impl TryParse for PerClientFlagsReply {
         let auto_ctrls_values = auto_ctrls_values.into();
         let result = PerClientFlagsReply { device_id, sequence, length, supported, value, auto_ctrls, auto_ctrls_values };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10790,8 +10822,8 @@ impl ListComponentsRequest {
         if header.minor_opcode != LIST_COMPONENTS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (max_names, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (max_names, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_names")?;
         let _ = remaining;
         Ok(ListComponentsRequest {
             device_spec,
@@ -10801,6 +10833,7 @@ impl ListComponentsRequest {
 }
 impl Request for ListComponentsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -10830,31 +10863,30 @@ pub struct ListComponentsReply {
 impl TryParse for ListComponentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (n_keymaps, remaining) = u16::try_parse(remaining)?;
-        let (n_keycodes, remaining) = u16::try_parse(remaining)?;
-        let (n_types, remaining) = u16::try_parse(remaining)?;
-        let (n_compat_maps, remaining) = u16::try_parse(remaining)?;
-        let (n_symbols, remaining) = u16::try_parse(remaining)?;
-        let (n_geometries, remaining) = u16::try_parse(remaining)?;
-        let (extra, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
-        let (keymaps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keymaps.try_to_usize()?)?;
-        let (keycodes, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keycodes.try_to_usize()?)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_types.try_to_usize()?)?;
-        let (compat_maps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_compat_maps.try_to_usize()?)?;
-        let (symbols, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_symbols.try_to_usize()?)?;
-        let (geometries, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_geometries.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (n_keymaps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_keymaps")?;
+        let (n_keycodes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_keycodes")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_types")?;
+        let (n_compat_maps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_compat_maps")?;
+        let (n_symbols, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_symbols")?;
+        let (n_geometries, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_geometries")?;
+        let (extra, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "extra")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
+        let (keymaps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keymaps.try_to_usize()?, Some("keymaps"))?;
+        let (keycodes, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_keycodes.try_to_usize()?, Some("keycodes"))?;
+        let (types, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_types.try_to_usize()?, Some("types"))?;
+        let (compat_maps, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_compat_maps.try_to_usize()?, Some("compat_maps"))?;
+        let (symbols, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_symbols.try_to_usize()?, Some("symbols"))?;
+        let (geometries, remaining) = crate::x11_utils::parse_list::<Listing>(remaining, n_geometries.try_to_usize()?, Some("geometries"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListComponentsReply { device_id, sequence, length, extra, keymaps, keycodes, types, compat_maps, symbols, geometries };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10974,6 +11006,33 @@ impl ListComponentsReply {
             .try_into().unwrap()
     }
 }
+impl ListComponentsReply {
+    /// Lazily parse the `keymaps` list from the raw bytes of a `ListComponentsReply`.
+    ///
+    /// In contrast to parsing the whole `ListComponentsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn keymaps_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Listing>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (n_keymaps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_keymaps")?;
+        let (n_keycodes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_keycodes")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_types")?;
+        let (n_compat_maps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_compat_maps")?;
+        let (n_symbols, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_symbols")?;
+        let (n_geometries, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_geometries")?;
+        let (extra, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "extra")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = n_keymaps.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetKbdByName request
 pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
@@ -11018,13 +11077,13 @@ impl GetKbdByNameRequest {
         if header.minor_opcode != GET_KBD_BY_NAME_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (need, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (need, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "need")?;
         let need = need.into();
-        let (want, remaining) = u16::try_parse(remaining)?;
+        let (want, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "want")?;
         let want = want.into();
-        let (load, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (load, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "load")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         Ok(GetKbdByNameRequest {
             device_spec,
@@ -11036,6 +11095,7 @@ impl GetKbdByNameRequest {
 }
 impl Request for GetKbdByNameRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -11057,13 +11117,13 @@ pub struct GetKbdByNameRepliesTypesMapKeyActions {
 impl GetKbdByNameRepliesTypesMapKeyActions {
     pub fn try_parse(remaining: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?)?;
+        let (acts_rtrn_count, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_to_usize()?), "acts_rtrn_count")?;
         let acts_rtrn_count = acts_rtrn_count.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list::<Action>(remaining, total_actions.try_to_usize()?, Some("acts_rtrn_acts"))?;
         let result = GetKbdByNameRepliesTypesMapKeyActions { acts_rtrn_count, acts_rtrn_acts };
         Ok((result, remaining))
     }
@@ -11101,7 +11161,7 @@ impl GetKbdByNameRepliesTypesMap {
         let mut outer_remaining = value;
         let types_rtrn = if switch_expr & u16::from(MapPart::KEY_TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_to_usize()?)?;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list::<KeyType>(remaining, n_types.try_to_usize()?, Some("types_rtrn"))?;
             outer_remaining = remaining;
             Some(types_rtrn)
         } else {
@@ -11109,7 +11169,7 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let syms_rtrn = if switch_expr & u16::from(MapPart::KEY_SYMS) != 0 {
             let remaining = outer_remaining;
-            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?)?;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list::<KeySymMap>(remaining, n_key_syms.try_to_usize()?, Some("syms_rtrn"))?;
             outer_remaining = remaining;
             Some(syms_rtrn)
         } else {
@@ -11124,7 +11184,7 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let behaviors_rtrn = if switch_expr & u16::from(MapPart::KEY_BEHAVIORS) != 0 {
             let remaining = outer_remaining;
-            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?)?;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list::<SetBehavior>(remaining, total_key_behaviors.try_to_usize()?, Some("behaviors_rtrn"))?;
             outer_remaining = remaining;
             Some(behaviors_rtrn)
         } else {
@@ -11145,7 +11205,7 @@ impl GetKbdByNameRepliesTypesMap {
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(vmods_rtrn)
         } else {
@@ -11154,11 +11214,11 @@ impl GetKbdByNameRepliesTypesMap {
         let explicit_rtrn = if switch_expr & u16::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?)?;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list::<SetExplicit>(remaining, total_key_explicit.try_to_usize()?, Some("explicit_rtrn"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(explicit_rtrn)
         } else {
@@ -11167,11 +11227,11 @@ impl GetKbdByNameRepliesTypesMap {
         let modmap_rtrn = if switch_expr & u16::from(MapPart::MODIFIER_MAP) != 0 {
             let remaining = outer_remaining;
             let value = remaining;
-            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?)?;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyModMap>(remaining, total_mod_map_keys.try_to_usize()?, Some("modmap_rtrn"))?;
             // Align offset to multiple of 4
             let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
             let misalignment = (4 - (offset % 4)) % 4;
-            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
             outer_remaining = remaining;
             Some(modmap_rtrn)
         } else {
@@ -11179,7 +11239,7 @@ impl GetKbdByNameRepliesTypesMap {
         };
         let vmodmap_rtrn = if switch_expr & u16::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
             let remaining = outer_remaining;
-            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?)?;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list::<KeyVModMap>(remaining, total_v_mod_map_keys.try_to_usize()?, Some("vmodmap_rtrn"))?;
             outer_remaining = remaining;
             Some(vmodmap_rtrn)
         } else {
@@ -11302,37 +11362,37 @@ pub struct GetKbdByNameRepliesTypes {
 }
 impl TryParse for GetKbdByNameRepliesTypes {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (getmap_type, remaining) = u8::try_parse(remaining)?;
-        let (type_device_id, remaining) = u8::try_parse(remaining)?;
-        let (getmap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (getmap_length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (type_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (type_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (total_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_syms, remaining) = u16::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_action, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (total_actions, remaining) = u16::try_parse(remaining)?;
-        let (n_key_actions, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (total_key_behaviors, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (total_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (total_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
+        let (getmap_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "getmap_type")?;
+        let (type_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_device_id")?;
+        let (getmap_sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "getmap_sequence")?;
+        let (getmap_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "getmap_length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (type_min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "type_min_key_code")?;
+        let (type_max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "type_max_key_code")?;
+        let (present, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "present")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (total_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_types")?;
+        let (first_key_sym, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_sym")?;
+        let (total_syms, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_syms")?;
+        let (n_key_syms, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_syms")?;
+        let (first_key_action, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_action")?;
+        let (total_actions, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "total_actions")?;
+        let (n_key_actions, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_actions")?;
+        let (first_key_behavior, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_behavior")?;
+        let (n_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_behaviors")?;
+        let (total_key_behaviors, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_behaviors")?;
+        let (first_key_explicit, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_explicit")?;
+        let (n_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_explicit")?;
+        let (total_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_key_explicit")?;
+        let (first_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_mod_map_key")?;
+        let (n_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_mod_map_keys")?;
+        let (total_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_mod_map_keys")?;
+        let (first_v_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_v_mod_map_key")?;
+        let (n_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_v_mod_map_keys")?;
+        let (total_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_v_mod_map_keys")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
         let (map, remaining) = GetKbdByNameRepliesTypesMap::try_parse(remaining, u16::from(present), u8::from(n_types), u8::from(n_key_syms), u8::from(n_key_actions), u16::from(total_actions), u8::from(total_key_behaviors), u16::from(virtual_mods), u8::from(total_key_explicit), u8::from(total_mod_map_keys), u8::from(total_v_mod_map_keys))?;
         let virtual_mods = virtual_mods.into();
         let result = GetKbdByNameRepliesTypes { getmap_type, type_device_id, getmap_sequence, getmap_length, type_min_key_code, type_max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
@@ -11398,18 +11458,18 @@ pub struct GetKbdByNameRepliesCompatMap {
 }
 impl TryParse for GetKbdByNameRepliesCompatMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (compatmap_type, remaining) = u8::try_parse(remaining)?;
-        let (compat_device_id, remaining) = u8::try_parse(remaining)?;
-        let (compatmap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (compatmap_length, remaining) = u32::try_parse(remaining)?;
-        let (groups_rtrn, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (first_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_si_rtrn, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_to_usize()?)?;
-        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups_rtrn).count_ones().try_to_usize()?)?;
+        let (compatmap_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compatmap_type")?;
+        let (compat_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_device_id")?;
+        let (compatmap_sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "compatmap_sequence")?;
+        let (compatmap_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "compatmap_length")?;
+        let (groups_rtrn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "groups_rtrn")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (first_si_rtrn, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first_si_rtrn")?;
+        let (n_si_rtrn, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_si_rtrn")?;
+        let (n_total_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_total_si")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list::<SymInterpret>(remaining, n_si_rtrn.try_to_usize()?, Some("si_rtrn"))?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list::<ModDef>(remaining, u32::from(groups_rtrn).count_ones().try_to_usize()?, Some("group_rtrn"))?;
         let groups_rtrn = groups_rtrn.into();
         let result = GetKbdByNameRepliesCompatMap { compatmap_type, compat_device_id, compatmap_sequence, compatmap_length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
         Ok((result, remaining))
@@ -11468,15 +11528,15 @@ pub struct GetKbdByNameRepliesIndicatorMaps {
 }
 impl TryParse for GetKbdByNameRepliesIndicatorMaps {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (indicatormap_type, remaining) = u8::try_parse(remaining)?;
-        let (indicator_device_id, remaining) = u8::try_parse(remaining)?;
-        let (indicatormap_sequence, remaining) = u16::try_parse(remaining)?;
-        let (indicatormap_length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (real_indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_indicators, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
-        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, n_indicators.try_to_usize()?)?;
+        let (indicatormap_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "indicatormap_type")?;
+        let (indicator_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "indicator_device_id")?;
+        let (indicatormap_sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "indicatormap_sequence")?;
+        let (indicatormap_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicatormap_length")?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (real_indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "real_indicators")?;
+        let (n_indicators, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_indicators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 15)?;
+        let (maps, remaining) = crate::x11_utils::parse_list::<IndicatorMap>(remaining, n_indicators.try_to_usize()?, Some("maps"))?;
         let result = GetKbdByNameRepliesIndicatorMaps { indicatormap_type, indicator_device_id, indicatormap_sequence, indicatormap_length, which, real_indicators, maps };
         Ok((result, remaining))
     }
@@ -11526,13 +11586,13 @@ pub struct GetKbdByNameRepliesKeyNamesValueListKTLevelNames {
 impl GetKbdByNameRepliesKeyNamesValueListKTLevelNames {
     pub fn try_parse(remaining: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?)?;
+        let (n_levels_per_type, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, n_types.try_to_usize()?), "n_levels_per_type")?;
         let n_levels_per_type = n_levels_per_type.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_to_usize()?, Some("kt_level_names"))?;
         let result = GetKbdByNameRepliesKeyNamesValueListKTLevelNames { n_levels_per_type, kt_level_names };
         Ok((result, remaining))
     }
@@ -11576,7 +11636,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         let mut outer_remaining = value;
         let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
             let remaining = outer_remaining;
-            let (keycodes_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (keycodes_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "keycodes_name")?;
             outer_remaining = remaining;
             Some(keycodes_name)
         } else {
@@ -11584,7 +11644,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
             let remaining = outer_remaining;
-            let (geometry_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (geometry_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "geometry_name")?;
             outer_remaining = remaining;
             Some(geometry_name)
         } else {
@@ -11592,7 +11652,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "symbols_name")?;
             outer_remaining = remaining;
             Some(symbols_name)
         } else {
@@ -11600,7 +11660,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
             let remaining = outer_remaining;
-            let (phys_symbols_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (phys_symbols_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "phys_symbols_name")?;
             outer_remaining = remaining;
             Some(phys_symbols_name)
         } else {
@@ -11608,7 +11668,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
             let remaining = outer_remaining;
-            let (types_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (types_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "types_name")?;
             outer_remaining = remaining;
             Some(types_name)
         } else {
@@ -11616,7 +11676,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
             let remaining = outer_remaining;
-            let (compat_name, remaining) = xproto::Atom::try_parse(remaining)?;
+            let (compat_name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "compat_name")?;
             outer_remaining = remaining;
             Some(compat_name)
         } else {
@@ -11624,7 +11684,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?)?;
+            let (type_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_types.try_to_usize()?, Some("type_names"))?;
             outer_remaining = remaining;
             Some(type_names)
         } else {
@@ -11639,7 +11699,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?)?;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(indicators).count_ones().try_to_usize()?, Some("indicator_names"))?;
             outer_remaining = remaining;
             Some(indicator_names)
         } else {
@@ -11647,7 +11707,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?)?;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(virtual_mods).count_ones().try_to_usize()?, Some("virtual_mod_names"))?;
             outer_remaining = remaining;
             Some(virtual_mod_names)
         } else {
@@ -11655,7 +11715,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?)?;
+            let (groups, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, u32::from(group_names).count_ones().try_to_usize()?, Some("groups"))?;
             outer_remaining = remaining;
             Some(groups)
         } else {
@@ -11663,7 +11723,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?)?;
+            let (key_names, remaining) = crate::x11_utils::parse_list::<KeyName>(remaining, n_keys.try_to_usize()?, Some("key_names"))?;
             outer_remaining = remaining;
             Some(key_names)
         } else {
@@ -11671,7 +11731,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
             let remaining = outer_remaining;
-            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?)?;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list::<KeyAlias>(remaining, n_key_aliases.try_to_usize()?, Some("key_aliases"))?;
             outer_remaining = remaining;
             Some(key_aliases)
         } else {
@@ -11679,7 +11739,7 @@ impl GetKbdByNameRepliesKeyNamesValueList {
         };
         let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
             let remaining = outer_remaining;
-            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?)?;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list::<xproto::Atom>(remaining, n_radio_groups.try_to_usize()?, Some("radio_group_names"))?;
             outer_remaining = remaining;
             Some(radio_group_names)
         } else {
@@ -11820,23 +11880,23 @@ pub struct GetKbdByNameRepliesKeyNames {
 }
 impl TryParse for GetKbdByNameRepliesKeyNames {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (keyname_type, remaining) = u8::try_parse(remaining)?;
-        let (key_device_id, remaining) = u8::try_parse(remaining)?;
-        let (keyname_sequence, remaining) = u16::try_parse(remaining)?;
-        let (keyname_length, remaining) = u32::try_parse(remaining)?;
-        let (which, remaining) = u32::try_parse(remaining)?;
-        let (key_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (key_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (group_names, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (indicators, remaining) = u32::try_parse(remaining)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (n_kt_levels, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (keyname_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keyname_type")?;
+        let (key_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "key_device_id")?;
+        let (keyname_sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "keyname_sequence")?;
+        let (keyname_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "keyname_length")?;
+        let (which, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "which")?;
+        let (key_min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "key_min_key_code")?;
+        let (key_max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "key_max_key_code")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (group_names, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group_names")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
+        let (first_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let (indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "indicators")?;
+        let (n_radio_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_radio_groups")?;
+        let (n_key_aliases, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_aliases")?;
+        let (n_kt_levels, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_kt_levels")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let (value_list, remaining) = GetKbdByNameRepliesKeyNamesValueList::try_parse(remaining, u32::from(which), u8::from(n_types), u32::from(indicators), u16::from(virtual_mods), u8::from(group_names), u8::from(n_keys), u8::from(n_key_aliases), u8::from(n_radio_groups))?;
         let group_names = group_names.into();
         let virtual_mods = virtual_mods.into();
@@ -11897,24 +11957,24 @@ pub struct GetKbdByNameRepliesGeometry {
 }
 impl TryParse for GetKbdByNameRepliesGeometry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (geometry_type, remaining) = u8::try_parse(remaining)?;
-        let (geometry_device_id, remaining) = u8::try_parse(remaining)?;
-        let (geometry_sequence, remaining) = u16::try_parse(remaining)?;
-        let (geometry_length, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (geometry_found, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (width_mm, remaining) = u16::try_parse(remaining)?;
-        let (height_mm, remaining) = u16::try_parse(remaining)?;
-        let (n_properties, remaining) = u16::try_parse(remaining)?;
-        let (n_colors, remaining) = u16::try_parse(remaining)?;
-        let (n_shapes, remaining) = u16::try_parse(remaining)?;
-        let (n_sections, remaining) = u16::try_parse(remaining)?;
-        let (n_doodads, remaining) = u16::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u16::try_parse(remaining)?;
-        let (base_color_ndx, remaining) = u8::try_parse(remaining)?;
-        let (label_color_ndx, remaining) = u8::try_parse(remaining)?;
-        let (label_font, remaining) = CountedString16::try_parse(remaining)?;
+        let (geometry_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "geometry_type")?;
+        let (geometry_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "geometry_device_id")?;
+        let (geometry_sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "geometry_sequence")?;
+        let (geometry_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "geometry_length")?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (geometry_found, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "geometry_found")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (width_mm, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_mm")?;
+        let (height_mm, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_mm")?;
+        let (n_properties, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_properties")?;
+        let (n_colors, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_colors")?;
+        let (n_shapes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_shapes")?;
+        let (n_sections, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_sections")?;
+        let (n_doodads, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_doodads")?;
+        let (n_key_aliases, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_key_aliases")?;
+        let (base_color_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "base_color_ndx")?;
+        let (label_color_ndx, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "label_color_ndx")?;
+        let (label_font, remaining) = crate::x11_utils::in_field(CountedString16::try_parse(remaining), "label_font")?;
         let result = GetKbdByNameRepliesGeometry { geometry_type, geometry_device_id, geometry_sequence, geometry_length, name, geometry_found, width_mm, height_mm, n_properties, n_colors, n_shapes, n_sections, n_doodads, n_key_aliases, base_color_ndx, label_color_ndx, label_font };
         Ok((result, remaining))
     }
@@ -12044,27 +12104,26 @@ pub struct GetKbdByNameReply {
 impl TryParse for GetKbdByNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (loaded, remaining) = bool::try_parse(remaining)?;
-        let (new_keyboard, remaining) = bool::try_parse(remaining)?;
-        let (found, remaining) = u16::try_parse(remaining)?;
-        let (reported, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (loaded, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "loaded")?;
+        let (new_keyboard, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "new_keyboard")?;
+        let (found, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "found")?;
+        let (reported, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "reported")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         let (replies, remaining) = GetKbdByNameReplies::try_parse(remaining, u16::from(reported))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let found = found.into();
         let reported = reported.into();
         let result = GetKbdByNameReply { device_id, sequence, length, min_key_code, max_key_code, loaded, new_keyboard, found, reported, replies };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12146,16 +12205,16 @@ impl GetDeviceInfoRequest {
         if header.minor_opcode != GET_DEVICE_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (wanted, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (wanted, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "wanted")?;
         let wanted = wanted.into();
-        let (all_buttons, remaining) = bool::try_parse(remaining)?;
-        let (first_button, remaining) = u8::try_parse(remaining)?;
-        let (n_buttons, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
+        let (all_buttons, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "all_buttons")?;
+        let (first_button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_button")?;
+        let (n_buttons, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_buttons")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (led_class, remaining) = crate::x11_utils::in_field(LedClassSpec::try_parse(remaining), "led_class")?;
         let led_class = led_class.into();
-        let (led_id, remaining) = IDSpec::try_parse(remaining)?;
+        let (led_id, remaining) = crate::x11_utils::in_field(IDSpec::try_parse(remaining), "led_id")?;
         let _ = remaining;
         Ok(GetDeviceInfoRequest {
             device_spec,
@@ -12170,6 +12229,7 @@ impl GetDeviceInfoRequest {
 }
 impl Request for GetDeviceInfoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12207,43 +12267,42 @@ impl TryParse for GetDeviceInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
         let value = remaining;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (present, remaining) = u16::try_parse(remaining)?;
-        let (supported, remaining) = u16::try_parse(remaining)?;
-        let (unsupported, remaining) = u16::try_parse(remaining)?;
-        let (n_device_led_f_bs, remaining) = u16::try_parse(remaining)?;
-        let (first_btn_wanted, remaining) = u8::try_parse(remaining)?;
-        let (n_btns_wanted, remaining) = u8::try_parse(remaining)?;
-        let (first_btn_rtrn, remaining) = u8::try_parse(remaining)?;
-        let (n_btns_rtrn, remaining) = u8::try_parse(remaining)?;
-        let (total_btns, remaining) = u8::try_parse(remaining)?;
-        let (has_own_state, remaining) = bool::try_parse(remaining)?;
-        let (dflt_kbd_fb, remaining) = u16::try_parse(remaining)?;
-        let (dflt_led_fb, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (dev_type, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (present, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "present")?;
+        let (supported, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "supported")?;
+        let (unsupported, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "unsupported")?;
+        let (n_device_led_f_bs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_device_led_f_bs")?;
+        let (first_btn_wanted, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_btn_wanted")?;
+        let (n_btns_wanted, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_btns_wanted")?;
+        let (first_btn_rtrn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_btn_rtrn")?;
+        let (n_btns_rtrn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_btns_rtrn")?;
+        let (total_btns, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "total_btns")?;
+        let (has_own_state, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "has_own_state")?;
+        let (dflt_kbd_fb, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dflt_kbd_fb")?;
+        let (dflt_led_fb, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dflt_led_fb")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (dev_type, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "dev_type")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns_rtrn.try_to_usize()?)?;
-        let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns_rtrn.try_to_usize()?, Some("btn_actions"))?;
+        let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_to_usize()?, Some("leds"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let present = present.into();
         let supported = supported.into();
         let unsupported = unsupported.into();
         let result = GetDeviceInfoReply { device_id, sequence, length, present, supported, unsupported, first_btn_wanted, n_btns_wanted, first_btn_rtrn, total_btns, has_own_state, dflt_kbd_fb, dflt_led_fb, dev_type, name, btn_actions, leds };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12380,14 +12439,14 @@ impl<'input> SetDeviceInfoRequest<'input> {
         if header.minor_opcode != SET_DEVICE_INFO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device_spec, remaining) = DeviceSpec::try_parse(value)?;
-        let (first_btn, remaining) = u8::try_parse(remaining)?;
-        let (n_btns, remaining) = u8::try_parse(remaining)?;
-        let (change, remaining) = u16::try_parse(remaining)?;
+        let (device_spec, remaining) = crate::x11_utils::in_field(DeviceSpec::try_parse(value), "device_spec")?;
+        let (first_btn, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_btn")?;
+        let (n_btns, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_btns")?;
+        let (change, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "change")?;
         let change = change.into();
-        let (n_device_led_f_bs, remaining) = u16::try_parse(remaining)?;
-        let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns.try_to_usize()?)?;
-        let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_to_usize()?)?;
+        let (n_device_led_f_bs, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_device_led_f_bs")?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list::<Action>(remaining, n_btns.try_to_usize()?, Some("btn_actions"))?;
+        let (leds, remaining) = crate::x11_utils::parse_list::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_to_usize()?, Some("leds"))?;
         let _ = remaining;
         Ok(SetDeviceInfoRequest {
             device_spec,
@@ -12410,6 +12469,7 @@ impl<'input> SetDeviceInfoRequest<'input> {
 }
 impl<'input> Request for SetDeviceInfoRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12482,13 +12542,13 @@ impl<'input> SetDebuggingFlagsRequest<'input> {
         if header.minor_opcode != SET_DEBUGGING_FLAGS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (msg_length, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (affect_flags, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (affect_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (ctrls, remaining) = u32::try_parse(remaining)?;
-        let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, msg_length.try_to_usize()?)?;
+        let (msg_length, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "msg_length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (affect_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_flags")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (affect_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "affect_ctrls")?;
+        let (ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "ctrls")?;
+        let (message, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, msg_length.try_to_usize()?), "message")?;
         let _ = remaining;
         Ok(SetDebuggingFlagsRequest {
             affect_flags,
@@ -12511,6 +12571,7 @@ impl<'input> SetDebuggingFlagsRequest<'input> {
 }
 impl<'input> Request for SetDebuggingFlagsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -12536,22 +12597,21 @@ pub struct SetDebuggingFlagsReply {
 impl TryParse for SetDebuggingFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (current_flags, remaining) = u32::try_parse(remaining)?;
-        let (current_ctrls, remaining) = u32::try_parse(remaining)?;
-        let (supported_flags, remaining) = u32::try_parse(remaining)?;
-        let (supported_ctrls, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (current_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "current_flags")?;
+        let (current_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "current_ctrls")?;
+        let (supported_flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "supported_flags")?;
+        let (supported_ctrls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "supported_ctrls")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = SetDebuggingFlagsReply { sequence, length, current_flags, current_ctrls, supported_flags, supported_ctrls };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12637,20 +12697,20 @@ pub struct NewKeyboardNotifyEvent {
 impl TryParse for NewKeyboardNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (old_device_id, remaining) = u8::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (old_min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (old_max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (old_device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "old_device_id")?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (old_min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "old_min_key_code")?;
+        let (old_max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "old_max_key_code")?;
+        let (request_major, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_major")?;
+        let (request_minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_minor")?;
+        let (changed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "changed")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 14)?;
         let changed = changed.into();
         let result = NewKeyboardNotifyEvent { response_type, xkb_type, sequence, time, device_id, old_device_id, min_key_code, max_key_code, old_min_key_code, old_max_key_code, request_major, request_minor, changed };
         let _ = remaining;
@@ -12818,31 +12878,31 @@ pub struct MapNotifyEvent {
 impl TryParse for MapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (ptr_btn_actions, remaining) = u8::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (min_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (max_key_code, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_key_sym, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_syms, remaining) = u8::try_parse(remaining)?;
-        let (first_key_act, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_acts, remaining) = u8::try_parse(remaining)?;
-        let (first_key_behavior, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_behavior, remaining) = u8::try_parse(remaining)?;
-        let (first_key_explicit, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_key_explicit, remaining) = u8::try_parse(remaining)?;
-        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_v_mod_map_keys, remaining) = u8::try_parse(remaining)?;
-        let (virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (ptr_btn_actions, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ptr_btn_actions")?;
+        let (changed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "changed")?;
+        let (min_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "min_key_code")?;
+        let (max_key_code, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "max_key_code")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (first_key_sym, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_sym")?;
+        let (n_key_syms, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_syms")?;
+        let (first_key_act, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_act")?;
+        let (n_key_acts, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_acts")?;
+        let (first_key_behavior, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_behavior")?;
+        let (n_key_behavior, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_behavior")?;
+        let (first_key_explicit, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key_explicit")?;
+        let (n_key_explicit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_explicit")?;
+        let (first_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_mod_map_key")?;
+        let (n_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_mod_map_keys")?;
+        let (first_v_mod_map_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_v_mod_map_key")?;
+        let (n_v_mod_map_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_v_mod_map_keys")?;
+        let (virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "virtual_mods")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let changed = changed.into();
         let virtual_mods = virtual_mods.into();
         let result = MapNotifyEvent { response_type, xkb_type, sequence, time, device_id, ptr_btn_actions, changed, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, first_key_act, n_key_acts, first_key_behavior, n_key_behavior, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, virtual_mods };
@@ -13044,30 +13104,30 @@ pub struct StateNotifyEvent {
 impl TryParse for StateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (base_mods, remaining) = u8::try_parse(remaining)?;
-        let (latched_mods, remaining) = u8::try_parse(remaining)?;
-        let (locked_mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
-        let (base_group, remaining) = i16::try_parse(remaining)?;
-        let (latched_group, remaining) = i16::try_parse(remaining)?;
-        let (locked_group, remaining) = u8::try_parse(remaining)?;
-        let (compat_state, remaining) = u8::try_parse(remaining)?;
-        let (grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_grab_mods, remaining) = u8::try_parse(remaining)?;
-        let (lookup_mods, remaining) = u8::try_parse(remaining)?;
-        let (compat_loockup_mods, remaining) = u8::try_parse(remaining)?;
-        let (ptr_btn_state, remaining) = u16::try_parse(remaining)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (event_type, remaining) = u8::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
+        let (base_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "base_mods")?;
+        let (latched_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "latched_mods")?;
+        let (locked_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "locked_mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group")?;
+        let (base_group, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "base_group")?;
+        let (latched_group, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "latched_group")?;
+        let (locked_group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "locked_group")?;
+        let (compat_state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_state")?;
+        let (grab_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "grab_mods")?;
+        let (compat_grab_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_grab_mods")?;
+        let (lookup_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "lookup_mods")?;
+        let (compat_loockup_mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "compat_loockup_mods")?;
+        let (ptr_btn_state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "ptr_btn_state")?;
+        let (changed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "changed")?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "event_type")?;
+        let (request_major, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_major")?;
+        let (request_minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_minor")?;
         let mods = mods.into();
         let base_mods = base_mods.into();
         let latched_mods = latched_mods.into();
@@ -13268,21 +13328,21 @@ pub struct ControlsNotifyEvent {
 impl TryParse for ControlsNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (num_groups, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (changed_controls, remaining) = u32::try_parse(remaining)?;
-        let (enabled_controls, remaining) = u32::try_parse(remaining)?;
-        let (enabled_control_changes, remaining) = u32::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (event_type, remaining) = u8::try_parse(remaining)?;
-        let (request_major, remaining) = u8::try_parse(remaining)?;
-        let (request_minor, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (num_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_groups")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (changed_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "changed_controls")?;
+        let (enabled_controls, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "enabled_controls")?;
+        let (enabled_control_changes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "enabled_control_changes")?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "event_type")?;
+        let (request_major, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_major")?;
+        let (request_minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request_minor")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let changed_controls = changed_controls.into();
         let enabled_controls = enabled_controls.into();
         let enabled_control_changes = enabled_control_changes.into();
@@ -13436,15 +13496,15 @@ pub struct IndicatorStateNotifyEvent {
 impl TryParse for IndicatorStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (state_changed, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state")?;
+        let (state_changed, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state_changed")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         let result = IndicatorStateNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, state_changed };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -13577,15 +13637,15 @@ pub struct IndicatorMapNotifyEvent {
 impl TryParse for IndicatorMapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (state, remaining) = u32::try_parse(remaining)?;
-        let (map_changed, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "state")?;
+        let (map_changed, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "map_changed")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
         let result = IndicatorMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, map_changed };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -13728,26 +13788,26 @@ pub struct NamesNotifyEvent {
 impl TryParse for NamesNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (changed, remaining) = u16::try_parse(remaining)?;
-        let (first_type, remaining) = u8::try_parse(remaining)?;
-        let (n_types, remaining) = u8::try_parse(remaining)?;
-        let (first_level_name, remaining) = u8::try_parse(remaining)?;
-        let (n_level_names, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (n_radio_groups, remaining) = u8::try_parse(remaining)?;
-        let (n_key_aliases, remaining) = u8::try_parse(remaining)?;
-        let (changed_group_names, remaining) = u8::try_parse(remaining)?;
-        let (changed_virtual_mods, remaining) = u16::try_parse(remaining)?;
-        let (first_key, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (n_keys, remaining) = u8::try_parse(remaining)?;
-        let (changed_indicators, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (changed, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "changed")?;
+        let (first_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_type")?;
+        let (n_types, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_types")?;
+        let (first_level_name, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_level_name")?;
+        let (n_level_names, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_level_names")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (n_radio_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_radio_groups")?;
+        let (n_key_aliases, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_key_aliases")?;
+        let (changed_group_names, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "changed_group_names")?;
+        let (changed_virtual_mods, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "changed_virtual_mods")?;
+        let (first_key, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "first_key")?;
+        let (n_keys, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_keys")?;
+        let (changed_indicators, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "changed_indicators")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let changed = changed.into();
         let changed_group_names = changed_group_names.into();
         let changed_virtual_mods = changed_virtual_mods.into();
@@ -13916,16 +13976,16 @@ pub struct CompatMapNotifyEvent {
 impl TryParse for CompatMapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (changed_groups, remaining) = u8::try_parse(remaining)?;
-        let (first_si, remaining) = u16::try_parse(remaining)?;
-        let (n_si, remaining) = u16::try_parse(remaining)?;
-        let (n_total_si, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (changed_groups, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "changed_groups")?;
+        let (first_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "first_si")?;
+        let (n_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_si")?;
+        let (n_total_si, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "n_total_si")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         let changed_groups = changed_groups.into();
         let result = CompatMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, changed_groups, first_si, n_si, n_total_si };
         let _ = remaining;
@@ -14070,20 +14130,20 @@ pub struct BellNotifyEvent {
 impl TryParse for BellNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (bell_class, remaining) = u8::try_parse(remaining)?;
-        let (bell_id, remaining) = u8::try_parse(remaining)?;
-        let (percent, remaining) = u8::try_parse(remaining)?;
-        let (pitch, remaining) = u16::try_parse(remaining)?;
-        let (duration, remaining) = u16::try_parse(remaining)?;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (window, remaining) = xproto::Window::try_parse(remaining)?;
-        let (event_only, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (bell_class, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bell_class")?;
+        let (bell_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bell_id")?;
+        let (percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "percent")?;
+        let (pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pitch")?;
+        let (duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "duration")?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "window")?;
+        let (event_only, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "event_only")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 7)?;
         let bell_class = bell_class.into();
         let result = BellNotifyEvent { response_type, xkb_type, sequence, time, device_id, bell_class, bell_id, percent, pitch, duration, name, window, event_only };
         let _ = remaining;
@@ -14238,18 +14298,18 @@ pub struct ActionMessageEvent {
 impl TryParse for ActionMessageEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (press, remaining) = bool::try_parse(remaining)?;
-        let (key_event_follows, remaining) = bool::try_parse(remaining)?;
-        let (mods, remaining) = u8::try_parse(remaining)?;
-        let (group, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (press, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "press")?;
+        let (key_event_follows, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "key_event_follows")?;
+        let (mods, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mods")?;
+        let (group, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "group")?;
         let (message, remaining) = crate::x11_utils::parse_u8_array::<8>(remaining)?;
-        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 10)?;
         let mods = mods.into();
         let group = group.into();
         let result = ActionMessageEvent { response_type, xkb_type, sequence, time, device_id, keycode, press, key_event_follows, mods, group, message };
@@ -14395,16 +14455,16 @@ pub struct AccessXNotifyEvent {
 impl TryParse for AccessXNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
-        let (detailt, remaining) = u16::try_parse(remaining)?;
-        let (slow_keys_delay, remaining) = u16::try_parse(remaining)?;
-        let (debounce_delay, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let (keycode, remaining) = crate::x11_utils::in_field(xproto::Keycode::try_parse(remaining), "keycode")?;
+        let (detailt, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "detailt")?;
+        let (slow_keys_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "slow_keys_delay")?;
+        let (debounce_delay, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "debounce_delay")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
         let detailt = detailt.into();
         let result = AccessXNotifyEvent { response_type, xkb_type, sequence, time, device_id, keycode, detailt, slow_keys_delay, debounce_delay };
         let _ = remaining;
@@ -14550,22 +14610,22 @@ pub struct ExtensionDeviceNotifyEvent {
 impl TryParse for ExtensionDeviceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (xkb_type, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (device_id, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (reason, remaining) = u16::try_parse(remaining)?;
-        let (led_class, remaining) = u16::try_parse(remaining)?;
-        let (led_id, remaining) = u16::try_parse(remaining)?;
-        let (leds_defined, remaining) = u32::try_parse(remaining)?;
-        let (led_state, remaining) = u32::try_parse(remaining)?;
-        let (first_button, remaining) = u8::try_parse(remaining)?;
-        let (n_buttons, remaining) = u8::try_parse(remaining)?;
-        let (supported, remaining) = u16::try_parse(remaining)?;
-        let (unsupported, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (xkb_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "xkb_type")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (device_id, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "device_id")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (reason, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "reason")?;
+        let (led_class, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "led_class")?;
+        let (led_id, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "led_id")?;
+        let (leds_defined, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "leds_defined")?;
+        let (led_state, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_state")?;
+        let (first_button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_button")?;
+        let (n_buttons, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "n_buttons")?;
+        let (supported, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "supported")?;
+        let (unsupported, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "unsupported")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let reason = reason.into();
         let led_class = led_class.into();
         let supported = supported.into();
@@ -47,20 +47,20 @@ pub struct Printer {
 impl TryParse for Printer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (name_len, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "name_len")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (desc_len, remaining) = u32::try_parse(remaining)?;
-        let (description, remaining) = crate::x11_utils::parse_u8_list(remaining, desc_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (desc_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "desc_len")?;
+        let (description, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, desc_len.try_to_usize()?), "description")?;
         let description = description.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = Printer { name, description };
         Ok((result, remaining))
     }
@@ -245,7 +245,7 @@ impl core::fmt::Debug for EvMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(EvMask, u8);
+bitmask_binop!(EvMask, u8, [PRINT_MASK, ATTRIBUTE_MASK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -416,6 +416,7 @@ impl PrintQueryVersionRequest {
 }
 impl Request for PrintQueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -439,19 +440,18 @@ pub struct PrintQueryVersionReply {
 impl TryParse for PrintQueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major_version, remaining) = u16::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major_version")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintQueryVersionReply { sequence, length, major_version, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -537,14 +537,14 @@ impl<'input> PrintGetPrinterListRequest<'input> {
         if header.minor_opcode != PRINT_GET_PRINTER_LIST_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (printer_name_len, remaining) = u32::try_parse(value)?;
-        let (locale_len, remaining) = u32::try_parse(remaining)?;
-        let (printer_name, remaining) = crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_to_usize()?)?;
+        let (printer_name_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "printer_name_len")?;
+        let (locale_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "locale_len")?;
+        let (printer_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_to_usize()?), "printer_name")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (locale, remaining) = crate::x11_utils::parse_u8_list(remaining, locale_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (locale, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, locale_len.try_to_usize()?), "locale")?;
         let _ = remaining;
         Ok(PrintGetPrinterListRequest {
             printer_name: Cow::Borrowed(printer_name),
@@ -561,6 +561,7 @@ impl<'input> PrintGetPrinterListRequest<'input> {
 }
 impl<'input> Request for PrintGetPrinterListRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -583,20 +584,19 @@ pub struct PrintGetPrinterListReply {
 impl TryParse for PrintGetPrinterListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (list_count, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (printers, remaining) = crate::x11_utils::parse_list::<Printer>(remaining, list_count.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (list_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list_count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (printers, remaining) = crate::x11_utils::parse_list::<Printer>(remaining, list_count.try_to_usize()?, Some("printers"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetPrinterListReply { sequence, length, printers };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -635,6 +635,27 @@ impl PrintGetPrinterListReply {
             .try_into().unwrap()
     }
 }
+impl PrintGetPrinterListReply {
+    /// Lazily parse the `printers` list from the raw bytes of a `PrintGetPrinterListReply`.
+    ///
+    /// In contrast to parsing the whole `PrintGetPrinterListReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn printers_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Printer>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (list_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list_count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = list_count.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the PrintRehashPrinterList request
 pub const PRINT_REHASH_PRINTER_LIST_REQUEST: u8 = 20;
@@ -669,6 +690,7 @@ impl PrintRehashPrinterListRequest {
 }
 impl Request for PrintRehashPrinterListRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -733,15 +755,15 @@ impl<'input> CreateContextRequest<'input> {
         if header.minor_opcode != CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_id, remaining) = u32::try_parse(value)?;
-        let (printer_name_len, remaining) = u32::try_parse(remaining)?;
-        let (locale_len, remaining) = u32::try_parse(remaining)?;
-        let (printer_name, remaining) = crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_to_usize()?)?;
+        let (context_id, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_id")?;
+        let (printer_name_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "printer_name_len")?;
+        let (locale_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "locale_len")?;
+        let (printer_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, printer_name_len.try_to_usize()?), "printer_name")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (locale, remaining) = crate::x11_utils::parse_u8_list(remaining, locale_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (locale, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, locale_len.try_to_usize()?), "locale")?;
         let _ = remaining;
         Ok(CreateContextRequest {
             context_id,
@@ -760,6 +782,7 @@ impl<'input> CreateContextRequest<'input> {
 }
 impl<'input> Request for CreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -804,7 +827,7 @@ impl PrintSetContextRequest {
         if header.minor_opcode != PRINT_SET_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = u32::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context")?;
         let _ = remaining;
         Ok(PrintSetContextRequest {
             context,
@@ -813,6 +836,7 @@ impl PrintSetContextRequest {
 }
 impl Request for PrintSetContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -857,6 +881,7 @@ impl PrintGetContextRequest {
 }
 impl Request for PrintGetContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -879,18 +904,17 @@ pub struct PrintGetContextReply {
 impl TryParse for PrintGetContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -960,7 +984,7 @@ impl PrintDestroyContextRequest {
         if header.minor_opcode != PRINT_DESTROY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = u32::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context")?;
         let _ = remaining;
         Ok(PrintDestroyContextRequest {
             context,
@@ -969,6 +993,7 @@ impl PrintDestroyContextRequest {
 }
 impl Request for PrintDestroyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1013,6 +1038,7 @@ impl PrintGetScreenOfContextRequest {
 }
 impl Request for PrintGetScreenOfContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1035,18 +1061,17 @@ pub struct PrintGetScreenOfContextReply {
 impl TryParse for PrintGetScreenOfContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetScreenOfContextReply { sequence, length, root };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1116,7 +1141,7 @@ impl PrintStartJobRequest {
         if header.minor_opcode != PRINT_START_JOB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (output_mode, remaining) = u8::try_parse(value)?;
+        let (output_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "output_mode")?;
         let _ = remaining;
         Ok(PrintStartJobRequest {
             output_mode,
@@ -1125,6 +1150,7 @@ impl PrintStartJobRequest {
 }
 impl Request for PrintStartJobRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1169,7 +1195,7 @@ impl PrintEndJobRequest {
         if header.minor_opcode != PRINT_END_JOB_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cancel, remaining) = bool::try_parse(value)?;
+        let (cancel, remaining) = crate::x11_utils::in_field(bool::try_parse(value), "cancel")?;
         let _ = remaining;
         Ok(PrintEndJobRequest {
             cancel,
@@ -1178,6 +1204,7 @@ impl PrintEndJobRequest {
 }
 impl Request for PrintEndJobRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1222,7 +1249,7 @@ impl PrintStartDocRequest {
         if header.minor_opcode != PRINT_START_DOC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (driver_mode, remaining) = u8::try_parse(value)?;
+        let (driver_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "driver_mode")?;
         let _ = remaining;
         Ok(PrintStartDocRequest {
             driver_mode,
@@ -1231,6 +1258,7 @@ impl PrintStartDocRequest {
 }
 impl Request for PrintStartDocRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1275,7 +1303,7 @@ impl PrintEndDocRequest {
         if header.minor_opcode != PRINT_END_DOC_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cancel, remaining) = bool::try_parse(value)?;
+        let (cancel, remaining) = crate::x11_utils::in_field(bool::try_parse(value), "cancel")?;
         let _ = remaining;
         Ok(PrintEndDocRequest {
             cancel,
@@ -1284,6 +1312,7 @@ impl PrintEndDocRequest {
 }
 impl Request for PrintEndDocRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1354,21 +1383,21 @@ impl<'input> PrintPutDocumentDataRequest<'input> {
         if header.minor_opcode != PRINT_PUT_DOCUMENT_DATA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (len_data, remaining) = u32::try_parse(remaining)?;
-        let (len_fmt, remaining) = u16::try_parse(remaining)?;
-        let (len_options, remaining) = u16::try_parse(remaining)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, len_data.try_to_usize()?)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (len_data, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "len_data")?;
+        let (len_fmt, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len_fmt")?;
+        let (len_options, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "len_options")?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, len_data.try_to_usize()?), "data")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (doc_format, remaining) = crate::x11_utils::parse_u8_list(remaining, len_fmt.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (doc_format, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, len_fmt.try_to_usize()?), "doc_format")?;
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (options, remaining) = crate::x11_utils::parse_u8_list(remaining, len_options.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (options, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, len_options.try_to_usize()?), "options")?;
         let _ = remaining;
         Ok(PrintPutDocumentDataRequest {
             drawable,
@@ -1389,6 +1418,7 @@ impl<'input> PrintPutDocumentDataRequest<'input> {
 }
 impl<'input> Request for PrintPutDocumentDataRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1439,8 +1469,8 @@ impl PrintGetDocumentDataRequest {
         if header.minor_opcode != PRINT_GET_DOCUMENT_DATA_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (max_bytes, remaining) = u32::try_parse(remaining)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (max_bytes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "max_bytes")?;
         let _ = remaining;
         Ok(PrintGetDocumentDataRequest {
             context,
@@ -1450,6 +1480,7 @@ impl PrintGetDocumentDataRequest {
 }
 impl Request for PrintGetDocumentDataRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1474,23 +1505,22 @@ pub struct PrintGetDocumentDataReply {
 impl TryParse for PrintGetDocumentDataReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (status_code, remaining) = u32::try_parse(remaining)?;
-        let (finished_flag, remaining) = u32::try_parse(remaining)?;
-        let (data_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (status_code, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "status_code")?;
+        let (finished_flag, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "finished_flag")?;
+        let (data_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, data_len.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetDocumentDataReply { sequence, length, status_code, finished_flag, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1565,7 +1595,7 @@ impl PrintStartPageRequest {
         if header.minor_opcode != PRINT_START_PAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(PrintStartPageRequest {
             window,
@@ -1574,6 +1604,7 @@ impl PrintStartPageRequest {
 }
 impl Request for PrintStartPageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1618,8 +1649,8 @@ impl PrintEndPageRequest {
         if header.minor_opcode != PRINT_END_PAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (cancel, remaining) = bool::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (cancel, remaining) = crate::x11_utils::in_field(bool::try_parse(value), "cancel")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(PrintEndPageRequest {
             cancel,
@@ -1628,6 +1659,7 @@ impl PrintEndPageRequest {
 }
 impl Request for PrintEndPageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1678,8 +1710,8 @@ impl PrintSelectInputRequest {
         if header.minor_opcode != PRINT_SELECT_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let _ = remaining;
         Ok(PrintSelectInputRequest {
             context,
@@ -1689,6 +1721,7 @@ impl PrintSelectInputRequest {
 }
 impl Request for PrintSelectInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1733,7 +1766,7 @@ impl PrintInputSelectedRequest {
         if header.minor_opcode != PRINT_INPUT_SELECTED_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
         let _ = remaining;
         Ok(PrintInputSelectedRequest {
             context,
@@ -1742,6 +1775,7 @@ impl PrintInputSelectedRequest {
 }
 impl Request for PrintInputSelectedRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1765,19 +1799,18 @@ pub struct PrintInputSelectedReply {
 impl TryParse for PrintInputSelectedReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
-        let (all_events_mask, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
+        let (all_events_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "all_events_mask")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintInputSelectedReply { sequence, length, event_mask, all_events_mask };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1859,9 +1892,9 @@ impl PrintGetAttributesRequest {
         if header.minor_opcode != PRINT_GET_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (pool, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (pool, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pool")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(PrintGetAttributesRequest {
             context,
@@ -1871,6 +1904,7 @@ impl PrintGetAttributesRequest {
 }
 impl Request for PrintGetAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1893,21 +1927,20 @@ pub struct PrintGetAttributesReply {
 impl TryParse for PrintGetAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (string_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (attributes, remaining) = crate::x11_utils::parse_u8_list(remaining, string_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (string_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "string_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (attributes, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, string_len.try_to_usize()?), "attributes")?;
         let attributes = attributes.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetAttributesReply { sequence, length, attributes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1996,11 +2029,11 @@ impl<'input> PrintGetOneAttributesRequest<'input> {
         if header.minor_opcode != PRINT_GET_ONE_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (name_len, remaining) = u32::try_parse(remaining)?;
-        let (pool, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "name_len")?;
+        let (pool, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pool")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(PrintGetOneAttributesRequest {
             context,
@@ -2019,6 +2052,7 @@ impl<'input> PrintGetOneAttributesRequest<'input> {
 }
 impl<'input> Request for PrintGetOneAttributesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2041,21 +2075,20 @@ pub struct PrintGetOneAttributesReply {
 impl TryParse for PrintGetOneAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (value, remaining) = crate::x11_utils::parse_u8_list(remaining, value_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (value_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (value, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, value_len.try_to_usize()?), "value")?;
         let value = value.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetOneAttributesReply { sequence, length, value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2146,11 +2179,11 @@ impl<'input> PrintSetAttributesRequest<'input> {
         if header.minor_opcode != PRINT_SET_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (string_len, remaining) = u32::try_parse(remaining)?;
-        let (pool, remaining) = u8::try_parse(remaining)?;
-        let (rule, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (string_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "string_len")?;
+        let (pool, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pool")?;
+        let (rule, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "rule")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let (attributes, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(PrintSetAttributesRequest {
@@ -2174,6 +2207,7 @@ impl<'input> PrintSetAttributesRequest<'input> {
 }
 impl<'input> Request for PrintSetAttributesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2218,7 +2252,7 @@ impl PrintGetPageDimensionsRequest {
         if header.minor_opcode != PRINT_GET_PAGE_DIMENSIONS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
         let _ = remaining;
         Ok(PrintGetPageDimensionsRequest {
             context,
@@ -2227,6 +2261,7 @@ impl PrintGetPageDimensionsRequest {
 }
 impl Request for PrintGetPageDimensionsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2254,23 +2289,22 @@ pub struct PrintGetPageDimensionsReply {
 impl TryParse for PrintGetPageDimensionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (offset_x, remaining) = u16::try_parse(remaining)?;
-        let (offset_y, remaining) = u16::try_parse(remaining)?;
-        let (reproducible_width, remaining) = u16::try_parse(remaining)?;
-        let (reproducible_height, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (offset_x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "offset_x")?;
+        let (offset_y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "offset_y")?;
+        let (reproducible_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "reproducible_width")?;
+        let (reproducible_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "reproducible_height")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetPageDimensionsReply { sequence, length, width, height, offset_x, offset_y, reproducible_width, reproducible_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2358,6 +2392,7 @@ impl PrintQueryScreensRequest {
 }
 impl Request for PrintQueryScreensRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2380,20 +2415,19 @@ pub struct PrintQueryScreensReply {
 impl TryParse for PrintQueryScreensReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (list_count, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (roots, remaining) = crate::x11_utils::parse_list::<xproto::Window>(remaining, list_count.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (list_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list_count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (roots, remaining) = crate::x11_utils::parse_list::<xproto::Window>(remaining, list_count.try_to_usize()?, Some("roots"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintQueryScreensReply { sequence, length, roots };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2432,6 +2466,27 @@ impl PrintQueryScreensReply {
             .try_into().unwrap()
     }
 }
+impl PrintQueryScreensReply {
+    /// Lazily parse the `roots` list from the raw bytes of a `PrintQueryScreensReply`.
+    ///
+    /// In contrast to parsing the whole `PrintQueryScreensReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn roots_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xproto::Window>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (list_count, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "list_count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = list_count.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the PrintSetImageResolution request
 pub const PRINT_SET_IMAGE_RESOLUTION_REQUEST: u8 = 23;
@@ -2472,8 +2527,8 @@ impl PrintSetImageResolutionRequest {
         if header.minor_opcode != PRINT_SET_IMAGE_RESOLUTION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
-        let (image_resolution, remaining) = u16::try_parse(remaining)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
+        let (image_resolution, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "image_resolution")?;
         let _ = remaining;
         Ok(PrintSetImageResolutionRequest {
             context,
@@ -2483,6 +2538,7 @@ impl PrintSetImageResolutionRequest {
 }
 impl Request for PrintSetImageResolutionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2506,18 +2562,17 @@ pub struct PrintSetImageResolutionReply {
 impl TryParse for PrintSetImageResolutionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (previous_resolutions, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (previous_resolutions, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "previous_resolutions")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintSetImageResolutionReply { status, sequence, length, previous_resolutions };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2586,7 +2641,7 @@ impl PrintGetImageResolutionRequest {
         if header.minor_opcode != PRINT_GET_IMAGE_RESOLUTION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context, remaining) = Pcontext::try_parse(value)?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(value), "context")?;
         let _ = remaining;
         Ok(PrintGetImageResolutionRequest {
             context,
@@ -2595,6 +2650,7 @@ impl PrintGetImageResolutionRequest {
 }
 impl Request for PrintGetImageResolutionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2617,18 +2673,17 @@ pub struct PrintGetImageResolutionReply {
 impl TryParse for PrintGetImageResolutionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (image_resolution, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (image_resolution, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "image_resolution")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = PrintGetImageResolutionReply { sequence, length, image_resolution };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2677,11 +2732,11 @@ pub struct NotifyEvent {
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (context, remaining) = Pcontext::try_parse(remaining)?;
-        let (cancel, remaining) = bool::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(remaining), "context")?;
+        let (cancel, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "cancel")?;
         let result = NotifyEvent { response_type, detail, sequence, context, cancel };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2781,10 +2836,10 @@ pub struct AttributNotifyEvent {
 impl TryParse for AttributNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (context, remaining) = Pcontext::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (context, remaining) = crate::x11_utils::in_field(Pcontext::try_parse(remaining), "context")?;
         let result = AttributNotifyEvent { response_type, detail, sequence, context };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -36,8 +36,8 @@ pub struct Char2b {
 }
 impl TryParse for Char2b {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (byte1, remaining) = u8::try_parse(remaining)?;
-        let (byte2, remaining) = u8::try_parse(remaining)?;
+        let (byte1, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "byte1")?;
+        let (byte2, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "byte2")?;
         let result = Char2b { byte1, byte2 };
         Ok((result, remaining))
     }
@@ -99,8 +99,8 @@ pub struct Point {
 }
 impl TryParse for Point {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let result = Point { x, y };
         Ok((result, remaining))
     }
@@ -134,10 +134,10 @@ pub struct Rectangle {
 }
 impl TryParse for Rectangle {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let result = Rectangle { x, y, width, height };
         Ok((result, remaining))
     }
@@ -181,12 +181,12 @@ pub struct Arc {
 }
 impl TryParse for Arc {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (angle1, remaining) = i16::try_parse(remaining)?;
-        let (angle2, remaining) = i16::try_parse(remaining)?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (angle1, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "angle1")?;
+        let (angle2, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "angle2")?;
         let result = Arc { x, y, width, height, angle1, angle2 };
         Ok((result, remaining))
     }
@@ -235,10 +235,10 @@ pub struct Format {
 }
 impl TryParse for Format {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (bits_per_pixel, remaining) = u8::try_parse(remaining)?;
-        let (scanline_pad, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (bits_per_pixel, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bits_per_pixel")?;
+        let (scanline_pad, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "scanline_pad")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 5)?;
         let result = Format { depth, bits_per_pixel, scanline_pad };
         Ok((result, remaining))
     }
@@ -349,14 +349,14 @@ pub struct Visualtype {
 }
 impl TryParse for Visualtype {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual_id, remaining) = Visualid::try_parse(remaining)?;
-        let (class, remaining) = u8::try_parse(remaining)?;
-        let (bits_per_rgb_value, remaining) = u8::try_parse(remaining)?;
-        let (colormap_entries, remaining) = u16::try_parse(remaining)?;
-        let (red_mask, remaining) = u32::try_parse(remaining)?;
-        let (green_mask, remaining) = u32::try_parse(remaining)?;
-        let (blue_mask, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (visual_id, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "visual_id")?;
+        let (class, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class")?;
+        let (bits_per_rgb_value, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bits_per_rgb_value")?;
+        let (colormap_entries, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "colormap_entries")?;
+        let (red_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red_mask")?;
+        let (green_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green_mask")?;
+        let (blue_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
         let class = class.into();
         let result = Visualtype { visual_id, class, bits_per_rgb_value, colormap_entries, red_mask, green_mask, blue_mask };
         Ok((result, remaining))
@@ -420,11 +420,11 @@ pub struct Depth {
 }
 impl TryParse for Depth {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (visuals_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (visuals, remaining) = crate::x11_utils::parse_list::<Visualtype>(remaining, visuals_len.try_to_usize()?)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (visuals_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visuals_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (visuals, remaining) = crate::x11_utils::parse_list::<Visualtype>(remaining, visuals_len.try_to_usize()?, Some("visuals"))?;
         let result = Depth { depth, visuals };
         Ok((result, remaining))
     }
@@ -556,7 +556,7 @@ impl core::fmt::Debug for EventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(EventMask, u32);
+bitmask_binop!(EventMask, u32, [KEY_PRESS, KEY_RELEASE, BUTTON_PRESS, BUTTON_RELEASE, ENTER_WINDOW, LEAVE_WINDOW, POINTER_MOTION, POINTER_MOTION_HINT, BUTTON1_MOTION, BUTTON2_MOTION, BUTTON3_MOTION, BUTTON4_MOTION, BUTTON5_MOTION, BUTTON_MOTION, KEYMAP_STATE, EXPOSURE, VISIBILITY_CHANGE, STRUCTURE_NOTIFY, RESIZE_REDIRECT, SUBSTRUCTURE_NOTIFY, SUBSTRUCTURE_REDIRECT, FOCUS_CHANGE, PROPERTY_CHANGE, COLOR_MAP_CHANGE, OWNER_GRAB_BUTTON]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -629,23 +629,23 @@ pub struct Screen {
 }
 impl TryParse for Screen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (default_colormap, remaining) = Colormap::try_parse(remaining)?;
-        let (white_pixel, remaining) = u32::try_parse(remaining)?;
-        let (black_pixel, remaining) = u32::try_parse(remaining)?;
-        let (current_input_masks, remaining) = u32::try_parse(remaining)?;
-        let (width_in_pixels, remaining) = u16::try_parse(remaining)?;
-        let (height_in_pixels, remaining) = u16::try_parse(remaining)?;
-        let (width_in_millimeters, remaining) = u16::try_parse(remaining)?;
-        let (height_in_millimeters, remaining) = u16::try_parse(remaining)?;
-        let (min_installed_maps, remaining) = u16::try_parse(remaining)?;
-        let (max_installed_maps, remaining) = u16::try_parse(remaining)?;
-        let (root_visual, remaining) = Visualid::try_parse(remaining)?;
-        let (backing_stores, remaining) = u8::try_parse(remaining)?;
-        let (save_unders, remaining) = bool::try_parse(remaining)?;
-        let (root_depth, remaining) = u8::try_parse(remaining)?;
-        let (allowed_depths_len, remaining) = u8::try_parse(remaining)?;
-        let (allowed_depths, remaining) = crate::x11_utils::parse_list::<Depth>(remaining, allowed_depths_len.try_to_usize()?)?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (default_colormap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "default_colormap")?;
+        let (white_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "white_pixel")?;
+        let (black_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "black_pixel")?;
+        let (current_input_masks, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "current_input_masks")?;
+        let (width_in_pixels, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_in_pixels")?;
+        let (height_in_pixels, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_in_pixels")?;
+        let (width_in_millimeters, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_in_millimeters")?;
+        let (height_in_millimeters, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_in_millimeters")?;
+        let (min_installed_maps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "min_installed_maps")?;
+        let (max_installed_maps, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_installed_maps")?;
+        let (root_visual, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "root_visual")?;
+        let (backing_stores, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "backing_stores")?;
+        let (save_unders, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "save_unders")?;
+        let (root_depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "root_depth")?;
+        let (allowed_depths_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "allowed_depths_len")?;
+        let (allowed_depths, remaining) = crate::x11_utils::parse_list::<Depth>(remaining, allowed_depths_len.try_to_usize()?, Some("allowed_depths"))?;
         let current_input_masks = current_input_masks.into();
         let backing_stores = backing_stores.into();
         let result = Screen { root, default_colormap, white_pixel, black_pixel, current_input_masks, width_in_pixels, height_in_pixels, width_in_millimeters, height_in_millimeters, min_installed_maps, max_installed_maps, root_visual, backing_stores, save_unders, root_depth, allowed_depths };
@@ -696,6 +696,55 @@ impl Screen {
             .try_into().unwrap()
     }
 }
+impl Screen {
+    /// Get the visual and the colormap of the root window.
+    ///
+    /// These are needed together for creating a window with the default visual:
+    /// ```
+    /// use x11rb_protocol::protocol::xproto::Screen;
+    ///
+    /// let screen = Screen {
+    ///     root_visual: 0x21,
+    ///     default_colormap: 0x20,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(screen.default_visual_and_colormap(), (0x21, 0x20));
+    /// ```
+    pub fn default_visual_and_colormap(&self) -> (Visualid, Colormap) {
+        (self.root_visual, self.default_colormap)
+    }
+
+    /// Get the horizontal and vertical resolution of the screen in dots per inch.
+    ///
+    /// The resolution is computed from the size of the screen in pixels and in millimeters.
+    /// Some X11 servers report a physical size of zero, e.g. because the monitor's EDID
+    /// data is broken. In this case, the common default of 96 DPI is returned instead.
+    /// ```
+    /// use x11rb_protocol::protocol::xproto::Screen;
+    ///
+    /// let screen = Screen {
+    ///     width_in_pixels: 1920,
+    ///     height_in_pixels: 1080,
+    ///     width_in_millimeters: 254,
+    ///     height_in_millimeters: 0,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(screen.dpi(), (192.0, 96.0));
+    /// ```
+    pub fn dpi(&self) -> (f64, f64) {
+        fn dpi(pixels: u16, millimeters: u16) -> f64 {
+            if millimeters == 0 {
+                96.0
+            } else {
+                f64::from(pixels) * 25.4 / f64::from(millimeters)
+            }
+        }
+        (
+            dpi(self.width_in_pixels, self.width_in_millimeters),
+            dpi(self.height_in_pixels, self.height_in_millimeters),
+        )
+    }
+}
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -709,25 +758,25 @@ pub struct SetupRequest {
 impl TryParse for SetupRequest {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (byte_order, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (protocol_major_version, remaining) = u16::try_parse(remaining)?;
-        let (protocol_minor_version, remaining) = u16::try_parse(remaining)?;
-        let (authorization_protocol_name_len, remaining) = u16::try_parse(remaining)?;
-        let (authorization_protocol_data_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (authorization_protocol_name, remaining) = crate::x11_utils::parse_u8_list(remaining, authorization_protocol_name_len.try_to_usize()?)?;
+        let (byte_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "byte_order")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (protocol_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_major_version")?;
+        let (protocol_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_minor_version")?;
+        let (authorization_protocol_name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "authorization_protocol_name_len")?;
+        let (authorization_protocol_data_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "authorization_protocol_data_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (authorization_protocol_name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, authorization_protocol_name_len.try_to_usize()?), "authorization_protocol_name")?;
         let authorization_protocol_name = authorization_protocol_name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (authorization_protocol_data, remaining) = crate::x11_utils::parse_u8_list(remaining, authorization_protocol_data_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (authorization_protocol_data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, authorization_protocol_data_len.try_to_usize()?), "authorization_protocol_data")?;
         let authorization_protocol_data = authorization_protocol_data.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = SetupRequest { byte_order, protocol_major_version, protocol_minor_version, authorization_protocol_name, authorization_protocol_data };
         Ok((result, remaining))
     }
@@ -796,12 +845,12 @@ pub struct SetupFailed {
 }
 impl TryParse for SetupFailed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (reason_len, remaining) = u8::try_parse(remaining)?;
-        let (protocol_major_version, remaining) = u16::try_parse(remaining)?;
-        let (protocol_minor_version, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u16::try_parse(remaining)?;
-        let (reason, remaining) = crate::x11_utils::parse_u8_list(remaining, reason_len.try_to_usize()?)?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (reason_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "reason_len")?;
+        let (protocol_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_major_version")?;
+        let (protocol_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_minor_version")?;
+        let (length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "length")?;
+        let (reason, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, reason_len.try_to_usize()?), "reason")?;
         let reason = reason.to_vec();
         let result = SetupFailed { status, protocol_major_version, protocol_minor_version, length, reason };
         Ok((result, remaining))
@@ -849,10 +898,10 @@ pub struct SetupAuthenticate {
 }
 impl TryParse for SetupAuthenticate {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
-        let (length, remaining) = u16::try_parse(remaining)?;
-        let (reason, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 5)?;
+        let (length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "length")?;
+        let (reason, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "reason")?;
         let reason = reason.to_vec();
         let result = SetupAuthenticate { status, reason };
         Ok((result, remaining))
@@ -976,34 +1025,34 @@ pub struct Setup {
 impl TryParse for Setup {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (protocol_major_version, remaining) = u16::try_parse(remaining)?;
-        let (protocol_minor_version, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u16::try_parse(remaining)?;
-        let (release_number, remaining) = u32::try_parse(remaining)?;
-        let (resource_id_base, remaining) = u32::try_parse(remaining)?;
-        let (resource_id_mask, remaining) = u32::try_parse(remaining)?;
-        let (motion_buffer_size, remaining) = u32::try_parse(remaining)?;
-        let (vendor_len, remaining) = u16::try_parse(remaining)?;
-        let (maximum_request_length, remaining) = u16::try_parse(remaining)?;
-        let (roots_len, remaining) = u8::try_parse(remaining)?;
-        let (pixmap_formats_len, remaining) = u8::try_parse(remaining)?;
-        let (image_byte_order, remaining) = u8::try_parse(remaining)?;
-        let (bitmap_format_bit_order, remaining) = u8::try_parse(remaining)?;
-        let (bitmap_format_scanline_unit, remaining) = u8::try_parse(remaining)?;
-        let (bitmap_format_scanline_pad, remaining) = u8::try_parse(remaining)?;
-        let (min_keycode, remaining) = Keycode::try_parse(remaining)?;
-        let (max_keycode, remaining) = Keycode::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_len.try_to_usize()?)?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (protocol_major_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_major_version")?;
+        let (protocol_minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "protocol_minor_version")?;
+        let (length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "length")?;
+        let (release_number, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "release_number")?;
+        let (resource_id_base, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resource_id_base")?;
+        let (resource_id_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "resource_id_mask")?;
+        let (motion_buffer_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "motion_buffer_size")?;
+        let (vendor_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vendor_len")?;
+        let (maximum_request_length, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "maximum_request_length")?;
+        let (roots_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "roots_len")?;
+        let (pixmap_formats_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pixmap_formats_len")?;
+        let (image_byte_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "image_byte_order")?;
+        let (bitmap_format_bit_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bitmap_format_bit_order")?;
+        let (bitmap_format_scanline_unit, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bitmap_format_scanline_unit")?;
+        let (bitmap_format_scanline_pad, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bitmap_format_scanline_pad")?;
+        let (min_keycode, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "min_keycode")?;
+        let (max_keycode, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "max_keycode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (vendor, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, vendor_len.try_to_usize()?), "vendor")?;
         let vendor = vendor.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (pixmap_formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, pixmap_formats_len.try_to_usize()?)?;
-        let (roots, remaining) = crate::x11_utils::parse_list::<Screen>(remaining, roots_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (pixmap_formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, pixmap_formats_len.try_to_usize()?, Some("pixmap_formats"))?;
+        let (roots, remaining) = crate::x11_utils::parse_list::<Screen>(remaining, roots_len.try_to_usize()?, Some("roots"))?;
         let image_byte_order = image_byte_order.into();
         let bitmap_format_bit_order = bitmap_format_bit_order.into();
         let result = Setup { status, protocol_major_version, protocol_minor_version, length, release_number, resource_id_base, resource_id_mask, motion_buffer_size, maximum_request_length, image_byte_order, bitmap_format_bit_order, bitmap_format_scanline_unit, bitmap_format_scanline_pad, min_keycode, max_keycode, vendor, pixmap_formats, roots };
@@ -1156,7 +1205,7 @@ impl core::fmt::Debug for ModMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ModMask, u16);
+bitmask_binop!(ModMask, u16, [SHIFT, LOCK, CONTROL, M1, M2, M3, M4, M5, ANY]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1232,7 +1281,7 @@ impl core::fmt::Debug for KeyButMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(KeyButMask, u16);
+bitmask_binop!(KeyButMask, u16, [SHIFT, LOCK, CONTROL, MOD1, MOD2, MOD3, MOD4, MOD5, BUTTON1, BUTTON2, BUTTON3, BUTTON4, BUTTON5]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1337,20 +1386,20 @@ pub struct KeyPressEvent {
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = Keycode::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_y")?;
+        let (state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let state = state.into();
         let result = KeyPressEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen };
         let _ = remaining;
@@ -1549,7 +1598,7 @@ impl core::fmt::Debug for ButtonMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ButtonMask, u16);
+bitmask_binop!(ButtonMask, u16, [M1, M2, M3, M4, M5, ANY]);
 
 /// Opcode for the ButtonPress event
 pub const BUTTON_PRESS_EVENT: u8 = 4;
@@ -1597,20 +1646,20 @@ pub struct ButtonPressEvent {
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = Button::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(Button::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_y")?;
+        let (state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let state = state.into();
         let result = ButtonPressEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen };
         let _ = remaining;
@@ -1854,20 +1903,20 @@ pub struct MotionNotifyEvent {
 impl TryParse for MotionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_y")?;
+        let (state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let detail = detail.into();
         let state = state.into();
         let result = MotionNotifyEvent { response_type, detail, sequence, time, root, event, child, root_x, root_y, event_x, event_y, state, same_screen };
@@ -2175,20 +2224,20 @@ pub struct EnterNotifyEvent {
 impl TryParse for EnterNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (event_x, remaining) = i16::try_parse(remaining)?;
-        let (event_y, remaining) = i16::try_parse(remaining)?;
-        let (state, remaining) = u16::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (same_screen_focus, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (event_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_x")?;
+        let (event_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "event_y")?;
+        let (state, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "state")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (same_screen_focus, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "same_screen_focus")?;
         let detail = detail.into();
         let state = state.into();
         let mode = mode.into();
@@ -2353,12 +2402,12 @@ pub struct FocusInEvent {
 impl TryParse for FocusInEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let detail = detail.into();
         let mode = mode.into();
         let result = FocusInEvent { response_type, detail, sequence, event, mode };
@@ -2466,7 +2515,7 @@ pub struct KeymapNotifyEvent {
 impl TryParse for KeymapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<31>(remaining)?;
         let result = KeymapNotifyEvent { response_type, keys };
         let _ = remaining;
@@ -2597,16 +2646,16 @@ pub struct ExposeEvent {
 impl TryParse for ExposeEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (count, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = ExposeEvent { response_type, sequence, window, x, y, width, height, count };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2734,18 +2783,18 @@ pub struct GraphicsExposureEvent {
 impl TryParse for GraphicsExposureEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (count, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (minor_opcode, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_opcode")?;
+        let (count, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "count")?;
+        let (major_opcode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_opcode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = GraphicsExposureEvent { response_type, sequence, drawable, x, y, width, height, minor_opcode, count, major_opcode };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -2878,13 +2927,13 @@ pub struct NoExposureEvent {
 impl TryParse for NoExposureEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (minor_opcode, remaining) = u16::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (minor_opcode, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_opcode")?;
+        let (major_opcode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_opcode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = NoExposureEvent { response_type, sequence, drawable, minor_opcode, major_opcode };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3050,12 +3099,12 @@ pub struct VisibilityNotifyEvent {
 impl TryParse for VisibilityNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let state = state.into();
         let result = VisibilityNotifyEvent { response_type, sequence, window, state };
         let _ = remaining;
@@ -3164,18 +3213,18 @@ pub struct CreateNotifyEvent {
 impl TryParse for CreateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let (override_redirect, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let (override_redirect, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "override_redirect")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = CreateNotifyEvent { response_type, sequence, parent, window, x, y, width, height, border_width, override_redirect };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3318,11 +3367,11 @@ pub struct DestroyNotifyEvent {
 impl TryParse for DestroyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
         let result = DestroyNotifyEvent { response_type, sequence, event, window };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3437,13 +3486,13 @@ pub struct UnmapNotifyEvent {
 impl TryParse for UnmapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (from_configure, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (from_configure, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "from_configure")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = UnmapNotifyEvent { response_type, sequence, event, window, from_configure };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3565,13 +3614,13 @@ pub struct MapNotifyEvent {
 impl TryParse for MapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (override_redirect, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (override_redirect, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "override_redirect")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = MapNotifyEvent { response_type, sequence, event, window, override_redirect };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3690,11 +3739,11 @@ pub struct MapRequestEvent {
 impl TryParse for MapRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
         let result = MapRequestEvent { response_type, sequence, parent, window };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3799,16 +3848,16 @@ pub struct ReparentNotifyEvent {
 impl TryParse for ReparentNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (override_redirect, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (override_redirect, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "override_redirect")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = ReparentNotifyEvent { response_type, sequence, event, window, parent, x, y, override_redirect };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -3963,19 +4012,19 @@ pub struct ConfigureNotifyEvent {
 impl TryParse for ConfigureNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (above_sibling, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let (override_redirect, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (above_sibling, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "above_sibling")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let (override_redirect, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "override_redirect")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let result = ConfigureNotifyEvent { response_type, sequence, event, window, above_sibling, x, y, width, height, border_width, override_redirect };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4122,18 +4171,18 @@ pub struct ConfigureRequestEvent {
 impl TryParse for ConfigureRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (stack_mode, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (sibling, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let (value_mask, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (stack_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "stack_mode")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (sibling, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "sibling")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "value_mask")?;
         let stack_mode = stack_mode.into();
         let value_mask = value_mask.into();
         let result = ConfigureRequestEvent { response_type, stack_mode, sequence, parent, window, sibling, x, y, width, height, border_width, value_mask };
@@ -4277,13 +4326,13 @@ pub struct GravityNotifyEvent {
 impl TryParse for GravityNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let result = GravityNotifyEvent { response_type, sequence, event, window, x, y };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4395,12 +4444,12 @@ pub struct ResizeRequestEvent {
 impl TryParse for ResizeRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let result = ResizeRequestEvent { response_type, sequence, window, width, height };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -4580,14 +4629,14 @@ pub struct CirculateNotifyEvent {
 impl TryParse for CirculateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (event, remaining) = Window::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (place, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (event, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "event")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (place, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "place")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let place = place.into();
         let result = CirculateNotifyEvent { response_type, sequence, event, window, place };
         let _ = remaining;
@@ -4779,14 +4828,14 @@ pub struct PropertyNotifyEvent {
 impl TryParse for PropertyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (atom, remaining) = Atom::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (atom, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "atom")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let state = state.into();
         let result = PropertyNotifyEvent { response_type, sequence, window, atom, time, state };
         let _ = remaining;
@@ -4904,12 +4953,12 @@ pub struct SelectionClearEvent {
 impl TryParse for SelectionClearEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (owner, remaining) = Window::try_parse(remaining)?;
-        let (selection, remaining) = Atom::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (owner, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "owner")?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "selection")?;
         let result = SelectionClearEvent { response_type, sequence, time, owner, selection };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -5273,15 +5322,15 @@ pub struct SelectionRequestEvent {
 impl TryParse for SelectionRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (owner, remaining) = Window::try_parse(remaining)?;
-        let (requestor, remaining) = Window::try_parse(remaining)?;
-        let (selection, remaining) = Atom::try_parse(remaining)?;
-        let (target, remaining) = Atom::try_parse(remaining)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (owner, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "owner")?;
+        let (requestor, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "requestor")?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "selection")?;
+        let (target, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "target")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
         let result = SelectionRequestEvent { response_type, sequence, time, owner, requestor, selection, target, property };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -5413,14 +5462,14 @@ pub struct SelectionNotifyEvent {
 impl TryParse for SelectionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (requestor, remaining) = Window::try_parse(remaining)?;
-        let (selection, remaining) = Atom::try_parse(remaining)?;
-        let (target, remaining) = Atom::try_parse(remaining)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (requestor, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "requestor")?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "selection")?;
+        let (target, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "target")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
         let result = SelectionNotifyEvent { response_type, sequence, time, requestor, selection, target, property };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -5677,14 +5726,14 @@ pub struct ColormapNotifyEvent {
 impl TryParse for ColormapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (colormap, remaining) = Colormap::try_parse(remaining)?;
-        let (new, remaining) = bool::try_parse(remaining)?;
-        let (state, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (colormap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "colormap")?;
+        let (new, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "new")?;
+        let (state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "state")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let state = state.into();
         let result = ColormapNotifyEvent { response_type, sequence, window, colormap, new, state };
         let _ = remaining;
@@ -5971,12 +6020,12 @@ pub struct ClientMessageEvent {
 impl TryParse for ClientMessageEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (type_, remaining) = Atom::try_parse(remaining)?;
-        let (data, remaining) = ClientMessageData::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (type_, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "type_")?;
+        let (data, remaining) = crate::x11_utils::in_field(ClientMessageData::try_parse(remaining), "data")?;
         let result = ClientMessageEvent { response_type, format, sequence, window, type_, data };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -6190,13 +6239,13 @@ pub struct MappingNotifyEvent {
 impl TryParse for MappingNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (request, remaining) = u8::try_parse(remaining)?;
-        let (first_keycode, remaining) = Keycode::try_parse(remaining)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (request, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "request")?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "first_keycode")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let request = request.into();
         let result = MappingNotifyEvent { response_type, sequence, request, first_keycode, count };
         let _ = remaining;
@@ -6306,16 +6355,15 @@ pub struct GeGenericEvent {
 impl TryParse for GeGenericEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (extension, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (event_type, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (extension, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "extension")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (event_type, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
         let result = GeGenericEvent { response_type, extension, sequence, length, event_type };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -6621,7 +6669,7 @@ impl core::fmt::Debug for CW  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(CW, u32);
+bitmask_binop!(CW, u32, [BACK_PIXMAP, BACK_PIXEL, BORDER_PIXMAP, BORDER_PIXEL, BIT_GRAVITY, WIN_GRAVITY, BACKING_STORE, BACKING_PLANES, BACKING_PIXEL, OVERRIDE_REDIRECT, SAVE_UNDER, EVENT_MASK, DONT_PROPAGATE, COLORMAP, CURSOR]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -6787,7 +6835,7 @@ impl CreateWindowAux {
         let mut outer_remaining = value;
         let background_pixmap = if switch_expr & u32::from(CW::BACK_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (background_pixmap, remaining) = Pixmap::try_parse(remaining)?;
+            let (background_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "background_pixmap")?;
             outer_remaining = remaining;
             Some(background_pixmap)
         } else {
@@ -6795,7 +6843,7 @@ impl CreateWindowAux {
         };
         let background_pixel = if switch_expr & u32::from(CW::BACK_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (background_pixel, remaining) = u32::try_parse(remaining)?;
+            let (background_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "background_pixel")?;
             outer_remaining = remaining;
             Some(background_pixel)
         } else {
@@ -6803,7 +6851,7 @@ impl CreateWindowAux {
         };
         let border_pixmap = if switch_expr & u32::from(CW::BORDER_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (border_pixmap, remaining) = Pixmap::try_parse(remaining)?;
+            let (border_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "border_pixmap")?;
             outer_remaining = remaining;
             Some(border_pixmap)
         } else {
@@ -6811,7 +6859,7 @@ impl CreateWindowAux {
         };
         let border_pixel = if switch_expr & u32::from(CW::BORDER_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (border_pixel, remaining) = u32::try_parse(remaining)?;
+            let (border_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "border_pixel")?;
             outer_remaining = remaining;
             Some(border_pixel)
         } else {
@@ -6819,7 +6867,7 @@ impl CreateWindowAux {
         };
         let bit_gravity = if switch_expr & u32::from(CW::BIT_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (bit_gravity, remaining) = u32::try_parse(remaining)?;
+            let (bit_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bit_gravity")?;
             let bit_gravity = bit_gravity.into();
             outer_remaining = remaining;
             Some(bit_gravity)
@@ -6828,7 +6876,7 @@ impl CreateWindowAux {
         };
         let win_gravity = if switch_expr & u32::from(CW::WIN_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (win_gravity, remaining) = u32::try_parse(remaining)?;
+            let (win_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "win_gravity")?;
             let win_gravity = win_gravity.into();
             outer_remaining = remaining;
             Some(win_gravity)
@@ -6837,7 +6885,7 @@ impl CreateWindowAux {
         };
         let backing_store = if switch_expr & u32::from(CW::BACKING_STORE) != 0 {
             let remaining = outer_remaining;
-            let (backing_store, remaining) = u32::try_parse(remaining)?;
+            let (backing_store, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_store")?;
             let backing_store = backing_store.into();
             outer_remaining = remaining;
             Some(backing_store)
@@ -6846,7 +6894,7 @@ impl CreateWindowAux {
         };
         let backing_planes = if switch_expr & u32::from(CW::BACKING_PLANES) != 0 {
             let remaining = outer_remaining;
-            let (backing_planes, remaining) = u32::try_parse(remaining)?;
+            let (backing_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_planes")?;
             outer_remaining = remaining;
             Some(backing_planes)
         } else {
@@ -6854,7 +6902,7 @@ impl CreateWindowAux {
         };
         let backing_pixel = if switch_expr & u32::from(CW::BACKING_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (backing_pixel, remaining) = u32::try_parse(remaining)?;
+            let (backing_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_pixel")?;
             outer_remaining = remaining;
             Some(backing_pixel)
         } else {
@@ -6862,7 +6910,7 @@ impl CreateWindowAux {
         };
         let override_redirect = if switch_expr & u32::from(CW::OVERRIDE_REDIRECT) != 0 {
             let remaining = outer_remaining;
-            let (override_redirect, remaining) = Bool32::try_parse(remaining)?;
+            let (override_redirect, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "override_redirect")?;
             outer_remaining = remaining;
             Some(override_redirect)
         } else {
@@ -6870,7 +6918,7 @@ impl CreateWindowAux {
         };
         let save_under = if switch_expr & u32::from(CW::SAVE_UNDER) != 0 {
             let remaining = outer_remaining;
-            let (save_under, remaining) = Bool32::try_parse(remaining)?;
+            let (save_under, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "save_under")?;
             outer_remaining = remaining;
             Some(save_under)
         } else {
@@ -6878,7 +6926,7 @@ impl CreateWindowAux {
         };
         let event_mask = if switch_expr & u32::from(CW::EVENT_MASK) != 0 {
             let remaining = outer_remaining;
-            let (event_mask, remaining) = u32::try_parse(remaining)?;
+            let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
             let event_mask = event_mask.into();
             outer_remaining = remaining;
             Some(event_mask)
@@ -6887,7 +6935,7 @@ impl CreateWindowAux {
         };
         let do_not_propogate_mask = if switch_expr & u32::from(CW::DONT_PROPAGATE) != 0 {
             let remaining = outer_remaining;
-            let (do_not_propogate_mask, remaining) = u32::try_parse(remaining)?;
+            let (do_not_propogate_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "do_not_propogate_mask")?;
             let do_not_propogate_mask = do_not_propogate_mask.into();
             outer_remaining = remaining;
             Some(do_not_propogate_mask)
@@ -6896,7 +6944,7 @@ impl CreateWindowAux {
         };
         let colormap = if switch_expr & u32::from(CW::COLORMAP) != 0 {
             let remaining = outer_remaining;
-            let (colormap, remaining) = Colormap::try_parse(remaining)?;
+            let (colormap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "colormap")?;
             outer_remaining = remaining;
             Some(colormap)
         } else {
@@ -6904,7 +6952,7 @@ impl CreateWindowAux {
         };
         let cursor = if switch_expr & u32::from(CW::CURSOR) != 0 {
             let remaining = outer_remaining;
-            let (cursor, remaining) = Cursor::try_parse(remaining)?;
+            let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(remaining), "cursor")?;
             outer_remaining = remaining;
             Some(cursor)
         } else {
@@ -7255,19 +7303,19 @@ impl<'input> CreateWindowRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let _ = remaining;
-        let (wid, remaining) = Window::try_parse(value)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let (class, remaining) = u16::try_parse(remaining)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let _ = remaining;
+        let (wid, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "wid")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let (class, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "class")?;
         let class = class.into();
-        let (visual, remaining) = Visualid::try_parse(remaining)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (visual, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "visual")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = CreateWindowAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(CreateWindowRequest {
@@ -7303,6 +7351,7 @@ impl<'input> CreateWindowRequest<'input> {
 }
 impl<'input> Request for CreateWindowRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -7340,7 +7389,7 @@ impl ChangeWindowAttributesAux {
         let mut outer_remaining = value;
         let background_pixmap = if switch_expr & u32::from(CW::BACK_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (background_pixmap, remaining) = Pixmap::try_parse(remaining)?;
+            let (background_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "background_pixmap")?;
             outer_remaining = remaining;
             Some(background_pixmap)
         } else {
@@ -7348,7 +7397,7 @@ impl ChangeWindowAttributesAux {
         };
         let background_pixel = if switch_expr & u32::from(CW::BACK_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (background_pixel, remaining) = u32::try_parse(remaining)?;
+            let (background_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "background_pixel")?;
             outer_remaining = remaining;
             Some(background_pixel)
         } else {
@@ -7356,7 +7405,7 @@ impl ChangeWindowAttributesAux {
         };
         let border_pixmap = if switch_expr & u32::from(CW::BORDER_PIXMAP) != 0 {
             let remaining = outer_remaining;
-            let (border_pixmap, remaining) = Pixmap::try_parse(remaining)?;
+            let (border_pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "border_pixmap")?;
             outer_remaining = remaining;
             Some(border_pixmap)
         } else {
@@ -7364,7 +7413,7 @@ impl ChangeWindowAttributesAux {
         };
         let border_pixel = if switch_expr & u32::from(CW::BORDER_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (border_pixel, remaining) = u32::try_parse(remaining)?;
+            let (border_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "border_pixel")?;
             outer_remaining = remaining;
             Some(border_pixel)
         } else {
@@ -7372,7 +7421,7 @@ impl ChangeWindowAttributesAux {
         };
         let bit_gravity = if switch_expr & u32::from(CW::BIT_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (bit_gravity, remaining) = u32::try_parse(remaining)?;
+            let (bit_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bit_gravity")?;
             let bit_gravity = bit_gravity.into();
             outer_remaining = remaining;
             Some(bit_gravity)
@@ -7381,7 +7430,7 @@ impl ChangeWindowAttributesAux {
         };
         let win_gravity = if switch_expr & u32::from(CW::WIN_GRAVITY) != 0 {
             let remaining = outer_remaining;
-            let (win_gravity, remaining) = u32::try_parse(remaining)?;
+            let (win_gravity, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "win_gravity")?;
             let win_gravity = win_gravity.into();
             outer_remaining = remaining;
             Some(win_gravity)
@@ -7390,7 +7439,7 @@ impl ChangeWindowAttributesAux {
         };
         let backing_store = if switch_expr & u32::from(CW::BACKING_STORE) != 0 {
             let remaining = outer_remaining;
-            let (backing_store, remaining) = u32::try_parse(remaining)?;
+            let (backing_store, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_store")?;
             let backing_store = backing_store.into();
             outer_remaining = remaining;
             Some(backing_store)
@@ -7399,7 +7448,7 @@ impl ChangeWindowAttributesAux {
         };
         let backing_planes = if switch_expr & u32::from(CW::BACKING_PLANES) != 0 {
             let remaining = outer_remaining;
-            let (backing_planes, remaining) = u32::try_parse(remaining)?;
+            let (backing_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_planes")?;
             outer_remaining = remaining;
             Some(backing_planes)
         } else {
@@ -7407,7 +7456,7 @@ impl ChangeWindowAttributesAux {
         };
         let backing_pixel = if switch_expr & u32::from(CW::BACKING_PIXEL) != 0 {
             let remaining = outer_remaining;
-            let (backing_pixel, remaining) = u32::try_parse(remaining)?;
+            let (backing_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_pixel")?;
             outer_remaining = remaining;
             Some(backing_pixel)
         } else {
@@ -7415,7 +7464,7 @@ impl ChangeWindowAttributesAux {
         };
         let override_redirect = if switch_expr & u32::from(CW::OVERRIDE_REDIRECT) != 0 {
             let remaining = outer_remaining;
-            let (override_redirect, remaining) = Bool32::try_parse(remaining)?;
+            let (override_redirect, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "override_redirect")?;
             outer_remaining = remaining;
             Some(override_redirect)
         } else {
@@ -7423,7 +7472,7 @@ impl ChangeWindowAttributesAux {
         };
         let save_under = if switch_expr & u32::from(CW::SAVE_UNDER) != 0 {
             let remaining = outer_remaining;
-            let (save_under, remaining) = Bool32::try_parse(remaining)?;
+            let (save_under, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "save_under")?;
             outer_remaining = remaining;
             Some(save_under)
         } else {
@@ -7431,7 +7480,7 @@ impl ChangeWindowAttributesAux {
         };
         let event_mask = if switch_expr & u32::from(CW::EVENT_MASK) != 0 {
             let remaining = outer_remaining;
-            let (event_mask, remaining) = u32::try_parse(remaining)?;
+            let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
             let event_mask = event_mask.into();
             outer_remaining = remaining;
             Some(event_mask)
@@ -7440,7 +7489,7 @@ impl ChangeWindowAttributesAux {
         };
         let do_not_propogate_mask = if switch_expr & u32::from(CW::DONT_PROPAGATE) != 0 {
             let remaining = outer_remaining;
-            let (do_not_propogate_mask, remaining) = u32::try_parse(remaining)?;
+            let (do_not_propogate_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "do_not_propogate_mask")?;
             let do_not_propogate_mask = do_not_propogate_mask.into();
             outer_remaining = remaining;
             Some(do_not_propogate_mask)
@@ -7449,7 +7498,7 @@ impl ChangeWindowAttributesAux {
         };
         let colormap = if switch_expr & u32::from(CW::COLORMAP) != 0 {
             let remaining = outer_remaining;
-            let (colormap, remaining) = Colormap::try_parse(remaining)?;
+            let (colormap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "colormap")?;
             outer_remaining = remaining;
             Some(colormap)
         } else {
@@ -7457,7 +7506,7 @@ impl ChangeWindowAttributesAux {
         };
         let cursor = if switch_expr & u32::from(CW::CURSOR) != 0 {
             let remaining = outer_remaining;
-            let (cursor, remaining) = Cursor::try_parse(remaining)?;
+            let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(remaining), "cursor")?;
             outer_remaining = remaining;
             Some(cursor)
         } else {
@@ -7736,10 +7785,10 @@ impl<'input> ChangeWindowAttributesRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = ChangeWindowAttributesAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(ChangeWindowAttributesRequest {
@@ -7757,6 +7806,7 @@ impl<'input> ChangeWindowAttributesRequest<'input> {
 }
 impl<'input> Request for ChangeWindowAttributesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -7875,9 +7925,9 @@ impl GetWindowAttributesRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetWindowAttributesRequest {
             window,
@@ -7886,6 +7936,7 @@ impl GetWindowAttributesRequest {
 }
 impl Request for GetWindowAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -7938,27 +7989,27 @@ pub struct GetWindowAttributesReply {
 impl TryParse for GetWindowAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (backing_store, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (visual, remaining) = Visualid::try_parse(remaining)?;
-        let (class, remaining) = u16::try_parse(remaining)?;
-        let (bit_gravity, remaining) = u8::try_parse(remaining)?;
-        let (win_gravity, remaining) = u8::try_parse(remaining)?;
-        let (backing_planes, remaining) = u32::try_parse(remaining)?;
-        let (backing_pixel, remaining) = u32::try_parse(remaining)?;
-        let (save_under, remaining) = bool::try_parse(remaining)?;
-        let (map_is_installed, remaining) = bool::try_parse(remaining)?;
-        let (map_state, remaining) = u8::try_parse(remaining)?;
-        let (override_redirect, remaining) = bool::try_parse(remaining)?;
-        let (colormap, remaining) = Colormap::try_parse(remaining)?;
-        let (all_event_masks, remaining) = u32::try_parse(remaining)?;
-        let (your_event_mask, remaining) = u32::try_parse(remaining)?;
-        let (do_not_propagate_mask, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (backing_store, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "backing_store")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (visual, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "visual")?;
+        let (class, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "class")?;
+        let (bit_gravity, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bit_gravity")?;
+        let (win_gravity, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "win_gravity")?;
+        let (backing_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_planes")?;
+        let (backing_pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "backing_pixel")?;
+        let (save_under, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "save_under")?;
+        let (map_is_installed, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "map_is_installed")?;
+        let (map_state, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_state")?;
+        let (override_redirect, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "override_redirect")?;
+        let (colormap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "colormap")?;
+        let (all_event_masks, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "all_event_masks")?;
+        let (your_event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "your_event_mask")?;
+        let (do_not_propagate_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "do_not_propagate_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let backing_store = backing_store.into();
         let class = class.into();
@@ -7970,8 +8021,7 @@ impl TryParse for GetWindowAttributesReply {
         let do_not_propagate_mask = do_not_propagate_mask.into();
         let result = GetWindowAttributesReply { backing_store, sequence, length, visual, class, bit_gravity, win_gravity, backing_planes, backing_pixel, save_under, map_is_installed, map_state, override_redirect, colormap, all_event_masks, your_event_mask, do_not_propagate_mask };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -8124,9 +8174,9 @@ impl DestroyWindowRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(DestroyWindowRequest {
             window,
@@ -8135,6 +8185,7 @@ impl DestroyWindowRequest {
 }
 impl Request for DestroyWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8180,9 +8231,9 @@ impl DestroySubwindowsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(DestroySubwindowsRequest {
             window,
@@ -8191,6 +8242,7 @@ impl DestroySubwindowsRequest {
 }
 impl Request for DestroySubwindowsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8319,10 +8371,10 @@ impl ChangeSaveSetRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ChangeSaveSetRequest {
             mode,
@@ -8332,6 +8384,7 @@ impl ChangeSaveSetRequest {
 }
 impl Request for ChangeSaveSetRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8423,12 +8476,12 @@ impl ReparentWindowRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let _ = remaining;
         Ok(ReparentWindowRequest {
             window,
@@ -8440,6 +8493,7 @@ impl ReparentWindowRequest {
 }
 impl Request for ReparentWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8520,9 +8574,9 @@ impl MapWindowRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(MapWindowRequest {
             window,
@@ -8531,6 +8585,7 @@ impl MapWindowRequest {
 }
 impl Request for MapWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8576,9 +8631,9 @@ impl MapSubwindowsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(MapSubwindowsRequest {
             window,
@@ -8587,6 +8642,7 @@ impl MapSubwindowsRequest {
 }
 impl Request for MapSubwindowsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8653,9 +8709,9 @@ impl UnmapWindowRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(UnmapWindowRequest {
             window,
@@ -8664,6 +8720,7 @@ impl UnmapWindowRequest {
 }
 impl Request for UnmapWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8709,9 +8766,9 @@ impl UnmapSubwindowsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(UnmapSubwindowsRequest {
             window,
@@ -8720,6 +8777,7 @@ impl UnmapSubwindowsRequest {
 }
 impl Request for UnmapSubwindowsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -8793,7 +8851,7 @@ impl core::fmt::Debug for ConfigWindow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ConfigWindow, u16);
+bitmask_binop!(ConfigWindow, u16, [X, Y, WIDTH, HEIGHT, BORDER_WIDTH, SIBLING, STACK_MODE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -8866,7 +8924,7 @@ impl ConfigureWindowAux {
         let mut outer_remaining = value;
         let x = if switch_expr & u16::from(ConfigWindow::X) != 0 {
             let remaining = outer_remaining;
-            let (x, remaining) = i32::try_parse(remaining)?;
+            let (x, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "x")?;
             outer_remaining = remaining;
             Some(x)
         } else {
@@ -8874,7 +8932,7 @@ impl ConfigureWindowAux {
         };
         let y = if switch_expr & u16::from(ConfigWindow::Y) != 0 {
             let remaining = outer_remaining;
-            let (y, remaining) = i32::try_parse(remaining)?;
+            let (y, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "y")?;
             outer_remaining = remaining;
             Some(y)
         } else {
@@ -8882,7 +8940,7 @@ impl ConfigureWindowAux {
         };
         let width = if switch_expr & u16::from(ConfigWindow::WIDTH) != 0 {
             let remaining = outer_remaining;
-            let (width, remaining) = u32::try_parse(remaining)?;
+            let (width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "width")?;
             outer_remaining = remaining;
             Some(width)
         } else {
@@ -8890,7 +8948,7 @@ impl ConfigureWindowAux {
         };
         let height = if switch_expr & u16::from(ConfigWindow::HEIGHT) != 0 {
             let remaining = outer_remaining;
-            let (height, remaining) = u32::try_parse(remaining)?;
+            let (height, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "height")?;
             outer_remaining = remaining;
             Some(height)
         } else {
@@ -8898,7 +8956,7 @@ impl ConfigureWindowAux {
         };
         let border_width = if switch_expr & u16::from(ConfigWindow::BORDER_WIDTH) != 0 {
             let remaining = outer_remaining;
-            let (border_width, remaining) = u32::try_parse(remaining)?;
+            let (border_width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "border_width")?;
             outer_remaining = remaining;
             Some(border_width)
         } else {
@@ -8906,7 +8964,7 @@ impl ConfigureWindowAux {
         };
         let sibling = if switch_expr & u16::from(ConfigWindow::SIBLING) != 0 {
             let remaining = outer_remaining;
-            let (sibling, remaining) = Window::try_parse(remaining)?;
+            let (sibling, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "sibling")?;
             outer_remaining = remaining;
             Some(sibling)
         } else {
@@ -8914,7 +8972,7 @@ impl ConfigureWindowAux {
         };
         let stack_mode = if switch_expr & u16::from(ConfigWindow::STACK_MODE) != 0 {
             let remaining = outer_remaining;
-            let (stack_mode, remaining) = u32::try_parse(remaining)?;
+            let (stack_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "stack_mode")?;
             let stack_mode = stack_mode.into();
             outer_remaining = remaining;
             Some(stack_mode)
@@ -9160,11 +9218,11 @@ impl<'input> ConfigureWindowRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (value_mask, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "value_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let (value_list, remaining) = ConfigureWindowAux::try_parse(remaining, u16::from(value_mask))?;
         let _ = remaining;
         Ok(ConfigureWindowRequest {
@@ -9182,6 +9240,7 @@ impl<'input> ConfigureWindowRequest<'input> {
 }
 impl<'input> Request for ConfigureWindowRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9305,10 +9364,10 @@ impl CirculateWindowRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (direction, remaining) = u8::try_parse(remaining)?;
+        let (direction, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "direction")?;
         let direction = direction.into();
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(CirculateWindowRequest {
             direction,
@@ -9318,6 +9377,7 @@ impl CirculateWindowRequest {
 }
 impl Request for CirculateWindowRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9399,9 +9459,9 @@ impl GetGeometryRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
         let _ = remaining;
         Ok(GetGeometryRequest {
             drawable,
@@ -9410,6 +9470,7 @@ impl GetGeometryRequest {
 }
 impl Request for GetGeometryRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9451,24 +9512,23 @@ pub struct GetGeometryReply {
 impl TryParse for GetGeometryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (border_width, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (border_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "border_width")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetGeometryReply { depth, sequence, length, root, x, y, width, height, border_width };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9600,9 +9660,9 @@ impl QueryTreeRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(QueryTreeRequest {
             window,
@@ -9611,6 +9671,7 @@ impl QueryTreeRequest {
 }
 impl Request for QueryTreeRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9639,22 +9700,21 @@ pub struct QueryTreeReply {
 impl TryParse for QueryTreeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (parent, remaining) = Window::try_parse(remaining)?;
-        let (children_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
-        let (children, remaining) = crate::x11_utils::parse_list::<Window>(remaining, children_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (children_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "children_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 14)?;
+        let (children, remaining) = crate::x11_utils::parse_list::<Window>(remaining, children_len.try_to_usize()?, Some("children"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryTreeReply { sequence, length, root, parent, children };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9695,6 +9755,29 @@ impl QueryTreeReply {
             .try_into().unwrap()
     }
 }
+impl QueryTreeReply {
+    /// Lazily parse the `children` list from the raw bytes of a `QueryTreeReply`.
+    ///
+    /// In contrast to parsing the whole `QueryTreeReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn children_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Window>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (parent, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "parent")?;
+        let (children_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "children_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 14)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = children_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the InternAtom request
 pub const INTERN_ATOM_REQUEST: u8 = 16;
@@ -9780,11 +9863,11 @@ impl<'input> InternAtomRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (only_if_exists, remaining) = bool::try_parse(remaining)?;
+        let (only_if_exists, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "only_if_exists")?;
         let _ = remaining;
-        let (name_len, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(InternAtomRequest {
             only_if_exists,
@@ -9801,6 +9884,7 @@ impl<'input> InternAtomRequest<'input> {
 }
 impl<'input> Request for InternAtomRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9823,18 +9907,17 @@ pub struct InternAtomReply {
 impl TryParse for InternAtomReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (atom, remaining) = Atom::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (atom, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "atom")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = InternAtomReply { sequence, length, atom };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -9905,9 +9988,9 @@ impl GetAtomNameRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (atom, remaining) = Atom::try_parse(value)?;
+        let (atom, remaining) = crate::x11_utils::in_field(Atom::try_parse(value), "atom")?;
         let _ = remaining;
         Ok(GetAtomNameRequest {
             atom,
@@ -9916,6 +9999,7 @@ impl GetAtomNameRequest {
 }
 impl Request for GetAtomNameRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -9938,21 +10022,20 @@ pub struct GetAtomNameReply {
 impl TryParse for GetAtomNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetAtomNameReply { sequence, length, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10178,16 +10261,16 @@ impl<'input> ChangePropertyRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
-        let (type_, remaining) = Atom::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (data_len, remaining) = u32::try_parse(remaining)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(data_len).checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "type_")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (data_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_len")?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(data_len).checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let _ = remaining;
         Ok(ChangePropertyRequest {
             mode,
@@ -10214,6 +10297,7 @@ impl<'input> ChangePropertyRequest<'input> {
 }
 impl<'input> Request for ChangePropertyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -10265,10 +10349,10 @@ impl DeletePropertyRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(DeletePropertyRequest {
             window,
@@ -10278,6 +10362,7 @@ impl DeletePropertyRequest {
 }
 impl Request for DeletePropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -10474,13 +10559,13 @@ impl GetPropertyRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (delete, remaining) = bool::try_parse(remaining)?;
+        let (delete, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "delete")?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
-        let (type_, remaining) = Atom::try_parse(remaining)?;
-        let (long_offset, remaining) = u32::try_parse(remaining)?;
-        let (long_length, remaining) = u32::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
+        let (type_, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "type_")?;
+        let (long_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_offset")?;
+        let (long_length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "long_length")?;
         let _ = remaining;
         Ok(GetPropertyRequest {
             delete,
@@ -10494,6 +10579,7 @@ impl GetPropertyRequest {
 }
 impl Request for GetPropertyRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -10687,23 +10773,22 @@ pub struct GetPropertyReply {
 impl TryParse for GetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = Atom::try_parse(remaining)?;
-        let (bytes_after, remaining) = u32::try_parse(remaining)?;
-        let (value_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (value, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(value_len).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (type_, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "type_")?;
+        let (bytes_after, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bytes_after")?;
+        let (value_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (value, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(value_len).checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "value")?;
         let value = value.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPropertyReply { format, sequence, length, type_, bytes_after, value_len, value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10764,9 +10849,9 @@ impl ListPropertiesRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ListPropertiesRequest {
             window,
@@ -10775,6 +10860,7 @@ impl ListPropertiesRequest {
 }
 impl Request for ListPropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -10797,20 +10883,19 @@ pub struct ListPropertiesReply {
 impl TryParse for ListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (atoms_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (atoms_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "atoms_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?, Some("atoms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListPropertiesReply { sequence, length, atoms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -10849,6 +10934,27 @@ impl ListPropertiesReply {
             .try_into().unwrap()
     }
 }
+impl ListPropertiesReply {
+    /// Lazily parse the `atoms` list from the raw bytes of a `ListPropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `ListPropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn atoms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Atom>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (atoms_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "atoms_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = atoms_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetSelectionOwner request
 pub const SET_SELECTION_OWNER_REQUEST: u8 = 22;
@@ -10925,11 +11031,11 @@ impl SetSelectionOwnerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (owner, remaining) = Window::try_parse(value)?;
-        let (selection, remaining) = Atom::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
+        let (owner, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "owner")?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "selection")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(SetSelectionOwnerRequest {
             owner,
@@ -10940,6 +11046,7 @@ impl SetSelectionOwnerRequest {
 }
 impl Request for SetSelectionOwnerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -11002,9 +11109,9 @@ impl GetSelectionOwnerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (selection, remaining) = Atom::try_parse(value)?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(value), "selection")?;
         let _ = remaining;
         Ok(GetSelectionOwnerRequest {
             selection,
@@ -11013,6 +11120,7 @@ impl GetSelectionOwnerRequest {
 }
 impl Request for GetSelectionOwnerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -11038,18 +11146,17 @@ pub struct GetSelectionOwnerReply {
 impl TryParse for GetSelectionOwnerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (owner, remaining) = Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (owner, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "owner")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectionOwnerReply { sequence, length, owner };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11144,13 +11251,13 @@ impl ConvertSelectionRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (requestor, remaining) = Window::try_parse(value)?;
-        let (selection, remaining) = Atom::try_parse(remaining)?;
-        let (target, remaining) = Atom::try_parse(remaining)?;
-        let (property, remaining) = Atom::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
+        let (requestor, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "requestor")?;
+        let (selection, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "selection")?;
+        let (target, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "target")?;
+        let (property, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "property")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(ConvertSelectionRequest {
             requestor,
@@ -11163,6 +11270,7 @@ impl ConvertSelectionRequest {
 }
 impl Request for ConvertSelectionRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -11362,10 +11470,10 @@ impl<'input> SendEventRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (propagate, remaining) = bool::try_parse(remaining)?;
+        let (propagate, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "propagate")?;
         let _ = remaining;
-        let (destination, remaining) = Window::try_parse(value)?;
-        let (event_mask, remaining) = u32::try_parse(remaining)?;
+        let (destination, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "destination")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
         let (event, remaining) = crate::x11_utils::parse_u8_array_ref::<32>(remaining)?;
         let _ = remaining;
@@ -11388,6 +11496,7 @@ impl<'input> SendEventRequest<'input> {
 }
 impl<'input> Request for SendEventRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -11721,18 +11830,18 @@ impl GrabPointerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (event_mask, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
-        let (pointer_mode, remaining) = u8::try_parse(remaining)?;
+        let (pointer_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pointer_mode")?;
         let pointer_mode = pointer_mode.into();
-        let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
+        let (keyboard_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keyboard_mode")?;
         let keyboard_mode = keyboard_mode.into();
-        let (confine_to, remaining) = Window::try_parse(remaining)?;
-        let (cursor, remaining) = Cursor::try_parse(remaining)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
+        let (confine_to, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "confine_to")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(remaining), "cursor")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(GrabPointerRequest {
             owner_events,
@@ -11748,6 +11857,7 @@ impl GrabPointerRequest {
 }
 impl Request for GrabPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -11770,18 +11880,17 @@ pub struct GrabPointerReply {
 impl TryParse for GrabPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = GrabPointerReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -11870,9 +11979,9 @@ impl UngrabPointerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (time, remaining) = Timestamp::try_parse(value)?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(value), "time")?;
         let _ = remaining;
         Ok(UngrabPointerRequest {
             time,
@@ -11881,6 +11990,7 @@ impl UngrabPointerRequest {
 }
 impl Request for UngrabPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12100,21 +12210,21 @@ impl GrabButtonRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (event_mask, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
-        let (pointer_mode, remaining) = u8::try_parse(remaining)?;
+        let (pointer_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pointer_mode")?;
         let pointer_mode = pointer_mode.into();
-        let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
+        let (keyboard_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keyboard_mode")?;
         let keyboard_mode = keyboard_mode.into();
-        let (confine_to, remaining) = Window::try_parse(remaining)?;
-        let (cursor, remaining) = Cursor::try_parse(remaining)?;
-        let (button, remaining) = u8::try_parse(remaining)?;
+        let (confine_to, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "confine_to")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(remaining), "cursor")?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
         let button = button.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
         let _ = remaining;
         Ok(GrabButtonRequest {
@@ -12132,6 +12242,7 @@ impl GrabButtonRequest {
 }
 impl Request for GrabButtonRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12185,13 +12296,13 @@ impl UngrabButtonRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (button, remaining) = u8::try_parse(remaining)?;
+        let (button, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "button")?;
         let button = button.into();
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(UngrabButtonRequest {
             button,
@@ -12202,6 +12313,7 @@ impl UngrabButtonRequest {
 }
 impl Request for UngrabButtonRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12259,13 +12371,13 @@ impl ChangeActivePointerGrabRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cursor, remaining) = Cursor::try_parse(value)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (event_mask, remaining) = u16::try_parse(remaining)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(value), "cursor")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (event_mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "event_mask")?;
         let event_mask = event_mask.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(ChangeActivePointerGrabRequest {
             cursor,
@@ -12276,6 +12388,7 @@ impl ChangeActivePointerGrabRequest {
 }
 impl Request for ChangeActivePointerGrabRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12399,15 +12512,15 @@ impl GrabKeyboardRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (pointer_mode, remaining) = u8::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (pointer_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pointer_mode")?;
         let pointer_mode = pointer_mode.into();
-        let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
+        let (keyboard_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keyboard_mode")?;
         let keyboard_mode = keyboard_mode.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(GrabKeyboardRequest {
             owner_events,
@@ -12420,6 +12533,7 @@ impl GrabKeyboardRequest {
 }
 impl Request for GrabKeyboardRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12442,18 +12556,17 @@ pub struct GrabKeyboardReply {
 impl TryParse for GrabKeyboardReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = GrabKeyboardReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -12519,9 +12632,9 @@ impl UngrabKeyboardRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (time, remaining) = Timestamp::try_parse(value)?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(value), "time")?;
         let _ = remaining;
         Ok(UngrabKeyboardRequest {
             time,
@@ -12530,6 +12643,7 @@ impl UngrabKeyboardRequest {
 }
 impl Request for UngrabKeyboardRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12709,17 +12823,17 @@ impl GrabKeyRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (owner_events, remaining) = bool::try_parse(remaining)?;
+        let (owner_events, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "owner_events")?;
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let (key, remaining) = Keycode::try_parse(remaining)?;
-        let (pointer_mode, remaining) = u8::try_parse(remaining)?;
+        let (key, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "key")?;
+        let (pointer_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "pointer_mode")?;
         let pointer_mode = pointer_mode.into();
-        let (keyboard_mode, remaining) = u8::try_parse(remaining)?;
+        let (keyboard_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keyboard_mode")?;
         let keyboard_mode = keyboard_mode.into();
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GrabKeyRequest {
             owner_events,
@@ -12733,6 +12847,7 @@ impl GrabKeyRequest {
 }
 impl Request for GrabKeyRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -12811,12 +12926,12 @@ impl UngrabKeyRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (key, remaining) = Keycode::try_parse(remaining)?;
+        let (key, remaining) = crate::x11_utils::in_field(Keycode::try_parse(remaining), "key")?;
         let _ = remaining;
-        let (grab_window, remaining) = Window::try_parse(value)?;
-        let (modifiers, remaining) = u16::try_parse(remaining)?;
+        let (grab_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "grab_window")?;
+        let (modifiers, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "modifiers")?;
         let modifiers = modifiers.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(UngrabKeyRequest {
             key,
@@ -12827,6 +12942,7 @@ impl UngrabKeyRequest {
 }
 impl Request for UngrabKeyRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13022,10 +13138,10 @@ impl AllowEventsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
-        let (time, remaining) = Timestamp::try_parse(value)?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(value), "time")?;
         let _ = remaining;
         Ok(AllowEventsRequest {
             mode,
@@ -13035,6 +13151,7 @@ impl AllowEventsRequest {
 }
 impl Request for AllowEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13073,7 +13190,7 @@ impl GrabServerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GrabServerRequest
@@ -13082,6 +13199,7 @@ impl GrabServerRequest {
 }
 impl Request for GrabServerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13120,7 +13238,7 @@ impl UngrabServerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(UngrabServerRequest
@@ -13129,6 +13247,7 @@ impl UngrabServerRequest {
 }
 impl Request for UngrabServerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13187,9 +13306,9 @@ impl QueryPointerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(QueryPointerRequest {
             window,
@@ -13198,6 +13317,7 @@ impl QueryPointerRequest {
 }
 impl Request for QueryPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13246,26 +13366,25 @@ pub struct QueryPointerReply {
 impl TryParse for QueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = Window::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let (win_x, remaining) = i16::try_parse(remaining)?;
-        let (win_y, remaining) = i16::try_parse(remaining)?;
-        let (mask, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (root, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "root")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let (win_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "win_x")?;
+        let (win_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "win_y")?;
+        let (mask, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let mask = mask.into();
         let result = QueryPointerReply { same_screen, sequence, length, root, child, root_x, root_y, win_x, win_y, mask };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13341,9 +13460,9 @@ pub struct Timecoord {
 }
 impl TryParse for Timecoord {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let result = Timecoord { time, x, y };
         Ok((result, remaining))
     }
@@ -13419,11 +13538,11 @@ impl GetMotionEventsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (start, remaining) = Timestamp::try_parse(remaining)?;
-        let (stop, remaining) = Timestamp::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (start, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "start")?;
+        let (stop, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "stop")?;
         let _ = remaining;
         Ok(GetMotionEventsRequest {
             window,
@@ -13434,6 +13553,7 @@ impl GetMotionEventsRequest {
 }
 impl Request for GetMotionEventsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13456,20 +13576,19 @@ pub struct GetMotionEventsReply {
 impl TryParse for GetMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (events_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (events, remaining) = crate::x11_utils::parse_list::<Timecoord>(remaining, events_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (events_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "events_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (events, remaining) = crate::x11_utils::parse_list::<Timecoord>(remaining, events_len.try_to_usize()?, Some("events"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetMotionEventsReply { sequence, length, events };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13508,6 +13627,27 @@ impl GetMotionEventsReply {
             .try_into().unwrap()
     }
 }
+impl GetMotionEventsReply {
+    /// Lazily parse the `events` list from the raw bytes of a `GetMotionEventsReply`.
+    ///
+    /// In contrast to parsing the whole `GetMotionEventsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn events_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Timecoord>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (events_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "events_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = events_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the TranslateCoordinates request
 pub const TRANSLATE_COORDINATES_REQUEST: u8 = 40;
@@ -13557,12 +13697,12 @@ impl TranslateCoordinatesRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (src_window, remaining) = Window::try_parse(value)?;
-        let (dst_window, remaining) = Window::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
+        let (src_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "src_window")?;
+        let (dst_window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "dst_window")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
         let _ = remaining;
         Ok(TranslateCoordinatesRequest {
             src_window,
@@ -13574,6 +13714,7 @@ impl TranslateCoordinatesRequest {
 }
 impl Request for TranslateCoordinatesRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13599,20 +13740,19 @@ pub struct TranslateCoordinatesReply {
 impl TryParse for TranslateCoordinatesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (same_screen, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (child, remaining) = Window::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (same_screen, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same_screen")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (child, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "child")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = TranslateCoordinatesReply { same_screen, sequence, length, child, dst_x, dst_y };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -13754,16 +13894,16 @@ impl WarpPointerRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (src_window, remaining) = Window::try_parse(value)?;
-        let (dst_window, remaining) = Window::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (src_width, remaining) = u16::try_parse(remaining)?;
-        let (src_height, remaining) = u16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
+        let (src_window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "src_window")?;
+        let (dst_window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "dst_window")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (src_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_width")?;
+        let (src_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_height")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
         let _ = remaining;
         Ok(WarpPointerRequest {
             src_window,
@@ -13779,6 +13919,7 @@ impl WarpPointerRequest {
 }
 impl Request for WarpPointerRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13939,11 +14080,11 @@ impl SetInputFocusRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (revert_to, remaining) = u8::try_parse(remaining)?;
+        let (revert_to, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "revert_to")?;
         let revert_to = revert_to.into();
         let _ = remaining;
-        let (focus, remaining) = Window::try_parse(value)?;
-        let (time, remaining) = Timestamp::try_parse(remaining)?;
+        let (focus, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "focus")?;
+        let (time, remaining) = crate::x11_utils::in_field(Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(SetInputFocusRequest {
             revert_to,
@@ -13954,6 +14095,7 @@ impl SetInputFocusRequest {
 }
 impl Request for SetInputFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -13992,7 +14134,7 @@ impl GetInputFocusRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetInputFocusRequest
@@ -14001,6 +14143,7 @@ impl GetInputFocusRequest {
 }
 impl Request for GetInputFocusRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14024,19 +14167,18 @@ pub struct GetInputFocusReply {
 impl TryParse for GetInputFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (revert_to, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (focus, remaining) = Window::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (revert_to, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "revert_to")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (focus, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "focus")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let revert_to = revert_to.into();
         let result = GetInputFocusReply { revert_to, sequence, length, focus };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14101,7 +14243,7 @@ impl QueryKeymapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(QueryKeymapRequest
@@ -14110,6 +14252,7 @@ impl QueryKeymapRequest {
 }
 impl Request for QueryKeymapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14132,18 +14275,17 @@ pub struct QueryKeymapReply {
 impl TryParse for QueryKeymapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         let (keys, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryKeymapReply { sequence, length, keys };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14270,12 +14412,12 @@ impl<'input> OpenFontRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (fid, remaining) = Font::try_parse(value)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (fid, remaining) = crate::x11_utils::in_field(Font::try_parse(value), "fid")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(OpenFontRequest {
             fid,
@@ -14292,6 +14434,7 @@ impl<'input> OpenFontRequest<'input> {
 }
 impl<'input> Request for OpenFontRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14337,9 +14480,9 @@ impl CloseFontRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (font, remaining) = Font::try_parse(value)?;
+        let (font, remaining) = crate::x11_utils::in_field(Font::try_parse(value), "font")?;
         let _ = remaining;
         Ok(CloseFontRequest {
             font,
@@ -14348,6 +14491,7 @@ impl CloseFontRequest {
 }
 impl Request for CloseFontRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14426,8 +14570,8 @@ pub struct Fontprop {
 }
 impl TryParse for Fontprop {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name, remaining) = Atom::try_parse(remaining)?;
-        let (value, remaining) = u32::try_parse(remaining)?;
+        let (name, remaining) = crate::x11_utils::in_field(Atom::try_parse(remaining), "name")?;
+        let (value, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value")?;
         let result = Fontprop { name, value };
         Ok((result, remaining))
     }
@@ -14467,12 +14611,12 @@ pub struct Charinfo {
 }
 impl TryParse for Charinfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (left_side_bearing, remaining) = i16::try_parse(remaining)?;
-        let (right_side_bearing, remaining) = i16::try_parse(remaining)?;
-        let (character_width, remaining) = i16::try_parse(remaining)?;
-        let (ascent, remaining) = i16::try_parse(remaining)?;
-        let (descent, remaining) = i16::try_parse(remaining)?;
-        let (attributes, remaining) = u16::try_parse(remaining)?;
+        let (left_side_bearing, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "left_side_bearing")?;
+        let (right_side_bearing, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "right_side_bearing")?;
+        let (character_width, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "character_width")?;
+        let (ascent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "ascent")?;
+        let (descent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "descent")?;
+        let (attributes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "attributes")?;
         let result = Charinfo { left_side_bearing, right_side_bearing, character_width, ascent, descent, attributes };
         Ok((result, remaining))
     }
@@ -14553,9 +14697,9 @@ impl QueryFontRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (font, remaining) = Fontable::try_parse(value)?;
+        let (font, remaining) = crate::x11_utils::in_field(Fontable::try_parse(value), "font")?;
         let _ = remaining;
         Ok(QueryFontRequest {
             font,
@@ -14564,6 +14708,7 @@ impl QueryFontRequest {
 }
 impl Request for QueryFontRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14575,6 +14720,76 @@ impl Request for QueryFontRequest {
 impl crate::x11_utils::ReplyRequest for QueryFontRequest {
     type Reply = QueryFontReply;
 }
+impl QueryFontReply {
+    /// Get the metrics of the character with the given code.
+    ///
+    /// For fonts with `min_byte1` and `max_byte1` both zero, `code` is the linear index of the
+    /// character. Otherwise, the most significant byte of `code` is the row (byte1) and the least
+    /// significant byte is the column (byte2) of the character.
+    ///
+    /// `None` is returned if `code` is outside of the range of characters of the font. If the
+    /// reply contains no char infos, all characters have the metrics in `min_bounds`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xproto::{Charinfo, QueryFontReply};
+    ///
+    /// let info = |width| Charinfo { character_width: width, ..Default::default() };
+    /// let reply = QueryFontReply {
+    ///     min_char_or_byte2: 32,
+    ///     max_char_or_byte2: 34,
+    ///     char_infos: vec![info(1), info(2), info(3)],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(reply.char_info(33), Some(info(2)));
+    /// assert_eq!(reply.char_info(35), None);
+    /// ```
+    pub fn char_info(&self, code: u16) -> Option<Charinfo> {
+        let index = crate::wrapper::char_info_index(
+            self.min_byte1,
+            self.max_byte1,
+            self.min_char_or_byte2,
+            self.max_char_or_byte2,
+            code,
+        )?;
+        if self.char_infos.is_empty() {
+            Some(self.min_bounds)
+        } else {
+            self.char_infos.get(index).copied()
+        }
+    }
+
+    /// Access the char infos of a raw `QueryFont` reply without parsing all of them.
+    ///
+    /// Fonts can contain many characters and text measuring usually only needs a few of them.
+    /// The returned value only parses the metrics of a character when it is looked up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use x11rb_protocol::protocol::xproto::{Charinfo, QueryFontReply};
+    /// use x11rb_protocol::x11_utils::Serialize;
+    ///
+    /// let info = |width| Charinfo { character_width: width, ..Default::default() };
+    /// let raw_reply = QueryFontReply {
+    ///     min_char_or_byte2: 32,
+    ///     max_char_or_byte2: 34,
+    ///     char_infos: vec![info(1), info(2), info(3)],
+    ///     ..Default::default()
+    /// }.serialize();
+    ///
+    /// let char_infos = QueryFontReply::lazy_char_infos(&raw_reply)?;
+    /// assert_eq!(char_infos.len(), 3);
+    /// assert_eq!(char_infos.get(34), Some(info(3)));
+    /// assert_eq!(char_infos.get(31), None);
+    /// # Ok::<(), x11rb_protocol::errors::ParseError>(())
+    /// ```
+    pub fn lazy_char_infos(reply: &[u8]) -> Result<crate::wrapper::QueryFontCharInfos<'_>, ParseError> {
+        crate::wrapper::QueryFontCharInfos::try_parse(reply)
+    }
+}
+
 
 /// # Fields
 ///
@@ -14609,35 +14824,34 @@ pub struct QueryFontReply {
 impl TryParse for QueryFontReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_bounds, remaining) = Charinfo::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (max_bounds, remaining) = Charinfo::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (min_char_or_byte2, remaining) = u16::try_parse(remaining)?;
-        let (max_char_or_byte2, remaining) = u16::try_parse(remaining)?;
-        let (default_char, remaining) = u16::try_parse(remaining)?;
-        let (properties_len, remaining) = u16::try_parse(remaining)?;
-        let (draw_direction, remaining) = u8::try_parse(remaining)?;
-        let (min_byte1, remaining) = u8::try_parse(remaining)?;
-        let (max_byte1, remaining) = u8::try_parse(remaining)?;
-        let (all_chars_exist, remaining) = bool::try_parse(remaining)?;
-        let (font_ascent, remaining) = i16::try_parse(remaining)?;
-        let (font_descent, remaining) = i16::try_parse(remaining)?;
-        let (char_infos_len, remaining) = u32::try_parse(remaining)?;
-        let (properties, remaining) = crate::x11_utils::parse_list::<Fontprop>(remaining, properties_len.try_to_usize()?)?;
-        let (char_infos, remaining) = crate::x11_utils::parse_list::<Charinfo>(remaining, char_infos_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (min_bounds, remaining) = crate::x11_utils::in_field(Charinfo::try_parse(remaining), "min_bounds")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (max_bounds, remaining) = crate::x11_utils::in_field(Charinfo::try_parse(remaining), "max_bounds")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (min_char_or_byte2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "min_char_or_byte2")?;
+        let (max_char_or_byte2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_char_or_byte2")?;
+        let (default_char, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "default_char")?;
+        let (properties_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "properties_len")?;
+        let (draw_direction, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "draw_direction")?;
+        let (min_byte1, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "min_byte1")?;
+        let (max_byte1, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "max_byte1")?;
+        let (all_chars_exist, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "all_chars_exist")?;
+        let (font_ascent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_ascent")?;
+        let (font_descent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_descent")?;
+        let (char_infos_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "char_infos_len")?;
+        let (properties, remaining) = crate::x11_utils::parse_list::<Fontprop>(remaining, properties_len.try_to_usize()?, Some("properties"))?;
+        let (char_infos, remaining) = crate::x11_utils::parse_list::<Charinfo>(remaining, char_infos_len.try_to_usize()?, Some("char_infos"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let draw_direction = draw_direction.into();
         let result = QueryFontReply { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, properties, char_infos };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14783,7 +14997,7 @@ impl<'input> QueryTextExtentsRequest<'input> {
         let remaining = &[header.minor_opcode];
         let (odd_length, remaining) = bool::try_parse(remaining)?;
         let _ = remaining;
-        let (font, remaining) = Fontable::try_parse(value)?;
+        let (font, remaining) = crate::x11_utils::in_field(Fontable::try_parse(value), "font")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut string = Vec::new();
@@ -14814,6 +15028,7 @@ impl<'input> QueryTextExtentsRequest<'input> {
 }
 impl<'input> Request for QueryTextExtentsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -14843,25 +15058,24 @@ pub struct QueryTextExtentsReply {
 impl TryParse for QueryTextExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (draw_direction, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (font_ascent, remaining) = i16::try_parse(remaining)?;
-        let (font_descent, remaining) = i16::try_parse(remaining)?;
-        let (overall_ascent, remaining) = i16::try_parse(remaining)?;
-        let (overall_descent, remaining) = i16::try_parse(remaining)?;
-        let (overall_width, remaining) = i32::try_parse(remaining)?;
-        let (overall_left, remaining) = i32::try_parse(remaining)?;
-        let (overall_right, remaining) = i32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (draw_direction, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "draw_direction")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (font_ascent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_ascent")?;
+        let (font_descent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_descent")?;
+        let (overall_ascent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "overall_ascent")?;
+        let (overall_descent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "overall_descent")?;
+        let (overall_width, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "overall_width")?;
+        let (overall_left, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "overall_left")?;
+        let (overall_right, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "overall_right")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let draw_direction = draw_direction.into();
         let result = QueryTextExtentsReply { draw_direction, sequence, length, font_ascent, font_descent, overall_ascent, overall_descent, overall_width, overall_left, overall_right };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -14934,8 +15148,8 @@ pub struct Str {
 }
 impl TryParse for Str {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (name_len, remaining) = u8::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "name_len")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         let result = Str { name };
         Ok((result, remaining))
@@ -15022,11 +15236,11 @@ impl<'input> ListFontsRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (max_names, remaining) = u16::try_parse(value)?;
-        let (pattern_len, remaining) = u16::try_parse(remaining)?;
-        let (pattern, remaining) = crate::x11_utils::parse_u8_list(remaining, pattern_len.try_to_usize()?)?;
+        let (max_names, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "max_names")?;
+        let (pattern_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pattern_len")?;
+        let (pattern, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, pattern_len.try_to_usize()?), "pattern")?;
         let _ = remaining;
         Ok(ListFontsRequest {
             max_names,
@@ -15043,6 +15257,7 @@ impl<'input> ListFontsRequest<'input> {
 }
 impl<'input> Request for ListFontsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15067,20 +15282,19 @@ pub struct ListFontsReply {
 impl TryParse for ListFontsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (names_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<Str>(remaining, names_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (names, remaining) = crate::x11_utils::parse_list::<Str>(remaining, names_len.try_to_usize()?, Some("names"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListFontsReply { sequence, length, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -15119,6 +15333,27 @@ impl ListFontsReply {
             .try_into().unwrap()
     }
 }
+impl ListFontsReply {
+    /// Lazily parse the `names` list from the raw bytes of a `ListFontsReply`.
+    ///
+    /// In contrast to parsing the whole `ListFontsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn names_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Str>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "names_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = names_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ListFontsWithInfo request
 pub const LIST_FONTS_WITH_INFO_REQUEST: u8 = 50;
@@ -15172,11 +15407,11 @@ impl<'input> ListFontsWithInfoRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (max_names, remaining) = u16::try_parse(value)?;
-        let (pattern_len, remaining) = u16::try_parse(remaining)?;
-        let (pattern, remaining) = crate::x11_utils::parse_u8_list(remaining, pattern_len.try_to_usize()?)?;
+        let (max_names, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "max_names")?;
+        let (pattern_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pattern_len")?;
+        let (pattern, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, pattern_len.try_to_usize()?), "pattern")?;
         let _ = remaining;
         Ok(ListFontsWithInfoRequest {
             max_names,
@@ -15193,6 +15428,7 @@ impl<'input> ListFontsWithInfoRequest<'input> {
 }
 impl<'input> Request for ListFontsWithInfoRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15242,36 +15478,35 @@ pub struct ListFontsWithInfoReply {
 impl TryParse for ListFontsWithInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (name_len, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (min_bounds, remaining) = Charinfo::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (max_bounds, remaining) = Charinfo::try_parse(remaining)?;
-        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
-        let (min_char_or_byte2, remaining) = u16::try_parse(remaining)?;
-        let (max_char_or_byte2, remaining) = u16::try_parse(remaining)?;
-        let (default_char, remaining) = u16::try_parse(remaining)?;
-        let (properties_len, remaining) = u16::try_parse(remaining)?;
-        let (draw_direction, remaining) = u8::try_parse(remaining)?;
-        let (min_byte1, remaining) = u8::try_parse(remaining)?;
-        let (max_byte1, remaining) = u8::try_parse(remaining)?;
-        let (all_chars_exist, remaining) = bool::try_parse(remaining)?;
-        let (font_ascent, remaining) = i16::try_parse(remaining)?;
-        let (font_descent, remaining) = i16::try_parse(remaining)?;
-        let (replies_hint, remaining) = u32::try_parse(remaining)?;
-        let (properties, remaining) = crate::x11_utils::parse_list::<Fontprop>(remaining, properties_len.try_to_usize()?)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "name_len")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (min_bounds, remaining) = crate::x11_utils::in_field(Charinfo::try_parse(remaining), "min_bounds")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (max_bounds, remaining) = crate::x11_utils::in_field(Charinfo::try_parse(remaining), "max_bounds")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 4)?;
+        let (min_char_or_byte2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "min_char_or_byte2")?;
+        let (max_char_or_byte2, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_char_or_byte2")?;
+        let (default_char, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "default_char")?;
+        let (properties_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "properties_len")?;
+        let (draw_direction, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "draw_direction")?;
+        let (min_byte1, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "min_byte1")?;
+        let (max_byte1, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "max_byte1")?;
+        let (all_chars_exist, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "all_chars_exist")?;
+        let (font_ascent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_ascent")?;
+        let (font_descent, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "font_descent")?;
+        let (replies_hint, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "replies_hint")?;
+        let (properties, remaining) = crate::x11_utils::parse_list::<Fontprop>(remaining, properties_len.try_to_usize()?, Some("properties"))?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let name = name.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let draw_direction = draw_direction.into();
         let result = ListFontsWithInfoReply { sequence, length, min_bounds, max_bounds, min_char_or_byte2, max_char_or_byte2, default_char, draw_direction, min_byte1, max_byte1, all_chars_exist, font_ascent, font_descent, replies_hint, properties, name };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -15378,11 +15613,11 @@ impl<'input> SetFontPathRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (font_qty, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (font, remaining) = crate::x11_utils::parse_list::<Str>(remaining, font_qty.try_to_usize()?)?;
+        let (font_qty, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "font_qty")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (font, remaining) = crate::x11_utils::parse_list::<Str>(remaining, font_qty.try_to_usize()?, Some("font"))?;
         let _ = remaining;
         Ok(SetFontPathRequest {
             font: Cow::Owned(font),
@@ -15397,6 +15632,7 @@ impl<'input> SetFontPathRequest<'input> {
 }
 impl<'input> Request for SetFontPathRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15435,7 +15671,7 @@ impl GetFontPathRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetFontPathRequest
@@ -15444,6 +15680,7 @@ impl GetFontPathRequest {
 }
 impl Request for GetFontPathRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15466,20 +15703,19 @@ pub struct GetFontPathReply {
 impl TryParse for GetFontPathReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (path_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (path, remaining) = crate::x11_utils::parse_list::<Str>(remaining, path_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (path_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "path_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (path, remaining) = crate::x11_utils::parse_list::<Str>(remaining, path_len.try_to_usize()?, Some("path"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetFontPathReply { sequence, length, path };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -15518,6 +15754,27 @@ impl GetFontPathReply {
             .try_into().unwrap()
     }
 }
+impl GetFontPathReply {
+    /// Lazily parse the `path` list from the raw bytes of a `GetFontPathReply`.
+    ///
+    /// In contrast to parsing the whole `GetFontPathReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn path_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Str>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (path_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "path_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = path_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CreatePixmap request
 pub const CREATE_PIXMAP_REQUEST: u8 = 53;
@@ -15592,12 +15849,12 @@ impl CreatePixmapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (depth, remaining) = u8::try_parse(remaining)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
         let _ = remaining;
-        let (pid, remaining) = Pixmap::try_parse(value)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (pid, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(value), "pid")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(CreatePixmapRequest {
             depth,
@@ -15610,6 +15867,7 @@ impl CreatePixmapRequest {
 }
 impl Request for CreatePixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15667,9 +15925,9 @@ impl FreePixmapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (pixmap, remaining) = Pixmap::try_parse(value)?;
+        let (pixmap, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(value), "pixmap")?;
         let _ = remaining;
         Ok(FreePixmapRequest {
             pixmap,
@@ -15678,6 +15936,7 @@ impl FreePixmapRequest {
 }
 impl Request for FreePixmapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -15879,7 +16138,7 @@ impl core::fmt::Debug for GC  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(GC, u32);
+bitmask_binop!(GC, u32, [FUNCTION, PLANE_MASK, FOREGROUND, BACKGROUND, LINE_WIDTH, LINE_STYLE, CAP_STYLE, JOIN_STYLE, FILL_STYLE, FILL_RULE, TILE, STIPPLE, TILE_STIPPLE_ORIGIN_X, TILE_STIPPLE_ORIGIN_Y, FONT, SUBWINDOW_MODE, GRAPHICS_EXPOSURES, CLIP_ORIGIN_X, CLIP_ORIGIN_Y, CLIP_MASK, DASH_OFFSET, DASH_LIST, ARC_MODE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -16331,7 +16590,7 @@ impl CreateGCAux {
         let mut outer_remaining = value;
         let function = if switch_expr & u32::from(GC::FUNCTION) != 0 {
             let remaining = outer_remaining;
-            let (function, remaining) = u32::try_parse(remaining)?;
+            let (function, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "function")?;
             let function = function.into();
             outer_remaining = remaining;
             Some(function)
@@ -16340,7 +16599,7 @@ impl CreateGCAux {
         };
         let plane_mask = if switch_expr & u32::from(GC::PLANE_MASK) != 0 {
             let remaining = outer_remaining;
-            let (plane_mask, remaining) = u32::try_parse(remaining)?;
+            let (plane_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "plane_mask")?;
             outer_remaining = remaining;
             Some(plane_mask)
         } else {
@@ -16348,7 +16607,7 @@ impl CreateGCAux {
         };
         let foreground = if switch_expr & u32::from(GC::FOREGROUND) != 0 {
             let remaining = outer_remaining;
-            let (foreground, remaining) = u32::try_parse(remaining)?;
+            let (foreground, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "foreground")?;
             outer_remaining = remaining;
             Some(foreground)
         } else {
@@ -16356,7 +16615,7 @@ impl CreateGCAux {
         };
         let background = if switch_expr & u32::from(GC::BACKGROUND) != 0 {
             let remaining = outer_remaining;
-            let (background, remaining) = u32::try_parse(remaining)?;
+            let (background, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "background")?;
             outer_remaining = remaining;
             Some(background)
         } else {
@@ -16364,7 +16623,7 @@ impl CreateGCAux {
         };
         let line_width = if switch_expr & u32::from(GC::LINE_WIDTH) != 0 {
             let remaining = outer_remaining;
-            let (line_width, remaining) = u32::try_parse(remaining)?;
+            let (line_width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "line_width")?;
             outer_remaining = remaining;
             Some(line_width)
         } else {
@@ -16372,7 +16631,7 @@ impl CreateGCAux {
         };
         let line_style = if switch_expr & u32::from(GC::LINE_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (line_style, remaining) = u32::try_parse(remaining)?;
+            let (line_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "line_style")?;
             let line_style = line_style.into();
             outer_remaining = remaining;
             Some(line_style)
@@ -16381,7 +16640,7 @@ impl CreateGCAux {
         };
         let cap_style = if switch_expr & u32::from(GC::CAP_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (cap_style, remaining) = u32::try_parse(remaining)?;
+            let (cap_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cap_style")?;
             let cap_style = cap_style.into();
             outer_remaining = remaining;
             Some(cap_style)
@@ -16390,7 +16649,7 @@ impl CreateGCAux {
         };
         let join_style = if switch_expr & u32::from(GC::JOIN_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (join_style, remaining) = u32::try_parse(remaining)?;
+            let (join_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "join_style")?;
             let join_style = join_style.into();
             outer_remaining = remaining;
             Some(join_style)
@@ -16399,7 +16658,7 @@ impl CreateGCAux {
         };
         let fill_style = if switch_expr & u32::from(GC::FILL_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (fill_style, remaining) = u32::try_parse(remaining)?;
+            let (fill_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fill_style")?;
             let fill_style = fill_style.into();
             outer_remaining = remaining;
             Some(fill_style)
@@ -16408,7 +16667,7 @@ impl CreateGCAux {
         };
         let fill_rule = if switch_expr & u32::from(GC::FILL_RULE) != 0 {
             let remaining = outer_remaining;
-            let (fill_rule, remaining) = u32::try_parse(remaining)?;
+            let (fill_rule, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fill_rule")?;
             let fill_rule = fill_rule.into();
             outer_remaining = remaining;
             Some(fill_rule)
@@ -16417,7 +16676,7 @@ impl CreateGCAux {
         };
         let tile = if switch_expr & u32::from(GC::TILE) != 0 {
             let remaining = outer_remaining;
-            let (tile, remaining) = Pixmap::try_parse(remaining)?;
+            let (tile, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "tile")?;
             outer_remaining = remaining;
             Some(tile)
         } else {
@@ -16425,7 +16684,7 @@ impl CreateGCAux {
         };
         let stipple = if switch_expr & u32::from(GC::STIPPLE) != 0 {
             let remaining = outer_remaining;
-            let (stipple, remaining) = Pixmap::try_parse(remaining)?;
+            let (stipple, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "stipple")?;
             outer_remaining = remaining;
             Some(stipple)
         } else {
@@ -16433,7 +16692,7 @@ impl CreateGCAux {
         };
         let tile_stipple_x_origin = if switch_expr & u32::from(GC::TILE_STIPPLE_ORIGIN_X) != 0 {
             let remaining = outer_remaining;
-            let (tile_stipple_x_origin, remaining) = i32::try_parse(remaining)?;
+            let (tile_stipple_x_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "tile_stipple_x_origin")?;
             outer_remaining = remaining;
             Some(tile_stipple_x_origin)
         } else {
@@ -16441,7 +16700,7 @@ impl CreateGCAux {
         };
         let tile_stipple_y_origin = if switch_expr & u32::from(GC::TILE_STIPPLE_ORIGIN_Y) != 0 {
             let remaining = outer_remaining;
-            let (tile_stipple_y_origin, remaining) = i32::try_parse(remaining)?;
+            let (tile_stipple_y_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "tile_stipple_y_origin")?;
             outer_remaining = remaining;
             Some(tile_stipple_y_origin)
         } else {
@@ -16449,7 +16708,7 @@ impl CreateGCAux {
         };
         let font = if switch_expr & u32::from(GC::FONT) != 0 {
             let remaining = outer_remaining;
-            let (font, remaining) = Font::try_parse(remaining)?;
+            let (font, remaining) = crate::x11_utils::in_field(Font::try_parse(remaining), "font")?;
             outer_remaining = remaining;
             Some(font)
         } else {
@@ -16457,7 +16716,7 @@ impl CreateGCAux {
         };
         let subwindow_mode = if switch_expr & u32::from(GC::SUBWINDOW_MODE) != 0 {
             let remaining = outer_remaining;
-            let (subwindow_mode, remaining) = u32::try_parse(remaining)?;
+            let (subwindow_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "subwindow_mode")?;
             let subwindow_mode = subwindow_mode.into();
             outer_remaining = remaining;
             Some(subwindow_mode)
@@ -16466,7 +16725,7 @@ impl CreateGCAux {
         };
         let graphics_exposures = if switch_expr & u32::from(GC::GRAPHICS_EXPOSURES) != 0 {
             let remaining = outer_remaining;
-            let (graphics_exposures, remaining) = Bool32::try_parse(remaining)?;
+            let (graphics_exposures, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "graphics_exposures")?;
             outer_remaining = remaining;
             Some(graphics_exposures)
         } else {
@@ -16474,7 +16733,7 @@ impl CreateGCAux {
         };
         let clip_x_origin = if switch_expr & u32::from(GC::CLIP_ORIGIN_X) != 0 {
             let remaining = outer_remaining;
-            let (clip_x_origin, remaining) = i32::try_parse(remaining)?;
+            let (clip_x_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clip_x_origin")?;
             outer_remaining = remaining;
             Some(clip_x_origin)
         } else {
@@ -16482,7 +16741,7 @@ impl CreateGCAux {
         };
         let clip_y_origin = if switch_expr & u32::from(GC::CLIP_ORIGIN_Y) != 0 {
             let remaining = outer_remaining;
-            let (clip_y_origin, remaining) = i32::try_parse(remaining)?;
+            let (clip_y_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clip_y_origin")?;
             outer_remaining = remaining;
             Some(clip_y_origin)
         } else {
@@ -16490,7 +16749,7 @@ impl CreateGCAux {
         };
         let clip_mask = if switch_expr & u32::from(GC::CLIP_MASK) != 0 {
             let remaining = outer_remaining;
-            let (clip_mask, remaining) = Pixmap::try_parse(remaining)?;
+            let (clip_mask, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "clip_mask")?;
             outer_remaining = remaining;
             Some(clip_mask)
         } else {
@@ -16498,7 +16757,7 @@ impl CreateGCAux {
         };
         let dash_offset = if switch_expr & u32::from(GC::DASH_OFFSET) != 0 {
             let remaining = outer_remaining;
-            let (dash_offset, remaining) = u32::try_parse(remaining)?;
+            let (dash_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dash_offset")?;
             outer_remaining = remaining;
             Some(dash_offset)
         } else {
@@ -16506,7 +16765,7 @@ impl CreateGCAux {
         };
         let dashes = if switch_expr & u32::from(GC::DASH_LIST) != 0 {
             let remaining = outer_remaining;
-            let (dashes, remaining) = u32::try_parse(remaining)?;
+            let (dashes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dashes")?;
             outer_remaining = remaining;
             Some(dashes)
         } else {
@@ -16514,7 +16773,7 @@ impl CreateGCAux {
         };
         let arc_mode = if switch_expr & u32::from(GC::ARC_MODE) != 0 {
             let remaining = outer_remaining;
-            let (arc_mode, remaining) = u32::try_parse(remaining)?;
+            let (arc_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "arc_mode")?;
             let arc_mode = arc_mode.into();
             outer_remaining = remaining;
             Some(arc_mode)
@@ -16899,11 +17158,11 @@ impl<'input> CreateGCRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cid, remaining) = Gcontext::try_parse(value)?;
-        let (drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (cid, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "cid")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "drawable")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = CreateGCAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(CreateGCRequest {
@@ -16923,6 +17182,7 @@ impl<'input> CreateGCRequest<'input> {
 }
 impl<'input> Request for CreateGCRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -16968,7 +17228,7 @@ impl ChangeGCAux {
         let mut outer_remaining = value;
         let function = if switch_expr & u32::from(GC::FUNCTION) != 0 {
             let remaining = outer_remaining;
-            let (function, remaining) = u32::try_parse(remaining)?;
+            let (function, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "function")?;
             let function = function.into();
             outer_remaining = remaining;
             Some(function)
@@ -16977,7 +17237,7 @@ impl ChangeGCAux {
         };
         let plane_mask = if switch_expr & u32::from(GC::PLANE_MASK) != 0 {
             let remaining = outer_remaining;
-            let (plane_mask, remaining) = u32::try_parse(remaining)?;
+            let (plane_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "plane_mask")?;
             outer_remaining = remaining;
             Some(plane_mask)
         } else {
@@ -16985,7 +17245,7 @@ impl ChangeGCAux {
         };
         let foreground = if switch_expr & u32::from(GC::FOREGROUND) != 0 {
             let remaining = outer_remaining;
-            let (foreground, remaining) = u32::try_parse(remaining)?;
+            let (foreground, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "foreground")?;
             outer_remaining = remaining;
             Some(foreground)
         } else {
@@ -16993,7 +17253,7 @@ impl ChangeGCAux {
         };
         let background = if switch_expr & u32::from(GC::BACKGROUND) != 0 {
             let remaining = outer_remaining;
-            let (background, remaining) = u32::try_parse(remaining)?;
+            let (background, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "background")?;
             outer_remaining = remaining;
             Some(background)
         } else {
@@ -17001,7 +17261,7 @@ impl ChangeGCAux {
         };
         let line_width = if switch_expr & u32::from(GC::LINE_WIDTH) != 0 {
             let remaining = outer_remaining;
-            let (line_width, remaining) = u32::try_parse(remaining)?;
+            let (line_width, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "line_width")?;
             outer_remaining = remaining;
             Some(line_width)
         } else {
@@ -17009,7 +17269,7 @@ impl ChangeGCAux {
         };
         let line_style = if switch_expr & u32::from(GC::LINE_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (line_style, remaining) = u32::try_parse(remaining)?;
+            let (line_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "line_style")?;
             let line_style = line_style.into();
             outer_remaining = remaining;
             Some(line_style)
@@ -17018,7 +17278,7 @@ impl ChangeGCAux {
         };
         let cap_style = if switch_expr & u32::from(GC::CAP_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (cap_style, remaining) = u32::try_parse(remaining)?;
+            let (cap_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "cap_style")?;
             let cap_style = cap_style.into();
             outer_remaining = remaining;
             Some(cap_style)
@@ -17027,7 +17287,7 @@ impl ChangeGCAux {
         };
         let join_style = if switch_expr & u32::from(GC::JOIN_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (join_style, remaining) = u32::try_parse(remaining)?;
+            let (join_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "join_style")?;
             let join_style = join_style.into();
             outer_remaining = remaining;
             Some(join_style)
@@ -17036,7 +17296,7 @@ impl ChangeGCAux {
         };
         let fill_style = if switch_expr & u32::from(GC::FILL_STYLE) != 0 {
             let remaining = outer_remaining;
-            let (fill_style, remaining) = u32::try_parse(remaining)?;
+            let (fill_style, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fill_style")?;
             let fill_style = fill_style.into();
             outer_remaining = remaining;
             Some(fill_style)
@@ -17045,7 +17305,7 @@ impl ChangeGCAux {
         };
         let fill_rule = if switch_expr & u32::from(GC::FILL_RULE) != 0 {
             let remaining = outer_remaining;
-            let (fill_rule, remaining) = u32::try_parse(remaining)?;
+            let (fill_rule, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "fill_rule")?;
             let fill_rule = fill_rule.into();
             outer_remaining = remaining;
             Some(fill_rule)
@@ -17054,7 +17314,7 @@ impl ChangeGCAux {
         };
         let tile = if switch_expr & u32::from(GC::TILE) != 0 {
             let remaining = outer_remaining;
-            let (tile, remaining) = Pixmap::try_parse(remaining)?;
+            let (tile, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "tile")?;
             outer_remaining = remaining;
             Some(tile)
         } else {
@@ -17062,7 +17322,7 @@ impl ChangeGCAux {
         };
         let stipple = if switch_expr & u32::from(GC::STIPPLE) != 0 {
             let remaining = outer_remaining;
-            let (stipple, remaining) = Pixmap::try_parse(remaining)?;
+            let (stipple, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "stipple")?;
             outer_remaining = remaining;
             Some(stipple)
         } else {
@@ -17070,7 +17330,7 @@ impl ChangeGCAux {
         };
         let tile_stipple_x_origin = if switch_expr & u32::from(GC::TILE_STIPPLE_ORIGIN_X) != 0 {
             let remaining = outer_remaining;
-            let (tile_stipple_x_origin, remaining) = i32::try_parse(remaining)?;
+            let (tile_stipple_x_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "tile_stipple_x_origin")?;
             outer_remaining = remaining;
             Some(tile_stipple_x_origin)
         } else {
@@ -17078,7 +17338,7 @@ impl ChangeGCAux {
         };
         let tile_stipple_y_origin = if switch_expr & u32::from(GC::TILE_STIPPLE_ORIGIN_Y) != 0 {
             let remaining = outer_remaining;
-            let (tile_stipple_y_origin, remaining) = i32::try_parse(remaining)?;
+            let (tile_stipple_y_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "tile_stipple_y_origin")?;
             outer_remaining = remaining;
             Some(tile_stipple_y_origin)
         } else {
@@ -17086,7 +17346,7 @@ impl ChangeGCAux {
         };
         let font = if switch_expr & u32::from(GC::FONT) != 0 {
             let remaining = outer_remaining;
-            let (font, remaining) = Font::try_parse(remaining)?;
+            let (font, remaining) = crate::x11_utils::in_field(Font::try_parse(remaining), "font")?;
             outer_remaining = remaining;
             Some(font)
         } else {
@@ -17094,7 +17354,7 @@ impl ChangeGCAux {
         };
         let subwindow_mode = if switch_expr & u32::from(GC::SUBWINDOW_MODE) != 0 {
             let remaining = outer_remaining;
-            let (subwindow_mode, remaining) = u32::try_parse(remaining)?;
+            let (subwindow_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "subwindow_mode")?;
             let subwindow_mode = subwindow_mode.into();
             outer_remaining = remaining;
             Some(subwindow_mode)
@@ -17103,7 +17363,7 @@ impl ChangeGCAux {
         };
         let graphics_exposures = if switch_expr & u32::from(GC::GRAPHICS_EXPOSURES) != 0 {
             let remaining = outer_remaining;
-            let (graphics_exposures, remaining) = Bool32::try_parse(remaining)?;
+            let (graphics_exposures, remaining) = crate::x11_utils::in_field(Bool32::try_parse(remaining), "graphics_exposures")?;
             outer_remaining = remaining;
             Some(graphics_exposures)
         } else {
@@ -17111,7 +17371,7 @@ impl ChangeGCAux {
         };
         let clip_x_origin = if switch_expr & u32::from(GC::CLIP_ORIGIN_X) != 0 {
             let remaining = outer_remaining;
-            let (clip_x_origin, remaining) = i32::try_parse(remaining)?;
+            let (clip_x_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clip_x_origin")?;
             outer_remaining = remaining;
             Some(clip_x_origin)
         } else {
@@ -17119,7 +17379,7 @@ impl ChangeGCAux {
         };
         let clip_y_origin = if switch_expr & u32::from(GC::CLIP_ORIGIN_Y) != 0 {
             let remaining = outer_remaining;
-            let (clip_y_origin, remaining) = i32::try_parse(remaining)?;
+            let (clip_y_origin, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "clip_y_origin")?;
             outer_remaining = remaining;
             Some(clip_y_origin)
         } else {
@@ -17127,7 +17387,7 @@ impl ChangeGCAux {
         };
         let clip_mask = if switch_expr & u32::from(GC::CLIP_MASK) != 0 {
             let remaining = outer_remaining;
-            let (clip_mask, remaining) = Pixmap::try_parse(remaining)?;
+            let (clip_mask, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "clip_mask")?;
             outer_remaining = remaining;
             Some(clip_mask)
         } else {
@@ -17135,7 +17395,7 @@ impl ChangeGCAux {
         };
         let dash_offset = if switch_expr & u32::from(GC::DASH_OFFSET) != 0 {
             let remaining = outer_remaining;
-            let (dash_offset, remaining) = u32::try_parse(remaining)?;
+            let (dash_offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dash_offset")?;
             outer_remaining = remaining;
             Some(dash_offset)
         } else {
@@ -17143,7 +17403,7 @@ impl ChangeGCAux {
         };
         let dashes = if switch_expr & u32::from(GC::DASH_LIST) != 0 {
             let remaining = outer_remaining;
-            let (dashes, remaining) = u32::try_parse(remaining)?;
+            let (dashes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "dashes")?;
             outer_remaining = remaining;
             Some(dashes)
         } else {
@@ -17151,7 +17411,7 @@ impl ChangeGCAux {
         };
         let arc_mode = if switch_expr & u32::from(GC::ARC_MODE) != 0 {
             let remaining = outer_remaining;
-            let (arc_mode, remaining) = u32::try_parse(remaining)?;
+            let (arc_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "arc_mode")?;
             let arc_mode = arc_mode.into();
             outer_remaining = remaining;
             Some(arc_mode)
@@ -17551,10 +17811,10 @@ impl<'input> ChangeGCRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (gc, remaining) = Gcontext::try_parse(value)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "gc")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let (value_list, remaining) = ChangeGCAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(ChangeGCRequest {
@@ -17572,6 +17832,7 @@ impl<'input> ChangeGCRequest<'input> {
 }
 impl<'input> Request for ChangeGCRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -17629,11 +17890,11 @@ impl CopyGCRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (src_gc, remaining) = Gcontext::try_parse(value)?;
-        let (dst_gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (value_mask, remaining) = u32::try_parse(remaining)?;
+        let (src_gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "src_gc")?;
+        let (dst_gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "dst_gc")?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "value_mask")?;
         let value_mask = value_mask.into();
         let _ = remaining;
         Ok(CopyGCRequest {
@@ -17645,6 +17906,7 @@ impl CopyGCRequest {
 }
 impl Request for CopyGCRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -17702,12 +17964,12 @@ impl<'input> SetDashesRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (gc, remaining) = Gcontext::try_parse(value)?;
-        let (dash_offset, remaining) = u16::try_parse(remaining)?;
-        let (dashes_len, remaining) = u16::try_parse(remaining)?;
-        let (dashes, remaining) = crate::x11_utils::parse_u8_list(remaining, dashes_len.try_to_usize()?)?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "gc")?;
+        let (dash_offset, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dash_offset")?;
+        let (dashes_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "dashes_len")?;
+        let (dashes, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, dashes_len.try_to_usize()?), "dashes")?;
         let _ = remaining;
         Ok(SetDashesRequest {
             gc,
@@ -17726,6 +17988,7 @@ impl<'input> SetDashesRequest<'input> {
 }
 impl<'input> Request for SetDashesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -17849,12 +18112,12 @@ impl<'input> SetClipRectanglesRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (ordering, remaining) = u8::try_parse(remaining)?;
+        let (ordering, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "ordering")?;
         let ordering = ordering.into();
         let _ = remaining;
-        let (gc, remaining) = Gcontext::try_parse(value)?;
-        let (clip_x_origin, remaining) = i16::try_parse(remaining)?;
-        let (clip_y_origin, remaining) = i16::try_parse(remaining)?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "gc")?;
+        let (clip_x_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_x_origin")?;
+        let (clip_y_origin, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "clip_y_origin")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -17885,6 +18148,7 @@ impl<'input> SetClipRectanglesRequest<'input> {
 }
 impl<'input> Request for SetClipRectanglesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -17941,9 +18205,9 @@ impl FreeGCRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (gc, remaining) = Gcontext::try_parse(value)?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(value), "gc")?;
         let _ = remaining;
         Ok(FreeGCRequest {
             gc,
@@ -17952,6 +18216,7 @@ impl FreeGCRequest {
 }
 impl Request for FreeGCRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18015,13 +18280,13 @@ impl ClearAreaRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (exposures, remaining) = bool::try_parse(remaining)?;
+        let (exposures, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "exposures")?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(ClearAreaRequest {
             exposures,
@@ -18035,6 +18300,7 @@ impl ClearAreaRequest {
 }
 impl Request for ClearAreaRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18137,17 +18403,17 @@ impl CopyAreaRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (src_drawable, remaining) = Drawable::try_parse(value)?;
-        let (dst_drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let _ = remaining;
+        let (src_drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "src_drawable")?;
+        let (dst_drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "dst_drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(CopyAreaRequest {
             src_drawable,
@@ -18164,6 +18430,7 @@ impl CopyAreaRequest {
 }
 impl Request for CopyAreaRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18251,18 +18518,18 @@ impl CopyPlaneRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (src_drawable, remaining) = Drawable::try_parse(value)?;
-        let (dst_drawable, remaining) = Drawable::try_parse(remaining)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (bit_plane, remaining) = u32::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let _ = remaining;
+        let (src_drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "src_drawable")?;
+        let (dst_drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(remaining), "dst_drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (bit_plane, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "bit_plane")?;
         let _ = remaining;
         Ok(CopyPlaneRequest {
             src_drawable,
@@ -18280,6 +18547,7 @@ impl CopyPlaneRequest {
 }
 impl Request for CopyPlaneRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18401,11 +18669,11 @@ impl<'input> PolyPointRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
+        let (coordinate_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "coordinate_mode")?;
         let coordinate_mode = coordinate_mode.into();
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut points = Vec::new();
@@ -18434,6 +18702,7 @@ impl<'input> PolyPointRequest<'input> {
 }
 impl<'input> Request for PolyPointRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18531,11 +18800,11 @@ impl<'input> PolyLineRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
+        let (coordinate_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "coordinate_mode")?;
         let coordinate_mode = coordinate_mode.into();
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut points = Vec::new();
@@ -18564,6 +18833,7 @@ impl<'input> PolyLineRequest<'input> {
 }
 impl<'input> Request for PolyLineRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18585,10 +18855,10 @@ pub struct Segment {
 }
 impl TryParse for Segment {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (x1, remaining) = i16::try_parse(remaining)?;
-        let (y1, remaining) = i16::try_parse(remaining)?;
-        let (x2, remaining) = i16::try_parse(remaining)?;
-        let (y2, remaining) = i16::try_parse(remaining)?;
+        let (x1, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x1")?;
+        let (y1, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y1")?;
+        let (x2, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x2")?;
+        let (y2, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y2")?;
         let result = Segment { x1, y1, x2, y2 };
         Ok((result, remaining))
     }
@@ -18691,10 +18961,10 @@ impl<'input> PolySegmentRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut segments = Vec::new();
@@ -18721,6 +18991,7 @@ impl<'input> PolySegmentRequest<'input> {
 }
 impl<'input> Request for PolySegmentRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18777,10 +19048,10 @@ impl<'input> PolyRectangleRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -18807,6 +19078,7 @@ impl<'input> PolyRectangleRequest<'input> {
 }
 impl<'input> Request for PolyRectangleRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -18863,10 +19135,10 @@ impl<'input> PolyArcRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut arcs = Vec::new();
@@ -18893,6 +19165,7 @@ impl<'input> PolyArcRequest<'input> {
 }
 impl<'input> Request for PolyArcRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19018,15 +19291,15 @@ impl<'input> FillPolyRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (shape, remaining) = u8::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (shape, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "shape")?;
         let shape = shape.into();
-        let (coordinate_mode, remaining) = u8::try_parse(remaining)?;
+        let (coordinate_mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "coordinate_mode")?;
         let coordinate_mode = coordinate_mode.into();
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut points = Vec::new();
@@ -19057,6 +19330,7 @@ impl<'input> FillPolyRequest<'input> {
 }
 impl<'input> Request for FillPolyRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19138,10 +19412,10 @@ impl<'input> PolyFillRectangleRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut rectangles = Vec::new();
@@ -19168,6 +19442,7 @@ impl<'input> PolyFillRectangleRequest<'input> {
 }
 impl<'input> Request for PolyFillRectangleRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19224,10 +19499,10 @@ impl<'input> PolyFillArcRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut arcs = Vec::new();
@@ -19254,6 +19529,7 @@ impl<'input> PolyFillArcRequest<'input> {
 }
 impl<'input> Request for PolyFillArcRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19396,18 +19672,18 @@ impl<'input> PutImageRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (format, remaining) = u8::try_parse(remaining)?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
         let format = format.into();
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (dst_x, remaining) = i16::try_parse(remaining)?;
-        let (dst_y, remaining) = i16::try_parse(remaining)?;
-        let (left_pad, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (dst_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_x")?;
+        let (dst_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "dst_y")?;
+        let (left_pad, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "left_pad")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(PutImageRequest {
@@ -19441,6 +19717,7 @@ impl<'input> PutImageRequest<'input> {
 }
 impl<'input> Request for PutImageRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19510,15 +19787,15 @@ impl GetImageRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (format, remaining) = u8::try_parse(remaining)?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
         let format = format.into();
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (plane_mask, remaining) = u32::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (plane_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "plane_mask")?;
         let _ = remaining;
         Ok(GetImageRequest {
             format,
@@ -19533,6 +19810,7 @@ impl GetImageRequest {
 }
 impl Request for GetImageRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19556,21 +19834,20 @@ pub struct GetImageReply {
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (visual, remaining) = Visualid::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (visual, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "visual")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(length).checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "data")?;
         let data = data.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetImageReply { depth, sequence, visual, data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -19664,12 +19941,12 @@ impl<'input> PolyText8Request<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let (items, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(PolyText8Request {
@@ -19693,6 +19970,7 @@ impl<'input> PolyText8Request<'input> {
 }
 impl<'input> Request for PolyText8Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19756,12 +20034,12 @@ impl<'input> PolyText16Request<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
         let (items, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(PolyText16Request {
@@ -19785,6 +20063,7 @@ impl<'input> PolyText16Request<'input> {
 }
 impl<'input> Request for PolyText16Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -19882,13 +20161,13 @@ impl<'input> ImageText8Request<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (string_len, remaining) = u8::try_parse(remaining)?;
+        let (string_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "string_len")?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, string_len.try_to_usize()?)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (string, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, string_len.try_to_usize()?), "string")?;
         let _ = remaining;
         Ok(ImageText8Request {
             drawable,
@@ -19911,6 +20190,7 @@ impl<'input> ImageText8Request<'input> {
 }
 impl<'input> Request for ImageText8Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20010,13 +20290,13 @@ impl<'input> ImageText16Request<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (string_len, remaining) = u8::try_parse(remaining)?;
+        let (string_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "string_len")?;
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (gc, remaining) = Gcontext::try_parse(remaining)?;
-        let (x, remaining) = i16::try_parse(remaining)?;
-        let (y, remaining) = i16::try_parse(remaining)?;
-        let (string, remaining) = crate::x11_utils::parse_list::<Char2b>(remaining, string_len.try_to_usize()?)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(Gcontext::try_parse(remaining), "gc")?;
+        let (x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "y")?;
+        let (string, remaining) = crate::x11_utils::parse_list::<Char2b>(remaining, string_len.try_to_usize()?, Some("string"))?;
         let _ = remaining;
         Ok(ImageText16Request {
             drawable,
@@ -20039,6 +20319,7 @@ impl<'input> ImageText16Request<'input> {
 }
 impl<'input> Request for ImageText16Request<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20157,12 +20438,12 @@ impl CreateColormapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (alloc, remaining) = u8::try_parse(remaining)?;
+        let (alloc, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "alloc")?;
         let alloc = alloc.into();
         let _ = remaining;
-        let (mid, remaining) = Colormap::try_parse(value)?;
-        let (window, remaining) = Window::try_parse(remaining)?;
-        let (visual, remaining) = Visualid::try_parse(remaining)?;
+        let (mid, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "mid")?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(remaining), "window")?;
+        let (visual, remaining) = crate::x11_utils::in_field(Visualid::try_parse(remaining), "visual")?;
         let _ = remaining;
         Ok(CreateColormapRequest {
             alloc,
@@ -20174,6 +20455,7 @@ impl CreateColormapRequest {
 }
 impl Request for CreateColormapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20219,9 +20501,9 @@ impl FreeColormapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
         let _ = remaining;
         Ok(FreeColormapRequest {
             cmap,
@@ -20230,6 +20512,7 @@ impl FreeColormapRequest {
 }
 impl Request for FreeColormapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20281,10 +20564,10 @@ impl CopyColormapAndFreeRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (mid, remaining) = Colormap::try_parse(value)?;
-        let (src_cmap, remaining) = Colormap::try_parse(remaining)?;
+        let (mid, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "mid")?;
+        let (src_cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(remaining), "src_cmap")?;
         let _ = remaining;
         Ok(CopyColormapAndFreeRequest {
             mid,
@@ -20294,6 +20577,7 @@ impl CopyColormapAndFreeRequest {
 }
 impl Request for CopyColormapAndFreeRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20339,9 +20623,9 @@ impl InstallColormapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
         let _ = remaining;
         Ok(InstallColormapRequest {
             cmap,
@@ -20350,6 +20634,7 @@ impl InstallColormapRequest {
 }
 impl Request for InstallColormapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20395,9 +20680,9 @@ impl UninstallColormapRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
         let _ = remaining;
         Ok(UninstallColormapRequest {
             cmap,
@@ -20406,6 +20691,7 @@ impl UninstallColormapRequest {
 }
 impl Request for UninstallColormapRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20451,9 +20737,9 @@ impl ListInstalledColormapsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ListInstalledColormapsRequest {
             window,
@@ -20462,6 +20748,7 @@ impl ListInstalledColormapsRequest {
 }
 impl Request for ListInstalledColormapsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20484,20 +20771,19 @@ pub struct ListInstalledColormapsReply {
 impl TryParse for ListInstalledColormapsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (cmaps_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (cmaps, remaining) = crate::x11_utils::parse_list::<Colormap>(remaining, cmaps_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (cmaps_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "cmaps_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (cmaps, remaining) = crate::x11_utils::parse_list::<Colormap>(remaining, cmaps_len.try_to_usize()?, Some("cmaps"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListInstalledColormapsReply { sequence, length, cmaps };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -20536,6 +20822,27 @@ impl ListInstalledColormapsReply {
             .try_into().unwrap()
     }
 }
+impl ListInstalledColormapsReply {
+    /// Lazily parse the `cmaps` list from the raw bytes of a `ListInstalledColormapsReply`.
+    ///
+    /// In contrast to parsing the whole `ListInstalledColormapsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn cmaps_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Colormap>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (cmaps_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "cmaps_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = cmaps_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the AllocColor request
 pub const ALLOC_COLOR_REQUEST: u8 = 84;
@@ -20603,13 +20910,13 @@ impl AllocColorRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let _ = remaining;
         Ok(AllocColorRequest {
             cmap,
@@ -20621,6 +20928,7 @@ impl AllocColorRequest {
 }
 impl Request for AllocColorRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20646,22 +20954,21 @@ pub struct AllocColorReply {
 impl TryParse for AllocColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (pixel, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixel")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AllocColorReply { sequence, length, red, green, blue, pixel };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -20757,12 +21064,12 @@ impl<'input> AllocNamedColorRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(AllocNamedColorRequest {
             cmap,
@@ -20779,6 +21086,7 @@ impl<'input> AllocNamedColorRequest<'input> {
 }
 impl<'input> Request for AllocNamedColorRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20807,24 +21115,23 @@ pub struct AllocNamedColorReply {
 impl TryParse for AllocNamedColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pixel, remaining) = u32::try_parse(remaining)?;
-        let (exact_red, remaining) = u16::try_parse(remaining)?;
-        let (exact_green, remaining) = u16::try_parse(remaining)?;
-        let (exact_blue, remaining) = u16::try_parse(remaining)?;
-        let (visual_red, remaining) = u16::try_parse(remaining)?;
-        let (visual_green, remaining) = u16::try_parse(remaining)?;
-        let (visual_blue, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixel")?;
+        let (exact_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_red")?;
+        let (exact_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_green")?;
+        let (exact_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_blue")?;
+        let (visual_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_red")?;
+        let (visual_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_green")?;
+        let (visual_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_blue")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AllocNamedColorReply { sequence, length, pixel, exact_red, exact_green, exact_blue, visual_red, visual_green, visual_blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -20929,11 +21236,11 @@ impl AllocColorCellsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (contiguous, remaining) = bool::try_parse(remaining)?;
+        let (contiguous, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "contiguous")?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (colors, remaining) = u16::try_parse(remaining)?;
-        let (planes, remaining) = u16::try_parse(remaining)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (colors, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "colors")?;
+        let (planes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "planes")?;
         let _ = remaining;
         Ok(AllocColorCellsRequest {
             contiguous,
@@ -20945,6 +21252,7 @@ impl AllocColorCellsRequest {
 }
 impl Request for AllocColorCellsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -20968,22 +21276,21 @@ pub struct AllocColorCellsReply {
 impl TryParse for AllocColorCellsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pixels_len, remaining) = u16::try_parse(remaining)?;
-        let (masks_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (pixels, remaining) = crate::x11_utils::parse_list::<u32>(remaining, pixels_len.try_to_usize()?)?;
-        let (masks, remaining) = crate::x11_utils::parse_list::<u32>(remaining, masks_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pixels_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixels_len")?;
+        let (masks_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "masks_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (pixels, remaining) = crate::x11_utils::parse_list::<u32>(remaining, pixels_len.try_to_usize()?, Some("pixels"))?;
+        let (masks, remaining) = crate::x11_utils::parse_list::<u32>(remaining, masks_len.try_to_usize()?, Some("masks"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AllocColorCellsReply { sequence, length, pixels, masks };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -21038,6 +21345,28 @@ impl AllocColorCellsReply {
             .try_into().unwrap()
     }
 }
+impl AllocColorCellsReply {
+    /// Lazily parse the `pixels` list from the raw bytes of a `AllocColorCellsReply`.
+    ///
+    /// In contrast to parsing the whole `AllocColorCellsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn pixels_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pixels_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixels_len")?;
+        let (masks_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "masks_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = pixels_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the AllocColorPlanes request
 pub const ALLOC_COLOR_PLANES_REQUEST: u8 = 87;
@@ -21091,13 +21420,13 @@ impl AllocColorPlanesRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (contiguous, remaining) = bool::try_parse(remaining)?;
+        let (contiguous, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "contiguous")?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (colors, remaining) = u16::try_parse(remaining)?;
-        let (reds, remaining) = u16::try_parse(remaining)?;
-        let (greens, remaining) = u16::try_parse(remaining)?;
-        let (blues, remaining) = u16::try_parse(remaining)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (colors, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "colors")?;
+        let (reds, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "reds")?;
+        let (greens, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "greens")?;
+        let (blues, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blues")?;
         let _ = remaining;
         Ok(AllocColorPlanesRequest {
             contiguous,
@@ -21111,6 +21440,7 @@ impl AllocColorPlanesRequest {
 }
 impl Request for AllocColorPlanesRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21136,24 +21466,23 @@ pub struct AllocColorPlanesReply {
 impl TryParse for AllocColorPlanesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (pixels_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (red_mask, remaining) = u32::try_parse(remaining)?;
-        let (green_mask, remaining) = u32::try_parse(remaining)?;
-        let (blue_mask, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (pixels, remaining) = crate::x11_utils::parse_list::<u32>(remaining, pixels_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pixels_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixels_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (red_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red_mask")?;
+        let (green_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green_mask")?;
+        let (blue_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (pixels, remaining) = crate::x11_utils::parse_list::<u32>(remaining, pixels_len.try_to_usize()?, Some("pixels"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = AllocColorPlanesReply { sequence, length, red_mask, green_mask, blue_mask, pixels };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -21196,6 +21525,31 @@ impl AllocColorPlanesReply {
             .try_into().unwrap()
     }
 }
+impl AllocColorPlanesReply {
+    /// Lazily parse the `pixels` list from the raw bytes of a `AllocColorPlanesReply`.
+    ///
+    /// In contrast to parsing the whole `AllocColorPlanesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn pixels_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (pixels_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pixels_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (red_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red_mask")?;
+        let (green_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green_mask")?;
+        let (blue_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue_mask")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = pixels_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the FreeColors request
 pub const FREE_COLORS_REQUEST: u8 = 88;
@@ -21242,10 +21596,10 @@ impl<'input> FreeColorsRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (plane_mask, remaining) = u32::try_parse(remaining)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (plane_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "plane_mask")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut pixels = Vec::new();
@@ -21272,6 +21626,7 @@ impl<'input> FreeColorsRequest<'input> {
 }
 impl<'input> Request for FreeColorsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21343,7 +21698,7 @@ impl core::fmt::Debug for ColorFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(ColorFlag, u8);
+bitmask_binop!(ColorFlag, u8, [RED, GREEN, BLUE]);
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21356,12 +21711,12 @@ pub struct Coloritem {
 }
 impl TryParse for Coloritem {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (pixel, remaining) = u32::try_parse(remaining)?;
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixel")?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let flags = flags.into();
         let result = Coloritem { pixel, red, green, blue, flags };
         Ok((result, remaining))
@@ -21440,9 +21795,9 @@ impl<'input> StoreColorsRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut items = Vec::new();
@@ -21467,6 +21822,7 @@ impl<'input> StoreColorsRequest<'input> {
 }
 impl<'input> Request for StoreColorsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21530,14 +21886,14 @@ impl<'input> StoreNamedColorRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (flags, remaining) = u8::try_parse(remaining)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "flags")?;
         let flags = flags.into();
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (pixel, remaining) = u32::try_parse(remaining)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (pixel, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "pixel")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(StoreNamedColorRequest {
             flags,
@@ -21558,6 +21914,7 @@ impl<'input> StoreNamedColorRequest<'input> {
 }
 impl<'input> Request for StoreNamedColorRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21578,10 +21935,10 @@ pub struct Rgb {
 }
 impl TryParse for Rgb {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (red, remaining) = u16::try_parse(remaining)?;
-        let (green, remaining) = u16::try_parse(remaining)?;
-        let (blue, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "red")?;
+        let (green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "green")?;
+        let (blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "blue")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let result = Rgb { red, green, blue };
         Ok((result, remaining))
     }
@@ -21651,9 +22008,9 @@ impl<'input> QueryColorsRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
         let mut pixels = Vec::new();
@@ -21678,6 +22035,7 @@ impl<'input> QueryColorsRequest<'input> {
 }
 impl<'input> Request for QueryColorsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21700,20 +22058,19 @@ pub struct QueryColorsReply {
 impl TryParse for QueryColorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (colors_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (colors, remaining) = crate::x11_utils::parse_list::<Rgb>(remaining, colors_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (colors_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "colors_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (colors, remaining) = crate::x11_utils::parse_list::<Rgb>(remaining, colors_len.try_to_usize()?, Some("colors"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryColorsReply { sequence, length, colors };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -21752,6 +22109,27 @@ impl QueryColorsReply {
             .try_into().unwrap()
     }
 }
+impl QueryColorsReply {
+    /// Lazily parse the `colors` list from the raw bytes of a `QueryColorsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryColorsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn colors_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Rgb>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (colors_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "colors_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = colors_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the LookupColor request
 pub const LOOKUP_COLOR_REQUEST: u8 = 92;
@@ -21797,12 +22175,12 @@ impl<'input> LookupColorRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cmap, remaining) = Colormap::try_parse(value)?;
-        let (name_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (cmap, remaining) = crate::x11_utils::in_field(Colormap::try_parse(value), "cmap")?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(LookupColorRequest {
             cmap,
@@ -21819,6 +22197,7 @@ impl<'input> LookupColorRequest<'input> {
 }
 impl<'input> Request for LookupColorRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -21846,23 +22225,22 @@ pub struct LookupColorReply {
 impl TryParse for LookupColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (exact_red, remaining) = u16::try_parse(remaining)?;
-        let (exact_green, remaining) = u16::try_parse(remaining)?;
-        let (exact_blue, remaining) = u16::try_parse(remaining)?;
-        let (visual_red, remaining) = u16::try_parse(remaining)?;
-        let (visual_green, remaining) = u16::try_parse(remaining)?;
-        let (visual_blue, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (exact_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_red")?;
+        let (exact_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_green")?;
+        let (exact_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "exact_blue")?;
+        let (visual_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_red")?;
+        let (visual_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_green")?;
+        let (visual_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "visual_blue")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = LookupColorReply { sequence, length, exact_red, exact_green, exact_blue, visual_red, visual_green, visual_blue };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -22052,19 +22430,19 @@ impl CreateCursorRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (cid, remaining) = Cursor::try_parse(value)?;
-        let (source, remaining) = Pixmap::try_parse(remaining)?;
-        let (mask, remaining) = Pixmap::try_parse(remaining)?;
-        let (fore_red, remaining) = u16::try_parse(remaining)?;
-        let (fore_green, remaining) = u16::try_parse(remaining)?;
-        let (fore_blue, remaining) = u16::try_parse(remaining)?;
-        let (back_red, remaining) = u16::try_parse(remaining)?;
-        let (back_green, remaining) = u16::try_parse(remaining)?;
-        let (back_blue, remaining) = u16::try_parse(remaining)?;
-        let (x, remaining) = u16::try_parse(remaining)?;
-        let (y, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let _ = remaining;
+        let (cid, remaining) = crate::x11_utils::in_field(Cursor::try_parse(value), "cid")?;
+        let (source, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "source")?;
+        let (mask, remaining) = crate::x11_utils::in_field(Pixmap::try_parse(remaining), "mask")?;
+        let (fore_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_red")?;
+        let (fore_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_green")?;
+        let (fore_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_blue")?;
+        let (back_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_red")?;
+        let (back_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_green")?;
+        let (back_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_blue")?;
+        let (x, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "x")?;
+        let (y, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "y")?;
         let _ = remaining;
         Ok(CreateCursorRequest {
             cid,
@@ -22083,6 +22461,7 @@ impl CreateCursorRequest {
 }
 impl Request for CreateCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22262,19 +22641,19 @@ impl CreateGlyphCursorRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let _ = remaining;
-        let (cid, remaining) = Cursor::try_parse(value)?;
-        let (source_font, remaining) = Font::try_parse(remaining)?;
-        let (mask_font, remaining) = Font::try_parse(remaining)?;
-        let (source_char, remaining) = u16::try_parse(remaining)?;
-        let (mask_char, remaining) = u16::try_parse(remaining)?;
-        let (fore_red, remaining) = u16::try_parse(remaining)?;
-        let (fore_green, remaining) = u16::try_parse(remaining)?;
-        let (fore_blue, remaining) = u16::try_parse(remaining)?;
-        let (back_red, remaining) = u16::try_parse(remaining)?;
-        let (back_green, remaining) = u16::try_parse(remaining)?;
-        let (back_blue, remaining) = u16::try_parse(remaining)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let _ = remaining;
+        let (cid, remaining) = crate::x11_utils::in_field(Cursor::try_parse(value), "cid")?;
+        let (source_font, remaining) = crate::x11_utils::in_field(Font::try_parse(remaining), "source_font")?;
+        let (mask_font, remaining) = crate::x11_utils::in_field(Font::try_parse(remaining), "mask_font")?;
+        let (source_char, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "source_char")?;
+        let (mask_char, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "mask_char")?;
+        let (fore_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_red")?;
+        let (fore_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_green")?;
+        let (fore_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_blue")?;
+        let (back_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_red")?;
+        let (back_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_green")?;
+        let (back_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_blue")?;
         let _ = remaining;
         Ok(CreateGlyphCursorRequest {
             cid,
@@ -22293,6 +22672,7 @@ impl CreateGlyphCursorRequest {
 }
 impl Request for CreateGlyphCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22350,9 +22730,9 @@ impl FreeCursorRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cursor, remaining) = Cursor::try_parse(value)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(value), "cursor")?;
         let _ = remaining;
         Ok(FreeCursorRequest {
             cursor,
@@ -22361,6 +22741,7 @@ impl FreeCursorRequest {
 }
 impl Request for FreeCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22430,15 +22811,15 @@ impl RecolorCursorRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (cursor, remaining) = Cursor::try_parse(value)?;
-        let (fore_red, remaining) = u16::try_parse(remaining)?;
-        let (fore_green, remaining) = u16::try_parse(remaining)?;
-        let (fore_blue, remaining) = u16::try_parse(remaining)?;
-        let (back_red, remaining) = u16::try_parse(remaining)?;
-        let (back_green, remaining) = u16::try_parse(remaining)?;
-        let (back_blue, remaining) = u16::try_parse(remaining)?;
+        let (cursor, remaining) = crate::x11_utils::in_field(Cursor::try_parse(value), "cursor")?;
+        let (fore_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_red")?;
+        let (fore_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_green")?;
+        let (fore_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "fore_blue")?;
+        let (back_red, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_red")?;
+        let (back_green, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_green")?;
+        let (back_blue, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "back_blue")?;
         let _ = remaining;
         Ok(RecolorCursorRequest {
             cursor,
@@ -22453,6 +22834,7 @@ impl RecolorCursorRequest {
 }
 impl Request for RecolorCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22569,12 +22951,12 @@ impl QueryBestSizeRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (class, remaining) = u8::try_parse(remaining)?;
+        let (class, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "class")?;
         let class = class.into();
         let _ = remaining;
-        let (drawable, remaining) = Drawable::try_parse(value)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(Drawable::try_parse(value), "drawable")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(QueryBestSizeRequest {
             class,
@@ -22586,6 +22968,7 @@ impl QueryBestSizeRequest {
 }
 impl Request for QueryBestSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22609,19 +22992,18 @@ pub struct QueryBestSizeReply {
 impl TryParse for QueryBestSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryBestSizeReply { sequence, length, width, height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -22719,11 +23101,11 @@ impl<'input> QueryExtensionRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (name_len, remaining) = u16::try_parse(value)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?)?;
+        let (name_len, remaining) = crate::x11_utils::in_field(u16::try_parse(value), "name_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_len.try_to_usize()?), "name")?;
         let _ = remaining;
         Ok(QueryExtensionRequest {
             name: Cow::Borrowed(name),
@@ -22738,6 +23120,7 @@ impl<'input> QueryExtensionRequest<'input> {
 }
 impl<'input> Request for QueryExtensionRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22769,21 +23152,20 @@ pub struct QueryExtensionReply {
 impl TryParse for QueryExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (present, remaining) = bool::try_parse(remaining)?;
-        let (major_opcode, remaining) = u8::try_parse(remaining)?;
-        let (first_event, remaining) = u8::try_parse(remaining)?;
-        let (first_error, remaining) = u8::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (present, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "present")?;
+        let (major_opcode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_opcode")?;
+        let (first_event, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_event")?;
+        let (first_error, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "first_error")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryExtensionReply { sequence, length, present, major_opcode, first_event, first_error };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -22853,7 +23235,7 @@ impl ListExtensionsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(ListExtensionsRequest
@@ -22862,6 +23244,7 @@ impl ListExtensionsRequest {
 }
 impl Request for ListExtensionsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -22884,19 +23267,18 @@ pub struct ListExtensionsReply {
 impl TryParse for ListExtensionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (names_len, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (names, remaining) = crate::x11_utils::parse_list::<Str>(remaining, names_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "names_len")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (names, remaining) = crate::x11_utils::parse_list::<Str>(remaining, names_len.try_to_usize()?, Some("names"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListExtensionsReply { sequence, length, names };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -22934,6 +23316,26 @@ impl ListExtensionsReply {
             .try_into().unwrap()
     }
 }
+impl ListExtensionsReply {
+    /// Lazily parse the `names` list from the raw bytes of a `ListExtensionsReply`.
+    ///
+    /// In contrast to parsing the whole `ListExtensionsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn names_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Str>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (names_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "names_len")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = names_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ChangeKeyboardMapping request
 pub const CHANGE_KEYBOARD_MAPPING_REQUEST: u8 = 100;
@@ -22979,12 +23381,12 @@ impl<'input> ChangeKeyboardMappingRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (keycode_count, remaining) = u8::try_parse(remaining)?;
+        let (keycode_count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycode_count")?;
         let _ = remaining;
-        let (first_keycode, remaining) = Keycode::try_parse(value)?;
-        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<Keysym>(remaining, u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(Keycode::try_parse(value), "first_keycode")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<Keysym>(remaining, u32::from(keycode_count).checked_mul(u32::from(keysyms_per_keycode)).ok_or(ParseError::InvalidExpression)?.try_to_usize()?, Some("keysyms"))?;
         let _ = remaining;
         Ok(ChangeKeyboardMappingRequest {
             keycode_count,
@@ -23005,6 +23407,7 @@ impl<'input> ChangeKeyboardMappingRequest<'input> {
 }
 impl<'input> Request for ChangeKeyboardMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23052,10 +23455,10 @@ impl GetKeyboardMappingRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (first_keycode, remaining) = Keycode::try_parse(value)?;
-        let (count, remaining) = u8::try_parse(remaining)?;
+        let (first_keycode, remaining) = crate::x11_utils::in_field(Keycode::try_parse(value), "first_keycode")?;
+        let (count, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "count")?;
         let _ = remaining;
         Ok(GetKeyboardMappingRequest {
             first_keycode,
@@ -23065,6 +23468,7 @@ impl GetKeyboardMappingRequest {
 }
 impl Request for GetKeyboardMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23087,19 +23491,18 @@ pub struct GetKeyboardMappingReply {
 impl TryParse for GetKeyboardMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (keysyms_per_keycode, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (keysyms, remaining) = crate::x11_utils::parse_list::<Keysym>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (keysyms, remaining) = crate::x11_utils::parse_list::<Keysym>(remaining, length.try_to_usize()?, Some("keysyms"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetKeyboardMappingReply { keysyms_per_keycode, sequence, keysyms };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -23137,6 +23540,26 @@ impl GetKeyboardMappingReply {
             .try_into().unwrap()
     }
 }
+impl GetKeyboardMappingReply {
+    /// Lazily parse the `keysyms` list from the raw bytes of a `GetKeyboardMappingReply`.
+    ///
+    /// In contrast to parsing the whole `GetKeyboardMappingReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn keysyms_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, Keysym>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (keysyms_per_keycode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keysyms_per_keycode")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23196,7 +23619,7 @@ impl core::fmt::Debug for KB  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(KB, u32);
+bitmask_binop!(KB, u32, [KEY_CLICK_PERCENT, BELL_PERCENT, BELL_PITCH, BELL_DURATION, LED, LED_MODE, KEY, AUTO_REPEAT_MODE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23313,7 +23736,7 @@ impl ChangeKeyboardControlAux {
         let mut outer_remaining = value;
         let key_click_percent = if switch_expr & u32::from(KB::KEY_CLICK_PERCENT) != 0 {
             let remaining = outer_remaining;
-            let (key_click_percent, remaining) = i32::try_parse(remaining)?;
+            let (key_click_percent, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "key_click_percent")?;
             outer_remaining = remaining;
             Some(key_click_percent)
         } else {
@@ -23321,7 +23744,7 @@ impl ChangeKeyboardControlAux {
         };
         let bell_percent = if switch_expr & u32::from(KB::BELL_PERCENT) != 0 {
             let remaining = outer_remaining;
-            let (bell_percent, remaining) = i32::try_parse(remaining)?;
+            let (bell_percent, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "bell_percent")?;
             outer_remaining = remaining;
             Some(bell_percent)
         } else {
@@ -23329,7 +23752,7 @@ impl ChangeKeyboardControlAux {
         };
         let bell_pitch = if switch_expr & u32::from(KB::BELL_PITCH) != 0 {
             let remaining = outer_remaining;
-            let (bell_pitch, remaining) = i32::try_parse(remaining)?;
+            let (bell_pitch, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "bell_pitch")?;
             outer_remaining = remaining;
             Some(bell_pitch)
         } else {
@@ -23337,7 +23760,7 @@ impl ChangeKeyboardControlAux {
         };
         let bell_duration = if switch_expr & u32::from(KB::BELL_DURATION) != 0 {
             let remaining = outer_remaining;
-            let (bell_duration, remaining) = i32::try_parse(remaining)?;
+            let (bell_duration, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "bell_duration")?;
             outer_remaining = remaining;
             Some(bell_duration)
         } else {
@@ -23345,7 +23768,7 @@ impl ChangeKeyboardControlAux {
         };
         let led = if switch_expr & u32::from(KB::LED) != 0 {
             let remaining = outer_remaining;
-            let (led, remaining) = u32::try_parse(remaining)?;
+            let (led, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led")?;
             outer_remaining = remaining;
             Some(led)
         } else {
@@ -23353,7 +23776,7 @@ impl ChangeKeyboardControlAux {
         };
         let led_mode = if switch_expr & u32::from(KB::LED_MODE) != 0 {
             let remaining = outer_remaining;
-            let (led_mode, remaining) = u32::try_parse(remaining)?;
+            let (led_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mode")?;
             let led_mode = led_mode.into();
             outer_remaining = remaining;
             Some(led_mode)
@@ -23362,7 +23785,7 @@ impl ChangeKeyboardControlAux {
         };
         let key = if switch_expr & u32::from(KB::KEY) != 0 {
             let remaining = outer_remaining;
-            let (key, remaining) = Keycode32::try_parse(remaining)?;
+            let (key, remaining) = crate::x11_utils::in_field(Keycode32::try_parse(remaining), "key")?;
             outer_remaining = remaining;
             Some(key)
         } else {
@@ -23370,7 +23793,7 @@ impl ChangeKeyboardControlAux {
         };
         let auto_repeat_mode = if switch_expr & u32::from(KB::AUTO_REPEAT_MODE) != 0 {
             let remaining = outer_remaining;
-            let (auto_repeat_mode, remaining) = u32::try_parse(remaining)?;
+            let (auto_repeat_mode, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "auto_repeat_mode")?;
             let auto_repeat_mode = auto_repeat_mode.into();
             outer_remaining = remaining;
             Some(auto_repeat_mode)
@@ -23540,9 +23963,9 @@ impl<'input> ChangeKeyboardControlRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (value_mask, remaining) = u32::try_parse(value)?;
+        let (value_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "value_mask")?;
         let (value_list, remaining) = ChangeKeyboardControlAux::try_parse(remaining, u32::from(value_mask))?;
         let _ = remaining;
         Ok(ChangeKeyboardControlRequest {
@@ -23558,6 +23981,7 @@ impl<'input> ChangeKeyboardControlRequest<'input> {
 }
 impl<'input> Request for ChangeKeyboardControlRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23596,7 +24020,7 @@ impl GetKeyboardControlRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetKeyboardControlRequest
@@ -23605,6 +24029,7 @@ impl GetKeyboardControlRequest {
 }
 impl Request for GetKeyboardControlRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23633,25 +24058,24 @@ pub struct GetKeyboardControlReply {
 impl TryParse for GetKeyboardControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (global_auto_repeat, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (led_mask, remaining) = u32::try_parse(remaining)?;
-        let (key_click_percent, remaining) = u8::try_parse(remaining)?;
-        let (bell_percent, remaining) = u8::try_parse(remaining)?;
-        let (bell_pitch, remaining) = u16::try_parse(remaining)?;
-        let (bell_duration, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (global_auto_repeat, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "global_auto_repeat")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (led_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "led_mask")?;
+        let (key_click_percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "key_click_percent")?;
+        let (bell_percent, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bell_percent")?;
+        let (bell_pitch, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "bell_pitch")?;
+        let (bell_duration, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "bell_duration")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let (auto_repeats, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let global_auto_repeat = global_auto_repeat.into();
         let result = GetKeyboardControlReply { global_auto_repeat, sequence, length, led_mask, key_click_percent, bell_percent, bell_pitch, bell_duration, auto_repeats };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -23769,7 +24193,7 @@ impl BellRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (percent, remaining) = i8::try_parse(remaining)?;
+        let (percent, remaining) = crate::x11_utils::in_field(i8::try_parse(remaining), "percent")?;
         let _ = remaining;
         let _ = value;
         Ok(BellRequest {
@@ -23779,6 +24203,7 @@ impl BellRequest {
 }
 impl Request for BellRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23836,13 +24261,13 @@ impl ChangePointerControlRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (acceleration_numerator, remaining) = i16::try_parse(value)?;
-        let (acceleration_denominator, remaining) = i16::try_parse(remaining)?;
-        let (threshold, remaining) = i16::try_parse(remaining)?;
-        let (do_acceleration, remaining) = bool::try_parse(remaining)?;
-        let (do_threshold, remaining) = bool::try_parse(remaining)?;
+        let (acceleration_numerator, remaining) = crate::x11_utils::in_field(i16::try_parse(value), "acceleration_numerator")?;
+        let (acceleration_denominator, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "acceleration_denominator")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "threshold")?;
+        let (do_acceleration, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "do_acceleration")?;
+        let (do_threshold, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "do_threshold")?;
         let _ = remaining;
         Ok(ChangePointerControlRequest {
             acceleration_numerator,
@@ -23855,6 +24280,7 @@ impl ChangePointerControlRequest {
 }
 impl Request for ChangePointerControlRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23893,7 +24319,7 @@ impl GetPointerControlRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetPointerControlRequest
@@ -23902,6 +24328,7 @@ impl GetPointerControlRequest {
 }
 impl Request for GetPointerControlRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -23926,21 +24353,20 @@ pub struct GetPointerControlReply {
 impl TryParse for GetPointerControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (acceleration_numerator, remaining) = u16::try_parse(remaining)?;
-        let (acceleration_denominator, remaining) = u16::try_parse(remaining)?;
-        let (threshold, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (acceleration_numerator, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "acceleration_numerator")?;
+        let (acceleration_denominator, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "acceleration_denominator")?;
+        let (threshold, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "threshold")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPointerControlReply { sequence, length, acceleration_numerator, acceleration_denominator, threshold };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -24168,13 +24594,13 @@ impl SetScreenSaverRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (timeout, remaining) = i16::try_parse(value)?;
-        let (interval, remaining) = i16::try_parse(remaining)?;
-        let (prefer_blanking, remaining) = u8::try_parse(remaining)?;
+        let (timeout, remaining) = crate::x11_utils::in_field(i16::try_parse(value), "timeout")?;
+        let (interval, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "interval")?;
+        let (prefer_blanking, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "prefer_blanking")?;
         let prefer_blanking = prefer_blanking.into();
-        let (allow_exposures, remaining) = u8::try_parse(remaining)?;
+        let (allow_exposures, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "allow_exposures")?;
         let allow_exposures = allow_exposures.into();
         let _ = remaining;
         Ok(SetScreenSaverRequest {
@@ -24187,6 +24613,7 @@ impl SetScreenSaverRequest {
 }
 impl Request for SetScreenSaverRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -24225,7 +24652,7 @@ impl GetScreenSaverRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetScreenSaverRequest
@@ -24234,6 +24661,7 @@ impl GetScreenSaverRequest {
 }
 impl Request for GetScreenSaverRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -24259,24 +24687,23 @@ pub struct GetScreenSaverReply {
 impl TryParse for GetScreenSaverReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (timeout, remaining) = u16::try_parse(remaining)?;
-        let (interval, remaining) = u16::try_parse(remaining)?;
-        let (prefer_blanking, remaining) = u8::try_parse(remaining)?;
-        let (allow_exposures, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (timeout, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "timeout")?;
+        let (interval, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "interval")?;
+        let (prefer_blanking, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "prefer_blanking")?;
+        let (allow_exposures, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "allow_exposures")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 18)?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let prefer_blanking = prefer_blanking.into();
         let allow_exposures = allow_exposures.into();
         let result = GetScreenSaverReply { sequence, length, timeout, interval, prefer_blanking, allow_exposures };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -24506,14 +24933,14 @@ impl<'input> ChangeHostsRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
-        let (family, remaining) = u8::try_parse(value)?;
+        let (family, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "family")?;
         let family = family.into();
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (address_len, remaining) = u16::try_parse(remaining)?;
-        let (address, remaining) = crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (address_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "address_len")?;
+        let (address, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?), "address")?;
         let _ = remaining;
         Ok(ChangeHostsRequest {
             mode,
@@ -24532,6 +24959,7 @@ impl<'input> ChangeHostsRequest<'input> {
 }
 impl<'input> Request for ChangeHostsRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -24552,15 +24980,15 @@ pub struct Host {
 impl TryParse for Host {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (family, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (address_len, remaining) = u16::try_parse(remaining)?;
-        let (address, remaining) = crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?)?;
+        let (family, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "family")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (address_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "address_len")?;
+        let (address, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, address_len.try_to_usize()?), "address")?;
         let address = address.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let family = family.into();
         let result = Host { family, address };
         Ok((result, remaining))
@@ -24626,7 +25054,7 @@ impl ListHostsRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(ListHostsRequest
@@ -24635,6 +25063,7 @@ impl ListHostsRequest {
 }
 impl Request for ListHostsRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -24658,21 +25087,20 @@ pub struct ListHostsReply {
 impl TryParse for ListHostsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (mode, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (hosts_len, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (hosts, remaining) = crate::x11_utils::parse_list::<Host>(remaining, hosts_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (hosts_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "hosts_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (hosts, remaining) = crate::x11_utils::parse_list::<Host>(remaining, hosts_len.try_to_usize()?, Some("hosts"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let mode = mode.into();
         let result = ListHostsReply { mode, sequence, length, hosts };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -24801,7 +25229,7 @@ impl SetAccessControlRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
         let _ = value;
@@ -24812,6 +25240,7 @@ impl SetAccessControlRequest {
 }
 impl Request for SetAccessControlRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -24914,7 +25343,7 @@ impl SetCloseDownModeRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
         let _ = value;
@@ -24925,6 +25354,7 @@ impl SetCloseDownModeRequest {
 }
 impl Request for SetCloseDownModeRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25046,9 +25476,9 @@ impl KillClientRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (resource, remaining) = u32::try_parse(value)?;
+        let (resource, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "resource")?;
         let _ = remaining;
         Ok(KillClientRequest {
             resource,
@@ -25057,6 +25487,7 @@ impl KillClientRequest {
 }
 impl Request for KillClientRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25115,12 +25546,12 @@ impl<'input> RotatePropertiesRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
-        let (window, remaining) = Window::try_parse(value)?;
-        let (atoms_len, remaining) = u16::try_parse(remaining)?;
-        let (delta, remaining) = i16::try_parse(remaining)?;
-        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?)?;
+        let (window, remaining) = crate::x11_utils::in_field(Window::try_parse(value), "window")?;
+        let (atoms_len, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "atoms_len")?;
+        let (delta, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "delta")?;
+        let (atoms, remaining) = crate::x11_utils::parse_list::<Atom>(remaining, atoms_len.try_to_usize()?, Some("atoms"))?;
         let _ = remaining;
         Ok(RotatePropertiesRequest {
             window,
@@ -25139,6 +25570,7 @@ impl<'input> RotatePropertiesRequest<'input> {
 }
 impl<'input> Request for RotatePropertiesRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25239,7 +25671,7 @@ impl ForceScreenSaverRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (mode, remaining) = u8::try_parse(remaining)?;
+        let (mode, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "mode")?;
         let mode = mode.into();
         let _ = remaining;
         let _ = value;
@@ -25250,6 +25682,7 @@ impl ForceScreenSaverRequest {
 }
 impl Request for ForceScreenSaverRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25356,9 +25789,9 @@ impl<'input> SetPointerMappingRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (map_len, remaining) = u8::try_parse(remaining)?;
+        let (map_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_len")?;
         let _ = remaining;
-        let (map, remaining) = crate::x11_utils::parse_u8_list(value, map_len.try_to_usize()?)?;
+        let (map, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(value, map_len.try_to_usize()?), "map")?;
         let _ = remaining;
         Ok(SetPointerMappingRequest {
             map: Cow::Borrowed(map),
@@ -25373,6 +25806,7 @@ impl<'input> SetPointerMappingRequest<'input> {
 }
 impl<'input> Request for SetPointerMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25395,18 +25829,17 @@ pub struct SetPointerMappingReply {
 impl TryParse for SetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetPointerMappingReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -25465,7 +25898,7 @@ impl GetPointerMappingRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetPointerMappingRequest
@@ -25474,6 +25907,7 @@ impl GetPointerMappingRequest {
 }
 impl Request for GetPointerMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25496,20 +25930,19 @@ pub struct GetPointerMappingReply {
 impl TryParse for GetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (map_len, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (map, remaining) = crate::x11_utils::parse_u8_list(remaining, map_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (map_len, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "map_len")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (map, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, map_len.try_to_usize()?), "map")?;
         let map = map.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPointerMappingReply { sequence, length, map };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -25654,9 +26087,9 @@ impl<'input> SetModifierMappingRequest<'input> {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
+        let (keycodes_per_modifier, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycodes_per_modifier")?;
         let _ = remaining;
-        let (keycodes, remaining) = crate::x11_utils::parse_u8_list(value, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (keycodes, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(value, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "keycodes")?;
         let _ = remaining;
         Ok(SetModifierMappingRequest {
             keycodes: Cow::Borrowed(keycodes),
@@ -25671,6 +26104,7 @@ impl<'input> SetModifierMappingRequest<'input> {
 }
 impl<'input> Request for SetModifierMappingRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25693,18 +26127,17 @@ pub struct SetModifierMappingReply {
 impl TryParse for SetModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (status, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (status, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "status")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let status = status.into();
         let result = SetModifierMappingReply { status, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -25763,7 +26196,7 @@ impl GetModifierMappingRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(GetModifierMappingRequest
@@ -25772,6 +26205,7 @@ impl GetModifierMappingRequest {
 }
 impl Request for GetModifierMappingRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
@@ -25794,20 +26228,19 @@ pub struct GetModifierMappingReply {
 impl TryParse for GetModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (keycodes_per_modifier, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (keycodes, remaining) = crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (keycodes_per_modifier, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "keycodes_per_modifier")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (keycodes, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, u32::from(keycodes_per_modifier).checked_mul(8u32).ok_or(ParseError::InvalidExpression)?.try_to_usize()?), "keycodes")?;
         let keycodes = keycodes.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetModifierMappingReply { sequence, length, keycodes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -25875,7 +26308,7 @@ impl NoOperationRequest {
             return Err(ParseError::InvalidValue);
         }
         let remaining = &[header.minor_opcode];
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
         let _ = remaining;
         let _ = value;
         Ok(NoOperationRequest
@@ -25884,6 +26317,7 @@ impl NoOperationRequest {
 }
 impl Request for NoOperationRequest {
     const EXTENSION_NAME: Option<&'static str> = None;
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
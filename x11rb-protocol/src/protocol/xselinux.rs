@@ -71,8 +71,8 @@ impl QueryVersionRequest {
         if header.minor_opcode != QUERY_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (client_major, remaining) = u8::try_parse(value)?;
-        let (client_minor, remaining) = u8::try_parse(remaining)?;
+        let (client_major, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "client_major")?;
+        let (client_minor, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "client_minor")?;
         let _ = remaining;
         Ok(QueryVersionRequest {
             client_major,
@@ -82,6 +82,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -105,19 +106,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major, remaining) = u16::try_parse(remaining)?;
-        let (server_minor, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (server_major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_major")?;
+        let (server_minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "server_minor")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, server_major, server_minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -193,8 +193,8 @@ impl<'input> SetDeviceCreateContextRequest<'input> {
         if header.minor_opcode != SET_DEVICE_CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetDeviceCreateContextRequest {
             context: Cow::Borrowed(context),
@@ -209,6 +209,7 @@ impl<'input> SetDeviceCreateContextRequest<'input> {
 }
 impl<'input> Request for SetDeviceCreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -253,6 +254,7 @@ impl GetDeviceCreateContextRequest {
 }
 impl Request for GetDeviceCreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -275,21 +277,20 @@ pub struct GetDeviceCreateContextReply {
 impl TryParse for GetDeviceCreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceCreateContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -372,9 +373,9 @@ impl<'input> SetDeviceContextRequest<'input> {
         if header.minor_opcode != SET_DEVICE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device, remaining) = u32::try_parse(value)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (device, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "device")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetDeviceContextRequest {
             device,
@@ -391,6 +392,7 @@ impl<'input> SetDeviceContextRequest<'input> {
 }
 impl<'input> Request for SetDeviceContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -435,7 +437,7 @@ impl GetDeviceContextRequest {
         if header.minor_opcode != GET_DEVICE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (device, remaining) = u32::try_parse(value)?;
+        let (device, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "device")?;
         let _ = remaining;
         Ok(GetDeviceContextRequest {
             device,
@@ -444,6 +446,7 @@ impl GetDeviceContextRequest {
 }
 impl Request for GetDeviceContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -466,21 +469,20 @@ pub struct GetDeviceContextReply {
 impl TryParse for GetDeviceContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetDeviceContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -557,8 +559,8 @@ impl<'input> SetWindowCreateContextRequest<'input> {
         if header.minor_opcode != SET_WINDOW_CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetWindowCreateContextRequest {
             context: Cow::Borrowed(context),
@@ -573,6 +575,7 @@ impl<'input> SetWindowCreateContextRequest<'input> {
 }
 impl<'input> Request for SetWindowCreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -617,6 +620,7 @@ impl GetWindowCreateContextRequest {
 }
 impl Request for GetWindowCreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -639,21 +643,20 @@ pub struct GetWindowCreateContextReply {
 impl TryParse for GetWindowCreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetWindowCreateContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -726,7 +729,7 @@ impl GetWindowContextRequest {
         if header.minor_opcode != GET_WINDOW_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(GetWindowContextRequest {
             window,
@@ -735,6 +738,7 @@ impl GetWindowContextRequest {
 }
 impl Request for GetWindowContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -757,21 +761,20 @@ pub struct GetWindowContextReply {
 impl TryParse for GetWindowContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetWindowContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -821,21 +824,21 @@ pub struct ListItem {
 impl TryParse for ListItem {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (name, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (object_context_len, remaining) = u32::try_parse(remaining)?;
-        let (data_context_len, remaining) = u32::try_parse(remaining)?;
-        let (object_context, remaining) = crate::x11_utils::parse_u8_list(remaining, object_context_len.try_to_usize()?)?;
+        let (name, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "name")?;
+        let (object_context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "object_context_len")?;
+        let (data_context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_context_len")?;
+        let (object_context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, object_context_len.try_to_usize()?), "object_context")?;
         let object_context = object_context.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (data_context, remaining) = crate::x11_utils::parse_u8_list(remaining, data_context_len.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (data_context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, data_context_len.try_to_usize()?), "data_context")?;
         let data_context = data_context.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = ListItem { name, object_context, data_context };
         Ok((result, remaining))
     }
@@ -926,8 +929,8 @@ impl<'input> SetPropertyCreateContextRequest<'input> {
         if header.minor_opcode != SET_PROPERTY_CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetPropertyCreateContextRequest {
             context: Cow::Borrowed(context),
@@ -942,6 +945,7 @@ impl<'input> SetPropertyCreateContextRequest<'input> {
 }
 impl<'input> Request for SetPropertyCreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -986,6 +990,7 @@ impl GetPropertyCreateContextRequest {
 }
 impl Request for GetPropertyCreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1008,21 +1013,20 @@ pub struct GetPropertyCreateContextReply {
 impl TryParse for GetPropertyCreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPropertyCreateContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1099,8 +1103,8 @@ impl<'input> SetPropertyUseContextRequest<'input> {
         if header.minor_opcode != SET_PROPERTY_USE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetPropertyUseContextRequest {
             context: Cow::Borrowed(context),
@@ -1115,6 +1119,7 @@ impl<'input> SetPropertyUseContextRequest<'input> {
 }
 impl<'input> Request for SetPropertyUseContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1159,6 +1164,7 @@ impl GetPropertyUseContextRequest {
 }
 impl Request for GetPropertyUseContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1181,21 +1187,20 @@ pub struct GetPropertyUseContextReply {
 impl TryParse for GetPropertyUseContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPropertyUseContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1274,8 +1279,8 @@ impl GetPropertyContextRequest {
         if header.minor_opcode != GET_PROPERTY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(GetPropertyContextRequest {
             window,
@@ -1285,6 +1290,7 @@ impl GetPropertyContextRequest {
 }
 impl Request for GetPropertyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1307,21 +1313,20 @@ pub struct GetPropertyContextReply {
 impl TryParse for GetPropertyContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPropertyContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1400,8 +1405,8 @@ impl GetPropertyDataContextRequest {
         if header.minor_opcode != GET_PROPERTY_DATA_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (property, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (property, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "property")?;
         let _ = remaining;
         Ok(GetPropertyDataContextRequest {
             window,
@@ -1411,6 +1416,7 @@ impl GetPropertyDataContextRequest {
 }
 impl Request for GetPropertyDataContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1433,21 +1439,20 @@ pub struct GetPropertyDataContextReply {
 impl TryParse for GetPropertyDataContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPropertyDataContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1520,7 +1525,7 @@ impl ListPropertiesRequest {
         if header.minor_opcode != LIST_PROPERTIES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(ListPropertiesRequest {
             window,
@@ -1529,6 +1534,7 @@ impl ListPropertiesRequest {
 }
 impl Request for ListPropertiesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1551,20 +1557,19 @@ pub struct ListPropertiesReply {
 impl TryParse for ListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (properties_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (properties, remaining) = crate::x11_utils::parse_list::<ListItem>(remaining, properties_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (properties_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "properties_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (properties, remaining) = crate::x11_utils::parse_list::<ListItem>(remaining, properties_len.try_to_usize()?, Some("properties"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListPropertiesReply { sequence, length, properties };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1603,6 +1608,27 @@ impl ListPropertiesReply {
             .try_into().unwrap()
     }
 }
+impl ListPropertiesReply {
+    /// Lazily parse the `properties` list from the raw bytes of a `ListPropertiesReply`.
+    ///
+    /// In contrast to parsing the whole `ListPropertiesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn properties_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ListItem>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (properties_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "properties_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = properties_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the SetSelectionCreateContext request
 pub const SET_SELECTION_CREATE_CONTEXT_REQUEST: u8 = 15;
@@ -1641,8 +1667,8 @@ impl<'input> SetSelectionCreateContextRequest<'input> {
         if header.minor_opcode != SET_SELECTION_CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetSelectionCreateContextRequest {
             context: Cow::Borrowed(context),
@@ -1657,6 +1683,7 @@ impl<'input> SetSelectionCreateContextRequest<'input> {
 }
 impl<'input> Request for SetSelectionCreateContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1701,6 +1728,7 @@ impl GetSelectionCreateContextRequest {
 }
 impl Request for GetSelectionCreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1723,21 +1751,20 @@ pub struct GetSelectionCreateContextReply {
 impl TryParse for GetSelectionCreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectionCreateContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1814,8 +1841,8 @@ impl<'input> SetSelectionUseContextRequest<'input> {
         if header.minor_opcode != SET_SELECTION_USE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_len, remaining) = u32::try_parse(value)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "context_len")?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let _ = remaining;
         Ok(SetSelectionUseContextRequest {
             context: Cow::Borrowed(context),
@@ -1830,6 +1857,7 @@ impl<'input> SetSelectionUseContextRequest<'input> {
 }
 impl<'input> Request for SetSelectionUseContextRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1874,6 +1902,7 @@ impl GetSelectionUseContextRequest {
 }
 impl Request for GetSelectionUseContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1896,21 +1925,20 @@ pub struct GetSelectionUseContextReply {
 impl TryParse for GetSelectionUseContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectionUseContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1983,7 +2011,7 @@ impl GetSelectionContextRequest {
         if header.minor_opcode != GET_SELECTION_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (selection, remaining) = xproto::Atom::try_parse(value)?;
+        let (selection, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(value), "selection")?;
         let _ = remaining;
         Ok(GetSelectionContextRequest {
             selection,
@@ -1992,6 +2020,7 @@ impl GetSelectionContextRequest {
 }
 impl Request for GetSelectionContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2014,21 +2043,20 @@ pub struct GetSelectionContextReply {
 impl TryParse for GetSelectionContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectionContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2101,7 +2129,7 @@ impl GetSelectionDataContextRequest {
         if header.minor_opcode != GET_SELECTION_DATA_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (selection, remaining) = xproto::Atom::try_parse(value)?;
+        let (selection, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(value), "selection")?;
         let _ = remaining;
         Ok(GetSelectionDataContextRequest {
             selection,
@@ -2110,6 +2138,7 @@ impl GetSelectionDataContextRequest {
 }
 impl Request for GetSelectionDataContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2132,21 +2161,20 @@ pub struct GetSelectionDataContextReply {
 impl TryParse for GetSelectionDataContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetSelectionDataContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2219,6 +2247,7 @@ impl ListSelectionsRequest {
 }
 impl Request for ListSelectionsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2241,20 +2270,19 @@ pub struct ListSelectionsReply {
 impl TryParse for ListSelectionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (selections_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (selections, remaining) = crate::x11_utils::parse_list::<ListItem>(remaining, selections_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (selections_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "selections_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (selections, remaining) = crate::x11_utils::parse_list::<ListItem>(remaining, selections_len.try_to_usize()?, Some("selections"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListSelectionsReply { sequence, length, selections };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2293,6 +2321,27 @@ impl ListSelectionsReply {
             .try_into().unwrap()
     }
 }
+impl ListSelectionsReply {
+    /// Lazily parse the `selections` list from the raw bytes of a `ListSelectionsReply`.
+    ///
+    /// In contrast to parsing the whole `ListSelectionsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn selections_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ListItem>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (selections_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "selections_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = selections_len.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GetClientContext request
 pub const GET_CLIENT_CONTEXT_REQUEST: u8 = 22;
@@ -2327,7 +2376,7 @@ impl GetClientContextRequest {
         if header.minor_opcode != GET_CLIENT_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (resource, remaining) = u32::try_parse(value)?;
+        let (resource, remaining) = crate::x11_utils::in_field(u32::try_parse(value), "resource")?;
         let _ = remaining;
         Ok(GetClientContextRequest {
             resource,
@@ -2336,6 +2385,7 @@ impl GetClientContextRequest {
 }
 impl Request for GetClientContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2358,21 +2408,20 @@ pub struct GetClientContextReply {
 impl TryParse for GetClientContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (context_len, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (context, remaining) = crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (context_len, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "context_len")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (context, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, context_len.try_to_usize()?), "context")?;
         let context = context.to_vec();
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetClientContextReply { sequence, length, context };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -71,9 +71,9 @@ impl GetVersionRequest {
         if header.minor_opcode != GET_VERSION_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (major_version, remaining) = u8::try_parse(value)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "major_version")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         let _ = remaining;
         Ok(GetVersionRequest {
             major_version,
@@ -83,6 +83,7 @@ impl GetVersionRequest {
 }
 impl Request for GetVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -106,18 +107,17 @@ pub struct GetVersionReply {
 impl TryParse for GetVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (major_version, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (minor_version, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (major_version, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "major_version")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (minor_version, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor_version")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetVersionReply { major_version, sequence, length, minor_version };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -263,8 +263,8 @@ impl CompareCursorRequest {
         if header.minor_opcode != COMPARE_CURSOR_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
-        let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
+        let (cursor, remaining) = crate::x11_utils::in_field(xproto::Cursor::try_parse(remaining), "cursor")?;
         let _ = remaining;
         Ok(CompareCursorRequest {
             window,
@@ -274,6 +274,7 @@ impl CompareCursorRequest {
 }
 impl Request for CompareCursorRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -296,17 +297,16 @@ pub struct CompareCursorReply {
 impl TryParse for CompareCursorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (same, remaining) = bool::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (same, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "same")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CompareCursorReply { same, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -411,16 +411,16 @@ impl FakeInputRequest {
         if header.minor_opcode != FAKE_INPUT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (type_, remaining) = u8::try_parse(value)?;
-        let (detail, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (time, remaining) = u32::try_parse(remaining)?;
-        let (root, remaining) = xproto::Window::try_parse(remaining)?;
-        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
-        let (root_x, remaining) = i16::try_parse(remaining)?;
-        let (root_y, remaining) = i16::try_parse(remaining)?;
-        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
-        let (deviceid, remaining) = u8::try_parse(remaining)?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(value), "type_")?;
+        let (detail, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "detail")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (time, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "time")?;
+        let (root, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(remaining), "root")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 8)?;
+        let (root_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_x")?;
+        let (root_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "root_y")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 7)?;
+        let (deviceid, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "deviceid")?;
         let _ = remaining;
         Ok(FakeInputRequest {
             type_,
@@ -435,6 +435,7 @@ impl FakeInputRequest {
 }
 impl Request for FakeInputRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -479,8 +480,8 @@ impl GrabControlRequest {
         if header.minor_opcode != GRAB_CONTROL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (impervious, remaining) = bool::try_parse(value)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (impervious, remaining) = crate::x11_utils::in_field(bool::try_parse(value), "impervious")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(GrabControlRequest {
             impervious,
@@ -489,6 +490,7 @@ impl GrabControlRequest {
 }
 impl Request for GrabControlRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -106,7 +106,7 @@ impl core::fmt::Debug for Type  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
-bitmask_binop!(Type, u8);
+bitmask_binop!(Type, u8, [INPUT_MASK, OUTPUT_MASK, VIDEO_MASK, STILL_MASK, IMAGE_MASK]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -272,7 +272,7 @@ impl core::fmt::Debug for AttributeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
-bitmask_binop!(AttributeFlag, u32);
+bitmask_binop!(AttributeFlag, u32, [GETTABLE, SETTABLE]);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -473,8 +473,8 @@ pub struct Rational {
 }
 impl TryParse for Rational {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (numerator, remaining) = i32::try_parse(remaining)?;
-        let (denominator, remaining) = i32::try_parse(remaining)?;
+        let (numerator, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "numerator")?;
+        let (denominator, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "denominator")?;
         let result = Rational { numerator, denominator };
         Ok((result, remaining))
     }
@@ -510,9 +510,9 @@ pub struct Format {
 }
 impl TryParse for Format {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (visual, remaining) = crate::x11_utils::in_field(xproto::Visualid::try_parse(remaining), "visual")?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let result = Format { visual, depth };
         Ok((result, remaining))
     }
@@ -553,19 +553,19 @@ pub struct AdaptorInfo {
 impl TryParse for AdaptorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (base_id, remaining) = Port::try_parse(remaining)?;
-        let (name_size, remaining) = u16::try_parse(remaining)?;
-        let (num_ports, remaining) = u16::try_parse(remaining)?;
-        let (num_formats, remaining) = u16::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_to_usize()?)?;
+        let (base_id, remaining) = crate::x11_utils::in_field(Port::try_parse(remaining), "base_id")?;
+        let (name_size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_size")?;
+        let (num_ports, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_ports")?;
+        let (num_formats, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_formats")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_size.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
-        let (formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, num_formats.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
+        let (formats, remaining) = crate::x11_utils::parse_list::<Format>(remaining, num_formats.try_to_usize()?, Some("formats"))?;
         let type_ = type_.into();
         let result = AdaptorInfo { base_id, num_ports, type_, name, formats };
         Ok((result, remaining))
@@ -634,18 +634,18 @@ pub struct EncodingInfo {
 impl TryParse for EncodingInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (encoding, remaining) = Encoding::try_parse(remaining)?;
-        let (name_size, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (rate, remaining) = Rational::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_to_usize()?)?;
+        let (encoding, remaining) = crate::x11_utils::in_field(Encoding::try_parse(remaining), "encoding")?;
+        let (name_size, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "name_size")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (rate, remaining) = crate::x11_utils::in_field(Rational::try_parse(remaining), "rate")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, name_size.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let result = EncodingInfo { encoding, width, height, rate, name };
         Ok((result, remaining))
     }
@@ -698,14 +698,14 @@ pub struct Image {
 }
 impl TryParse for Image {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (data_size, remaining) = u32::try_parse(remaining)?;
-        let (num_planes, remaining) = u32::try_parse(remaining)?;
-        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?)?;
-        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_size.try_to_usize()?)?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (data_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_size")?;
+        let (num_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_planes")?;
+        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?, Some("pitches"))?;
+        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?, Some("offsets"))?;
+        let (data, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, data_size.try_to_usize()?), "data")?;
         let data = data.to_vec();
         let result = Image { id, width, height, pitches, offsets, data };
         Ok((result, remaining))
@@ -773,16 +773,16 @@ pub struct AttributeInfo {
 impl TryParse for AttributeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let (min, remaining) = i32::try_parse(remaining)?;
-        let (max, remaining) = i32::try_parse(remaining)?;
-        let (size, remaining) = u32::try_parse(remaining)?;
-        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, size.try_to_usize()?)?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
+        let (min, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "min")?;
+        let (max, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "max")?;
+        let (size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "size")?;
+        let (name, remaining) = crate::x11_utils::in_field(crate::x11_utils::parse_u8_list(remaining, size.try_to_usize()?), "name")?;
         let name = name.to_vec();
         // Align offset to multiple of 4
         let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
         let misalignment = (4 - (offset % 4)) % 4;
-        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, misalignment)?;
         let flags = flags.into();
         let result = AttributeInfo { flags, min, max, name };
         Ok((result, remaining))
@@ -850,33 +850,33 @@ pub struct ImageFormatInfo {
 }
 impl TryParse for ImageFormatInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (type_, remaining) = u8::try_parse(remaining)?;
-        let (byte_order, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (type_, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "type_")?;
+        let (byte_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "byte_order")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
         let (guid, remaining) = crate::x11_utils::parse_u8_array::<16>(remaining)?;
-        let (bpp, remaining) = u8::try_parse(remaining)?;
-        let (num_planes, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (depth, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (red_mask, remaining) = u32::try_parse(remaining)?;
-        let (green_mask, remaining) = u32::try_parse(remaining)?;
-        let (blue_mask, remaining) = u32::try_parse(remaining)?;
-        let (format, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
-        let (y_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (u_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (v_sample_bits, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_y_period, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_u_period, remaining) = u32::try_parse(remaining)?;
-        let (vhorz_v_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_y_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_u_period, remaining) = u32::try_parse(remaining)?;
-        let (vvert_v_period, remaining) = u32::try_parse(remaining)?;
+        let (bpp, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "bpp")?;
+        let (num_planes, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "num_planes")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 2)?;
+        let (depth, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "depth")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (red_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "red_mask")?;
+        let (green_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "green_mask")?;
+        let (blue_mask, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "blue_mask")?;
+        let (format, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "format")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
+        let (y_sample_bits, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "y_sample_bits")?;
+        let (u_sample_bits, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "u_sample_bits")?;
+        let (v_sample_bits, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "v_sample_bits")?;
+        let (vhorz_y_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vhorz_y_period")?;
+        let (vhorz_u_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vhorz_u_period")?;
+        let (vhorz_v_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vhorz_v_period")?;
+        let (vvert_y_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vvert_y_period")?;
+        let (vvert_u_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vvert_u_period")?;
+        let (vvert_v_period, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "vvert_v_period")?;
         let (vcomp_order, remaining) = crate::x11_utils::parse_u8_array::<32>(remaining)?;
-        let (vscanline_order, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let (vscanline_order, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "vscanline_order")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 11)?;
         let type_ = type_.into();
         let byte_order = byte_order.into();
         let format = format.into();
@@ -1095,12 +1095,12 @@ pub struct VideoNotifyEvent {
 impl TryParse for VideoNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (reason, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (port, remaining) = Port::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (reason, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "reason")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(remaining), "port")?;
         let reason = reason.into();
         let result = VideoNotifyEvent { response_type, reason, sequence, time, drawable, port };
         let _ = remaining;
@@ -1213,13 +1213,13 @@ pub struct PortNotifyEvent {
 impl TryParse for PortNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
-        let (port, remaining) = Port::try_parse(remaining)?;
-        let (attribute, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (value, remaining) = i32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(remaining), "port")?;
+        let (attribute, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "attribute")?;
+        let (value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "value")?;
         let result = PortNotifyEvent { response_type, sequence, time, port, attribute, value };
         let _ = remaining;
         let remaining = initial_value.get(32..)
@@ -1354,6 +1354,7 @@ impl QueryExtensionRequest {
 }
 impl Request for QueryExtensionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1377,19 +1378,18 @@ pub struct QueryExtensionReply {
 impl TryParse for QueryExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major, remaining) = u16::try_parse(remaining)?;
-        let (minor, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "minor")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryExtensionReply { sequence, length, major, minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1461,7 +1461,7 @@ impl QueryAdaptorsRequest {
         if header.minor_opcode != QUERY_ADAPTORS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (window, remaining) = xproto::Window::try_parse(value)?;
+        let (window, remaining) = crate::x11_utils::in_field(xproto::Window::try_parse(value), "window")?;
         let _ = remaining;
         Ok(QueryAdaptorsRequest {
             window,
@@ -1470,6 +1470,7 @@ impl QueryAdaptorsRequest {
 }
 impl Request for QueryAdaptorsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1492,20 +1493,19 @@ pub struct QueryAdaptorsReply {
 impl TryParse for QueryAdaptorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_adaptors, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (info, remaining) = crate::x11_utils::parse_list::<AdaptorInfo>(remaining, num_adaptors.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_adaptors, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_adaptors")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (info, remaining) = crate::x11_utils::parse_list::<AdaptorInfo>(remaining, num_adaptors.try_to_usize()?, Some("info"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryAdaptorsReply { sequence, length, info };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1544,6 +1544,27 @@ impl QueryAdaptorsReply {
             .try_into().unwrap()
     }
 }
+impl QueryAdaptorsReply {
+    /// Lazily parse the `info` list from the raw bytes of a `QueryAdaptorsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryAdaptorsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn info_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, AdaptorInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_adaptors, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_adaptors")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_adaptors.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryEncodings request
 pub const QUERY_ENCODINGS_REQUEST: u8 = 2;
@@ -1578,7 +1599,7 @@ impl QueryEncodingsRequest {
         if header.minor_opcode != QUERY_ENCODINGS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
         let _ = remaining;
         Ok(QueryEncodingsRequest {
             port,
@@ -1587,6 +1608,7 @@ impl QueryEncodingsRequest {
 }
 impl Request for QueryEncodingsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1609,20 +1631,19 @@ pub struct QueryEncodingsReply {
 impl TryParse for QueryEncodingsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_encodings, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
-        let (info, remaining) = crate::x11_utils::parse_list::<EncodingInfo>(remaining, num_encodings.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_encodings, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_encodings")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        let (info, remaining) = crate::x11_utils::parse_list::<EncodingInfo>(remaining, num_encodings.try_to_usize()?, Some("info"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryEncodingsReply { sequence, length, info };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1661,6 +1682,27 @@ impl QueryEncodingsReply {
             .try_into().unwrap()
     }
 }
+impl QueryEncodingsReply {
+    /// Lazily parse the `info` list from the raw bytes of a `QueryEncodingsReply`.
+    ///
+    /// In contrast to parsing the whole `QueryEncodingsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn info_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, EncodingInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_encodings, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_encodings")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 22)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_encodings.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the GrabPort request
 pub const GRAB_PORT_REQUEST: u8 = 3;
@@ -1701,8 +1743,8 @@ impl GrabPortRequest {
         if header.minor_opcode != GRAB_PORT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(GrabPortRequest {
             port,
@@ -1712,6 +1754,7 @@ impl GrabPortRequest {
 }
 impl Request for GrabPortRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1734,18 +1777,17 @@ pub struct GrabPortReply {
 impl TryParse for GrabPortReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let (result, remaining) = u8::try_parse(remaining)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let (result, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "result")?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = result.into();
         let result = GrabPortReply { result, sequence, length };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1816,8 +1858,8 @@ impl UngrabPortRequest {
         if header.minor_opcode != UNGRAB_PORT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (time, remaining) = xproto::Timestamp::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (time, remaining) = crate::x11_utils::in_field(xproto::Timestamp::try_parse(remaining), "time")?;
         let _ = remaining;
         Ok(UngrabPortRequest {
             port,
@@ -1827,6 +1869,7 @@ impl UngrabPortRequest {
 }
 impl Request for UngrabPortRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1915,17 +1958,17 @@ impl PutVideoRequest {
         if header.minor_opcode != PUT_VIDEO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (vid_x, remaining) = i16::try_parse(remaining)?;
-        let (vid_y, remaining) = i16::try_parse(remaining)?;
-        let (vid_w, remaining) = u16::try_parse(remaining)?;
-        let (vid_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (vid_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_x")?;
+        let (vid_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_y")?;
+        let (vid_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_w")?;
+        let (vid_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
         let _ = remaining;
         Ok(PutVideoRequest {
             port,
@@ -1944,6 +1987,7 @@ impl PutVideoRequest {
 }
 impl Request for PutVideoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2032,17 +2076,17 @@ impl PutStillRequest {
         if header.minor_opcode != PUT_STILL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (vid_x, remaining) = i16::try_parse(remaining)?;
-        let (vid_y, remaining) = i16::try_parse(remaining)?;
-        let (vid_w, remaining) = u16::try_parse(remaining)?;
-        let (vid_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (vid_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_x")?;
+        let (vid_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_y")?;
+        let (vid_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_w")?;
+        let (vid_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
         let _ = remaining;
         Ok(PutStillRequest {
             port,
@@ -2061,6 +2105,7 @@ impl PutStillRequest {
 }
 impl Request for PutStillRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2149,17 +2194,17 @@ impl GetVideoRequest {
         if header.minor_opcode != GET_VIDEO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (vid_x, remaining) = i16::try_parse(remaining)?;
-        let (vid_y, remaining) = i16::try_parse(remaining)?;
-        let (vid_w, remaining) = u16::try_parse(remaining)?;
-        let (vid_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (vid_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_x")?;
+        let (vid_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_y")?;
+        let (vid_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_w")?;
+        let (vid_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
         let _ = remaining;
         Ok(GetVideoRequest {
             port,
@@ -2178,6 +2223,7 @@ impl GetVideoRequest {
 }
 impl Request for GetVideoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2266,17 +2312,17 @@ impl GetStillRequest {
         if header.minor_opcode != GET_STILL_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (vid_x, remaining) = i16::try_parse(remaining)?;
-        let (vid_y, remaining) = i16::try_parse(remaining)?;
-        let (vid_w, remaining) = u16::try_parse(remaining)?;
-        let (vid_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (vid_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_x")?;
+        let (vid_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "vid_y")?;
+        let (vid_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_w")?;
+        let (vid_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
         let _ = remaining;
         Ok(GetStillRequest {
             port,
@@ -2295,6 +2341,7 @@ impl GetStillRequest {
 }
 impl Request for GetStillRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2345,8 +2392,8 @@ impl StopVideoRequest {
         if header.minor_opcode != STOP_VIDEO_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
         let _ = remaining;
         Ok(StopVideoRequest {
             port,
@@ -2356,6 +2403,7 @@ impl StopVideoRequest {
 }
 impl Request for StopVideoRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2406,9 +2454,9 @@ impl SelectVideoNotifyRequest {
         if header.minor_opcode != SELECT_VIDEO_NOTIFY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (drawable, remaining) = xproto::Drawable::try_parse(value)?;
-        let (onoff, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(value), "drawable")?;
+        let (onoff, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "onoff")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(SelectVideoNotifyRequest {
             drawable,
@@ -2418,6 +2466,7 @@ impl SelectVideoNotifyRequest {
 }
 impl Request for SelectVideoNotifyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2468,9 +2517,9 @@ impl SelectPortNotifyRequest {
         if header.minor_opcode != SELECT_PORT_NOTIFY_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (onoff, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (onoff, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "onoff")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(SelectPortNotifyRequest {
             port,
@@ -2480,6 +2529,7 @@ impl SelectPortNotifyRequest {
 }
 impl Request for SelectPortNotifyRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2546,13 +2596,13 @@ impl QueryBestSizeRequest {
         if header.minor_opcode != QUERY_BEST_SIZE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (vid_w, remaining) = u16::try_parse(remaining)?;
-        let (vid_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
-        let (motion, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (vid_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_w")?;
+        let (vid_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "vid_h")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
+        let (motion, remaining) = crate::x11_utils::in_field(bool::try_parse(remaining), "motion")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(QueryBestSizeRequest {
             port,
@@ -2566,6 +2616,7 @@ impl QueryBestSizeRequest {
 }
 impl Request for QueryBestSizeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2589,19 +2640,18 @@ pub struct QueryBestSizeReply {
 impl TryParse for QueryBestSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (actual_width, remaining) = u16::try_parse(remaining)?;
-        let (actual_height, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (actual_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "actual_width")?;
+        let (actual_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "actual_height")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryBestSizeReply { sequence, length, actual_width, actual_height };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2685,9 +2735,9 @@ impl SetPortAttributeRequest {
         if header.minor_opcode != SET_PORT_ATTRIBUTE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (attribute, remaining) = xproto::Atom::try_parse(remaining)?;
-        let (value, remaining) = i32::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (attribute, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "attribute")?;
+        let (value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "value")?;
         let _ = remaining;
         Ok(SetPortAttributeRequest {
             port,
@@ -2698,6 +2748,7 @@ impl SetPortAttributeRequest {
 }
 impl Request for SetPortAttributeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2748,8 +2799,8 @@ impl GetPortAttributeRequest {
         if header.minor_opcode != GET_PORT_ATTRIBUTE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (attribute, remaining) = xproto::Atom::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (attribute, remaining) = crate::x11_utils::in_field(xproto::Atom::try_parse(remaining), "attribute")?;
         let _ = remaining;
         Ok(GetPortAttributeRequest {
             port,
@@ -2759,6 +2810,7 @@ impl GetPortAttributeRequest {
 }
 impl Request for GetPortAttributeRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2781,18 +2833,17 @@ pub struct GetPortAttributeReply {
 impl TryParse for GetPortAttributeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (value, remaining) = i32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (value, remaining) = crate::x11_utils::in_field(i32::try_parse(remaining), "value")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = GetPortAttributeReply { sequence, length, value };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2862,7 +2913,7 @@ impl QueryPortAttributesRequest {
         if header.minor_opcode != QUERY_PORT_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
         let _ = remaining;
         Ok(QueryPortAttributesRequest {
             port,
@@ -2871,6 +2922,7 @@ impl QueryPortAttributesRequest {
 }
 impl Request for QueryPortAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -2894,21 +2946,20 @@ pub struct QueryPortAttributesReply {
 impl TryParse for QueryPortAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_attributes, remaining) = u32::try_parse(remaining)?;
-        let (text_size, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
-        let (attributes, remaining) = crate::x11_utils::parse_list::<AttributeInfo>(remaining, num_attributes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attributes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attributes")?;
+        let (text_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "text_size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        let (attributes, remaining) = crate::x11_utils::parse_list::<AttributeInfo>(remaining, num_attributes.try_to_usize()?, Some("attributes"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryPortAttributesReply { sequence, length, text_size, attributes };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -2948,6 +2999,28 @@ impl QueryPortAttributesReply {
             .try_into().unwrap()
     }
 }
+impl QueryPortAttributesReply {
+    /// Lazily parse the `attributes` list from the raw bytes of a `QueryPortAttributesReply`.
+    ///
+    /// In contrast to parsing the whole `QueryPortAttributesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn attributes_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, AttributeInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_attributes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_attributes")?;
+        let (text_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "text_size")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 16)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_attributes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the ListImageFormats request
 pub const LIST_IMAGE_FORMATS_REQUEST: u8 = 16;
@@ -2982,7 +3055,7 @@ impl ListImageFormatsRequest {
         if header.minor_opcode != LIST_IMAGE_FORMATS_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
         let _ = remaining;
         Ok(ListImageFormatsRequest {
             port,
@@ -2991,6 +3064,7 @@ impl ListImageFormatsRequest {
 }
 impl Request for ListImageFormatsRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3013,20 +3087,19 @@ pub struct ListImageFormatsReply {
 impl TryParse for ListImageFormatsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_formats, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (format, remaining) = crate::x11_utils::parse_list::<ImageFormatInfo>(remaining, num_formats.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_formats, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_formats")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (format, remaining) = crate::x11_utils::parse_list::<ImageFormatInfo>(remaining, num_formats.try_to_usize()?, Some("format"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListImageFormatsReply { sequence, length, format };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3065,6 +3138,27 @@ impl ListImageFormatsReply {
             .try_into().unwrap()
     }
 }
+impl ListImageFormatsReply {
+    /// Lazily parse the `format` list from the raw bytes of a `ListImageFormatsReply`.
+    ///
+    /// In contrast to parsing the whole `ListImageFormatsReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn format_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, ImageFormatInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_formats, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_formats")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_formats.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the QueryImageAttributes request
 pub const QUERY_IMAGE_ATTRIBUTES_REQUEST: u8 = 17;
@@ -3113,10 +3207,10 @@ impl QueryImageAttributesRequest {
         if header.minor_opcode != QUERY_IMAGE_ATTRIBUTES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(QueryImageAttributesRequest {
             port,
@@ -3128,6 +3222,7 @@ impl QueryImageAttributesRequest {
 }
 impl Request for QueryImageAttributesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3154,24 +3249,23 @@ pub struct QueryImageAttributesReply {
 impl TryParse for QueryImageAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num_planes, remaining) = u32::try_parse(remaining)?;
-        let (data_size, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?)?;
-        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_planes")?;
+        let (data_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_size")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (pitches, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?, Some("pitches"))?;
+        let (offsets, remaining) = crate::x11_utils::parse_list::<u32>(remaining, num_planes.try_to_usize()?, Some("offsets"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryImageAttributesReply { sequence, length, data_size, width, height, pitches, offsets };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -3215,6 +3309,30 @@ impl QueryImageAttributesReply {
             .try_into().unwrap()
     }
 }
+impl QueryImageAttributesReply {
+    /// Lazily parse the `pitches` list from the raw bytes of a `QueryImageAttributesReply`.
+    ///
+    /// In contrast to parsing the whole `QueryImageAttributesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn pitches_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num_planes, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num_planes")?;
+        let (data_size, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "data_size")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num_planes.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 18;
@@ -3311,20 +3429,20 @@ impl<'input> PutImageRequest<'input> {
         if header.minor_opcode != PUT_IMAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (src_w, remaining) = u16::try_parse(remaining)?;
-        let (src_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (src_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_w")?;
+        let (src_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let (data, remaining) = remaining.split_at(remaining.len());
         let _ = remaining;
         Ok(PutImageRequest {
@@ -3368,6 +3486,7 @@ impl<'input> PutImageRequest<'input> {
 }
 impl<'input> Request for PutImageRequest<'input> {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -3488,24 +3607,24 @@ impl ShmPutImageRequest {
         if header.minor_opcode != SHM_PUT_IMAGE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port, remaining) = Port::try_parse(value)?;
-        let (drawable, remaining) = xproto::Drawable::try_parse(remaining)?;
-        let (gc, remaining) = xproto::Gcontext::try_parse(remaining)?;
-        let (shmseg, remaining) = shm::Seg::try_parse(remaining)?;
-        let (id, remaining) = u32::try_parse(remaining)?;
-        let (offset, remaining) = u32::try_parse(remaining)?;
-        let (src_x, remaining) = i16::try_parse(remaining)?;
-        let (src_y, remaining) = i16::try_parse(remaining)?;
-        let (src_w, remaining) = u16::try_parse(remaining)?;
-        let (src_h, remaining) = u16::try_parse(remaining)?;
-        let (drw_x, remaining) = i16::try_parse(remaining)?;
-        let (drw_y, remaining) = i16::try_parse(remaining)?;
-        let (drw_w, remaining) = u16::try_parse(remaining)?;
-        let (drw_h, remaining) = u16::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (send_event, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (port, remaining) = crate::x11_utils::in_field(Port::try_parse(value), "port")?;
+        let (drawable, remaining) = crate::x11_utils::in_field(xproto::Drawable::try_parse(remaining), "drawable")?;
+        let (gc, remaining) = crate::x11_utils::in_field(xproto::Gcontext::try_parse(remaining), "gc")?;
+        let (shmseg, remaining) = crate::x11_utils::in_field(shm::Seg::try_parse(remaining), "shmseg")?;
+        let (id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "id")?;
+        let (offset, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "offset")?;
+        let (src_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_x")?;
+        let (src_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "src_y")?;
+        let (src_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_w")?;
+        let (src_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "src_h")?;
+        let (drw_x, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_x")?;
+        let (drw_y, remaining) = crate::x11_utils::in_field(i16::try_parse(remaining), "drw_y")?;
+        let (drw_w, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_w")?;
+        let (drw_h, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "drw_h")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (send_event, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "send_event")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 3)?;
         let _ = remaining;
         Ok(ShmPutImageRequest {
             port,
@@ -3530,6 +3649,7 @@ impl ShmPutImageRequest {
 }
 impl Request for ShmPutImageRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -57,15 +57,15 @@ pub struct SurfaceInfo {
 }
 impl TryParse for SurfaceInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let (id, remaining) = Surface::try_parse(remaining)?;
-        let (chroma_format, remaining) = u16::try_parse(remaining)?;
-        let (pad0, remaining) = u16::try_parse(remaining)?;
-        let (max_width, remaining) = u16::try_parse(remaining)?;
-        let (max_height, remaining) = u16::try_parse(remaining)?;
-        let (subpicture_max_width, remaining) = u16::try_parse(remaining)?;
-        let (subpicture_max_height, remaining) = u16::try_parse(remaining)?;
-        let (mc_type, remaining) = u32::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (id, remaining) = crate::x11_utils::in_field(Surface::try_parse(remaining), "id")?;
+        let (chroma_format, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "chroma_format")?;
+        let (pad0, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "pad0")?;
+        let (max_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_width")?;
+        let (max_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "max_height")?;
+        let (subpicture_max_width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "subpicture_max_width")?;
+        let (subpicture_max_height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "subpicture_max_height")?;
+        let (mc_type, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "mc_type")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let result = SurfaceInfo { id, chroma_format, pad0, max_width, max_height, subpicture_max_width, subpicture_max_height, mc_type, flags };
         Ok((result, remaining))
     }
@@ -156,6 +156,7 @@ impl QueryVersionRequest {
 }
 impl Request for QueryVersionRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -179,19 +180,18 @@ pub struct QueryVersionReply {
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (major, remaining) = u32::try_parse(remaining)?;
-        let (minor, remaining) = u32::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (major, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "major")?;
+        let (minor, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "minor")?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = QueryVersionReply { sequence, length, major, minor };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -267,7 +267,7 @@ impl ListSurfaceTypesRequest {
         if header.minor_opcode != LIST_SURFACE_TYPES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port_id, remaining) = xv::Port::try_parse(value)?;
+        let (port_id, remaining) = crate::x11_utils::in_field(xv::Port::try_parse(value), "port_id")?;
         let _ = remaining;
         Ok(ListSurfaceTypesRequest {
             port_id,
@@ -276,6 +276,7 @@ impl ListSurfaceTypesRequest {
 }
 impl Request for ListSurfaceTypesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -298,20 +299,19 @@ pub struct ListSurfaceTypesReply {
 impl TryParse for ListSurfaceTypesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (surfaces, remaining) = crate::x11_utils::parse_list::<SurfaceInfo>(remaining, num.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (surfaces, remaining) = crate::x11_utils::parse_list::<SurfaceInfo>(remaining, num.try_to_usize()?, Some("surfaces"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListSurfaceTypesReply { sequence, length, surfaces };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -350,6 +350,27 @@ impl ListSurfaceTypesReply {
             .try_into().unwrap()
     }
 }
+impl ListSurfaceTypesReply {
+    /// Lazily parse the `surfaces` list from the raw bytes of a `ListSurfaceTypesReply`.
+    ///
+    /// In contrast to parsing the whole `ListSurfaceTypesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn surfaces_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, SurfaceInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the CreateContext request
 pub const CREATE_CONTEXT_REQUEST: u8 = 2;
@@ -410,12 +431,12 @@ impl CreateContextRequest {
         if header.minor_opcode != CREATE_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_id, remaining) = Context::try_parse(value)?;
-        let (port_id, remaining) = xv::Port::try_parse(remaining)?;
-        let (surface_id, remaining) = Surface::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
+        let (context_id, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context_id")?;
+        let (port_id, remaining) = crate::x11_utils::in_field(xv::Port::try_parse(remaining), "port_id")?;
+        let (surface_id, remaining) = crate::x11_utils::in_field(Surface::try_parse(remaining), "surface_id")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
+        let (flags, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags")?;
         let _ = remaining;
         Ok(CreateContextRequest {
             context_id,
@@ -429,6 +450,7 @@ impl CreateContextRequest {
 }
 impl Request for CreateContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -453,22 +475,21 @@ pub struct CreateContextReply {
 impl TryParse for CreateContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width_actual, remaining) = u16::try_parse(remaining)?;
-        let (height_actual, remaining) = u16::try_parse(remaining)?;
-        let (flags_return, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_actual")?;
+        let (height_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_actual")?;
+        let (flags_return, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags_return")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("priv_data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateContextReply { sequence, width_actual, height_actual, flags_return, priv_data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -509,6 +530,29 @@ impl CreateContextReply {
             .try_into().unwrap()
     }
 }
+impl CreateContextReply {
+    /// Lazily parse the `priv_data` list from the raw bytes of a `CreateContextReply`.
+    ///
+    /// In contrast to parsing the whole `CreateContextReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn priv_data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_actual")?;
+        let (height_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_actual")?;
+        let (flags_return, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "flags_return")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the DestroyContext request
 pub const DESTROY_CONTEXT_REQUEST: u8 = 3;
@@ -543,7 +587,7 @@ impl DestroyContextRequest {
         if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (context_id, remaining) = Context::try_parse(value)?;
+        let (context_id, remaining) = crate::x11_utils::in_field(Context::try_parse(value), "context_id")?;
         let _ = remaining;
         Ok(DestroyContextRequest {
             context_id,
@@ -552,6 +596,7 @@ impl DestroyContextRequest {
 }
 impl Request for DestroyContextRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -602,8 +647,8 @@ impl CreateSurfaceRequest {
         if header.minor_opcode != CREATE_SURFACE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (surface_id, remaining) = Surface::try_parse(value)?;
-        let (context_id, remaining) = Context::try_parse(remaining)?;
+        let (surface_id, remaining) = crate::x11_utils::in_field(Surface::try_parse(value), "surface_id")?;
+        let (context_id, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "context_id")?;
         let _ = remaining;
         Ok(CreateSurfaceRequest {
             surface_id,
@@ -613,6 +658,7 @@ impl CreateSurfaceRequest {
 }
 impl Request for CreateSurfaceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -634,19 +680,18 @@ pub struct CreateSurfaceReply {
 impl TryParse for CreateSurfaceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("priv_data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateSurfaceReply { sequence, priv_data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -684,6 +729,26 @@ impl CreateSurfaceReply {
             .try_into().unwrap()
     }
 }
+impl CreateSurfaceReply {
+    /// Lazily parse the `priv_data` list from the raw bytes of a `CreateSurfaceReply`.
+    ///
+    /// In contrast to parsing the whole `CreateSurfaceReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn priv_data_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, u32>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 24)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = length.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
 /// Opcode for the DestroySurface request
 pub const DESTROY_SURFACE_REQUEST: u8 = 5;
@@ -718,7 +783,7 @@ impl DestroySurfaceRequest {
         if header.minor_opcode != DESTROY_SURFACE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (surface_id, remaining) = Surface::try_parse(value)?;
+        let (surface_id, remaining) = crate::x11_utils::in_field(Surface::try_parse(value), "surface_id")?;
         let _ = remaining;
         Ok(DestroySurfaceRequest {
             surface_id,
@@ -727,6 +792,7 @@ impl DestroySurfaceRequest {
 }
 impl Request for DestroySurfaceRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -791,11 +857,11 @@ impl CreateSubpictureRequest {
         if header.minor_opcode != CREATE_SUBPICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (subpicture_id, remaining) = Subpicture::try_parse(value)?;
-        let (context, remaining) = Context::try_parse(remaining)?;
-        let (xvimage_id, remaining) = u32::try_parse(remaining)?;
-        let (width, remaining) = u16::try_parse(remaining)?;
-        let (height, remaining) = u16::try_parse(remaining)?;
+        let (subpicture_id, remaining) = crate::x11_utils::in_field(Subpicture::try_parse(value), "subpicture_id")?;
+        let (context, remaining) = crate::x11_utils::in_field(Context::try_parse(remaining), "context")?;
+        let (xvimage_id, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "xvimage_id")?;
+        let (width, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width")?;
+        let (height, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height")?;
         let _ = remaining;
         Ok(CreateSubpictureRequest {
             subpicture_id,
@@ -808,6 +874,7 @@ impl CreateSubpictureRequest {
 }
 impl Request for CreateSubpictureRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -834,24 +901,23 @@ pub struct CreateSubpictureReply {
 impl TryParse for CreateSubpictureReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (width_actual, remaining) = u16::try_parse(remaining)?;
-        let (height_actual, remaining) = u16::try_parse(remaining)?;
-        let (num_palette_entries, remaining) = u16::try_parse(remaining)?;
-        let (entry_bytes, remaining) = u16::try_parse(remaining)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (width_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "width_actual")?;
+        let (height_actual, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "height_actual")?;
+        let (num_palette_entries, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "num_palette_entries")?;
+        let (entry_bytes, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "entry_bytes")?;
         let (component_order, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?)?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 12)?;
+        let (priv_data, remaining) = crate::x11_utils::parse_list::<u32>(remaining, length.try_to_usize()?, Some("priv_data"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = CreateSubpictureReply { sequence, width_actual, height_actual, num_palette_entries, entry_bytes, component_order, priv_data };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -928,7 +994,7 @@ impl DestroySubpictureRequest {
         if header.minor_opcode != DESTROY_SUBPICTURE_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (subpicture_id, remaining) = Subpicture::try_parse(value)?;
+        let (subpicture_id, remaining) = crate::x11_utils::in_field(Subpicture::try_parse(value), "subpicture_id")?;
         let _ = remaining;
         Ok(DestroySubpictureRequest {
             subpicture_id,
@@ -937,6 +1003,7 @@ impl DestroySubpictureRequest {
 }
 impl Request for DestroySubpictureRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = false;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -987,8 +1054,8 @@ impl ListSubpictureTypesRequest {
         if header.minor_opcode != LIST_SUBPICTURE_TYPES_REQUEST {
             return Err(ParseError::InvalidValue);
         }
-        let (port_id, remaining) = xv::Port::try_parse(value)?;
-        let (surface_id, remaining) = Surface::try_parse(remaining)?;
+        let (port_id, remaining) = crate::x11_utils::in_field(xv::Port::try_parse(value), "port_id")?;
+        let (surface_id, remaining) = crate::x11_utils::in_field(Surface::try_parse(remaining), "surface_id")?;
         let _ = remaining;
         Ok(ListSubpictureTypesRequest {
             port_id,
@@ -998,6 +1065,7 @@ impl ListSubpictureTypesRequest {
 }
 impl Request for ListSubpictureTypesRequest {
     const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+    const HAS_REPLY: bool = true;
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
@@ -1020,20 +1088,19 @@ pub struct ListSubpictureTypesReply {
 impl TryParse for ListSubpictureTypesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (num, remaining) = u32::try_parse(remaining)?;
-        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
-        let (types, remaining) = crate::x11_utils::parse_list::<xv::ImageFormatInfo>(remaining, num.try_to_usize()?)?;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        let (types, remaining) = crate::x11_utils::parse_list::<xv::ImageFormatInfo>(remaining, num.try_to_usize()?, Some("types"))?;
         if response_type != 1 {
-            return Err(ParseError::InvalidValue);
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
         }
         let result = ListSubpictureTypesReply { sequence, length, types };
         let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
+        let remaining = &initial_value[crate::x11_utils::validate_reply_length(initial_value)?..];
         Ok((result, remaining))
     }
 }
@@ -1072,4 +1139,25 @@ impl ListSubpictureTypesReply {
             .try_into().unwrap()
     }
 }
+impl ListSubpictureTypesReply {
+    /// Lazily parse the `types` list from the raw bytes of a `ListSubpictureTypesReply`.
+    ///
+    /// In contrast to parsing the whole `ListSubpictureTypesReply`, this does not allocate a `Vec` for the
+    /// list. Instead, the returned iterator parses the entries on demand.
+    #[allow(unused_variables)]
+    pub fn types_lazy(value: &[u8]) -> Result<crate::x11_utils::LazyList<'_, xv::ImageFormatInfo>, ParseError> {
+        let remaining = value;
+        let (response_type, remaining) = crate::x11_utils::in_field(u8::try_parse(remaining), "response_type")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 1)?;
+        let (sequence, remaining) = crate::x11_utils::in_field(u16::try_parse(remaining), "sequence")?;
+        let (length, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "length")?;
+        let (num, remaining) = crate::x11_utils::in_field(u32::try_parse(remaining), "num")?;
+        let remaining = crate::x11_utils::parse_padding(remaining, 20)?;
+        if response_type != 1 {
+            return Err(ParseError::UnexpectedValue { value: response_type.into() });
+        }
+        let list_length = num.try_to_usize()?;
+        Ok(crate::x11_utils::LazyList::new(remaining, list_length))
+    }
+}
 
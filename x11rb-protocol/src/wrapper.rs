@@ -1,6 +1,9 @@
 //! Helpers for the generated code
 
+use super::errors::ParseError;
+use super::protocol::xproto::Charinfo;
 use super::x11_utils::TryParse;
+use core::convert::TryFrom;
 use core::marker::PhantomData;
 
 /// Iterator implementation used by [GetPropertyReply].
@@ -48,9 +51,280 @@ where
 
 impl<T: TryParse> core::iter::FusedIterator for PropertyIterator<'_, T> {}
 
+/// Compute the index of character `code` in the `char_infos` of a [QueryFontReply].
+///
+/// [QueryFontReply]: crate::protocol::xproto::QueryFontReply
+pub(crate) fn char_info_index(
+    min_byte1: u8,
+    max_byte1: u8,
+    min_char_or_byte2: u16,
+    max_char_or_byte2: u16,
+    code: u16,
+) -> Option<usize> {
+    if min_byte1 == 0 && max_byte1 == 0 {
+        // A linear font: The code is the character index
+        if (min_char_or_byte2..=max_char_or_byte2).contains(&code) {
+            Some(usize::from(code - min_char_or_byte2))
+        } else {
+            None
+        }
+    } else {
+        // A matrix font: The code consists of a row and a column
+        let [byte1, byte2] = code.to_be_bytes();
+        let (byte1, byte2) = (u16::from(byte1), u16::from(byte2));
+        let (min_byte1, max_byte1) = (u16::from(min_byte1), u16::from(max_byte1));
+        if (min_byte1..=max_byte1).contains(&byte1)
+            && (min_char_or_byte2..=max_char_or_byte2).contains(&byte2)
+        {
+            let row_length = usize::from(max_char_or_byte2 - min_char_or_byte2) + 1;
+            let row = usize::from(byte1 - min_byte1);
+            Some(row * row_length + usize::from(byte2 - min_char_or_byte2))
+        } else {
+            None
+        }
+    }
+}
+
+/// Borrowed access to the character metrics of a raw `QueryFont` reply.
+///
+/// This is the type returned by [QueryFontReply::lazy_char_infos]. In contrast to
+/// [QueryFontReply::char_infos], the metrics of a character are only parsed when they are looked
+/// up, so that fonts with many characters do not have to be parsed completely.
+///
+/// [QueryFontReply::lazy_char_infos]: crate::protocol::xproto::QueryFontReply::lazy_char_infos
+/// [QueryFontReply::char_infos]: crate::protocol::xproto::QueryFontReply::char_infos
+#[derive(Debug, Clone, Copy)]
+pub struct QueryFontCharInfos<'a> {
+    min_bounds: Charinfo,
+    min_char_or_byte2: u16,
+    max_char_or_byte2: u16,
+    min_byte1: u8,
+    max_byte1: u8,
+    char_infos: &'a [u8],
+}
+
+impl<'a> QueryFontCharInfos<'a> {
+    const CHAR_INFO_SIZE: usize = 12;
+
+    /// Get access to the char infos in the given raw `QueryFont` reply.
+    pub(crate) fn try_parse(reply: &'a [u8]) -> Result<Self, ParseError> {
+        let field = |offset: usize| reply.get(offset..).ok_or(ParseError::InsufficientData);
+        let (min_bounds, _) = Charinfo::try_parse(field(8)?)?;
+        let (min_char_or_byte2, _) = u16::try_parse(field(40)?)?;
+        let (max_char_or_byte2, _) = u16::try_parse(field(42)?)?;
+        let (properties_len, _) = u16::try_parse(field(46)?)?;
+        let (min_byte1, _) = u8::try_parse(field(49)?)?;
+        let (max_byte1, _) = u8::try_parse(field(50)?)?;
+        let (char_infos_len, _) = u32::try_parse(field(56)?)?;
+        let start = 60 + usize::from(properties_len) * 8;
+        let length = usize::try_from(char_infos_len)
+            .ok()
+            .and_then(|len| len.checked_mul(Self::CHAR_INFO_SIZE))
+            .ok_or(ParseError::ConversionFailed)?;
+        let char_infos = reply
+            .get(start..)
+            .and_then(|remaining| remaining.get(..length))
+            .ok_or(ParseError::InsufficientData)?;
+        Ok(Self {
+            min_bounds,
+            min_char_or_byte2,
+            max_char_or_byte2,
+            min_byte1,
+            max_byte1,
+            char_infos,
+        })
+    }
+
+    /// Get the number of char infos in the reply.
+    pub fn len(&self) -> usize {
+        self.char_infos.len() / Self::CHAR_INFO_SIZE
+    }
+
+    /// Check if the reply contains no char infos.
+    ///
+    /// In this case, all characters have the same metrics as `min_bounds`.
+    pub fn is_empty(&self) -> bool {
+        self.char_infos.is_empty()
+    }
+
+    /// Get the metrics of the character with the given code.
+    ///
+    /// `None` is returned if the code is outside of the range of characters of the font. See
+    /// [QueryFontReply::char_info] for the details.
+    ///
+    /// [QueryFontReply::char_info]: crate::protocol::xproto::QueryFontReply::char_info
+    pub fn get(&self, code: u16) -> Option<Charinfo> {
+        let index = char_info_index(
+            self.min_byte1,
+            self.max_byte1,
+            self.min_char_or_byte2,
+            self.max_char_or_byte2,
+            code,
+        )?;
+        if self.is_empty() {
+            return Some(self.min_bounds);
+        }
+        let offset = index.checked_mul(Self::CHAR_INFO_SIZE)?;
+        let data = self.char_infos.get(offset..)?;
+        Charinfo::try_parse(data).ok().map(|(info, _)| info)
+    }
+}
+
+/// A protocol element that was recorded by the RECORD extension.
+///
+/// See [RecordedElements].
+#[cfg(feature = "record")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordedElement<'a> {
+    /// The time when the element was recorded, if requested via the element header.
+    pub time: Option<u32>,
+    /// The sequence number of a recorded request, if requested via the element header.
+    pub client_sequence: Option<u32>,
+    /// The raw element, e.g. a request, a reply, an error, or an event.
+    pub data: &'a [u8],
+}
+
+/// Iterator over the protocol elements in a reply to `record::EnableContext`.
+///
+/// This is the type returned by [EnableContextReply::elements]. For the categories `FromServer`
+/// and `FromClient`, the data of a reply consists of a sequence of events, errors, replies, or
+/// requests, each possibly preceded by a timestamp and a sequence number as described by the
+/// element header of the reply. This iterator splits the data into these elements. For the
+/// `ClientStarted` category, the whole connection setup is a single element. The other
+/// categories do not contain any elements.
+///
+/// Elements that were recorded from clients with a different byte order cannot be split and
+/// produce an error.
+///
+/// [EnableContextReply::elements]: crate::protocol::record::EnableContextReply::elements
+#[cfg(feature = "record")]
+#[derive(Debug, Clone)]
+pub struct RecordedElements<'a> {
+    category: u8,
+    element_header: u8,
+    client_swapped: bool,
+    data: &'a [u8],
+}
+
+#[cfg(feature = "record")]
+impl<'a> RecordedElements<'a> {
+    const FROM_SERVER: u8 = 0;
+    const FROM_CLIENT: u8 = 1;
+    const CLIENT_STARTED: u8 = 2;
+
+    pub(crate) fn new(reply: &'a crate::protocol::record::EnableContextReply) -> Self {
+        let data = match reply.category {
+            Self::FROM_SERVER | Self::FROM_CLIENT | Self::CLIENT_STARTED => &reply.data[..],
+            _ => &[],
+        };
+        Self {
+            category: reply.category,
+            element_header: reply.element_header,
+            client_swapped: reply.client_swapped,
+            data,
+        }
+    }
+
+    fn has_flag(&self, flag: crate::protocol::record::HType) -> bool {
+        self.element_header & u8::from(flag) != 0
+    }
+
+    fn parse_element(&self) -> Result<(RecordedElement<'a>, &'a [u8]), ParseError> {
+        use crate::protocol::record::HType;
+
+        if self.client_swapped {
+            return Err(ParseError::InvalidValue);
+        }
+        let mut remaining = self.data;
+        let mut parse_u32_if = |condition: bool| -> Result<Option<u32>, ParseError> {
+            if !condition {
+                return Ok(None);
+            }
+            let (value, rest) = u32::try_parse(remaining)?;
+            remaining = rest;
+            Ok(Some(value))
+        };
+        let (time, client_sequence) = match self.category {
+            Self::FROM_CLIENT => (
+                parse_u32_if(self.has_flag(HType::FROM_CLIENT_TIME))?,
+                parse_u32_if(self.has_flag(HType::FROM_CLIENT_SEQUENCE))?,
+            ),
+            _ => (parse_u32_if(self.has_flag(HType::FROM_SERVER_TIME))?, None),
+        };
+        let length = |offset: usize| -> Result<usize, ParseError> {
+            let (length, _) = u32::try_parse(remaining.get(offset..).unwrap_or(&[]))?;
+            usize::try_from(length)
+                .ok()
+                .and_then(|length| length.checked_mul(4))
+                .ok_or(ParseError::ConversionFailed)
+        };
+        let length = match self.category {
+            Self::FROM_SERVER => {
+                // Replies and generic events have a length field, everything else is 32 bytes
+                let response_type = *remaining.first().ok_or(ParseError::InsufficientData)?;
+                if response_type == 1
+                    || response_type & 0x7f == crate::protocol::xproto::GE_GENERIC_EVENT
+                {
+                    32 + length(4)?
+                } else {
+                    32
+                }
+            }
+            Self::FROM_CLIENT => {
+                let (length16, _) = u16::try_parse(remaining.get(2..).unwrap_or(&[]))?;
+                if length16 == 0 {
+                    // A BIG-REQUESTS request with a 32 bit length
+                    length(4)?
+                } else {
+                    usize::from(length16) * 4
+                }
+            }
+            _ => remaining.len(),
+        };
+        if length == 0 || remaining.len() < length {
+            return Err(ParseError::InsufficientData);
+        }
+        let (data, remaining) = remaining.split_at(length);
+        let element = RecordedElement {
+            time,
+            client_sequence,
+            data,
+        };
+        Ok((element, remaining))
+    }
+}
+
+#[cfg(feature = "record")]
+impl<'a> Iterator for RecordedElements<'a> {
+    type Item = Result<RecordedElement<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        match self.parse_element() {
+            Ok((element, remaining)) => {
+                self.data = remaining;
+                Some(Ok(element))
+            }
+            Err(err) => {
+                self.data = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "record")]
+impl core::iter::FusedIterator for RecordedElements<'_> {}
+
 #[cfg(test)]
 mod tests {
-    use super::PropertyIterator;
+    use super::{char_info_index, PropertyIterator, QueryFontCharInfos};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{Charinfo, QueryFontReply};
+    use crate::x11_utils::Serialize;
+    use alloc::vec;
     use alloc::vec::Vec;
 
     #[test]
@@ -87,4 +361,150 @@ mod tests {
         let hint = PropertyIterator::<u32>::new(&[0; 30]).size_hint();
         assert_eq!(hint, (7, Some(7)));
     }
+
+    #[test]
+    fn test_char_info_index_matrix() {
+        // Rows 0x21 to 0x22, columns 0x30 to 0x32
+        let index = |code| char_info_index(0x21, 0x22, 0x30, 0x32, code);
+        assert_eq!(index(0x2130), Some(0));
+        assert_eq!(index(0x2132), Some(2));
+        assert_eq!(index(0x2230), Some(3));
+        assert_eq!(index(0x2232), Some(5));
+        assert_eq!(index(0x2033), None);
+        assert_eq!(index(0x2133), None);
+        assert_eq!(index(0x232f), None);
+        assert_eq!(index(0x0031), None);
+    }
+
+    #[test]
+    fn test_lazy_char_infos() {
+        let info = |width| Charinfo {
+            character_width: width,
+            ..Default::default()
+        };
+        let reply = QueryFontReply {
+            min_bounds: info(7),
+            min_char_or_byte2: 0x30,
+            max_char_or_byte2: 0x31,
+            min_byte1: 1,
+            max_byte1: 2,
+            properties: vec![Default::default(); 3],
+            char_infos: (1..=4).map(info).collect(),
+            ..Default::default()
+        };
+        let raw = reply.serialize();
+        let char_infos = QueryFontCharInfos::try_parse(&raw).unwrap();
+        assert_eq!(char_infos.len(), 4);
+        for code in [0x0130, 0x0131, 0x0230, 0x0231, 0x0232, 0x0330] {
+            assert_eq!(char_infos.get(code), reply.char_info(code));
+        }
+        assert_eq!(char_infos.get(0x0231), Some(info(4)));
+
+        let truncated = QueryFontCharInfos::try_parse(&raw[..raw.len() - 1]);
+        assert_eq!(truncated.unwrap_err(), ParseError::InsufficientData);
+
+        // Without char infos, all characters have the same metrics
+        let reply = QueryFontReply {
+            char_infos: Vec::new(),
+            ..reply
+        };
+        let raw = reply.serialize();
+        let char_infos = QueryFontCharInfos::try_parse(&raw).unwrap();
+        assert!(char_infos.is_empty());
+        assert_eq!(char_infos.get(0x0231), Some(info(7)));
+        assert_eq!(char_infos.get(0x0232), None);
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn test_recorded_elements_from_server() {
+        use super::RecordedElement;
+        use crate::protocol::record::{EnableContextReply, HType};
+
+        let event = [12; 32];
+        let mut reply = [1; 40];
+        reply[4..8].copy_from_slice(&2u32.to_ne_bytes());
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_ne_bytes());
+        data.extend_from_slice(&event);
+        data.extend_from_slice(&2u32.to_ne_bytes());
+        data.extend_from_slice(&reply);
+        let reply_ = EnableContextReply {
+            category: 0,
+            element_header: HType::FROM_SERVER_TIME.into(),
+            data,
+            ..Default::default()
+        };
+        let elements = reply_.elements().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            elements,
+            [
+                RecordedElement {
+                    time: Some(1),
+                    client_sequence: None,
+                    data: &event,
+                },
+                RecordedElement {
+                    time: Some(2),
+                    client_sequence: None,
+                    data: &reply,
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn test_recorded_elements_from_client() {
+        use crate::protocol::record::{EnableContextReply, HType};
+
+        let request = [8, 0, 2, 0, 42, 0, 0, 0];
+        let mut big_request = [0; 12];
+        big_request[4..8].copy_from_slice(&3u32.to_ne_bytes());
+        let mut data = Vec::new();
+        for (sequence, request) in [(7u32, &request[..]), (8, &big_request[..])] {
+            data.extend_from_slice(&sequence.to_ne_bytes());
+            data.extend_from_slice(request);
+        }
+        let reply = EnableContextReply {
+            category: 1,
+            element_header: HType::FROM_CLIENT_SEQUENCE.into(),
+            data,
+            ..Default::default()
+        };
+        let elements = reply.elements().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(
+            (elements[0].time, elements[0].client_sequence),
+            (None, Some(7))
+        );
+        assert_eq!(elements[0].data, request);
+        assert_eq!(elements[1].client_sequence, Some(8));
+        assert_eq!(elements[1].data, &big_request[..]);
+    }
+
+    #[cfg(feature = "record")]
+    #[test]
+    fn test_recorded_elements_errors() {
+        use crate::protocol::record::EnableContextReply;
+
+        let mut reply = EnableContextReply {
+            category: 0,
+            data: vec![12; 40],
+            ..Default::default()
+        };
+        let mut elements = reply.elements();
+        assert!(elements.next().unwrap().is_ok());
+        assert_eq!(elements.next(), Some(Err(ParseError::InsufficientData)));
+        assert_eq!(elements.next(), None);
+
+        reply.client_swapped = true;
+        let mut elements = reply.elements();
+        assert_eq!(elements.next(), Some(Err(ParseError::InvalidValue)));
+        assert_eq!(elements.next(), None);
+
+        // StartOfData does not contain any elements
+        reply.category = 4;
+        assert_eq!(reply.elements().next(), None);
+    }
 }
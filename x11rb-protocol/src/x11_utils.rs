@@ -48,7 +48,9 @@ impl X11Error {
         let (minor_opcode, remaining) = u16::try_parse(remaining)?;
         let (major_opcode, _) = u8::try_parse(remaining)?;
         if response_type != 0 {
-            Err(ParseError::InvalidValue)
+            Err(ParseError::UnexpectedValue {
+                value: response_type.into(),
+            })
         } else {
             let error_kind = ErrorKind::from_wire_error_code(error_code, ext_info_provider);
             let extension_name = ext_info_provider
@@ -67,12 +69,244 @@ impl X11Error {
             })
         }
     }
+
+    /// Construct an error of the core protocol from its fields.
+    ///
+    /// This is mostly useful for testing code that handles errors. No extensions are known, so
+    /// `error_code` is interpreted as a core protocol error and `major_opcode` as a core request.
+    /// The wire format of the error can be obtained by converting it into a `[u8; 32]`:
+    /// ```
+    /// use x11rb_protocol::protocol::{xproto, ErrorKind};
+    /// use x11rb_protocol::x11_utils::X11Error;
+    ///
+    /// let error = X11Error::from_code(xproto::WINDOW_ERROR, 42, 0x1234, xproto::MAP_WINDOW_REQUEST, 0);
+    /// assert_eq!(error.error_kind, ErrorKind::Window);
+    /// assert_eq!(error.request_name, Some("MapWindow"));
+    /// let packet = <[u8; 32]>::from(&error);
+    /// assert_eq!(packet[..2], [0, xproto::WINDOW_ERROR]);
+    /// ```
+    pub fn from_code(
+        error_code: u8,
+        sequence: u16,
+        bad_value: u32,
+        major_opcode: u8,
+        minor_opcode: u16,
+    ) -> Self {
+        X11Error {
+            error_kind: ErrorKind::from_wire_error_code(error_code, &NoExtensions),
+            error_code,
+            sequence,
+            bad_value,
+            minor_opcode,
+            major_opcode,
+            extension_name: None,
+            request_name: request_name(None, major_opcode, minor_opcode),
+        }
+    }
+}
+
+impl core::fmt::Display for X11Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.error_kind {
+            // This already contains the error code
+            ErrorKind::Unknown(_) => write!(f, "{} in ", self.error_kind)?,
+            _ => write!(
+                f,
+                "{} (error code {}) in ",
+                self.error_kind, self.error_code
+            )?,
+        }
+        match (&self.extension_name, self.request_name) {
+            (Some(extension), Some(request)) => write!(f, "{}::{}", extension, request)?,
+            (None, Some(request)) => f.write_str(request)?,
+            (_, None) => f.write_str("unknown request")?,
+        }
+        write!(
+            f,
+            " (opcode {}.{}), sequence number {}, bad value {:#x}",
+            self.major_opcode, self.minor_opcode, self.sequence, self.bad_value
+        )
+    }
+}
+
+/// An [`ExtInfoProvider`] that does not know any extensions.
+struct NoExtensions;
+
+impl ExtInfoProvider for NoExtensions {
+    fn get_from_major_opcode(&self, _major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+    fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+/// A borrowed X11 event or error that was not parsed yet.
+///
+/// This allows to look at the header of a packet without copying it, e.g. to decide whether the
+/// packet needs to be kept. The length of the packet is checked when it is created from a slice:
+/// Events and errors are 32 bytes long, except for generic events, which contain a length field.
+/// More data than needed is not an error; [`GenericEventRef::raw_bytes`] only returns the packet
+/// itself.
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use x11rb_protocol::protocol::xproto::MAP_NOTIFY_EVENT;
+/// use x11rb_protocol::x11_utils::GenericEventRef;
+///
+/// let mut data = [0; 40];
+/// data[0] = MAP_NOTIFY_EVENT | 0x80;
+/// data[2] = 42;
+/// let event = GenericEventRef::try_from(&data[..])?;
+/// assert_eq!(event.response_type(), MAP_NOTIFY_EVENT);
+/// assert!(event.sent_event());
+/// assert_eq!(event.wire_sequence_number(), Some(u16::from_ne_bytes([42, 0])));
+/// assert_eq!(event.raw_bytes().len(), 32);
+/// # Ok::<(), x11rb_protocol::errors::ParseError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenericEventRef<'a>(&'a [u8]);
+
+impl<'a> GenericEventRef<'a> {
+    /// Get the raw bytes of this packet.
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Get the raw response type of this packet, including the `SendEvent` bit.
+    pub fn raw_response_type(&self) -> u8 {
+        self.0[0]
+    }
+
+    /// Get the response type of this packet.
+    ///
+    /// The response type of errors is zero.
+    pub fn response_type(&self) -> u8 {
+        self.raw_response_type() & 0x7f
+    }
+
+    /// Was this event generated by the X11 server?
+    pub fn server_generated(&self) -> bool {
+        self.raw_response_type() & 0x80 == 0
+    }
+
+    /// Was this event generated by another X11 client via the `SendEvent` request?
+    pub fn sent_event(&self) -> bool {
+        self.raw_response_type() & 0x80 != 0
+    }
+
+    /// Get the sequence number contained in this packet.
+    ///
+    /// `KeymapNotify` events do not contain a sequence number.
+    pub fn wire_sequence_number(&self) -> Option<u16> {
+        if self.response_type() == crate::protocol::xproto::KEYMAP_NOTIFY_EVENT {
+            None
+        } else {
+            Some(u16::from_ne_bytes([self.0[2], self.0[3]]))
+        }
+    }
+
+    /// Parse this packet into an event.
+    ///
+    /// This copies the data of the packet.
+    pub fn parse(
+        &self,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<crate::protocol::Event, ParseError> {
+        crate::protocol::Event::parse(self.0, ext_info_provider)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for GenericEventRef<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        let &response_type = value.first().ok_or(ParseError::DataTooShort {
+            needed: 1,
+            got: value.len(),
+        })?;
+        let length = if response_type & 0x7f == crate::protocol::xproto::GE_GENERIC_EVENT {
+            validate_reply_length(value)?
+        } else {
+            32
+        };
+        value
+            .get(..length)
+            .map(GenericEventRef)
+            .ok_or(ParseError::DataTooShort {
+                needed: length,
+                got: value.len(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod generic_event_ref_test {
+    use super::{GenericEventRef, NoExtensions, ParseError};
+    use crate::protocol::xproto::{GE_GENERIC_EVENT, KEYMAP_NOTIFY_EVENT};
+    use crate::protocol::Event;
+    use alloc::vec;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn error_packet() {
+        let mut data = [0; 32];
+        data[1] = 3;
+        data[2..4].copy_from_slice(&0x1234u16.to_ne_bytes());
+        let packet = GenericEventRef::try_from(&data[..]).unwrap();
+        assert_eq!(packet.response_type(), 0);
+        assert!(packet.server_generated());
+        assert_eq!(packet.wire_sequence_number(), Some(0x1234));
+        assert!(matches!(packet.parse(&NoExtensions), Ok(Event::Error(_))));
+        assert_eq!(
+            GenericEventRef::try_from(&data[..31]),
+            Err(ParseError::DataTooShort {
+                needed: 32,
+                got: 31
+            })
+        );
+        assert_eq!(
+            GenericEventRef::try_from(&[][..]),
+            Err(ParseError::DataTooShort { needed: 1, got: 0 })
+        );
+    }
+
+    #[test]
+    fn keymap_notify() {
+        let mut data = [0; 32];
+        data[0] = KEYMAP_NOTIFY_EVENT;
+        let packet = GenericEventRef::try_from(&data[..]).unwrap();
+        assert_eq!(packet.wire_sequence_number(), None);
+        let event = packet.parse(&NoExtensions).unwrap();
+        assert_eq!(event.wire_sequence_number(), None);
+    }
+
+    #[test]
+    fn generic_event_length() {
+        let mut data = vec![0; 40];
+        data[0] = GE_GENERIC_EVENT;
+        data[4..8].copy_from_slice(&2u32.to_ne_bytes());
+        assert_eq!(
+            GenericEventRef::try_from(&data[..39]),
+            Err(ParseError::DataTooShort {
+                needed: 40,
+                got: 39
+            })
+        );
+        data.extend([0; 8]);
+        let packet = GenericEventRef::try_from(&data[..]).unwrap();
+        assert_eq!(packet.raw_bytes().len(), 40);
+    }
 }
 
 #[cfg(test)]
 mod tryparse_x11error_test {
     use super::{ErrorKind, ExtInfoProvider, ParseError, X11Error};
     use crate::x11_utils::ExtensionInformation;
+    use alloc::string::ToString;
 
     struct Provider;
 
@@ -109,10 +343,37 @@ mod tryparse_x11error_test {
         assert_eq!(error, Ok(expected));
     }
 
+    #[test]
+    fn from_code_round_trip() {
+        let error = X11Error::from_code(3, 0x1234, 0xdead_beef, 10, 7);
+        assert_eq!(error.error_kind, ErrorKind::Window);
+        assert_eq!(error.request_name, Some("UnmapWindow"));
+        let packet = <[u8; 32]>::from(&error);
+        assert_eq!(X11Error::try_parse(&packet, &Provider), Ok(error));
+    }
+
+    #[test]
+    fn display() {
+        let error = X11Error::from_code(3, 0x1234, 0x2a, 8, 0);
+        assert_eq!(
+            error.to_string(),
+            "BadWindow (error code 3) in MapWindow (opcode 8.0), sequence number 4660, bad value 0x2a"
+        );
+        let error = X11Error {
+            extension_name: Some("RANDR".to_string()),
+            request_name: None,
+            ..X11Error::from_code(200, 1, 0, 140, 99)
+        };
+        assert_eq!(
+            error.to_string(),
+            "Unknown error 200 in unknown request (opcode 140.99), sequence number 1, bad value 0x0"
+        );
+    }
+
     #[test]
     fn reject_invalid_response_type() {
         let result = X11Error::try_parse(&[1; 32], &Provider);
-        assert_eq!(Err(ParseError::InvalidValue), result);
+        assert_eq!(Err(ParseError::UnexpectedValue { value: 1 }), result);
     }
 }
 
@@ -268,6 +529,12 @@ pub trait Request {
     /// The protocol name of the extension that this request belongs to, or None for core requests
     const EXTENSION_NAME: Option<&'static str>;
 
+    /// Whether the X11 server sends a reply to this request.
+    ///
+    /// This is `true` exactly for the requests that implement [`ReplyRequest`] or
+    /// [`ReplyFDsRequest`]. Requests without a reply implement [`VoidRequest`].
+    const HAS_REPLY: bool;
+
     /// Serialize this request into its X11 protocol wire representation.
     ///
     /// The argument is the major opcode of the extension that this request belongs to. For core
@@ -326,7 +593,10 @@ macro_rules! implement_try_parse {
                 let len = core::mem::size_of::<$t>();
                 let bytes = value
                     .get(..len)
-                    .ok_or(ParseError::InsufficientData)?
+                    .ok_or(ParseError::DataTooShort {
+                        needed: len,
+                        got: value.len(),
+                    })?
                     .try_into() // TryInto<[u8; len]>
                     .unwrap();
                 Ok((<$t>::from_ne_bytes(bytes), &value[len..]))
@@ -426,6 +696,54 @@ mod float_tests {
         }
     }
 
+    fn test_bit_round_trip<F>(bytes: &[u8])
+    where
+        F: TryParse + Serialize,
+        <F as Serialize>::Bytes: AsRef<[u8]>,
+    {
+        let (value, remaining) = F::try_parse(bytes).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(bytes, value.serialize().as_ref());
+
+        let mut output = alloc::vec::Vec::new();
+        value.serialize_into(&mut output);
+        assert_eq!(bytes, &output[..]);
+    }
+
+    #[test]
+    fn test_f32_bit_exact_round_trips() {
+        for &bits in &[
+            0x7fc0_0000u32, // quiet NaN
+            0xffc0_0000,    // negative quiet NaN
+            0x7f80_0001,    // signaling NaN
+            0x7fa1_2345,    // signaling NaN with payload
+            0x7fc1_2345,    // quiet NaN with payload
+            0x0000_0001,    // smallest subnormal
+            0x807f_ffff,    // largest negative subnormal
+            0x8000_0000,    // negative zero
+            0x7f80_0000,    // infinity
+        ] {
+            test_bit_round_trip::<f32>(&bits.to_ne_bytes());
+        }
+    }
+
+    #[test]
+    fn test_f64_bit_exact_round_trips() {
+        for &bits in &[
+            0x7ff8_0000_0000_0000u64, // quiet NaN
+            0xfff8_0000_0000_0000,    // negative quiet NaN
+            0x7ff0_0000_0000_0001,    // signaling NaN
+            0x7ff4_0123_4567_89ab,    // signaling NaN with payload
+            0x7ff8_0123_4567_89ab,    // quiet NaN with payload
+            0x0000_0000_0000_0001,    // smallest subnormal
+            0x800f_ffff_ffff_ffff,    // largest negative subnormal
+            0x8000_0000_0000_0000,    // negative zero
+            0x7ff0_0000_0000_0000,    // infinity
+        ] {
+            test_bit_round_trip::<f64>(&bits.to_ne_bytes());
+        }
+    }
+
     #[test]
     fn test_parse_known_value() {
         let bytes = 0x42280000u32.to_ne_bytes();
@@ -538,30 +856,169 @@ tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13 O:14);
 ///
 /// This function parses a list of objects where the length of the list was specified externally.
 /// The wire format for `list_length` instances of `T` will be read from the given data.
-pub(crate) fn parse_list<T>(data: &[u8], list_length: usize) -> Result<(Vec<T>, &[u8]), ParseError>
+/// `field_name` is the name of the field that contains the list. It is only used for
+/// `parse_context`.
+pub(crate) fn parse_list<'a, T>(
+    data: &'a [u8],
+    list_length: usize,
+    _field_name: Option<&'static str>,
+) -> Result<(Vec<T>, &'a [u8]), ParseError>
 where
     T: TryParse,
 {
     let mut remaining = data;
     let mut result = Vec::with_capacity(list_length);
-    for _ in 0..list_length {
-        let (entry, new_remaining) = T::try_parse(remaining)?;
+    for _index in 0..list_length {
+        let (entry, new_remaining) = match T::try_parse(remaining) {
+            Ok(value) => value,
+            Err(err) => {
+                #[cfg(feature = "parse_context")]
+                crate::parse_context::push_list_entry::<T>(_field_name, _index);
+                return Err(err);
+            }
+        };
         result.push(entry);
         remaining = new_remaining;
     }
     Ok((result, remaining))
 }
 
+/// Record that parsing the field `field_name` of a structure failed.
+///
+/// This is only used for `parse_context` and returns `result` unchanged.
+#[inline]
+pub(crate) fn in_field<T>(
+    result: Result<T, ParseError>,
+    _field_name: &'static str,
+) -> Result<T, ParseError> {
+    #[cfg(feature = "parse_context")]
+    if result.is_err() {
+        crate::parse_context::push_field(_field_name);
+    }
+    result
+}
+
+/// An iterator that lazily parses a list of objects.
+///
+/// Instead of parsing all entries of a list into a `Vec` up front, this iterator parses the
+/// wire format of one entry at a time when it is requested. Once parsing an entry fails, the
+/// error is returned and the iteration ends. Thus, the length of the list is only an upper bound
+/// for the number of items.
+#[derive(Debug, Clone)]
+pub struct LazyList<'a, T> {
+    data: &'a [u8],
+    remaining_length: usize,
+    phantom: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, T: TryParse> LazyList<'a, T> {
+    /// Create a new iterator over `list_length` instances of `T` contained in `data`.
+    pub fn new(data: &'a [u8], list_length: usize) -> Self {
+        Self {
+            data,
+            remaining_length: list_length,
+            phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: TryParse> Iterator for LazyList<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_length == 0 {
+            return None;
+        }
+        match T::try_parse(self.data) {
+            Ok((entry, remaining)) => {
+                self.data = remaining;
+                self.remaining_length -= 1;
+                Some(Ok(entry))
+            }
+            Err(err) => {
+                self.remaining_length = 0;
+                Some(Err(err))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A parse error ends the iteration early, so only one more item is guaranteed
+        (self.remaining_length.min(1), Some(self.remaining_length))
+    }
+}
+
+impl<T: TryParse> core::iter::FusedIterator for LazyList<'_, T> {}
+
+#[cfg(test)]
+mod lazy_list_test {
+    use super::{LazyList, ParseError};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn parse_all() {
+        let data = [1, 0, 2, 0, 3, 0, 42];
+        let list = LazyList::<u16>::new(&data, 3);
+        assert_eq!(list.size_hint(), (1, Some(3)));
+        assert_eq!(
+            list.collect::<Result<Vec<_>, _>>(),
+            Ok(alloc::vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn insufficient_data() {
+        let data = [1, 0, 2];
+        let mut list = LazyList::<u16>::new(&data, 2);
+        assert_eq!(list.size_hint(), (1, Some(2)));
+        assert_eq!(list.next(), Some(Ok(1)));
+        assert_eq!(
+            list.next(),
+            Some(Err(ParseError::DataTooShort { needed: 2, got: 1 }))
+        );
+        assert_eq!(list.size_hint(), (0, Some(0)));
+        assert_eq!(list.next(), None);
+    }
+}
+
 /// Parse a list of `u8` from the given data.
+///
+/// Returns the first `list_length` bytes of `data` and the remaining data, or
+/// [`ParseError::DataTooShort`] if `data` is too short. Use `.to_vec()` on the result to get
+/// an owned copy of the list.
 #[inline]
-pub(crate) fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &[u8]), ParseError> {
+pub fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &[u8]), ParseError> {
     if data.len() < list_length {
-        Err(ParseError::InsufficientData)
+        Err(ParseError::DataTooShort {
+            needed: list_length,
+            got: data.len(),
+        })
     } else {
         Ok(data.split_at(list_length))
     }
 }
 
+/// Parse a UTF-8 string of `length` bytes from the given data.
+///
+/// Returns [`ParseError::InsufficientData`] if `data` is too short and
+/// [`ParseError::InvalidValue`] if the string is not valid UTF-8.
+pub fn parse_utf8(data: &[u8], length: usize) -> Result<(&str, &[u8]), ParseError> {
+    let (bytes, remaining) = parse_u8_list(data, length)?;
+    let string = core::str::from_utf8(bytes).map_err(|_| ParseError::InvalidValue)?;
+    Ok((string, remaining))
+}
+
+/// Parse a Latin-1 (ISO 8859-1) string of `length` bytes from the given data.
+///
+/// Many strings in the X11 protocol, e.g. atom names, are Latin-1 encoded. Every byte is a valid
+/// Latin-1 character, so this only fails with [`ParseError::InsufficientData`] if `data` is too
+/// short.
+pub fn parse_latin1(data: &[u8], length: usize) -> Result<(String, &[u8]), ParseError> {
+    let (bytes, remaining) = parse_u8_list(data, length)?;
+    let string = bytes.iter().copied().map(char::from).collect();
+    Ok((string, remaining))
+}
+
 /// Parse an array of `u8` from the given data.
 #[inline]
 pub(crate) fn parse_u8_array_ref<const N: usize>(
@@ -581,6 +1038,85 @@ pub(crate) fn parse_u8_array<const N: usize>(data: &[u8]) -> Result<([u8; N], &[
     Ok((*array, remaining))
 }
 
+/// Skip over padding bytes in the given data.
+///
+/// With the `strict_padding` feature, [`ParseError::NonZeroPadding`] is returned if one of the
+/// padding bytes is not zero.
+#[inline]
+pub(crate) fn parse_padding(data: &[u8], length: usize) -> Result<&[u8], ParseError> {
+    let (padding, remaining) = parse_u8_list(data, length)?;
+    if cfg!(feature = "strict_padding") && padding.iter().any(|&byte| byte != 0) {
+        return Err(ParseError::NonZeroPadding);
+    }
+    Ok(remaining)
+}
+
+/// Get the length in bytes of the reply at the beginning of `buffer`.
+///
+/// A reply (and also a `GE_GENERIC_EVENT`) consists of 32 bytes and additional data whose length
+/// in four byte units is given by the length field at offset 4. [`ParseError::DataTooShort`]
+/// is returned if `buffer` is shorter than the reply. [`ParseError::ConversionFailed`] is
+/// returned if the length of the reply does not fit into an `usize`.
+pub fn validate_reply_length(buffer: &[u8]) -> Result<usize, ParseError> {
+    let (length, _) = u32::try_parse(buffer.get(4..).unwrap_or(&[]))?;
+    let length = length
+        .try_to_usize()?
+        .checked_mul(4)
+        .and_then(|length| length.checked_add(32))
+        .ok_or(ParseError::ConversionFailed)?;
+    if buffer.len() < length {
+        return Err(ParseError::DataTooShort {
+            needed: length,
+            got: buffer.len(),
+        });
+    }
+    Ok(length)
+}
+
+impl<T: TryParse, const N: usize> TryParse for [T; N] {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (list, remaining) = parse_list::<T>(value, N, None)?;
+        let array = list
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("parse_list() returns exactly N elements"));
+        Ok((array, remaining))
+    }
+}
+
+#[cfg(test)]
+mod array_test {
+    use super::{ParseError, TryParse};
+    use crate::protocol::xproto::Point;
+
+    #[test]
+    fn parse_empty() {
+        let data = [1, 2, 3];
+        assert_eq!(<[u32; 0]>::try_parse(&data), Ok(([], &data[..])));
+        assert_eq!(<[u32; 0]>::try_parse(&[]), Ok(([], &[][..])));
+    }
+
+    #[test]
+    fn parse_partial() {
+        let data = [1, 0, 2, 0, 3];
+        assert_eq!(<[u16; 2]>::try_parse(&data), Ok(([1, 2], &[3][..])));
+        assert_eq!(
+            <[u16; 3]>::try_parse(&data),
+            Err(ParseError::DataTooShort { needed: 2, got: 1 })
+        );
+    }
+
+    #[test]
+    fn parse_struct() {
+        let data = [1, 0, 2, 0, 0xff, 0xff, 4, 0, 42];
+        let points = [Point { x: 1, y: 2 }, Point { x: -1, y: 4 }];
+        assert_eq!(<[Point; 2]>::try_parse(&data), Ok((points, &[42][..])));
+        assert_eq!(
+            <[Point; 2]>::try_parse(&data[..7]),
+            Err(ParseError::DataTooShort { needed: 2, got: 1 })
+        );
+    }
+}
+
 impl<T: Serialize> Serialize for [T] {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
@@ -595,10 +1131,228 @@ impl<T: Serialize> Serialize for [T] {
     }
 }
 
+impl<T: Serialize + ?Sized> Serialize for &T {
+    type Bytes = T::Bytes;
+    fn serialize(&self) -> Self::Bytes {
+        (**self).serialize()
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        (**self).serialize_into(bytes)
+    }
+}
+
+#[cfg(test)]
+mod padding_test {
+    use super::{parse_padding, ParseError};
+
+    #[test]
+    fn parse_zero_padding() {
+        let data = [0, 0, 0, 42];
+        assert_eq!(parse_padding(&data, 3), Ok(&[42][..]));
+        assert_eq!(
+            parse_padding(&data, 5),
+            Err(ParseError::DataTooShort { needed: 5, got: 4 })
+        );
+    }
+
+    #[test]
+    fn parse_non_zero_padding() {
+        let data = [0, 1, 0, 42];
+        let expected = if cfg!(feature = "strict_padding") {
+            Err(ParseError::NonZeroPadding)
+        } else {
+            Ok(&[42][..])
+        };
+        assert_eq!(parse_padding(&data, 3), expected);
+    }
+}
+
+#[cfg(test)]
+mod string_test {
+    use super::{parse_latin1, parse_u8_list, parse_utf8, ParseError};
+
+    #[test]
+    fn u8_list() {
+        let data = [1, 2, 3];
+        assert_eq!(parse_u8_list(&data, 2), Ok((&[1, 2][..], &[3][..])));
+        assert_eq!(parse_u8_list(&data, 3), Ok((&data[..], &[][..])));
+        assert_eq!(
+            parse_u8_list(&data, 4),
+            Err(ParseError::DataTooShort { needed: 4, got: 3 })
+        );
+        assert_eq!(
+            parse_u8_list(&data, usize::MAX),
+            Err(ParseError::DataTooShort {
+                needed: usize::MAX,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn utf8() {
+        let data = "größe".as_bytes();
+        assert_eq!(parse_utf8(data, 2), Ok(("gr", &data[2..])));
+        assert_eq!(parse_utf8(data, 7), Ok(("größe", &[][..])));
+        // The length ends in the middle of a character
+        assert_eq!(parse_utf8(data, 3), Err(ParseError::InvalidValue));
+        assert_eq!(
+            parse_utf8(data, 8),
+            Err(ParseError::DataTooShort { needed: 8, got: 7 })
+        );
+    }
+
+    #[test]
+    fn latin1() {
+        let data = b"gr\xf6\xdfe!";
+        assert_eq!(parse_latin1(data, 5), Ok(("größe".into(), &b"!"[..])));
+        assert_eq!(parse_latin1(data, 0), Ok(("".into(), &data[..])));
+        assert_eq!(
+            parse_latin1(data, 7),
+            Err(ParseError::DataTooShort { needed: 7, got: 6 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod reply_length_test {
+    use super::{validate_reply_length, ParseError, TryParse};
+    use crate::protocol::xproto::GetAtomNameReply;
+
+    fn reply(length: u32, size: usize) -> alloc::vec::Vec<u8> {
+        let mut reply = alloc::vec![0; size];
+        reply[0] = 1;
+        reply[4..8].copy_from_slice(&length.to_ne_bytes());
+        reply
+    }
+
+    #[test]
+    fn exact_length() {
+        assert_eq!(validate_reply_length(&reply(0, 32)), Ok(32));
+        assert_eq!(validate_reply_length(&reply(2, 40)), Ok(40));
+        // Additional data is not part of the reply
+        assert_eq!(validate_reply_length(&reply(2, 48)), Ok(40));
+    }
+
+    #[test]
+    fn truncated() {
+        assert_eq!(
+            validate_reply_length(&reply(2, 39)),
+            Err(ParseError::DataTooShort {
+                needed: 40,
+                got: 39
+            })
+        );
+        assert_eq!(
+            validate_reply_length(&[1, 0, 0, 0, 0, 0]),
+            Err(ParseError::DataTooShort { needed: 4, got: 2 })
+        );
+    }
+
+    #[test]
+    fn huge_length() {
+        let expected = if usize::BITS < 64 {
+            Err(ParseError::ConversionFailed)
+        } else {
+            Err(ParseError::DataTooShort {
+                needed: (u64::from(u32::MAX) * 4 + 32) as usize,
+                got: 32,
+            })
+        };
+        assert_eq!(validate_reply_length(&reply(u32::MAX, 32)), expected);
+    }
+
+    #[test]
+    fn reply_parser() {
+        let mut data = reply(1, 36);
+        data[8..10].copy_from_slice(&3u16.to_ne_bytes());
+        data[32..35].copy_from_slice(b"foo");
+        let (parsed, remaining) = GetAtomNameReply::try_parse(&data).unwrap();
+        assert_eq!(parsed.name, b"foo");
+        assert!(remaining.is_empty());
+
+        data[4..8].copy_from_slice(&u32::MAX.to_ne_bytes());
+        assert!(GetAtomNameReply::try_parse(&data).is_err());
+    }
+}
+
+#[cfg(test)]
+mod request_has_reply_test {
+    use super::Request;
+    use crate::protocol::xproto::{GetInputFocusRequest, MapWindowRequest};
+
+    fn has_reply<R: Request>(_request: &R) -> bool {
+        R::HAS_REPLY
+    }
+
+    #[test]
+    fn has_reply_matches_request() {
+        assert!(has_reply(&GetInputFocusRequest));
+        assert!(!has_reply(&MapWindowRequest { window: 1 }));
+    }
+}
+
+#[cfg(test)]
+mod reference_serialize_test {
+    use super::Serialize;
+    use alloc::vec::Vec;
+
+    fn serialize_owned<T: Serialize>(value: T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize_into(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn serialize_reference() {
+        let value = 0x1234_5678u32;
+        let reference: &u32 = &value;
+        assert_eq!(serialize_owned(reference), value.serialize());
+
+        let list: &[u16] = &[1, 2];
+        assert_eq!(serialize_owned(list), list.serialize());
+    }
+}
+
+/// An iterator over the flags that are set in a bitmask.
+///
+/// This is created by the `iter()` method of bitmask types, for example
+/// [`EventMask::iter`](crate::protocol::xproto::EventMask::iter). Bits that do not belong to
+/// any known flag are skipped.
+#[derive(Debug, Clone)]
+pub struct BitmaskIter<T: 'static> {
+    pub(crate) mask: T,
+    pub(crate) flags: core::slice::Iter<'static, T>,
+}
+
+impl<T> Iterator for BitmaskIter<T>
+where
+    T: Copy + PartialEq + core::ops::BitAnd<Output = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mask = self.mask;
+        self.flags
+            .by_ref()
+            .copied()
+            .find(|&flag| mask & flag == flag)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.flags.size_hint().1)
+    }
+}
+
+impl<T> core::iter::FusedIterator for BitmaskIter<T> where
+    T: Copy + PartialEq + core::ops::BitAnd<Output = T>
+{
+}
+
 // This macro is used by the generated code to implement e.g. `std::ops::BitOr` and
-// `std::ops::BitOrAssign`.
+// `std::ops::BitOrAssign`. The list contains the flags that `iter()` looks for.
 macro_rules! bitmask_binop {
-    ($t:ty, $u:ty) => {
+    ($t:ty, $u:ty, [$($flag:ident),*]) => {
         impl core::ops::BitOr for $t {
             type Output = $t;
             fn bitor(self, other: Self) -> Self::Output {
@@ -681,6 +1435,25 @@ macro_rules! bitmask_binop {
                 let flag = flag.into();
                 (<$u>::from(self) & flag) != 0
             }
+
+            /// Iterate over the flags that are set in this object.
+            ///
+            /// Each flag is one of the enum variants. Bits that do not correspond to a variant
+            /// are skipped.
+            pub fn iter(self) -> crate::x11_utils::BitmaskIter<$t> {
+                const FLAGS: &[$t] = &[$(<$t>::$flag),*];
+                crate::x11_utils::BitmaskIter {
+                    mask: self,
+                    flags: FLAGS.iter(),
+                }
+            }
+        }
+        impl IntoIterator for $t {
+            type Item = $t;
+            type IntoIter = crate::x11_utils::BitmaskIter<$t>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
         }
     };
 }
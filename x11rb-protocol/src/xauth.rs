@@ -394,4 +394,102 @@ mod test {
     fn protocol_mismatch() {
         expect_mismatch(|entry| entry.name = b"XDM-AUTHORIZATION-1".to_vec());
     }
+
+    #[test]
+    fn first_matching_entry_wins() {
+        let entry = |family, address: &[u8], number: &[u8], name: &[u8], data: &[u8]| AuthEntry {
+            family,
+            address: address.to_vec(),
+            number: number.to_vec(),
+            name: name.to_vec(),
+            data: data.to_vec(),
+        };
+        let entries = [
+            entry(
+                Family::INTERNET,
+                &[10, 0, 0, 1],
+                b"0",
+                MIT_MAGIC_COOKIE_1,
+                b"a",
+            ),
+            entry(
+                Family::INTERNET,
+                &[10, 0, 0, 2],
+                b"0",
+                b"XDM-AUTHORIZATION-1",
+                b"b",
+            ),
+            entry(
+                Family::INTERNET,
+                &[10, 0, 0, 2],
+                b"1",
+                MIT_MAGIC_COOKIE_1,
+                b"c",
+            ),
+            entry(
+                Family::INTERNET,
+                &[10, 0, 0, 2],
+                b"0",
+                MIT_MAGIC_COOKIE_1,
+                b"d",
+            ),
+            entry(Family::WILD, &[], b"", MIT_MAGIC_COOKIE_1, b"e"),
+        ];
+        let auth = |address: &[u8], display| {
+            get_auth_impl(
+                entries.iter().cloned().map(Ok),
+                Family::INTERNET,
+                address,
+                display,
+            )
+            .unwrap()
+            .map(|(_, data)| data)
+        };
+        assert_eq!(auth(&[10, 0, 0, 1], 0), Some(b"a".to_vec()));
+        assert_eq!(auth(&[10, 0, 0, 2], 0), Some(b"d".to_vec()));
+        assert_eq!(auth(&[10, 0, 0, 2], 1), Some(b"c".to_vec()));
+        assert_eq!(auth(&[10, 0, 0, 3], 0), Some(b"e".to_vec()));
+    }
+
+    #[test]
+    fn read_errors_are_returned() {
+        use std::io::{Error, ErrorKind};
+
+        let entries = core::iter::once(Err(Error::new(ErrorKind::UnexpectedEof, "truncated")));
+        let result = get_auth_impl(entries, Family::LOCAL, b"whatever", 42);
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    // This is the only test that modifies `XAUTHORITY`, so it does not race with other tests.
+    #[test]
+    fn get_auth_from_file() {
+        use super::get_auth;
+
+        // Data generated via xauth -f /tmp/file add :1 MIT-MAGIC-COOKIE-1 deadbeef
+        let mut data = vec![0x01, 0x00, 0x00, 0x07];
+        data.extend_from_slice(b"ZweiLED");
+        data.extend_from_slice(&[0x00, 0x01, b'1', 0x00, 0x12]);
+        data.extend_from_slice(MIT_MAGIC_COOKIE_1);
+        data.extend_from_slice(&[0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]);
+        // A truncated second entry
+        data.extend_from_slice(&[0x01, 0x00, 0x00]);
+
+        let path =
+            std::env::temp_dir().join(alloc::format!("x11rb-xauth-test-{}", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        std::env::set_var("XAUTHORITY", &path);
+
+        let auth = get_auth(Family::LOCAL, b"ZweiLED", 1).unwrap();
+        assert_eq!(
+            auth,
+            Some((MIT_MAGIC_COOKIE_1.to_vec(), vec![0xde, 0xad, 0xbe, 0xef]))
+        );
+        // Looking for another display reaches the truncated entry
+        assert!(get_auth(Family::LOCAL, b"ZweiLED", 2).is_err());
+
+        // A missing file is an error, too
+        std::fs::remove_file(&path).unwrap();
+        assert!(get_auth(Family::LOCAL, b"ZweiLED", 1).is_err());
+        std::env::remove_var("XAUTHORITY");
+    }
 }
@@ -105,3 +105,21 @@ fn test_intersects() {
     assert!(!mask.intersects(16u32));
     assert!(mask.intersects(20u32));
 }
+
+#[test]
+fn test_iter() {
+    let mask = EventMask::EXPOSURE | EventMask::KEY_PRESS;
+    let flags = mask.iter().collect::<Vec<_>>();
+    assert_eq!(flags, [EventMask::KEY_PRESS, EventMask::EXPOSURE]);
+    assert_eq!(mask.into_iter().count(), 2);
+
+    assert_eq!(EventMask::NO_EVENT.iter().next(), None);
+
+    // Bits without a variant are skipped
+    let mask = EventMask::from(1u32 << 31) | EventMask::BUTTON_PRESS;
+    let mut flags = Vec::new();
+    for flag in mask {
+        flags.push(flag);
+    }
+    assert_eq!(flags, [EventMask::BUTTON_PRESS]);
+}
@@ -0,0 +1,87 @@
+// Tests for passing file descriptors along with requests.
+#![cfg(all(unix, feature = "std"))]
+
+use std::io::{ErrorKind, Read};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixStream;
+
+use x11rb_protocol::RawFdContainer;
+
+/// Check whether the other end of a socket pair was closed.
+fn peer_closed(stream: &mut UnixStream) -> bool {
+    stream.set_nonblocking(true).unwrap();
+    match stream.read(&mut [0]) {
+        Ok(0) => true,
+        Err(err) if err.kind() == ErrorKind::WouldBlock => false,
+        result => panic!("Unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn drop_closes_fd() {
+    let (first, mut second) = UnixStream::pair().unwrap();
+    let container = RawFdContainer::from(first);
+    assert!(!peer_closed(&mut second));
+    drop(container);
+    assert!(peer_closed(&mut second));
+}
+
+#[test]
+fn into_raw_fd_keeps_fd_open() {
+    let (first, mut second) = UnixStream::pair().unwrap();
+    let container = RawFdContainer::from(first);
+    let fd = container.as_raw_fd();
+    assert_eq!(container.into_raw_fd(), fd);
+    assert!(!peer_closed(&mut second));
+
+    // Give the FD a new owner so that it is closed again
+    RawFdContainer::new(fd).close().unwrap();
+    assert!(peer_closed(&mut second));
+}
+
+#[cfg(feature = "dri3")]
+#[test]
+fn pixmap_from_buffer_round_trip() {
+    use x11rb_protocol::protocol::dri3::{PixmapFromBufferRequest, PIXMAP_FROM_BUFFER_REQUEST};
+    use x11rb_protocol::x11_utils::RequestHeader;
+
+    let (first, mut second) = UnixStream::pair().unwrap();
+    let fd = first.as_raw_fd();
+    let request = PixmapFromBufferRequest {
+        pixmap: 1,
+        drawable: 2,
+        size: 64 * 64 * 4,
+        width: 64,
+        height: 64,
+        stride: 64 * 4,
+        depth: 24,
+        bpp: 32,
+        pixmap_fd: first.into(),
+    };
+    let (bufs, mut fds) = request.serialize(42);
+    let bytes = bufs.concat();
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(bytes[..2], [42, PIXMAP_FROM_BUFFER_REQUEST]);
+    assert_eq!(bytes[2..4], 6u16.to_ne_bytes());
+    // The FD is not part of the bytes, but passed on its own
+    assert_eq!(fds.len(), 1);
+    assert_eq!(fds[0].as_raw_fd(), fd);
+
+    let header = RequestHeader {
+        major_opcode: 42,
+        minor_opcode: PIXMAP_FROM_BUFFER_REQUEST,
+        remaining_length: 5,
+    };
+    let parsed =
+        PixmapFromBufferRequest::try_parse_request_fd(header, &bytes[4..], &mut fds).unwrap();
+    assert!(fds.is_empty());
+    assert_eq!((parsed.width, parsed.stride, parsed.bpp), (64, 256, 32));
+    assert_eq!(parsed.pixmap_fd.as_raw_fd(), fd);
+    assert!(!peer_closed(&mut second));
+    drop(parsed);
+    assert!(peer_closed(&mut second));
+
+    // Without the FD, the request cannot be parsed
+    let result = PixmapFromBufferRequest::try_parse_request_fd(header, &bytes[4..], &mut fds);
+    assert!(result.is_err());
+}
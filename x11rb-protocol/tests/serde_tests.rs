@@ -0,0 +1,57 @@
+#![cfg(feature = "serde")]
+
+use x11rb_protocol::protocol::xproto::{EventMask, MapNotifyEvent, MAP_NOTIFY_EVENT};
+
+#[test]
+fn test_event_round_trip() {
+    let event = MapNotifyEvent {
+        response_type: MAP_NOTIFY_EVENT,
+        sequence: 42,
+        event: 1,
+        window: 2,
+        override_redirect: true,
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MapNotifyEvent>(&json).unwrap(),
+        event
+    );
+}
+
+#[test]
+fn test_bitmask_is_transparent() {
+    let mask = EventMask::KEY_PRESS | EventMask::EXPOSURE;
+    let json = serde_json::to_string(&mask).unwrap();
+    assert_eq!(json, u32::from(mask).to_string());
+    assert_eq!(serde_json::from_str::<EventMask>(&json).unwrap(), mask);
+}
+
+#[cfg(feature = "xinerama")]
+#[test]
+fn test_xinerama_query_screens_reply_round_trip() {
+    use x11rb_protocol::protocol::xinerama::{QueryScreensReply, ScreenInfo};
+
+    let reply = QueryScreensReply {
+        sequence: 7,
+        length: 4,
+        screen_info: vec![
+            ScreenInfo {
+                x_org: 0,
+                y_org: 0,
+                width: 1920,
+                height: 1080,
+            },
+            ScreenInfo {
+                x_org: 1920,
+                y_org: -200,
+                width: 1280,
+                height: 1024,
+            },
+        ],
+    };
+    let json = serde_json::to_string(&reply).unwrap();
+    assert_eq!(
+        serde_json::from_str::<QueryScreensReply>(&json).unwrap(),
+        reply
+    );
+}
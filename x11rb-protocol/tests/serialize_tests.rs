@@ -0,0 +1,242 @@
+// `Serialize` produces native endian data. The expected byte sequences below are written down in
+// little endian, so these tests only run there.
+#![cfg(target_endian = "little")]
+
+use std::borrow::Cow;
+
+use x11rb_protocol::protocol::xproto::{
+    ChangePropertyRequest, ChangeWindowAttributesAux, ChangeWindowAttributesRequest, Charinfo,
+    ConfigureNotifyEvent, ConfigureWindowAux, ConfigureWindowRequest, CreateWindowAux,
+    CreateWindowRequest, EventMask, Family, Format, GetGeometryReply, Gravity, Host, ImageFormat,
+    InternAtomRequest, PropMode, PutImageRequest, Rectangle, StackMode, WindowClass,
+    CONFIGURE_NOTIFY_EVENT,
+};
+use x11rb_protocol::x11_utils::Serialize;
+
+#[test]
+fn test_rectangle() {
+    let rect = Rectangle {
+        x: 1,
+        y: -2,
+        width: 0x0304,
+        height: 0x0506,
+    };
+    assert_eq!(rect.serialize(), [1, 0, 0xfe, 0xff, 4, 3, 6, 5]);
+}
+
+#[test]
+fn test_format_padding() {
+    let format = Format {
+        depth: 24,
+        bits_per_pixel: 32,
+        scanline_pad: 8,
+    };
+    assert_eq!(format.serialize(), [24, 32, 8, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_charinfo() {
+    let info = Charinfo {
+        left_side_bearing: -1,
+        right_side_bearing: 0x0102,
+        character_width: 0x0304,
+        ascent: 0x0506,
+        descent: 0x0708,
+        attributes: 0x090a,
+    };
+    assert_eq!(
+        info.serialize(),
+        [0xff, 0xff, 2, 1, 4, 3, 6, 5, 8, 7, 0x0a, 0x09]
+    );
+}
+
+#[test]
+fn test_host_with_list() {
+    let host = Host {
+        family: Family::INTERNET,
+        address: vec![127, 0, 0, 1, 42],
+    };
+    assert_eq!(host.serialize(), [0, 0, 5, 0, 127, 0, 0, 1, 42, 0, 0, 0]);
+}
+
+#[test]
+fn test_reply() {
+    let reply = GetGeometryReply {
+        depth: 24,
+        sequence: 0x0102,
+        length: 0,
+        root: 0x0304_0506,
+        x: -1,
+        y: 2,
+        width: 0x0708,
+        height: 0x090a,
+        border_width: 0x0b0c,
+    };
+    // The padding at the end of the reply is not part of the serialized form, so this is shorter
+    // than the 32 bytes that the X11 server sends.
+    let mut expected = vec![
+        1, 24, 2, 1, 0, 0, 0, 0, 6, 5, 4, 3, 0xff, 0xff, 2, 0, 8, 7, 0x0a, 0x09, 0x0c, 0x0b,
+    ];
+    expected.resize(24, 0);
+    assert_eq!(reply.serialize(), &expected[..]);
+}
+
+#[test]
+fn test_event() {
+    let event = ConfigureNotifyEvent {
+        response_type: CONFIGURE_NOTIFY_EVENT,
+        sequence: 0x0102,
+        event: 0x0304_0506,
+        window: 0x0708_090a,
+        above_sibling: 0,
+        x: -2,
+        y: 3,
+        width: 0x0405,
+        height: 0x0607,
+        border_width: 1,
+        override_redirect: true,
+    };
+    let mut expected = vec![
+        22, 0, 2, 1, 6, 5, 4, 3, 0x0a, 9, 8, 7, 0, 0, 0, 0, 0xfe, 0xff, 3, 0, 5, 4, 7, 6, 1, 0, 1,
+    ];
+    expected.resize(32, 0);
+    assert_eq!(<[u8; 32]>::from(event), &expected[..]);
+}
+
+#[test]
+fn test_request_with_list() {
+    let request = InternAtomRequest {
+        only_if_exists: true,
+        name: Cow::Borrowed(b"WM"),
+    };
+    let (bufs, fds) = request.serialize();
+    assert!(fds.is_empty());
+    assert_eq!(bufs.concat(), [16, 1, 3, 0, 2, 0, 0, 0, b'W', b'M', 0, 0]);
+}
+
+#[test]
+fn test_request_with_switch() {
+    let aux = ConfigureWindowAux::new().x(10).stack_mode(StackMode::BELOW);
+    let request = ConfigureWindowRequest {
+        window: 0x0102_0304,
+        value_list: Cow::Owned(aux),
+    };
+    let (bufs, _) = request.serialize();
+    assert_eq!(
+        bufs.concat(),
+        [12, 0, 5, 0, 4, 3, 2, 1, 0x41, 0, 0, 0, 10, 0, 0, 0, 1, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_window_attributes_in_bit_order() {
+    // The setters are called in the opposite order of the bits in the value mask
+    let aux = CreateWindowAux::new()
+        .event_mask(EventMask::EXPOSURE)
+        .win_gravity(Gravity::STATIC)
+        .background_pixel(0x0102_0304);
+    let request = CreateWindowRequest {
+        depth: 0,
+        wid: 1,
+        parent: 2,
+        x: 0,
+        y: 0,
+        width: 1,
+        height: 1,
+        border_width: 0,
+        class: WindowClass::INPUT_OUTPUT,
+        visual: 0,
+        value_list: Cow::Borrowed(&aux),
+    };
+    let (bufs, _) = request.serialize();
+    let expected_values = [
+        0x22, 8, 0, 0, // value mask: BackPixel, WinGravity, EventMask
+        4, 3, 2, 1, // background pixel
+        10, 0, 0, 0, // win gravity
+        0, 0x80, 0, 0, // event mask
+    ];
+    assert_eq!(bufs.concat()[28..], expected_values);
+
+    let aux = ChangeWindowAttributesAux::new()
+        .event_mask(EventMask::EXPOSURE)
+        .win_gravity(Gravity::STATIC)
+        .background_pixel(0x0102_0304);
+    let request = ChangeWindowAttributesRequest {
+        window: 1,
+        value_list: Cow::Borrowed(&aux),
+    };
+    let (bufs, _) = request.serialize();
+    assert_eq!(bufs.concat()[8..], expected_values);
+}
+
+#[test]
+fn test_serialize_into_reuses_buffer() {
+    let rects = [
+        Rectangle {
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 4,
+        },
+        Rectangle {
+            x: 5,
+            y: 6,
+            width: 7,
+            height: 8,
+        },
+    ];
+    let mut buffer = Vec::with_capacity(64);
+    for rect in &rects {
+        rect.serialize_into(&mut buffer);
+        0x1234_5678u32.serialize_into(&mut buffer);
+    }
+    let mut expected = Vec::new();
+    for rect in &rects {
+        expected.extend_from_slice(&rect.serialize());
+        expected.extend_from_slice(&0x1234_5678u32.serialize());
+    }
+    assert_eq!(buffer, expected);
+    assert_eq!(buffer.len(), 24);
+
+    // Serializing appends to the existing content
+    buffer.truncate(12);
+    rects[1].serialize_into(&mut buffer);
+    assert_eq!(buffer[12..], rects[1].serialize());
+}
+
+#[test]
+fn test_list_fields_stay_borrowed() {
+    let data = vec![42; 4097];
+    let request = ChangePropertyRequest {
+        mode: PropMode::REPLACE,
+        window: 1,
+        property: 2,
+        type_: 3,
+        format: 8,
+        data_len: 4097,
+        data: Cow::Borrowed(&data),
+    };
+    let (bufs, _) = request.serialize();
+    // The header, the data, and the padding are separate pieces
+    assert_eq!(bufs.len(), 3);
+    assert!(matches!(&bufs[1], Cow::Borrowed(slice) if slice.as_ptr() == data.as_ptr()));
+    assert_eq!(bufs[2].len(), 3);
+
+    let request = PutImageRequest {
+        format: ImageFormat::Z_PIXMAP,
+        drawable: 1,
+        gc: 2,
+        width: 32,
+        height: 32,
+        dst_x: 0,
+        dst_y: 0,
+        left_pad: 0,
+        depth: 32,
+        data: Cow::Borrowed(&data[..4096]),
+    };
+    let (bufs, _) = request.serialize();
+    assert!(bufs
+        .iter()
+        .any(|buf| matches!(buf, Cow::Borrowed(slice) if slice.as_ptr() == data.as_ptr())));
+    assert_eq!(bufs.iter().map(|buf| buf.len()).sum::<usize>(), 24 + 4096);
+}
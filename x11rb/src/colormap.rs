@@ -0,0 +1,152 @@
+//! Helpers for allocating colors in a colormap.
+//!
+//! Colors that are allocated with `AllocColor` stay allocated until they are freed with
+//! `FreeColors` or the connection is closed. [`AllocatedColor`] and [`ColormapAllocator`] free
+//! their colors when they are dropped.
+
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{Colormap, ConnectionExt};
+
+/// A RAII-like wrapper around a color cell that was allocated with
+/// [crate::protocol::xproto::alloc_color].
+///
+/// When this struct is dropped, the pixel is freed with a `FreeColors` request. Any errors
+/// during `Drop` are silently ignored. Most likely an error here means that your X11 connection
+/// is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct AllocatedColor<'c, C: ConnectionExt> {
+    conn: &'c C,
+    colormap: Colormap,
+    pixel: u32,
+}
+
+impl<'c, C: ConnectionExt> AllocatedColor<'c, C> {
+    /// Allocate the color closest to the given RGB values in `colormap`.
+    pub fn alloc(
+        conn: &'c C,
+        colormap: Colormap,
+        red: u16,
+        green: u16,
+        blue: u16,
+    ) -> Result<Self, ReplyError> {
+        let reply = conn.alloc_color(colormap, red, green, blue)?.reply()?;
+        Ok(Self {
+            conn,
+            colormap,
+            pixel: reply.pixel,
+        })
+    }
+
+    /// Get the pixel value of the allocated color.
+    pub fn pixel(&self) -> u32 {
+        self.pixel
+    }
+
+    /// Get the colormap that the color was allocated in.
+    pub fn colormap(&self) -> Colormap {
+        self.colormap
+    }
+
+    /// Give up ownership of the pixel without freeing it.
+    pub fn into_pixel(self) -> u32 {
+        let pixel = self.pixel;
+        std::mem::forget(self);
+        pixel
+    }
+}
+
+impl<C: ConnectionExt> Drop for AllocatedColor<'_, C> {
+    fn drop(&mut self) {
+        let _ = self.conn.free_colors(self.colormap, 0, &[self.pixel]);
+    }
+}
+
+/// Allocates colors in a colormap and frees all of them together.
+///
+/// [`ColormapAllocator::alloc`] sends the `AllocColor` requests for a batch of colors before
+/// waiting for the first reply, so that a batch only needs one round trip. All allocated pixels
+/// are remembered and freed with a single `FreeColors` request by
+/// [`ColormapAllocator::free_all`] or when the allocator is dropped. Errors during `Drop` are
+/// silently ignored.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::colormap::ColormapAllocator;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, screen_num) = x11rb::connect(None)?;
+/// let colormap = conn.setup().roots[screen_num].default_colormap;
+/// let mut colors = ColormapAllocator::new(&conn, colormap);
+/// let pixels = colors.alloc(&[(0xffff, 0, 0), (0, 0xffff, 0)])?;
+/// println!("Red is {:#x}, green is {:#x}", pixels[0], pixels[1]);
+/// // Both colors are freed here
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ColormapAllocator<'c, C: ConnectionExt> {
+    conn: &'c C,
+    colormap: Colormap,
+    pixels: Vec<u32>,
+}
+
+impl<'c, C: ConnectionExt> ColormapAllocator<'c, C> {
+    /// Create an allocator for the given colormap.
+    pub fn new(conn: &'c C, colormap: Colormap) -> Self {
+        Self {
+            conn,
+            colormap,
+            pixels: Vec::new(),
+        }
+    }
+
+    /// Allocate the colors closest to the given `(red, green, blue)` values.
+    ///
+    /// The pixels are returned in the same order as the colors. If some of the allocations fail,
+    /// the first error is returned; the colors that could be allocated are still freed later.
+    pub fn alloc(&mut self, colors: &[(u16, u16, u16)]) -> Result<Vec<u32>, ReplyError> {
+        let cookies = colors
+            .iter()
+            .map(|&(red, green, blue)| self.conn.alloc_color(self.colormap, red, green, blue))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut result = Ok(Vec::with_capacity(cookies.len()));
+        for cookie in cookies {
+            match (cookie.reply(), &mut result) {
+                (Ok(reply), Ok(pixels)) => {
+                    self.pixels.push(reply.pixel);
+                    pixels.push(reply.pixel);
+                }
+                (Ok(reply), Err(_)) => self.pixels.push(reply.pixel),
+                (Err(err), Ok(_)) => result = Err(err),
+                (Err(_), Err(_)) => {}
+            }
+        }
+        result
+    }
+
+    /// Get the colormap that colors are allocated in.
+    pub fn colormap(&self) -> Colormap {
+        self.colormap
+    }
+
+    /// Get all pixels that were allocated and not yet freed.
+    pub fn pixels(&self) -> &[u32] {
+        &self.pixels
+    }
+
+    /// Free all allocated pixels.
+    pub fn free_all(&mut self) -> Result<(), ConnectionError> {
+        if self.pixels.is_empty() {
+            return Ok(());
+        }
+        let pixels = std::mem::take(&mut self.pixels);
+        drop(self.conn.free_colors(self.colormap, 0, &pixels)?);
+        Ok(())
+    }
+}
+
+impl<C: ConnectionExt> Drop for ColormapAllocator<'_, C> {
+    fn drop(&mut self) {
+        let _ = self.free_all();
+    }
+}
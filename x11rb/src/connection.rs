@@ -6,15 +6,18 @@
 use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 use std::io::IoSlice;
+use std::sync::Mutex;
 
 use x11rb_protocol::x11_utils::{ReplyFDsRequest, ReplyRequest, VoidRequest};
 
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
-use crate::protocol::xproto::Setup;
+use crate::protocol::xproto::{Screen, Setup, Timestamp};
 use crate::protocol::Event;
 use crate::utils::RawFdContainer;
-use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+use crate::x11_utils::{
+    event_time, is_newer, ExtensionInformation, TryParse, TryParseFd, X11Error,
+};
 
 pub use x11rb_protocol::{DiscardMode, RawEventAndSeqNumber, SequenceNumber};
 
@@ -233,6 +236,25 @@ pub trait RequestConnection {
         extension_name: &'static str,
     ) -> Result<Option<ExtensionInformation>, ConnectionError>;
 
+    /// Get the range of event codes that an extension uses.
+    ///
+    /// This returns the first and the last event code (inclusive) of the extension's events,
+    /// based on the events that this crate knows about for the extension. `None` is returned if
+    /// the extension is not present, is unknown, or has no events with an event code of their
+    /// own, e.g. because it only uses the generic event extension.
+    fn extension_event_range(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<(u8, u8)>, ConnectionError> {
+        let count = match x11rb_protocol::protocol::extension_event_count(extension_name) {
+            Some(count) if count > 0 => count,
+            _ => return Ok(None),
+        };
+        Ok(self
+            .extension_information(extension_name)?
+            .and_then(|info| Some((info.first_event, info.first_event.checked_add(count - 1)?))))
+    }
+
     /// Wait for the reply to a request.
     ///
     /// The given sequence number identifies the request for which replies are expected. If the X11
@@ -360,6 +382,10 @@ pub trait RequestConnection {
 /// A connection to an X11 server.
 pub trait Connection: RequestConnection {
     /// Wait for a new event from the X11 server.
+    ///
+    /// This function does not send pending requests to the X11 server. If the awaited event is
+    /// caused by a request that is still buffered, call [`Connection::flush`] first. Otherwise,
+    /// this function might block forever.
     fn wait_for_event(&self) -> Result<Event, ConnectionError> {
         Ok(self.wait_for_event_with_sequence()?.0)
     }
@@ -382,6 +408,12 @@ pub trait Connection: RequestConnection {
     ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
 
     /// Poll for a new event from the X11 server.
+    ///
+    /// This function never blocks. It returns an event that was already received, or reads
+    /// whatever data is available from the X11 server without waiting for more. `Ok(None)` is
+    /// returned if no complete event is available. This makes it possible to integrate a
+    /// connection into an external event loop: Wait for the connection's file descriptor to
+    /// become readable, then call this function until it returns `None`.
     fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
         Ok(self.poll_for_event_with_sequence()?.map(|r| r.0))
     }
@@ -404,6 +436,98 @@ pub trait Connection: RequestConnection {
         &self,
     ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError>;
 
+    /// Wait for an event that matches the given predicate.
+    ///
+    /// This function waits for events until `predicate` returns `true` for one of them. This
+    /// event is returned. All other events that were received in the mean time are put back into
+    /// the event queue, so that later calls to e.g. `wait_for_event()` still return them in
+    /// their original order.
+    ///
+    /// This is useful for waiting for a specific event, for example a `SelectionNotify` after a
+    /// `ConvertSelection` request, without losing other events.
+    fn wait_for_event_matching<F>(&self, mut predicate: F) -> Result<Event, ConnectionError>
+    where
+        F: FnMut(&Event) -> bool,
+        Self: Sized,
+    {
+        let mut skipped = Vec::new();
+        let result = loop {
+            let (event, seqno) = match self.wait_for_raw_event_with_sequence() {
+                Ok(event) => event,
+                Err(err) => break Err(err),
+            };
+            match self.parse_event(event.as_ref()) {
+                Ok(parsed) if predicate(&parsed) => break Ok(parsed),
+                Ok(_) => skipped.push((event, seqno)),
+                Err(err) => {
+                    skipped.push((event, seqno));
+                    break Err(err.into());
+                }
+            }
+        };
+        self.requeue_raw_events(skipped);
+        result
+    }
+
+    /// Wait for an event with the given sequence number.
+    ///
+    /// The sequence number of an event is the sequence number of the last request that the X11
+    /// server processed before generating the event, see e.g. [`Cookie::sequence_number`]. Only
+    /// the low 16 bits of it are sent on the wire, but the connection reconstructs the full
+    /// number. This function waits for events until one with the given sequence number arrives
+    /// and returns it. Like with [`Connection::wait_for_event_matching`], all other events that
+    /// were received in the mean time are put back into the event queue in their original order.
+    /// `KeymapNotify` events do not have a sequence number and thus never match.
+    ///
+    /// If no event with this sequence number ever arrives, this function blocks forever. Since
+    /// the sequence number of events only increases, an event with a larger sequence number means
+    /// that the awaited event will not arrive anymore. Thus, this function returns `None` in that
+    /// case. Still, this function blocks if no further events arrive at all, e.g. because the
+    /// request does not cause any events.
+    fn wait_for_event_with_sequence_number(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Event>, ConnectionError>
+    where
+        Self: Sized,
+    {
+        use crate::protocol::xproto::KEYMAP_NOTIFY_EVENT;
+
+        let mut skipped = Vec::new();
+        let result = loop {
+            let (event, seqno) = match self.wait_for_raw_event_with_sequence() {
+                Ok(event) => event,
+                Err(err) => break Err(err),
+            };
+            if event.as_ref().first() == Some(&KEYMAP_NOTIFY_EVENT) || seqno < sequence {
+                skipped.push((event, seqno));
+                continue;
+            }
+            if seqno > sequence {
+                skipped.push((event, seqno));
+                break Ok(None);
+            }
+            match self.parse_event(event.as_ref()) {
+                Ok(parsed) => break Ok(Some(parsed)),
+                Err(err) => {
+                    skipped.push((event, seqno));
+                    break Err(err.into());
+                }
+            }
+        };
+        self.requeue_raw_events(skipped);
+        result
+    }
+
+    /// Put events back at the front of the event queue.
+    ///
+    /// The given events are returned by the following calls to e.g. `wait_for_event()` or
+    /// `poll_for_event()` in the given order, before any other events.
+    ///
+    /// This is used by [`Connection::wait_for_event_matching`] and
+    /// [`Connection::wait_for_event_with_sequence_number`].
+    fn requeue_raw_events(&self, events: Vec<RawEventAndSeqNumber<Self::Buf>>);
+
     /// Send all pending requests to the server.
     ///
     /// Implementations of this trait may buffer requests for batched sending. When this method is
@@ -411,6 +535,15 @@ pub trait Connection: RequestConnection {
     ///
     /// You do not have to call this method before `wait_for_reply()`. If the request you want to
     /// wait for was not yet sent, it will be sent by `wait_for_reply()`.
+    ///
+    /// However, functions that wait for events, like `wait_for_event()`, do not flush. When this
+    /// method returns `Ok`, all requests that were sent before were written to the underlying
+    /// stream. Thus, they reach the X11 server even if this client only waits for events
+    /// afterwards, e.g. for an event that another client causes in reaction to these requests.
+    ///
+    /// An error returned by this function means that writing to the stream failed, i.e. the
+    /// connection is broken. Errors caused by the requests themselves are reported by the X11
+    /// server later and are not returned here.
     fn flush(&self) -> Result<(), ConnectionError>;
 
     /// Get the setup information sent by the X11 server.
@@ -418,6 +551,48 @@ pub trait Connection: RequestConnection {
     /// The setup information contains X11 server, for example the window id of the root window.
     fn setup(&self) -> &Setup;
 
+    /// Get all screens of the X11 server.
+    ///
+    /// The screen number that is returned by e.g. [`crate::connect`] is an index into this slice.
+    /// Classic multi-screen setups have one entry for each screen, each with its own root window:
+    /// ```no_run
+    /// use x11rb::connection::Connection;
+    ///
+    /// let (conn, _) = x11rb::connect(None).unwrap();
+    /// for (screen_num, screen) in conn.screens().iter().enumerate() {
+    ///     println!("Screen {} has root window {:#x}", screen_num, screen.root);
+    /// }
+    /// ```
+    fn screens(&self) -> &[Screen] {
+        &self.setup().roots
+    }
+
+    /// Get the latest server timestamp of the events that this connection returned.
+    ///
+    /// Requests like `SetSelectionOwner` or `SetInputFocus` should get the timestamp of the event
+    /// that triggered them instead of `CURRENT_TIME`. This tracks the timestamps of the core
+    /// protocol events that carry one: key, button, motion, crossing, `PropertyNotify` and the
+    /// selection events. Events with an older timestamp than the latest one do not change the
+    /// result, see [`crate::x11_utils::is_newer`]. `CURRENT_TIME` is returned if no such event was
+    /// received yet.
+    ///
+    /// The connection types in this crate implement this. The default implementation does not
+    /// track anything and always returns `CURRENT_TIME`.
+    fn last_event_time(&self) -> Timestamp {
+        crate::CURRENT_TIME
+    }
+
+    /// Check if file descriptors can be passed to and from the X11 server.
+    ///
+    /// Requests like `shm::AttachFd` or most of DRI3 send or receive file descriptors, which is
+    /// only possible over a local Unix domain socket, but not over TCP. Clients should check this
+    /// before using such requests and fall back to e.g. `shm::Attach` otherwise.
+    ///
+    /// The default implementation returns `false`.
+    fn supports_fd_passing(&self) -> bool {
+        false
+    }
+
     /// Generate a new X11 identifier.
     ///
     /// This method can, for example, be used for creating a new window. First, this method is
@@ -611,3 +786,28 @@ pub fn compute_length_field<'b>(
 
     Ok(&storage.0[..])
 }
+
+/// The latest server timestamp of the events that a connection returned.
+///
+/// This is the state behind [`Connection::last_event_time`] for the connection types in this
+/// crate. It works on raw events so that events do not have to be parsed twice.
+#[derive(Debug, Default)]
+pub(crate) struct LastEventTime(Mutex<Option<Timestamp>>);
+
+impl LastEventTime {
+    /// Update the latest timestamp from the given raw event.
+    pub(crate) fn update(&self, event: &[u8]) {
+        if let Some(time) = event_time(event) {
+            let mut last = self.0.lock().unwrap();
+            match *last {
+                Some(last) if !is_newer(time, last) => {}
+                _ => *last = Some(time),
+            }
+        }
+    }
+
+    /// Get the latest timestamp, or `CURRENT_TIME` if there was none.
+    pub(crate) fn get(&self) -> Timestamp {
+        self.0.lock().unwrap().unwrap_or(crate::CURRENT_TIME)
+    }
+}
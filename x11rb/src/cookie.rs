@@ -97,6 +97,11 @@ where
         result
     }
 
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.consume().1
+    }
+
     /// Check if the original request caused an X11 error.
     pub fn check(self) -> Result<(), ReplyError> {
         let (connection, sequence) = self.consume();
@@ -107,6 +112,18 @@ where
     ///
     /// Without calling this method, an error becomes available on the connection as an event after
     /// this cookie was dropped. This function causes errors to be ignored instead.
+    ///
+    /// This is useful for requests where an error is expected and harmless:
+    /// ```no_run
+    /// # use x11rb::connection::Connection;
+    /// # use x11rb::errors::ConnectionError;
+    /// # use x11rb::protocol::xproto::{ConnectionExt, Window};
+    /// fn destroy_if_exists(conn: &impl Connection, window: Window) -> Result<(), ConnectionError> {
+    ///     // The window might already be gone, in which case a `Window` error is generated
+    ///     conn.destroy_window(window)?.ignore_error();
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn ignore_error(self) {
         let (connection, sequence) = self.consume();
         connection.discard_reply(
@@ -257,6 +274,82 @@ where
     }
 }
 
+/// Get the replies to many requests in the order in which they arrive.
+///
+/// This is an iterator over the replies for a batch of cookies. Each item contains the index of
+/// the cookie in the batch and the reply or error. The X11 server answers the requests of a
+/// connection in the order in which they were sent, so the replies are produced in the order of
+/// the cookies' sequence numbers, independent of the order of the batch. Thus, waiting for one
+/// reply never delays a reply that already arrived.
+///
+/// ```no_run
+/// use x11rb::cookie::ReplyCollector;
+/// use x11rb::protocol::xproto::ConnectionExt;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, _) = x11rb::connect(None)?;
+/// let windows = [0x1234, 0x5678];
+/// let cookies = windows
+///     .iter()
+///     .map(|&window| conn.get_geometry(window))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// for (index, reply) in ReplyCollector::new(cookies) {
+///     match reply {
+///         Ok(geometry) => println!("{:#x}: {}x{}", windows[index], geometry.width, geometry.height),
+///         Err(err) => println!("{:#x}: {}", windows[index], err),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReplyCollector<'a, C, R>
+where
+    C: RequestConnection + ?Sized,
+{
+    cookies: std::vec::IntoIter<(usize, Cookie<'a, C, R>)>,
+}
+
+impl<'a, C, R> ReplyCollector<'a, C, R>
+where
+    R: TryParse,
+    C: RequestConnection + ?Sized,
+{
+    /// Collect the replies for the given cookies.
+    pub fn new(cookies: impl IntoIterator<Item = Cookie<'a, C, R>>) -> Self {
+        let mut cookies = cookies.into_iter().enumerate().collect::<Vec<_>>();
+        cookies.sort_by_key(|(_, cookie)| cookie.sequence_number());
+        Self {
+            cookies: cookies.into_iter(),
+        }
+    }
+}
+
+impl<C, R> Iterator for ReplyCollector<'_, C, R>
+where
+    R: TryParse,
+    C: RequestConnection + ?Sized,
+{
+    type Item = (usize, Result<R, ReplyError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cookies
+            .next()
+            .map(|(index, cookie)| (index, cookie.reply()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cookies.size_hint()
+    }
+}
+
+impl<C, R> ExactSizeIterator for ReplyCollector<'_, C, R>
+where
+    R: TryParse,
+    C: RequestConnection + ?Sized,
+{
+}
+
 /// A handle to a response containing `RawFd` from the X11 server.
 ///
 /// When sending a request to the X11 server, this library returns a `Cookie`. This `Cookie` can
@@ -307,6 +400,11 @@ where
         let (buffer, mut fds) = self.raw_reply()?;
         Ok(R::try_parse_fd(buffer.as_ref(), &mut fds)?.0)
     }
+
+    /// Consume this instance and get the contained sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.raw_cookie.into_sequence_number()
+    }
 }
 
 macro_rules! multiple_reply_cookie {
@@ -0,0 +1,323 @@
+//! Cursors from the standard X11 "cursor" font.
+//!
+//! Every X11 server provides a font called "cursor" whose glyphs are the classic X11 cursor
+//! shapes. [`CursorShape`] names these glyphs, [`set_window_cursor`] gives a window one of them,
+//! and [`StandardCursors`] keeps the created cursors around for reuse.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+use crate::connection::Connection;
+use crate::errors::ReplyOrIdError;
+use crate::protocol::xproto::{
+    ChangeWindowAttributesAux, ConnectionExt as _, Cursor, Font, Window,
+};
+
+/// A cursor from the standard X11 "cursor" font.
+///
+/// The names of the shapes are the names from `X11/cursorfont.h` without the `XC_` prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum CursorShape {
+    /// The `X` that is shown when a window has no cursor.
+    XCursor = 0,
+    /// The `arrow` cursor.
+    Arrow = 2,
+    /// The `based_arrow_down` cursor.
+    BasedArrowDown = 4,
+    /// The `based_arrow_up` cursor.
+    BasedArrowUp = 6,
+    /// The `boat` cursor.
+    Boat = 8,
+    /// The `bogosity` cursor.
+    Bogosity = 10,
+    /// The `bottom_left_corner` cursor.
+    BottomLeftCorner = 12,
+    /// The `bottom_right_corner` cursor.
+    BottomRightCorner = 14,
+    /// The `bottom_side` cursor.
+    BottomSide = 16,
+    /// The `bottom_tee` cursor.
+    BottomTee = 18,
+    /// The `box_spiral` cursor.
+    BoxSpiral = 20,
+    /// The `center_ptr` cursor.
+    CenterPtr = 22,
+    /// The `circle` cursor.
+    Circle = 24,
+    /// The `clock` cursor.
+    Clock = 26,
+    /// The `coffee_mug` cursor.
+    CoffeeMug = 28,
+    /// The `cross` cursor.
+    Cross = 30,
+    /// The `cross_reverse` cursor.
+    CrossReverse = 32,
+    /// A crosshair for selecting a position (`crosshair`).
+    Crosshair = 34,
+    /// The `diamond_cross` cursor.
+    DiamondCross = 36,
+    /// The `dot` cursor.
+    Dot = 38,
+    /// The `dotbox` cursor.
+    Dotbox = 40,
+    /// The `double_arrow` cursor.
+    DoubleArrow = 42,
+    /// The `draft_large` cursor.
+    DraftLarge = 44,
+    /// The `draft_small` cursor.
+    DraftSmall = 46,
+    /// The `draped_box` cursor.
+    DrapedBox = 48,
+    /// The `exchange` cursor.
+    Exchange = 50,
+    /// Four arrows for moving something (`fleur`).
+    Fleur = 52,
+    /// The `gobbler` cursor.
+    Gobbler = 54,
+    /// The `gumby` cursor.
+    Gumby = 56,
+    /// The `hand1` cursor.
+    Hand1 = 58,
+    /// A pointing hand, e.g. for links (`hand2`).
+    Hand2 = 60,
+    /// The `heart` cursor.
+    Heart = 62,
+    /// The `icon` cursor.
+    Icon = 64,
+    /// The `iron_cross` cursor.
+    IronCross = 66,
+    /// The usual arrow pointer (`left_ptr`).
+    LeftPtr = 68,
+    /// The `left_side` cursor.
+    LeftSide = 70,
+    /// The `left_tee` cursor.
+    LeftTee = 72,
+    /// The `leftbutton` cursor.
+    Leftbutton = 74,
+    /// The `ll_angle` cursor.
+    LlAngle = 76,
+    /// The `lr_angle` cursor.
+    LrAngle = 78,
+    /// The `man` cursor.
+    Man = 80,
+    /// The `middlebutton` cursor.
+    Middlebutton = 82,
+    /// The `mouse` cursor.
+    Mouse = 84,
+    /// The `pencil` cursor.
+    Pencil = 86,
+    /// The `pirate` cursor.
+    Pirate = 88,
+    /// The `plus` cursor.
+    Plus = 90,
+    /// An arrow with a question mark for help (`question_arrow`).
+    QuestionArrow = 92,
+    /// The `right_ptr` cursor.
+    RightPtr = 94,
+    /// The `right_side` cursor.
+    RightSide = 96,
+    /// The `right_tee` cursor.
+    RightTee = 98,
+    /// The `rightbutton` cursor.
+    Rightbutton = 100,
+    /// The `rtl_logo` cursor.
+    RtlLogo = 102,
+    /// The `sailboat` cursor.
+    Sailboat = 104,
+    /// The `sb_down_arrow` cursor.
+    SbDownArrow = 106,
+    /// A horizontal double arrow for resizing (`sb_h_double_arrow`).
+    SbHDoubleArrow = 108,
+    /// The `sb_left_arrow` cursor.
+    SbLeftArrow = 110,
+    /// The `sb_right_arrow` cursor.
+    SbRightArrow = 112,
+    /// The `sb_up_arrow` cursor.
+    SbUpArrow = 114,
+    /// A vertical double arrow for resizing (`sb_v_double_arrow`).
+    SbVDoubleArrow = 116,
+    /// The `shuttle` cursor.
+    Shuttle = 118,
+    /// The `sizing` cursor.
+    Sizing = 120,
+    /// The `spider` cursor.
+    Spider = 122,
+    /// The `spraycan` cursor.
+    Spraycan = 124,
+    /// The `star` cursor.
+    Star = 126,
+    /// The `target` cursor.
+    Target = 128,
+    /// The `tcross` cursor.
+    Tcross = 130,
+    /// The `top_left_arrow` cursor.
+    TopLeftArrow = 132,
+    /// The `top_left_corner` cursor.
+    TopLeftCorner = 134,
+    /// The `top_right_corner` cursor.
+    TopRightCorner = 136,
+    /// The `top_side` cursor.
+    TopSide = 138,
+    /// The `top_tee` cursor.
+    TopTee = 140,
+    /// The `trek` cursor.
+    Trek = 142,
+    /// The `ul_angle` cursor.
+    UlAngle = 144,
+    /// The `umbrella` cursor.
+    Umbrella = 146,
+    /// The `ur_angle` cursor.
+    UrAngle = 148,
+    /// A busy cursor (`watch`).
+    Watch = 150,
+    /// A text cursor (`xterm`).
+    Xterm = 152,
+}
+
+impl CursorShape {
+    /// Get the index of the glyph in the cursor font.
+    ///
+    /// The mask for the glyph is the following glyph in the font.
+    pub fn glyph(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Create a cursor from the standard X11 "cursor" font.
+///
+/// The cursor is black with a white outline.
+pub fn create_font_cursor<C: Connection>(
+    conn: &C,
+    font: Font,
+    shape: CursorShape,
+) -> Result<Cursor, ReplyOrIdError> {
+    let cursor = conn.generate_id()?;
+    let glyph = shape.glyph();
+    let _ = conn.create_glyph_cursor(
+        cursor,
+        font,
+        font,
+        glyph,
+        glyph + 1,
+        0,
+        0,
+        0,
+        u16::MAX,
+        u16::MAX,
+        u16::MAX,
+    )?;
+    Ok(cursor)
+}
+
+/// Set the cursor of a window to one of the standard cursor shapes.
+///
+/// This opens the cursor font, creates the cursor, and frees both again. The window keeps its
+/// cursor afterwards. When cursors are set more than once, [`StandardCursors`] avoids creating
+/// the same cursor again. Setting the cursor of the root window changes the default cursor of
+/// the screen.
+pub fn set_window_cursor<C: Connection>(
+    conn: &C,
+    window: Window,
+    shape: CursorShape,
+) -> Result<(), ReplyOrIdError> {
+    let font = conn.generate_id()?;
+    let _ = conn.open_font(font, b"cursor")?;
+    let cursor = create_font_cursor(conn, font, shape)?;
+    let _ = conn.close_font(font)?;
+    let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+    let _ = conn.change_window_attributes(window, &aux)?;
+    let _ = conn.free_cursor(cursor)?;
+    Ok(())
+}
+
+/// A cache for cursors from the standard X11 "cursor" font.
+///
+/// The cursor font is opened when the first cursor is created and each shape is only created
+/// once. When this struct is dropped, the font and all created cursors are freed. Windows that
+/// use one of these cursors keep it. Any errors during `Drop` are silently ignored.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::cursor_font::{CursorShape, StandardCursors};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, screen_num) = x11rb::connect(None)?;
+/// let root = conn.setup().roots[screen_num].root;
+/// let cursors = StandardCursors::new(&conn);
+/// cursors.set_window_cursor(root, CursorShape::LeftPtr)?;
+/// conn.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct StandardCursors<'c, C: Connection> {
+    conn: &'c C,
+    font: Cell<Option<Font>>,
+    cursors: RefCell<HashMap<CursorShape, Cursor>>,
+}
+
+impl<'c, C: Connection> StandardCursors<'c, C> {
+    /// Create an empty cache for the given connection.
+    pub fn new(conn: &'c C) -> Self {
+        Self {
+            conn,
+            font: Default::default(),
+            cursors: Default::default(),
+        }
+    }
+
+    /// Get the cursor with the given shape, creating it if it does not exist yet.
+    pub fn get(&self, shape: CursorShape) -> Result<Cursor, ReplyOrIdError> {
+        if let Some(&cursor) = self.cursors.borrow().get(&shape) {
+            return Ok(cursor);
+        }
+        let font = match self.font.get() {
+            Some(font) => font,
+            None => {
+                let font = self.conn.generate_id()?;
+                let _ = self.conn.open_font(font, b"cursor")?;
+                self.font.set(Some(font));
+                font
+            }
+        };
+        let cursor = create_font_cursor(self.conn, font, shape)?;
+        let _ = self.cursors.borrow_mut().insert(shape, cursor);
+        Ok(cursor)
+    }
+
+    /// Set the cursor of a window to the cursor with the given shape.
+    pub fn set_window_cursor(
+        &self,
+        window: Window,
+        shape: CursorShape,
+    ) -> Result<(), ReplyOrIdError> {
+        let aux = ChangeWindowAttributesAux::new().cursor(self.get(shape)?);
+        let _ = self.conn.change_window_attributes(window, &aux)?;
+        Ok(())
+    }
+}
+
+impl<C: Connection> Drop for StandardCursors<'_, C> {
+    fn drop(&mut self) {
+        for &cursor in self.cursors.get_mut().values() {
+            let _ = self.conn.free_cursor(cursor);
+        }
+        if let Some(font) = self.font.get() {
+            let _ = self.conn.close_font(font);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CursorShape;
+
+    #[test]
+    fn test_cursor_shape_glyph() {
+        // The values from X11/cursorfont.h
+        assert_eq!(CursorShape::XCursor.glyph(), 0);
+        assert_eq!(CursorShape::LeftPtr.glyph(), 68);
+        assert_eq!(CursorShape::Xterm.glyph(), 152);
+    }
+}
@@ -1,6 +1,8 @@
 //! This module contains the current mess that is error handling.
 
+use crate::protocol::xproto::{GrabStatus, Visualid};
 use crate::x11_utils::X11Error;
+use x11rb_protocol::SequenceNumber;
 
 pub use x11rb_protocol::errors::{ConnectError, IdsExhausted, ParseError};
 
@@ -74,11 +76,63 @@ pub enum ConnectionError {
     /// This is `XCB_CONN_CLOSED_MEM_INSUFFICIENT`.
     InsufficientMemory,
 
+    /// An event was discarded because the event queue was full.
+    ///
+    /// This is only reported by `RustConnection` when a limit with
+    /// [`QueueOverflowPolicy::Error`](x11rb_protocol::connection::QueueOverflowPolicy::Error)
+    /// was configured. The connection is still usable.
+    EventQueueOverflow,
+
+    /// The X11 server sent a reply that does not belong to any request with a reply.
+    ///
+    /// This means that the connection's idea of the sent requests does not match the X11 server,
+    /// e.g. because of a bug or because something else wrote to the connection. The reply was
+    /// discarded. This is only reported by `RustConnection`.
+    ProtocolDesync {
+        /// The sequence number of the unexpected reply.
+        sequence: SequenceNumber,
+    },
+
     /// An I/O error occurred on the connection.
     IoError(std::io::Error),
 }
 
-impl std::error::Error for ConnectionError {}
+impl ConnectionError {
+    /// Check if this error means that the connection to the X11 server is gone.
+    ///
+    /// The connection implementations report a lost connection differently: `RustConnection`
+    /// forwards the I/O error from the operating system, for example
+    /// [`std::io::ErrorKind::BrokenPipe`], or uses [`std::io::ErrorKind::UnexpectedEof`] when the
+    /// X11 server closed the connection. `XCBConnection` only learns from libxcb that something
+    /// went wrong with the socket and uses [`std::io::ErrorKind::ConnectionAborted`]. This
+    /// function returns `true` for all of these cases.
+    ///
+    /// Once this happened, all further requests on the connection fail as well.
+    pub fn is_connection_closed(&self) -> bool {
+        use std::io::ErrorKind;
+        match self {
+            ConnectionError::IoError(err) => matches!(
+                err.kind(),
+                ErrorKind::UnexpectedEof
+                    | ErrorKind::WriteZero
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl std::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConnectionError::ParseError(err) => Some(err),
+            ConnectionError::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl std::fmt::Display for ConnectionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -90,8 +144,14 @@ impl std::fmt::Display for ConnectionError {
                 write!(f, "Maximum request length exceeded")
             }
             ConnectionError::FdPassingFailed => write!(f, "FD passing failed"),
-            ConnectionError::ParseError(err) => err.fmt(f),
-            ConnectionError::IoError(err) => err.fmt(f),
+            ConnectionError::EventQueueOverflow => write!(f, "Event queue overflow"),
+            ConnectionError::ProtocolDesync { sequence } => write!(
+                f,
+                "Received a reply with sequence number {} that no request expected",
+                sequence
+            ),
+            ConnectionError::ParseError(_) => write!(f, "Failed to parse data from the X11 server"),
+            ConnectionError::IoError(_) => write!(f, "I/O error on the X11 connection"),
         }
     }
 }
@@ -117,13 +177,20 @@ pub enum ReplyError {
     X11Error(X11Error),
 }
 
-impl std::error::Error for ReplyError {}
+impl std::error::Error for ReplyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReplyError::ConnectionError(err) => Some(err),
+            ReplyError::X11Error(_) => None,
+        }
+    }
+}
 
 impl std::fmt::Display for ReplyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ReplyError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyError::ConnectionError(_) => write!(f, "X11 connection error"),
+            ReplyError::X11Error(e) => write!(f, "X11 error {}", e),
         }
     }
 }
@@ -167,13 +234,20 @@ impl std::fmt::Display for ReplyOrIdError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ReplyOrIdError::IdsExhausted => f.write_str("X11 IDs have been exhausted"),
-            ReplyOrIdError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyOrIdError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyOrIdError::ConnectionError(_) => write!(f, "X11 connection error"),
+            ReplyOrIdError::X11Error(e) => write!(f, "X11 error {}", e),
         }
     }
 }
 
-impl std::error::Error for ReplyOrIdError {}
+impl std::error::Error for ReplyOrIdError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReplyOrIdError::ConnectionError(err) => Some(err),
+            ReplyOrIdError::IdsExhausted | ReplyOrIdError::X11Error(_) => None,
+        }
+    }
+}
 
 impl From<ParseError> for ReplyOrIdError {
     fn from(err: ParseError) -> Self {
@@ -207,3 +281,221 @@ impl From<IdsExhausted> for ReplyOrIdError {
         ReplyOrIdError::IdsExhausted
     }
 }
+
+/// An invalid combination of parameters for a `CreateWindow` request.
+///
+/// This error is produced by [`crate::wrapper::validate_create_window`] for parameters that the
+/// X11 server would reject with a `Match` error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CreateWindowError {
+    /// An `InputOnly` window must use a depth of zero (`COPY_DEPTH_FROM_PARENT`).
+    InputOnlyDepth(u8),
+    /// An `InputOnly` window must have a border width of zero.
+    InputOnlyBorderWidth(u16),
+    /// The named window attribute cannot be set on an `InputOnly` window.
+    InputOnlyAttribute(&'static str),
+    /// The screen does not support windows with this depth.
+    UnsupportedDepth(u8),
+    /// The screen does not support this visual with the requested depth.
+    UnsupportedVisual(Visualid),
+}
+
+impl std::fmt::Display for CreateWindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateWindowError::InputOnlyDepth(depth) => {
+                write!(f, "InputOnly windows must have depth 0, not {}", depth)
+            }
+            CreateWindowError::InputOnlyBorderWidth(width) => write!(
+                f,
+                "InputOnly windows must have border width 0, not {}",
+                width
+            ),
+            CreateWindowError::InputOnlyAttribute(name) => {
+                write!(f, "Attribute {} cannot be set on InputOnly windows", name)
+            }
+            CreateWindowError::UnsupportedDepth(depth) => {
+                write!(f, "Depth {} is not supported by the screen", depth)
+            }
+            CreateWindowError::UnsupportedVisual(visual) => write!(
+                f,
+                "Visual {:#x} is not supported by the screen with this depth",
+                visual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CreateWindowError {}
+
+/// An error from [`crate::wrapper::create_window_validated`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateWindowRequestError {
+    /// The parameters were rejected before the request was sent.
+    InvalidParameters(CreateWindowError),
+    /// Some error occurred on the X11 connection.
+    ConnectionError(ConnectionError),
+}
+
+impl std::fmt::Display for CreateWindowRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CreateWindowRequestError::InvalidParameters(e) => {
+                write!(f, "Invalid CreateWindow parameters: {}", e)
+            }
+            CreateWindowRequestError::ConnectionError(e) => {
+                write!(f, "X11 connection error: {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CreateWindowRequestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CreateWindowRequestError::InvalidParameters(err) => Some(err),
+            CreateWindowRequestError::ConnectionError(err) => Some(err),
+        }
+    }
+}
+
+impl From<CreateWindowError> for CreateWindowRequestError {
+    fn from(err: CreateWindowError) -> Self {
+        CreateWindowRequestError::InvalidParameters(err)
+    }
+}
+
+impl From<ConnectionError> for CreateWindowRequestError {
+    fn from(err: ConnectionError) -> Self {
+        CreateWindowRequestError::ConnectionError(err)
+    }
+}
+
+/// A serialized event is longer than the 32 bytes that `SendEvent` can send.
+///
+/// This error is produced by [`crate::wrapper::event_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventTooLong {
+    /// The length of the serialized event in bytes.
+    pub length: usize,
+}
+
+impl std::fmt::Display for EventTooLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "An event of {} bytes does not fit into 32 bytes",
+            self.length
+        )
+    }
+}
+
+impl std::error::Error for EventTooLong {}
+
+/// An error from [`crate::wrapper::retry_grab`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GrabError {
+    /// The grab did not succeed. This contains the status of the last attempt.
+    Failed(GrabStatus),
+    /// The grab request failed.
+    ReplyError(ReplyError),
+}
+
+impl std::fmt::Display for GrabError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrabError::Failed(status) => write!(f, "The grab failed with status {:?}", status),
+            GrabError::ReplyError(err) => write!(f, "The grab request failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for GrabError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GrabError::Failed(_) => None,
+            GrabError::ReplyError(err) => Some(err),
+        }
+    }
+}
+
+impl From<ReplyError> for GrabError {
+    fn from(err: ReplyError) -> Self {
+        GrabError::ReplyError(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use super::{
+        ConnectionError, CreateWindowRequestError, ParseError, ReplyError, ReplyOrIdError,
+    };
+
+    /// Get the chain of errors starting with `error`, as strings.
+    fn chain(error: &dyn Error) -> Vec<String> {
+        let mut result = vec![error.to_string()];
+        let mut current = error;
+        while let Some(source) = current.source() {
+            result.push(source.to_string());
+            current = source;
+        }
+        result
+    }
+
+    #[test]
+    fn test_parse_error_source() {
+        let parse = ParseError::InsufficientData.to_string();
+        let error = ReplyOrIdError::from(ParseError::InsufficientData);
+        assert_eq!(
+            chain(&error),
+            [
+                "X11 connection error",
+                "Failed to parse data from the X11 server",
+                &parse
+            ]
+        );
+        let error = ReplyError::from(ParseError::InsufficientData);
+        let messages = chain(&error);
+        assert_eq!(messages.len(), 3);
+        // Each error in the chain only describes itself
+        for (i, message) in messages.iter().enumerate() {
+            assert!(!messages[i + 1..].contains(message));
+        }
+        assert!(ParseError::InsufficientData.source().is_none());
+    }
+
+    #[test]
+    fn test_io_error_source() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::Other, "socket exploded");
+        let error = ReplyOrIdError::from(ConnectionError::from(io_error));
+        assert_eq!(error.to_string(), "X11 connection error");
+        let source = error.source().unwrap();
+        assert!(source.is::<ConnectionError>());
+        assert_eq!(source.to_string(), "I/O error on the X11 connection");
+        let root = source.source().unwrap();
+        assert!(root.is::<std::io::Error>());
+        assert_eq!(root.to_string(), "socket exploded");
+        assert!(root.source().is_none());
+    }
+
+    #[test]
+    fn test_create_window_request_error_display() {
+        let error = CreateWindowRequestError::from(ConnectionError::UnsupportedExtension);
+        assert_eq!(
+            error.to_string(),
+            "X11 connection error: Unsupported extension"
+        );
+        assert!(error.source().unwrap().is::<ConnectionError>());
+    }
+
+    #[test]
+    fn test_leaf_errors() {
+        assert!(ReplyOrIdError::IdsExhausted.source().is_none());
+        assert!(ConnectionError::UnsupportedExtension.source().is_none());
+    }
+}
@@ -0,0 +1,202 @@
+//! Dispatching events to typed handlers.
+//!
+//! An [`EventLoop`] is a list of closures that each handle one type of event. The loop waits for
+//! events and converts each event into the type of the first handler that accepts it:
+//!
+//! ```no_run
+//! use std::ops::ControlFlow;
+//! use x11rb::event_loop::EventLoop;
+//! use x11rb::protocol::xproto::{ButtonPressEvent, ExposeEvent};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::connect(None)?;
+//! EventLoop::new()
+//!     .on(|event: ExposeEvent| {
+//!         println!("Window {} needs to be redrawn", event.window);
+//!         ControlFlow::Continue(())
+//!     })
+//!     .on(|_: ButtonPressEvent| ControlFlow::Break(()))
+//!     .on_error(|error| {
+//!         eprintln!("Got an error: {:?}", error);
+//!         ControlFlow::Continue(())
+//!     })
+//!     .run(&conn)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Events of extensions are handled the same way, since the connection already knows the event
+//! codes of all extensions that were used.
+//!
+//! Some event structures are shared by more than one event. For example, `KeyPress` and
+//! `KeyRelease` events are both a `KeyPressEvent`. A handler for such a structure receives all of
+//! these events; the `response_type` field tells them apart.
+
+use std::convert::TryFrom;
+use std::ops::ControlFlow;
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::Event;
+use crate::x11_utils::X11Error;
+
+type Handler<'h> = Box<dyn FnMut(Event) -> Result<ControlFlow<()>, Event> + 'h>;
+
+/// A set of handlers for different types of events.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Default)]
+pub struct EventLoop<'h> {
+    handlers: Vec<Handler<'h>>,
+    error_handler: Option<Box<dyn FnMut(X11Error) -> ControlFlow<()> + 'h>>,
+    default_handler: Option<Box<dyn FnMut(Event) -> ControlFlow<()> + 'h>>,
+}
+
+impl std::fmt::Debug for EventLoop<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventLoop")
+            .field("handlers", &self.handlers.len())
+            .field("error_handler", &self.error_handler.is_some())
+            .field("default_handler", &self.default_handler.is_some())
+            .finish()
+    }
+}
+
+impl<'h> EventLoop<'h> {
+    /// Create an event loop without any handlers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a handler for events of type `T`.
+    ///
+    /// If more than one handler accepts an event, only the handler that was added first is
+    /// called. Returning [`ControlFlow::Break`] from the handler stops [`EventLoop::run`].
+    // The handler gives back the event it did not accept, which is as large as it is
+    #[allow(clippy::result_large_err)]
+    pub fn on<T, F>(mut self, mut handler: F) -> Self
+    where
+        T: TryFrom<Event, Error = Event>,
+        F: FnMut(T) -> ControlFlow<()> + 'h,
+    {
+        self.handlers
+            .push(Box::new(move |event| T::try_from(event).map(&mut handler)));
+        self
+    }
+
+    /// Set the handler for X11 errors.
+    ///
+    /// Errors of requests whose cookie was dropped or that were sent with `_unchecked` are
+    /// delivered as events. Without an error handler, these go to the default handler.
+    pub fn on_error<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(X11Error) -> ControlFlow<()> + 'h,
+    {
+        self.error_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the handler for all events that no other handler accepts.
+    ///
+    /// Without a default handler, such events are ignored.
+    pub fn otherwise<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(Event) -> ControlFlow<()> + 'h,
+    {
+        self.default_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Give an event to the first handler that accepts it.
+    ///
+    /// Returns what the handler returned, or [`ControlFlow::Continue`] if the event was ignored.
+    pub fn dispatch(&mut self, event: Event) -> ControlFlow<()> {
+        let mut event = match (event, &mut self.error_handler) {
+            (Event::Error(error), Some(handler)) => return handler(error),
+            (event, _) => event,
+        };
+        for handler in self.handlers.iter_mut() {
+            event = match handler(event) {
+                Ok(flow) => return flow,
+                Err(event) => event,
+            };
+        }
+        match &mut self.default_handler {
+            Some(handler) => handler(event),
+            None => ControlFlow::Continue(()),
+        }
+    }
+
+    /// Wait for events and dispatch them until a handler returns [`ControlFlow::Break`].
+    ///
+    /// Requests that the handlers send are flushed before waiting for the next event.
+    pub fn run<C: Connection>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        loop {
+            conn.flush()?;
+            let event = conn.wait_for_event()?;
+            if let ControlFlow::Break(()) = self.dispatch(event) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::ops::ControlFlow;
+
+    use super::EventLoop;
+    use crate::protocol::xproto::{
+        ExposeEvent, KeyPressEvent, MapNotifyEvent, EXPOSE_EVENT, KEY_RELEASE_EVENT,
+    };
+    use crate::protocol::Event;
+
+    #[test]
+    fn test_dispatch() {
+        let seen = RefCell::new(Vec::new());
+        let mut event_loop = EventLoop::new()
+            .on(|event: ExposeEvent| {
+                seen.borrow_mut().push(("expose", event.response_type));
+                ControlFlow::Continue(())
+            })
+            .on(|event: KeyPressEvent| {
+                seen.borrow_mut().push(("key", event.response_type));
+                ControlFlow::Break(())
+            })
+            .on(|_: ExposeEvent| unreachable!("Only the first handler is called"))
+            .otherwise(|event| {
+                seen.borrow_mut().push(("other", event.response_type()));
+                ControlFlow::Continue(())
+            });
+
+        let event = ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            ..Default::default()
+        };
+        assert_eq!(
+            event_loop.dispatch(Event::Expose(event)),
+            ControlFlow::Continue(())
+        );
+        let event = KeyPressEvent {
+            response_type: KEY_RELEASE_EVENT,
+            ..Default::default()
+        };
+        assert_eq!(
+            event_loop.dispatch(Event::KeyRelease(event)),
+            ControlFlow::Break(())
+        );
+        let event = Event::MapNotify(MapNotifyEvent::default());
+        assert_eq!(event_loop.dispatch(event), ControlFlow::Continue(()));
+        drop(event_loop);
+
+        assert_eq!(
+            seen.into_inner(),
+            [
+                ("expose", EXPOSE_EVENT),
+                ("key", KEY_RELEASE_EVENT),
+                ("other", 0)
+            ]
+        );
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! To integrate x11rb with an event loop,
 //! [`std::os::unix::io::AsRawFd`](https://doc.rust-lang.org/std/os/unix/io/trait.AsRawFd.html) is
-//! implemented by [`RustConnection`](../rust_connection/struct.RustConnection.html)'s
-//! [`DefaultStream`](../rust_connection/struct.DefaultStream.html#impl-AsRawFd) and
+//! implemented by [`RustConnection`](../rust_connection/struct.RustConnection.html#impl-AsRawFd),
+//! its [`DefaultStream`](../rust_connection/struct.DefaultStream.html#impl-AsRawFd) and
 //! [`XCBConnection`](../xcb_ffi/struct.XCBConnection.html#impl-AsRawFd). This allows to integrate
 //! with an event loop that also handles timeouts or network I/O. See
 //! [`xclock_utc`](https://github.com/psychon/x11rb/blob/master/x11rb/examples/xclock_utc.rs) for an
@@ -21,9 +21,9 @@
 //!
 //! fn main_loop(conn: &RustConnection) -> Result<(), ConnectionError> {
 //!     #[cfg(unix)]
-//!     let raw_handle = conn.stream().as_raw_fd();
+//!     let raw_handle = conn.as_raw_fd();
 //!     #[cfg(windows)]
-//!     let raw_handle = conn.stream().as_raw_socket();
+//!     let raw_handle = conn.as_raw_socket();
 //!     loop {
 //!         while let Some(event) = conn.poll_for_event()? {
 //!             handle_event(event);
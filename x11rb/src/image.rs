@@ -692,7 +692,12 @@ impl<'a> Image<'a> {
     ///
     /// This function takes a `GetImageReply` and wraps it in an `Image`. The given `width` and
     /// `height` describe the corresponding values of the `GetImage` request that was used to get
-    /// the `GetImageReply`.
+    /// the `GetImageReply`. The reply must be for a `GetImage` request in `ZPixmap` format.
+    ///
+    /// The depth of the resulting image is taken from the reply. The bits per pixel and the
+    /// scanline padding are looked up in the `setup` for this depth. The byte order is the
+    /// server's image byte order. Thus, the resulting image describes how to interpret the
+    /// contained pixel data.
     pub fn get_from_reply(
         setup: &Setup,
         width: u16,
@@ -706,7 +711,7 @@ impl<'a> Image<'a> {
             format.scanline_pad.try_into()?,
             reply.depth,
             format.bits_per_pixel.try_into()?,
-            ImageOrder::MsbFirst,
+            setup.image_byte_order.try_into()?,
             Cow::Owned(reply.data),
         )
     }
@@ -969,6 +974,7 @@ fn compute_depth_1_address(x: usize, order: ImageOrder) -> (usize, usize) {
 #[cfg(test)]
 mod test_image {
     use super::{BitsPerPixel, Image, ImageOrder, ParseError, ScanlinePad};
+    use crate::protocol::xproto::{Format, GetImageReply, ImageOrder as XprotoImageOrder, Setup};
     use std::borrow::Cow;
 
     #[test]
@@ -1009,6 +1015,32 @@ mod test_image {
         assert_eq!(image.data(), [42, 125]);
     }
 
+    #[test]
+    fn test_get_from_reply() {
+        let setup = Setup {
+            image_byte_order: XprotoImageOrder::LSB_FIRST,
+            pixmap_formats: vec![Format {
+                depth: 24,
+                bits_per_pixel: 32,
+                scanline_pad: 32,
+            }],
+            ..Default::default()
+        };
+        let reply = GetImageReply {
+            depth: 24,
+            sequence: 0,
+            visual: 0,
+            data: vec![0x11, 0x22, 0x33, 0, 0x44, 0x55, 0x66, 0],
+        };
+        let image = Image::get_from_reply(&setup, 2, 1, reply).unwrap();
+        assert_eq!(image.depth(), 24);
+        assert_eq!(image.bits_per_pixel(), BitsPerPixel::B32);
+        assert_eq!(image.scanline_pad(), ScanlinePad::Pad32);
+        assert_eq!(image.byte_order(), ImageOrder::LsbFirst);
+        assert_eq!(image.get_pixel(0, 0), 0x33_2211);
+        assert_eq!(image.get_pixel(1, 0), 0x66_5544);
+    }
+
     #[test]
     fn put_pixel_depth1() {
         let mut image = Image::allocate(
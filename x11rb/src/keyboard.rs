@@ -0,0 +1,1019 @@
+//! Helpers for working with the core protocol's keyboard state.
+//!
+//! Events like `KeyPress` or `ButtonPress` carry the current modifier state as a [`KeyButMask`].
+//! When comparing this state against a configured key binding, the state of "lock" modifiers like
+//! CapsLock or NumLock usually has to be ignored. Which modifier bit represents NumLock is not
+//! fixed by the protocol, but is instead described by the server's modifier mapping.
+//!
+//! [`KeyboardMapping`] translates keycodes into keysyms. [`KeyboardCache`] wraps a connection
+//! and keeps such a mapping up to date by watching for `MappingNotify` events.
+//!
+//! With the `xkb` feature, this module also contains [`VirtualModMap`] for translating between
+//! the real modifiers used by the core protocol and XKB's virtual modifiers.
+
+use std::io::IoSlice;
+use std::sync::Mutex;
+
+use crate::connection::{
+    BufWithFds, Connection, RawEventAndSeqNumber, ReplyOrError, RequestConnection, RequestKind,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    ConnectionExt as _, GetKeyboardMappingReply, GetModifierMappingReply, KeyButMask, Keycode,
+    Keysym, MapIndex, Mapping, MappingNotifyEvent, MappingStatus, Setup, Timestamp,
+    MAPPING_NOTIFY_EVENT,
+};
+use crate::protocol::Event;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+#[cfg(feature = "xkb")]
+use crate::protocol::{
+    xkb::{GetMapReply, VMod},
+    xproto::ModMask,
+};
+use x11rb_protocol::{DiscardMode, SequenceNumber};
+
+/// The keysym that is used for keys without a keysym.
+const NO_SYMBOL: Keysym = 0;
+/// The keysym of the `Mode_switch` key.
+const XK_MODE_SWITCH: Keysym = 0xff7e;
+/// The keysym of the `Caps_Lock` key.
+const XK_CAPS_LOCK: Keysym = 0xffe5;
+/// The keysym of the `Shift_Lock` key.
+const XK_SHIFT_LOCK: Keysym = 0xffe6;
+/// The first keysym of the keypad, `KP_Space`.
+const XK_KP_SPACE: Keysym = 0xff80;
+/// The last keysym of the keypad, `KP_Equal`.
+const XK_KP_EQUAL: Keysym = 0xffbd;
+/// The keysym of the `Num_Lock` key.
+const XK_NUM_LOCK: Keysym = 0xff7f;
+/// The keysym of the `Scroll_Lock` key.
+const XK_SCROLL_LOCK: Keysym = 0xff14;
+
+/// The bits of a [`KeyButMask`] that belong to one of the eight modifiers.
+const MODIFIER_BITS: [KeyButMask; 8] = [
+    KeyButMask::SHIFT,
+    KeyButMask::LOCK,
+    KeyButMask::CONTROL,
+    KeyButMask::MOD1,
+    KeyButMask::MOD2,
+    KeyButMask::MOD3,
+    KeyButMask::MOD4,
+    KeyButMask::MOD5,
+];
+
+/// Remove the bits in `ignore` from the given modifier `state`.
+///
+/// This is useful for matching key bindings modulo lock modifiers:
+/// ```
+/// use x11rb::keyboard::effective_mods;
+/// use x11rb::protocol::xproto::KeyButMask;
+///
+/// let state = KeyButMask::CONTROL | KeyButMask::LOCK | KeyButMask::MOD2;
+/// let locks = KeyButMask::LOCK | KeyButMask::MOD2;
+/// assert_eq!(effective_mods(state, locks), KeyButMask::CONTROL);
+/// ```
+pub fn effective_mods(state: KeyButMask, ignore: KeyButMask) -> KeyButMask {
+    KeyButMask::from(u16::from(state) & !u16::from(ignore))
+}
+
+/// Compute the modifiers that act as lock modifiers.
+///
+/// The result always contains [`KeyButMask::LOCK`]. Additionally, all modifiers that have a key
+/// with the `Num_Lock` or `Scroll_Lock` keysyms assigned in the modifier mapping are included.
+///
+/// `keyboard_mapping` must describe the keyboard mapping starting at `min_keycode`, which is
+/// usually the `min_keycode` of the connection's `Setup`. The result can be passed to
+/// [`effective_mods`].
+pub fn lock_mods(
+    modifier_mapping: &GetModifierMappingReply,
+    keyboard_mapping: &GetKeyboardMappingReply,
+    min_keycode: Keycode,
+) -> KeyButMask {
+    KeyButMask::LOCK
+        | mods_with_keysyms(
+            modifier_mapping,
+            keyboard_mapping,
+            min_keycode,
+            &[XK_NUM_LOCK, XK_SCROLL_LOCK],
+        )
+}
+
+/// Get the keysyms that are assigned to the given keycode.
+fn keycode_keysyms(
+    keyboard_mapping: &GetKeyboardMappingReply,
+    min_keycode: Keycode,
+    keycode: Keycode,
+) -> &[Keysym] {
+    let per_keycode = usize::from(keyboard_mapping.keysyms_per_keycode);
+    if per_keycode == 0 || keycode < min_keycode {
+        return &[];
+    }
+    let start = usize::from(keycode - min_keycode) * per_keycode;
+    keyboard_mapping
+        .keysyms
+        .get(start..start + per_keycode)
+        .unwrap_or(&[])
+}
+
+/// Compute the modifiers that have a key with one of the given keysyms assigned.
+fn mods_with_keysyms(
+    modifier_mapping: &GetModifierMappingReply,
+    keyboard_mapping: &GetKeyboardMappingReply,
+    min_keycode: Keycode,
+    keysyms: &[Keysym],
+) -> KeyButMask {
+    let has_keysym = |keycode: Keycode| {
+        keycode_keysyms(keyboard_mapping, min_keycode, keycode)
+            .iter()
+            .any(|sym| keysyms.contains(sym))
+    };
+
+    let per_modifier = usize::from(modifier_mapping.keycodes_per_modifier());
+    let mut result = KeyButMask::default();
+    if per_modifier == 0 {
+        return result;
+    }
+    for (keycodes, &bit) in modifier_mapping
+        .keycodes
+        .chunks(per_modifier)
+        .zip(MODIFIER_BITS.iter())
+    {
+        if keycodes
+            .iter()
+            .any(|&keycode| keycode != 0 && has_keysym(keycode))
+        {
+            result |= bit;
+        }
+    }
+    result
+}
+
+/// A builder for the modifier mapping that is set with a `SetModifierMapping` request.
+///
+/// The X11 server expects the modifier mapping as eight rows of keycodes, one row for each
+/// modifier, where all rows have the same length and unused entries are zero. This builder
+/// collects the keycodes for each modifier and produces that layout.
+///
+/// ```
+/// use x11rb::keyboard::ModifierMappingBuilder;
+/// use x11rb::protocol::xproto::MapIndex;
+///
+/// let mapping = ModifierMappingBuilder::new()
+///     .add(MapIndex::SHIFT, 50)
+///     .add(MapIndex::SHIFT, 62)
+///     .add(MapIndex::CONTROL, 37);
+/// assert_eq!(
+///     mapping.keycodes(),
+///     [50, 62, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModifierMappingBuilder {
+    rows: [Vec<Keycode>; 8],
+}
+
+impl ModifierMappingBuilder {
+    /// Create a new builder with all modifiers unassigned.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a new builder that starts from the current modifier mapping.
+    pub fn from_reply(reply: &GetModifierMappingReply) -> Self {
+        let mut result = Self::new();
+        let per_modifier = usize::from(reply.keycodes_per_modifier());
+        if per_modifier != 0 {
+            for (row, keycodes) in result
+                .rows
+                .iter_mut()
+                .zip(reply.keycodes.chunks(per_modifier))
+            {
+                row.extend(keycodes.iter().copied().filter(|&keycode| keycode != 0));
+            }
+        }
+        result
+    }
+
+    /// Assign the given keycode to a modifier.
+    ///
+    /// Adding a keycode that is already assigned to the modifier does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modifier` is not one of the eight modifiers defined by [`MapIndex`].
+    pub fn add(mut self, modifier: MapIndex, keycode: Keycode) -> Self {
+        let row = &mut self.rows[usize::from(u8::from(modifier))];
+        if keycode != 0 && !row.contains(&keycode) {
+            row.push(keycode);
+        }
+        self
+    }
+
+    /// Remove the given keycode from a modifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modifier` is not one of the eight modifiers defined by [`MapIndex`].
+    pub fn remove(mut self, modifier: MapIndex, keycode: Keycode) -> Self {
+        self.rows[usize::from(u8::from(modifier))].retain(|&k| k != keycode);
+        self
+    }
+
+    /// Remove all keycodes from a modifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modifier` is not one of the eight modifiers defined by [`MapIndex`].
+    pub fn clear(mut self, modifier: MapIndex) -> Self {
+        self.rows[usize::from(u8::from(modifier))].clear();
+        self
+    }
+
+    /// Get the keycodes in the layout expected by `SetModifierMapping`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modifier has more than 255 keycodes assigned.
+    pub fn keycodes(&self) -> Vec<Keycode> {
+        let per_modifier = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        assert!(per_modifier <= 255, "Too many keycodes for a modifier");
+        let mut result = Vec::with_capacity(8 * per_modifier);
+        for row in self.rows.iter() {
+            result.extend(row);
+            result.resize(result.len() + per_modifier - row.len(), 0);
+        }
+        result
+    }
+
+    /// Send a `SetModifierMapping` request with this mapping and wait for its reply.
+    ///
+    /// The server refuses to change the mapping while one of the affected keys is pressed. In
+    /// this case [`MappingStatus::BUSY`] is returned and the request can be retried later.
+    /// [`MappingStatus::FAILURE`] is returned if the server does not allow a modifier mapping
+    /// with the given keycodes.
+    pub fn apply<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+    ) -> Result<MappingStatus, ReplyError> {
+        Ok(conn.set_modifier_mapping(&self.keycodes())?.reply()?.status)
+    }
+}
+
+/// How the `Lock` modifier affects the keysym of a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockMeaning {
+    None,
+    CapsLock,
+    ShiftLock,
+}
+
+/// Get the uppercase variant of a Latin-1 keysym.
+fn to_upper(sym: Keysym) -> Keysym {
+    match sym {
+        0x61..=0x7a | 0xe0..=0xf6 | 0xf8..=0xfe => sym - 0x20,
+        _ => sym,
+    }
+}
+
+/// Get the lowercase variant of a Latin-1 keysym.
+fn to_lower(sym: Keysym) -> Keysym {
+    match sym {
+        0x41..=0x5a | 0xc0..=0xd6 | 0xd8..=0xde => sym + 0x20,
+        _ => sym,
+    }
+}
+
+/// Check if the given keysym belongs to the keypad.
+fn is_keypad(sym: Keysym) -> bool {
+    (XK_KP_SPACE..=XK_KP_EQUAL).contains(&sym)
+}
+
+/// The keyboard and modifier mapping of the core protocol.
+///
+/// This translates keycodes into keysyms without the XKB extension. The keyboard mapping is the
+/// reply to a `GetKeyboardMapping` request that starts at `min_keycode`, which is usually the
+/// `min_keycode` of the connection's `Setup`. The modifier mapping describes which modifiers act
+/// as `Mode_switch`, `Num_Lock`, and `Caps_Lock` or `Shift_Lock`.
+///
+/// ```
+/// use x11rb::keyboard::KeyboardMapping;
+/// use x11rb::protocol::xproto::{GetKeyboardMappingReply, GetModifierMappingReply, KeyButMask};
+///
+/// let keyboard = GetKeyboardMappingReply {
+///     keysyms_per_keycode: 2,
+///     // The keycodes 8 and 9 with the keysyms "a"/"A" and "1"/"!"
+///     keysyms: vec![0x61, 0x41, 0x31, 0x21],
+///     ..Default::default()
+/// };
+/// let mapping = KeyboardMapping::new(keyboard, &GetModifierMappingReply::default(), 8);
+/// assert_eq!(mapping.keysym(9, 1), Some(0x21));
+/// assert_eq!(mapping.keysym(10, 0), None);
+/// assert_eq!(mapping.lookup(8, KeyButMask::SHIFT), Some(0x41));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyboardMapping {
+    keyboard: GetKeyboardMappingReply,
+    min_keycode: Keycode,
+    mode_switch: KeyButMask,
+    num_lock: KeyButMask,
+    lock: LockMeaning,
+}
+
+impl KeyboardMapping {
+    /// Create a mapping from the replies to `GetKeyboardMapping` and `GetModifierMapping`.
+    pub fn new(
+        keyboard: GetKeyboardMappingReply,
+        modifiers: &GetModifierMappingReply,
+        min_keycode: Keycode,
+    ) -> Self {
+        let mods = |keysym| mods_with_keysyms(modifiers, &keyboard, min_keycode, &[keysym]);
+        let lock = if mods(XK_CAPS_LOCK).contains(KeyButMask::LOCK) {
+            LockMeaning::CapsLock
+        } else if mods(XK_SHIFT_LOCK).contains(KeyButMask::LOCK) {
+            LockMeaning::ShiftLock
+        } else {
+            LockMeaning::None
+        };
+        Self {
+            mode_switch: mods(XK_MODE_SWITCH),
+            num_lock: mods(XK_NUM_LOCK),
+            lock,
+            keyboard,
+            min_keycode,
+        }
+    }
+
+    /// Get the keysym in the given column of the keyboard mapping.
+    ///
+    /// `None` is returned if the keycode or the column are outside of the keyboard mapping or if
+    /// no keysym is assigned there.
+    pub fn keysym(&self, keycode: Keycode, column: usize) -> Option<Keysym> {
+        keycode_keysyms(&self.keyboard, self.min_keycode, keycode)
+            .get(column)
+            .copied()
+            .filter(|&sym| sym != NO_SYMBOL)
+    }
+
+    /// Compute the keysym of a key according to the rules of the core protocol.
+    ///
+    /// `state` usually comes from a `KeyPress` or `KeyRelease` event. The group is selected by
+    /// the `Mode_switch` modifier and the keysym within the group by `Shift`, `Lock`, and
+    /// `Num_Lock`. See section 5, "Keyboards", of the X11 protocol specification. Case conversion
+    /// is limited to Latin-1 keysyms.
+    pub fn lookup(&self, keycode: Keycode, state: KeyButMask) -> Option<Keysym> {
+        let syms = keycode_keysyms(&self.keyboard, self.min_keycode, keycode);
+        let len = syms.iter().rposition(|&sym| sym != NO_SYMBOL)? + 1;
+        let column = |index: usize| syms.get(index).copied().unwrap_or(NO_SYMBOL);
+        let list = match len {
+            1 => [syms[0], NO_SYMBOL, syms[0], NO_SYMBOL],
+            2 => [syms[0], syms[1], syms[0], syms[1]],
+            _ => [column(0), column(1), column(2), column(3)],
+        };
+        let group = if state & self.mode_switch != KeyButMask::default() {
+            &list[2..]
+        } else {
+            &list[..2]
+        };
+        let (first, second) = match (group[0], group[1]) {
+            (first, NO_SYMBOL) if to_upper(first) != to_lower(first) => {
+                (to_lower(first), to_upper(first))
+            }
+            (first, NO_SYMBOL) => (first, first),
+            pair => pair,
+        };
+
+        let shift = state.contains(KeyButMask::SHIFT);
+        let lock = state.contains(KeyButMask::LOCK);
+        let sym = if state & self.num_lock != KeyButMask::default() && is_keypad(second) {
+            if shift || (lock && self.lock == LockMeaning::ShiftLock) {
+                first
+            } else {
+                second
+            }
+        } else {
+            match (shift, lock, self.lock) {
+                (false, false, _) | (false, true, LockMeaning::None) => first,
+                (false, true, LockMeaning::CapsLock) => to_upper(first),
+                (true, true, LockMeaning::CapsLock) => to_upper(second),
+                _ => second,
+            }
+        };
+        Some(sym).filter(|&sym| sym != NO_SYMBOL)
+    }
+}
+
+/// The cached mappings of a [`KeyboardCache`]. `None` means that the mapping has to be fetched.
+#[derive(Debug, Default)]
+struct CachedMappings {
+    keyboard: Option<GetKeyboardMappingReply>,
+    modifiers: Option<GetModifierMappingReply>,
+    mapping: Option<KeyboardMapping>,
+}
+
+/// A connection wrapper that keeps the keyboard mapping up to date.
+///
+/// Translating keycodes into keysyms requires the keyboard and modifier mapping of the server.
+/// When these mappings change, the server sends a `MappingNotify` event and clients have to fetch
+/// the new mappings. This wrapper looks at all events that are received through it and forgets
+/// the affected mapping when it sees a `MappingNotify` event. The event is still returned to the
+/// application. The next call to [`KeyboardCache::keysym`] then fetches the new mapping.
+///
+/// Since this type implements [`Connection`], it can be used everywhere a connection is expected.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::keyboard::KeyboardCache;
+/// use x11rb::protocol::Event;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, _) = x11rb::connect(None)?;
+/// let conn = KeyboardCache::new(conn);
+/// loop {
+///     if let Event::KeyPress(event) = conn.wait_for_event()? {
+///         println!("keysym: {:?}", conn.keysym(event.detail, event.state)?);
+///     }
+/// }
+/// # }
+/// ```
+///
+/// Only the core protocol's rules for selecting a keysym are implemented. Case conversion is
+/// limited to Latin-1 keysyms.
+#[derive(Debug)]
+pub struct KeyboardCache<C> {
+    conn: C,
+    cache: Mutex<CachedMappings>,
+}
+
+impl<C> KeyboardCache<C> {
+    /// Start caching the keyboard mapping of the given connection.
+    ///
+    /// The mappings are only fetched when they are first needed.
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            cache: Default::default(),
+        }
+    }
+
+    /// Get a reference to the wrapped connection.
+    ///
+    /// Events that are received directly through this reference do not update the cache.
+    pub fn inner(&self) -> &C {
+        &self.conn
+    }
+
+    /// Stop caching and get the wrapped connection back.
+    pub fn into_inner(self) -> C {
+        self.conn
+    }
+
+    /// Forget the cached mappings, so that they are fetched again when they are next needed.
+    pub fn invalidate(&self) {
+        *self.cache.lock().unwrap() = Default::default();
+    }
+
+    /// Forget the mappings that are affected by the given raw event.
+    fn handle_event(&self, event: &[u8]) {
+        let event = match MappingNotifyEvent::try_parse(event) {
+            Ok((event, _)) if event.response_type & 0x7f == MAPPING_NOTIFY_EVENT => event,
+            _ => return,
+        };
+        let mut cache = self.cache.lock().unwrap();
+        match event.request {
+            Mapping::MODIFIER => cache.modifiers = None,
+            Mapping::KEYBOARD => cache.keyboard = None,
+            _ => return,
+        }
+        cache.mapping = None;
+    }
+}
+
+impl<C: Connection> KeyboardCache<C> {
+    /// Get the keysym of a key for the given modifier state.
+    ///
+    /// `keycode` and `state` usually come from a `KeyPress` or `KeyRelease` event. If the
+    /// keyboard or modifier mapping is not known, it is fetched from the server first. `None` is
+    /// returned if no keysym is assigned to the key.
+    pub fn keysym(
+        &self,
+        keycode: Keycode,
+        state: KeyButMask,
+    ) -> Result<Option<Keysym>, ReplyError> {
+        let mut cache = self.cache.lock().unwrap();
+        if cache.mapping.is_none() {
+            let setup = self.conn.setup();
+            let keyboard = match cache.keyboard.take() {
+                Some(keyboard) => keyboard,
+                None => {
+                    let count = setup.max_keycode - setup.min_keycode + 1;
+                    self.conn
+                        .get_keyboard_mapping(setup.min_keycode, count)?
+                        .reply()?
+                }
+            };
+            let modifiers = match cache.modifiers.take() {
+                Some(modifiers) => modifiers,
+                None => self.conn.get_modifier_mapping()?.reply()?,
+            };
+            let mapping = KeyboardMapping::new(keyboard.clone(), &modifiers, setup.min_keycode);
+            cache.keyboard = Some(keyboard);
+            cache.modifiers = Some(modifiers);
+            cache.mapping = Some(mapping);
+        }
+        Ok(cache
+            .mapping
+            .as_ref()
+            .and_then(|mapping| mapping.lookup(keycode, state)))
+    }
+}
+
+impl<C: RequestConnection> RequestConnection for KeyboardCache<C> {
+    type Buf = C::Buf;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        let cookie = self.conn.send_request_with_reply::<R>(bufs, fds)?;
+        Ok(Cookie::new(self, cookie.into_sequence_number()))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        let cookie = self.conn.send_request_with_reply_with_fds::<R>(bufs, fds)?;
+        Ok(CookieWithFds::new(self, cookie.into_sequence_number()))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        let cookie = self.conn.send_request_without_reply(bufs, fds)?;
+        Ok(VoidCookie::new(self, cookie.into_sequence_number()))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.extension_information(extension_name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.conn.check_for_raw_error(sequence)
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.conn.parse_event(event)
+    }
+}
+
+impl<C: Connection> Connection for KeyboardCache<C> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
+        let (event, sequence) = self.conn.wait_for_raw_event_with_sequence()?;
+        self.handle_event(event.as_ref());
+        Ok((event, sequence))
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError> {
+        let result = self.conn.poll_for_raw_event_with_sequence()?;
+        if let Some((event, _)) = &result {
+            self.handle_event(event.as_ref());
+        }
+        Ok(result)
+    }
+
+    fn requeue_raw_events(&self, events: Vec<RawEventAndSeqNumber<Self::Buf>>) {
+        self.conn.requeue_raw_events(events)
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.conn.flush()
+    }
+
+    fn last_event_time(&self) -> Timestamp {
+        self.conn.last_event_time()
+    }
+
+    fn supports_fd_passing(&self) -> bool {
+        self.conn.supports_fd_passing()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.conn.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.conn.generate_id()
+    }
+}
+
+/// Get the real modifiers from the state of a core event.
+///
+/// XKB describes the real modifiers with a [`ModMask`], which has the same bits as the modifier
+/// part of a [`KeyButMask`]. The state of the pointer buttons is dropped.
+#[cfg(feature = "xkb")]
+pub fn core_to_xkb_mods(state: KeyButMask) -> ModMask {
+    ModMask::from(u16::from(state) & 0xff)
+}
+
+/// Convert real modifiers from XKB to the modifier state that core events carry.
+///
+/// Only the eight real modifiers are kept; [`ModMask::ANY`] has no equivalent in a core event.
+#[cfg(feature = "xkb")]
+pub fn xkb_to_core_mods(mods: ModMask) -> KeyButMask {
+    KeyButMask::from(u16::from(mods) & 0xff)
+}
+
+/// The mapping from XKB's sixteen virtual modifiers to real modifiers.
+///
+/// XKB state is partly described in terms of virtual modifiers, while core events only know real
+/// modifiers. Each virtual modifier is bound to a set of real modifiers by the keyboard's
+/// modifier map, which is part of the reply to `xkb::GetMap` when [`MapPart::VIRTUAL_MODS`] is
+/// requested.
+///
+/// [`MapPart::VIRTUAL_MODS`]: crate::protocol::xkb::MapPart::VIRTUAL_MODS
+///
+/// ```
+/// use x11rb::keyboard::VirtualModMap;
+/// use x11rb::protocol::xkb::VMod;
+/// use x11rb::protocol::xproto::ModMask;
+///
+/// // Virtual modifier 0 is bound to Mod2, virtual modifier 3 to Mod1
+/// let map = VirtualModMap::new(VMod::M0 | VMod::M3, &[ModMask::M2, ModMask::M1]);
+/// assert_eq!(map.to_real(VMod::M3), ModMask::M1);
+/// assert_eq!(map.to_virtual(ModMask::M2 | ModMask::SHIFT), VMod::M0);
+/// ```
+#[cfg(feature = "xkb")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VirtualModMap {
+    real: [u16; 16],
+}
+
+#[cfg(feature = "xkb")]
+impl VirtualModMap {
+    /// Create a mapping from the list of real modifiers for the given virtual modifiers.
+    ///
+    /// `real` contains one entry for each bit that is set in `virtual_mods`, starting with the
+    /// lowest bit. This is the layout of the `virtual_mods` and `vmods_rtrn` fields of
+    /// [`GetMapReply`]. Virtual modifiers without an entry are not bound to any real modifier.
+    pub fn new(virtual_mods: VMod, real: &[ModMask]) -> Self {
+        let mut result = Self::default();
+        let virtual_mods = u16::from(virtual_mods);
+        let bits = (0..16).filter(|bit| virtual_mods & (1 << bit) != 0);
+        for (bit, &mods) in bits.zip(real) {
+            result.real[bit] = u16::from(mods);
+        }
+        result
+    }
+
+    /// Create a mapping from the reply to `xkb::GetMap`.
+    ///
+    /// If the reply does not contain the virtual modifier bindings, all virtual modifiers are
+    /// unbound.
+    pub fn from_reply(reply: &GetMapReply) -> Self {
+        match &reply.map.vmods_rtrn {
+            Some(real) => Self::new(reply.virtual_mods, real),
+            None => Self::default(),
+        }
+    }
+
+    /// Get the real modifiers that the given virtual modifiers are bound to.
+    pub fn to_real(&self, vmods: VMod) -> ModMask {
+        let vmods = u16::from(vmods);
+        let real = (0..16)
+            .filter(|bit| vmods & (1 << bit) != 0)
+            .fold(0, |acc, bit| acc | self.real[bit]);
+        ModMask::from(real)
+    }
+
+    /// Get the virtual modifiers that are active for the given real modifiers.
+    ///
+    /// A virtual modifier is active if it is bound to at least one real modifier and all of its
+    /// real modifiers are set in `mods`.
+    pub fn to_virtual(&self, mods: ModMask) -> VMod {
+        let mods = u16::from(mods);
+        let vmods = (0..16)
+            .filter(|&bit| self.real[bit] != 0 && self.real[bit] & !mods == 0)
+            .fold(0u16, |acc, bit| acc | (1 << bit));
+        VMod::from(vmods)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        effective_mods, lock_mods, KeyboardCache, KeyboardMapping, ModifierMappingBuilder,
+        XK_CAPS_LOCK, XK_MODE_SWITCH, XK_NUM_LOCK,
+    };
+    use crate::protocol::xproto::{
+        GetKeyboardMappingReply, GetModifierMappingReply, KeyButMask, MapIndex, Mapping,
+        MappingNotifyEvent, MAPPING_NOTIFY_EVENT,
+    };
+    use crate::x11_utils::Serialize;
+
+    #[test]
+    fn test_effective_mods() {
+        let state = KeyButMask::SHIFT | KeyButMask::LOCK | KeyButMask::BUTTON1;
+        assert_eq!(
+            effective_mods(state, KeyButMask::LOCK),
+            KeyButMask::SHIFT | KeyButMask::BUTTON1
+        );
+        assert_eq!(effective_mods(state, KeyButMask::MOD5), state);
+        assert_eq!(effective_mods(state, state), KeyButMask::from(0u16));
+    }
+
+    #[test]
+    fn test_lock_mods() {
+        // Two keycodes per modifier; keycode 12 is in mod2
+        let mut keycodes = vec![0; 16];
+        keycodes[9] = 11;
+        keycodes[8] = 12;
+        let modifier_mapping = GetModifierMappingReply {
+            sequence: 0,
+            length: 0,
+            keycodes,
+        };
+        // Keycodes 10, 11, 12 with two keysyms each; keycode 12 is Num_Lock
+        let keyboard_mapping = GetKeyboardMappingReply {
+            keysyms_per_keycode: 2,
+            sequence: 0,
+            keysyms: vec![0x61, 0x41, 0x62, 0x42, XK_NUM_LOCK, 0],
+        };
+        assert_eq!(
+            lock_mods(&modifier_mapping, &keyboard_mapping, 10),
+            KeyButMask::LOCK | KeyButMask::MOD2
+        );
+    }
+
+    #[test]
+    fn test_modifier_mapping_builder() {
+        let reply = GetModifierMappingReply {
+            sequence: 0,
+            length: 0,
+            keycodes: vec![50, 62, 66, 0, 37, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        };
+        let builder = ModifierMappingBuilder::from_reply(&reply);
+        assert_eq!(builder.keycodes(), reply.keycodes);
+
+        // Move keycode 66 from Lock to Control
+        let builder = builder
+            .clear(MapIndex::LOCK)
+            .add(MapIndex::CONTROL, 66)
+            .add(MapIndex::CONTROL, 66)
+            .remove(MapIndex::SHIFT, 62);
+        assert_eq!(
+            builder.keycodes(),
+            [50, 0, 0, 0, 0, 0, 37, 105, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert!(ModifierMappingBuilder::new().keycodes().is_empty());
+    }
+
+    #[cfg(feature = "xkb")]
+    #[test]
+    fn test_virtual_mod_map() {
+        use super::{core_to_xkb_mods, xkb_to_core_mods, VirtualModMap};
+        use crate::protocol::xkb::VMod;
+        use crate::protocol::xproto::ModMask;
+
+        let state = KeyButMask::CONTROL | KeyButMask::MOD2 | KeyButMask::BUTTON1;
+        let mods = core_to_xkb_mods(state);
+        assert_eq!(mods, ModMask::CONTROL | ModMask::M2);
+        assert_eq!(
+            xkb_to_core_mods(mods | ModMask::ANY),
+            KeyButMask::CONTROL | KeyButMask::MOD2
+        );
+
+        // M1 is unbound, M4 needs both Mod4 and Mod5
+        let map = VirtualModMap::new(
+            VMod::M1 | VMod::M2 | VMod::M4,
+            &[ModMask::from(0u16), ModMask::M2, ModMask::M4 | ModMask::M5],
+        );
+        assert_eq!(map.to_real(VMod::M1), ModMask::from(0u16));
+        assert_eq!(
+            map.to_real(VMod::M2 | VMod::M4),
+            ModMask::M2 | ModMask::M4 | ModMask::M5
+        );
+        assert_eq!(map.to_virtual(mods), VMod::M2);
+        assert_eq!(map.to_virtual(ModMask::M4), VMod::from(0u16));
+        assert_eq!(
+            map.to_virtual(ModMask::M2 | ModMask::M4 | ModMask::M5),
+            VMod::M2 | VMod::M4
+        );
+    }
+
+    #[test]
+    fn test_core_keysym() {
+        // Caps_Lock is in Lock, Mode_switch in Mod5 and Num_Lock in Mod2
+        let mut keycodes = vec![0; 8];
+        keycodes[1] = 13;
+        keycodes[7] = 14;
+        keycodes[4] = 15;
+        let modifiers = GetModifierMappingReply {
+            sequence: 0,
+            length: 0,
+            keycodes,
+        };
+        let keyboard = GetKeyboardMappingReply {
+            keysyms_per_keycode: 4,
+            sequence: 0,
+            keysyms: vec![
+                // 10: a, with an uppercase variant
+                0x61,
+                0,
+                0,
+                0, //
+                // 11: 1 and !, ¡ in the second group
+                0x31,
+                0x21,
+                0xa1,
+                0, //
+                // 12: KP_Home and KP_7
+                0xff95,
+                0xffb7,
+                0,
+                0, //
+                // 13 to 15: modifier keys
+                XK_CAPS_LOCK,
+                0,
+                0,
+                0, //
+                XK_MODE_SWITCH,
+                0,
+                0,
+                0, //
+                XK_NUM_LOCK,
+                0,
+                0,
+                0, //
+                // 16: no keysyms
+                0,
+                0,
+                0,
+                0,
+            ],
+        };
+        let mapping = KeyboardMapping::new(keyboard, &modifiers, 10);
+        let none = KeyButMask::default();
+        let keysym = |keycode, state| mapping.lookup(keycode, state);
+
+        assert_eq!(keysym(10, none), Some(0x61));
+        assert_eq!(keysym(10, KeyButMask::SHIFT), Some(0x41));
+        assert_eq!(keysym(10, KeyButMask::LOCK), Some(0x41));
+        assert_eq!(keysym(10, KeyButMask::SHIFT | KeyButMask::LOCK), Some(0x41));
+        // A single keysym is used for both groups
+        assert_eq!(keysym(10, KeyButMask::MOD5), Some(0x61));
+
+        assert_eq!(keysym(11, none), Some(0x31));
+        assert_eq!(keysym(11, KeyButMask::SHIFT), Some(0x21));
+        assert_eq!(keysym(11, KeyButMask::LOCK), Some(0x31));
+        assert_eq!(keysym(11, KeyButMask::MOD5), Some(0xa1));
+        assert_eq!(keysym(11, KeyButMask::MOD5 | KeyButMask::SHIFT), Some(0xa1));
+
+        assert_eq!(keysym(12, none), Some(0xff95));
+        assert_eq!(keysym(12, KeyButMask::MOD2), Some(0xffb7));
+        assert_eq!(
+            keysym(12, KeyButMask::MOD2 | KeyButMask::SHIFT),
+            Some(0xff95)
+        );
+
+        assert_eq!(keysym(16, none), None);
+        assert_eq!(keysym(9, none), None);
+        assert_eq!(keysym(17, none), None);
+
+        // Looking at the columns directly
+        assert_eq!(mapping.keysym(11, 2), Some(0xa1));
+        assert_eq!(mapping.keysym(11, 3), None);
+        assert_eq!(mapping.keysym(11, 4), None);
+        assert_eq!(mapping.keysym(9, 0), None);
+        assert_eq!(mapping.keysym(17, 0), None);
+    }
+
+    #[test]
+    fn test_keyboard_cache_invalidation() {
+        let keyboard = GetKeyboardMappingReply {
+            keysyms_per_keycode: 1,
+            sequence: 0,
+            keysyms: vec![0x61],
+        };
+        let modifiers = GetModifierMappingReply {
+            sequence: 0,
+            length: 0,
+            keycodes: vec![0; 8],
+        };
+        let cache = KeyboardCache::new(());
+        let fill = || {
+            let mut mappings = cache.cache.lock().unwrap();
+            mappings.mapping = Some(KeyboardMapping::new(keyboard.clone(), &modifiers, 8));
+            mappings.keyboard = Some(keyboard.clone());
+            mappings.modifiers = Some(modifiers.clone());
+        };
+        let event = |request| {
+            let event = MappingNotifyEvent {
+                response_type: MAPPING_NOTIFY_EVENT,
+                sequence: 0,
+                request,
+                first_keycode: 8,
+                count: 1,
+            };
+            // Events are always 32 bytes long
+            let mut event = event.serialize().to_vec();
+            event.resize(32, 0);
+            event
+        };
+
+        // Pointer mappings do not matter for the keyboard
+        fill();
+        cache.handle_event(&event(Mapping::POINTER));
+        assert!(cache.cache.lock().unwrap().mapping.is_some());
+
+        cache.handle_event(&event(Mapping::KEYBOARD));
+        {
+            let mappings = cache.cache.lock().unwrap();
+            assert!(mappings.mapping.is_none());
+            assert!(mappings.keyboard.is_none());
+            assert!(mappings.modifiers.is_some());
+        }
+
+        fill();
+        cache.handle_event(&event(Mapping::MODIFIER));
+        {
+            let mappings = cache.cache.lock().unwrap();
+            assert!(mappings.mapping.is_none());
+            assert!(mappings.keyboard.is_some());
+            assert!(mappings.modifiers.is_none());
+        }
+
+        // Other events are ignored
+        fill();
+        let mut other = event(Mapping::KEYBOARD);
+        other[0] = 2;
+        cache.handle_event(&other);
+        assert!(cache.cache.lock().unwrap().mapping.is_some());
+    }
+}
@@ -104,6 +104,10 @@
 //! * `resource_manager`: Enable the code in [crate::resource_manager] for loading and querying the
 //!   X11 resource database.
 //! * `image`: Enable the code in [crate::image] for working with pixel image data.
+//! * `parse_context`: Enable `x11rb_protocol::parse_context` for finding out where in a reply
+//!   parsing failed.
+//! * `resource_tracker`: Enable the code in [crate::resource_tracker] for keeping track of the
+//!   resources that a connection created.
 //! * `dl-libxcb`: Enabling this feature will prevent from libxcb being linked to the
 //!   resulting executable. Instead libxcb will be dynamically loaded at runtime.
 //!   This feature adds the [`crate::xcb_ffi::load_libxcb`] function, that allows to load
@@ -151,15 +155,21 @@ pub mod utils;
 pub mod xcb_ffi;
 #[macro_use]
 pub mod x11_utils;
+pub mod colormap;
 pub mod connection;
 pub mod cookie;
 #[cfg(feature = "cursor")]
 pub mod cursor;
+pub mod cursor_font;
 pub mod errors;
+pub mod event_loop;
 pub mod extension_manager;
 #[cfg(feature = "image")]
 pub mod image;
+pub mod keyboard;
+pub mod pointer;
 pub mod properties;
+pub mod request_log;
 pub mod rust_connection;
 pub mod wrapper;
 #[rustfmt::skip]
@@ -167,6 +177,8 @@ pub mod wrapper;
 pub mod protocol;
 #[cfg(feature = "resource_manager")]
 pub mod resource_manager;
+#[cfg(feature = "resource_tracker")]
+pub mod resource_tracker;
 #[cfg(test)]
 mod test;
 
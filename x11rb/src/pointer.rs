@@ -0,0 +1,418 @@
+//! Helpers for working with the pointer.
+//!
+//! [`PointerInfo`] collects the pointer state that all pointer events carry. The pointer
+//! acceleration can be queried and changed with [`get_pointer_acceleration`] and
+//! [`set_pointer_acceleration`], and [`ButtonGrabBuilder`] establishes passive button grabs.
+//! With the `xfixes` feature, `CursorTracker` follows the image of the cursor.
+
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{
+    ButtonIndex, ButtonPressEvent, ConnectionExt, Cursor, EnterNotifyEvent, EventMask, GrabMode,
+    KeyButMask, ModMask, MotionNotifyEvent, Timestamp, Window,
+};
+use crate::protocol::Event;
+
+/// The pointer state of an event, independent of the type of the event.
+///
+/// `ButtonPress`, `ButtonRelease`, `MotionNotify`, `EnterNotify`, and `LeaveNotify` events all
+/// describe the position of the pointer and the state of the modifiers and buttons. This struct
+/// contains these common fields, so that all pointer events can be handled in the same way:
+///
+/// ```
+/// use x11rb::protocol::xproto::{ButtonPressEvent, MotionNotifyEvent};
+/// use x11rb::pointer::PointerInfo;
+///
+/// let press = ButtonPressEvent { event_x: 10, event_y: 20, ..Default::default() };
+/// let motion = MotionNotifyEvent { event_x: 10, event_y: 20, ..Default::default() };
+/// assert_eq!(PointerInfo::from(&press), PointerInfo::from(&motion));
+/// assert_eq!(PointerInfo::from(&press).event_position(), (10, 20));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointerInfo {
+    /// The server time when the event was generated.
+    pub time: Timestamp,
+    /// The root window of the screen that the pointer is on.
+    pub root: Window,
+    /// The window that the event is reported relative to.
+    pub event: Window,
+    /// The child of `event` that contains the pointer, or `NONE`.
+    pub child: Window,
+    /// The pointer position relative to `root`.
+    pub root_x: i16,
+    /// The pointer position relative to `root`.
+    pub root_y: i16,
+    /// The pointer position relative to `event`.
+    pub event_x: i16,
+    /// The pointer position relative to `event`.
+    pub event_y: i16,
+    /// The state of the modifiers and pointer buttons before the event.
+    pub state: KeyButMask,
+    /// Whether `event` is on the same screen as `root`.
+    ///
+    /// If this is `false`, `event_x`, `event_y`, and `child` are zero.
+    pub same_screen: bool,
+}
+
+impl PointerInfo {
+    /// Get the pointer information from an event, if it has any.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        Some(match event {
+            Event::ButtonPress(e) | Event::ButtonRelease(e) => e.into(),
+            Event::MotionNotify(e) => e.into(),
+            Event::EnterNotify(e) | Event::LeaveNotify(e) => e.into(),
+            _ => return None,
+        })
+    }
+
+    /// Get the pointer position relative to the root window.
+    pub fn root_position(&self) -> (i16, i16) {
+        (self.root_x, self.root_y)
+    }
+
+    /// Get the pointer position relative to the event window.
+    pub fn event_position(&self) -> (i16, i16) {
+        (self.event_x, self.event_y)
+    }
+}
+
+macro_rules! pointer_info_from {
+    ($event:ty, |$e:ident| $same_screen:expr) => {
+        impl From<&$event> for PointerInfo {
+            fn from($e: &$event) -> Self {
+                Self {
+                    time: $e.time,
+                    root: $e.root,
+                    event: $e.event,
+                    child: $e.child,
+                    root_x: $e.root_x,
+                    root_y: $e.root_y,
+                    event_x: $e.event_x,
+                    event_y: $e.event_y,
+                    state: $e.state,
+                    same_screen: $same_screen,
+                }
+            }
+        }
+    };
+}
+
+pointer_info_from!(ButtonPressEvent, |e| e.same_screen);
+pointer_info_from!(MotionNotifyEvent, |e| e.same_screen);
+// Bit 1 of same_screen_focus is the same-screen flag, bit 0 is the focus flag
+pointer_info_from!(EnterNotifyEvent, |e| e.same_screen_focus & 2 != 0);
+
+/// The acceleration of the pointer.
+///
+/// When the pointer moves by more than `threshold` pixels at once, the movement beyond the
+/// threshold is multiplied by `numerator / denominator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointerAcceleration {
+    /// The numerator of the acceleration factor.
+    pub numerator: u16,
+    /// The denominator of the acceleration factor.
+    pub denominator: u16,
+    /// The number of pixels that the pointer has to move at once before it is accelerated.
+    pub threshold: u16,
+}
+
+/// Get the current acceleration of the pointer via `GetPointerControl`.
+pub fn get_pointer_acceleration<C: ConnectionExt + ?Sized>(
+    conn: &C,
+) -> Result<PointerAcceleration, ReplyError> {
+    let reply = conn.get_pointer_control()?.reply()?;
+    Ok(PointerAcceleration {
+        numerator: reply.acceleration_numerator,
+        denominator: reply.acceleration_denominator,
+        threshold: reply.threshold,
+    })
+}
+
+/// Set the acceleration of the pointer via `ChangePointerControl`.
+///
+/// `ChangePointerControl` ignores the acceleration and the threshold unless its
+/// `do_acceleration` and `do_threshold` flags are set. This function always sets both flags, so
+/// that all given values are applied. A value of `-1` restores the server's default for the
+/// acceleration factor or the threshold, respectively. The X11 server reports a `Value` error
+/// for a `denominator` of zero and for other negative values.
+pub fn set_pointer_acceleration<C: ConnectionExt + ?Sized>(
+    conn: &C,
+    numerator: i16,
+    denominator: i16,
+    threshold: i16,
+) -> Result<VoidCookie<'_, C>, ConnectionError> {
+    conn.change_pointer_control(numerator, denominator, threshold, true, true)
+}
+
+/// A builder for passive button grabs via [crate::protocol::xproto::grab_button].
+///
+/// `GrabButton` has many arguments. This builder starts with defaults that fit the typical
+/// passive grab of a window manager, for example for click-to-focus:
+///
+/// - the grab applies for any modifiers,
+/// - `owner_events` is `false`,
+/// - button press and release events are reported,
+/// - the pointer and the keyboard are not frozen ([`GrabMode::ASYNC`]),
+/// - the pointer is not confined and the cursor is not changed.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::{ButtonIndex, GrabMode, Window};
+/// use x11rb::pointer::ButtonGrabBuilder;
+///
+/// fn click_to_focus(conn: &impl Connection, frame: Window) -> Result<(), Box<dyn std::error::Error>> {
+///     let grab = ButtonGrabBuilder::new(ButtonIndex::M1)
+///         .pointer_mode(GrabMode::SYNC)
+///         .grab(conn, frame)?;
+///     // ... handle clicks, replaying them with AllowEvents ...
+///     // Dropping the grab sends an `UngrabButton` request
+///     drop(grab);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonGrabBuilder {
+    button: ButtonIndex,
+    modifiers: ModMask,
+    owner_events: bool,
+    event_mask: EventMask,
+    pointer_mode: GrabMode,
+    keyboard_mode: GrabMode,
+    confine_to: Window,
+    cursor: Cursor,
+}
+
+impl ButtonGrabBuilder {
+    /// Start building a grab of the given button.
+    ///
+    /// [`ButtonIndex::ANY`] grabs all buttons.
+    pub fn new(button: ButtonIndex) -> Self {
+        Self {
+            button,
+            modifiers: ModMask::ANY,
+            owner_events: false,
+            event_mask: EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE,
+            pointer_mode: GrabMode::ASYNC,
+            keyboard_mode: GrabMode::ASYNC,
+            confine_to: crate::NONE,
+            cursor: crate::NONE,
+        }
+    }
+
+    /// Only grab the button while exactly the given modifiers are held.
+    pub fn modifiers(mut self, modifiers: ModMask) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// Report pointer events to the window that would normally get them.
+    pub fn owner_events(mut self, owner_events: bool) -> Self {
+        self.owner_events = owner_events;
+        self
+    }
+
+    /// Set the pointer events that are reported to the grab window.
+    pub fn event_mask(mut self, event_mask: EventMask) -> Self {
+        self.event_mask = event_mask;
+        self
+    }
+
+    /// Set how pointer events are processed while the grab is active.
+    pub fn pointer_mode(mut self, pointer_mode: GrabMode) -> Self {
+        self.pointer_mode = pointer_mode;
+        self
+    }
+
+    /// Set how keyboard events are processed while the grab is active.
+    pub fn keyboard_mode(mut self, keyboard_mode: GrabMode) -> Self {
+        self.keyboard_mode = keyboard_mode;
+        self
+    }
+
+    /// Confine the pointer to the given window while the grab is active.
+    pub fn confine_to(mut self, confine_to: Window) -> Self {
+        self.confine_to = confine_to;
+        self
+    }
+
+    /// Show the given cursor while the grab is active.
+    pub fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Establish the passive grab on the given window.
+    ///
+    /// The returned guard releases the grab with an `UngrabButton` request when it is dropped.
+    pub fn grab<'c, C: ConnectionExt>(
+        &self,
+        conn: &'c C,
+        grab_window: Window,
+    ) -> Result<ButtonGrab<'c, C>, ConnectionError> {
+        drop(conn.grab_button(
+            self.owner_events,
+            grab_window,
+            self.event_mask,
+            self.pointer_mode,
+            self.keyboard_mode,
+            self.confine_to,
+            self.cursor,
+            self.button,
+            self.modifiers,
+        )?);
+        Ok(ButtonGrab {
+            conn,
+            button: self.button,
+            grab_window,
+            modifiers: self.modifiers,
+        })
+    }
+}
+
+/// A passive button grab that was established with [`ButtonGrabBuilder::grab`].
+///
+/// When this struct is dropped, an [crate::protocol::xproto::ungrab_button] request is sent.
+/// Any errors during `Drop` are silently ignored.
+#[derive(Debug)]
+pub struct ButtonGrab<'c, C: ConnectionExt> {
+    conn: &'c C,
+    button: ButtonIndex,
+    grab_window: Window,
+    modifiers: ModMask,
+}
+
+impl<C: ConnectionExt> Drop for ButtonGrab<'_, C> {
+    fn drop(&mut self) {
+        let _ = self
+            .conn
+            .ungrab_button(self.button, self.grab_window, self.modifiers);
+    }
+}
+
+/// Keep track of the current cursor image via the XFIXES extension.
+///
+/// [`CursorTracker::new`] fetches the current cursor image and selects `CursorNotify` events on
+/// a root window. Afterwards, all events should be given to [`CursorTracker::handle_event`],
+/// which fetches the new image when the cursor changes. This is useful e.g. for screen
+/// recording, since screenshots via `GetImage` do not contain the cursor.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::pointer::CursorTracker;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, screen_num) = x11rb::connect(None)?;
+/// let root = conn.setup().roots[screen_num].root;
+/// let mut tracker = CursorTracker::new(&conn, root)?;
+/// loop {
+///     let event = conn.wait_for_event()?;
+///     if tracker.handle_event(&conn, &event)? {
+///         let image = tracker.image();
+///         println!("New cursor of size {}x{}", image.width, image.height);
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "xfixes")]
+#[derive(Debug, Clone)]
+pub struct CursorTracker {
+    image: crate::protocol::xfixes::GetCursorImageReply,
+}
+
+#[cfg(feature = "xfixes")]
+impl CursorTracker {
+    /// Start tracking the cursor that is shown on the screen of the given root window.
+    ///
+    /// This also negotiates the version of the XFIXES extension, which is required before any
+    /// other XFIXES request can be used.
+    pub fn new<C: ConnectionExt>(conn: &C, root: Window) -> Result<Self, ReplyError> {
+        use crate::protocol::xfixes::{ConnectionExt as _, CursorNotifyMask};
+
+        let _ = conn.xfixes_query_version(5, 0)?.reply()?;
+        conn.xfixes_select_cursor_input(root, CursorNotifyMask::DISPLAY_CURSOR)?
+            .check()?;
+        let image = conn.xfixes_get_cursor_image()?.reply()?;
+        Ok(Self { image })
+    }
+
+    /// Get the current cursor image.
+    ///
+    /// The `cursor_image` is a list of `width * height` pixels in row-major order. Each pixel
+    /// is a 32 bit ARGB value with premultiplied alpha. `xhot` and `yhot` are the position of
+    /// the hotspot within the image, and `x` and `y` are the position of the hotspot on the
+    /// screen when the image was fetched.
+    pub fn image(&self) -> &crate::protocol::xfixes::GetCursorImageReply {
+        &self.image
+    }
+
+    /// Handle an event.
+    ///
+    /// For a `CursorNotify` event about a cursor that differs from the current one, the new
+    /// cursor image is fetched. Returns whether the cursor image changed.
+    pub fn handle_event<C: ConnectionExt>(
+        &mut self,
+        conn: &C,
+        event: &Event,
+    ) -> Result<bool, ReplyError> {
+        use crate::protocol::xfixes::ConnectionExt as _;
+
+        match event {
+            Event::XfixesCursorNotify(event) if event.cursor_serial != self.image.cursor_serial => {
+                self.image = conn.xfixes_get_cursor_image()?.reply()?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ButtonGrabBuilder, PointerInfo};
+    use crate::protocol::xproto::{
+        ButtonIndex, EnterNotifyEvent, EventMask, GrabMode, KeyButMask, ModMask,
+    };
+    use crate::protocol::Event;
+
+    #[test]
+    fn test_button_grab_builder() {
+        let builder = ButtonGrabBuilder::new(ButtonIndex::M3);
+        assert_eq!(builder.modifiers, ModMask::ANY);
+        assert_eq!(builder.pointer_mode, GrabMode::ASYNC);
+        assert_eq!(builder.confine_to, 0);
+
+        let builder = builder
+            .modifiers(ModMask::M4)
+            .pointer_mode(GrabMode::SYNC)
+            .event_mask(EventMask::BUTTON_PRESS)
+            .cursor(42);
+        assert_eq!(builder.button, ButtonIndex::M3);
+        assert_eq!(builder.modifiers, ModMask::M4);
+        assert_eq!(builder.pointer_mode, GrabMode::SYNC);
+        assert_eq!(builder.keyboard_mode, GrabMode::ASYNC);
+        assert_eq!(builder.event_mask, EventMask::BUTTON_PRESS);
+        assert_eq!(builder.cursor, 42);
+    }
+
+    #[test]
+    fn test_pointer_info_from_event() {
+        let enter = EnterNotifyEvent {
+            root_x: 5,
+            root_y: 6,
+            state: KeyButMask::BUTTON1,
+            same_screen_focus: 3,
+            ..Default::default()
+        };
+        let info = PointerInfo::from_event(&Event::LeaveNotify(enter)).unwrap();
+        assert_eq!(info.root_position(), (5, 6));
+        assert_eq!(info.state, KeyButMask::BUTTON1);
+        assert!(info.same_screen);
+
+        let enter = EnterNotifyEvent {
+            same_screen_focus: 1,
+            ..enter
+        };
+        assert!(!PointerInfo::from(&enter).same_screen);
+        assert_eq!(PointerInfo::from_event(&Event::Unknown(Vec::new())), None);
+    }
+}
@@ -1,11 +1,18 @@
 //! Utility functions for working with X11 properties
 
-use std::convert::TryInto;
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::convert::{TryFrom, TryInto};
 
-use crate::connection::RequestConnection;
+use crate::connection::{Connection, RequestConnection};
 use crate::cookie::{Cookie, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError};
-use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, GetPropertyReply, Property, SelectionNotifyEvent,
+    Timestamp, Window,
+};
+use crate::protocol::Event;
+use crate::wrapper::ConnectionExt as _;
 use crate::x11_utils::{Serialize, TryParse};
 
 macro_rules! property_cookie {
@@ -655,6 +662,990 @@ impl Serialize for WmHints {
     }
 }
 
+// WM_PROTOCOLS
+
+/// The atoms that identify the `WM_PROTOCOLS` client messages that [`WmProtocol`] understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WmProtocolAtoms {
+    /// The atom `WM_PROTOCOLS`, which is the type of the client messages.
+    pub wm_protocols: Atom,
+    /// The atom `WM_DELETE_WINDOW`.
+    pub wm_delete_window: Atom,
+    /// The atom `WM_TAKE_FOCUS`.
+    pub wm_take_focus: Atom,
+    /// The atom `_NET_WM_PING`.
+    pub net_wm_ping: Atom,
+    /// The atom `_NET_WM_SYNC_REQUEST`.
+    pub net_wm_sync_request: Atom,
+}
+
+impl WmProtocolAtoms {
+    /// Intern all atoms with a single round trip to the X11 server.
+    pub fn intern<C: RequestConnection + ?Sized>(conn: &C) -> Result<Self, ReplyError> {
+        let wm_protocols = xproto::intern_atom(conn, false, b"WM_PROTOCOLS")?;
+        let wm_delete_window = xproto::intern_atom(conn, false, b"WM_DELETE_WINDOW")?;
+        let wm_take_focus = xproto::intern_atom(conn, false, b"WM_TAKE_FOCUS")?;
+        let net_wm_ping = xproto::intern_atom(conn, false, b"_NET_WM_PING")?;
+        let net_wm_sync_request = xproto::intern_atom(conn, false, b"_NET_WM_SYNC_REQUEST")?;
+        Ok(Self {
+            wm_protocols: wm_protocols.reply()?.atom,
+            wm_delete_window: wm_delete_window.reply()?.atom,
+            wm_take_focus: wm_take_focus.reply()?.atom,
+            net_wm_ping: net_wm_ping.reply()?.atom,
+            net_wm_sync_request: net_wm_sync_request.reply()?.atom,
+        })
+    }
+}
+
+/// A decoded `WM_PROTOCOLS` client message as described by ICCCM and EWMH.
+///
+/// ```
+/// use x11rb::properties::{WmProtocol, WmProtocolAtoms};
+/// use x11rb::protocol::xproto::ClientMessageEvent;
+///
+/// let atoms = WmProtocolAtoms {
+///     wm_protocols: 1,
+///     wm_delete_window: 2,
+///     wm_take_focus: 3,
+///     net_wm_ping: 4,
+///     net_wm_sync_request: 5,
+/// };
+/// let event = ClientMessageEvent::new(32, 0x1234, atoms.wm_protocols, [3, 42, 0, 0, 0]);
+/// assert_eq!(WmProtocol::from_event(&event, &atoms), Some(WmProtocol::TakeFocus(42)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WmProtocol {
+    /// `WM_DELETE_WINDOW`: The window should be closed.
+    DeleteWindow,
+    /// `WM_TAKE_FOCUS`: The window should take the input focus, using the given timestamp.
+    TakeFocus(Timestamp),
+    /// `_NET_WM_PING`: The window manager checks whether the client is still responsive.
+    ///
+    /// The client should send the event back to the root window of the screen. The fields are
+    /// the timestamp of the ping and the window that it concerns.
+    Ping(Timestamp, Window),
+    /// `_NET_WM_SYNC_REQUEST`: The window manager requests the given value to be set on the
+    /// `_NET_WM_SYNC_REQUEST_COUNTER` after the next redraw.
+    SyncRequest(Timestamp, u64),
+    /// A protocol that is not known, identified by its atom.
+    Other(Atom),
+}
+
+impl WmProtocol {
+    /// Decode a client message.
+    ///
+    /// `None` is returned if the message is not a `WM_PROTOCOLS` message with format 32.
+    pub fn from_event(event: &ClientMessageEvent, atoms: &WmProtocolAtoms) -> Option<Self> {
+        if event.type_ != atoms.wm_protocols || event.format != 32 {
+            return None;
+        }
+        let data = event.data.as_data32();
+        let protocol = match data[0] {
+            atom if atom == atoms.wm_delete_window => Self::DeleteWindow,
+            atom if atom == atoms.wm_take_focus => Self::TakeFocus(data[1]),
+            atom if atom == atoms.net_wm_ping => Self::Ping(data[1], data[2]),
+            atom if atom == atoms.net_wm_sync_request => {
+                Self::SyncRequest(data[1], u64::from(data[3]) << 32 | u64::from(data[2]))
+            }
+            atom => Self::Other(atom),
+        };
+        Some(protocol)
+    }
+}
+
+// _NET_WM_ICON
+
+/// Set the `_NET_WM_ICON` property of a window.
+///
+/// Each entry of `icons` is an icon of the form `(width, height, pixels)`, where `pixels` contains
+/// `width * height` pixels in ARGB format, row by row. The EWMH specification allows setting icons
+/// of different sizes so that the window manager can choose the most fitting one.
+///
+/// The `_NET_WM_ICON` atom is not predefined and must be interned by the caller, for example via
+/// [crate::atom_manager].
+///
+/// # Panics
+///
+/// Panics if the number of pixels of an icon does not match its size.
+pub fn set_wm_icon<'a, C: RequestConnection + ?Sized>(
+    conn: &'a C,
+    window: Window,
+    net_wm_icon: Atom,
+    icons: &[(u32, u32, &[u32])],
+) -> Result<VoidCookie<'a, C>, ConnectionError> {
+    conn.change_property32(
+        xproto::PropMode::REPLACE,
+        window,
+        net_wm_icon,
+        AtomEnum::CARDINAL,
+        &wm_icon_data(icons),
+    )
+}
+
+/// Pack icons into the format of the `_NET_WM_ICON` property.
+fn wm_icon_data(icons: &[(u32, u32, &[u32])]) -> Vec<u32> {
+    let len = icons.iter().map(|(_, _, pixels)| 2 + pixels.len()).sum();
+    let mut data = Vec::with_capacity(len);
+    for &(width, height, pixels) in icons {
+        let expected_len = u64::from(width) * u64::from(height);
+        assert_eq!(
+            Ok(expected_len),
+            pixels.len().try_into(),
+            "Icon size {}x{} does not match the number of pixels",
+            width,
+            height
+        );
+        data.push(width);
+        data.push(height);
+        data.extend_from_slice(pixels);
+    }
+    data
+}
+
+// _NET_WM_STRUT_PARTIAL
+
+/// The number of elements in a `_NET_WM_STRUT_PARTIAL` property.
+const NUM_WM_STRUT_PARTIAL_ELEMENTS: u32 = 12;
+
+/// A structure representing a `_NET_WM_STRUT_PARTIAL` property.
+///
+/// Panels and docks use this property to reserve space at the edges of the screen, so that the
+/// window manager does not place other windows there. The sizes are given in pixels from the
+/// respective edge of the root window. The start and end values describe which part of the edge
+/// is covered, e.g. `left_start_y` and `left_end_y` are the vertical range of the reserved space
+/// at the left edge.
+///
+/// This property is described in the EWMH specification. The `_NET_WM_STRUT_PARTIAL` atom is not
+/// predefined and must be interned by the caller, for example via [crate::atom_manager].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct WmStrutPartial {
+    /// The width of the reserved space at the left edge.
+    pub left: u32,
+    /// The width of the reserved space at the right edge.
+    pub right: u32,
+    /// The height of the reserved space at the top edge.
+    pub top: u32,
+    /// The height of the reserved space at the bottom edge.
+    pub bottom: u32,
+    /// The first y coordinate of the reserved space at the left edge.
+    pub left_start_y: u32,
+    /// The last y coordinate of the reserved space at the left edge.
+    pub left_end_y: u32,
+    /// The first y coordinate of the reserved space at the right edge.
+    pub right_start_y: u32,
+    /// The last y coordinate of the reserved space at the right edge.
+    pub right_end_y: u32,
+    /// The first x coordinate of the reserved space at the top edge.
+    pub top_start_x: u32,
+    /// The last x coordinate of the reserved space at the top edge.
+    pub top_end_x: u32,
+    /// The first x coordinate of the reserved space at the bottom edge.
+    pub bottom_start_x: u32,
+    /// The last x coordinate of the reserved space at the bottom edge.
+    pub bottom_end_x: u32,
+}
+
+impl WmStrutPartial {
+    /// Get a new, empty `WmStrutPartial` structure that does not reserve any space.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Construct a new `WmStrutPartial` instance from a `GetPropertyReply`.
+    ///
+    /// The original `GetProperty` request must have been for a `_NET_WM_STRUT_PARTIAL` property
+    /// for this function to return sensible results.
+    pub fn from_reply(reply: &GetPropertyReply) -> Result<Self, ParseError> {
+        if reply.type_ != AtomEnum::CARDINAL.into() || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(Self::try_parse(&reply.value)?.0)
+    }
+
+    /// Set this `_NET_WM_STRUT_PARTIAL` on some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+        net_wm_strut_partial: Atom,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let data = self.serialize();
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            net_wm_strut_partial,
+            AtomEnum::CARDINAL,
+            32,
+            NUM_WM_STRUT_PARTIAL_ELEMENTS,
+            &data,
+        )
+    }
+}
+
+impl TryParse for WmStrutPartial {
+    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (left, remaining) = u32::try_parse(remaining)?;
+        let (right, remaining) = u32::try_parse(remaining)?;
+        let (top, remaining) = u32::try_parse(remaining)?;
+        let (bottom, remaining) = u32::try_parse(remaining)?;
+        let (left_start_y, remaining) = u32::try_parse(remaining)?;
+        let (left_end_y, remaining) = u32::try_parse(remaining)?;
+        let (right_start_y, remaining) = u32::try_parse(remaining)?;
+        let (right_end_y, remaining) = u32::try_parse(remaining)?;
+        let (top_start_x, remaining) = u32::try_parse(remaining)?;
+        let (top_end_x, remaining) = u32::try_parse(remaining)?;
+        let (bottom_start_x, remaining) = u32::try_parse(remaining)?;
+        let (bottom_end_x, remaining) = u32::try_parse(remaining)?;
+        let result = WmStrutPartial {
+            left,
+            right,
+            top,
+            bottom,
+            left_start_y,
+            left_end_y,
+            right_start_y,
+            right_end_y,
+            top_start_x,
+            top_end_x,
+            bottom_start_x,
+            bottom_end_x,
+        };
+        Ok((result, remaining))
+    }
+}
+
+impl Serialize for WmStrutPartial {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Self::Bytes {
+        // 12*4 surely fits into an usize, so this unwrap() cannot trigger
+        let mut result =
+            Vec::with_capacity((NUM_WM_STRUT_PARTIAL_ELEMENTS * 4).try_into().unwrap());
+        self.serialize_into(&mut result);
+        result
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.left.serialize_into(bytes);
+        self.right.serialize_into(bytes);
+        self.top.serialize_into(bytes);
+        self.bottom.serialize_into(bytes);
+        self.left_start_y.serialize_into(bytes);
+        self.left_end_y.serialize_into(bytes);
+        self.right_start_y.serialize_into(bytes);
+        self.right_end_y.serialize_into(bytes);
+        self.top_start_x.serialize_into(bytes);
+        self.top_end_x.serialize_into(bytes);
+        self.bottom_start_x.serialize_into(bytes);
+        self.bottom_end_x.serialize_into(bytes);
+    }
+}
+
+// Lists of atoms
+
+/// Get the names of the atoms in a property of type `ATOM`.
+///
+/// This is useful for properties like `WM_PROTOCOLS` or `_NET_SUPPORTED`. All `GetAtomName`
+/// requests are sent immediately, so that only a single round trip to the X11 server is needed.
+/// The returned iterator produces the atoms together with their names in the order of the
+/// property.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::atom_names;
+/// use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, Window};
+///
+/// fn print_protocols(
+///     conn: &impl Connection,
+///     window: Window,
+///     wm_protocols: u32,
+/// ) -> Result<(), ReplyError> {
+///     let reply = conn
+///         .get_property(false, window, wm_protocols, AtomEnum::ATOM, 0, 1024)?
+///         .reply()?;
+///     for entry in atom_names(conn, &reply)? {
+///         let (atom, name) = entry?;
+///         println!("{}: {}", atom, name);
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// An error is returned if the property does not have type `ATOM` and format 32.
+pub fn atom_names<'c, C: RequestConnection + ?Sized>(
+    conn: &'c C,
+    reply: &GetPropertyReply,
+) -> Result<AtomNames<'c, C>, ReplyError> {
+    let atoms = match reply.value32() {
+        Some(atoms) if reply.type_ == AtomEnum::ATOM.into() => atoms,
+        _ => return Err(ParseError::InvalidValue.into()),
+    };
+    let cookies = atoms
+        .map(|atom| Ok((atom, xproto::get_atom_name(conn, atom)?)))
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    Ok(AtomNames(cookies.into_iter()))
+}
+
+/// An iterator over the atoms of a property and their names.
+///
+/// See [`atom_names`].
+#[derive(Debug)]
+pub struct AtomNames<'c, C: RequestConnection + ?Sized>(
+    std::vec::IntoIter<(Atom, Cookie<'c, C, xproto::GetAtomNameReply>)>,
+);
+
+impl<C: RequestConnection + ?Sized> Iterator for AtomNames<'_, C> {
+    type Item = Result<(Atom, String), ReplyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (atom, cookie) = self.0.next()?;
+        Some(cookie.reply().map(|reply| {
+            // Atom names are Latin-1, which agrees with UTF-8 for ASCII
+            (atom, String::from_utf8_lossy(&reply.name).into_owned())
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<C: RequestConnection + ?Sized> ExactSizeIterator for AtomNames<'_, C> {}
+
+/// A cache of atom names that resolves new atoms in batches.
+///
+/// Atoms that are not yet known are queued with [`AtomResolver::request`]. [`AtomResolver::flush`]
+/// sends `GetAtomName` requests for all queued atoms at once and waits for their replies, so that
+/// a whole batch of atoms only costs a single round trip to the X11 server. Afterwards, the names
+/// are available via [`AtomResolver::name`].
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::AtomResolver;
+/// use x11rb::protocol::Event;
+///
+/// fn log_events(conn: &impl Connection) -> Result<(), ReplyError> {
+///     let mut resolver = AtomResolver::new();
+///     let mut events = Vec::new();
+///     loop {
+///         // Collect all events that are currently available
+///         events.push(conn.wait_for_event()?);
+///         while let Some(event) = conn.poll_for_event()? {
+///             events.push(event);
+///         }
+///         for event in &events {
+///             if let Event::PropertyNotify(event) = event {
+///                 resolver.request(event.atom);
+///             }
+///         }
+///         resolver.flush(conn)?;
+///         for event in events.drain(..) {
+///             if let Event::PropertyNotify(event) = event {
+///                 println!("{:?} changed", resolver.name(event.atom));
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AtomResolver {
+    names: HashMap<Atom, String>,
+    pending: BTreeSet<Atom>,
+}
+
+impl AtomResolver {
+    /// Create an empty resolver.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Queue an atom for resolution by the next call to [`AtomResolver::flush`].
+    ///
+    /// Atoms whose name is already known are not queued again.
+    pub fn request(&mut self, atom: Atom) {
+        if !self.names.contains_key(&atom) {
+            let _ = self.pending.insert(atom);
+        }
+    }
+
+    /// Check whether there are atoms that are waiting for [`AtomResolver::flush`].
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Get the name of an atom if it was already resolved.
+    ///
+    /// Atom names are Latin-1, which agrees with UTF-8 for ASCII. Other bytes are replaced
+    /// with `U+FFFD`.
+    pub fn name(&self, atom: Atom) -> Option<&str> {
+        self.names.get(&atom).map(|name| &name[..])
+    }
+
+    /// Resolve all queued atoms.
+    ///
+    /// All `GetAtomName` requests are sent before the first reply is awaited. If the X11 server
+    /// reports an error for some atom, e.g. because it does not exist, the other atoms are still
+    /// resolved and the first error is returned afterwards. Failed atoms are not queued anymore.
+    /// If the connection fails, the atoms whose replies were not received yet stay queued.
+    pub fn flush<C: RequestConnection + ?Sized>(&mut self, conn: &C) -> Result<(), ReplyError> {
+        self.flush_impl(conn, None)
+    }
+
+    /// Resolve all queued atoms, preferring to report the error for the atom `wanted`.
+    fn flush_impl<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        wanted: Option<Atom>,
+    ) -> Result<(), ReplyError> {
+        let mut cookies = Vec::with_capacity(self.pending.len());
+        for &atom in &self.pending {
+            cookies.push((atom, xproto::get_atom_name(conn, atom)?));
+        }
+        let mut result = Ok(());
+        for (atom, cookie) in cookies {
+            let reply = cookie.reply();
+            if let Err(ReplyError::ConnectionError(_)) = reply {
+                // Keep this and all later atoms queued for another try
+                return reply.map(|_| ());
+            }
+            let _ = self.pending.remove(&atom);
+            match reply {
+                Ok(reply) => {
+                    let name = String::from_utf8_lossy(&reply.name).into_owned();
+                    let _ = self.names.insert(atom, name);
+                }
+                Err(error) => {
+                    if result.is_ok() || wanted == Some(atom) {
+                        result = Err(error);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Get the name of an atom, resolving it and all other queued atoms if necessary.
+    ///
+    /// Errors for the other queued atoms are not reported. These atoms are not queued anymore,
+    /// just like with [`AtomResolver::flush`].
+    pub fn resolve<C: RequestConnection + ?Sized>(
+        &mut self,
+        conn: &C,
+        atom: Atom,
+    ) -> Result<&str, ReplyError> {
+        if !self.names.contains_key(&atom) {
+            self.request(atom);
+            let result = self.flush_impl(conn, Some(atom));
+            if !self.names.contains_key(&atom) {
+                // Resolving this atom failed, so there must be an error
+                result?;
+            }
+        }
+        Ok(&self.names[&atom])
+    }
+}
+
+/// A cache for atoms that are looked up by their name.
+///
+/// [`AtomCache::get_or_intern`] only sends an `InternAtom` request the first time a name is
+/// looked up. Names that are known to be needed can be interned up front with
+/// [`AtomCache::prewarm`], which sends all requests before waiting for the first reply.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::AtomCache;
+/// use x11rb::protocol::xproto::Atom;
+///
+/// fn net_wm_state(conn: &impl Connection) -> Result<Vec<Atom>, ReplyError> {
+///     let cache = AtomCache::new(conn);
+///     cache.prewarm(&["_NET_WM_STATE", "_NET_WM_STATE_FULLSCREEN", "_NET_WM_STATE_ABOVE"])?;
+///     // No further round trips are needed
+///     Ok(vec![
+///         cache.get_or_intern("_NET_WM_STATE")?,
+///         cache.get_or_intern("_NET_WM_STATE_FULLSCREEN")?,
+///     ])
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AtomCache<'c, C: RequestConnection + ?Sized> {
+    conn: &'c C,
+    atoms: RefCell<HashMap<String, Atom>>,
+}
+
+impl<'c, C: RequestConnection + ?Sized> AtomCache<'c, C> {
+    /// Create an empty cache for the given connection.
+    pub fn new(conn: &'c C) -> Self {
+        Self {
+            conn,
+            atoms: Default::default(),
+        }
+    }
+
+    /// Get the atom with the given name if it is already cached.
+    pub fn get(&self, name: &str) -> Option<Atom> {
+        self.atoms.borrow().get(name).copied()
+    }
+
+    /// Get the atom with the given name, interning it if it is not cached yet.
+    pub fn get_or_intern(&self, name: &str) -> Result<Atom, ReplyError> {
+        if let Some(atom) = self.get(name) {
+            return Ok(atom);
+        }
+        let atom = xproto::intern_atom(self.conn, false, name.as_bytes())?
+            .reply()?
+            .atom;
+        let _ = self.atoms.borrow_mut().insert(name.to_owned(), atom);
+        Ok(atom)
+    }
+
+    /// Intern all of the given names that are not cached yet.
+    ///
+    /// All `InternAtom` requests are sent before the first reply is awaited, so that this only
+    /// needs a single round trip to the X11 server. If an error occurs, the atoms whose replies
+    /// were received before are still cached.
+    pub fn prewarm(&self, names: &[&str]) -> Result<(), ReplyError> {
+        let cookies = names
+            .iter()
+            .filter(|name| self.get(name).is_none())
+            .map(|&name| {
+                Ok((
+                    name,
+                    xproto::intern_atom(self.conn, false, name.as_bytes())?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+        for (name, cookie) in cookies {
+            let atom = cookie.reply()?.atom;
+            let _ = self.atoms.borrow_mut().insert(name.to_owned(), atom);
+        }
+        Ok(())
+    }
+}
+
+// Reading large properties
+
+/// The number of four byte units that [`get_property_full`] requests at once.
+const PROPERTY_CHUNK_LENGTH: u32 = 16 * 1024;
+
+/// How often [`get_property_full`] starts over when the property changes while it is read.
+const PROPERTY_READ_ATTEMPTS: usize = 3;
+
+/// Get the complete value of a property with as many `GetProperty` requests as necessary.
+///
+/// The value is read in chunks until `bytes_after` is zero. The returned reply contains the
+/// concatenated value, the `value_len` of the whole value, and a `bytes_after` of zero. If the
+/// property does not exist or does not have the requested `type_`, the reply of the first request
+/// is returned unchanged, i.e. with an empty value.
+///
+/// If the X11 server reports a different type, format, or size while the chunks are read, the
+/// property was changed in the mean time and reading starts over. After a few failed attempts,
+/// [`ParseError::InvalidValue`] is returned.
+///
+/// With `delete`, the property is deleted after it was read completely. The X11 server only
+/// deletes a property as part of a `GetProperty` request that returns all remaining data, so
+/// only the final request deletes the property.
+pub fn get_property_full<C, A, B>(
+    conn: &C,
+    delete: bool,
+    window: Window,
+    property: A,
+    type_: B,
+) -> Result<GetPropertyReply, ReplyError>
+where
+    C: RequestConnection + ?Sized,
+    A: Into<Atom>,
+    B: Into<Atom>,
+{
+    let (property, type_) = (property.into(), type_.into());
+    let get_chunk = |offset| {
+        xproto::get_property(
+            conn,
+            delete,
+            window,
+            property,
+            type_,
+            offset,
+            PROPERTY_CHUNK_LENGTH,
+        )?
+        .reply()
+    };
+    'attempts: for _ in 0..PROPERTY_READ_ATTEMPTS {
+        let mut result = get_chunk(0)?;
+        if result.format == 0 || type_ != AtomEnum::ANY.into() && result.type_ != type_ {
+            return Ok(result);
+        }
+        while result.bytes_after != 0 {
+            let offset = u32::try_from(result.value.len() / 4).unwrap();
+            let chunk = get_chunk(offset)?;
+            if !append_property_chunk(&mut result, &chunk) {
+                continue 'attempts;
+            }
+        }
+        return Ok(result);
+    }
+    Err(ParseError::InvalidValue.into())
+}
+
+/// Append the value of a `GetProperty` reply to the value that was read so far.
+///
+/// Returns `false` if the chunk does not fit to the value, because the property was changed.
+fn append_property_chunk(result: &mut GetPropertyReply, chunk: &GetPropertyReply) -> bool {
+    let expected_len = result.bytes_after.try_into().unwrap_or(usize::MAX);
+    if chunk.type_ != result.type_
+        || chunk.format != result.format
+        || chunk.format == 0
+        || chunk
+            .value
+            .len()
+            .checked_add(chunk.bytes_after.try_into().unwrap())
+            != Some(expected_len)
+    {
+        return false;
+    }
+    result.value.extend_from_slice(&chunk.value);
+    result.value_len += chunk.value_len;
+    result.bytes_after = chunk.bytes_after;
+    true
+}
+
+// Dumping all properties
+
+/// Get all properties of a window together with their values.
+///
+/// This sends a `ListProperties` request and afterwards one `GetProperty` request for each
+/// property. The `GetProperty` requests are all sent before waiting for the first reply, so that
+/// only two round trips to the X11 server are needed. The complete value of each property is
+/// fetched.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::dump_properties;
+/// use x11rb::protocol::xproto::{ConnectionExt, Window};
+///
+/// fn xprop(conn: &impl Connection, window: Window) -> Result<(), ReplyError> {
+///     for (atom, reply) in dump_properties(conn, window)? {
+///         let name = conn.get_atom_name(atom)?.reply()?.name;
+///         println!("{}: {} bytes", String::from_utf8_lossy(&name), reply.value.len());
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+/// Properties that are deleted while this function runs are left out of the result.
+pub fn dump_properties<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Vec<(Atom, GetPropertyReply)>, ReplyError> {
+    let atoms = xproto::list_properties(conn, window)?.reply()?.atoms;
+    let cookies = atoms
+        .into_iter()
+        .map(|atom| {
+            let cookie =
+                xproto::get_property(conn, false, window, atom, AtomEnum::ANY, 0, u32::MAX)?;
+            Ok((atom, cookie))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+    let mut result = Vec::with_capacity(cookies.len());
+    for (atom, cookie) in cookies {
+        let reply = cookie.reply()?;
+        if reply.type_ != AtomEnum::NONE.into() {
+            result.push((atom, reply));
+        }
+    }
+    Ok(result)
+}
+
+// Selections
+
+/// The data of a selection that was converted with `ConvertSelection`.
+///
+/// See [`read_selection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionData {
+    /// The type of the data, for example `UTF8_STRING`.
+    pub type_: Atom,
+    /// The format of the data, i.e. 8, 16, or 32.
+    pub format: u8,
+    /// The raw data. Items of format 16 and 32 are in native byte order.
+    pub data: Vec<u8>,
+}
+
+/// The state of an incremental selection transfer.
+#[derive(Debug)]
+struct IncrTransfer(SelectionData);
+
+impl IncrTransfer {
+    fn new(size_hint: usize) -> Self {
+        Self(SelectionData {
+            type_: AtomEnum::NONE.into(),
+            format: 0,
+            data: Vec::with_capacity(size_hint),
+        })
+    }
+
+    /// Add the next chunk of the transfer.
+    ///
+    /// Returns `true` when `reply` is the empty chunk that ends the transfer. A reply with type
+    /// `NONE` means that the property does not exist, i.e. the selection owner did not store the
+    /// next chunk yet. This does not end the transfer.
+    fn add_chunk(&mut self, reply: &GetPropertyReply) -> bool {
+        if reply.type_ == Atom::from(AtomEnum::NONE) {
+            return false;
+        }
+        // The first chunk determines the type. If there is no data at all, the final chunk does.
+        if self.0.data.is_empty() {
+            self.0.type_ = reply.type_;
+            self.0.format = reply.format;
+        }
+        self.0.data.extend_from_slice(&reply.value);
+        reply.value.is_empty()
+    }
+}
+
+/// Read the data of a selection after its conversion finished.
+///
+/// After a `ConvertSelection` request, the selection owner stores the converted data in the
+/// requested property of the requestor window and sends a `SelectionNotify` event. This function
+/// reads and deletes that property as described by the ICCCM. `None` is returned if the selection
+/// owner refused the conversion.
+///
+/// Large data is transferred incrementally: The property then has type `INCR` and the selection
+/// owner afterwards stores one chunk of data after another in the property. This function handles
+/// the complete transfer and waits for the necessary `PropertyNotify` events. Other events that
+/// arrive in the mean time stay in the event queue. For this to work, the requestor window must
+/// have selected [`EventMask::PROPERTY_CHANGE`] before the `SelectionNotify` event arrived.
+///
+/// The `INCR` atom is not predefined and must be interned by the caller, for example via
+/// [crate::atom_manager]. Since the selection owner could stop in the middle of an incremental
+/// transfer, this function can block forever.
+///
+/// [`EventMask::PROPERTY_CHANGE`]: crate::protocol::xproto::EventMask::PROPERTY_CHANGE
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::ReplyError;
+/// use x11rb::properties::read_selection;
+/// use x11rb::protocol::xproto::{Atom, ConnectionExt, Window, SELECTION_NOTIFY_EVENT};
+/// use x11rb::protocol::Event;
+/// use x11rb::CURRENT_TIME;
+///
+/// fn paste(
+///     conn: &impl Connection,
+///     window: Window,
+///     clipboard: Atom,
+///     utf8_string: Atom,
+///     incr: Atom,
+/// ) -> Result<Option<Vec<u8>>, ReplyError> {
+///     // Store the data in property `clipboard` of `window`
+///     conn.convert_selection(window, clipboard, utf8_string, clipboard, CURRENT_TIME)?;
+///     conn.flush()?;
+///     let event = conn.wait_for_event_matching(|event| {
+///         matches!(event, Event::SelectionNotify(event) if event.requestor == window)
+///     })?;
+///     let event = match event {
+///         Event::SelectionNotify(event) => event,
+///         _ => unreachable!(),
+///     };
+///     Ok(read_selection(conn, &event, incr)?.map(|data| data.data))
+/// }
+/// ```
+pub fn read_selection<C: Connection>(
+    conn: &C,
+    event: &SelectionNotifyEvent,
+    incr: Atom,
+) -> Result<Option<SelectionData>, ReplyError> {
+    if event.property == Atom::from(AtomEnum::NONE) {
+        return Ok(None);
+    }
+    let (window, property) = (event.requestor, event.property);
+    let get_property = || -> Result<GetPropertyReply, ReplyError> {
+        xproto::get_property(conn, true, window, property, AtomEnum::ANY, 0, u32::MAX)?.reply()
+    };
+
+    let reply = get_property()?;
+    if reply.type_ != incr {
+        return Ok(Some(SelectionData {
+            type_: reply.type_,
+            format: reply.format,
+            data: reply.value,
+        }));
+    }
+
+    // Deleting the INCR property (done by get_property() above) starts the transfer. The value
+    // of the INCR property is a lower bound on the size of the data.
+    let size_hint = reply
+        .value32()
+        .and_then(|mut value| value.next())
+        .and_then(|size| size.try_into().ok())
+        .unwrap_or(0);
+    let mut transfer = IncrTransfer::new(size_hint);
+    conn.flush()?;
+    // A NEW_VALUE event is only about the next chunk if it arrives after the PropertyNotify event
+    // for deleting the previous chunk. Earlier ones, like the one for storing the INCR property
+    // itself, could still be in the event queue.
+    let mut deleted = false;
+    loop {
+        let event = conn.wait_for_event_matching(|event| match event {
+            Event::PropertyNotify(event) => event.window == window && event.atom == property,
+            _ => false,
+        })?;
+        let state = match event {
+            Event::PropertyNotify(event) => event.state,
+            _ => unreachable!(),
+        };
+        if state == Property::DELETE {
+            deleted = true;
+            continue;
+        }
+        if !deleted {
+            continue;
+        }
+        let reply = get_property()?;
+        if transfer.add_chunk(&reply) {
+            return Ok(Some(transfer.0));
+        }
+        // Without a property, nothing was deleted and no DELETE event follows
+        if reply.type_ != Atom::from(AtomEnum::NONE) {
+            // Deleting the property asks for the next chunk
+            deleted = false;
+            conn.flush()?;
+        }
+    }
+}
+
+// Watching properties
+
+/// Watch some properties of a window for changes.
+///
+/// [`PropertyWatcher::new`] selects `PropertyChange` events on the window. Afterwards, all
+/// events should be given to [`PropertyWatcher::handle_event`]. For `PropertyNotify` events about
+/// one of the watched properties, it fetches the new value of the property and calls a callback
+/// with it.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::properties::PropertyWatcher;
+/// use x11rb::protocol::xproto::{Atom, Window};
+///
+/// fn watch_active_window(
+///     conn: &impl Connection,
+///     root: Window,
+///     net_active_window: Atom,
+/// ) -> Result<(), Box<dyn std::error::Error>> {
+///     let watcher = PropertyWatcher::new(conn, root, &[net_active_window])?;
+///     loop {
+///         let event = conn.wait_for_event()?;
+///         watcher.handle_event(conn, &event, |_atom, value| {
+///             let window = value.and_then(|value| value.value32()?.next());
+///             println!("Active window changed: {:?}", window);
+///         })?;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyWatcher {
+    window: Window,
+    atoms: Vec<Atom>,
+}
+
+impl PropertyWatcher {
+    /// Start watching the given properties of `window`.
+    ///
+    /// `PropertyChange` is added to the events that this client selected on the window. Other
+    /// events that were already selected stay selected.
+    pub fn new<C: Connection>(
+        conn: &C,
+        window: Window,
+        atoms: &[Atom],
+    ) -> Result<Self, ReplyError> {
+        let attributes = xproto::get_window_attributes(conn, window)?.reply()?;
+        let aux = xproto::ChangeWindowAttributesAux::new()
+            .event_mask(attributes.your_event_mask | xproto::EventMask::PROPERTY_CHANGE);
+        xproto::change_window_attributes(conn, window, &aux)?.check()?;
+        Ok(Self {
+            window,
+            atoms: atoms.to_vec(),
+        })
+    }
+
+    /// Get the window whose properties are watched.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the watched properties.
+    pub fn atoms(&self) -> &[Atom] {
+        &self.atoms
+    }
+
+    /// Fetch the current values of all watched properties.
+    ///
+    /// This is useful for getting the initial state. The values are returned in the order of
+    /// [`PropertyWatcher::atoms`]. Properties that do not exist have the value `None`.
+    pub fn current_values<C: RequestConnection + ?Sized>(
+        &self,
+        conn: &C,
+    ) -> Result<Vec<Option<GetPropertyReply>>, ReplyError> {
+        let cookies = self
+            .atoms
+            .iter()
+            .map(|&atom| self.get_property(conn, atom))
+            .collect::<Result<Vec<_>, _>>()?;
+        cookies
+            .into_iter()
+            .map(|cookie| Ok(Some(cookie.reply()?).filter(property_exists)))
+            .collect()
+    }
+
+    /// Handle an event.
+    ///
+    /// If the event is a `PropertyNotify` for one of the watched properties, the new value is
+    /// fetched and `callback` is called with the atom of the property and its value; the value
+    /// is `None` if the property was deleted. Returns whether `callback` was called.
+    pub fn handle_event<C, F>(
+        &self,
+        conn: &C,
+        event: &Event,
+        callback: F,
+    ) -> Result<bool, ReplyError>
+    where
+        C: RequestConnection + ?Sized,
+        F: FnOnce(Atom, Option<&GetPropertyReply>),
+    {
+        let event = match event {
+            Event::PropertyNotify(event)
+                if event.window == self.window && self.atoms.contains(&event.atom) =>
+            {
+                event
+            }
+            _ => return Ok(false),
+        };
+        let value = if event.state == Property::DELETE {
+            None
+        } else {
+            Some(self.get_property(conn, event.atom)?.reply()?).filter(property_exists)
+        };
+        callback(event.atom, value.as_ref());
+        Ok(true)
+    }
+
+    fn get_property<'c, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'c C,
+        atom: Atom,
+    ) -> Result<Cookie<'c, C, GetPropertyReply>, ConnectionError> {
+        xproto::get_property(conn, false, self.window, atom, AtomEnum::ANY, 0, u32::MAX)
+    }
+}
+
+/// Check whether a `GetProperty` reply describes an existing property.
+fn property_exists(reply: &GetPropertyReply) -> bool {
+    reply.type_ != Atom::from(AtomEnum::NONE)
+}
+
 /// Parse an element of type `T` and turn it into an `Option` by checking if the given `bit` is set
 /// in `flags`.
 fn parse_with_flag<T: TryParse>(
@@ -674,8 +1665,12 @@ fn parse_with_flag<T: TryParse>(
 mod test {
     use std::convert::TryInto;
 
-    use super::{WmClass, WmHints, WmHintsState, WmSizeHints};
-    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity};
+    use super::{
+        append_property_chunk, wm_icon_data, AtomResolver, IncrTransfer, WmClass, WmHints,
+        WmHintsState, WmProtocol, WmProtocolAtoms, WmSizeHints, WmStrutPartial,
+    };
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{Atom, AtomEnum, ClientMessageEvent, GetPropertyReply, Gravity};
     use crate::x11_utils::Serialize;
 
     fn get_property_reply(value: &[u8], format: u8, type_: impl Into<Atom>) -> GetPropertyReply {
@@ -793,4 +1788,146 @@ mod test {
 
         assert_eq!(input, wm_hints.serialize());
     }
+
+    #[test]
+    fn test_wm_icon_data() {
+        let icons: &[(u32, u32, &[u32])] =
+            &[(1, 1, &[0xff00_0000]), (2, 1, &[0xffff_0000, 0xff00_ff00])];
+        assert_eq!(
+            wm_icon_data(icons),
+            [1, 1, 0xff00_0000, 2, 1, 0xffff_0000, 0xff00_ff00]
+        );
+        assert!(wm_icon_data(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wm_icon_data_size_mismatch() {
+        let _ = wm_icon_data(&[(2, 2, &[0, 0, 0])]);
+    }
+
+    #[test]
+    fn test_wm_strut_partial() {
+        // A panel with a height of 30 pixels at the top of the left half of a 1920 pixels screen
+        let strut = WmStrutPartial {
+            top: 30,
+            top_start_x: 0,
+            top_end_x: 959,
+            ..WmStrutPartial::new()
+        };
+        let value = strut.serialize();
+        let values = value
+            .chunks_exact(4)
+            .map(|chunk| u32::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0, 0, 30, 0, 0, 0, 0, 0, 0, 959, 0, 0]);
+
+        let reply = get_property_reply(&value, 32, AtomEnum::CARDINAL);
+        assert_eq!(WmStrutPartial::from_reply(&reply), Ok(strut));
+        let reply = get_property_reply(&value[..44], 32, AtomEnum::CARDINAL);
+        assert_eq!(
+            WmStrutPartial::from_reply(&reply),
+            Err(ParseError::DataTooShort { needed: 4, got: 0 })
+        );
+    }
+
+    #[test]
+    fn test_incr_transfer() {
+        let utf8_string = 300;
+        let mut transfer = IncrTransfer::new(6);
+        assert!(!transfer.add_chunk(&get_property_reply(b"abc", 8, utf8_string)));
+        // The property does not exist until the selection owner stored the next chunk
+        assert!(!transfer.add_chunk(&get_property_reply(b"", 0, AtomEnum::NONE)));
+        assert!(!transfer.add_chunk(&get_property_reply(b"def", 8, utf8_string)));
+        assert!(transfer.add_chunk(&get_property_reply(b"", 8, utf8_string)));
+        assert_eq!(transfer.0.type_, utf8_string);
+        assert_eq!(transfer.0.format, 8);
+        assert_eq!(transfer.0.data, b"abcdef");
+
+        // A transfer without any data
+        let mut transfer = IncrTransfer::new(0);
+        assert!(transfer.add_chunk(&get_property_reply(b"", 8, utf8_string)));
+        assert_eq!(transfer.0.type_, utf8_string);
+        assert!(transfer.0.data.is_empty());
+    }
+
+    #[test]
+    fn test_append_property_chunk() {
+        let reply = |value: &[u8], bytes_after| GetPropertyReply {
+            bytes_after,
+            ..get_property_reply(value, 8, AtomEnum::STRING)
+        };
+        let mut result = reply(b"abcd", 3);
+        assert!(append_property_chunk(&mut result, &reply(b"ef", 1)));
+        assert!(append_property_chunk(&mut result, &reply(b"g", 0)));
+        assert_eq!(result.value, b"abcdefg");
+        assert_eq!(result.value_len, 7);
+        assert_eq!(result.bytes_after, 0);
+
+        // The property grew, changed its type, or was deleted in the mean time
+        let mut result = reply(b"abcd", 3);
+        assert!(!append_property_chunk(&mut result, &reply(b"efg", 1)));
+        assert!(!append_property_chunk(
+            &mut result,
+            &get_property_reply(b"efg", 8, AtomEnum::ATOM)
+        ));
+        assert!(!append_property_chunk(
+            &mut result,
+            &get_property_reply(b"", 0, AtomEnum::NONE)
+        ));
+        assert_eq!(result.value, b"abcd");
+    }
+
+    #[test]
+    fn test_atom_resolver_queue() {
+        let mut resolver = AtomResolver::new();
+        assert!(!resolver.has_pending());
+        resolver.request(42);
+        resolver.request(42);
+        assert!(resolver.has_pending());
+        assert_eq!(resolver.pending.len(), 1);
+        assert_eq!(resolver.name(42), None);
+
+        let _ = resolver.names.insert(43, "FOO".to_string());
+        resolver.request(43);
+        assert_eq!(resolver.pending.len(), 1);
+        assert_eq!(resolver.name(43), Some("FOO"));
+    }
+
+    #[test]
+    fn test_wm_protocol() {
+        let atoms = WmProtocolAtoms {
+            wm_protocols: 10,
+            wm_delete_window: 11,
+            wm_take_focus: 12,
+            net_wm_ping: 13,
+            net_wm_sync_request: 14,
+        };
+        let protocol = |format, type_: Atom, data: [u32; 5]| {
+            let event = ClientMessageEvent::new(format, 1, type_, data);
+            WmProtocol::from_event(&event, &atoms)
+        };
+        assert_eq!(
+            protocol(32, 10, [11, 0, 0, 0, 0]),
+            Some(WmProtocol::DeleteWindow)
+        );
+        assert_eq!(
+            protocol(32, 10, [12, 5, 0, 0, 0]),
+            Some(WmProtocol::TakeFocus(5))
+        );
+        assert_eq!(
+            protocol(32, 10, [13, 5, 1, 0, 0]),
+            Some(WmProtocol::Ping(5, 1))
+        );
+        assert_eq!(
+            protocol(32, 10, [14, 5, 2, 1, 0]),
+            Some(WmProtocol::SyncRequest(5, (1 << 32) | 2))
+        );
+        assert_eq!(
+            protocol(32, 10, [99, 0, 0, 0, 0]),
+            Some(WmProtocol::Other(99))
+        );
+        assert_eq!(protocol(32, 99, [11, 0, 0, 0, 0]), None);
+        assert_eq!(protocol(8, 10, [11, 0, 0, 0, 0]), None);
+    }
 }
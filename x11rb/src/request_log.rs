@@ -0,0 +1,311 @@
+//! Recording requests for replaying them later.
+//!
+//! A [`RequestLog`] collects serialized requests in a single buffer. The buffer can be stored,
+//! for example in a file, and be turned back into a log with [`RequestLog::from_bytes`].
+//! [`replay`] sends all requests of a log in order:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{MapWindowRequest, UnmapWindowRequest};
+//! use x11rb::request_log::{replay, RequestLog};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, _) = x11rb::connect(None)?;
+//! let window = 0x1234;
+//! let mut log = RequestLog::new();
+//! log.push(UnmapWindowRequest { window });
+//! log.push(MapWindowRequest { window });
+//!
+//! let log = RequestLog::from_bytes(log.into_bytes())?;
+//! replay(&conn, &log)?;
+//! conn.flush()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requests of extensions are recorded with the name of their extension instead of its major
+//! opcode, which is looked up again when replaying. Thus, a log can be replayed on a different
+//! connection than the one it was recorded for.
+//!
+//! The integers in the headers of the entries are stored in little endian byte order. The
+//! requests themselves are stored as they were serialized, i.e. in native byte order, since
+//! converting them would require knowing the layout of each request. Each entry records the byte
+//! order of its request, so that a log from a machine with a different byte order is rejected
+//! instead of sending garbage.
+//!
+//! Replies and errors of replayed requests are not returned. Errors are delivered as events.
+
+use std::convert::TryInto;
+
+use crate::connection::RequestConnection;
+use crate::errors::{ConnectionError, ParseError};
+use crate::x11_utils::{parse_u8_list, parse_utf8, Request, TryParse};
+
+use x11rb_protocol::protocol::known_extension_name;
+
+/// The flag of an entry for a request that has a reply.
+const HAS_REPLY: u8 = 1;
+
+/// The flag of an entry for a request in big endian byte order.
+const BIG_ENDIAN: u8 = 2;
+
+/// The byte order flag for requests serialized on this machine.
+const NATIVE_BYTE_ORDER: u8 = if cfg!(target_endian = "big") {
+    BIG_ENDIAN
+} else {
+    0
+};
+
+/// A recorded request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoggedRequest<'a> {
+    /// The protocol name of the extension of the request, or `None` for core requests.
+    pub extension_name: Option<&'a str>,
+    /// Whether the X11 server sends a reply to the request.
+    pub has_reply: bool,
+    /// The serialized request. For extension requests, the major opcode is zero.
+    pub data: &'a [u8],
+}
+
+/// A sequence of serialized requests.
+///
+/// Each request is stored with a small header consisting of a flags byte, the length of the
+/// extension name, the extension name, and the length of the request as a little endian `u32`.
+/// The flags say whether the request has a reply and in which byte order it is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestLog {
+    buffer: Vec<u8>,
+}
+
+impl RequestLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a log from a buffer that was produced by [`RequestLog::into_bytes`].
+    ///
+    /// An error is returned if the buffer does not consist of complete entries, if a request
+    /// does not have a valid length, or if a request was recorded on a machine with a different
+    /// byte order.
+    pub fn from_bytes(buffer: Vec<u8>) -> Result<Self, ParseError> {
+        let mut remaining = &buffer[..];
+        while !remaining.is_empty() {
+            remaining = parse_entry(remaining)?.1;
+        }
+        Ok(Self { buffer })
+    }
+
+    /// Get the serialized form of this log.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Turn this log into its serialized form.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /// Append a request to the log.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request contains file descriptors, since these cannot be recorded.
+    pub fn push<R: Request>(&mut self, request: R) {
+        let (data, fds) = request.serialize(0);
+        assert!(fds.is_empty(), "Requests with FDs cannot be recorded");
+        let name = R::EXTENSION_NAME.unwrap_or("");
+        let flags = if R::HAS_REPLY { HAS_REPLY } else { 0 } | NATIVE_BYTE_ORDER;
+        self.buffer.push(flags);
+        self.buffer
+            .push(name.len().try_into().expect("Extension name is too long"));
+        self.buffer.extend_from_slice(name.as_bytes());
+        let length: u32 = data.len().try_into().expect("Request is too long");
+        self.buffer.extend_from_slice(&length.to_le_bytes());
+        self.buffer.extend_from_slice(&data);
+    }
+
+    /// Get the number of recorded requests.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Check if no requests were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Iterate over the recorded requests in order.
+    pub fn iter(&self) -> impl Iterator<Item = LoggedRequest<'_>> + '_ {
+        let mut remaining = &self.buffer[..];
+        std::iter::from_fn(move || {
+            if remaining.is_empty() {
+                return None;
+            }
+            // The buffer was checked when the log was created
+            let (entry, rest) = parse_entry(remaining).expect("RequestLog is corrupted");
+            remaining = rest;
+            Some(entry)
+        })
+    }
+}
+
+/// Parse one entry of a [`RequestLog`].
+fn parse_entry(data: &[u8]) -> Result<(LoggedRequest<'_>, &[u8]), ParseError> {
+    let (flags, remaining) = u8::try_parse(data)?;
+    let (name_len, remaining) = u8::try_parse(remaining)?;
+    if flags & !(HAS_REPLY | BIG_ENDIAN) != 0 || flags & BIG_ENDIAN != NATIVE_BYTE_ORDER {
+        return Err(ParseError::InvalidValue);
+    }
+    let (name, remaining) = parse_utf8(remaining, name_len.into())?;
+    let (length, remaining) = parse_u8_list(remaining, 4)?;
+    let length = u32::from_le_bytes(length.try_into().unwrap());
+    let length = length.try_into().or(Err(ParseError::ConversionFailed))?;
+    let (data, remaining) = parse_u8_list(remaining, length)?;
+    check_request_length(data)?;
+    let entry = LoggedRequest {
+        extension_name: Some(name).filter(|name| !name.is_empty()),
+        has_reply: flags & HAS_REPLY != 0,
+        data,
+    };
+    Ok((entry, remaining))
+}
+
+/// Check that `data` is a complete request with a matching length field.
+///
+/// Requests that are too long for the length field have a length field of zero and are sent
+/// via BigRequests.
+fn check_request_length(data: &[u8]) -> Result<(), ParseError> {
+    if data.len() < 4 || data.len() % 4 != 0 {
+        return Err(ParseError::InvalidValue);
+    }
+    let length_field = u16::from_ne_bytes([data[2], data[3]]);
+    let expected = u16::try_from(data.len() / 4).unwrap_or(0);
+    if length_field != expected {
+        return Err(ParseError::InvalidValue);
+    }
+    Ok(())
+}
+
+/// A reply whose content is ignored.
+#[derive(Debug, Clone, Copy)]
+struct IgnoredReply;
+
+impl TryParse for IgnoredReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Ok((IgnoredReply, value))
+    }
+}
+
+/// Send all requests of the given log in order.
+///
+/// The major opcodes of extension requests are looked up on `conn`. If an extension is not
+/// supported, [`ConnectionError::UnsupportedExtension`] is returned and the remaining requests
+/// are not sent. Replies are discarded and errors are delivered as events.
+pub fn replay<C: RequestConnection + ?Sized>(
+    conn: &C,
+    log: &RequestLog,
+) -> Result<(), ConnectionError> {
+    for entry in log.iter() {
+        let mut data = entry.data.to_vec();
+        if let Some(name) = entry.extension_name {
+            // extension_information() requires a 'static name, but the log only borrows it
+            let name = known_extension_name(name).ok_or(ConnectionError::UnsupportedExtension)?;
+            let info = conn
+                .extension_information(name)?
+                .ok_or(ConnectionError::UnsupportedExtension)?;
+            if let Some(opcode) = data.first_mut() {
+                *opcode = info.major_opcode;
+            }
+        }
+        let bufs = [std::io::IoSlice::new(&data)];
+        if entry.has_reply {
+            drop(conn.send_request_with_reply::<IgnoredReply>(&bufs, Vec::new())?);
+        } else {
+            drop(conn.send_request_without_reply(&bufs, Vec::new())?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LoggedRequest, RequestLog, BIG_ENDIAN, NATIVE_BYTE_ORDER};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{GetInputFocusRequest, MapWindowRequest};
+
+    #[test]
+    fn test_round_trip() {
+        let mut log = RequestLog::new();
+        assert!(log.is_empty());
+        log.push(MapWindowRequest { window: 42 });
+        log.push(GetInputFocusRequest);
+        assert_eq!(log.len(), 2);
+
+        let log = RequestLog::from_bytes(log.into_bytes()).unwrap();
+        let entries = log.iter().collect::<Vec<_>>();
+        assert_eq!(
+            entries[0],
+            LoggedRequest {
+                extension_name: None,
+                has_reply: false,
+                data: &[8, 0, 2, 0, 42, 0, 0, 0],
+            }
+        );
+        assert!(entries[1].has_reply);
+        assert_eq!(entries[1].data, [43, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        let mut log = RequestLog::new();
+        log.push(MapWindowRequest { window: 42 });
+        let bytes = log.into_bytes();
+        assert_eq!(
+            RequestLog::from_bytes(bytes[..bytes.len() - 1].to_vec()),
+            Err(ParseError::DataTooShort { needed: 8, got: 7 })
+        );
+        let mut invalid_flags = bytes.clone();
+        invalid_flags[0] = 4;
+        assert_eq!(
+            RequestLog::from_bytes(invalid_flags),
+            Err(ParseError::InvalidValue)
+        );
+        let mut other_byte_order = bytes;
+        other_byte_order[0] ^= BIG_ENDIAN;
+        assert_eq!(
+            RequestLog::from_bytes(other_byte_order),
+            Err(ParseError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn test_invalid_request_length() {
+        // An entry for a core request without a reply with the given data
+        fn entry(data: &[u8]) -> Vec<u8> {
+            let mut entry = vec![NATIVE_BYTE_ORDER, 0];
+            let length = u32::try_from(data.len()).unwrap();
+            entry.extend_from_slice(&length.to_le_bytes());
+            entry.extend_from_slice(data);
+            entry
+        }
+        let length = |length: u16| length.to_ne_bytes();
+
+        let valid = [&[8, 0][..], &length(2), &[42, 0, 0, 0]].concat();
+        assert!(RequestLog::from_bytes(entry(&valid)).is_ok());
+
+        // An empty request, a truncated request, and a wrong length field
+        for data in [
+            Vec::new(),
+            valid[..7].to_vec(),
+            [&[8, 0][..], &length(3), &[42, 0, 0, 0]].concat(),
+        ] {
+            assert_eq!(
+                RequestLog::from_bytes(entry(&data)),
+                Err(ParseError::InvalidValue),
+                "{:?}",
+                data
+            );
+        }
+    }
+}
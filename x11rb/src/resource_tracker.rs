@@ -0,0 +1,409 @@
+//! Keeping track of the resources that a connection created.
+//!
+//! X11 resources like windows or graphic contexts live until they are explicitly freed or until
+//! the connection that created them is closed. A long-running application that forgets to free
+//! its resources thus slowly leaks memory in the X11 server. The [`ResourceTracker`] in this
+//! module wraps a connection and remembers all core protocol resources that were created through
+//! it, so that leaked resources can be found, for example at shutdown of a debug build:
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{CreateGCAux, GcontextWrapper};
+//! use x11rb::resource_tracker::ResourceTracker;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let conn = ResourceTracker::new(conn);
+//! let root = conn.setup().roots[screen_num].root;
+//! let gc = GcontextWrapper::create_gc(&conn, root, &CreateGCAux::new())?;
+//! // Forget to free the graphics context
+//! std::mem::forget(gc);
+//!
+//! for (kind, id) in conn.live_resources() {
+//!     eprintln!("Leaked {:?} {:#x}", kind, id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only requests from the core protocol are tracked. Resources that are freed implicitly by the
+//! X11 server, for example subwindows of a destroyed window, are not noticed and stay in the list
+//! of live resources.
+
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::io::IoSlice;
+use std::sync::Mutex;
+
+use crate::connection::{
+    BufWithFds, Connection, RawEventAndSeqNumber, ReplyOrError, RequestConnection, RequestKind,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::{self, ConnectionExt as _, Setup};
+use crate::protocol::Event;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+
+use x11rb_protocol::{DiscardMode, SequenceNumber};
+
+/// The kind of a resource that is tracked by a [`ResourceTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResourceKind {
+    /// A window created with `CreateWindow`.
+    Window,
+    /// A font opened with `OpenFont`.
+    Font,
+    /// A pixmap created with `CreatePixmap`.
+    Pixmap,
+    /// A graphics context created with `CreateGC`.
+    Gcontext,
+    /// A colormap created with `CreateColormap` or `CopyColormapAndFree`.
+    Colormap,
+    /// A cursor created with `CreateCursor` or `CreateGlyphCursor`.
+    Cursor,
+}
+
+/// What a request does to the set of live resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Create(ResourceKind),
+    Free,
+}
+
+impl Change {
+    /// Figure out what the core request with the given major opcode does.
+    fn for_opcode(opcode: u8) -> Option<Self> {
+        use ResourceKind::*;
+        Some(match opcode {
+            xproto::CREATE_WINDOW_REQUEST => Change::Create(Window),
+            xproto::OPEN_FONT_REQUEST => Change::Create(Font),
+            xproto::CREATE_PIXMAP_REQUEST => Change::Create(Pixmap),
+            xproto::CREATE_GC_REQUEST => Change::Create(Gcontext),
+            xproto::CREATE_COLORMAP_REQUEST | xproto::COPY_COLORMAP_AND_FREE_REQUEST => {
+                Change::Create(Colormap)
+            }
+            xproto::CREATE_CURSOR_REQUEST | xproto::CREATE_GLYPH_CURSOR_REQUEST => {
+                Change::Create(Cursor)
+            }
+            xproto::DESTROY_WINDOW_REQUEST
+            | xproto::CLOSE_FONT_REQUEST
+            | xproto::FREE_PIXMAP_REQUEST
+            | xproto::FREE_GC_REQUEST
+            | xproto::FREE_COLORMAP_REQUEST
+            | xproto::FREE_CURSOR_REQUEST => Change::Free,
+            _ => return None,
+        })
+    }
+}
+
+/// Get the effect of the given raw request on the set of live resources.
+///
+/// All requests handled here have the affected resource as their first field, directly after the
+/// four byte request header.
+fn parse_request(bufs: &[IoSlice<'_>]) -> Option<(Change, u32)> {
+    let mut header = [0; 8];
+    let mut filled = 0;
+    for buf in bufs {
+        let count = buf.len().min(header.len() - filled);
+        header[filled..filled + count].copy_from_slice(&buf[..count]);
+        filled += count;
+        if filled == header.len() {
+            let change = Change::for_opcode(header[0])?;
+            let id = u32::from_ne_bytes(header[4..].try_into().unwrap());
+            return Some((change, id));
+        }
+    }
+    None
+}
+
+/// A connection wrapper that keeps track of the resources that are created through it.
+///
+/// All requests are forwarded to the wrapped connection. Requests that create or free one of the
+/// resources described by [`ResourceKind`] additionally update the set of live resources, which
+/// can be queried with [`ResourceTracker::live_resources`]. Since this type implements
+/// [`Connection`], it can be used everywhere a connection is expected, including the resource
+/// wrappers like [`xproto::WindowWrapper`].
+#[derive(Debug)]
+pub struct ResourceTracker<C> {
+    conn: C,
+    live: Mutex<BTreeMap<u32, ResourceKind>>,
+}
+
+impl<C> ResourceTracker<C> {
+    /// Start tracking the resources created through the given connection.
+    ///
+    /// Resources that were created before this function was called are not known.
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            live: Default::default(),
+        }
+    }
+
+    /// Get a reference to the wrapped connection.
+    ///
+    /// Requests that are sent directly through this reference are not tracked.
+    pub fn inner(&self) -> &C {
+        &self.conn
+    }
+
+    /// Stop tracking resources and get the wrapped connection back.
+    pub fn into_inner(self) -> C {
+        self.conn
+    }
+
+    /// Get all resources that were created, but not yet freed.
+    ///
+    /// The resources are sorted by their XID.
+    pub fn live_resources(&self) -> Vec<(ResourceKind, u32)> {
+        let live = self.live.lock().unwrap();
+        live.iter().map(|(&id, &kind)| (kind, id)).collect()
+    }
+
+    /// Get all resources of the given kind that were created, but not yet freed.
+    pub fn live_resources_of_kind(&self, kind: ResourceKind) -> Vec<u32> {
+        let live = self.live.lock().unwrap();
+        live.iter()
+            .filter(|(_, &k)| k == kind)
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    fn record(&self, bufs: &[IoSlice<'_>]) {
+        if let Some((change, id)) = parse_request(bufs) {
+            let mut live = self.live.lock().unwrap();
+            match change {
+                Change::Create(kind) => drop(live.insert(id, kind)),
+                Change::Free => drop(live.remove(&id)),
+            }
+        }
+    }
+}
+
+impl<C: RequestConnection> ResourceTracker<C> {
+    /// Free all live resources of the given kind.
+    ///
+    /// Errors caused by the free requests are ignored, since e.g. a window might already have been
+    /// destroyed together with its parent.
+    pub fn free_all(&self, kind: ResourceKind) -> Result<(), ConnectionError> {
+        for id in self.live_resources_of_kind(kind) {
+            let cookie = match kind {
+                ResourceKind::Window => self.destroy_window(id)?,
+                ResourceKind::Font => self.close_font(id)?,
+                ResourceKind::Pixmap => self.free_pixmap(id)?,
+                ResourceKind::Gcontext => self.free_gc(id)?,
+                ResourceKind::Colormap => self.free_colormap(id)?,
+                ResourceKind::Cursor => self.free_cursor(id)?,
+            };
+            cookie.ignore_error();
+        }
+        Ok(())
+    }
+}
+
+impl<C: RequestConnection> RequestConnection for ResourceTracker<C> {
+    type Buf = C::Buf;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: TryParse,
+    {
+        let cookie = self.conn.send_request_with_reply::<R>(bufs, fds)?;
+        self.record(bufs);
+        Ok(Cookie::new(self, cookie.into_sequence_number()))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: TryParseFd,
+    {
+        let cookie = self.conn.send_request_with_reply_with_fds::<R>(bufs, fds)?;
+        self.record(bufs);
+        Ok(CookieWithFds::new(self, cookie.into_sequence_number()))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        let cookie = self.conn.send_request_without_reply(bufs, fds)?;
+        self.record(bufs);
+        Ok(VoidCookie::new(self, cookie.into_sequence_number()))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.extension_information(extension_name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError> {
+        self.conn.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        self.conn.check_for_raw_error(sequence)
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.conn.parse_event(event)
+    }
+}
+
+impl<C: Connection> Connection for ResourceTracker<C> {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
+        self.conn.wait_for_raw_event_with_sequence()
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Self::Buf>>, ConnectionError> {
+        self.conn.poll_for_raw_event_with_sequence()
+    }
+
+    fn requeue_raw_events(&self, events: Vec<RawEventAndSeqNumber<Self::Buf>>) {
+        self.conn.requeue_raw_events(events)
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.conn.flush()
+    }
+
+    fn last_event_time(&self) -> xproto::Timestamp {
+        self.conn.last_event_time()
+    }
+
+    fn supports_fd_passing(&self) -> bool {
+        self.conn.supports_fd_passing()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.conn.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.conn.generate_id()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::{parse_request, Change, ResourceKind, ResourceTracker};
+    use crate::protocol::xproto;
+
+    fn request(opcode: u8, id: u32) -> Vec<u8> {
+        let mut result = vec![opcode, 0, 2, 0];
+        result.extend(id.to_ne_bytes());
+        result
+    }
+
+    #[test]
+    fn test_parse_request() {
+        let create = request(xproto::CREATE_GC_REQUEST, 0x1234);
+        assert_eq!(
+            parse_request(&[IoSlice::new(&create)]),
+            Some((Change::Create(ResourceKind::Gcontext), 0x1234))
+        );
+
+        // The header may be split over several buffers
+        let free = request(xproto::FREE_GC_REQUEST, 0x1234);
+        let (first, second) = free.split_at(5);
+        assert_eq!(
+            parse_request(&[IoSlice::new(first), IoSlice::new(second)]),
+            Some((Change::Free, 0x1234))
+        );
+
+        // Other requests are ignored, also when they are too short
+        let other = request(xproto::MAP_WINDOW_REQUEST, 0x1234);
+        assert_eq!(parse_request(&[IoSlice::new(&other)]), None);
+        assert_eq!(parse_request(&[IoSlice::new(&create[..6])]), None);
+    }
+
+    #[test]
+    fn test_record() {
+        let tracker = ResourceTracker::new(());
+        for &(opcode, id) in &[
+            (xproto::CREATE_WINDOW_REQUEST, 3),
+            (xproto::CREATE_PIXMAP_REQUEST, 2),
+            (xproto::CREATE_GC_REQUEST, 1),
+            (xproto::CREATE_GC_REQUEST, 4),
+            (xproto::FREE_PIXMAP_REQUEST, 2),
+            (xproto::FREE_PIXMAP_REQUEST, 5),
+        ] {
+            tracker.record(&[IoSlice::new(&request(opcode, id))]);
+        }
+        assert_eq!(
+            tracker.live_resources(),
+            [
+                (ResourceKind::Gcontext, 1),
+                (ResourceKind::Window, 3),
+                (ResourceKind::Gcontext, 4)
+            ]
+        );
+        assert_eq!(
+            tracker.live_resources_of_kind(ResourceKind::Gcontext),
+            [1, 4]
+        );
+        assert!(tracker
+            .live_resources_of_kind(ResourceKind::Pixmap)
+            .is_empty());
+    }
+}
@@ -3,19 +3,25 @@
 use std::convert::TryInto;
 use std::io::IoSlice;
 use std::mem::drop;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(windows)]
+use std::os::windows::io::{AsRawSocket, RawSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
 
 use crate::connection::{
-    compute_length_field, Connection, ReplyOrError, RequestConnection, RequestKind,
+    compute_length_field, Connection, LastEventTime, ReplyOrError, RequestConnection, RequestKind,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
-use crate::protocol::xproto::{Setup, GET_INPUT_FOCUS_REQUEST};
+use crate::protocol::xproto::{Setup, Timestamp, GET_INPUT_FOCUS_REQUEST};
 use crate::utils::RawFdContainer;
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 use x11rb_protocol::connect::Connect;
+pub use x11rb_protocol::connection::QueueOverflowPolicy;
 use x11rb_protocol::connection::{Connection as ProtoConnection, PollReply, ReplyFdKind};
 use x11rb_protocol::id_allocator::IdAllocator;
 use x11rb_protocol::{xauth::get_auth, DiscardMode, RawEventAndSeqNumber, SequenceNumber};
@@ -32,6 +38,17 @@ type Buffer = <RustConnection as RequestConnection>::Buf;
 /// A combination of a buffer and a list of file descriptors for use by [`RustConnection`].
 pub type BufWithFds = crate::connection::BufWithFds<Buffer>;
 
+/// A `GetInputFocus` request, which is used for synchronising with the X11 server.
+const SYNC_REQUEST: [u8; 4] = {
+    let length = 1u16.to_ne_bytes();
+    [
+        GET_INPUT_FOCUS_REQUEST,
+        0, /* pad */
+        length[0],
+        length[1],
+    ]
+};
+
 #[derive(Debug)]
 enum MaxRequestBytes {
     Unknown,
@@ -73,6 +90,82 @@ pub struct RustConnection<S: Stream = DefaultStream> {
     extension_manager: Mutex<ExtensionManager>,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
     id_allocator: Mutex<IdAllocator>,
+    synchronous: AtomicBool,
+    last_event_time: LastEventTime,
+}
+
+/// Settings for establishing a [`RustConnection`].
+///
+/// ```no_run
+/// use x11rb::rust_connection::{QueueOverflowPolicy, RustConnectionBuilder};
+///
+/// let (conn, screen_num) = RustConnectionBuilder::new()
+///     .max_event_queue(Some(1000))
+///     .queue_overflow_policy(QueueOverflowPolicy::DropOldest)
+///     .connect(None)?;
+/// # Ok::<(), x11rb::errors::ConnectError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RustConnectionBuilder {
+    max_event_queue: Option<usize>,
+    queue_overflow_policy: QueueOverflowPolicy,
+}
+
+impl Default for RustConnectionBuilder {
+    fn default() -> Self {
+        Self {
+            max_event_queue: None,
+            queue_overflow_policy: QueueOverflowPolicy::DropOldest,
+        }
+    }
+}
+
+impl RustConnectionBuilder {
+    /// Create a builder with the default settings.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Limit the number of events that are buffered by the connection.
+    ///
+    /// See [`RustConnection::set_max_event_queue`]. The default is `None`, i.e. no limit.
+    pub fn max_event_queue(mut self, max: Option<usize>) -> Self {
+        self.max_event_queue = max;
+        self
+    }
+
+    /// Set what happens when an event arrives while the event queue is full.
+    ///
+    /// This only has an effect together with [`RustConnectionBuilder::max_event_queue`]. The
+    /// default is [`QueueOverflowPolicy::DropOldest`].
+    pub fn queue_overflow_policy(mut self, policy: QueueOverflowPolicy) -> Self {
+        self.queue_overflow_policy = policy;
+        self
+    }
+
+    /// Establish a new connection with these settings.
+    ///
+    /// See [`RustConnection::connect`].
+    pub fn connect(self, dpy_name: Option<&str>) -> Result<(RustConnection, usize), ConnectError> {
+        let (conn, screen) = RustConnection::connect(dpy_name)?;
+        Ok((self.apply(conn), screen))
+    }
+
+    /// Establish a new connection to the given stream with these settings.
+    ///
+    /// See [`RustConnection::connect_to_stream`].
+    pub fn connect_to_stream<S: Stream>(
+        self,
+        stream: S,
+        screen: usize,
+    ) -> Result<RustConnection<S>, ConnectError> {
+        Ok(self.apply(RustConnection::connect_to_stream(stream, screen)?))
+    }
+
+    fn apply<S: Stream>(self, conn: RustConnection<S>) -> RustConnection<S> {
+        conn.set_max_event_queue(self.max_event_queue, self.queue_overflow_policy);
+        conn
+    }
 }
 
 // Locking rules
@@ -259,6 +352,8 @@ impl<S: Stream> RustConnection<S> {
             extension_manager: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
             id_allocator: Mutex::new(id_allocator),
+            synchronous: AtomicBool::new(false),
+            last_event_time: Default::default(),
         })
     }
 
@@ -286,7 +381,10 @@ impl<S: Stream> RustConnection<S> {
             match send_result {
                 Some(seqno) => {
                     // Now actually send the buffers
-                    let _inner = self.write_all_vectored(inner, bufs, fds)?;
+                    let inner = self.write_all_vectored(inner, bufs, fds)?;
+                    if self.synchronous.load(Ordering::Relaxed) {
+                        self.wait_for_sync(inner)?;
+                    }
                     return Ok(seqno);
                 }
                 None => {
@@ -305,14 +403,6 @@ impl<S: Stream> RustConnection<S> {
         &'a self,
         mut inner: MutexGuardInner<'a>,
     ) -> Result<MutexGuardInner<'a>, std::io::Error> {
-        let length = 1u16.to_ne_bytes();
-        let request = [
-            GET_INPUT_FOCUS_REQUEST,
-            0, /* pad */
-            length[0],
-            length[1],
-        ];
-
         let seqno = inner
             .inner
             .send_request(ReplyFdKind::ReplyWithoutFDs)
@@ -320,11 +410,25 @@ impl<S: Stream> RustConnection<S> {
         inner
             .inner
             .discard_reply(seqno, DiscardMode::DiscardReplyAndError);
-        let inner = self.write_all_vectored(inner, &[IoSlice::new(&request)], Vec::new())?;
+        let inner = self.write_all_vectored(inner, &[IoSlice::new(&SYNC_REQUEST)], Vec::new())?;
 
         Ok(inner)
     }
 
+    /// Wait until the X11 server processed all requests that were sent so far.
+    ///
+    /// This function sends a `GetInputFocus` request to the X11 server and waits for its reply.
+    fn wait_for_sync(&self, mut inner: MutexGuardInner<'_>) -> Result<(), ConnectionError> {
+        let seqno = inner
+            .inner
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .expect("Sending a HasResponse request should not be blocked by syncs");
+        let inner = self.write_all_vectored(inner, &[IoSlice::new(&SYNC_REQUEST)], Vec::new())?;
+        drop(inner);
+        let _ = self.wait_for_reply(seqno)?;
+        Ok(())
+    }
+
     /// Write a set of buffers on a `writer`. May also read packets
     /// from the server.
     fn write_all_vectored<'a>(
@@ -510,6 +614,39 @@ impl<S: Stream> RustConnection<S> {
     pub fn stream(&self) -> &S {
         &self.stream
     }
+
+    /// Limit the number of events that are buffered by this connection.
+    ///
+    /// Events are buffered until they are fetched with e.g. `wait_for_event()`. A client that
+    /// does not keep up with a burst of events can thus use lots of memory. With a limit, events
+    /// are discarded according to `policy` once `max` events are buffered. `None` removes the
+    /// limit, which is the default.
+    ///
+    /// With [`QueueOverflowPolicy::Error`], the next call to fetch an event returns
+    /// [`ConnectionError::EventQueueOverflow`] after an event was discarded. The following calls
+    /// continue to return the remaining events. Discarded events are logged as warnings. Events
+    /// that were put back into the queue, e.g. by [`Connection::wait_for_event_matching`], do
+    /// not count towards the limit and are never discarded.
+    ///
+    /// The limit can also be set while connecting via [`RustConnectionBuilder`].
+    pub fn set_max_event_queue(&self, max: Option<usize>, policy: QueueOverflowPolicy) {
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .set_max_pending_events(max, policy);
+    }
+
+    /// Enable or disable synchronous mode, which is useful for debugging.
+    ///
+    /// In synchronous mode, every request is followed by a round trip to the X11 server, similar
+    /// to `XSynchronize` in libX11. Thus, when sending a request returns, the X11 server already
+    /// processed it. An error caused by a request that is not checked is thus available as an
+    /// event right afterwards, instead of arriving at some later point. This makes it easier to
+    /// find the request that caused an error, but makes the connection a lot slower.
+    pub fn set_synchronous(&self, synchronous: bool) {
+        self.synchronous.store(synchronous, Ordering::Relaxed);
+    }
 }
 
 impl<S: Stream> RequestConnection for RustConnection<S> {
@@ -603,6 +740,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                 PollReply::Reply(buffer) => return Ok(Some(buffer)),
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            check_unexpected_reply(&mut inner)?;
         }
     }
 
@@ -625,6 +763,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                 PollReply::Reply(buffer) => return Ok(Some(buffer)),
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            check_unexpected_reply(&mut inner)?;
         }
     }
 
@@ -644,6 +783,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
                 }
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+            check_unexpected_reply(&mut inner)?;
         }
     }
 
@@ -699,7 +839,12 @@ impl<S: Stream> Connection for RustConnection<S> {
     ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
         loop {
+            if inner.inner.take_event_queue_overflow() {
+                return Err(ConnectionError::EventQueueOverflow);
+            }
+            check_unexpected_reply(&mut inner)?;
             if let Some(event) = inner.inner.poll_for_event_with_sequence() {
+                self.last_event_time.update(&event.0);
                 return Ok(event);
             }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
@@ -710,12 +855,29 @@ impl<S: Stream> Connection for RustConnection<S> {
         &self,
     ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
         let mut inner = self.inner.lock().unwrap();
-        if let Some(event) = inner.inner.poll_for_event_with_sequence() {
-            Ok(Some(event))
+        if inner.inner.take_event_queue_overflow() {
+            return Err(ConnectionError::EventQueueOverflow);
+        }
+        let event = if let Some(event) = inner.inner.poll_for_event_with_sequence() {
+            Some(event)
         } else {
             inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
-            Ok(inner.inner.poll_for_event_with_sequence())
+            if inner.inner.take_event_queue_overflow() {
+                return Err(ConnectionError::EventQueueOverflow);
+            }
+            check_unexpected_reply(&mut inner)?;
+            inner.inner.poll_for_event_with_sequence()
+        };
+        if let Some(event) = &event {
+            self.last_event_time.update(&event.0);
         }
+        Ok(event)
+    }
+
+    fn requeue_raw_events(&self, events: Vec<RawEventAndSeqNumber<Vec<u8>>>) {
+        self.inner.lock().unwrap().inner.requeue_events(events);
+        // Threads that sleep in wait_for_event() until the reader is done should see the events
+        self.reader_condition.notify_all();
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
@@ -724,6 +886,14 @@ impl<S: Stream> Connection for RustConnection<S> {
         Ok(())
     }
 
+    fn last_event_time(&self) -> Timestamp {
+        self.last_event_time.get()
+    }
+
+    fn supports_fd_passing(&self) -> bool {
+        self.stream.supports_fd_passing()
+    }
+
     fn setup(&self) -> &Setup {
         &self.setup
     }
@@ -751,6 +921,40 @@ impl<S: Stream> Connection for RustConnection<S> {
     }
 }
 
+/// The file descriptor of the underlying stream.
+///
+/// This is meant for registering the connection with an event loop, see
+/// [`event_loop_integration`](crate::event_loop_integration). Do not read from the file
+/// descriptor directly. Events may already be buffered by the connection when the file descriptor
+/// is not readable, so always drain [`Connection::poll_for_event`] before waiting for it.
+#[cfg(unix)]
+impl<S: Stream + AsRawFd> AsRawFd for RustConnection<S> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.stream.as_raw_fd()
+    }
+}
+
+/// The socket of the underlying stream.
+///
+/// This is meant for registering the connection with an event loop, see
+/// [`event_loop_integration`](crate::event_loop_integration). Do not read from the socket
+/// directly. Events may already be buffered by the connection when the socket is not readable,
+/// so always drain [`Connection::poll_for_event`] before waiting for it.
+#[cfg(windows)]
+impl<S: Stream + AsRawSocket> AsRawSocket for RustConnection<S> {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.stream.as_raw_socket()
+    }
+}
+
+/// Report a reply that does not belong to any request as [`ConnectionError::ProtocolDesync`].
+fn check_unexpected_reply(inner: &mut MutexGuardInner<'_>) -> Result<(), ConnectionError> {
+    match inner.inner.take_unexpected_reply() {
+        Some(sequence) => Err(ConnectionError::ProtocolDesync { sequence }),
+        None => Ok(()),
+    }
+}
+
 /// Call `notify_all` on a condition variable when dropped.
 #[derive(Debug)]
 struct NotifyOnDrop<'a>(&'a Condvar);
@@ -170,6 +170,13 @@ pub trait Stream {
         }
         Ok(0)
     }
+
+    /// Check if file descriptors can be sent and received over this stream.
+    ///
+    /// The default implementation returns `false`.
+    fn supports_fd_passing(&self) -> bool {
+        false
+    }
 }
 
 /// A wrapper around a `TcpStream` or `UnixStream`.
@@ -387,6 +394,16 @@ fn do_write(
 }
 
 impl Stream for DefaultStream {
+    fn supports_fd_passing(&self) -> bool {
+        match self.inner {
+            DefaultStreamInner::TcpStream(_) => false,
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(_) => true,
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnix(_) => true,
+        }
+    }
+
     fn poll(&self, mode: PollMode) -> Result<()> {
         #[cfg(unix)]
         {
@@ -635,3 +652,26 @@ mod recvmsg {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::net::{Ipv4Addr, TcpListener, TcpStream};
+
+    use super::{DefaultStream, Stream};
+
+    #[test]
+    fn tcp_does_not_support_fd_passing() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let stream = DefaultStream::from_tcp_stream(client).unwrap();
+        assert!(!stream.supports_fd_passing());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unix_supports_fd_passing() {
+        let (client, _server) = std::os::unix::net::UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        assert!(stream.supports_fd_passing());
+    }
+}
@@ -159,6 +159,7 @@ impl WriteBuffer {
 
 #[cfg(test)]
 mod test {
+    use std::cell::RefCell;
     use std::io::{Error, ErrorKind, IoSlice, Result};
 
     use super::super::{PollMode, Stream};
@@ -206,4 +207,76 @@ mod test {
             Err(e) => panic!("Unexpected error: {:?}", e),
         }
     }
+
+    /// A stream that records the address and length of every slice that it is asked to write.
+    #[derive(Default)]
+    struct RecordingWriter(RefCell<Vec<Vec<(usize, usize)>>>);
+
+    impl Stream for RecordingWriter {
+        fn poll(&self, _mode: PollMode) -> Result<()> {
+            unimplemented!();
+        }
+
+        fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+            unimplemented!();
+        }
+
+        fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+            self.write_vectored(&[IoSlice::new(buf)], fds)
+        }
+
+        fn write_vectored(
+            &self,
+            bufs: &[IoSlice<'_>],
+            _fds: &mut Vec<RawFdContainer>,
+        ) -> Result<usize> {
+            let slices = bufs
+                .iter()
+                .filter(|buf| !buf.is_empty())
+                .map(|buf| (buf.as_ptr() as usize, buf.len()))
+                .collect();
+            self.0.borrow_mut().push(slices);
+            Ok(bufs.iter().map(|buf| buf.len()).sum())
+        }
+    }
+
+    // Large requests like PutImage should not be copied into the buffer, but be handed to the
+    // stream as separate slices.
+    #[test]
+    fn large_write_is_not_copied() {
+        let stream = RecordingWriter::default();
+        let mut write_buffer = WriteBuffer::with_capacity(16);
+        let header = [1; 8];
+        let payload = vec![2; 64];
+        let bufs = [IoSlice::new(&header), IoSlice::new(&payload)];
+        let written = write_buffer
+            .write_vectored(&stream, &bufs, &mut Vec::new())
+            .unwrap();
+        assert_eq!(written, 72);
+        assert!(!write_buffer.needs_flush());
+        let writes = stream.0.into_inner();
+        assert_eq!(
+            writes,
+            [vec![
+                (header.as_ptr() as usize, 8),
+                (payload.as_ptr() as usize, 64)
+            ]]
+        );
+    }
+
+    // Small requests are buffered instead
+    #[test]
+    fn small_write_is_buffered() {
+        let stream = RecordingWriter::default();
+        let mut write_buffer = WriteBuffer::with_capacity(16);
+        let bufs = [IoSlice::new(&[1; 4]), IoSlice::new(&[2; 4])];
+        let written = write_buffer
+            .write_vectored(&stream, &bufs, &mut Vec::new())
+            .unwrap();
+        assert_eq!(written, 8);
+        assert!(write_buffer.needs_flush());
+        assert!(stream.0.borrow().is_empty());
+        write_buffer.flush(&stream).unwrap();
+        assert_eq!(stream.0.borrow().len(), 1);
+    }
 }
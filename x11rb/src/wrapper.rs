@@ -1,10 +1,24 @@
 //! Some wrappers around the generated code to simplify use.
 
-use std::convert::TryInto;
+use std::collections::VecDeque;
+use std::convert::{TryFrom, TryInto};
+use std::ops::Range;
+use std::time::Duration;
 
+use super::connection::Connection;
 use super::cookie::VoidCookie;
-use super::errors::{ConnectionError, ReplyError};
-use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
+use super::errors::{
+    ConnectionError, CreateWindowError, CreateWindowRequestError, EventTooLong, GrabError,
+    ReplyError, ReplyOrIdError,
+};
+use super::protocol::xproto::{
+    Atom, AtomEnum, ChangeWindowAttributesAux, Char2b, Charinfo, ClientMessageEvent, ClipOrdering,
+    CloseDown, ConnectionExt as XProtoConnectionExt, CreateWindowAux, Drawable, EventMask,
+    Gcontext, GrabStatus, MapState, Pixmap, PropMode, QueryFontReply, Rectangle, Screen, Setup,
+    Timestamp, Visualid, Window, WindowClass,
+};
+use super::protocol::{ErrorKind, Event};
+use super::x11_utils::{parse_latin1, Serialize, X11Error};
 
 /// Extension trait that simplifies API use
 pub trait ConnectionExt: XProtoConnectionExt {
@@ -100,9 +114,1030 @@ pub trait ConnectionExt: XProtoConnectionExt {
         // reply to our GetInputFocus after everything before was processed.
         self.get_input_focus()?.reply().and(Ok(()))
     }
+
+    /// Clear a rectangle of a window and generate `Expose` events for it.
+    ///
+    /// This sends a `ClearArea` request with `exposures` enabled. The cleared area is filled with
+    /// the window's background and the server then sends `Expose` events for the parts of
+    /// `rect` that are visible, so that the usual redraw code paints it again.
+    ///
+    /// As with `ClearArea`, a `width` or `height` of zero means "until the edge of the window".
+    fn clear_and_expose(
+        &self,
+        window: Window,
+        rect: Rectangle,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        self.clear_area(true, window, rect.x, rect.y, rect.width, rect.height)
+    }
+
+    /// Reparent a window without changing its position on the screen.
+    ///
+    /// A `ReparentWindow` request places the window at the given coordinates relative to its new
+    /// parent. This function computes the coordinates that keep the window at its current
+    /// position, taking the window's border into account, and then reparents the window.
+    fn reparent_window_keep_position(
+        &self,
+        window: Window,
+        new_parent: Window,
+    ) -> Result<VoidCookie<'_, Self>, ReplyError> {
+        let geometry = self.get_geometry(window)?;
+        let translated = self.translate_coordinates(window, new_parent, 0, 0)?;
+        let border_width = i16::try_from(geometry.reply()?.border_width).unwrap_or(i16::MAX);
+        let translated = translated.reply()?;
+        let x = translated.dst_x.saturating_sub(border_width);
+        let y = translated.dst_y.saturating_sub(border_width);
+        Ok(self.reparent_window(window, new_parent, x, y)?)
+    }
+
+    /// Draw a string of 8 bit characters with `PolyText8`.
+    ///
+    /// A single text item of `PolyText8` can only contain 254 characters and the whole request
+    /// must not exceed the server's maximum request length. This function splits `text` into as
+    /// many text items and requests as necessary. The string is drawn with the font of `gc`,
+    /// starting with the baseline at `(x, y)`.
+    ///
+    /// When more than one request is needed, the width of the text that was already drawn is
+    /// queried with `QueryTextExtents`, so that the next request continues at the right position.
+    fn draw_text8(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        x: i16,
+        y: i16,
+        text: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ReplyError> {
+        let to_char2b = |chars: &[u8]| {
+            chars
+                .iter()
+                .map(|&byte2| Char2b { byte1: 0, byte2 })
+                .collect::<Vec<_>>()
+        };
+        draw_text(self, gc, x, text, 1, |x, chunk, items| {
+            let width = if chunk.end == text.len() {
+                None
+            } else {
+                Some(to_char2b(&text[chunk]))
+            };
+            Ok((self.poly_text8(drawable, gc, x, y, items)?, width))
+        })
+    }
+
+    /// Draw a string of 16 bit characters with `PolyText16`.
+    ///
+    /// This is the same as [`ConnectionExt::draw_text8`], but for fonts with two byte
+    /// characters.
+    fn draw_text16(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        x: i16,
+        y: i16,
+        text: &[Char2b],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ReplyError> {
+        let bytes = text
+            .iter()
+            .flat_map(|c| [c.byte1, c.byte2])
+            .collect::<Vec<_>>();
+        draw_text(self, gc, x, &bytes, 2, |x, chunk, items| {
+            let width = if chunk.end == text.len() {
+                None
+            } else {
+                Some(text[chunk].to_vec())
+            };
+            Ok((self.poly_text16(drawable, gc, x, y, items)?, width))
+        })
+    }
+
+    /// Set the clip mask of a graphics context to a list of rectangles.
+    ///
+    /// `SetClipRectangles` fails with a `Match` error if the rectangles do not satisfy the given
+    /// `ordering`. This function sorts the rectangles as required by `ordering`. For
+    /// [`ClipOrdering::YX_BANDED`], the rectangles must additionally form bands with identical
+    /// vertical extents. If they do not, [`ClipOrdering::YX_SORTED`] is used instead, which
+    /// describes the same clip region.
+    fn set_clip_rects(
+        &self,
+        gc: Gcontext,
+        clip_x_origin: i16,
+        clip_y_origin: i16,
+        rects: &[Rectangle],
+        ordering: ClipOrdering,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        let (ordering, rects) = sort_clip_rectangles(rects, ordering);
+        self.set_clip_rectangles(ordering, gc, clip_x_origin, clip_y_origin, &rects)
+    }
+
+    /// Append data to a property, split over as many requests as needed.
+    ///
+    /// The value of a property can be larger than the maximum request length. This function
+    /// sends `ChangeProperty` requests with [`PropMode::APPEND`], each of which appends a chunk of
+    /// `data` that fits into a single request. This is how e.g. chunked selection transfers grow
+    /// a property. The property has to have the same `type_` and `format` already, or not exist.
+    ///
+    /// `data` contains the raw bytes of the value, so for format 16 and 32 the items have to be in
+    /// native byte order. No request is sent if `data` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format` is not 8, 16, or 32, or if the length of `data` is not a multiple of
+    /// the size of an item.
+    fn append_property<A, B>(
+        &self,
+        window: Window,
+        property: A,
+        type_: B,
+        format: u8,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        change_property_chunked(
+            self,
+            PropMode::APPEND,
+            window,
+            property.into(),
+            type_.into(),
+            format,
+            data,
+        )
+    }
+
+    /// Replace the value of a property, split over as many requests as needed.
+    ///
+    /// This is like [`ConnectionExt::append_property`], except that the first request uses
+    /// [`PropMode::REPLACE`], so that the old value of the property is discarded, including its
+    /// type and format. If `data` is empty, the property is set to an empty value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format` is not 8, 16, or 32, or if the length of `data` is not a multiple of
+    /// the size of an item.
+    fn replace_property<A, B>(
+        &self,
+        window: Window,
+        property: A,
+        type_: B,
+        format: u8,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        change_property_chunked(
+            self,
+            PropMode::REPLACE,
+            window,
+            property.into(),
+            type_.into(),
+            format,
+            data,
+        )
+    }
+
+    /// Intern an atom and retry if the X11 server ran out of memory.
+    ///
+    /// The X11 server answers `InternAtom` with an `Alloc` error if it could not allocate the
+    /// atom. This error is transient, so the request is sent again up to `attempts` times in
+    /// total, sleeping for `delay` between the attempts if it is given. All other errors, e.g.
+    /// `Value` for an invalid `only_if_exists` or connection errors, are returned immediately.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use x11rb::wrapper::ConnectionExt as _;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (conn, _) = x11rb::connect(None)?;
+    /// let atom = conn.intern_atom_with_retry(false, b"_NET_WM_NAME", 3, Some(Duration::from_millis(10)))?;
+    /// # let _ = atom;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn intern_atom_with_retry(
+        &self,
+        only_if_exists: bool,
+        name: &[u8],
+        attempts: usize,
+        delay: Option<Duration>,
+    ) -> Result<Atom, ReplyError> {
+        let mut result = self.intern_atom(only_if_exists, name)?.reply();
+        for _ in 1..attempts {
+            match &result {
+                Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Alloc => {}
+                _ => break,
+            }
+            if let Some(delay) = delay {
+                std::thread::sleep(delay);
+            }
+            result = self.intern_atom(only_if_exists, name)?.reply();
+        }
+        Ok(result?.atom)
+    }
+
+    /// Get information about the X11 server's implementation.
+    ///
+    /// This is a summary of the vendor and version fields of [`Connection::setup`], see
+    /// [`ServerInfo`] for details.
+    ///
+    /// ```no_run
+    /// use x11rb::wrapper::{ConnectionExt as _, ServerKind};
+    ///
+    /// let (conn, _) = x11rb::connect(None)?;
+    /// if conn.server_info().kind == ServerKind::Xwayland {
+    ///     println!("Running on Wayland");
+    /// }
+    /// # Ok::<(), x11rb::errors::ConnectError>(())
+    /// ```
+    fn server_info(&self) -> ServerInfo
+    where
+        Self: Connection,
+    {
+        ServerInfo::from_setup(self.setup())
+    }
 }
 impl<C: XProtoConnectionExt + ?Sized> ConnectionExt for C {}
 
+/// Sort rectangles as required by the given ordering of a `SetClipRectangles` request.
+///
+/// Returns the ordering that the sorted rectangles satisfy.
+fn sort_clip_rectangles(
+    rects: &[Rectangle],
+    ordering: ClipOrdering,
+) -> (ClipOrdering, Vec<Rectangle>) {
+    let mut rects = rects.to_vec();
+    match ordering {
+        ClipOrdering::Y_SORTED => rects.sort_by_key(|r| r.y),
+        ClipOrdering::YX_SORTED | ClipOrdering::YX_BANDED => rects.sort_by_key(|r| (r.y, r.x)),
+        _ => return (ordering, rects),
+    }
+    if ordering == ClipOrdering::YX_BANDED && !is_yx_banded(&rects) {
+        (ClipOrdering::YX_SORTED, rects)
+    } else {
+        (ordering, rects)
+    }
+}
+
+/// Check if rectangles that are sorted by Y and X form bands.
+///
+/// In a band, all rectangles have the same Y coordinate and height. Different bands may not
+/// overlap vertically.
+fn is_yx_banded(rects: &[Rectangle]) -> bool {
+    let bottom = |r: &Rectangle| i32::from(r.y) + i32::from(r.height);
+    rects.windows(2).all(|pair| {
+        let (a, b) = (&pair[0], &pair[1]);
+        (a.y == b.y && a.height == b.height) || bottom(a) <= i32::from(b.y)
+    })
+}
+
+/// The size of a `ChangeProperty` request without its data.
+const CHANGE_PROPERTY_HEADER_BYTES: usize = 24;
+
+/// Split the value of a property into chunks that fit into one `ChangeProperty` request of at
+/// most `max_request_bytes` bytes.
+fn property_chunks(
+    data: &[u8],
+    format: u8,
+    max_request_bytes: usize,
+) -> std::slice::Chunks<'_, u8> {
+    let item_size = match format {
+        8 | 16 | 32 => usize::from(format / 8),
+        _ => panic!("Invalid property format {}", format),
+    };
+    assert_eq!(
+        data.len() % item_size,
+        0,
+        "`data` does not contain a whole number of items"
+    );
+    // Chunks that are multiples of four bytes do not need any padding
+    let chunk_size = max_request_bytes.saturating_sub(CHANGE_PROPERTY_HEADER_BYTES) / 4 * 4;
+    assert!(chunk_size > 0, "Maximum request length is too small");
+    data.chunks(chunk_size)
+}
+
+/// Implementation of `append_property` and `replace_property`.
+///
+/// The first request uses `first_mode`, all other requests append to the property.
+fn change_property_chunked<'c, C: XProtoConnectionExt + ?Sized>(
+    conn: &'c C,
+    first_mode: PropMode,
+    window: Window,
+    property: Atom,
+    type_: Atom,
+    format: u8,
+    data: &[u8],
+) -> Result<Vec<VoidCookie<'c, C>>, ConnectionError> {
+    let item_size = usize::from(format / 8);
+    let mut cookies = Vec::new();
+    let mut mode = first_mode;
+    for chunk in property_chunks(data, format, conn.maximum_request_bytes()) {
+        let items = (chunk.len() / item_size).try_into().unwrap();
+        cookies.push(conn.change_property(mode, window, property, type_, format, items, chunk)?);
+        mode = PropMode::APPEND;
+    }
+    if cookies.is_empty() && first_mode == PropMode::REPLACE {
+        cookies.push(conn.change_property(mode, window, property, type_, format, 0, &[])?);
+    }
+    Ok(cookies)
+}
+
+/// The maximum number of characters in a text item of `PolyText8` or `PolyText16`.
+const MAX_TEXT_ITEM_CHARS: usize = 254;
+
+/// The size of a `PolyText8` or `PolyText16` request without its text items.
+const POLY_TEXT_HEADER_BYTES: usize = 16;
+
+/// Split `chars` characters of `char_size` bytes each into ranges that fit into one `PolyText`
+/// request of at most `max_request_bytes` bytes.
+fn poly_text_requests(
+    chars: usize,
+    char_size: usize,
+    max_request_bytes: usize,
+) -> Vec<Range<usize>> {
+    // Leave room for the padding at the end of the request
+    let available = max_request_bytes.saturating_sub(POLY_TEXT_HEADER_BYTES + 3);
+    let item_bytes = 2 + MAX_TEXT_ITEM_CHARS * char_size;
+    let rest = available % item_bytes;
+    let per_request =
+        (available / item_bytes) * MAX_TEXT_ITEM_CHARS + rest.saturating_sub(2) / char_size;
+    assert!(per_request > 0, "Maximum request length is too small");
+    (0..chars)
+        .step_by(per_request)
+        .map(|start| start..chars.min(start + per_request))
+        .collect()
+}
+
+/// Encode the given text as a list of `PolyText` text items.
+fn poly_text_items(text: &[u8], char_size: usize) -> Vec<u8> {
+    let mut items = Vec::with_capacity(text.len() + 2 * (text.len() / MAX_TEXT_ITEM_CHARS + 1));
+    for chunk in text.chunks(MAX_TEXT_ITEM_CHARS * char_size) {
+        // The length is at most 254, the delta is always zero
+        items.extend([(chunk.len() / char_size) as u8, 0]);
+        items.extend(chunk);
+    }
+    items
+}
+
+/// Implementation of `draw_text8` and `draw_text16`.
+///
+/// `send` gets the position, the range of characters, and the text items for one request. It
+/// sends the request and returns the characters whose width is needed for the next request, if
+/// there is one.
+fn draw_text<'c, C, F>(
+    conn: &'c C,
+    gc: Gcontext,
+    mut x: i16,
+    text: &[u8],
+    char_size: usize,
+    mut send: F,
+) -> Result<Vec<VoidCookie<'c, C>>, ReplyError>
+where
+    C: XProtoConnectionExt + ?Sized,
+    F: FnMut(
+        i16,
+        Range<usize>,
+        &[u8],
+    ) -> Result<(VoidCookie<'c, C>, Option<Vec<Char2b>>), ReplyError>,
+{
+    let requests = poly_text_requests(
+        text.len() / char_size,
+        char_size,
+        conn.maximum_request_bytes(),
+    );
+    let mut cookies = Vec::with_capacity(requests.len());
+    for chunk in requests {
+        let bytes = &text[chunk.start * char_size..chunk.end * char_size];
+        let (cookie, drawn) = send(x, chunk, &poly_text_items(bytes, char_size))?;
+        cookies.push(cookie);
+        if let Some(drawn) = drawn {
+            // A graphics context can be used as a fontable
+            let width = conn.query_text_extents(gc, &drawn)?.reply()?.overall_width;
+            x = x.saturating_add(i16::try_from(width).unwrap_or(i16::MAX));
+        }
+    }
+    Ok(cookies)
+}
+
+/// Compute the width of an 8 bit string from the metrics of its font.
+///
+/// This sums up the `character_width` of all characters, like `QueryTextExtents` does, but
+/// without a round trip to the X11 server. As in the X11 server, characters that do not exist in
+/// the font are replaced with the font's `default_char`. If that does not exist either, the
+/// character has no width. A character does not exist if it is outside of the range of the font
+/// or if all its metrics are zero.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::{ConnectionExt, Font};
+/// use x11rb::wrapper::text_width;
+///
+/// fn layout(conn: &impl Connection, font: Font) -> Result<(), Box<dyn std::error::Error>> {
+///     let metrics = conn.query_font(font)?.reply()?;
+///     for word in ["Hello", "World"] {
+///         println!("{} is {} pixels wide", word, text_width(&metrics, word.as_bytes()));
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn text_width(font_reply: &QueryFontReply, text: &[u8]) -> i32 {
+    let glyph = |code| {
+        font_reply
+            .char_info(code)
+            .filter(|info| *info != Charinfo::default())
+    };
+    let default_width = glyph(font_reply.default_char).map_or(0, |info| info.character_width);
+    text.iter()
+        .map(|&code| glyph(code.into()).map_or(default_width, |info| info.character_width))
+        .map(i32::from)
+        .sum()
+}
+
+/// Create a pixmap with the same depth as the given drawable.
+///
+/// A pixmap can only be used together with drawables of the same depth, for example as the
+/// source of a `CopyArea` request. This function queries the depth of `drawable` via
+/// `GetGeometry` and then creates a pixmap of that depth on the same screen.
+///
+/// The returned pixmap should be freed with `FreePixmap` when it is no longer needed.
+pub fn create_pixmap_matching<C: Connection>(
+    conn: &C,
+    drawable: Drawable,
+    width: u16,
+    height: u16,
+) -> Result<Pixmap, ReplyOrIdError> {
+    let depth = conn.get_geometry(drawable)?.reply()?.depth;
+    let pixmap = conn.generate_id()?;
+    drop(conn.create_pixmap(depth, pixmap, drawable, width, height)?);
+    Ok(pixmap)
+}
+
+/// Create an `InputOnly` window that covers the given rectangle of `parent`.
+///
+/// An `InputOnly` window is invisible and only receives input, which is e.g. useful for an
+/// overlay that tracks the pointer or for detecting when the pointer reaches a screen edge. The
+/// X11 server reports a `Match` error unless such a window has depth zero, no border, and no
+/// attributes that concern its content, see [`validate_create_window`]. This function creates a
+/// window with the right parameters and the given event mask.
+///
+/// The window is not mapped. It should be destroyed with `DestroyWindow` when it is no longer
+/// needed.
+pub fn create_input_only_window<C: Connection>(
+    conn: &C,
+    parent: Window,
+    rect: Rectangle,
+    event_mask: EventMask,
+) -> Result<Window, ReplyOrIdError> {
+    let window = conn.generate_id()?;
+    let aux = CreateWindowAux::new().event_mask(event_mask);
+    drop(conn.create_window(
+        0,
+        window,
+        parent,
+        rect.x,
+        rect.y,
+        rect.width,
+        rect.height,
+        0,
+        WindowClass::INPUT_ONLY,
+        super::COPY_FROM_PARENT,
+        &aux,
+    )?);
+    Ok(window)
+}
+
+/// Collect all X11 errors that were generated by previously sent requests.
+///
+/// This function synchronises with the X11 server (see [`ConnectionExt::sync`]) so that all
+/// errors for previously sent requests have been received. Afterwards, the errors are removed
+/// from the event queue and returned. All other events are put back into the event queue in
+/// their original order.
+///
+/// This is mostly useful in tests, where a sequence of requests should not produce any errors:
+/// ```no_run
+/// # use x11rb::connection::Connection;
+/// # fn test(conn: &impl Connection) -> Result<(), Box<dyn std::error::Error>> {
+/// // ...send some requests...
+/// let errors = x11rb::wrapper::collect_pending_errors(conn)?;
+/// assert!(errors.is_empty(), "Unexpected errors: {:?}", errors);
+/// # Ok(())
+/// # }
+/// ```
+pub fn collect_pending_errors<C: Connection>(conn: &C) -> Result<Vec<X11Error>, ReplyError> {
+    conn.sync()?;
+    let mut errors = Vec::new();
+    let mut skipped = Vec::new();
+    let result = loop {
+        let (event, seqno) = match conn.poll_for_raw_event_with_sequence() {
+            Ok(Some(event)) => event,
+            Ok(None) => break Ok(errors),
+            Err(err) => break Err(err.into()),
+        };
+        // The response type of errors is zero
+        if event.as_ref()[0] != 0 {
+            skipped.push((event, seqno));
+            continue;
+        }
+        match conn.parse_error(event.as_ref()) {
+            Ok(error) => errors.push(error),
+            Err(err) => {
+                skipped.push((event, seqno));
+                break Err(ConnectionError::from(err).into());
+            }
+        }
+    };
+    conn.requeue_raw_events(skipped);
+    result
+}
+
+/// Check the parameters of a `CreateWindow` request before sending it.
+///
+/// The X11 server rejects some combinations of parameters with a `Match` error. This function
+/// catches the common cases locally, based on the information that `screen` provides:
+///
+/// - An `InputOnly` window must have depth zero and a border width of zero, and only a few
+///   attributes (`win_gravity`, `event_mask`, `do_not_propagate_mask`, `override_redirect` and
+///   `cursor`) can be set.
+/// - For an `InputOutput` window, a non-zero `depth` must be supported by the screen, and a
+///   visual other than `COPY_FROM_PARENT` must be supported for that depth.
+///
+/// Checks that require knowing the parent window, like
+/// `COPY_DEPTH_FROM_PARENT`, are not done.
+///
+/// ```
+/// use x11rb::wrapper::validate_create_window;
+/// use x11rb::protocol::xproto::{CreateWindowAux, Screen, WindowClass};
+///
+/// # let screen = Screen::default();
+/// let aux = CreateWindowAux::new().background_pixel(0);
+/// assert!(validate_create_window(&screen, 0, WindowClass::INPUT_ONLY, 0, 0, &aux).is_err());
+/// ```
+pub fn validate_create_window(
+    screen: &Screen,
+    depth: u8,
+    class: WindowClass,
+    visual: Visualid,
+    border_width: u16,
+    value_list: &CreateWindowAux,
+) -> Result<(), CreateWindowError> {
+    if class == WindowClass::INPUT_ONLY {
+        if depth != 0 {
+            return Err(CreateWindowError::InputOnlyDepth(depth));
+        }
+        if border_width != 0 {
+            return Err(CreateWindowError::InputOnlyBorderWidth(border_width));
+        }
+        let invalid_attributes = [
+            ("background_pixmap", value_list.background_pixmap.is_some()),
+            ("background_pixel", value_list.background_pixel.is_some()),
+            ("border_pixmap", value_list.border_pixmap.is_some()),
+            ("border_pixel", value_list.border_pixel.is_some()),
+            ("bit_gravity", value_list.bit_gravity.is_some()),
+            ("backing_store", value_list.backing_store.is_some()),
+            ("backing_planes", value_list.backing_planes.is_some()),
+            ("backing_pixel", value_list.backing_pixel.is_some()),
+            ("save_under", value_list.save_under.is_some()),
+            ("colormap", value_list.colormap.is_some()),
+        ];
+        if let Some((name, _)) = invalid_attributes.iter().find(|(_, is_set)| *is_set) {
+            return Err(CreateWindowError::InputOnlyAttribute(name));
+        }
+    }
+    if class == WindowClass::INPUT_OUTPUT && depth != 0 {
+        let depth_info = screen
+            .allowed_depths
+            .iter()
+            .find(|d| d.depth == depth)
+            .ok_or(CreateWindowError::UnsupportedDepth(depth))?;
+        if visual != super::COPY_FROM_PARENT
+            && !depth_info.visuals.iter().any(|v| v.visual_id == visual)
+        {
+            return Err(CreateWindowError::UnsupportedVisual(visual));
+        }
+    } else if visual != super::COPY_FROM_PARENT
+        && !screen
+            .allowed_depths
+            .iter()
+            .any(|d| d.visuals.iter().any(|v| v.visual_id == visual))
+    {
+        return Err(CreateWindowError::UnsupportedVisual(visual));
+    }
+    Ok(())
+}
+
+/// Send a `CreateWindow` request after checking its parameters with [`validate_create_window`].
+///
+/// The parameters are the same as for `xproto::create_window`, plus the `screen` that the window
+/// is created on. Invalid parameters are reported as
+/// [`CreateWindowRequestError::InvalidParameters`] and nothing is sent. Since not everything can
+/// be checked locally, the X11 server can still report an error for the returned cookie.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::{CreateWindowAux, EventMask, WindowClass};
+/// use x11rb::wrapper::create_window_validated;
+///
+/// let (conn, screen_num) = x11rb::connect(None)?;
+/// let screen = &conn.setup().roots[screen_num];
+/// let window = conn.generate_id()?;
+/// let aux = CreateWindowAux::new().event_mask(EventMask::POINTER_MOTION);
+/// create_window_validated(
+///     &conn, screen, 0, window, screen.root, 0, 0, 100, 100, 0,
+///     WindowClass::INPUT_ONLY, x11rb::COPY_FROM_PARENT, &aux,
+/// )?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn create_window_validated<'c, C: Connection>(
+    conn: &'c C,
+    screen: &Screen,
+    depth: u8,
+    wid: Window,
+    parent: Window,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    class: WindowClass,
+    visual: Visualid,
+    value_list: &CreateWindowAux,
+) -> Result<VoidCookie<'c, C>, CreateWindowRequestError> {
+    validate_create_window(screen, depth, class, visual, border_width, value_list)?;
+    Ok(conn.create_window(
+        depth,
+        wid,
+        parent,
+        x,
+        y,
+        width,
+        height,
+        border_width,
+        class,
+        visual,
+        value_list,
+    )?)
+}
+
+/// Check whether a window manager is running on the given screen.
+///
+/// ICCCM window managers own the `WM_Sn` manager selection, where `n` is the screen number. This
+/// function checks whether that selection has an owner. It does not change anything on the
+/// server, but it also does not notice window managers that do not follow ICCCM. Use
+/// [`become_window_manager`] to really find out whether another client manages the screen.
+///
+/// Note that this reports `false` if the window manager is this connection itself.
+///
+/// # Panics
+///
+/// Panics if `screen_num` is not the number of a screen in the connection's setup.
+pub fn another_wm_running<C: Connection>(conn: &C, screen_num: usize) -> Result<bool, ReplyError> {
+    assert!(
+        screen_num < conn.setup().roots.len(),
+        "Invalid screen number {}",
+        screen_num
+    );
+    let selection = format!("WM_S{}", screen_num);
+    let selection = conn.intern_atom(true, selection.as_bytes())?.reply()?.atom;
+    if selection == super::NONE {
+        return Ok(false);
+    }
+    Ok(conn.get_selection_owner(selection)?.reply()?.owner != super::NONE)
+}
+
+/// Select `SubstructureRedirect` on the root window of the given screen.
+///
+/// Only one client at a time can select `SubstructureRedirect` on a root window, which is what
+/// makes a client the window manager: Afterwards, the `MapRequest` and `ConfigureRequest` events
+/// of other clients are sent to this connection instead of being carried out. Thus, only the
+/// window manager that is about to take over the screen may call this function, and it has to
+/// handle these events from now on. The root window's other selected events are kept.
+///
+/// `false` is returned if another client already selected `SubstructureRedirect`, i.e. if
+/// another window manager is running.
+///
+/// # Panics
+///
+/// Panics if `screen_num` is not the number of a screen in the connection's setup.
+pub fn become_window_manager<C: Connection>(
+    conn: &C,
+    screen_num: usize,
+) -> Result<bool, ReplyError> {
+    let root = conn.setup().roots[screen_num].root;
+    let old_mask = conn.get_window_attributes(root)?.reply()?.your_event_mask;
+    let aux =
+        ChangeWindowAttributesAux::new().event_mask(old_mask | EventMask::SUBSTRUCTURE_REDIRECT);
+    match conn.change_window_attributes(root, &aux)?.check() {
+        Ok(()) => Ok(true),
+        Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Access => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+/// Get the 32 bytes that `SendEvent` expects for the given event.
+///
+/// Events are always 32 bytes long on the wire. A serialized form that is shorter is padded with
+/// zeros. [`EventTooLong`] is returned if the serialized form is longer, since that is not an
+/// event that can be sent.
+///
+/// All events that x11rb generates can be converted into `[u8; 32]` directly and can be given to
+/// `send_event` as they are. This function is meant for events that are defined outside of x11rb
+/// and only implement [`Serialize`]. Since there is no trait that all events implement, this
+/// function cannot check that `event` really is an event.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::{ClientMessageEvent, ConnectionExt, EventMask};
+/// use x11rb::wrapper::event_bytes;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, _) = x11rb::connect(None)?;
+/// # let (window, wm_protocols, wm_delete_window): (u32, u32, u32) = (0, 0, 0);
+/// let data = [wm_delete_window, x11rb::CURRENT_TIME, 0, 0, 0];
+/// let event = ClientMessageEvent::new(32, window, wm_protocols, data);
+/// conn.send_event(false, window, EventMask::NO_EVENT, event_bytes(&event)?)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn event_bytes<E>(event: &E) -> Result<[u8; 32], EventTooLong>
+where
+    E: Serialize + ?Sized,
+    E::Bytes: AsRef<[u8]>,
+{
+    let serialized = event.serialize();
+    let serialized = serialized.as_ref();
+    let mut bytes = [0; 32];
+    bytes
+        .get_mut(..serialized.len())
+        .ok_or(EventTooLong {
+            length: serialized.len(),
+        })?
+        .copy_from_slice(serialized);
+    Ok(bytes)
+}
+
+/// Ask a window to close itself via the `WM_DELETE_WINDOW` protocol from ICCCM.
+///
+/// If `WM_DELETE_WINDOW` is listed in the window's `WM_PROTOCOLS` property, a `ClientMessage` is
+/// sent to the window and `true` is returned. Otherwise, nothing is sent and `false` is returned.
+/// The client may ignore the message, e.g. because it is unresponsive, in which case
+/// [`force_kill_window`] can be used afterwards.
+pub fn request_window_close<C: Connection>(conn: &C, window: Window) -> Result<bool, ReplyError> {
+    let wm_protocols = conn.intern_atom(false, b"WM_PROTOCOLS")?;
+    let wm_delete_window = conn.intern_atom(false, b"WM_DELETE_WINDOW")?;
+    let wm_protocols = wm_protocols.reply()?.atom;
+    let wm_delete_window = wm_delete_window.reply()?.atom;
+
+    let protocols = conn
+        .get_property(false, window, wm_protocols, AtomEnum::ATOM, 0, u32::MAX)?
+        .reply()?;
+    let supported = protocols.value32().map_or(false, |mut atoms| {
+        atoms.any(|atom| atom == wm_delete_window)
+    });
+    if supported {
+        let data = [wm_delete_window, super::CURRENT_TIME, 0, 0, 0];
+        let event = ClientMessageEvent::new(32, window, wm_protocols, data);
+        conn.send_event(false, window, EventMask::NO_EVENT, event)?
+            .check()?;
+    }
+    Ok(supported)
+}
+
+/// Kill the client that created the given window via `KillClient`.
+///
+/// `KillClient` closes the connection of the client that owns the given resource and destroys
+/// all of its resources (unless its close down mode says otherwise). For a window manager, the
+/// window that the user interacts with is often a frame window that belongs to the window manager
+/// itself. Thus, this function uses the resource ID base and mask of `conn` to detect windows
+/// that were created by `conn`. For such windows, their children are searched (breadth-first)
+/// for a window that belongs to another client, and that client is killed instead. If no such
+/// window is found, nothing is done. Root windows are never killed.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::Window;
+/// use x11rb::wrapper::{force_kill_window, request_window_close};
+///
+/// fn close(conn: &impl Connection, window: Window, force: bool) -> Result<(), Box<dyn std::error::Error>> {
+///     if force || !request_window_close(conn, window)? {
+///         force_kill_window(conn, window)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn force_kill_window<C: Connection>(conn: &C, window: Window) -> Result<(), ReplyError> {
+    let setup = conn.setup();
+    let is_own = |window: Window| window & !setup.resource_id_mask == setup.resource_id_base;
+    let is_root = |window: Window| setup.roots.iter().any(|screen| screen.root == window);
+
+    let mut pending = VecDeque::from(vec![window]);
+    while let Some(window) = pending.pop_front() {
+        if is_root(window) {
+            continue;
+        }
+        if !is_own(window) {
+            conn.kill_client(window)?.check()?;
+            return Ok(());
+        }
+        pending.extend(conn.query_tree(window)?.reply()?.children);
+    }
+    Ok(())
+}
+
+/// Get the current time of the X11 server.
+///
+/// The X11 server only reports its time as part of events. This function creates a temporary
+/// `InputOnly` window, appends zero bytes to a property of it and waits for the resulting
+/// `PropertyNotify` event. Other events that arrive in the mean time stay in the event queue.
+///
+/// Since the X11 server processes the requests of a client in order, all requests that `conn`
+/// sent before were processed before the returned time.
+pub fn server_time<C: Connection>(conn: &C) -> Result<Timestamp, ReplyOrIdError> {
+    let root = conn.setup().roots[0].root;
+    let rect = Rectangle {
+        x: -1,
+        y: -1,
+        width: 1,
+        height: 1,
+    };
+    let window = create_input_only_window(conn, root, rect, EventMask::PROPERTY_CHANGE)?;
+    let sequence = conn
+        .change_property8(
+            PropMode::APPEND,
+            window,
+            AtomEnum::WM_NAME,
+            AtomEnum::STRING,
+            &[],
+        )?
+        .sequence_number();
+    let event = conn.wait_for_event_with_sequence_number(sequence);
+    conn.destroy_window(window)?.check()?;
+    match event? {
+        Some(Event::PropertyNotify(event)) => Ok(event.time),
+        Some(Event::Error(error)) => Err(ReplyError::X11Error(error).into()),
+        _ => Err(ConnectionError::UnknownError.into()),
+    }
+}
+
+/// Establish a common point in time on two connections to the same X11 server.
+///
+/// This gets the [`server_time`] on `first` and then on `second`. When this function returns,
+/// the X11 server processed all requests that were sent on either connection before. The two
+/// returned times are the times at which this happened for `first` and `second`, respectively.
+/// The first time is not newer than the second one. Thus, e.g. events recorded on a `RECORD` data
+/// connection can be related to the requests of the other connection via their time.
+///
+/// Both connections must belong to the same X11 server, otherwise the times are unrelated.
+pub fn sync_connections<A: Connection, B: Connection>(
+    first: &A,
+    second: &B,
+) -> Result<(Timestamp, Timestamp), ReplyOrIdError> {
+    let first_time = server_time(first)?;
+    let second_time = server_time(second)?;
+    Ok((first_time, second_time))
+}
+
+/// Retry a keyboard or pointer grab until it succeeds.
+///
+/// `GrabKeyboard` and `GrabPointer` fail with `AlreadyGrabbed` while another client holds a
+/// grab, which often only lasts for a short time, e.g. right after a button press. Similarly,
+/// `NotViewable` is reported when the window is not mapped yet. This function calls `grab` up
+/// to `attempts` times (but at least once), sleeping for `delay` between the attempts, as long
+/// as one of these two statuses is reported.
+///
+/// [`GrabError::Failed`] is returned with the status of the last attempt if the grab did not
+/// succeed. Other statuses, e.g. `InvalidTime`, are not retried.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use x11rb::connection::Connection;
+/// use x11rb::errors::GrabError;
+/// use x11rb::protocol::xproto::{ConnectionExt, GrabMode, Window};
+/// use x11rb::wrapper::retry_grab;
+///
+/// fn grab_keyboard(conn: &impl Connection, window: Window) -> Result<(), GrabError> {
+///     retry_grab(10, Duration::from_millis(10), || {
+///         let cookie = conn.grab_keyboard(
+///             false,
+///             window,
+///             x11rb::CURRENT_TIME,
+///             GrabMode::ASYNC,
+///             GrabMode::ASYNC,
+///         )?;
+///         Ok(cookie.reply()?.status)
+///     })
+/// }
+/// ```
+pub fn retry_grab<F>(attempts: usize, delay: Duration, mut grab: F) -> Result<(), GrabError>
+where
+    F: FnMut() -> Result<GrabStatus, ReplyError>,
+{
+    let mut status = grab()?;
+    for _ in 1..attempts {
+        if status != GrabStatus::ALREADY_GRABBED && status != GrabStatus::NOT_VIEWABLE {
+            break;
+        }
+        std::thread::sleep(delay);
+        status = grab()?;
+    }
+    if status == GrabStatus::SUCCESS {
+        Ok(())
+    } else {
+        Err(GrabError::Failed(status))
+    }
+}
+
+/// The kind of X11 server, as far as it can be guessed from its vendor string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServerKind {
+    /// The X.Org server or another server that is based on its code.
+    XOrg,
+    /// Xwayland, the X11 server of Wayland compositors.
+    Xwayland,
+    /// Xephyr, the nested X11 server.
+    Xephyr,
+    /// Some other X11 server.
+    Unknown,
+}
+
+/// Information about the implementation of the X11 server.
+///
+/// This is returned by [`ConnectionExt::server_info`].
+///
+/// The X.Org server and the servers built from its code base, including Xwayland, Xephyr, and
+/// Xvfb, usually all report "The X.Org Foundation" as their vendor. Thus, these are normally
+/// reported as [`ServerKind::XOrg`]. Xwayland can be detected more reliably via the `XWAYLAND`
+/// extension, which Xwayland 21.1 and newer provide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerInfo {
+    /// The vendor string of the server.
+    pub vendor: String,
+    /// The major and minor version of the X11 protocol that the server implements.
+    pub protocol_version: (u16, u16),
+    /// The vendor-specific release number of the server.
+    pub release_number: u32,
+    /// The kind of server that was guessed from the vendor string.
+    pub kind: ServerKind,
+}
+
+impl ServerInfo {
+    /// Extract the server information from the setup that the server sent.
+    ///
+    /// ```
+    /// use x11rb::protocol::xproto::Setup;
+    /// use x11rb::wrapper::{ServerInfo, ServerKind};
+    ///
+    /// let setup = Setup {
+    ///     protocol_major_version: 11,
+    ///     vendor: b"The X.Org Foundation".to_vec(),
+    ///     release_number: 12_101_004,
+    ///     ..Default::default()
+    /// };
+    /// let info = ServerInfo::from_setup(&setup);
+    /// assert_eq!(info.kind, ServerKind::XOrg);
+    /// assert_eq!(info.xorg_version(), Some((21, 1, 4)));
+    /// ```
+    pub fn from_setup(setup: &Setup) -> Self {
+        let (vendor, _) = parse_latin1(&setup.vendor, setup.vendor.len())
+            .expect("The whole vendor string is available");
+        let lowercase = vendor.to_lowercase();
+        let kind = if lowercase.contains("xwayland") {
+            ServerKind::Xwayland
+        } else if lowercase.contains("xephyr") {
+            ServerKind::Xephyr
+        } else if lowercase.contains("x.org") || lowercase.contains("xfree86") {
+            ServerKind::XOrg
+        } else {
+            ServerKind::Unknown
+        };
+        Self {
+            vendor,
+            protocol_version: (setup.protocol_major_version, setup.protocol_minor_version),
+            release_number: setup.release_number,
+            kind,
+        }
+    }
+
+    /// Decode the release number of an X.Org-based server into a version.
+    ///
+    /// The X.Org server encodes its version `1.major.minor.patch` into the release number. Since
+    /// version 21, the leading `1.` is not part of the official version number anymore, so this
+    /// function returns `(major, minor, patch)`. For example, X.Org 21.1.4 has release number
+    /// `12101004`. `None` is returned for other kinds of servers.
+    pub fn xorg_version(&self) -> Option<(u32, u32, u32)> {
+        if self.kind == ServerKind::Unknown {
+            return None;
+        }
+        let release = self.release_number;
+        Some((
+            release / 100_000 % 100,
+            release / 1_000 % 100,
+            release % 1_000,
+        ))
+    }
+}
+
 /// A RAII-like wrapper around [super::protocol::xproto::grab_server] and
 /// [super::protocol::xproto::ungrab_server].
 ///
@@ -131,3 +1166,609 @@ impl<C: XProtoConnectionExt> Drop for GrabServer<'_, C> {
         let _ = (self.0).ungrab_server();
     }
 }
+
+/// A RAII-like wrapper around [super::protocol::xproto::set_close_down_mode].
+///
+/// With a close down mode of [`CloseDown::RETAIN_PERMANENT`] or [`CloseDown::RETAIN_TEMPORARY`],
+/// the resources of a client survive when its connection is closed. Forgetting to switch back
+/// leaks these resources in the X11 server. Instances of this struct represent that we sent a
+/// `SetCloseDownMode` request. When this struct is dropped, the close down mode is reset to
+/// [`CloseDown::DESTROY_ALL`], which is the initial mode of every connection.
+///
+/// Any errors during `Drop` are silently ignored. Most likely an error here means that your
+/// X11 connection is broken and later requests will also fail.
+#[derive(Debug)]
+pub struct CloseDownModeGuard<'c, C: XProtoConnectionExt>(&'c C);
+
+impl<'c, C: XProtoConnectionExt> CloseDownModeGuard<'c, C> {
+    /// Set the close down mode by sending a [super::protocol::xproto::set_close_down_mode]
+    /// request.
+    ///
+    /// The returned type resets the mode to [`CloseDown::DESTROY_ALL`] when it is dropped.
+    pub fn set(conn: &'c C, mode: CloseDown) -> Result<Self, ConnectionError> {
+        drop(conn.set_close_down_mode(mode)?);
+        Ok(Self(conn))
+    }
+
+    /// Keep the current close down mode instead of resetting it.
+    ///
+    /// This is for the case where the resources really should outlive the connection, e.g.
+    /// because the connection is about to be closed.
+    pub fn keep(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl<C: XProtoConnectionExt> Drop for CloseDownModeGuard<'_, C> {
+    fn drop(&mut self) {
+        let _ = (self.0).set_close_down_mode(CloseDown::DESTROY_ALL);
+    }
+}
+
+/// Compute the part of a window that is not covered by its siblings.
+///
+/// The siblings of a window are the other children of its parent. Siblings that are higher in
+/// the stacking order are drawn on top of the window. This function queries the stacking order
+/// with `QueryTree` and then subtracts the area of all higher, mapped `InputOutput` siblings,
+/// including their borders, from the area of the window. The requests for all siblings are sent
+/// together, so that only three round trips to the X11 server are needed.
+///
+/// The result is a list of non-overlapping rectangles in the coordinate system of the window,
+/// i.e. relative to the inside of its border, which is not included in the result. If the window
+/// is completely covered, the list is empty. Note that only siblings are considered; the window
+/// might also be clipped by its ancestors or covered by siblings of its ancestors.
+///
+/// A [`Rectangle`] cannot start at a coordinate above `i16::MAX`. Visible parts of very large
+/// windows that start beyond this coordinate are not included in the result.
+pub fn visible_region<C: XProtoConnectionExt + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Vec<Rectangle>, ReplyError> {
+    let geometry = conn.get_geometry(window)?;
+    let tree = conn.query_tree(window)?;
+    let geometry = geometry.reply()?;
+    let parent = tree.reply()?.parent;
+    let mut region = vec![Rectangle {
+        x: 0,
+        y: 0,
+        width: geometry.width,
+        height: geometry.height,
+    }];
+    if parent == super::NONE {
+        return Ok(region);
+    }
+
+    // Children are listed in stacking order, from bottom to top
+    let siblings = conn.query_tree(parent)?.reply()?.children;
+    let higher = siblings
+        .iter()
+        .skip_while(|&&sibling| sibling != window)
+        .skip(1);
+    let cookies = higher
+        .map(|&sibling| {
+            Ok((
+                conn.get_window_attributes(sibling)?,
+                conn.get_geometry(sibling)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ConnectionError>>()?;
+
+    // Sibling geometries are relative to the parent, the result is relative to the window
+    let origin_x = i32::from(geometry.x) + i32::from(geometry.border_width);
+    let origin_y = i32::from(geometry.y) + i32::from(geometry.border_width);
+    for (attributes, sibling) in cookies {
+        // Siblings might have been destroyed in the mean time
+        let (attributes, sibling) = match (attributes.reply(), sibling.reply()) {
+            (Ok(attributes), Ok(sibling)) => (attributes, sibling),
+            (Err(ReplyError::X11Error(_)), _) | (_, Err(ReplyError::X11Error(_))) => continue,
+            (Err(err), _) | (_, Err(err)) => return Err(err),
+        };
+        if attributes.map_state == MapState::UNMAPPED || attributes.class == WindowClass::INPUT_ONLY
+        {
+            continue;
+        }
+        let border = 2 * i32::from(sibling.border_width);
+        let cover = (
+            i32::from(sibling.x) - origin_x,
+            i32::from(sibling.y) - origin_y,
+            i32::from(sibling.width) + border,
+            i32::from(sibling.height) + border,
+        );
+        region = subtract_rectangle(&region, cover);
+    }
+    Ok(region)
+}
+
+/// Remove the area `(x, y, width, height)` from the given non-overlapping rectangles.
+///
+/// Remaining pieces whose position does not fit into a `Rectangle` are dropped.
+fn subtract_rectangle(
+    region: &[Rectangle],
+    (x, y, width, height): (i32, i32, i32, i32),
+) -> Vec<Rectangle> {
+    let mut result = Vec::with_capacity(region.len());
+    let mut push = |x1: i32, y1: i32, x2: i32, y2: i32| {
+        if x1 >= x2 || y1 >= y2 {
+            return;
+        }
+        // The size is at most the size of the original rectangle, so it always fits. A piece
+        // of a large rectangle can start beyond i16::MAX, though.
+        if let (Ok(x), Ok(y)) = (x1.try_into(), y1.try_into()) {
+            result.push(Rectangle {
+                x,
+                y,
+                width: (x2 - x1).try_into().unwrap(),
+                height: (y2 - y1).try_into().unwrap(),
+            });
+        }
+    };
+    for rect in region {
+        let (left, top) = (i32::from(rect.x), i32::from(rect.y));
+        let (right, bottom) = (left + i32::from(rect.width), top + i32::from(rect.height));
+        let (cut_left, cut_top) = (x.max(left), y.max(top));
+        let (cut_right, cut_bottom) = ((x + width).min(right), (y + height).min(bottom));
+        if cut_left >= cut_right || cut_top >= cut_bottom {
+            // No overlap
+            push(left, top, right, bottom);
+            continue;
+        }
+        // The parts above and below the cut span the whole width, the parts on the left and
+        // on the right only the height of the cut
+        push(left, top, right, cut_top);
+        push(left, cut_top, cut_left, cut_bottom);
+        push(cut_right, cut_top, right, cut_bottom);
+        push(left, cut_bottom, right, bottom);
+    }
+    result
+}
+
+/// Create a RECORD context and start recording on a second connection.
+///
+/// The RECORD extension sends the recorded data as a series of replies to a single
+/// `EnableContext` request. Since this request blocks the connection that sent it until recording
+/// is disabled, the context is controlled via `ctrl_conn`, but enabled on `data_conn`. The
+/// returned cookie is an iterator over the replies. The protocol elements in each reply can be
+/// decoded with [`EnableContextReply::elements`]:
+///
+/// ```no_run
+/// use x11rb::protocol::record::{ClientSpec, CS, HType, Range};
+/// use x11rb::protocol::xproto::{KEY_PRESS_EVENT, KEY_RELEASE_EVENT};
+/// use x11rb::wrapper::enable_recording;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (ctrl_conn, _) = x11rb::connect(None)?;
+/// let (data_conn, _) = x11rb::connect(None)?;
+/// let mut range = Range::default();
+/// range.device_events.first = KEY_PRESS_EVENT;
+/// range.device_events.last = KEY_RELEASE_EVENT;
+/// let clients = [ClientSpec::from(CS::ALL_CLIENTS)];
+/// let header = HType::FROM_SERVER_TIME.into();
+/// let (_context, replies) = enable_recording(&ctrl_conn, &data_conn, header, &clients, &[range])?;
+/// for reply in replies {
+///     for element in reply?.elements() {
+///         let element = element?;
+///         println!("Key event at {:?}: {:?}", element.time, element.data);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Recording stops when `record::DisableContext` is sent via `ctrl_conn`. The context should
+/// afterwards be freed with `record::FreeContext`.
+///
+/// [`EnableContextReply::elements`]: super::protocol::record::EnableContextReply::elements
+#[cfg(feature = "record")]
+pub fn enable_recording<'d, C, D>(
+    ctrl_conn: &C,
+    data_conn: &'d D,
+    element_header: super::protocol::record::ElementHeader,
+    client_specs: &[super::protocol::record::ClientSpec],
+    ranges: &[super::protocol::record::Range],
+) -> Result<
+    (
+        super::protocol::record::Context,
+        super::cookie::RecordEnableContextCookie<'d, D>,
+    ),
+    ReplyOrIdError,
+>
+where
+    C: Connection,
+    D: super::connection::RequestConnection + ?Sized,
+{
+    use super::protocol::record::ConnectionExt as _;
+
+    let context = ctrl_conn.generate_id()?;
+    ctrl_conn
+        .record_create_context(context, element_header, client_specs, ranges)?
+        .check()?;
+    let cookie = data_conn.record_enable_context(context)?;
+    Ok((context, cookie))
+}
+
+/// The state of a RandR output, as returned by [`list_outputs`].
+#[cfg(feature = "randr")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputStatus {
+    /// The output that this is about.
+    pub output: super::protocol::randr::Output,
+    /// The name of the output, e.g. `HDMI-1`.
+    ///
+    /// Invalid UTF-8 in the name is replaced with `U+FFFD`.
+    pub name: String,
+    /// Whether a monitor is connected to the output.
+    pub connection: super::protocol::randr::Connection,
+    /// The CRTC that currently drives the output, or [`x11rb::NONE`] if the output is disabled.
+    ///
+    /// [`x11rb::NONE`]: super::NONE
+    pub crtc: super::protocol::randr::Crtc,
+}
+
+/// List the outputs of the screen of the given window via the RandR extension.
+///
+/// This sends `GetScreenResources` and afterwards all `GetOutputInfo` requests at once before
+/// waiting for any of their replies.
+///
+/// The X11 server only accepts these requests from clients that support at least version 1.2 of
+/// the RandR extension. The version must already be negotiated via `RRQueryVersion`. This
+/// function does not do so itself, since a later `RRQueryVersion` with a lower version would
+/// downgrade the version that the rest of the program negotiated.
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::randr::ConnectionExt as _;
+/// use x11rb::wrapper::list_outputs;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, screen_num) = x11rb::connect(None)?;
+/// let root = conn.setup().roots[screen_num].root;
+/// conn.randr_query_version(1, 2)?.reply()?;
+/// for output in list_outputs(&conn, root)? {
+///     println!("{}: {:?}", output.name, output.connection);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "randr")]
+pub fn list_outputs<C: Connection>(
+    conn: &C,
+    window: Window,
+) -> Result<Vec<OutputStatus>, ReplyError> {
+    use super::protocol::randr::ConnectionExt as _;
+
+    let resources = conn.randr_get_screen_resources(window)?.reply()?;
+    let cookies = resources
+        .outputs
+        .iter()
+        .map(|&output| {
+            conn.randr_get_output_info(output, resources.config_timestamp)
+                .map(|cookie| (output, cookie))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    cookies
+        .into_iter()
+        .map(|(output, cookie)| {
+            let info = cookie.reply()?;
+            Ok(OutputStatus {
+                output,
+                name: String::from_utf8_lossy(&info.name).into_owned(),
+                connection: info.connection,
+                crtc: info.crtc,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{
+        event_bytes, poly_text_items, poly_text_requests, property_chunks, retry_grab,
+        sort_clip_rectangles, subtract_rectangle, text_width, validate_create_window, ServerInfo,
+        ServerKind,
+    };
+    use crate::errors::{CreateWindowError, EventTooLong, GrabError};
+    use crate::protocol::xproto::{
+        Charinfo, ClientMessageEvent, ClipOrdering, CreateWindowAux, Depth, EventMask, GrabStatus,
+        QueryFontReply, Rectangle, Screen, Setup, Visualtype, WindowClass,
+    };
+
+    fn screen() -> Screen {
+        let visual = |visual_id| Visualtype {
+            visual_id,
+            ..Default::default()
+        };
+        Screen {
+            allowed_depths: vec![
+                Depth {
+                    depth: 24,
+                    visuals: vec![visual(0x21), visual(0x22)],
+                },
+                Depth {
+                    depth: 32,
+                    visuals: vec![visual(0x41)],
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sort_clip_rectangles() {
+        fn rect(x: i16, y: i16, height: u16) -> Rectangle {
+            Rectangle {
+                x,
+                y,
+                width: 5,
+                height,
+            }
+        }
+        let rects = [rect(10, 10, 5), rect(0, 10, 5), rect(0, 0, 10)];
+
+        let (ordering, sorted) = sort_clip_rectangles(&rects, ClipOrdering::UNSORTED);
+        assert_eq!(
+            (ordering, &sorted[..]),
+            (ClipOrdering::UNSORTED, &rects[..])
+        );
+
+        let (ordering, sorted) = sort_clip_rectangles(&rects, ClipOrdering::Y_SORTED);
+        assert_eq!(ordering, ClipOrdering::Y_SORTED);
+        assert_eq!(sorted, [rects[2], rects[0], rects[1]]);
+
+        let expected = [rects[2], rects[1], rects[0]];
+        let (ordering, sorted) = sort_clip_rectangles(&rects, ClipOrdering::YX_SORTED);
+        assert_eq!(
+            (ordering, &sorted[..]),
+            (ClipOrdering::YX_SORTED, &expected[..])
+        );
+        let (ordering, sorted) = sort_clip_rectangles(&rects, ClipOrdering::YX_BANDED);
+        assert_eq!(
+            (ordering, &sorted[..]),
+            (ClipOrdering::YX_BANDED, &expected[..])
+        );
+
+        // The first band overlaps the second one
+        let rects = [rect(0, 0, 20), rect(10, 10, 5)];
+        let (ordering, _) = sort_clip_rectangles(&rects, ClipOrdering::YX_BANDED);
+        assert_eq!(ordering, ClipOrdering::YX_SORTED);
+        // Same Y coordinate, but different heights
+        let rects = [rect(0, 0, 20), rect(10, 0, 5)];
+        let (ordering, _) = sort_clip_rectangles(&rects, ClipOrdering::YX_BANDED);
+        assert_eq!(ordering, ClipOrdering::YX_SORTED);
+    }
+
+    #[test]
+    fn test_poly_text_requests() {
+        assert!(poly_text_requests(0, 1, 4096).is_empty());
+        assert_eq!(poly_text_requests(300, 1, 4096), vec![0..300]);
+        // 256 bytes per item; one full item and 17 characters fit
+        assert_eq!(
+            poly_text_requests(600, 1, 16 + 3 + 256 + 19),
+            [0..271, 271..542, 542..600]
+        );
+        // Two byte characters and an incomplete item
+        let requests = poly_text_requests(600, 2, 16 + 3 + 12);
+        assert_eq!(requests.len(), 120);
+        assert_eq!(requests[..2], [0..5, 5..10]);
+    }
+
+    #[test]
+    fn test_poly_text_items() {
+        assert!(poly_text_items(&[], 1).is_empty());
+        assert_eq!(poly_text_items(b"abc", 1), b"\x03\x00abc");
+        assert_eq!(poly_text_items(&[0, 1, 0, 2], 2), [2, 0, 0, 1, 0, 2]);
+
+        let text = vec![b'x'; 300];
+        let items = poly_text_items(&text, 1);
+        assert_eq!(items.len(), 304);
+        assert_eq!(items[..2], [254, 0]);
+        assert_eq!(items[256..258], [46, 0]);
+    }
+
+    #[test]
+    fn test_property_chunks() {
+        assert_eq!(property_chunks(&[], 8, 4096).count(), 0);
+        let data = (0..100).collect::<Vec<u8>>();
+        assert_eq!(
+            property_chunks(&data, 8, 4096).collect::<Vec<_>>(),
+            [&data[..]]
+        );
+
+        // 42 bytes of room are rounded down to 40 bytes per chunk
+        let chunks = property_chunks(&data, 32, 24 + 42).collect::<Vec<_>>();
+        assert_eq!(chunks, [&data[..40], &data[40..80], &data[80..]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "whole number of items")]
+    fn test_property_chunks_partial_item() {
+        let _ = property_chunks(&[1, 2, 3], 16, 4096);
+    }
+
+    #[test]
+    fn test_text_width() {
+        let info = |character_width| Charinfo {
+            character_width,
+            ..Default::default()
+        };
+        let mut font = QueryFontReply {
+            min_char_or_byte2: u16::from(b'a'),
+            max_char_or_byte2: u16::from(b'd'),
+            default_char: u16::from(b'b'),
+            char_infos: vec![info(1), info(2), info(0), info(-4)],
+            ..Default::default()
+        };
+        assert_eq!(text_width(&font, b""), 0);
+        assert_eq!(text_width(&font, b"aad"), -2);
+        // 'c' does not exist and 'x' is out of range, so the default character is used
+        assert_eq!(text_width(&font, b"acx"), 5);
+        font.default_char = u16::from(b'c');
+        assert_eq!(text_width(&font, b"acx"), 1);
+
+        // Without char infos, all characters have the same width
+        font.char_infos.clear();
+        font.min_bounds = info(7);
+        assert_eq!(text_width(&font, b"abc"), 21);
+    }
+
+    #[test]
+    fn test_subtract_rectangle() {
+        let rect = |x, y, width, height| Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+        let region = [rect(0, 0, 100, 50)];
+
+        // No overlap
+        assert_eq!(subtract_rectangle(&region, (100, 0, 10, 10)), region);
+        // Completely covered
+        assert!(subtract_rectangle(&region, (-10, -10, 200, 200)).is_empty());
+        // A hole in the middle
+        assert_eq!(
+            subtract_rectangle(&region, (10, 20, 30, 10)),
+            [
+                rect(0, 0, 100, 20),
+                rect(0, 20, 10, 10),
+                rect(40, 20, 60, 10),
+                rect(0, 30, 100, 20),
+            ]
+        );
+        // Covering the right half, then the bottom
+        let region = subtract_rectangle(&region, (50, -5, 100, 100));
+        assert_eq!(region, [rect(0, 0, 50, 50)]);
+        let region = subtract_rectangle(&region, (0, 40, 50, 10));
+        assert_eq!(region, [rect(0, 0, 50, 40)]);
+
+        // Pieces that start beyond i16::MAX cannot be represented
+        let region = [rect(0, 0, u16::MAX, u16::MAX)];
+        assert_eq!(
+            subtract_rectangle(&region, (100, 40_000, 100, 100)),
+            [rect(0, 0, u16::MAX, 40_000)]
+        );
+        assert_eq!(
+            subtract_rectangle(&region, (40_000, 100, 100, 100)),
+            [
+                rect(0, 0, u16::MAX, 100),
+                rect(0, 100, 40_000, 100),
+                rect(0, 200, u16::MAX, u16::MAX - 200),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retry_grab() {
+        let mut statuses = vec![
+            GrabStatus::SUCCESS,
+            GrabStatus::NOT_VIEWABLE,
+            GrabStatus::ALREADY_GRABBED,
+        ];
+        let result = retry_grab(5, Duration::from_millis(0), || Ok(statuses.pop().unwrap()));
+        assert!(result.is_ok());
+        assert!(statuses.is_empty());
+
+        let mut calls = 0;
+        let result = retry_grab(3, Duration::from_millis(0), || {
+            calls += 1;
+            Ok(GrabStatus::ALREADY_GRABBED)
+        });
+        assert!(matches!(
+            result,
+            Err(GrabError::Failed(GrabStatus::ALREADY_GRABBED))
+        ));
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result = retry_grab(3, Duration::from_millis(0), || {
+            calls += 1;
+            Ok(GrabStatus::FROZEN)
+        });
+        assert!(matches!(result, Err(GrabError::Failed(GrabStatus::FROZEN))));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_validate_input_only() {
+        let screen = screen();
+        let aux = CreateWindowAux::new()
+            .event_mask(EventMask::KEY_PRESS)
+            .cursor(42);
+        let check = |depth, visual, border_width, aux: &CreateWindowAux| {
+            validate_create_window(
+                &screen,
+                depth,
+                WindowClass::INPUT_ONLY,
+                visual,
+                border_width,
+                aux,
+            )
+        };
+        assert_eq!(check(0, 0, 0, &aux), Ok(()));
+        assert_eq!(check(0, 0x41, 0, &aux), Ok(()));
+        assert_eq!(
+            check(24, 0, 0, &aux),
+            Err(CreateWindowError::InputOnlyDepth(24))
+        );
+        assert_eq!(
+            check(0, 0, 1, &aux),
+            Err(CreateWindowError::InputOnlyBorderWidth(1))
+        );
+        assert_eq!(
+            check(0, 0, 0, &aux.colormap(1)),
+            Err(CreateWindowError::InputOnlyAttribute("colormap"))
+        );
+        assert_eq!(
+            check(0, 0x99, 0, &aux),
+            Err(CreateWindowError::UnsupportedVisual(0x99))
+        );
+    }
+
+    #[test]
+    fn test_validate_input_output() {
+        let screen = screen();
+        let aux = CreateWindowAux::new().background_pixel(0);
+        let check = |depth, visual| {
+            validate_create_window(&screen, depth, WindowClass::INPUT_OUTPUT, visual, 1, &aux)
+        };
+        assert_eq!(check(0, 0), Ok(()));
+        assert_eq!(check(24, 0x22), Ok(()));
+        assert_eq!(check(32, 0x41), Ok(()));
+        assert_eq!(check(16, 0), Err(CreateWindowError::UnsupportedDepth(16)));
+        assert_eq!(
+            check(32, 0x21),
+            Err(CreateWindowError::UnsupportedVisual(0x21))
+        );
+        assert_eq!(
+            check(0, 0x99),
+            Err(CreateWindowError::UnsupportedVisual(0x99))
+        );
+    }
+
+    #[test]
+    fn test_event_bytes() {
+        let event = ClientMessageEvent::new(32, 1u32, 2u32, [3u32, 4, 5, 6, 7]);
+        assert_eq!(event_bytes(&event), Ok(<[u8; 32]>::from(event)));
+
+        // A shorter serialized form is padded, e.g. for an event that is defined elsewhere
+        let short = [1u8, 2, 3];
+        let mut expected = [0; 32];
+        expected[..3].copy_from_slice(&short);
+        assert_eq!(event_bytes(&short[..]), Ok(expected));
+
+        let too_long = [0u32; 9];
+        assert_eq!(event_bytes(&too_long[..]), Err(EventTooLong { length: 36 }));
+    }
+
+    #[test]
+    fn test_server_info_latin1_vendor() {
+        let setup = Setup {
+            vendor: b"Soci\xe9t\xe9 Xephyr".to_vec(),
+            ..Default::default()
+        };
+        let info = ServerInfo::from_setup(&setup);
+        assert_eq!(info.vendor, "Soci\u{e9}t\u{e9} Xephyr");
+        assert_eq!(info.kind, ServerKind::Xephyr);
+    }
+}
@@ -1,10 +1,54 @@
 //! Some utilities for working with X11.
 
 pub use x11rb_protocol::x11_utils::{
-    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, ReplyParsingFunction,
-    Request, RequestHeader, Serialize, TryParse, TryParseFd, X11Error,
+    parse_latin1, parse_request_header, parse_u8_list, parse_utf8, BigRequests, ExtInfoProvider,
+    ExtensionInformation, LazyList, ReplyParsingFunction, Request, RequestHeader, Serialize,
+    TryParse, TryParseFd, X11Error,
 };
 
+use crate::protocol::xproto::{
+    Timestamp, KEY_PRESS_EVENT, LEAVE_NOTIFY_EVENT, PROPERTY_NOTIFY_EVENT, SELECTION_CLEAR_EVENT,
+    SELECTION_NOTIFY_EVENT,
+};
+
+/// Check if timestamp `a` is later than timestamp `b`.
+///
+/// X11 timestamps are milliseconds since some unspecified point in time and wrap around after
+/// about 49.7 days. Following the X11 protocol specification, a timestamp is considered to be
+/// later than another one if it is at most half of the timestamp space ahead of it.
+///
+/// ```
+/// use x11rb::x11_utils::is_newer;
+///
+/// assert!(is_newer(20, 10));
+/// assert!(!is_newer(10, 20));
+/// assert!(!is_newer(10, 10));
+/// // Handles wraparound
+/// assert!(is_newer(5, u32::MAX - 5));
+/// ```
+pub fn is_newer(a: Timestamp, b: Timestamp) -> bool {
+    a != b && a.wrapping_sub(b) < 1 << 31
+}
+
+/// Get the server timestamp that is contained in a raw event.
+///
+/// This handles the core protocol events that carry a timestamp: key, button, motion, crossing,
+/// `PropertyNotify` and the selection events. `None` is returned for other events and for events
+/// that contain `CURRENT_TIME`.
+pub(crate) fn event_time(event: &[u8]) -> Option<Timestamp> {
+    let offset = match event.first()? & 0x7f {
+        KEY_PRESS_EVENT..=LEAVE_NOTIFY_EVENT | SELECTION_CLEAR_EVENT..=SELECTION_NOTIFY_EVENT => 4,
+        PROPERTY_NOTIFY_EVENT => 12,
+        _ => return None,
+    };
+    let time = Timestamp::from_ne_bytes(event.get(offset..offset + 4)?.try_into().unwrap());
+    if time == crate::CURRENT_TIME {
+        None
+    } else {
+        Some(time)
+    }
+}
+
 /// A helper macro for managing atoms
 ///
 /// In X11, one often has to work with many different atoms that are already known at compile time.
@@ -2,6 +2,7 @@
 //!
 //! This module is only available when the `allow-unsafe-code` feature is enabled.
 
+use std::collections::VecDeque;
 use std::convert::TryInto;
 use std::ffi::CStr;
 use std::io::{Error as IOError, ErrorKind, IoSlice};
@@ -17,12 +18,12 @@ use std::sync::{
 use libc::c_void;
 
 use crate::connection::{
-    compute_length_field, Connection, ReplyOrError, RequestConnection, RequestKind,
+    compute_length_field, Connection, LastEventTime, ReplyOrError, RequestConnection, RequestKind,
 };
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::extension_manager::ExtensionManager;
-use crate::protocol::xproto::Setup;
+use crate::protocol::xproto::{Setup, Timestamp};
 use crate::utils::{CSlice, RawFdContainer};
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
 
@@ -51,7 +52,9 @@ pub struct XCBConnection {
     setup: Setup,
     ext_mgr: Mutex<ExtensionManager>,
     errors: pending_errors::PendingErrors,
+    requeued_events: Mutex<VecDeque<RawEventAndSeqNumber>>,
     maximum_sequence_received: AtomicU64,
+    last_event_time: LastEventTime,
 }
 
 impl XCBConnection {
@@ -66,7 +69,13 @@ impl XCBConnection {
 
         assert_ne!(error, 0);
         match error {
-            ERROR => IOError::new(ErrorKind::Other, ConnectionError::UnknownError).into(),
+            // libxcb does not say what went wrong, but this almost always means that the
+            // connection is gone. RustConnection reports the I/O error from the operating
+            // system instead, which is not available here. ConnectionAborted makes
+            // ConnectionError::is_connection_closed() work for both.
+            ERROR => {
+                IOError::new(ErrorKind::ConnectionAborted, ConnectionError::UnknownError).into()
+            }
             EXT_NOTSUPPORTED => ConnectionError::UnsupportedExtension,
             MEM_INSUFFICIENT => ConnectionError::InsufficientMemory,
             REQ_LEN_EXCEED => ConnectionError::MaximumRequestLengthExceeded,
@@ -115,7 +124,9 @@ impl XCBConnection {
                     setup: Self::parse_setup(setup)?,
                     ext_mgr: Default::default(),
                     errors: Default::default(),
+                    requeued_events: Default::default(),
                     maximum_sequence_received: AtomicU64::new(0),
+                    last_event_time: Default::default(),
                 };
                 Ok((conn, screen as usize))
             }
@@ -145,7 +156,9 @@ impl XCBConnection {
             setup: Self::parse_setup(setup)?,
             ext_mgr: Default::default(),
             errors: Default::default(),
+            requeued_events: Default::default(),
             maximum_sequence_received: AtomicU64::new(0),
+            last_event_time: Default::default(),
         })
     }
 
@@ -340,7 +353,9 @@ impl XCBConnection {
             // the 32-byte boundary.
             std::ptr::copy(event.add(36), event.add(32), length_field * 4);
         }
-        Ok((CSlice::new(header.into_ptr(), length), seqno))
+        let event = CSlice::new(header.into_ptr(), length);
+        self.last_event_time.update(&event);
+        Ok((event, seqno))
     }
 
     /// Reconstruct a full sequence number based on a partial value.
@@ -524,6 +539,9 @@ impl RequestConnection for XCBConnection {
 
 impl Connection for XCBConnection {
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        if let Some(event) = self.requeued_events.lock().unwrap().pop_front() {
+            return Ok(event);
+        }
         if let Some(error) = self.errors.get(self) {
             return Ok((error.1, error.0));
         }
@@ -539,6 +557,9 @@ impl Connection for XCBConnection {
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        if let Some(event) = self.requeued_events.lock().unwrap().pop_front() {
+            return Ok(Some(event));
+        }
         if let Some(error) = self.errors.get(self) {
             return Ok(Some((error.1, error.0)));
         }
@@ -556,6 +577,13 @@ impl Connection for XCBConnection {
         }
     }
 
+    fn requeue_raw_events(&self, events: Vec<RawEventAndSeqNumber>) {
+        let mut requeued_events = self.requeued_events.lock().unwrap();
+        for event in events.into_iter().rev() {
+            requeued_events.push_front(event);
+        }
+    }
+
     fn flush(&self) -> Result<(), ConnectionError> {
         // xcb_flush() returns 0 if the connection is in (or just entered) an error state, else 1.
         let res = unsafe { raw_ffi::xcb_flush(self.conn.as_ptr()) };
@@ -566,6 +594,17 @@ impl Connection for XCBConnection {
         }
     }
 
+    fn last_event_time(&self) -> Timestamp {
+        self.last_event_time.get()
+    }
+
+    fn supports_fd_passing(&self) -> bool {
+        use nix::sys::socket::{getsockname, AddressFamily, SockaddrLike, SockaddrStorage};
+        // libxcb passes file descriptors exactly for Unix domain sockets
+        getsockname::<SockaddrStorage>(self.as_raw_fd())
+            .map_or(false, |addr| addr.family() == Some(AddressFamily::Unix))
+    }
+
     fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
         unsafe {
             let id = raw_ffi::xcb_generate_id(self.conn.as_ptr());
@@ -585,6 +624,11 @@ impl Connection for XCBConnection {
     }
 }
 
+/// The file descriptor of the connection, as returned by `xcb_get_file_descriptor`.
+///
+/// Do not read from the file descriptor directly. Events may already be buffered by libxcb when
+/// the file descriptor is not readable, so always drain [`Connection::poll_for_event`] before
+/// waiting for it.
 #[cfg(unix)]
 impl AsRawFd for XCBConnection {
     fn as_raw_fd(&self) -> RawFd {
@@ -647,6 +691,28 @@ mod test {
         assert_eq!(screen, 0);
     }
 
+    #[test]
+    fn connection_error_mapping() {
+        use crate::errors::ConnectionError;
+        use crate::xcb_ffi::raw_ffi::connection_errors::*;
+
+        let error = XCBConnection::connection_error_from_c_error(ERROR);
+        assert!(error.is_connection_closed(), "{:?}", error);
+        for &code in &[
+            EXT_NOTSUPPORTED,
+            MEM_INSUFFICIENT,
+            REQ_LEN_EXCEED,
+            FDPASSING_FAILED,
+        ] {
+            let error = XCBConnection::connection_error_from_c_error(code);
+            assert!(!error.is_connection_closed(), "{:?}", error);
+        }
+        assert!(matches!(
+            XCBConnection::connection_error_from_c_error(REQ_LEN_EXCEED),
+            ConnectionError::MaximumRequestLengthExceeded
+        ));
+    }
+
     #[test]
     fn reconstruct_full_sequence() {
         use super::reconstruct_full_sequence_impl;
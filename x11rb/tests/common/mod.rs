@@ -0,0 +1,51 @@
+// Helpers for tests that talk to a fake X11 server over a socket pair.
+
+#![cfg(unix)]
+// Not every test file uses every helper
+#![allow(dead_code)]
+
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+
+use x11rb::protocol::xproto::{ImageOrder, Screen, Setup};
+use x11rb::rust_connection::{DefaultStream, RustConnection};
+use x11rb::x11_utils::Serialize;
+
+/// Get the bytes of a successful connection setup with a single screen.
+pub fn setup() -> Vec<u8> {
+    let setup = Setup {
+        status: 1,
+        protocol_major_version: 11,
+        resource_id_base: 0x0040_0000,
+        resource_id_mask: 0x001f_ffff,
+        maximum_request_length: u16::MAX,
+        image_byte_order: ImageOrder::LSB_FIRST,
+        bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+        bitmap_format_scanline_unit: 32,
+        bitmap_format_scanline_pad: 32,
+        min_keycode: 8,
+        max_keycode: 255,
+        roots: vec![Screen::default()],
+        ..Default::default()
+    };
+    let mut data = setup.serialize();
+    // The length field counts the four byte units after the first eight bytes
+    let length = u16::try_from((data.len() - 8) / 4).unwrap();
+    data[6..8].copy_from_slice(&length.to_ne_bytes());
+    data
+}
+
+/// Connect to a fake X11 server that already sent the given events after the setup.
+///
+/// The returned stream is the server side of the connection. The connection setup request that
+/// the client sent is not read from it.
+pub fn connect(events: &[[u8; 32]]) -> (RustConnection, UnixStream) {
+    let (client, mut server) = UnixStream::pair().unwrap();
+    server.write_all(&setup()).unwrap();
+    for event in events {
+        server.write_all(event).unwrap();
+    }
+    let stream = DefaultStream::from_unix_stream(client).unwrap();
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    (conn, server)
+}
@@ -0,0 +1,208 @@
+// Check that code written against the Connection trait behaves the same with RustConnection and
+// with XCBConnection. Every check runs against both implementations and a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    use x11rb::connection::Connection;
+    use x11rb::errors::{ConnectionError, ReplyError};
+    use x11rb::protocol::xproto::{
+        ConnectionExt, BUTTON_PRESS_EVENT, GET_INPUT_FOCUS_REQUEST, KEYMAP_NOTIFY_EVENT,
+        MAP_NOTIFY_EVENT, PROPERTY_NOTIFY_EVENT,
+    };
+    use x11rb::protocol::{ErrorKind, Event};
+    use x11rb::rust_connection::RustConnection;
+    use x11rb::wrapper::ConnectionExt as _;
+
+    /// Generate a test that runs the given check against all connection implementations.
+    macro_rules! conformance_test {
+        ($name:ident) => {
+            mod $name {
+                #[test]
+                fn rust_connection() {
+                    super::$name(super::rust_connect);
+                }
+
+                #[cfg(feature = "allow-unsafe-code")]
+                #[test]
+                fn xcb_connection() {
+                    super::$name(super::xcb_connect);
+                }
+            }
+        };
+    }
+
+    /// Connect a `RustConnection` to a fake X11 server that already sent the given events.
+    ///
+    /// The connection setup request was already read from the returned stream.
+    fn rust_connect(events: &[[u8; 32]]) -> (RustConnection, UnixStream) {
+        let (conn, mut server) = super::common::connect(events);
+        // Skip the connection setup request without any authorization
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        (conn, server)
+    }
+
+    /// Connect an `XCBConnection` to a fake X11 server that already sent the given events.
+    ///
+    /// The connection setup request was already read from the returned stream.
+    #[cfg(feature = "allow-unsafe-code")]
+    fn xcb_connect(events: &[[u8; 32]]) -> (x11rb::xcb_ffi::XCBConnection, UnixStream) {
+        use std::os::raw::{c_int, c_void};
+        use std::os::unix::io::IntoRawFd;
+
+        #[link(name = "xcb")]
+        extern "C" {
+            fn xcb_connect_to_fd(fd: c_int, auth_info: *mut c_void) -> *mut c_void;
+            fn xcb_connection_has_error(c: *mut c_void) -> c_int;
+        }
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        // libxcb does not expect any data before it sent the connection setup request
+        let events = events.to_vec();
+        let server = std::thread::spawn(move || {
+            let mut setup_request = [0; 12];
+            server.read_exact(&mut setup_request).unwrap();
+            server.write_all(&super::common::setup()).unwrap();
+            for event in events {
+                server.write_all(&event).unwrap();
+            }
+            server
+        });
+        // libxcb takes ownership of the file descriptor
+        let ptr = unsafe { xcb_connect_to_fd(client.into_raw_fd(), std::ptr::null_mut()) };
+        assert_eq!(unsafe { xcb_connection_has_error(ptr) }, 0);
+        let conn = unsafe { x11rb::xcb_ffi::XCBConnection::from_raw_xcb_connection(ptr, true) };
+        (conn.unwrap(), server.join().unwrap())
+    }
+
+    /// Act as X11 server until the client disconnects.
+    ///
+    /// `respond` gets the sequence number and the bytes of each request and produces the bytes
+    /// that are sent back.
+    fn serve(mut server: UnixStream, respond: impl Fn(u16, &[u8]) -> Vec<u8>) {
+        let mut sequence = 0u16;
+        let mut header = [0; 4];
+        while server.read_exact(&mut header).is_ok() {
+            let length = usize::from(u16::from_ne_bytes([header[2], header[3]])) * 4;
+            let mut request = header.to_vec();
+            request.resize(length, 0);
+            server.read_exact(&mut request[4..]).unwrap();
+            sequence = sequence.wrapping_add(1);
+            server.write_all(&respond(sequence, &request)).unwrap();
+        }
+    }
+
+    fn event(response_type: u8, sequence: u16) -> [u8; 32] {
+        let mut event = [0; 32];
+        event[0] = response_type;
+        if response_type != KEYMAP_NOTIFY_EVENT {
+            event[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        }
+        event
+    }
+
+    fn closed_connection<C: Connection>(connect: fn(&[[u8; 32]]) -> (C, UnixStream)) {
+        let (conn, server) = connect(&[]);
+
+        // The X11 server goes away
+        drop(server);
+
+        let error = match conn.get_input_focus().map(|cookie| cookie.reply()) {
+            Err(error) | Ok(Err(ReplyError::ConnectionError(error))) => error,
+            other => panic!("Unexpected result {:?}", other),
+        };
+        assert!(error.is_connection_closed(), "{:?}", error);
+        assert!(matches!(error, ConnectionError::IoError(_)));
+    }
+    conformance_test!(closed_connection);
+
+    fn events_in_order<C: Connection>(connect: fn(&[[u8; 32]]) -> (C, UnixStream)) {
+        let events = [event(MAP_NOTIFY_EVENT, 0), event(KEYMAP_NOTIFY_EVENT, 0)];
+        let (conn, _server) = connect(&events);
+
+        let event = conn.wait_for_event().unwrap();
+        assert!(matches!(event, Event::MapNotify(_)), "{:?}", event);
+        let event = conn.poll_for_event().unwrap();
+        assert!(matches!(event, Some(Event::KeymapNotify(_))), "{:?}", event);
+        assert!(conn.poll_for_event().unwrap().is_none());
+    }
+    conformance_test!(events_in_order);
+
+    fn last_event_time<C: Connection>(connect: fn(&[[u8; 32]]) -> (C, UnixStream)) {
+        fn timed(response_type: u8, offset: usize, time: u32) -> [u8; 32] {
+            let mut event = event(response_type, 0);
+            event[offset..offset + 4].copy_from_slice(&time.to_ne_bytes());
+            event
+        }
+        let events = [
+            timed(BUTTON_PRESS_EVENT, 4, u32::MAX - 5),
+            // Events without a timestamp and CURRENT_TIME are ignored
+            event(MAP_NOTIFY_EVENT, 0),
+            timed(PROPERTY_NOTIFY_EVENT, 12, x11rb::CURRENT_TIME),
+            // The timestamp wrapped around
+            timed(PROPERTY_NOTIFY_EVENT, 12, 5),
+            // An older timestamp is ignored
+            timed(BUTTON_PRESS_EVENT, 4, u32::MAX - 10),
+        ];
+        let (conn, _server) = connect(&events);
+
+        assert_eq!(conn.last_event_time(), x11rb::CURRENT_TIME);
+        let expected = [u32::MAX - 5, u32::MAX - 5, u32::MAX - 5, 5, 5];
+        for expected in expected {
+            conn.wait_for_event().unwrap();
+            assert_eq!(conn.last_event_time(), expected);
+        }
+    }
+    conformance_test!(last_event_time);
+
+    fn replies_and_errors<C: Connection>(connect: fn(&[[u8; 32]]) -> (C, UnixStream)) {
+        let (conn, server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            serve(server, |sequence, request| {
+                let mut response = [0; 32];
+                response[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                if request[0] == GET_INPUT_FOCUS_REQUEST {
+                    // A reply with focus = sequence
+                    response[0] = 1;
+                    response[8..12].copy_from_slice(&u32::from(sequence).to_ne_bytes());
+                } else {
+                    // A Window error for everything else
+                    response[1] = 3;
+                }
+                response.to_vec()
+            })
+        });
+
+        let void = conn.map_window(42).unwrap();
+        let reply = conn.get_input_focus().unwrap();
+        let void_sequence = void.sequence_number();
+        assert_eq!(reply.sequence_number(), void_sequence + 1);
+        assert_eq!(reply.reply().unwrap().focus, 2);
+        match void.check() {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_kind, ErrorKind::Window);
+                assert_eq!(u64::from(error.sequence), void_sequence);
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+
+        // An unchecked error becomes an event
+        drop(conn.map_window(42).unwrap());
+        conn.sync().unwrap();
+        let event = conn.poll_for_event().unwrap();
+        assert!(
+            matches!(event, Some(Event::Error(ref error)) if error.error_kind == ErrorKind::Window),
+            "{:?}",
+            event
+        );
+
+        drop(conn);
+        server.join().unwrap();
+    }
+    conformance_test!(replies_and_errors);
+}
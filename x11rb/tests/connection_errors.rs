@@ -0,0 +1,53 @@
+// Tests for how RustConnection reports X11 errors. See connection_conformance.rs for checks
+// that apply to all connection implementations.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ConnectionExt, GET_INPUT_FOCUS_REQUEST};
+    use x11rb::protocol::{ErrorKind, Event};
+
+    use super::common::connect;
+
+    #[test]
+    fn synchronous_mode() {
+        let (conn, mut server) = connect(&[]);
+        conn.set_synchronous(true);
+
+        let server = std::thread::spawn(move || {
+            // Skip the connection setup request without any authorization
+            let mut setup_request = [0; 12];
+            server.read_exact(&mut setup_request).unwrap();
+
+            // The MapWindow request is followed by a GetInputFocus request
+            let mut requests = [0; 12];
+            server.read_exact(&mut requests).unwrap();
+            assert_eq!(requests[8], GET_INPUT_FOCUS_REQUEST);
+
+            // A Window error for the MapWindow request and the reply to GetInputFocus
+            let mut error = [0; 32];
+            error[1] = 3;
+            error[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&2u16.to_ne_bytes());
+            server.write_all(&error).unwrap();
+            server.write_all(&reply).unwrap();
+            server
+        });
+
+        drop(conn.map_window(42).unwrap());
+        // The error is available without waiting for it
+        let event = conn.poll_for_event().unwrap();
+        assert!(
+            matches!(event, Some(Event::Error(ref error)) if error.error_kind == ErrorKind::Window),
+            "{:?}",
+            event
+        );
+        drop(server.join().unwrap());
+    }
+}
@@ -0,0 +1,50 @@
+// Tests for the helpers in x11rb::cookie against a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+
+    use x11rb::connection::Connection;
+
+    use super::common::connect;
+
+    #[test]
+    fn reply_collector_in_sequence_order() {
+        use x11rb::cookie::ReplyCollector;
+        use x11rb::errors::ReplyError;
+        use x11rb::protocol::xproto::{ConnectionExt, WINDOW_ERROR};
+
+        let (conn, mut server) = connect(&[]);
+        let cookies = (0..3)
+            .map(|_| conn.get_input_focus().unwrap())
+            .collect::<Vec<_>>();
+        conn.flush().unwrap();
+
+        // The connection setup request and three GetInputFocus requests
+        let mut buffer = [0; 12 + 3 * 4];
+        server.read_exact(&mut buffer).unwrap();
+        for sequence in 1..=3u16 {
+            let mut packet = [0; 32];
+            if sequence == 2 {
+                packet[1] = WINDOW_ERROR;
+            } else {
+                packet[0] = 1;
+                packet[8..12].copy_from_slice(&u32::from(sequence).to_ne_bytes());
+            }
+            packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            server.write_all(&packet).unwrap();
+        }
+
+        // The cookies are given in reverse order, but are resolved in sequence order
+        let results = ReplyCollector::new(cookies.into_iter().rev()).collect::<Vec<_>>();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, 2);
+        assert_eq!(results[0].1.as_ref().unwrap().focus, 1);
+        assert_eq!(results[1].0, 1);
+        assert!(matches!(results[1].1, Err(ReplyError::X11Error(_))));
+        assert_eq!(results[2].0, 0);
+        assert_eq!(results[2].1.as_ref().unwrap().focus, 3);
+    }
+}
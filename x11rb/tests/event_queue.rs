@@ -0,0 +1,94 @@
+// Tests for fetching events from a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::Write;
+
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{KEYMAP_NOTIFY_EVENT, MAP_NOTIFY_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    use super::common::connect;
+
+    fn event(response_type: u8, sequence: u16) -> [u8; 32] {
+        let mut event = [0; 32];
+        event[0] = response_type;
+        if response_type != KEYMAP_NOTIFY_EVENT {
+            event[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        }
+        event
+    }
+
+    fn next_sequence(conn: &RustConnection) -> Option<u16> {
+        conn.poll_for_event()
+            .unwrap()
+            .map(|event| event.wire_sequence_number().unwrap_or(u16::MAX))
+    }
+
+    #[test]
+    fn wait_for_sequence_number() {
+        let events = [
+            event(MAP_NOTIFY_EVENT, 1),
+            event(KEYMAP_NOTIFY_EVENT, 0),
+            event(MAP_NOTIFY_EVENT, 2),
+            event(MAP_NOTIFY_EVENT, 3),
+        ];
+        let (conn, _server) = connect(&events);
+        let found = conn.wait_for_event_with_sequence_number(2).unwrap();
+        assert!(matches!(found, Some(Event::MapNotify(e)) if e.sequence == 2));
+
+        // The skipped events are still there in their original order
+        assert_eq!(next_sequence(&conn), Some(1));
+        assert_eq!(next_sequence(&conn), Some(u16::MAX));
+        assert_eq!(next_sequence(&conn), Some(3));
+        assert_eq!(next_sequence(&conn), None);
+    }
+
+    #[test]
+    fn wait_for_missed_sequence_number() {
+        let events = [event(MAP_NOTIFY_EVENT, 1), event(MAP_NOTIFY_EVENT, 3)];
+        let (conn, _server) = connect(&events);
+        assert!(conn
+            .wait_for_event_with_sequence_number(2)
+            .unwrap()
+            .is_none());
+        assert_eq!(next_sequence(&conn), Some(1));
+        assert_eq!(next_sequence(&conn), Some(3));
+    }
+
+    #[test]
+    fn poll_does_not_block() {
+        let (conn, mut server) = connect(&[]);
+        // The X11 server is still connected, but did not send anything
+        assert!(conn.poll_for_event().unwrap().is_none());
+
+        // Half an event is not enough
+        let event = event(MAP_NOTIFY_EVENT, 1);
+        server.write_all(&event[..16]).unwrap();
+        assert!(conn.poll_for_event().unwrap().is_none());
+        server.write_all(&event[16..]).unwrap();
+        assert_eq!(next_sequence(&conn), Some(1));
+        assert!(conn.poll_for_event().unwrap().is_none());
+    }
+
+    #[cfg(feature = "resource_tracker")]
+    #[test]
+    fn wrappers_forward_last_event_time() {
+        use x11rb::keyboard::KeyboardCache;
+        use x11rb::protocol::xproto::BUTTON_PRESS_EVENT;
+        use x11rb::resource_tracker::ResourceTracker;
+
+        let mut press = event(BUTTON_PRESS_EVENT, 1);
+        press[4..8].copy_from_slice(&1234u32.to_ne_bytes());
+        let (conn, _server) = connect(&[press]);
+        let conn = ResourceTracker::new(KeyboardCache::new(conn));
+        assert!(matches!(
+            conn.wait_for_event().unwrap(),
+            Event::ButtonPress(_)
+        ));
+        assert_eq!(conn.last_event_time(), 1234);
+    }
+}
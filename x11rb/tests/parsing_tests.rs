@@ -1,6 +1,8 @@
 use x11rb::errors::ParseError;
-use x11rb::protocol::xproto::{Setup, VisualClass};
-use x11rb::x11_utils::TryParse;
+use x11rb::protocol::xproto::Setup;
+#[cfg(not(feature = "strict_padding"))]
+use x11rb::protocol::xproto::VisualClass;
+use x11rb::x11_utils::{ExtInfoProvider, ExtensionInformation, TryParse};
 
 fn get_setup_data() -> Vec<u8> {
     let mut s = Vec::new();
@@ -80,6 +82,8 @@ fn get_setup_data() -> Vec<u8> {
     s
 }
 
+// The padding after the vendor string is not zero
+#[cfg(not(feature = "strict_padding"))]
 #[test]
 fn parse_setup() -> Result<(), ParseError> {
     let setup = get_setup_data();
@@ -134,6 +138,22 @@ fn parse_setup() -> Result<(), ParseError> {
     Ok(())
 }
 
+#[cfg(feature = "strict_padding")]
+#[test]
+fn parse_setup_non_zero_padding() {
+    let mut setup = get_setup_data();
+    assert!(matches!(
+        Setup::try_parse(&setup),
+        Err(ParseError::NonZeroPadding)
+    ));
+
+    // The setup is fine with zero padding
+    let vendor = 40..48;
+    assert_eq!(&setup[vendor.clone()], b"Vendor  ");
+    setup[vendor].copy_from_slice(b"Vendor\0\0");
+    assert!(Setup::try_parse(&setup).is_ok());
+}
+
 #[cfg(feature = "xinput")]
 #[test]
 fn parse_xi_get_property_reply_format_0() {
@@ -166,3 +186,171 @@ fn parse_xi_get_property_reply_format_0() {
         )),
     );
 }
+
+#[test]
+fn parse_query_tree_reply_lazy() {
+    use x11rb::protocol::xproto::QueryTreeReply;
+
+    let mut s = vec![
+        1, // response_type
+        0, // pad
+    ];
+    s.extend(0u16.to_ne_bytes()); // sequence
+    s.extend(2u32.to_ne_bytes()); // length
+    s.extend(1u32.to_ne_bytes()); // root
+    s.extend(2u32.to_ne_bytes()); // parent
+    s.extend(2u16.to_ne_bytes()); // children_len
+    s.extend([0; 14]); // pad
+    s.extend(10u32.to_ne_bytes()); // children[0]
+    s.extend(11u32.to_ne_bytes()); // children[1]
+
+    let (reply, _) = QueryTreeReply::try_parse(&s).unwrap();
+    let children = QueryTreeReply::children_lazy(&s).unwrap();
+    assert_eq!(children.size_hint(), (1, Some(2)));
+    assert_eq!(children.collect::<Result<Vec<_>, _>>(), Ok(reply.children));
+
+    // The lazy iterator only notices missing data when it reaches it
+    let mut children = QueryTreeReply::children_lazy(&s[..36]).unwrap();
+    assert_eq!(children.next(), Some(Ok(10)));
+    assert_eq!(
+        children.next(),
+        Some(Err(ParseError::DataTooShort { needed: 4, got: 0 }))
+    );
+}
+
+/// An `ExtInfoProvider` that knows about the given extensions.
+struct Extensions(&'static [(&'static str, ExtensionInformation)]);
+
+const NO_EXTENSIONS: Extensions = Extensions(&[]);
+
+impl Extensions {
+    /// Find the extension with the largest base that is at most `code`.
+    ///
+    /// A base of zero means that the extension does not have such codes.
+    fn find_by_base(
+        &self,
+        code: u8,
+        base: impl Fn(&ExtensionInformation) -> u8,
+    ) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| base(info) != 0 && base(info) <= code)
+            .max_by_key(|(_, info)| base(info))
+            .copied()
+    }
+}
+
+impl ExtInfoProvider for Extensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .copied()
+    }
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.find_by_base(event_code, |info| info.first_event)
+    }
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.find_by_base(error_code, |info| info.first_error)
+    }
+}
+
+#[test]
+fn event_sequence_number() {
+    use x11rb::protocol::xproto::{KEYMAP_NOTIFY_EVENT, MAP_NOTIFY_EVENT};
+    use x11rb::protocol::Event;
+
+    let mut data = [0; 32];
+    data[0] = MAP_NOTIFY_EVENT;
+    data[2..4].copy_from_slice(&0xfffeu16.to_ne_bytes());
+    let event = Event::parse(&data, &NO_EXTENSIONS).unwrap();
+    assert_eq!(event.wire_sequence_number(), Some(0xfffe));
+    assert_eq!(event.sequence_number(0x2_fff0), Some(0x2_fffe));
+    // The event was received after the sequence number wrapped around
+    assert_eq!(event.sequence_number(0x3_0003), Some(0x2_fffe));
+
+    data[0] = KEYMAP_NOTIFY_EVENT;
+    let event = Event::parse(&data, &NO_EXTENSIONS).unwrap();
+    assert_eq!(event.sequence_number(0x3_0003), None);
+}
+
+#[cfg(feature = "present")]
+#[test]
+fn parse_present_complete_notify() {
+    use x11rb::protocol::present::{self, CompleteKind, CompleteMode};
+    use x11rb::protocol::xproto::GE_GENERIC_EVENT;
+    use x11rb::protocol::Event;
+
+    const PRESENT: ExtensionInformation = ExtensionInformation {
+        major_opcode: 140,
+        first_event: 0,
+        first_error: 0,
+    };
+    let extensions = Extensions(&[(present::X11_EXTENSION_NAME, PRESENT)]);
+
+    let mut data = [0; 40];
+    data[0] = GE_GENERIC_EVENT;
+    data[1] = PRESENT.major_opcode;
+    data[4..8].copy_from_slice(&2u32.to_ne_bytes());
+    data[8..10].copy_from_slice(&present::COMPLETE_NOTIFY_EVENT.to_ne_bytes());
+    data[10] = CompleteKind::PIXMAP.into();
+    data[11] = CompleteMode::FLIP.into();
+    data[16..20].copy_from_slice(&0x1234u32.to_ne_bytes());
+    data[20..24].copy_from_slice(&0x8765_4321u32.to_ne_bytes());
+    // Both 64 bit fields use more than the low 32 bits
+    data[24..32].copy_from_slice(&0x0102_0304_0506_0708u64.to_ne_bytes());
+    data[32..40].copy_from_slice(&0x1_0000_0001u64.to_ne_bytes());
+    let event = match Event::parse(&data, &extensions).unwrap() {
+        Event::PresentCompleteNotify(event) => event,
+        event => panic!("Unexpected event {:?}", event),
+    };
+    assert_eq!(event.kind, CompleteKind::PIXMAP);
+    assert_eq!(event.mode, CompleteMode::FLIP);
+    assert_eq!(event.window, 0x1234);
+    assert_eq!(event.serial, 0x8765_4321);
+    assert_eq!(event.ust, 0x0102_0304_0506_0708);
+    assert_eq!(event.msc, 0x1_0000_0001);
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn parse_randr_events_with_event_base() {
+    use x11rb::protocol::randr::{self, Rotation};
+    use x11rb::protocol::xproto::MAP_NOTIFY_EVENT;
+    use x11rb::protocol::Event;
+
+    const RANDR: ExtensionInformation = ExtensionInformation {
+        major_opcode: 140,
+        first_event: 89,
+        first_error: 147,
+    };
+    let extensions = Extensions(&[(randr::X11_EXTENSION_NAME, RANDR)]);
+    let mut data = [0; 32];
+    data[0] = RANDR.first_event + randr::SCREEN_CHANGE_NOTIFY_EVENT;
+    data[1] = 1 << 1; // Rotation::ROTATE90
+    data[12..16].copy_from_slice(&0x1234u32.to_ne_bytes());
+    match Event::parse(&data, &extensions).unwrap() {
+        Event::RandrScreenChangeNotify(event) => {
+            assert_eq!(event.rotation, Rotation::ROTATE90);
+            assert_eq!(event.root, 0x1234);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    // The flag for events from SendEvent is ignored
+    data[0] |= 0x80;
+    let event = Event::parse(&data, &extensions).unwrap();
+    assert!(matches!(event, Event::RandrScreenChangeNotify(_)));
+
+    // Without the extension, the same response type is not a RandR event
+    assert!(matches!(
+        Event::parse(&data, &NO_EXTENSIONS).unwrap(),
+        Event::Unknown(_)
+    ));
+
+    // A core event is not affected by the event base
+    let mut data = [0; 32];
+    data[0] = MAP_NOTIFY_EVENT;
+    let event = Event::parse(&data, &extensions).unwrap();
+    assert!(matches!(event, Event::MapNotify(_)));
+}
@@ -0,0 +1,208 @@
+// Tests for the helpers in x11rb::properties against a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::time::Duration;
+
+    use x11rb::connection::Connection;
+    use x11rb::errors::ReplyError;
+    use x11rb::properties::{read_selection, AtomResolver};
+    use x11rb::protocol::xproto::{
+        AtomEnum, Property, SelectionNotifyEvent, PROPERTY_NOTIFY_EVENT, SELECTION_NOTIFY_EVENT,
+    };
+    use x11rb::protocol::{ErrorKind, Event};
+
+    use super::common::connect;
+
+    const WINDOW: u32 = 0x0040_0001;
+    const PROPERTY: u32 = 300;
+    const INCR: u32 = 301;
+
+    fn property_notify(sequence: u16, state: Property) -> [u8; 32] {
+        let mut event = [0; 32];
+        event[0] = PROPERTY_NOTIFY_EVENT;
+        event[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        event[4..8].copy_from_slice(&WINDOW.to_ne_bytes());
+        event[8..12].copy_from_slice(&PROPERTY.to_ne_bytes());
+        event[16] = u8::from(state);
+        event
+    }
+
+    fn get_property_reply(sequence: u16, type_: u32, format: u8, value: &[u8]) -> Vec<u8> {
+        let mut reply = vec![0; 32];
+        reply[0] = 1;
+        reply[1] = format;
+        reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        let length = u32::try_from((value.len() + 3) / 4).unwrap();
+        reply[4..8].copy_from_slice(&length.to_ne_bytes());
+        reply[8..12].copy_from_slice(&type_.to_ne_bytes());
+        let items = u32::try_from(value.len() / usize::from(format.max(8) / 8)).unwrap();
+        reply[16..20].copy_from_slice(&items.to_ne_bytes());
+        reply.extend_from_slice(value);
+        reply.resize(32 + 4 * usize::try_from(length).unwrap(), 0);
+        reply
+    }
+
+    /// Act as X11 server and as a slow selection owner that sends the given chunks via INCR.
+    fn incr_owner(server: &mut UnixStream, chunks: &[&[u8]]) {
+        // The connection setup request and the GetProperty request for the INCR property
+        let mut request = [0; 24];
+        server.read_exact(&mut request[..12]).unwrap();
+        server.read_exact(&mut request).unwrap();
+        let mut sequence = 1;
+        let size = 11u32.to_ne_bytes();
+        server
+            .write_all(&get_property_reply(sequence, INCR, 32, &size))
+            .unwrap();
+        server
+            .write_all(&property_notify(sequence, Property::DELETE))
+            .unwrap();
+
+        for chunk in chunks {
+            // Until the selection owner stored the next chunk, the property does not exist
+            server
+                .set_read_timeout(Some(Duration::from_millis(100)))
+                .unwrap();
+            while server.read_exact(&mut request).is_ok() {
+                sequence += 1;
+                let reply = get_property_reply(sequence, AtomEnum::NONE.into(), 0, &[]);
+                server.write_all(&reply).unwrap();
+            }
+            server.set_read_timeout(None).unwrap();
+            server
+                .write_all(&property_notify(sequence, Property::NEW_VALUE))
+                .unwrap();
+
+            server.read_exact(&mut request).unwrap();
+            sequence += 1;
+            let reply = get_property_reply(sequence, AtomEnum::STRING.into(), 8, chunk);
+            server.write_all(&reply).unwrap();
+            server
+                .write_all(&property_notify(sequence, Property::DELETE))
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn read_selection_skips_stale_new_value() {
+        let mut selection_notify = [0; 32];
+        selection_notify[0] = SELECTION_NOTIFY_EVENT;
+        selection_notify[8..12].copy_from_slice(&WINDOW.to_ne_bytes());
+        selection_notify[20..24].copy_from_slice(&PROPERTY.to_ne_bytes());
+        // The selection owner stored the INCR property before sending SelectionNotify
+        let events = [property_notify(0, Property::NEW_VALUE), selection_notify];
+        let (conn, mut server) = connect(&events);
+        let server = std::thread::spawn(move || {
+            incr_owner(&mut server, &[b"hello", b" world", b""]);
+            server
+        });
+
+        // The PropertyNotify event is put back into the event queue
+        let event = conn
+            .wait_for_event_matching(|event| matches!(event, Event::SelectionNotify(_)))
+            .unwrap();
+        let event: SelectionNotifyEvent = match event {
+            Event::SelectionNotify(event) => event,
+            _ => unreachable!(),
+        };
+        let data = read_selection(&conn, &event, INCR).unwrap().unwrap();
+        assert_eq!(data.type_, u32::from(AtomEnum::STRING));
+        assert_eq!(data.format, 8);
+        assert_eq!(data.data, b"hello world");
+        let _server = server.join().unwrap();
+    }
+
+    /// Act as X11 server that answers `GetAtomName` requests until the client asks for `CLOSE`.
+    ///
+    /// Only the atoms in `names` exist.
+    fn atom_names(server: &mut UnixStream, names: &[(u32, &[u8])]) {
+        const CLOSE: u32 = 3;
+
+        // The connection setup request
+        let mut request = [0; 12];
+        server.read_exact(&mut request).unwrap();
+        let mut sequence = 0u16;
+        let mut request = [0; 8];
+        while server.read_exact(&mut request).is_ok() {
+            sequence += 1;
+            let atom = u32::from_ne_bytes(request[4..8].try_into().unwrap());
+            if atom == CLOSE {
+                // Give the client time to handle the earlier replies before it sees the EOF
+                std::thread::sleep(Duration::from_millis(100));
+                return;
+            }
+            let mut response = vec![0; 32];
+            response[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            match names.iter().find(|(known, _)| *known == atom) {
+                Some((_, name)) => {
+                    response[0] = 1;
+                    let length = u32::try_from((name.len() + 3) / 4).unwrap();
+                    response[4..8].copy_from_slice(&length.to_ne_bytes());
+                    let name_len = u16::try_from(name.len()).unwrap();
+                    response[8..10].copy_from_slice(&name_len.to_ne_bytes());
+                    response.extend_from_slice(name);
+                    response.resize(32 + 4 * usize::try_from(length).unwrap(), 0);
+                }
+                None => {
+                    // An Atom error
+                    response[1] = 5;
+                    response[4..8].copy_from_slice(&atom.to_ne_bytes());
+                }
+            }
+            server.write_all(&response).unwrap();
+        }
+    }
+
+    #[test]
+    fn atom_resolver_keeps_unanswered_atoms() {
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || atom_names(&mut server, &[(1, b"FOO")]));
+
+        let mut resolver = AtomResolver::new();
+        for atom in [1, 2, 3] {
+            resolver.request(atom);
+        }
+        let result = resolver.flush(&conn);
+        assert!(
+            matches!(result, Err(ReplyError::ConnectionError(_))),
+            "{:?}",
+            result
+        );
+        // The atoms before the connection failed were handled, the last one is still queued
+        assert_eq!(resolver.name(1), Some("FOO"));
+        assert_eq!(resolver.name(2), None);
+        assert!(resolver.has_pending());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn atom_resolver_ignores_errors_for_other_atoms() {
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            atom_names(&mut server, &[(1, b"FOO"), (4, b"BAR")]);
+            server
+        });
+
+        let mut resolver = AtomResolver::new();
+        resolver.request(2);
+        assert_eq!(resolver.resolve(&conn, 4).unwrap(), "BAR");
+        assert!(!resolver.has_pending());
+
+        // The error for the requested atom is reported, even if another one failed first
+        resolver.request(2);
+        match resolver.resolve(&conn, 5) {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_kind, ErrorKind::Atom);
+                assert_eq!(error.bad_value, 5);
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+        assert_eq!(resolver.resolve(&conn, 1).unwrap(), "FOO");
+        drop(conn);
+        drop(server.join().unwrap());
+    }
+}
@@ -272,6 +272,21 @@ fn test_send_event() -> Result<(), ConnectionError> {
     Ok(())
 }
 
+#[test]
+fn test_core_request_without_extension_lookup() -> Result<(), ConnectionError> {
+    // Core requests have a fixed major opcode. FakeConnection panics in
+    // extension_information(), so this also checks that no lookup happens.
+    let conn = FakeConnection::default();
+    let window: u32 = 0x1234;
+    conn.map_window(window)?;
+
+    let mut expected = vec![x11rb::protocol::xproto::MAP_WINDOW_REQUEST, 0];
+    expected.extend(2u16.to_ne_bytes()); // length
+    expected.extend(window.to_ne_bytes());
+    conn.check_requests(&[(false, expected)]);
+    Ok(())
+}
+
 #[test]
 fn test_get_keyboard_mapping() -> Result<(), ConnectionError> {
     let conn = FakeConnection::default();
@@ -35,7 +35,7 @@ fn test_bad_request_header_length() {
     let body = &[];
     assert_eq!(
         CreateWindowRequest::try_parse_request(header, body),
-        Err(ParseError::InsufficientData)
+        Err(ParseError::DataTooShort { needed: 4, got: 0 })
     );
 }
 
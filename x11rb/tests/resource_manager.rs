@@ -262,6 +262,10 @@ mod test {
             unimplemented!()
         }
 
+        fn requeue_raw_events(&self, _: Vec<RawEventAndSeqNumber<Self::Buf>>) {
+            unimplemented!()
+        }
+
         fn flush(&self) -> Result<(), ConnectionError> {
             unimplemented!()
         }
@@ -0,0 +1,216 @@
+// Tests for sending requests from RustConnection to a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    use x11rb::connection::Connection;
+
+    use super::common::connect;
+
+    #[test]
+    fn builder_limits_event_queue() {
+        use x11rb::protocol::xproto::MAP_NOTIFY_EVENT;
+        use x11rb::protocol::Event;
+        use x11rb::rust_connection::{DefaultStream, QueueOverflowPolicy, RustConnectionBuilder};
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        server.write_all(&super::common::setup()).unwrap();
+        for sequence in 1..=3u16 {
+            let mut event = [0; 32];
+            event[0] = MAP_NOTIFY_EVENT;
+            event[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            server.write_all(&event).unwrap();
+        }
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = RustConnectionBuilder::new()
+            .max_event_queue(Some(1))
+            .queue_overflow_policy(QueueOverflowPolicy::DropOldest)
+            .connect_to_stream(stream, 0)
+            .unwrap();
+
+        // Only the newest event is kept
+        let event = conn.wait_for_event().unwrap();
+        assert!(matches!(event, Event::MapNotify(e) if e.sequence == 3));
+        assert!(conn.poll_for_event().unwrap().is_none());
+    }
+
+    #[test]
+    fn raw_fd_is_the_stream() {
+        use std::os::unix::io::AsRawFd;
+
+        let (conn, _server) = connect(&[]);
+        assert_eq!(conn.as_raw_fd(), conn.stream().as_raw_fd());
+    }
+
+    #[test]
+    fn flush_writes_requests() {
+        use x11rb::protocol::xproto::{ConnectionExt, MAP_WINDOW_REQUEST};
+
+        let (conn, mut server) = connect(&[]);
+        server.set_nonblocking(true).unwrap();
+        // Skip the connection setup request without any authorization
+        let mut buffer = [0; 12];
+        server.read_exact(&mut buffer).unwrap();
+
+        // Small requests are buffered
+        drop(conn.map_window(42).unwrap());
+        let error = server.read(&mut buffer).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::WouldBlock);
+        assert!(conn.poll_for_event().unwrap().is_none());
+        let error = server.read(&mut buffer).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::WouldBlock);
+
+        conn.flush().unwrap();
+        assert_eq!(server.read(&mut buffer).unwrap(), 8);
+        assert_eq!(buffer[0], MAP_WINDOW_REQUEST);
+    }
+
+    #[cfg(feature = "randr")]
+    #[test]
+    fn extension_event_range() {
+        use x11rb::connection::RequestConnection;
+        use x11rb::protocol::{ge, randr};
+
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            // The connection setup request and QueryExtension for "RANDR"
+            let mut buffer = [0; 12 + 16];
+            server.read_exact(&mut buffer).unwrap();
+            assert_eq!(&buffer[20..25], b"RANDR");
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+            reply[8..12].copy_from_slice(&[1, 140, 89, 147]);
+            server.write_all(&reply).unwrap();
+            server
+        });
+
+        // RandR has two events; the extension's info is only fetched once
+        let range = conn
+            .extension_event_range(randr::X11_EXTENSION_NAME)
+            .unwrap();
+        assert_eq!(range, Some((89, 90)));
+        let range = conn
+            .extension_event_range(randr::X11_EXTENSION_NAME)
+            .unwrap();
+        assert_eq!(range, Some((89, 90)));
+        let _server = server.join().unwrap();
+
+        // The generic event extension has no events with their own event code
+        let range = conn.extension_event_range(ge::X11_EXTENSION_NAME).unwrap();
+        assert_eq!(range, None);
+    }
+
+    /// Answer the QueryExtension request for BIG-REQUESTS and, if present, BigReqEnable.
+    fn negotiate_big_requests(server: &mut UnixStream, maximum_request_length: Option<u32>) {
+        // The connection setup request and QueryExtension for "BIG-REQUESTS"
+        let mut buffer = [0; 12 + 20];
+        server.read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer[20..32], b"BIG-REQUESTS");
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        if maximum_request_length.is_some() {
+            reply[8..10].copy_from_slice(&[1, 133]);
+        }
+        server.write_all(&reply).unwrap();
+
+        if let Some(length) = maximum_request_length {
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            assert_eq!(request, [133, 0, 1, 0]);
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&2u16.to_ne_bytes());
+            reply[8..12].copy_from_slice(&length.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+        }
+    }
+
+    #[test]
+    fn big_requests_are_negotiated() {
+        use x11rb::connection::RequestConnection;
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, PropMode, CHANGE_PROPERTY_REQUEST};
+
+        let data = vec![7; 300_000];
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            negotiate_big_requests(&mut server, Some(0x0010_0000));
+            // The ChangeProperty request uses the extended length field
+            let mut request = vec![0; 28 + 300_000];
+            server.read_exact(&mut request).unwrap();
+            request
+        });
+
+        assert_eq!(conn.maximum_request_bytes(), 4 * 0x0010_0000);
+        let atom = AtomEnum::STRING;
+        conn.change_property(PropMode::REPLACE, 1, atom, atom, 8, 300_000, &data)
+            .unwrap();
+        conn.flush().unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request[..4], [CHANGE_PROPERTY_REQUEST, 0, 0, 0]);
+        let length = u32::try_from(request.len() / 4).unwrap();
+        assert_eq!(request[4..8], length.to_ne_bytes());
+        assert_eq!(request[28..], data[..]);
+    }
+
+    #[test]
+    fn too_large_request_without_big_requests() {
+        use x11rb::connection::RequestConnection;
+        use x11rb::errors::ConnectionError;
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt, PropMode};
+
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            negotiate_big_requests(&mut server, None);
+            server
+        });
+
+        // Without the extension, the limit from the setup applies
+        assert_eq!(conn.maximum_request_bytes(), 4 * usize::from(u16::MAX));
+        let _server = server.join().unwrap();
+        let atom = AtomEnum::STRING;
+        let result =
+            conn.change_property(PropMode::REPLACE, 1, atom, atom, 8, 300_000, &[0; 300_000]);
+        assert!(matches!(
+            result,
+            Err(ConnectionError::MaximumRequestLengthExceeded)
+        ));
+    }
+
+    #[test]
+    fn requeue_wakes_waiting_threads() {
+        use std::sync::{mpsc, Arc};
+        use std::time::Duration;
+        use x11rb::protocol::xproto::{MAP_NOTIFY_EVENT, UNMAP_NOTIFY_EVENT};
+        use x11rb::protocol::Event;
+
+        let (conn, mut server) = connect(&[]);
+        let conn = Arc::new(conn);
+        let (sender, receiver) = mpsc::channel();
+        // The first thread reads from the socket, the second one waits for it to finish
+        for _ in 0..2 {
+            let (conn, sender) = (Arc::clone(&conn), sender.clone());
+            std::thread::spawn(move || sender.send(conn.wait_for_event().unwrap()).unwrap());
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        let mut event = vec![0; 32];
+        event[0] = MAP_NOTIFY_EVENT;
+        conn.requeue_raw_events(vec![(event, 0)]);
+        let event = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(matches!(event, Event::MapNotify(_)), "{:?}", event);
+
+        // Let the thread that reads from the socket finish
+        let mut event = [0; 32];
+        event[0] = UNMAP_NOTIFY_EVENT;
+        server.write_all(&event).unwrap();
+        let event = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(matches!(event, Event::UnmapNotify(_)), "{:?}", event);
+    }
+}
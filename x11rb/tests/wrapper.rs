@@ -0,0 +1,305 @@
+// Tests for the helpers in x11rb::wrapper against a fake X11 server.
+
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    use x11rb::connection::Connection;
+
+    use super::common::connect;
+
+    /// Act as X11 server until the client disconnects and return all requests that were sent.
+    ///
+    /// `respond` gets the sequence number and the bytes of each request and produces the bytes
+    /// that are sent back, if any.
+    fn serve(
+        mut server: UnixStream,
+        respond: impl Fn(u16, &[u8]) -> Option<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        // Skip the connection setup request
+        let mut setup_request = [0; 12];
+        server.read_exact(&mut setup_request).unwrap();
+        let mut requests = Vec::new();
+        let mut header = [0; 4];
+        while server.read_exact(&mut header).is_ok() {
+            let length = usize::from(u16::from_ne_bytes([header[2], header[3]])) * 4;
+            let mut request = header.to_vec();
+            request.resize(length, 0);
+            server.read_exact(&mut request[4..]).unwrap();
+            let sequence = u16::try_from(requests.len() + 1).unwrap();
+            if let Some(response) = respond(sequence, &request) {
+                server.write_all(&response).unwrap();
+            }
+            requests.push(request);
+        }
+        requests
+    }
+
+    /// A reply with the given sequence number and a `u32` at byte offset 8.
+    fn reply(sequence: u16, value: u32) -> Vec<u8> {
+        let mut reply = vec![0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        reply[8..12].copy_from_slice(&value.to_ne_bytes());
+        reply
+    }
+
+    #[test]
+    fn intern_atom_retries_on_alloc_error() {
+        use x11rb::errors::ReplyError;
+        use x11rb::protocol::xproto::{ALLOC_ERROR, VALUE_ERROR};
+        use x11rb::protocol::ErrorKind;
+        use x11rb::wrapper::ConnectionExt as _;
+
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            // Skip the connection setup request
+            let mut buffer = [0; 12];
+            server.read_exact(&mut buffer).unwrap();
+            for sequence in 1..=4u16 {
+                // An InternAtom request for a three byte name
+                server.read_exact(&mut buffer).unwrap();
+                assert_eq!(&buffer[8..11], b"FOO");
+                let mut packet = [0; 32];
+                match sequence {
+                    1 | 2 => packet[1] = ALLOC_ERROR,
+                    3 => {
+                        packet[0] = 1;
+                        packet[8..12].copy_from_slice(&42u32.to_ne_bytes());
+                    }
+                    _ => packet[1] = VALUE_ERROR,
+                }
+                packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                server.write_all(&packet).unwrap();
+            }
+            server
+        });
+
+        // Two Alloc errors are followed by the reply
+        let atom = conn.intern_atom_with_retry(false, b"FOO", 3, None).unwrap();
+        assert_eq!(atom, 42);
+
+        // Other errors are not retried
+        let error = conn
+            .intern_atom_with_retry(false, b"FOO", 3, None)
+            .unwrap_err();
+        assert!(matches!(error, ReplyError::X11Error(e) if e.error_kind == ErrorKind::Value));
+        let _server = server.join().unwrap();
+    }
+
+    #[test]
+    fn colormap_allocator_frees_all_colors() {
+        use x11rb::colormap::ColormapAllocator;
+        use x11rb::protocol::xproto::{FREE_COLORS_REQUEST, VALUE_ERROR};
+
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            // The connection setup request and three AllocColor requests
+            let mut buffer = [0; 12 + 3 * 16];
+            server.read_exact(&mut buffer).unwrap();
+            for sequence in 1..=3u16 {
+                let mut packet = [0; 32];
+                if sequence == 2 {
+                    packet[1] = VALUE_ERROR;
+                } else {
+                    packet[0] = 1;
+                    packet[16..20].copy_from_slice(&u32::from(sequence * 10).to_ne_bytes());
+                }
+                packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                server.write_all(&packet).unwrap();
+            }
+            // The FreeColors request for the two allocated pixels
+            let mut request = [0; 20];
+            server.read_exact(&mut request).unwrap();
+            request
+        });
+
+        let mut allocator = ColormapAllocator::new(&conn, 0x20);
+        let colors = [(0xffff, 0, 0), (1, 2, 3), (0, 0, 0xffff)];
+        assert!(allocator.alloc(&colors).is_err());
+        assert_eq!(allocator.pixels(), [10, 30]);
+        drop(allocator);
+        conn.flush().unwrap();
+
+        let request = server.join().unwrap();
+        assert_eq!(request[..4], [FREE_COLORS_REQUEST, 0, 5, 0]);
+        assert_eq!(request[4..8], 0x20u32.to_ne_bytes());
+        assert_eq!(request[12..16], 10u32.to_ne_bytes());
+        assert_eq!(request[16..20], 30u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn create_window_validated_sends_valid_requests() {
+        use x11rb::errors::{CreateWindowError, CreateWindowRequestError};
+        use x11rb::protocol::xproto::{CreateWindowAux, WindowClass, CREATE_WINDOW_REQUEST};
+        use x11rb::wrapper::create_window_validated;
+
+        let (conn, mut server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            // The connection setup request and one CreateWindow request without attributes
+            let mut buffer = [0; 12 + 32];
+            server.read_exact(&mut buffer).unwrap();
+            buffer
+        });
+
+        let screen = conn.setup().roots[0].clone();
+        let create = |aux: &CreateWindowAux| {
+            create_window_validated(
+                &conn,
+                &screen,
+                0,
+                0x0040_0001,
+                screen.root,
+                0,
+                0,
+                10,
+                10,
+                0,
+                WindowClass::INPUT_ONLY,
+                x11rb::COPY_FROM_PARENT,
+                aux,
+            )
+        };
+        // Nothing is sent for invalid parameters
+        let error = create(&CreateWindowAux::new().background_pixel(0)).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                CreateWindowRequestError::InvalidParameters(CreateWindowError::InputOnlyAttribute(
+                    "background_pixel"
+                ))
+            ),
+            "{:?}",
+            error
+        );
+        drop(create(&CreateWindowAux::new()).unwrap());
+        conn.flush().unwrap();
+
+        let buffer = server.join().unwrap();
+        assert_eq!(buffer[12..16], [CREATE_WINDOW_REQUEST, 0, 8, 0]);
+        assert_eq!(buffer[16..20], 0x0040_0001u32.to_ne_bytes());
+    }
+
+    #[test]
+    fn another_wm_running_only_checks_the_selection() {
+        use x11rb::protocol::xproto::{GET_SELECTION_OWNER_REQUEST, INTERN_ATOM_REQUEST};
+        use x11rb::wrapper::another_wm_running;
+
+        const WM_S0: u32 = 300;
+        let (conn, server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            serve(server, |sequence, request| match request[0] {
+                // The atom only exists for the first call
+                INTERN_ATOM_REQUEST if sequence == 1 => Some(reply(sequence, WM_S0)),
+                INTERN_ATOM_REQUEST => Some(reply(sequence, x11rb::NONE)),
+                _ => Some(reply(sequence, 0x0060_0001)),
+            })
+        });
+
+        assert!(another_wm_running(&conn, 0).unwrap());
+        assert!(!another_wm_running(&conn, 0).unwrap());
+        drop(conn);
+
+        let requests = server.join().unwrap();
+        let opcodes = requests.iter().map(|r| r[0]).collect::<Vec<_>>();
+        assert_eq!(
+            opcodes,
+            [
+                INTERN_ATOM_REQUEST,
+                GET_SELECTION_OWNER_REQUEST,
+                INTERN_ATOM_REQUEST
+            ]
+        );
+        assert_eq!(&requests[0][8..12], b"WM_S");
+        assert_eq!(requests[1][4..8], WM_S0.to_ne_bytes());
+    }
+
+    #[test]
+    fn become_window_manager_keeps_the_redirect() {
+        use x11rb::protocol::xproto::{
+            EventMask, ACCESS_ERROR, CHANGE_WINDOW_ATTRIBUTES_REQUEST,
+            GET_WINDOW_ATTRIBUTES_REQUEST,
+        };
+        use x11rb::wrapper::become_window_manager;
+
+        let old_mask = u32::from(EventMask::PROPERTY_CHANGE);
+        let (conn, server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            serve(server, |sequence, request| match request[0] {
+                GET_WINDOW_ATTRIBUTES_REQUEST => {
+                    let mut reply = reply(sequence, 0);
+                    reply[4..8].copy_from_slice(&3u32.to_ne_bytes());
+                    reply.resize(44, 0);
+                    reply[36..40].copy_from_slice(&old_mask.to_ne_bytes());
+                    Some(reply)
+                }
+                // Another window manager is running for the first call
+                CHANGE_WINDOW_ATTRIBUTES_REQUEST if sequence == 2 => {
+                    let mut error = vec![0; 32];
+                    error[1] = ACCESS_ERROR;
+                    error[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                    Some(error)
+                }
+                CHANGE_WINDOW_ATTRIBUTES_REQUEST => None,
+                // The GetInputFocus request for checking for errors
+                _ => Some(reply(sequence, 0)),
+            })
+        });
+
+        assert!(!become_window_manager(&conn, 0).unwrap());
+        assert!(become_window_manager(&conn, 0).unwrap());
+        drop(conn);
+
+        // The old event mask is not restored afterwards
+        let requests = server.join().unwrap();
+        let changes = requests
+            .iter()
+            .filter(|r| r[0] == CHANGE_WINDOW_ATTRIBUTES_REQUEST)
+            .collect::<Vec<_>>();
+        assert_eq!(changes.len(), 2);
+        let new_mask = old_mask | u32::from(EventMask::SUBSTRUCTURE_REDIRECT);
+        for change in changes {
+            assert_eq!(change[12..16], new_mask.to_ne_bytes());
+        }
+    }
+
+    #[test]
+    fn collect_pending_errors_keeps_other_events() {
+        use x11rb::protocol::xproto::{ConnectionExt as _, MAP_NOTIFY_EVENT, MAP_WINDOW_REQUEST};
+        use x11rb::protocol::{ErrorKind, Event};
+        use x11rb::wrapper::collect_pending_errors;
+
+        let (conn, server) = connect(&[]);
+        let server = std::thread::spawn(move || {
+            serve(server, |sequence, request| {
+                if request[0] != MAP_WINDOW_REQUEST {
+                    return Some(reply(sequence, 0));
+                }
+                // A Window error for the request, surrounded by events
+                let mut response = vec![0; 96];
+                for chunk in response.chunks_mut(32) {
+                    chunk[0] = MAP_NOTIFY_EVENT;
+                    chunk[2..4].copy_from_slice(&sequence.to_ne_bytes());
+                }
+                response[32] = 0;
+                response[33] = 3;
+                Some(response)
+            })
+        });
+
+        drop(conn.map_window(42).unwrap());
+        let errors = collect_pending_errors(&conn).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].error_kind, ErrorKind::Window);
+        for _ in 0..2 {
+            let event = conn.poll_for_event().unwrap();
+            assert!(matches!(event, Some(Event::MapNotify(_))), "{:?}", event);
+        }
+        assert!(conn.poll_for_event().unwrap().is_none());
+        drop(conn);
+        server.join().unwrap();
+    }
+}